@@ -9,12 +9,13 @@ import { Link, useNavigate } from '@tanstack/react-router'
 import { Button } from '@/components/ui/button'
 import { Input } from '@/components/ui/input'
 import { isSuccessResponse } from '@/types/api'
+import { localizeError } from '@/utils/i18n'
 import { useState } from 'react'
 import { useTranslation } from 'react-i18next'
 import { toast } from 'sonner'
 
 export function HeroSection() {
-  const { t } = useTranslation()
+  const { t, i18n } = useTranslation()
   const navigate = useNavigate()
   const [youtubeUrl, setYoutubeUrl] = useState('')
   const [isLoading, setIsLoading] = useState(false)
@@ -54,7 +55,7 @@ export function HeroSection() {
       const response = await window.electronAPI.downloadManager.getStreamingInfo(url)
 
       if (!isSuccessResponse(response)) {
-        throw new Error(response.error || 'Failed to get video info')
+        throw new Error(localizeError(response, i18n.language, 'Failed to get video info'))
       }
 
       const { videoInfo, streamingUrl } = response.data
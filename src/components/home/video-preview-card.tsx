@@ -6,15 +6,23 @@ import { Avatar, AvatarFallback, AvatarImage } from '@/components/ui/avatar'
 import { Button } from '@/components/ui/button'
 import { CheckCircle2, ChevronLeft } from 'lucide-react'
 import { Dialog, DialogContent } from '@/components/ui/dialog'
-import type { DownloadProgress, VideoInfo } from '@/types/download'
+import {
+  type DownloadProgress,
+  type OutputTemplatePreview,
+  type VideoInfo,
+  isCompletedStatus,
+} from '@/types/download'
+import { Input } from '@/components/ui/input'
 import { Progress } from '@/components/ui/progress'
 import { Switch } from '@/components/ui/switch'
 import { VideoEditor } from '@/components/editor/video-editor'
 import { toast } from 'sonner'
 import { useCallback, useEffect, useRef, useState } from 'react'
 import { useNavigate } from '@tanstack/react-router'
+import { localizeError } from '@/utils/i18n'
 import { useTimelineStore } from '@/stores/timeline-store'
 import { useTranslation } from 'react-i18next'
+import { isSuccessResponse } from '@/types/api'
 
 interface VideoPreviewCardProps {
   videoInfo: VideoInfo
@@ -31,8 +39,15 @@ interface DownloadOptions {
   downloadThumbnail: boolean
   saveMetadata: boolean
   createSubdirectories: boolean
+  audioLanguage?: string
+  downloadAllAudioTracks?: boolean
+  writeChaptersFile?: boolean
+  writePodcastFeed?: boolean
+  filenameTemplate?: string
 }
 
+const FILENAME_PREVIEW_DEBOUNCE = 300 // ms after the last change to the options
+
 function formatTime(seconds: number): string {
   const floor = Math.floor(seconds)
   const min = Math.floor(floor / 60)
@@ -77,7 +92,7 @@ function formatFileSize(bytes: number): string {
 }
 
 export function VideoPreviewCard({ videoInfo, onDownload, onReset }: VideoPreviewCardProps) {
-  const { t } = useTranslation()
+  const { t, i18n } = useTranslation()
   const navigate = useNavigate()
 
   const [showExportDialog, setShowExportDialog] = useState(false)
@@ -86,6 +101,7 @@ export function VideoPreviewCard({ videoInfo, onDownload, onReset }: VideoPrevie
   const [streamUrl, setStreamUrl] = useState<string | null>(null)
   const [streamQuality, setStreamQuality] = useState<string | null>(null)
   const [showInfo, setShowInfo] = useState(false)
+  const [filenamePreview, setFilenamePreview] = useState<OutputTemplatePreview | null>(null)
 
   const { trimStart, trimEnd, isTrimmed, initializeForVideo } = useTimelineStore()
 
@@ -102,6 +118,21 @@ export function VideoPreviewCard({ videoInfo, onDownload, onReset }: VideoPrevie
     initializeForVideo(videoInfo.duration)
   }, [videoInfo.duration, initializeForVideo])
 
+  // Where the download would be saved, rendered in the main process exactly as the download will be
+  useEffect(() => {
+    if (!showExportDialog) return
+    const timer = setTimeout(async () => {
+      try {
+        const template = downloadOptions.filenameTemplate ?? ''
+        const response = await window.electronAPI.download.previewFilename(template, videoInfo, downloadOptions as any)
+        setFilenamePreview(isSuccessResponse(response) ? response.data : null)
+      } catch (error) {
+        console.error('Failed to preview filename:', error)
+      }
+    }, FILENAME_PREVIEW_DEBOUNCE)
+    return () => clearTimeout(timer)
+  }, [showExportDialog, downloadOptions, videoInfo])
+
   useEffect(() => {
     const loadUserDefaults = async () => {
       try {
@@ -195,21 +226,21 @@ export function VideoPreviewCard({ videoInfo, onDownload, onReset }: VideoPrevie
     const handleProgressUpdate = (progress: DownloadProgress) => {
       if (progress.title === videoInfo.title) {
         setDownloadProgress(progress)
-        if (progress.status === 'completed') {
+        if (isCompletedStatus(progress.status)) {
           setIsDownloading(false)
           setShowExportDialog(false)
           toast.success(t('msgDownloadCompleted'))
           setTimeout(() => navigate({ to: '/library' }), 2000)
         } else if (progress.status === 'failed') {
           setIsDownloading(false)
-          toast.error(progress.error?.message || t('msgDownloadFailed'))
+          toast.error(localizeError(progress.error, i18n.language, t('msgDownloadFailed')))
         }
       }
     }
 
     window.addEventListener('download-progress-update', handleProgressUpdate as any)
     return () => window.removeEventListener('download-progress-update', handleProgressUpdate as any)
-  }, [videoInfo.title, t, navigate])
+  }, [videoInfo.title, t, i18n.language, navigate])
 
   const handleDownload = useCallback(async () => {
     setIsDownloading(true)
@@ -537,8 +568,63 @@ export function VideoPreviewCard({ videoInfo, onDownload, onReset }: VideoPrevie
                   ? t('previewAudioOnly')
                   : t('previewVideoAudio')}
               </p>
+              {(downloadOptions.format === 'mp3' || downloadOptions.format === 'm4a') && (
+                <div className="flex gap-4 pt-1">
+                  <label className="flex cursor-pointer items-center gap-1.5">
+                    <Switch
+                      checked={downloadOptions.writeChaptersFile ?? false}
+                      onCheckedChange={checked => setDownloadOptions(prev => ({ ...prev, writeChaptersFile: checked }))}
+                      className="scale-75"
+                    />
+                    <span className="text-[11px]">{t('previewChapterFiles')}</span>
+                  </label>
+                  <label className="flex cursor-pointer items-center gap-1.5">
+                    <Switch
+                      checked={downloadOptions.writePodcastFeed ?? false}
+                      onCheckedChange={checked => setDownloadOptions(prev => ({ ...prev, writePodcastFeed: checked }))}
+                      className="scale-75"
+                    />
+                    <span className="text-[11px]">{t('previewPodcastFeed')}</span>
+                  </label>
+                </div>
+              )}
             </div>
 
+            {/* Audio Language Selection (videos with dubs) */}
+            {videoInfo.audioLanguages && (
+              <div className="space-y-2">
+                <label className="text-xs font-medium">{t('previewAudioLanguage')}</label>
+                <div className="flex flex-wrap gap-2">
+                  {[
+                    { key: 'default', label: t('previewAudioLanguageDefault') },
+                    ...videoInfo.audioLanguages.map(language => ({ key: language.code, label: language.label })),
+                    { key: 'all', label: t('previewAllAudioTracks') },
+                  ].map(({ key, label }) => {
+                    const selected = downloadOptions.downloadAllAudioTracks
+                      ? key === 'all'
+                      : (downloadOptions.audioLanguage ?? 'default') === key
+                    return (
+                      <button
+                        key={key}
+                        onClick={() =>
+                          setDownloadOptions(prev => ({
+                            ...prev,
+                            audioLanguage: key === 'default' || key === 'all' ? undefined : key,
+                            downloadAllAudioTracks: key === 'all',
+                          }))
+                        }
+                        className={`rounded-lg border px-3 py-1.5 text-xs font-medium transition-colors ${
+                          selected ? 'border-primary bg-primary text-primary-foreground' : 'hover:bg-muted'
+                        }`}
+                      >
+                        {label}
+                      </button>
+                    )
+                  })}
+                </div>
+              </div>
+            )}
+
             {/* Options */}
             <div className="flex items-center justify-between border-t pt-4">
               <div className="flex gap-4">
@@ -568,6 +654,34 @@ export function VideoPreviewCard({ videoInfo, onDownload, onReset }: VideoPrevie
                 </label>
               </div>
             </div>
+
+            {/* Filename template, previewed as it is typed */}
+            <div className="space-y-1.5">
+              <label className="text-xs font-medium">{t('previewFilename')}</label>
+              <Input
+                value={downloadOptions.filenameTemplate ?? ''}
+                placeholder={t('previewFilenamePlaceholder')}
+                className="h-8 font-mono text-xs"
+                onChange={e => setDownloadOptions(prev => ({ ...prev, filenameTemplate: e.target.value }))}
+              />
+              {filenamePreview && (
+                <div className="space-y-0.5 text-[10px]">
+                  <p className="text-muted-foreground truncate font-mono" title={filenamePreview.path}>
+                    {filenamePreview.filename}
+                  </p>
+                  {filenamePreview.errors.map(error => (
+                    <p key={error} className="text-destructive">
+                      {error}
+                    </p>
+                  ))}
+                  {filenamePreview.warnings.map(warning => (
+                    <p key={warning} className="text-yellow-600">
+                      {warning}
+                    </p>
+                  ))}
+                </div>
+              )}
+            </div>
           </div>
 
           {/* Footer */}
@@ -580,7 +694,12 @@ export function VideoPreviewCard({ videoInfo, onDownload, onReset }: VideoPrevie
               <Button variant="ghost" size="sm" onClick={() => setShowExportDialog(false)}>
                 {t('cancel')}
               </Button>
-              <Button size="sm" onClick={handleDownload} disabled={isDownloading} className="px-6">
+              <Button
+                size="sm"
+                onClick={handleDownload}
+                disabled={isDownloading || (filenamePreview?.errors.length ?? 0) > 0}
+                className="px-6"
+              >
                 {isDownloading ? t('editorExporting') : t('editorExport')}
               </Button>
             </div>
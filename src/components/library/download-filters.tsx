@@ -1,15 +1,15 @@
 /**
  * DownloadFilters - Filter controls for download list
- * Search box and status filter dropdown (all, active, completed, failed).
+ * Search box and status filter dropdown (all, active, completed, failed, continue watching).
  */
 
-import { AlertCircle, CheckCircle, Download, Search } from 'lucide-react'
+import { AlertCircle, CheckCircle, Download, History, Search } from 'lucide-react'
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select'
 
 import { Input } from '@/components/ui/input'
 import { useTranslation } from 'react-i18next'
 
-type DownloadFilter = 'all' | 'active' | 'completed' | 'failed'
+type DownloadFilter = 'all' | 'active' | 'completed' | 'failed' | 'continue-watching'
 
 interface DownloadFiltersProps {
   filter: DownloadFilter
@@ -63,6 +63,12 @@ export function DownloadFilters({ filter, searchQuery, onFilterChange, onSearchC
               <span>{t('filterFailed')}</span>
             </div>
           </SelectItem>
+          <SelectItem value="continue-watching">
+            <div className="flex items-center gap-2">
+              <History className="h-4 w-4" />
+              <span>{t('filterContinueWatching')}</span>
+            </div>
+          </SelectItem>
         </SelectContent>
       </Select>
     </div>
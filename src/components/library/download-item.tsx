@@ -9,9 +9,12 @@ import {
   Clock,
   Download,
   Eye,
+  EyeOff,
   FileVideo,
   FolderOpen,
+  Loader2,
   MoreHorizontal,
+  Pause,
   Play,
   Scissors,
   Trash2,
@@ -38,7 +41,7 @@ import {
 
 import { Badge } from '@/components/ui/badge'
 import { Button } from '@/components/ui/button'
-import type { DownloadProgress } from '@/types/download'
+import { type DownloadProgress, isCompletedStatus } from '@/types/download'
 import { Progress } from '@/components/ui/progress'
 import React, { useState } from 'react'
 import { toast } from 'sonner'
@@ -67,10 +70,14 @@ function getStatusIcon(status: string) {
   switch (status) {
     case 'completed':
       return <CheckCircle className="h-4 w-4 text-green-600" />
+    case 'completed-with-errors':
+      return <CheckCircle className="h-4 w-4 text-yellow-600" />
     case 'failed':
       return <AlertCircle className="text-destructive h-4 w-4" />
     case 'downloading':
       return <Download className="text-primary h-4 w-4 animate-pulse" />
+    case 'processing':
+      return <Loader2 className="text-primary h-4 w-4 animate-spin" />
     case 'cancelled':
       return <X className="text-muted-foreground h-4 w-4" />
     default:
@@ -81,8 +88,10 @@ function getStatusIcon(status: string) {
 function getStatusBadge(status: string, t: (key: string) => string) {
   const statusLabels: Record<string, string> = {
     completed: t('statusCompleted'),
+    'completed-with-errors': t('statusCompletedWithErrors'),
     failed: t('statusFailed'),
     downloading: t('statusDownloading'),
+    processing: t('statusProcessing'),
     cancelled: t('downloadCancelled'),
     paused: t('statusPaused'),
   }
@@ -90,6 +99,7 @@ function getStatusBadge(status: string, t: (key: string) => string) {
 
   switch (status) {
     case 'completed':
+    case 'completed-with-errors':
       return <Badge variant="default">{label}</Badge>
     case 'failed':
       return <Badge variant="destructive">{label}</Badge>
@@ -152,6 +162,13 @@ export function DownloadItem({
     }
   }
 
+  const handleToggleWatched = async () => {
+    const response = await window.electronAPI.library.markWatched(download.downloadId, !download.playback?.watched)
+    if (!response.success) {
+      toast.error(t('msgMarkWatchedFailed'))
+    }
+  }
+
   const handleRetry = async () => {
     try {
       await onRetry(download.downloadId)
@@ -166,19 +183,40 @@ export function DownloadItem({
     onCancel(download.downloadId)
   }
 
+  // The new status arrives as a progress event
+  const handlePause = async () => {
+    const response = await window.electronAPI.downloadManager.pause(download.downloadId)
+    if (!response.success) {
+      toast.error(t('msgDownloadPauseFailed'))
+    }
+  }
+
+  const handleResume = async () => {
+    const response = await window.electronAPI.downloadManager.resume(download.downloadId)
+    if (!response.success) {
+      toast.error(t('msgDownloadResumeFailed'))
+    }
+  }
+
+  // Thumbnail saved next to the video, else the copy prefetched into the thumbnail cache
+  const thumbnailPath = download.thumbnailPath || download.cachedThumbnailPath
+  const { playback } = download
+  const watchedPercent =
+    playback && !playback.watched && playback.duration ? Math.min(playback.position / playback.duration, 1) * 100 : 0
+
   return (
     <Card className="border-0 shadow-sm transition-shadow hover:shadow-md">
       <CardContent className="p-6">
         <div className="flex items-start gap-4">
           <div
             className="bg-muted group relative flex h-12 w-20 shrink-0 cursor-pointer items-center justify-center overflow-hidden rounded-lg"
-            onClick={() => download.status === 'completed' && onPreview?.(download)}
+            onClick={() => isCompletedStatus(download.status) && onPreview?.(download)}
           >
-            {download.thumbnailPath ? (
+            {thumbnailPath ? (
               <img
                 src={(() => {
                   // Convert file path to clipy-file:// URL (cross-platform)
-                  const normalizedPath = download.thumbnailPath.replace(/\\/g, '/')
+                  const normalizedPath = thumbnailPath.replace(/\\/g, '/')
                   // Windows paths have drive letter (C:/), Unix paths start with /
                   return /^[a-zA-Z]:/.test(normalizedPath)
                     ? `clipy-file:///${normalizedPath}` // Windows: clipy-file:///C:/...
@@ -193,8 +231,8 @@ export function DownloadItem({
                 }}
               />
             ) : null}
-            <FileVideo className={`text-muted-foreground h-6 w-6 ${download.thumbnailPath ? 'hidden' : ''}`} />
-            {download.status === 'completed' && (
+            <FileVideo className={`text-muted-foreground h-6 w-6 ${thumbnailPath ? 'hidden' : ''}`} />
+            {isCompletedStatus(download.status) && (
               <div className="bg-foreground/10 group-hover:bg-foreground/20 absolute inset-0 flex items-center justify-center rounded-lg transition-all duration-200">
                 <Eye className="text-foreground h-4 w-4 opacity-0 group-hover:opacity-100" />
               </div>
@@ -202,6 +240,11 @@ export function DownloadItem({
             {download.status === 'downloading' && (
               <div className="bg-primary absolute right-0 bottom-0 left-0 h-1 animate-pulse rounded-b-lg" />
             )}
+            {watchedPercent > 0 && (
+              <div className="bg-foreground/30 absolute right-0 bottom-0 left-0 h-1">
+                <div className="bg-primary h-full" style={{ width: `${watchedPercent}%` }} />
+              </div>
+            )}
           </div>
 
           <div className="min-w-0 flex-1">
@@ -215,9 +258,15 @@ export function DownloadItem({
                   <span>{formatFileSize(download.totalBytes)}</span>
                   <span>•</span>
                   <span>{new Date(download.startTime).toLocaleDateString()}</span>
+                  {playback?.watched && (
+                    <>
+                      <span>•</span>
+                      <span>{t('libraryWatched')}</span>
+                    </>
+                  )}
                 </div>
 
-                {download.status === 'downloading' && (
+                {(download.status === 'downloading' || download.status === 'processing') && (
                   <div className="mt-4 space-y-2">
                     <div className="flex justify-between text-sm">
                       <span>{t('progress')}</span>
@@ -240,19 +289,64 @@ export function DownloadItem({
                   </div>
                 )}
 
+                {download.qualityFallback && download.status !== 'failed' && (
+                  <p className="text-muted-foreground mt-2 text-xs">
+                    {t(
+                      isCompletedStatus(download.status) ? 'downloadQualityFallback' : 'downloadQualityFallbackPending',
+                      {
+                        requested: download.qualityFallback.requested,
+                        actual: download.qualityFallback.actual,
+                      },
+                    )}
+                  </p>
+                )}
+
+                {download.warning && isCompletedStatus(download.status) && (
+                  <p className="mt-2 text-xs text-yellow-600">{download.warning}</p>
+                )}
+
+                {download.resumeRestarted && !isCompletedStatus(download.status) && (
+                  <p className="mt-2 text-xs text-yellow-600">{t('downloadResumeRestarted')}</p>
+                )}
+
                 {download.status === 'failed' && download.error && (
                   <p className="text-destructive mt-2 text-sm">{download.error.message}</p>
                 )}
+
+                {download.status === 'completed-with-errors' && download.partialErrors && (
+                  <div className="mt-2 text-xs text-yellow-600">
+                    <p>{t('downloadCompletedWithErrors')}</p>
+                    <ul className="mt-1 list-inside list-disc">
+                      {download.partialErrors.map((error, index) => (
+                        <li key={index} className="truncate" title={error}>
+                          {error}
+                        </li>
+                      ))}
+                    </ul>
+                  </div>
+                )}
               </div>
 
               <div className="flex shrink-0 items-center gap-2">
-                {download.status === 'downloading' && (
+                {(download.status === 'downloading' || download.status === 'queued') && (
+                  <Button variant="outline" size="sm" onClick={handlePause} title={t('actionPause')}>
+                    <Pause className="h-4 w-4" />
+                  </Button>
+                )}
+
+                {download.status === 'paused' && (
+                  <Button variant="outline" size="sm" onClick={handleResume} title={t('actionResume')}>
+                    <Play className="h-4 w-4" />
+                  </Button>
+                )}
+
+                {(download.status === 'downloading' || download.status === 'paused') && (
                   <Button variant="outline" size="sm" onClick={handleCancel}>
                     <X className="h-4 w-4" />
                   </Button>
                 )}
 
-                {download.status === 'completed' && download.filePath && (
+                {isCompletedStatus(download.status) && download.filePath && (
                   <>
                     <Button variant="outline" size="sm" onClick={() => onEdit?.(download)} title={t('actionEdit')}>
                       <Scissors className="h-4 w-4" />
@@ -283,7 +377,7 @@ export function DownloadItem({
                     </Button>
                   </DropdownMenuTrigger>
                   <DropdownMenuContent align="end">
-                    {download.status === 'completed' && download.filePath && (
+                    {isCompletedStatus(download.status) && download.filePath && (
                       <>
                         <DropdownMenuItem onClick={() => onEdit?.(download)}>
                           <Scissors className="mr-2 h-4 w-4" />
@@ -297,6 +391,10 @@ export function DownloadItem({
                           <FolderOpen className="mr-2 h-4 w-4" />
                           {t('actionOpenFolder')}
                         </DropdownMenuItem>
+                        <DropdownMenuItem onClick={handleToggleWatched}>
+                          {playback?.watched ? <EyeOff className="mr-2 h-4 w-4" /> : <Eye className="mr-2 h-4 w-4" />}
+                          {t(playback?.watched ? 'actionMarkUnwatched' : 'actionMarkWatched')}
+                        </DropdownMenuItem>
                         <DropdownMenuSeparator />
                       </>
                     )}
@@ -3,29 +3,44 @@
  * Renders DownloadItem for each download with search and filter controls.
  */
 
-import { BarChart3, Download, FileVideo } from 'lucide-react'
+import {
+  AlertDialog,
+  AlertDialogAction,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from '@/components/ui/alert-dialog'
+import { BarChart3, Download, FileVideo, Square } from 'lucide-react'
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card'
 
 import { Button } from '@/components/ui/button'
 import { DownloadFilters } from './download-filters'
 import { DownloadItem } from './download-item'
-import type { DownloadProgress } from '@/types/download'
+import type { DownloadProgress, LibraryChannel } from '@/types/download'
 import { Link } from '@tanstack/react-router'
-import React from 'react'
+import React, { useState } from 'react'
 import { Separator } from '@/components/ui/separator'
 import { Skeleton } from '@/components/ui/skeleton'
 import { useTranslation } from 'react-i18next'
 
 type DownloadFilter = 'all' | 'active' | 'completed' | 'failed'
 
+/** Statuses the "stop all" button stops */
+const STOPPABLE_STATUSES = ['queued', 'initializing', 'fetching-info', 'downloading', 'processing', 'retrying']
+
 interface DownloadsListProps {
   downloads: DownloadProgress[]
+  channels?: LibraryChannel[] // For matching channels by their current and earlier names
   filter: DownloadFilter
   searchQuery: string
   isLoading: boolean
   onFilterChange: (filter: DownloadFilter) => void
   onSearchChange: (query: string) => void
   onCancelDownload: (downloadId: string) => void
+  onCancelAll?: () => void // Shows a "stop all" button while downloads run
   onDeleteDownload: (downloadId: string) => void
   onRetryDownload: (downloadId: string) => void
   onPreviewDownload?: (download: DownloadProgress) => void
@@ -36,12 +51,14 @@ interface DownloadsListProps {
 
 export function DownloadsList({
   downloads,
+  channels = [],
   filter,
   searchQuery,
   isLoading,
   onFilterChange,
   onSearchChange,
   onCancelDownload,
+  onCancelAll,
   onDeleteDownload,
   onRetryDownload,
   onPreviewDownload,
@@ -50,19 +67,34 @@ export function DownloadsList({
   getStatusBadge,
 }: DownloadsListProps) {
   const { t } = useTranslation()
+  const [showCancelAllDialog, setShowCancelAllDialog] = useState(false)
+  const runningCount = downloads.filter(download => STOPPABLE_STATUSES.includes(download.status)).length
 
-  const filteredDownloads = downloads.filter(
-    download =>
-      download.title.toLowerCase().includes(searchQuery.toLowerCase()) ||
-      download.url.toLowerCase().includes(searchQuery.toLowerCase()),
-  )
+  const query = searchQuery.toLowerCase()
+  const channelNames = new Map(channels.map(channel => [channel.id, [channel.name, ...channel.previousNames]]))
+  const filteredDownloads = downloads.filter(download => {
+    const names = (download.channelId && channelNames.get(download.channelId)) || [download.channel ?? '']
+    return (
+      download.title.toLowerCase().includes(query) ||
+      download.url.toLowerCase().includes(query) ||
+      (query !== '' && names.some(name => name.toLowerCase().includes(query)))
+    )
+  })
 
   return (
     <Card className="border-0 shadow-sm">
       <CardHeader>
-        <div className="flex items-center gap-2">
-          <BarChart3 className="text-primary h-5 w-5" />
-          <CardTitle>{t('downloadHistory')}</CardTitle>
+        <div className="flex items-center justify-between gap-2">
+          <div className="flex items-center gap-2">
+            <BarChart3 className="text-primary h-5 w-5" />
+            <CardTitle>{t('downloadHistory')}</CardTitle>
+          </div>
+          {onCancelAll && runningCount > 0 && (
+            <Button variant="outline" size="sm" onClick={() => setShowCancelAllDialog(true)}>
+              <Square className="mr-2 h-4 w-4" />
+              {t('stopAllDownloads')}
+            </Button>
+          )}
         </div>
       </CardHeader>
       <CardContent className="space-y-6">
@@ -107,6 +139,23 @@ export function DownloadsList({
           )}
         </div>
       </CardContent>
+      <AlertDialog open={showCancelAllDialog} onOpenChange={setShowCancelAllDialog}>
+        <AlertDialogContent>
+          <AlertDialogHeader>
+            <AlertDialogTitle>{t('confirmStopAllTitle')}</AlertDialogTitle>
+            <AlertDialogDescription>{t('confirmStopAllDescription', { count: runningCount })}</AlertDialogDescription>
+          </AlertDialogHeader>
+          <AlertDialogFooter>
+            <AlertDialogCancel>{t('cancel')}</AlertDialogCancel>
+            <AlertDialogAction
+              onClick={onCancelAll}
+              className="bg-destructive text-destructive-foreground hover:bg-destructive/90"
+            >
+              {t('stopAllDownloads')}
+            </AlertDialogAction>
+          </AlertDialogFooter>
+        </AlertDialogContent>
+      </AlertDialog>
     </Card>
   )
 }
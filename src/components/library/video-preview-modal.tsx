@@ -1,13 +1,18 @@
 /**
  * VideoPreviewModal - Modal for previewing completed downloads
+ * Resumes where the video was last stopped and saves the position while it plays.
  */
 
 import type { DownloadProgress } from '@/types/download'
 import { Folder, Play } from 'lucide-react'
+import { useEffect, useRef } from 'react'
 
 import { Button } from '@/components/ui/button'
 import { useTranslation } from 'react-i18next'
 
+const POSITION_SAVE_INTERVAL = 5 // Seconds of playback between saved positions
+const MIN_RESUME_POSITION = 5 // Closer to the start than this, playback starts over
+
 interface VideoPreviewModalProps {
   download: DownloadProgress & { blobUrl?: string }
   isLoading: boolean
@@ -17,6 +22,38 @@ interface VideoPreviewModalProps {
 
 export function VideoPreviewModal({ download, isLoading, onClose, onOpenFolder }: VideoPreviewModalProps) {
   const { t } = useTranslation()
+  const videoRef = useRef<HTMLVideoElement>(null)
+  const lastSavedRef = useRef(0)
+  // Kept outside the element, which is already detached when the closing cleanup runs
+  const playheadRef = useRef<{ time: number; duration: number } | null>(null)
+
+  const savePosition = () => {
+    const playhead = playheadRef.current
+    if (!playhead || !Number.isFinite(playhead.duration)) return
+    lastSavedRef.current = playhead.time
+    window.electronAPI.library.setPlaybackPosition(download.downloadId, playhead.time, playhead.duration)
+  }
+
+  const handleLoadedMetadata = () => {
+    const video = videoRef.current
+    const playback = download.playback
+    if (video && playback && !playback.watched && playback.position >= MIN_RESUME_POSITION) {
+      video.currentTime = playback.position
+      lastSavedRef.current = playback.position
+    }
+  }
+
+  const handleTimeUpdate = () => {
+    const video = videoRef.current
+    if (!video) return
+    playheadRef.current = { time: video.currentTime, duration: video.duration }
+    if (Math.abs(video.currentTime - lastSavedRef.current) >= POSITION_SAVE_INTERVAL) {
+      savePosition()
+    }
+  }
+
+  // Save where playback stopped when the preview closes
+  useEffect(() => savePosition, [])
 
   // Convert file path to clipy-file:// URL (cross-platform)
   const getVideoSource = () => {
@@ -49,7 +86,16 @@ export function VideoPreviewModal({ download, isLoading, onClose, onOpenFolder }
                 </div>
               </div>
             ) : (
-              <video controls className="w-full rounded-lg" preload="metadata">
+              <video
+                ref={videoRef}
+                controls
+                className="w-full rounded-lg"
+                preload="metadata"
+                onLoadedMetadata={handleLoadedMetadata}
+                onTimeUpdate={handleTimeUpdate}
+                onPause={savePosition}
+                onEnded={savePosition}
+              >
                 <source src={getVideoSource()} type="video/mp4" />
                 Your browser does not support the video tag.
               </video>
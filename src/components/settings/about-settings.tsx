@@ -4,7 +4,7 @@
  */
 
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card'
-import { ExternalLink, Github } from 'lucide-react'
+import { ClipboardCopy, ExternalLink, Github, Loader2 } from 'lucide-react'
 import { useEffect, useState } from 'react'
 
 import { Badge } from '@/components/ui/badge'
@@ -13,12 +13,14 @@ import Logo from '@/components/navigation/logo'
 import { Skeleton } from '@/components/ui/skeleton'
 import type { SystemInfo } from '@/types/system'
 import { isSuccessResponse } from '@/types/api'
+import { toast } from 'sonner'
 import { useTranslation } from 'react-i18next'
 
 export default function AboutSettings() {
   const { t } = useTranslation()
   const [systemInfo, setSystemInfo] = useState<SystemInfo | null>(null)
   const [isLoading, setIsLoading] = useState(true)
+  const [isCopyingDiagnostics, setIsCopyingDiagnostics] = useState(false)
 
   useEffect(() => {
     async function fetchSystemInfo() {
@@ -55,6 +57,23 @@ export default function AboutSettings() {
     fetchSystemInfo()
   }, [])
 
+  const handleCopyDiagnostics = async () => {
+    setIsCopyingDiagnostics(true)
+    try {
+      const response = await window.electronAPI.system.runDiagnostics()
+      if (!isSuccessResponse(response)) {
+        throw new Error(response.error)
+      }
+      await navigator.clipboard.writeText(JSON.stringify(response.data, null, 2))
+      toast.success(t('settingsDiagnosticsCopied'))
+    } catch (error) {
+      console.error('[AboutSettings] Failed to copy diagnostics:', error)
+      toast.error(t('settingsDiagnosticsFailed'))
+    } finally {
+      setIsCopyingDiagnostics(false)
+    }
+  }
+
   const handleOpenUrl = async (url: string) => {
     try {
       await window.electronAPI.shell.openExternal(url)
@@ -98,7 +117,17 @@ export default function AboutSettings() {
 
         {/* System Info */}
         <div className="space-y-4">
-          <h3 className="text-foreground font-medium">{t('settingsSystemInformation')}</h3>
+          <div className="flex items-center justify-between gap-2">
+            <h3 className="text-foreground font-medium">{t('settingsSystemInformation')}</h3>
+            <Button variant="outline" size="sm" onClick={handleCopyDiagnostics} disabled={isCopyingDiagnostics}>
+              {isCopyingDiagnostics ? (
+                <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+              ) : (
+                <ClipboardCopy className="mr-2 h-4 w-4" />
+              )}
+              {t('settingsCopyDiagnostics')}
+            </Button>
+          </div>
           <div className="bg-muted/30 grid gap-3 rounded-lg p-4 sm:grid-cols-2">
             <div className="text-sm">
               <span className="text-muted-foreground">{t('settingsOperatingSystem')}</span>
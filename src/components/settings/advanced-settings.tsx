@@ -2,7 +2,7 @@
  * AdvancedSettings - Advanced configuration and debugging
  */
 
-import type { AdvancedConfig, AppConfig } from '@/types/system'
+import type { AdvancedConfig, AppConfig, BinaryType, ConnectivityReport } from '@/types/system'
 import {
   AlertDialog,
   AlertDialogAction,
@@ -14,15 +14,17 @@ import {
   AlertDialogTitle,
   AlertDialogTrigger,
 } from '@/components/ui/alert-dialog'
-import { AlertTriangle, Bug, FileCode2, RefreshCw, RotateCcw, Terminal } from 'lucide-react'
+import { AlertTriangle, Bug, FileCode2, FolderOpen, RefreshCw, RotateCcw, Terminal, Wifi } from 'lucide-react'
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card'
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select'
 import { useCallback, useEffect, useState } from 'react'
 
 import { Badge } from '@/components/ui/badge'
 import { Button } from '@/components/ui/button'
+import { Input } from '@/components/ui/input'
 import { Skeleton } from '@/components/ui/skeleton'
 import { Switch } from '@/components/ui/switch'
-import { isSuccessResponse } from '@/types/api'
+import { type ApiResponse, isSuccessResponse } from '@/types/api'
 import { toast } from 'sonner'
 import { useTranslation } from 'react-i18next'
 
@@ -30,35 +32,79 @@ const defaultAdvancedConfig: AdvancedConfig = {
   debugLogging: false,
   ffmpegPath: '',
   ytDlpPath: '',
+  maxLogFileSizeMb: 10,
+  maxLogFiles: 10,
+  thumbnailCacheDays: 30,
+  respectUserYtdlpConfig: false,
+  proxyUrl: '',
+  maxMemoryMb: 0,
 }
 
+const MEMORY_LIMITS_MB = [0, 1024, 2048, 4096, 8192]
+
 interface BinaryInfo {
   ffmpeg: { version: string; path: string } | null
   ytDlp: { version: string; path: string } | null
 }
 
+type BinaryPathSetting = 'ffmpegPath' | 'ytDlpPath'
+
+const BINARY_PATH_SETTINGS: { setting: BinaryPathSetting; type: BinaryType; labelKey: string }[] = [
+  { setting: 'ffmpegPath', type: 'ffmpeg', labelKey: 'settingsCustomFfmpegPath' },
+  { setting: 'ytDlpPath', type: 'ytdlp', labelKey: 'settingsCustomYtdlpPath' },
+]
+
+/** Connection checks in the order they are shown; proxy only when one is set */
+const CONNECTIVITY_CHECKS = [
+  { key: 'internet', okField: 'internetOk', labelKey: 'settingsConnectivityInternet' },
+  { key: 'github', okField: 'githubOk', labelKey: 'settingsConnectivityGithub' },
+  { key: 'youtube', okField: 'youtubeOk', labelKey: 'settingsConnectivityYoutube' },
+  { key: 'proxy', okField: 'proxyOk', labelKey: 'settingsConnectivityProxy' },
+] as const
+
+/** App folders that can be opened from the settings, without the renderer knowing their paths */
+const APP_FOLDERS = [
+  { labelKey: 'settingsOpenAppDataFolder', open: () => window.electronAPI.shell.openAppDataFolder() },
+  { labelKey: 'settingsOpenCacheFolder', open: () => window.electronAPI.shell.openCacheFolder() },
+  { labelKey: 'settingsOpenLogsFolder', open: () => window.electronAPI.shell.openLogsFolder() },
+]
+
 export default function AdvancedSettings() {
   const { t } = useTranslation()
   const [config, setConfig] = useState<AdvancedConfig>(defaultAdvancedConfig)
   const [isLoading, setIsLoading] = useState(true)
   const [binaryInfo, setBinaryInfo] = useState<BinaryInfo>({ ffmpeg: null, ytDlp: null })
+  // Custom binary paths as typed; saved on blur once the binary checks out
+  const [pathDrafts, setPathDrafts] = useState<Record<BinaryPathSetting, string>>({ ffmpegPath: '', ytDlpPath: '' })
+  const [proxyDraft, setProxyDraft] = useState('')
+  const [connectivity, setConnectivity] = useState<ConnectivityReport | null>(null)
+  const [isTestingConnection, setIsTestingConnection] = useState(false)
   const [isResetting, setIsResetting] = useState(false)
 
+  const fetchBinaryInfo = useCallback(async () => {
+    const [ffmpegResponse, ytDlpResponse] = await Promise.all([
+      window.electronAPI.system.getFfmpegVersion(),
+      window.electronAPI.system.getYtdlpVersion(),
+    ])
+    setBinaryInfo({
+      ffmpeg: isSuccessResponse(ffmpegResponse) ? ffmpegResponse.data : null,
+      ytDlp: isSuccessResponse(ytDlpResponse) ? ytDlpResponse.data : null,
+    })
+  }, [])
+
   useEffect(() => {
     async function fetchConfig() {
       try {
         const response = await window.electronAPI.config.get()
         if (isSuccessResponse(response)) {
           const appConfig = response.data as AppConfig
-          setConfig(appConfig.advanced || defaultAdvancedConfig)
+          const advanced = appConfig.advanced || defaultAdvancedConfig
+          setConfig(advanced)
+          setPathDrafts({ ffmpegPath: advanced.ffmpegPath ?? '', ytDlpPath: advanced.ytDlpPath ?? '' })
+          setProxyDraft(advanced.proxyUrl ?? '')
         }
 
-        // For now, show placeholder binary info
-        // In a real implementation, you'd fetch this from the main process
-        setBinaryInfo({
-          ffmpeg: { version: '6.1', path: 'Bundled' },
-          ytDlp: { version: '2024.01.30', path: 'Bundled' },
-        })
+        await fetchBinaryInfo()
       } catch (error) {
         console.error('Failed to fetch config:', error)
       } finally {
@@ -66,7 +112,7 @@ export default function AdvancedSettings() {
       }
     }
     fetchConfig()
-  }, [])
+  }, [fetchBinaryInfo])
 
   const handleUpdate = useCallback(
     (updates: Partial<AdvancedConfig>) => {
@@ -82,6 +128,75 @@ export default function AdvancedSettings() {
     [config],
   )
 
+  const handleBinaryPathCommit = async (setting: BinaryPathSetting, type: BinaryType) => {
+    const value = pathDrafts[setting].trim()
+    if (value === (config[setting] ?? '')) return
+
+    try {
+      if (value) {
+        const response = await window.electronAPI.system.validateBinaryPath(value, type)
+        if (!isSuccessResponse(response)) {
+          toast.error(t('settingsInvalidBinaryPath', { error: response.error }))
+          setPathDrafts(drafts => ({ ...drafts, [setting]: config[setting] ?? '' }))
+          return
+        }
+        toast.success(t('settingsBinaryPathSaved', { version: response.data }))
+      }
+
+      const newConfig = { ...config, [setting]: value }
+      setConfig(newConfig)
+      await window.electronAPI.config.update({ advanced: newConfig } as any)
+      await fetchBinaryInfo()
+    } catch (error) {
+      console.error('Failed to update binary path:', error)
+    }
+  }
+
+  const handleProxyCommit = async () => {
+    const value = proxyDraft.trim()
+    if (value === (config.proxyUrl ?? '')) return
+
+    try {
+      const newConfig = { ...config, proxyUrl: value }
+      const response = await window.electronAPI.config.update({ advanced: newConfig } as any)
+      if (!isSuccessResponse(response)) {
+        toast.error(t('settingsInvalidProxyUrl', { error: response.error }))
+        setProxyDraft(config.proxyUrl ?? '')
+        return
+      }
+      setConfig(newConfig)
+    } catch (error) {
+      console.error('Failed to update proxy:', error)
+    }
+  }
+
+  const handleTestConnection = async () => {
+    setIsTestingConnection(true)
+    try {
+      const response = await window.electronAPI.system.testConnectivity()
+      if (isSuccessResponse(response)) {
+        setConnectivity(response.data)
+      } else {
+        toast.error(t('settingsConnectivityFailed', { error: response.error }))
+      }
+    } catch (error) {
+      console.error('Failed to test connection:', error)
+    } finally {
+      setIsTestingConnection(false)
+    }
+  }
+
+  const handleOpenFolder = async (open: () => Promise<ApiResponse<void>>) => {
+    try {
+      const response = await open()
+      if (!isSuccessResponse(response)) {
+        toast.error(t('settingsOpenFolderFailed', { error: response.error }))
+      }
+    } catch (error) {
+      console.error('Failed to open folder:', error)
+    }
+  }
+
   const handleResetAll = async () => {
     setIsResetting(true)
     try {
@@ -152,6 +267,82 @@ export default function AdvancedSettings() {
               </div>
             </div>
           </div>
+
+          {BINARY_PATH_SETTINGS.map(({ setting, type, labelKey }) => (
+            <div key={setting} className="flex items-center justify-between gap-4 py-2">
+              <div>
+                <p className="text-foreground text-sm font-medium">{t(labelKey)}</p>
+                <p className="text-muted-foreground text-xs">{t('settingsCustomBinaryPathDesc')}</p>
+              </div>
+              <Input
+                value={pathDrafts[setting]}
+                placeholder={t('settingsBundledBinary')}
+                className="w-72 font-mono text-xs"
+                onChange={e => setPathDrafts(drafts => ({ ...drafts, [setting]: e.target.value }))}
+                onBlur={() => handleBinaryPathCommit(setting, type)}
+                onKeyDown={e => e.key === 'Enter' && e.currentTarget.blur()}
+                disabled={isLoading}
+              />
+            </div>
+          ))}
+
+          <div className="flex items-center justify-between gap-4 py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsProxyUrl')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsProxyUrlDesc')}</p>
+            </div>
+            <Input
+              value={proxyDraft}
+              placeholder="socks5://127.0.0.1:1080"
+              className="w-72 font-mono text-xs"
+              onChange={e => setProxyDraft(e.target.value)}
+              onBlur={handleProxyCommit}
+              onKeyDown={e => e.key === 'Enter' && e.currentTarget.blur()}
+              disabled={isLoading}
+            />
+          </div>
+
+          <div className="space-y-2 py-2">
+            <div className="flex items-center justify-between gap-4">
+              <div>
+                <p className="text-foreground text-sm font-medium">{t('settingsTestConnection')}</p>
+                <p className="text-muted-foreground text-xs">{t('settingsTestConnectionDesc')}</p>
+              </div>
+              <Button variant="outline" size="sm" onClick={handleTestConnection} disabled={isTestingConnection}>
+                <Wifi className="mr-2 h-4 w-4" />
+                {isTestingConnection ? t('loading') : t('settingsTestConnection')}
+              </Button>
+            </div>
+            {connectivity && (
+              <div className="flex flex-wrap gap-2">
+                {CONNECTIVITY_CHECKS.filter(check => connectivity[check.okField] !== null).map(check => (
+                  <Badge
+                    key={check.key}
+                    variant={connectivity[check.okField] ? 'secondary' : 'destructive'}
+                    title={connectivity.errors[check.key]}
+                    className="text-xs"
+                  >
+                    {t(check.labelKey)}:{' '}
+                    {connectivity[check.okField]
+                      ? `${connectivity.latencyMs[check.key]} ms`
+                      : t('settingsConnectivityUnreachable')}
+                  </Badge>
+                ))}
+              </div>
+            )}
+          </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsRespectYtdlpConfig')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsRespectYtdlpConfigDesc')}</p>
+            </div>
+            <Switch
+              checked={config.respectUserYtdlpConfig ?? false}
+              onCheckedChange={checked => handleUpdate({ respectUserYtdlpConfig: checked })}
+              disabled={isLoading}
+            />
+          </div>
         </div>
 
         {/* Debug Options */}
@@ -168,6 +359,113 @@ export default function AdvancedSettings() {
               disabled={isLoading}
             />
           </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsMaxLogFileSize')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsMaxLogFileSizeDesc')}</p>
+            </div>
+            <Select
+              value={String(config.maxLogFileSizeMb ?? 10)}
+              onValueChange={value => handleUpdate({ maxLogFileSizeMb: Number(value) })}
+              disabled={isLoading}
+            >
+              <SelectTrigger className="w-32">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {[5, 10, 25, 50, 100].map(size => (
+                  <SelectItem key={size} value={String(size)}>
+                    {size} MB
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsMaxLogFiles')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsMaxLogFilesDesc')}</p>
+            </div>
+            <Select
+              value={String(config.maxLogFiles ?? 10)}
+              onValueChange={value => handleUpdate({ maxLogFiles: Number(value) })}
+              disabled={isLoading}
+            >
+              <SelectTrigger className="w-32">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {[3, 5, 10, 20, 50].map(count => (
+                  <SelectItem key={count} value={String(count)}>
+                    {count}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsThumbnailCacheDays')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsThumbnailCacheDaysDesc')}</p>
+            </div>
+            <Select
+              value={String(config.thumbnailCacheDays ?? 30)}
+              onValueChange={value => handleUpdate({ thumbnailCacheDays: Number(value) })}
+              disabled={isLoading}
+            >
+              <SelectTrigger className="w-32">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {[7, 14, 30, 90, 365].map(days => (
+                  <SelectItem key={days} value={String(days)}>
+                    {t('settingsDayCount', { count: days })}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsMaxMemory')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsMaxMemoryDesc')}</p>
+            </div>
+            <Select
+              value={String(config.maxMemoryMb ?? 0)}
+              onValueChange={value => handleUpdate({ maxMemoryMb: Number(value) })}
+              disabled={isLoading}
+            >
+              <SelectTrigger className="w-32">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {MEMORY_LIMITS_MB.map(limit => (
+                  <SelectItem key={limit} value={String(limit)}>
+                    {limit === 0 ? t('settingsUnlimited') : `${limit / 1024} GB`}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex items-center justify-between gap-4 py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsAppFolders')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsAppFoldersDesc')}</p>
+            </div>
+            <div className="flex gap-2">
+              {APP_FOLDERS.map(folder => (
+                <Button key={folder.labelKey} variant="outline" size="sm" onClick={() => handleOpenFolder(folder.open)}>
+                  <FolderOpen className="mr-2 h-4 w-4" />
+                  {t(folder.labelKey)}
+                </Button>
+              ))}
+            </div>
+          </div>
         </div>
 
         {/* Danger Zone */}
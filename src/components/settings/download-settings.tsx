@@ -3,7 +3,14 @@
  * Clean, minimal design with quality, format, path, and performance settings.
  */
 
-import type { AppConfig, DownloadConfig } from '@/types/system'
+import type {
+  AppConfig,
+  BrowserInfo,
+  DownloadConfig,
+  FinalContainer,
+  QualityFallbackMode,
+  SponsorBlockMode,
+} from '@/types/system'
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card'
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select'
 import { useCallback, useEffect, useRef, useState } from 'react'
@@ -16,12 +23,32 @@ import { Skeleton } from '@/components/ui/skeleton'
 import { Slider } from '@/components/ui/slider'
 import { Switch } from '@/components/ui/switch'
 import { isSuccessResponse } from '@/types/api'
+import { toast } from 'sonner'
 import { useTranslation } from 'react-i18next'
 
+/** SponsorBlock categories with their label keys; the last two can only be marked, not cut out */
+const SPONSORBLOCK_CATEGORIES = [
+  { id: 'sponsor', labelKey: 'sponsorBlockCategorySponsor' },
+  { id: 'intro', labelKey: 'sponsorBlockCategoryIntro' },
+  { id: 'outro', labelKey: 'sponsorBlockCategoryOutro' },
+  { id: 'selfpromo', labelKey: 'sponsorBlockCategorySelfpromo' },
+  { id: 'preview', labelKey: 'sponsorBlockCategoryPreview' },
+  { id: 'filler', labelKey: 'sponsorBlockCategoryFiller' },
+  { id: 'interaction', labelKey: 'sponsorBlockCategoryInteraction' },
+  { id: 'music_offtopic', labelKey: 'sponsorBlockCategoryMusicOfftopic' },
+  { id: 'poi_highlight', labelKey: 'sponsorBlockCategoryPoiHighlight', markOnly: true },
+  { id: 'chapter', labelKey: 'sponsorBlockCategoryChapter', markOnly: true },
+]
+
+function toggleCategory(categories: string[], id: string): string[] {
+  return categories.includes(id) ? categories.filter(category => category !== id) : [...categories, id]
+}
+
 export default function DownloadSettings() {
   const { t } = useTranslation()
   const [config, setConfig] = useState<DownloadConfig | null>(null)
   const [isLoading, setIsLoading] = useState(true)
+  const [browsers, setBrowsers] = useState<BrowserInfo[]>([])
 
   useEffect(() => {
     async function fetchConfig() {
@@ -36,7 +63,18 @@ export default function DownloadSettings() {
         setIsLoading(false)
       }
     }
+    async function fetchBrowsers() {
+      try {
+        const response = await window.electronAPI.system.getAvailableBrowsers()
+        if (isSuccessResponse(response)) {
+          setBrowsers(response.data)
+        }
+      } catch (error) {
+        console.error('Failed to detect browsers:', error)
+      }
+    }
     fetchConfig()
+    fetchBrowsers()
   }, [])
 
   const debouncedUpdateRef = useRef<NodeJS.Timeout | null>(null)
@@ -84,7 +122,13 @@ export default function DownloadSettings() {
         properties: ['openDirectory', 'createDirectory'],
       })
       if (isSuccessResponse(response) && response.data) {
-        handleUpdate({ downloadPath: response.data as string })
+        const downloadPath = response.data as string
+        const check = await window.electronAPI.downloadManager.checkPathWritable(downloadPath)
+        if (isSuccessResponse(check) && !check.data.writable) {
+          toast.error(t('settingsDownloadFolderNotWritable'), { description: check.data.error })
+          return
+        }
+        handleUpdate({ downloadPath })
       }
     } catch (error) {
       console.error('Failed to open dialog:', error)
@@ -105,6 +149,10 @@ export default function DownloadSettings() {
     )
   }
 
+  const sponsorBlockMode = config.sponsorBlockMode ?? 'off'
+  const removeCategories = config.sponsorBlockRemoveCategories ?? []
+  const markCategories = config.sponsorBlockMarkCategories ?? []
+
   return (
     <Card className="border-border/50 bg-card/50 backdrop-blur-sm">
       <CardHeader>
@@ -229,6 +277,29 @@ export default function DownloadSettings() {
                 onCheckedChange={checked => handleUpdate({ saveMetadata: checked })}
               />
             </div>
+
+            <div className="flex items-center justify-between gap-4 py-2">
+              <div>
+                <p className="text-foreground text-sm font-medium">{t('settingsCookiesFromBrowser')}</p>
+                <p className="text-muted-foreground text-xs">{t('settingsCookiesFromBrowserDesc')}</p>
+              </div>
+              <Select
+                value={config.cookiesFromBrowser || 'none'}
+                onValueChange={value => handleUpdate({ cookiesFromBrowser: value === 'none' ? '' : value })}
+              >
+                <SelectTrigger className="w-48">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="none">{t('settingsCookiesNone')}</SelectItem>
+                  {browsers.map(browser => (
+                    <SelectItem key={browser.name} value={browser.name}>
+                      {browser.displayName}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
           </div>
         </div>
 
@@ -258,6 +329,140 @@ export default function DownloadSettings() {
             </div>
           </div>
 
+          <div className="flex items-center justify-between gap-4 py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsMaxPerHost')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsMaxPerHostDesc')}</p>
+            </div>
+            <Select
+              value={String(config.maxConcurrentPerHost ?? 0)}
+              onValueChange={value => handleUpdate({ maxConcurrentPerHost: Number(value) })}
+            >
+              <SelectTrigger className="w-32">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="0">{t('settingsUnlimited')}</SelectItem>
+                {[1, 2, 3, 4].map(limit => (
+                  <SelectItem key={limit} value={String(limit)}>
+                    {limit}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex items-center justify-between gap-4 py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsQualityFallback')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsQualityFallbackDesc')}</p>
+            </div>
+            <Select
+              value={config.qualityFallback ?? 'closest'}
+              onValueChange={value => handleUpdate({ qualityFallback: value as QualityFallbackMode })}
+            >
+              <SelectTrigger className="w-32">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="closest">{t('settingsQualityFallbackClosest')}</SelectItem>
+                <SelectItem value="fail">{t('settingsQualityFallbackFail')}</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex items-center justify-between gap-4 py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsFinalContainer')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsFinalContainerDesc')}</p>
+            </div>
+            <Select
+              value={config.finalContainer ?? 'keep'}
+              onValueChange={value => handleUpdate({ finalContainer: value as FinalContainer })}
+            >
+              <SelectTrigger className="w-32">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="keep">{t('settingsFinalContainerKeep')}</SelectItem>
+                {['mp4', 'mkv', 'webm', 'mov'].map(container => (
+                  <SelectItem key={container} value={container}>
+                    {container.toUpperCase()}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="space-y-3 py-2">
+            <div className="flex items-center justify-between gap-4">
+              <div>
+                <p className="text-foreground text-sm font-medium">{t('settingsSponsorBlock')}</p>
+                <p className="text-muted-foreground text-xs">{t('settingsSponsorBlockDesc')}</p>
+              </div>
+              <Select
+                value={sponsorBlockMode}
+                onValueChange={value => handleUpdate({ sponsorBlockMode: value as SponsorBlockMode })}
+              >
+                <SelectTrigger className="w-40">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="off">{t('sponsorBlockOff')}</SelectItem>
+                  <SelectItem value="remove">{t('sponsorBlockRemove')}</SelectItem>
+                  <SelectItem value="mark">{t('sponsorBlockMark')}</SelectItem>
+                  <SelectItem value="mark-and-remove">{t('sponsorBlockMarkAndRemove')}</SelectItem>
+                </SelectContent>
+              </Select>
+            </div>
+
+            {(sponsorBlockMode === 'remove' || sponsorBlockMode === 'mark-and-remove') && (
+              <div className="space-y-1.5">
+                <p className="text-muted-foreground text-xs">{t('settingsSponsorBlockRemoveCategories')}</p>
+                <div className="flex flex-wrap gap-1.5">
+                  {SPONSORBLOCK_CATEGORIES.filter(category => !category.markOnly).map(category => {
+                    const selected = removeCategories.includes(category.id)
+                    return (
+                      <Badge
+                        key={category.id}
+                        variant={selected ? 'default' : 'outline'}
+                        className="cursor-pointer"
+                        onClick={() =>
+                          handleUpdate({ sponsorBlockRemoveCategories: toggleCategory(removeCategories, category.id) })
+                        }
+                      >
+                        {t(category.labelKey)}
+                      </Badge>
+                    )
+                  })}
+                </div>
+              </div>
+            )}
+
+            {(sponsorBlockMode === 'mark' || sponsorBlockMode === 'mark-and-remove') && (
+              <div className="space-y-1.5">
+                <p className="text-muted-foreground text-xs">{t('settingsSponsorBlockMarkCategories')}</p>
+                <div className="flex flex-wrap gap-1.5">
+                  {SPONSORBLOCK_CATEGORIES.map(category => {
+                    const selected = markCategories.includes(category.id)
+                    return (
+                      <Badge
+                        key={category.id}
+                        variant={selected ? 'default' : 'outline'}
+                        className="cursor-pointer"
+                        onClick={() =>
+                          handleUpdate({ sponsorBlockMarkCategories: toggleCategory(markCategories, category.id) })
+                        }
+                      >
+                        {t(category.labelKey)}
+                      </Badge>
+                    )
+                  })}
+                </div>
+              </div>
+            )}
+          </div>
+
           <div className="flex items-center justify-between py-2">
             <div>
               <p className="text-foreground text-sm font-medium">{t('settingsAutoRetryFailed')}</p>
@@ -268,6 +473,39 @@ export default function DownloadSettings() {
               onCheckedChange={checked => handleUpdate({ autoRetryFailed: checked })}
             />
           </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsPermanentlyDelete')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsPermanentlyDeleteDesc')}</p>
+            </div>
+            <Switch
+              checked={config.permanentlyDeleteFiles ?? false}
+              onCheckedChange={checked => handleUpdate({ permanentlyDeleteFiles: checked })}
+            />
+          </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsWatchDownloadFolder')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsWatchDownloadFolderDesc')}</p>
+            </div>
+            <Switch
+              checked={config.watchDownloadFolder ?? false}
+              onCheckedChange={checked => handleUpdate({ watchDownloadFolder: checked })}
+            />
+          </div>
+
+          <div className="flex items-center justify-between py-2">
+            <div>
+              <p className="text-foreground text-sm font-medium">{t('settingsUseTempDir')}</p>
+              <p className="text-muted-foreground text-xs">{t('settingsUseTempDirDesc')}</p>
+            </div>
+            <Switch
+              checked={config.useTempDirForDownloads ?? true}
+              onCheckedChange={checked => handleUpdate({ useTempDirForDownloads: checked })}
+            />
+          </div>
         </div>
       </CardContent>
     </Card>
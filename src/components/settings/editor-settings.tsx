@@ -19,8 +19,16 @@ const defaultEditorConfig: EditorConfig = {
   defaultQuality: 'high',
   preferFastTrim: true,
   defaultAudioFormat: 'mp3',
+  processPriority: 'normal',
+  threadsLimit: 0,
+  previewCacheMb: 64,
+  maxAnalysisJobs: 0,
 }
 
+const THREAD_LIMITS = [0, 1, 2, 4, 8, 16]
+const PREVIEW_CACHE_SIZES = [16, 32, 64, 128, 256]
+const ANALYSIS_JOB_LIMITS = [0, 1, 2, 4, 8]
+
 export default function EditorSettings() {
   const { t } = useTranslation()
   const [config, setConfig] = useState<EditorConfig>(defaultEditorConfig)
@@ -170,6 +178,106 @@ export default function EditorSettings() {
             />
           </div>
         </div>
+
+        {/* Performance */}
+        <div className="space-y-4">
+          <h3 className="text-foreground font-medium">{t('settingsProcessingPerformance')}</h3>
+          <div className="grid gap-4 sm:grid-cols-2">
+            <div className="space-y-2">
+              <Label className="text-sm">{t('settingsProcessPriority')}</Label>
+              {isLoading ? (
+                <Skeleton className="h-10 w-full" />
+              ) : (
+                <Select
+                  value={config.processPriority ?? 'normal'}
+                  onValueChange={value => handleUpdate({ processPriority: value as EditorConfig['processPriority'] })}
+                >
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="normal">{t('priorityNormal')}</SelectItem>
+                    <SelectItem value="below-normal">{t('priorityBelowNormal')}</SelectItem>
+                    <SelectItem value="idle">{t('priorityIdle')}</SelectItem>
+                  </SelectContent>
+                </Select>
+              )}
+              <p className="text-muted-foreground text-xs">{t('settingsProcessPriorityDesc')}</p>
+            </div>
+
+            <div className="space-y-2">
+              <Label className="text-sm">{t('settingsThreadsLimit')}</Label>
+              {isLoading ? (
+                <Skeleton className="h-10 w-full" />
+              ) : (
+                <Select
+                  value={String(config.threadsLimit ?? 0)}
+                  onValueChange={value => handleUpdate({ threadsLimit: Number(value) })}
+                >
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {THREAD_LIMITS.map(limit => (
+                      <SelectItem key={limit} value={String(limit)}>
+                        {limit === 0 ? t('threadsAutomatic') : limit}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              )}
+              <p className="text-muted-foreground text-xs">{t('settingsThreadsLimitDesc')}</p>
+            </div>
+
+            <div className="space-y-2">
+              <Label className="text-sm">{t('settingsMaxAnalysisJobs')}</Label>
+              {isLoading ? (
+                <Skeleton className="h-10 w-full" />
+              ) : (
+                <Select
+                  value={String(config.maxAnalysisJobs ?? 0)}
+                  onValueChange={value => handleUpdate({ maxAnalysisJobs: Number(value) })}
+                >
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {ANALYSIS_JOB_LIMITS.map(limit => (
+                      <SelectItem key={limit} value={String(limit)}>
+                        {limit === 0 ? t('threadsAutomatic') : limit}
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              )}
+              <p className="text-muted-foreground text-xs">{t('settingsMaxAnalysisJobsDesc')}</p>
+            </div>
+
+            <div className="space-y-2">
+              <Label className="text-sm">{t('settingsPreviewCache')}</Label>
+              {isLoading ? (
+                <Skeleton className="h-10 w-full" />
+              ) : (
+                <Select
+                  value={String(config.previewCacheMb ?? 64)}
+                  onValueChange={value => handleUpdate({ previewCacheMb: Number(value) })}
+                >
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {PREVIEW_CACHE_SIZES.map(size => (
+                      <SelectItem key={size} value={String(size)}>
+                        {size} MB
+                      </SelectItem>
+                    ))}
+                  </SelectContent>
+                </Select>
+              )}
+              <p className="text-muted-foreground text-xs">{t('settingsPreviewCacheDesc')}</p>
+            </div>
+          </div>
+        </div>
       </CardContent>
     </Card>
   )
@@ -14,6 +14,23 @@ export const ALLOWED_BROADCAST_CHANNELS = [
   'download-failed',
   'download-deleted',
   'theme-changed',
+  'editor-export-progress',
+  'batch-enqueue-progress',
+  'batch-export-progress',
+  'editor-scene-analysis-progress',
+  'editor-analysis-job',
+  'editor-transcode-progress',
+  'backup-progress',
+  'settings-changed',
+  'ffmpeg-install-progress',
+  'library-video-added',
+  'library-video-updated',
+  'library-bulk-progress',
+  'library-conversion-progress',
+  'queue-completed',
+  'queue-completion-cancelled',
+  'shutdown-in-progress',
+  'high-memory-warning',
 ] as const
 
 export type AllowedBroadcastChannel = (typeof ALLOWED_BROADCAST_CHANNELS)[number]
@@ -35,16 +52,48 @@ export const IPC_CHANNELS = {
   SHELL_OPEN_PATH: 'shell:open-path',
   SHELL_SHOW_ITEM_IN_FOLDER: 'shell:show-item-in-folder',
   SHELL_OPEN_EXTERNAL: 'shell:open-external',
+  SHELL_OPEN_APP_DATA_FOLDER: 'shell:open-app-data-folder',
+  SHELL_OPEN_CACHE_FOLDER: 'shell:open-cache-folder',
+  SHELL_OPEN_LOGS_FOLDER: 'shell:open-logs-folder',
 
   // Download Operations
   DOWNLOAD_START: 'download:start',
+  DOWNLOAD_START_WITH_PROFILE: 'download:start-with-profile', // Profile options for this download only
+  DOWNLOAD_GET_PROFILES: 'download:get-profiles',
+  DOWNLOAD_SAVE_PROFILE: 'download:save-profile',
+  DOWNLOAD_DELETE_PROFILE: 'download:delete-profile',
   DOWNLOAD_CANCEL: 'download:cancel',
+  DOWNLOAD_CANCEL_ALL: 'download:cancel-all', // Every queued and running download; paused ones stay
   DOWNLOAD_DELETE: 'download:delete',
+  DOWNLOAD_BULK_DELETE: 'download:bulk-delete',
   DOWNLOAD_RETRY: 'download:retry',
+  DOWNLOAD_PAUSE: 'download:pause',
+  DOWNLOAD_RESUME: 'download:resume', // Also downloads paused in an earlier session
   DOWNLOAD_PROGRESS: 'download:progress',
   DOWNLOAD_LIST: 'download:list',
+  DOWNLOAD_GET_BY_ID: 'download:get-by-id', // Point lookups, so cards can poll one download cheaply
+  DOWNLOAD_GET_STATUS: 'download:get-status',
+  DOWNLOAD_SYNC_STATE: 'download:sync-state', // Full snapshot for a renderer that (re)attaches mid-download
+  DOWNLOAD_GET_QUEUE_ETA: 'download:get-queue-eta', // Seconds until the queue is done
+  DOWNLOAD_GET_AVERAGE_SPEED: 'download:get-average-speed', // Bytes/s across running downloads
   DOWNLOAD_INFO: 'download:info',
+  DOWNLOAD_FORMAT_DETAILS: 'download:format-details', // Dynamic range, audio channels and language of one format
   DOWNLOAD_STREAMING_INFO: 'download:streaming-info', // Get video info with streaming URL for editor preview
+  DOWNLOAD_STORYBOARD: 'download:storyboard', // Cache storyboard sprite sheets for hover previews
+  DOWNLOAD_ENQUEUE_FILE: 'download:enqueue-file', // Queue every URL in a list file
+  DOWNLOAD_ENQUEUE_URLS: 'download:enqueue-urls', // Queue pasted URLs
+  DOWNLOAD_GET_LOG: 'download:get-log',
+  DOWNLOAD_OPEN_LOG: 'download:open-log',
+  DOWNLOAD_TAG: 'download:tag',
+  DOWNLOAD_IS_SITE_SUPPORTED: 'download:is-site-supported',
+  DOWNLOAD_GET_COMMENTS: 'download:get-comments', // Comment threads, fetched without downloading the video
+  DOWNLOAD_THUMBNAIL: 'download:thumbnail', // Just the thumbnail, without the video
+  DOWNLOAD_GET_RELATED_VIDEOS: 'download:get-related-videos', // From the (cached) video info
+  DOWNLOAD_CHECK_PATH_WRITABLE: 'download:check-path-writable', // Validate a download folder from settings
+  DOWNLOAD_PREVIEW_FILENAME: 'download:preview-filename', // Where a filename template would save a video
+  DOWNLOAD_GET_COMPLETION_ACTION: 'download:get-completion-action',
+  DOWNLOAD_SET_COMPLETION_ACTION: 'download:set-completion-action', // Sleep/shut down/quit when the queue empties
+  DOWNLOAD_CANCEL_COMPLETION_ACTION: 'download:cancel-completion-action',
 
   // File Operations
   FILE_EXISTS: 'file:exists',
@@ -52,6 +101,33 @@ export const IPC_CHANNELS = {
   FILE_WRITE: 'file:write',
   FILE_DELETE: 'file:delete',
 
+  // Library Operations
+  LIBRARY_GET_FORMATS: 'library:get-formats',
+  LIBRARY_GET_BY_FORMAT: 'library:get-by-format',
+  LIBRARY_SEARCH: 'library:search', // Best match first; the last word matches as a prefix
+  LIBRARY_GET_CHANNELS: 'library:get-channels',
+  LIBRARY_GET_BY_CHANNEL: 'library:get-by-channel', // Grouped by channel ID, so renames keep one group
+  LIBRARY_CHECK_ALREADY_DOWNLOADED: 'library:check-already-downloaded',
+  LIBRARY_EXPORT_CSV: 'library:export-csv',
+  LIBRARY_EXPORT_CSV_TO_FILE: 'library:export-csv-to-file',
+  LIBRARY_GENERATE_REPORT: 'library:generate-report', // Standalone HTML overview, opened once written
+  LIBRARY_SAVE_SEARCH_PLAYLIST: 'library:save-search-playlist', // Search results as an .m3u playlist
+  LIBRARY_LIST_PLAYLISTS: 'library:list-playlists',
+  LIBRARY_IMPORT_CSV: 'library:import-csv',
+  LIBRARY_DOWNLOAD_MISSING_THUMBNAILS: 'library:download-missing-thumbnails',
+  LIBRARY_START_WATCHER: 'library:start-watcher',
+  LIBRARY_STOP_WATCHER: 'library:stop-watcher',
+  LIBRARY_BULK_JOB_STATUS: 'library:bulk-job-status',
+  LIBRARY_BULK_JOB_CANCEL: 'library:bulk-job-cancel',
+  LIBRARY_REMUX_VIDEO: 'library:remux-video', // Copy into another container, added as a new entry
+  LIBRARY_EXTRACT_AUDIO: 'library:extract-audio',
+  LIBRARY_CONVERSION_STATUS: 'library:conversion-status',
+  LIBRARY_CONVERSION_CANCEL: 'library:conversion-cancel',
+  LIBRARY_SET_PLAYBACK_POSITION: 'library:set-playback-position',
+  LIBRARY_GET_PLAYBACK_POSITION: 'library:get-playback-position',
+  LIBRARY_MARK_WATCHED: 'library:mark-watched',
+  LIBRARY_GET_CONTINUE_WATCHING: 'library:get-continue-watching', // Started but unfinished, latest first
+
   // Storage Operations
   STORAGE_LOAD: 'storage:load',
   STORAGE_SAVE: 'storage:save',
@@ -61,11 +137,33 @@ export const IPC_CHANNELS = {
   CONFIG_GET: 'config:get',
   CONFIG_UPDATE: 'config:update',
   CONFIG_RESET: 'config:reset',
+  CONFIG_EXPORT: 'config:export',
+  CONFIG_IMPORT_PREVIEW: 'config:import-preview',
+  CONFIG_IMPORT: 'config:import',
 
   // System Information
   SYSTEM_INFO: 'system:info',
   SYSTEM_OPEN_DIALOG: 'system:open-dialog',
   SYSTEM_SAVE_DIALOG: 'system:save-dialog',
+  SYSTEM_AVAILABLE_BROWSERS: 'system:available-browsers',
+  SYSTEM_HEALTH_CHECK: 'system:health-check',
+  SYSTEM_TEST_CONNECTIVITY: 'system:test-connectivity', // Through the configured proxy
+  SYSTEM_RUN_DIAGNOSTICS: 'system:run-diagnostics', // Full report for bug reports
+  SYSTEM_MEMORY_USAGE: 'system:memory-usage', // Of the main process
+  SYSTEM_EXPORT_DIAGNOSTICS: 'system:export-diagnostics',
+  SYSTEM_PREFETCH_THUMBNAILS: 'system:prefetch-thumbnails',
+  SYSTEM_THUMBNAIL_CACHE_STATS: 'system:thumbnail-cache-stats',
+  SYSTEM_SUPPORTED_SITES: 'system:supported-sites',
+  SYSTEM_INSTALL_FFMPEG: 'system:install-ffmpeg',
+  SYSTEM_YTDLP_VERSION: 'system:ytdlp-version', // Cached per session; cheaper than a health check
+  SYSTEM_FFMPEG_VERSION: 'system:ffmpeg-version',
+  SYSTEM_INVALIDATE_VERSIONS: 'system:invalidate-versions', // Detect versions again, e.g. after an update
+  SYSTEM_VALIDATE_BINARY_PATH: 'system:validate-binary-path', // Version of a custom yt-dlp/FFmpeg path
+  SYSTEM_AVAILABLE_LANGUAGES: 'system:available-languages',
+
+  // Backup & Restore
+  BACKUP_CREATE: 'backup:create',
+  BACKUP_RESTORE: 'backup:restore',
 
   // Storage Management
   STORAGE_USAGE: 'storage:usage',
@@ -77,10 +175,67 @@ export const IPC_CHANNELS = {
   VIDEO_PREVIEW: 'video:preview',
   VIDEO_TRIM: 'video:trim',
   VIDEO_INFO: 'video:info',
+  VIDEO_DETECT_SCENES: 'video:detect-scenes',
 
   // Streaming Proxy
   PROXY_GET_URL: 'proxy:get-url', // Get proxy URL for a video stream
   PROXY_STATUS: 'proxy:status', // Check if proxy is running
+
+  // Editor Projects
+  EDITOR_PROJECT_CREATE: 'editor:project-create',
+  EDITOR_PROJECT_OPEN: 'editor:project-open',
+  EDITOR_PROJECT_SAVE: 'editor:project-save',
+  EDITOR_PROJECT_GET: 'editor:project-get',
+  EDITOR_PROJECT_CLOSE: 'editor:project-close',
+  EDITOR_GET_AUTOSAVES: 'editor:get-autosaves', // Autosaves left by projects that weren't saved or closed
+  EDITOR_DELETE_AUTOSAVE: 'editor:delete-autosave',
+
+  // Editor Undo/Redo
+  EDITOR_EXECUTE_COMMAND: 'editor:execute-command',
+  EDITOR_UNDO: 'editor:undo',
+  EDITOR_REDO: 'editor:redo',
+  EDITOR_UNDO_STACK_SIZE: 'editor:undo-stack-size',
+  EDITOR_PROJECT_DIFF: 'editor:project-diff', // Compact change list between two snapshots, for undo history
+  EDITOR_AUTO_SPLIT_CLIP: 'editor:auto-split-clip',
+  EDITOR_SPLIT_CLIP_AT_TIMES: 'editor:split-clip-at-times',
+  EDITOR_IMPORT_LIBRARY_CLIP: 'editor:import-library-clip',
+  EDITOR_ADD_AUDIO_CLIP: 'editor:add-audio-clip', // Audio-only files; checked with ffprobe
+  EDITOR_ANALYZE_SCENES: 'editor:analyze-scenes', // Scene cuts of a whole video, cached per file
+  EDITOR_SCENE_ANALYSIS_STATUS: 'editor:scene-analysis-status',
+  EDITOR_SCENE_ANALYSIS_CANCEL: 'editor:scene-analysis-cancel',
+  EDITOR_CANCEL_ANALYSIS_JOBS: 'editor:cancel-analysis-jobs', // Thumbnail, waveform and scene jobs of a project
+
+  // Editor Markers
+  EDITOR_MARKER_ADD: 'editor:marker-add',
+  EDITOR_MARKER_REMOVE: 'editor:marker-remove',
+  EDITOR_MARKER_UPDATE: 'editor:marker-update',
+  EDITOR_TRACK_MARKER_ADD: 'editor:track-marker-add',
+  EDITOR_TRACK_MARKER_REMOVE: 'editor:track-marker-remove',
+  EDITOR_TRACK_MARKER_UPDATE: 'editor:track-marker-update',
+  EDITOR_MARKERS_GET: 'editor:markers-get',
+
+  // Editor Export
+  EDITOR_EXPORT_PROJECT: 'editor:export-project',
+  EDITOR_CANCEL_EXPORT: 'editor:cancel-export', // Also cancels the rest of a batch export
+  EDITOR_BATCH_EXPORT: 'editor:batch-export', // Saved projects, one after the other
+  EDITOR_GET_EXPORT_REPORTS: 'editor:get-export-reports', // Of a project's last exports, newest first
+  EDITOR_SET_EXPORT_PRIORITY: 'editor:set-export-priority', // Renice the running export
+  EDITOR_GET_EXPORT_FORMATS: 'editor:get-export-formats',
+  EDITOR_GET_MEDIA_URL: 'editor:get-media-url', // Preview URL for sources the video element can't play
+  EDITOR_RENDER_TEXT_PREVIEW: 'editor:render-text-preview',
+  EDITOR_RENDER_PREVIEW_FRAME: 'editor:render-preview-frame', // Composited project frame at a timeline time
+  EDITOR_CANCEL_PREVIEW_RENDER: 'editor:cancel-preview-render',
+  EDITOR_EXTRACT_FRAMES: 'editor:extract-frames', // PNG sequence of a time range, for external tools
+  EDITOR_EXTRACT_SINGLE_FRAME: 'editor:extract-single-frame',
+  EDITOR_GET_PREVIEW_FRAME: 'editor:get-preview-frame', // Scrubber frame as a JPEG data URL, cached in memory
+  EDITOR_TRANSCODE_FOR_EDITING: 'editor:transcode-for-editing', // Reuses an earlier transcode of the same source
+  EDITOR_DETECT_KEYFRAMES: 'editor:detect-keyframes', // Keyframe times in a range, for snapping trims to
+  EDITOR_GET_VIDEO_STREAMS: 'editor:get-video-streams', // Audio tracks and subtitles to pick from
+  EDITOR_REMUX_VIDEO: 'editor:remux-video', // Change container without re-encoding
+  EDITOR_MERGE_AUDIO: 'editor:merge-audio', // Replace or mix in an external audio file
+  EDITOR_CREATE_SLIDESHOW: 'editor:create-slideshow', // Still images to a video with crossfades
+  EDITOR_GET_AVAILABLE_EFFECTS: 'editor:get-available-effects',
+  EDITOR_APPLY_EFFECT_PREVIEW: 'editor:apply-effect-preview', // Short excerpt with one effect applied
 } as const
 
 export type IpcChannel = (typeof IPC_CHANNELS)[keyof typeof IPC_CHANNELS]
@@ -4,11 +4,83 @@
  */
 
 import { ALLOWED_BROADCAST_CHANNELS, IPC_CHANNELS } from './channels'
-import { AppConfig, StoragePaths, ThemeMode } from '@/types/system'
-import { DownloadFilter, DownloadListData, DownloadOptions, DownloadProgress, VideoInfo } from '@/types/download'
+import {
+  AppConfig,
+  BackupResult,
+  BinaryInstallResult,
+  BinaryType,
+  BinaryVersion,
+  BrowserInfo,
+  ConnectivityReport,
+  DiagnosticsReport,
+  HealthReport,
+  ProcessMemory,
+  ProcessPriority,
+  RestoreResult,
+  SettingsExport,
+  SettingsImportPreview,
+  StoragePaths,
+  ThemeMode,
+  ThumbnailCacheStats,
+} from '@/types/system'
+import {
+  AudioExtractFormat,
+  BatchEnqueueReport,
+  BulkJobProgress,
+  CookieChoice,
+  DownloadDeleteOptions,
+  DownloadFilter,
+  DownloadListData,
+  DownloadOptions,
+  DownloadProfile,
+  DownloadProgress,
+  DownloadStateSnapshot,
+  DownloadStatus,
+  FileRemovalResult,
+  LibraryChannelSummary,
+  LibraryFormatSummary,
+  LibraryConversionProgress,
+  LibraryImportResult,
+  OutputTemplatePreview,
+  PlaybackPosition,
+  PlaylistFile,
+  QueueCompletionAction,
+  RelatedVideo,
+  RemuxStreamSelection,
+  StoryboardDownload,
+  VideoComment,
+  VideoFormatInfo,
+  VideoInfo,
+} from '@/types/download'
 import { contextBridge, ipcRenderer } from 'electron'
 
 import { ApiResponse } from '@/types/api'
+import type { Language } from '@/localization/languages'
+import {
+  AudioMergeOptions,
+  AutosaveInfo,
+  Clip,
+  EditingTranscodeProfile,
+  EditingTranscodeResult,
+  Effect,
+  EffectDefinition,
+  ExportFormatInfo,
+  ExportJob,
+  ExportJobResult,
+  ExportReport,
+  MarkerWithClipId,
+  MediaUrlInfo,
+  Project,
+  ProjectCommand,
+  ProjectDiff,
+  ProjectExportResult,
+  ProjectExportSettings,
+  SceneAnalysisProgress,
+  SlideshowOptions,
+  TextProperties,
+  Track,
+  UndoRedoState,
+} from '@/types/project'
 
 // Video processing types
 interface TrimOptions {
@@ -26,11 +98,15 @@ interface ThumbnailOptions {
   count?: number
   interval?: number
   width?: number
+  projectId?: string // Cancelled with the project's other analysis jobs when it closes
+  priority?: 'high' | 'normal' | 'low' // 'high' for what is in view
 }
 
 interface WaveformOptions {
   inputPath: string
   samples?: number
+  projectId?: string
+  priority?: 'high' | 'normal' | 'low'
 }
 
 interface VideoMetadata {
@@ -43,6 +119,24 @@ interface VideoMetadata {
   fps: number
 }
 
+interface SceneChange {
+  time: number
+  score: number
+}
+
+interface StreamInfo {
+  index: number
+  streamType: string
+  codec: string
+  language?: string
+  title?: string
+  bitrate?: number
+  width?: number
+  height?: number
+  channels?: number
+  sampleRate?: number
+}
+
 // Define the secure API interface
 interface ElectronAPI {
   // Window operations
@@ -66,23 +160,70 @@ interface ElectronAPI {
     openPath: (filePath: string) => Promise<void>
     showItemInFolder: (filePath: string) => Promise<void>
     openExternal: (url: string) => Promise<void>
+    openAppDataFolder: () => Promise<void>
+    openCacheFolder: () => Promise<void>
+    openLogsFolder: () => Promise<void>
   }
 
   // Download operations
   downloadManager: {
     start: (url: string, options?: DownloadOptions) => Promise<{ downloadId: string; message: string }>
+    startWithProfile: (url: string, profileId: string) => Promise<{ downloadId: string; message: string }>
+    getProfiles: () => Promise<ApiResponse<DownloadProfile[]>>
+    saveProfile: (name: string, options: DownloadOptions, profileId?: string) => Promise<ApiResponse<DownloadProfile>>
+    deleteProfile: (profileId: string) => Promise<ApiResponse<{ deleted: boolean }>>
     cancel: (downloadId: string) => Promise<{ downloadId: string; message: string }>
-    delete: (downloadId: string) => Promise<{ downloadId: string; message: string }>
+    cancelAll: () => Promise<ApiResponse<{ downloadIds: string[] }>>
+    delete: (
+      downloadId: string,
+      options?: DownloadDeleteOptions,
+    ) => Promise<{ downloadId: string; message: string; files: FileRemovalResult[]; failedFiles?: FileRemovalResult[] }>
+    bulkDelete: (downloadIds: string[], options?: DownloadDeleteOptions) => Promise<ApiResponse<{ jobId: string }>>
     retry: (downloadId: string) => Promise<{ downloadId: string; message: string }>
+    pause: (downloadId: string) => Promise<ApiResponse<{ downloadId: string }>>
+    resume: (downloadId: string) => Promise<ApiResponse<{ downloadId: string }>>
     getProgress: (downloadId?: string) => Promise<DownloadProgress | DownloadProgress[]>
     list: (filter?: DownloadFilter) => Promise<DownloadListData>
-    getInfo: (url: string) => Promise<VideoInfo>
+    getInfo: (url: string, useCookies?: CookieChoice) => Promise<VideoInfo>
     getStreamingInfo: (url: string) => Promise<{
       videoInfo: VideoInfo
       streamingUrl: string | null
       audioUrl: string | null
       fallbackUrl: string | null
     }>
+    downloadStoryboard: (videoId: string, url: string) => Promise<ApiResponse<StoryboardDownload>>
+    enqueueFromFile: (
+      filePath: string,
+      options?: DownloadOptions,
+      prefetchInfo?: boolean,
+    ) => Promise<ApiResponse<BatchEnqueueReport>>
+    enqueueUrls: (
+      urls: string[],
+      options?: DownloadOptions,
+      prefetchInfo?: boolean,
+    ) => Promise<ApiResponse<BatchEnqueueReport>>
+    getLog: (downloadId: string, tailLines?: number) => Promise<ApiResponse<string[]>>
+    openLog: (downloadId: string) => Promise<ApiResponse<void>>
+    checkPathWritable: (dirPath: string) => Promise<ApiResponse<{ writable: boolean; error?: string }>>
+    isSiteSupported: (url: string) => Promise<ApiResponse<boolean>>
+    tag: (downloadId: string, tags: string[]) => Promise<ApiResponse<{ downloadId: string; tags: string[] }>>
+    getFormatDetails: (videoInfo: VideoInfo, formatId: string) => Promise<ApiResponse<VideoFormatInfo | null>>
+    getCompletionAction: () => Promise<ApiResponse<QueueCompletionAction>>
+    setCompletionAction: (action: QueueCompletionAction) => Promise<ApiResponse<void>>
+    cancelCompletionAction: () => Promise<ApiResponse<{ cancelled: boolean }>>
+    getById: (downloadId: string) => Promise<ApiResponse<DownloadProgress | null>>
+    getStatus: (downloadId: string) => Promise<ApiResponse<DownloadStatus | null>>
+    previewFilename: (
+      template: string,
+      videoInfo: VideoInfo,
+      options?: DownloadOptions,
+    ) => Promise<ApiResponse<OutputTemplatePreview>>
+    syncState: () => Promise<ApiResponse<DownloadStateSnapshot>>
+    getQueueEta: () => Promise<ApiResponse<number>>
+    getAverageSpeed: () => Promise<ApiResponse<number>>
+    getComments: (url: string, maxComments?: number) => Promise<ApiResponse<VideoComment[]>>
+    downloadThumbnail: (url: string, outputPath?: string) => Promise<ApiResponse<{ path: string }>>
+    getRelatedVideos: (url: string) => Promise<ApiResponse<RelatedVideo[]>>
   }
 
   // File operations
@@ -105,6 +246,9 @@ interface ElectronAPI {
     get: () => Promise<AppConfig>
     update: (updates: Partial<AppConfig>) => Promise<AppConfig>
     reset: () => Promise<AppConfig>
+    exportSettings: (outputPath: string, redactSecrets?: boolean) => Promise<SettingsExport>
+    previewImport: (filePath: string) => Promise<SettingsImportPreview>
+    importSettings: (filePath: string) => Promise<SettingsImportPreview & { config: AppConfig }>
   }
 
   // System operations
@@ -114,6 +258,23 @@ interface ElectronAPI {
     saveDialog: (options?: SaveDialogOptions) => Promise<Electron.SaveDialogReturnValue>
     getStorageUsage: () => Promise<StorageUsage>
     getStoragePaths: () => Promise<StoragePaths>
+    createBackup: (outputPath: string, includeThumbnails?: boolean) => Promise<ApiResponse<BackupResult>>
+    restoreBackup: (zipPath: string, includeThumbnails?: boolean) => Promise<ApiResponse<RestoreResult>>
+    getAvailableBrowsers: () => Promise<ApiResponse<BrowserInfo[]>>
+    runHealthCheck: () => Promise<ApiResponse<HealthReport>>
+    testConnectivity: () => Promise<ApiResponse<ConnectivityReport>>
+    prefetchThumbnails: () => Promise<ApiResponse<number>>
+    getSupportedSites: () => Promise<ApiResponse<string[]>>
+    installFfmpeg: () => Promise<ApiResponse<BinaryInstallResult>>
+    getAvailableLanguages: () => Promise<ApiResponse<Language[]>>
+    runDiagnostics: () => Promise<ApiResponse<DiagnosticsReport>>
+    getMemoryUsage: () => Promise<ApiResponse<ProcessMemory>>
+    exportDiagnostics: (outputPath: string) => Promise<ApiResponse<{ outputPath: string }>>
+    getThumbnailCacheStats: () => Promise<ApiResponse<ThumbnailCacheStats>>
+    getYtdlpVersion: () => Promise<ApiResponse<BinaryVersion | null>>
+    getFfmpegVersion: () => Promise<ApiResponse<BinaryVersion | null>>
+    invalidateVersions: () => Promise<ApiResponse<void>>
+    validateBinaryPath: (path: string, type: BinaryType) => Promise<ApiResponse<string>>
   }
 
   // Video processing operations
@@ -125,6 +286,7 @@ interface ElectronAPI {
       options: ThumbnailOptions,
     ) => Promise<ApiResponse<{ thumbnails: string[]; interval: number; duration: number }>>
     getWaveform: (options: WaveformOptions) => Promise<ApiResponse<{ waveform: number[]; samples: number }>>
+    detectSceneChanges: (filePath: string, threshold?: number) => Promise<ApiResponse<SceneChange[]>>
   }
 
   // Streaming proxy operations (for YouTube video preview)
@@ -133,6 +295,166 @@ interface ElectronAPI {
     getStatus: () => Promise<ApiResponse<{ running: boolean; port: number }>>
   }
 
+  // Editor project operations
+  editor: {
+    createProject: (name: string) => Promise<ApiResponse<Project>>
+    openProject: (filePath: string) => Promise<ApiResponse<Project>>
+    saveProject: (projectId: string, filePath?: string) => Promise<ApiResponse<Project>>
+    getProject: (projectId: string) => Promise<ApiResponse<Project>>
+    closeProject: (projectId: string) => Promise<ApiResponse<void>>
+    getAutosaves: () => Promise<ApiResponse<AutosaveInfo[]>>
+    deleteAutosave: (projectId: string) => Promise<ApiResponse<void>>
+    executeCommand: (projectId: string, command: ProjectCommand) => Promise<ApiResponse<Project>>
+    undo: (projectId: string) => Promise<ApiResponse<Project>>
+    redo: (projectId: string) => Promise<ApiResponse<Project>>
+    getUndoStackSize: (projectId: string) => Promise<ApiResponse<UndoRedoState>>
+    addMarker: (
+      projectId: string,
+      clipId: string,
+      time: number,
+      label: string,
+      color?: string,
+    ) => Promise<ApiResponse<Clip>>
+    removeMarker: (projectId: string, clipId: string, markerId: string) => Promise<ApiResponse<Clip>>
+    updateMarker: (
+      projectId: string,
+      clipId: string,
+      markerId: string,
+      label?: string,
+      color?: string,
+    ) => Promise<ApiResponse<Clip>>
+    addTrackMarker: (
+      projectId: string,
+      trackId: string,
+      time: number,
+      label: string,
+      color?: string,
+    ) => Promise<ApiResponse<Track>>
+    removeTrackMarker: (projectId: string, trackId: string, markerId: string) => Promise<ApiResponse<Track>>
+    updateTrackMarker: (
+      projectId: string,
+      trackId: string,
+      markerId: string,
+      label?: string,
+      color?: string,
+    ) => Promise<ApiResponse<Track>>
+    getMarkers: (projectId: string) => Promise<ApiResponse<MarkerWithClipId[]>>
+    exportProject: (projectId: string, settings: ProjectExportSettings) => Promise<ApiResponse<ProjectExportResult>>
+    cancelExport: () => Promise<ApiResponse<{ cancelled: boolean }>>
+    getExportReports: (projectId: string) => Promise<ApiResponse<ExportReport[]>>
+    batchExport: (jobs: ExportJob[]) => Promise<ApiResponse<ExportJobResult[]>>
+    renderPreviewFrame: (projectId: string, time: number, outputPath: string) => Promise<ApiResponse<void>>
+    cancelPreviewRender: () => Promise<ApiResponse<{ cancelled: boolean }>>
+    setExportPriority: (priority: ProcessPriority) => Promise<ApiResponse<{ changed: boolean }>>
+    autoSplitClip: (projectId: string, clipId: string, threshold?: number) => Promise<ApiResponse<Project>>
+    splitClipAtTimes: (projectId: string, clipId: string, times: number[]) => Promise<ApiResponse<Project>>
+    importLibraryClip: (
+      projectId: string,
+      libraryId: string,
+      trackId: string,
+      insertAtTime: number,
+    ) => Promise<ApiResponse<{ project: Project; clip: Clip }>>
+    addAudioClip: (
+      projectId: string,
+      trackId: string,
+      filePath: string,
+      startTime: number,
+      sourceStart?: number,
+      sourceEnd?: number,
+    ) => Promise<ApiResponse<{ project: Project; clip: Clip }>>
+    analyzeScenes: (
+      videoPath: string,
+      threshold?: number,
+      projectId?: string,
+    ) => Promise<ApiResponse<{ jobId: string }>>
+    getSceneAnalysisStatus: (jobId: string) => Promise<ApiResponse<SceneAnalysisProgress>>
+    cancelSceneAnalysis: (jobId: string) => Promise<ApiResponse<{ cancelled: boolean }>>
+    cancelAnalysisJobs: (projectId: string) => Promise<ApiResponse<{ cancelled: number }>>
+    getExportFormats: () => Promise<ApiResponse<ExportFormatInfo[]>>
+    getMediaUrl: (filePath: string) => Promise<ApiResponse<MediaUrlInfo>>
+    renderTextPreview: (
+      backgroundVideoPath: string,
+      timeOffset: number,
+      text: TextProperties,
+      outputPath?: string,
+    ) => Promise<ApiResponse<{ outputPath: string }>>
+    extractFrames: (
+      videoPath: string,
+      outputDir: string,
+      start: number,
+      end: number,
+      fps: number,
+    ) => Promise<ApiResponse<string[]>>
+    extractSingleFrame: (videoPath: string, time: number, outputPath: string) => Promise<ApiResponse<void>>
+    getPreviewFrame: (videoPath: string, time: number, maxWidth: number) => Promise<ApiResponse<string>>
+    detectKeyframes: (videoPath: string, start: number, end: number) => Promise<ApiResponse<number[]>>
+    transcodeForEditing: (
+      inputPath: string,
+      profile: EditingTranscodeProfile,
+      projectId?: string,
+    ) => Promise<ApiResponse<EditingTranscodeResult>>
+    getVideoStreams: (filePath: string) => Promise<ApiResponse<StreamInfo[]>>
+    remuxVideo: (inputPath: string, outputPath: string, streams?: RemuxStreamSelection) => Promise<ApiResponse<string>>
+    diffProjects: (before: Project, after: Project) => Promise<ApiResponse<ProjectDiff>>
+    mergeAudioToVideo: (
+      videoPath: string,
+      audioPath: string,
+      outputPath: string,
+      options?: AudioMergeOptions,
+    ) => Promise<ApiResponse<string>>
+    createSlideshow: (images: string[], outputPath: string, options?: SlideshowOptions) => Promise<ApiResponse<string>>
+    getAvailableEffects: () => Promise<ApiResponse<EffectDefinition[]>>
+    applyEffectPreview: (videoPath: string, effect: Effect, outputPath: string) => Promise<ApiResponse<void>>
+  }
+
+  // Library operations (completed downloads)
+  library: {
+    exportCsv: (videoIds?: string[]) => Promise<ApiResponse<string>>
+    exportCsvToFile: (
+      outputPath: string,
+      videoIds?: string[],
+    ) => Promise<ApiResponse<{ outputPath: string; bytesWritten: number }>>
+    generateReport: (outputPath: string) => Promise<ApiResponse<{ outputPath: string; bytesWritten: number }>>
+    saveSearchAsPlaylist: (
+      query: string,
+      name: string,
+      outputPath?: string,
+    ) => Promise<ApiResponse<{ outputPath: string; count: number }>>
+    listPlaylists: () => Promise<ApiResponse<PlaylistFile[]>>
+    importCsv: (csvPath: string, skipMissingFiles?: boolean) => Promise<ApiResponse<LibraryImportResult>>
+    downloadMissingThumbnails: () => Promise<ApiResponse<number>>
+    getFormats: () => Promise<ApiResponse<LibraryFormatSummary[]>>
+    getByFormat: (format: string) => Promise<ApiResponse<DownloadProgress[]>>
+    search: (query: string, limit?: number) => Promise<ApiResponse<DownloadProgress[]>>
+    getChannels: () => Promise<ApiResponse<LibraryChannelSummary[]>>
+    getByChannel: (channelId: string) => Promise<ApiResponse<DownloadProgress[]>>
+    startWatcher: (folderPath: string) => Promise<ApiResponse<void>>
+    stopWatcher: () => Promise<ApiResponse<void>>
+    getBulkJobStatus: (jobId: string) => Promise<ApiResponse<BulkJobProgress>>
+    cancelBulkJob: (jobId: string) => Promise<ApiResponse<{ cancelled: boolean }>>
+    checkAlreadyDownloaded: (url: string) => Promise<ApiResponse<DownloadProgress | null>>
+    remuxVideo: (
+      downloadId: string,
+      container: string,
+      streams?: RemuxStreamSelection,
+    ) => Promise<ApiResponse<{ jobId: string }>>
+    extractAudio: (
+      downloadId: string,
+      format: AudioExtractFormat,
+      bitrate?: number,
+    ) => Promise<ApiResponse<{ jobId: string }>>
+    getConversionStatus: (jobId: string) => Promise<ApiResponse<LibraryConversionProgress>>
+    cancelConversion: (jobId: string) => Promise<ApiResponse<{ cancelled: boolean }>>
+    setPlaybackPosition: (
+      downloadId: string,
+      seconds: number,
+      duration?: number,
+    ) => Promise<ApiResponse<PlaybackPosition>>
+    getPlaybackPosition: (downloadId: string) => Promise<ApiResponse<PlaybackPosition | null>>
+    markWatched: (downloadId: string, watched: boolean) => Promise<ApiResponse<PlaybackPosition>>
+    getContinueWatching: (limit?: number) => Promise<ApiResponse<DownloadProgress[]>>
+  }
+
   // Event listeners
   on: (channel: string, listener: (...args: any[]) => void) => void
   removeListener: (channel: string, listener: (...args: any[]) => void) => void
@@ -209,18 +531,64 @@ function createSecureAPI(): ElectronAPI {
       openPath: (filePath: string) => ipcRenderer.invoke(IPC_CHANNELS.SHELL_OPEN_PATH, filePath),
       showItemInFolder: (filePath: string) => ipcRenderer.invoke(IPC_CHANNELS.SHELL_SHOW_ITEM_IN_FOLDER, filePath),
       openExternal: (url: string) => ipcRenderer.invoke(IPC_CHANNELS.SHELL_OPEN_EXTERNAL, url),
+      openAppDataFolder: () => ipcRenderer.invoke(IPC_CHANNELS.SHELL_OPEN_APP_DATA_FOLDER),
+      openCacheFolder: () => ipcRenderer.invoke(IPC_CHANNELS.SHELL_OPEN_CACHE_FOLDER),
+      openLogsFolder: () => ipcRenderer.invoke(IPC_CHANNELS.SHELL_OPEN_LOGS_FOLDER),
     },
 
     // Download operations
     downloadManager: {
       start: (url: string, options?: DownloadOptions) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_START, url, options),
+      startWithProfile: (url: string, profileId: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_START_WITH_PROFILE, url, profileId),
+      getProfiles: () => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_PROFILES),
+      saveProfile: (name: string, options: DownloadOptions, profileId?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_SAVE_PROFILE, name, options, profileId),
+      deleteProfile: (profileId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_DELETE_PROFILE, profileId),
       cancel: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_CANCEL, downloadId),
-      delete: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_DELETE, downloadId),
+      cancelAll: () => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_CANCEL_ALL),
+      delete: (downloadId: string, options?: DownloadDeleteOptions) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_DELETE, downloadId, options),
+      bulkDelete: (downloadIds: string[], options?: DownloadDeleteOptions) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_BULK_DELETE, downloadIds, options),
       retry: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_RETRY, downloadId),
+      pause: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_PAUSE, downloadId),
+      resume: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_RESUME, downloadId),
       getProgress: (downloadId?: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_PROGRESS, downloadId),
       list: (filter?: DownloadFilter) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_LIST, filter),
-      getInfo: (url: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_INFO, url),
+      getInfo: (url: string, useCookies?: CookieChoice) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_INFO, url, useCookies),
       getStreamingInfo: (url: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_STREAMING_INFO, url),
+      downloadStoryboard: (videoId: string, url: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_STORYBOARD, videoId, url),
+      enqueueFromFile: (filePath: string, options?: DownloadOptions, prefetchInfo?: boolean) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_ENQUEUE_FILE, filePath, options, prefetchInfo),
+      enqueueUrls: (urls: string[], options?: DownloadOptions, prefetchInfo?: boolean) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_ENQUEUE_URLS, urls, options, prefetchInfo),
+      getLog: (downloadId: string, tailLines?: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_LOG, downloadId, tailLines),
+      openLog: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_OPEN_LOG, downloadId),
+      checkPathWritable: (dirPath: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_CHECK_PATH_WRITABLE, dirPath),
+      isSiteSupported: (url: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_IS_SITE_SUPPORTED, url),
+      tag: (downloadId: string, tags: string[]) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_TAG, downloadId, tags),
+      getFormatDetails: (videoInfo: VideoInfo, formatId: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_FORMAT_DETAILS, videoInfo, formatId),
+      getCompletionAction: () => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_COMPLETION_ACTION),
+      setCompletionAction: (action: QueueCompletionAction) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_SET_COMPLETION_ACTION, action),
+      cancelCompletionAction: () => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_CANCEL_COMPLETION_ACTION),
+      getById: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_BY_ID, downloadId),
+      getStatus: (downloadId: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_STATUS, downloadId),
+      previewFilename: (template: string, videoInfo: VideoInfo, options?: DownloadOptions) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_PREVIEW_FILENAME, template, videoInfo, options),
+      syncState: () => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_SYNC_STATE),
+      getQueueEta: () => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_QUEUE_ETA),
+      getAverageSpeed: () => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_AVERAGE_SPEED),
+      getComments: (url: string, maxComments?: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_COMMENTS, url, maxComments),
+      downloadThumbnail: (url: string, outputPath?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_THUMBNAIL, url, outputPath),
+      getRelatedVideos: (url: string) => ipcRenderer.invoke(IPC_CHANNELS.DOWNLOAD_GET_RELATED_VIDEOS, url),
     },
 
     // File operations
@@ -243,6 +611,10 @@ function createSecureAPI(): ElectronAPI {
       get: () => ipcRenderer.invoke(IPC_CHANNELS.CONFIG_GET),
       update: (updates: Partial<AppConfig>) => ipcRenderer.invoke(IPC_CHANNELS.CONFIG_UPDATE, updates),
       reset: () => ipcRenderer.invoke(IPC_CHANNELS.CONFIG_RESET),
+      exportSettings: (outputPath: string, redactSecrets?: boolean) =>
+        ipcRenderer.invoke(IPC_CHANNELS.CONFIG_EXPORT, outputPath, redactSecrets),
+      previewImport: (filePath: string) => ipcRenderer.invoke(IPC_CHANNELS.CONFIG_IMPORT_PREVIEW, filePath),
+      importSettings: (filePath: string) => ipcRenderer.invoke(IPC_CHANNELS.CONFIG_IMPORT, filePath),
     },
 
     // System operations
@@ -252,6 +624,26 @@ function createSecureAPI(): ElectronAPI {
       saveDialog: (options?: SaveDialogOptions) => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_SAVE_DIALOG, options),
       getStorageUsage: () => ipcRenderer.invoke(IPC_CHANNELS.STORAGE_USAGE),
       getStoragePaths: () => ipcRenderer.invoke(IPC_CHANNELS.STORAGE_PATHS),
+      createBackup: (outputPath: string, includeThumbnails?: boolean) =>
+        ipcRenderer.invoke(IPC_CHANNELS.BACKUP_CREATE, outputPath, includeThumbnails),
+      restoreBackup: (zipPath: string, includeThumbnails?: boolean) =>
+        ipcRenderer.invoke(IPC_CHANNELS.BACKUP_RESTORE, zipPath, includeThumbnails),
+      getAvailableBrowsers: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_AVAILABLE_BROWSERS),
+      runHealthCheck: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_HEALTH_CHECK),
+      testConnectivity: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_TEST_CONNECTIVITY),
+      prefetchThumbnails: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_PREFETCH_THUMBNAILS),
+      getSupportedSites: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_SUPPORTED_SITES),
+      installFfmpeg: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_INSTALL_FFMPEG),
+      getAvailableLanguages: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_AVAILABLE_LANGUAGES),
+      runDiagnostics: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_RUN_DIAGNOSTICS),
+      getMemoryUsage: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_MEMORY_USAGE),
+      exportDiagnostics: (outputPath: string) => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_EXPORT_DIAGNOSTICS, outputPath),
+      getThumbnailCacheStats: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_THUMBNAIL_CACHE_STATS),
+      getYtdlpVersion: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_YTDLP_VERSION),
+      getFfmpegVersion: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_FFMPEG_VERSION),
+      invalidateVersions: () => ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_INVALIDATE_VERSIONS),
+      validateBinaryPath: (path: string, type: BinaryType) =>
+        ipcRenderer.invoke(IPC_CHANNELS.SYSTEM_VALIDATE_BINARY_PATH, path, type),
     },
 
     // Video processing operations
@@ -262,6 +654,8 @@ function createSecureAPI(): ElectronAPI {
         ipcRenderer.invoke(IPC_CHANNELS.VIDEO_PREVIEW, inputPath, timePosition),
       getThumbnails: (options: ThumbnailOptions) => ipcRenderer.invoke('video:thumbnails', options),
       getWaveform: (options: WaveformOptions) => ipcRenderer.invoke('video:waveform', options),
+      detectSceneChanges: (filePath: string, threshold?: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.VIDEO_DETECT_SCENES, filePath, threshold),
     },
 
     // Streaming proxy operations (for YouTube video preview)
@@ -270,6 +664,138 @@ function createSecureAPI(): ElectronAPI {
       getStatus: () => ipcRenderer.invoke(IPC_CHANNELS.PROXY_STATUS),
     },
 
+    // Editor project operations
+    editor: {
+      createProject: (name: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_PROJECT_CREATE, name),
+      openProject: (filePath: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_PROJECT_OPEN, filePath),
+      saveProject: (projectId: string, filePath?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_PROJECT_SAVE, projectId, filePath),
+      getProject: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_PROJECT_GET, projectId),
+      closeProject: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_PROJECT_CLOSE, projectId),
+      getAutosaves: () => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_GET_AUTOSAVES),
+      deleteAutosave: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_DELETE_AUTOSAVE, projectId),
+      executeCommand: (projectId: string, command: ProjectCommand) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_EXECUTE_COMMAND, projectId, command),
+      undo: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_UNDO, projectId),
+      redo: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_REDO, projectId),
+      getUndoStackSize: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_UNDO_STACK_SIZE, projectId),
+      addMarker: (projectId: string, clipId: string, time: number, label: string, color?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_MARKER_ADD, projectId, clipId, time, label, color),
+      removeMarker: (projectId: string, clipId: string, markerId: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_MARKER_REMOVE, projectId, clipId, markerId),
+      updateMarker: (projectId: string, clipId: string, markerId: string, label?: string, color?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_MARKER_UPDATE, projectId, clipId, markerId, label, color),
+      addTrackMarker: (projectId: string, trackId: string, time: number, label: string, color?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_TRACK_MARKER_ADD, projectId, trackId, time, label, color),
+      removeTrackMarker: (projectId: string, trackId: string, markerId: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_TRACK_MARKER_REMOVE, projectId, trackId, markerId),
+      updateTrackMarker: (projectId: string, trackId: string, markerId: string, label?: string, color?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_TRACK_MARKER_UPDATE, projectId, trackId, markerId, label, color),
+      getMarkers: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_MARKERS_GET, projectId),
+      exportProject: (projectId: string, settings: ProjectExportSettings) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_EXPORT_PROJECT, projectId, settings),
+      cancelExport: () => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_CANCEL_EXPORT),
+      getExportReports: (projectId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_GET_EXPORT_REPORTS, projectId),
+      batchExport: (jobs: ExportJob[]) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_BATCH_EXPORT, jobs),
+      renderPreviewFrame: (projectId: string, time: number, outputPath: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_RENDER_PREVIEW_FRAME, projectId, time, outputPath),
+      cancelPreviewRender: () => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_CANCEL_PREVIEW_RENDER),
+      setExportPriority: (priority: ProcessPriority) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_SET_EXPORT_PRIORITY, priority),
+      autoSplitClip: (projectId: string, clipId: string, threshold?: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_AUTO_SPLIT_CLIP, projectId, clipId, threshold),
+      splitClipAtTimes: (projectId: string, clipId: string, times: number[]) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_SPLIT_CLIP_AT_TIMES, projectId, clipId, times),
+      importLibraryClip: (projectId: string, libraryId: string, trackId: string, insertAtTime: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_IMPORT_LIBRARY_CLIP, projectId, libraryId, trackId, insertAtTime),
+      addAudioClip: (
+        projectId: string,
+        trackId: string,
+        filePath: string,
+        startTime: number,
+        sourceStart?: number,
+        sourceEnd?: number,
+      ) =>
+        ipcRenderer.invoke(
+          IPC_CHANNELS.EDITOR_ADD_AUDIO_CLIP,
+          projectId,
+          trackId,
+          filePath,
+          startTime,
+          sourceStart,
+          sourceEnd,
+        ),
+      analyzeScenes: (videoPath: string, threshold?: number, projectId?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_ANALYZE_SCENES, videoPath, threshold, projectId),
+      getSceneAnalysisStatus: (jobId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_SCENE_ANALYSIS_STATUS, jobId),
+      cancelSceneAnalysis: (jobId: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_SCENE_ANALYSIS_CANCEL, jobId),
+      cancelAnalysisJobs: (projectId: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_CANCEL_ANALYSIS_JOBS, projectId),
+      getExportFormats: () => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_GET_EXPORT_FORMATS),
+      getMediaUrl: (filePath: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_GET_MEDIA_URL, filePath),
+      renderTextPreview: (backgroundVideoPath: string, timeOffset: number, text: TextProperties, outputPath?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_RENDER_TEXT_PREVIEW, backgroundVideoPath, timeOffset, text, outputPath),
+      extractFrames: (videoPath: string, outputDir: string, start: number, end: number, fps: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_EXTRACT_FRAMES, videoPath, outputDir, start, end, fps),
+      extractSingleFrame: (videoPath: string, time: number, outputPath: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_EXTRACT_SINGLE_FRAME, videoPath, time, outputPath),
+      getPreviewFrame: (videoPath: string, time: number, maxWidth: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_GET_PREVIEW_FRAME, videoPath, time, maxWidth),
+      detectKeyframes: (videoPath: string, start: number, end: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_DETECT_KEYFRAMES, videoPath, start, end),
+      transcodeForEditing: (inputPath: string, profile: EditingTranscodeProfile, projectId?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_TRANSCODE_FOR_EDITING, inputPath, profile, projectId),
+      getVideoStreams: (filePath: string) => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_GET_VIDEO_STREAMS, filePath),
+      remuxVideo: (inputPath: string, outputPath: string, streams?: RemuxStreamSelection) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_REMUX_VIDEO, inputPath, outputPath, streams),
+      diffProjects: (before: Project, after: Project) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_PROJECT_DIFF, before, after),
+      mergeAudioToVideo: (videoPath: string, audioPath: string, outputPath: string, options?: AudioMergeOptions) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_MERGE_AUDIO, videoPath, audioPath, outputPath, options),
+      createSlideshow: (images: string[], outputPath: string, options?: SlideshowOptions) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_CREATE_SLIDESHOW, images, outputPath, options),
+      getAvailableEffects: () => ipcRenderer.invoke(IPC_CHANNELS.EDITOR_GET_AVAILABLE_EFFECTS),
+      applyEffectPreview: (videoPath: string, effect: Effect, outputPath: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.EDITOR_APPLY_EFFECT_PREVIEW, videoPath, effect, outputPath),
+    },
+
+    // Library operations (completed downloads)
+    library: {
+      exportCsv: (videoIds?: string[]) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_EXPORT_CSV, videoIds),
+      exportCsvToFile: (outputPath: string, videoIds?: string[]) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_EXPORT_CSV_TO_FILE, outputPath, videoIds),
+      generateReport: (outputPath: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_GENERATE_REPORT, outputPath),
+      saveSearchAsPlaylist: (query: string, name: string, outputPath?: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_SAVE_SEARCH_PLAYLIST, query, name, outputPath),
+      listPlaylists: () => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_LIST_PLAYLISTS),
+      importCsv: (csvPath: string, skipMissingFiles?: boolean) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_IMPORT_CSV, csvPath, skipMissingFiles),
+      downloadMissingThumbnails: () => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_DOWNLOAD_MISSING_THUMBNAILS),
+      getFormats: () => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_GET_FORMATS),
+      getByFormat: (format: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_GET_BY_FORMAT, format),
+      search: (query: string, limit?: number) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_SEARCH, query, limit),
+      getChannels: () => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_GET_CHANNELS),
+      getByChannel: (channelId: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_GET_BY_CHANNEL, channelId),
+      startWatcher: (folderPath: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_START_WATCHER, folderPath),
+      stopWatcher: () => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_STOP_WATCHER),
+      getBulkJobStatus: (jobId: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_BULK_JOB_STATUS, jobId),
+      cancelBulkJob: (jobId: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_BULK_JOB_CANCEL, jobId),
+      checkAlreadyDownloaded: (url: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_CHECK_ALREADY_DOWNLOADED, url),
+      remuxVideo: (downloadId: string, container: string, streams?: RemuxStreamSelection) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_REMUX_VIDEO, downloadId, container, streams),
+      extractAudio: (downloadId: string, format: AudioExtractFormat, bitrate?: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_EXTRACT_AUDIO, downloadId, format, bitrate),
+      getConversionStatus: (jobId: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_CONVERSION_STATUS, jobId),
+      cancelConversion: (jobId: string) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_CONVERSION_CANCEL, jobId),
+      setPlaybackPosition: (downloadId: string, seconds: number, duration?: number) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_SET_PLAYBACK_POSITION, downloadId, seconds, duration),
+      getPlaybackPosition: (downloadId: string) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_GET_PLAYBACK_POSITION, downloadId),
+      markWatched: (downloadId: string, watched: boolean) =>
+        ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_MARK_WATCHED, downloadId, watched),
+      getContinueWatching: (limit?: number) => ipcRenderer.invoke(IPC_CHANNELS.LIBRARY_GET_CONTINUE_WATCHING, limit),
+    },
+
     // Event listeners (secure wrapper)
     // Uses ALLOWED_BROADCAST_CHANNELS from channels.ts as single source of truth
     on: (channel: string, listener: (...args: any[]) => void) => {
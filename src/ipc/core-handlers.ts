@@ -3,21 +3,44 @@
  * Handles core system operations: window management, shell operations, and theming
  */
 
-import { BrowserWindow, app, dialog, ipcMain, shell } from 'electron'
+import { BrowserWindow, dialog, ipcMain, shell } from 'electron'
 import { createErrorResponse, createSuccessResponse } from '../types/api'
-import { join } from 'path'
-import { readFileSync } from 'fs'
-
+import { dirname, extname, isAbsolute, join, normalize } from 'path'
+import { existsSync, mkdirSync } from 'fs'
+
+import { BackupManager } from '../services/backup-manager'
+import { installFfmpeg } from '../services/binary-installer'
+import {
+  getFfmpegVersion,
+  getYtdlpVersion,
+  invalidateBinaryVersions,
+  validateCustomBinaryPath,
+} from '../services/binary-versions'
+import { exportDiagnosticsReport, getSystemInfo, runDiagnostics } from '../services/diagnostics'
+import { runHealthCheck } from '../services/health-check'
+import { testConnectivity } from '../services/connectivity'
+import { memoryEvents, startMemoryMonitor } from '../services/memory-monitor'
+import { StorageManager } from '../services/storage-manager'
+import { ThumbnailCache } from '../services/thumbnail-cache'
+import { getSupportedExtractors } from '../services/downloader/yt-dlp-provider'
 import { ConfigManager } from '../utils/config'
+import { getAvailableLanguages } from '../utils/i18n'
 import { IPC_CHANNELS } from './channels'
 import { Logger } from '../utils/logger'
 import { PlatformUtils } from '../utils/platform'
 import { ValidationUtils } from '../utils/validation'
-import type { ThemeMode } from '../types/system'
+import type {
+  BackupProgress,
+  BinaryInstallProgress,
+  BinaryType,
+  HighMemoryWarning,
+  ThemeMode,
+} from '../types/system'
 
 const logger = Logger.getInstance()
 const configManager = ConfigManager.getInstance()
 const platform = PlatformUtils.getInstance()
+const backupManager = BackupManager.getInstance()
 
 /**
  * Allowed extensions for shell.openPath (media and document files only)
@@ -136,6 +159,41 @@ export function setupWindowHandlers(): void {
   })
 }
 
+/**
+ * Open an already-validated path in the OS default application
+ */
+export async function openPathInDefaultApp(filePath: string) {
+  const result = await shell.openPath(filePath)
+
+  // shell.openPath returns empty string on success, error message on failure
+  if (result) {
+    logger.error('Failed to open file', new Error(result), { filePath })
+    return createErrorResponse(`Failed to open file: ${result}`, 'SHELL_OPEN_FAILED')
+  }
+
+  logger.info('Opened file in default application', { filePath })
+  return createSuccessResponse(undefined)
+}
+
+/**
+ * Open one of the app's own folders in the file manager, creating it first if needed
+ */
+async function openAppFolder(folder: 'appData' | 'cache' | 'logs', folderPath: string) {
+  try {
+    mkdirSync(folderPath, { recursive: true })
+    const result = await shell.openPath(folderPath)
+    if (result) {
+      logger.error('Failed to open app folder', new Error(result), { folder, folderPath })
+      return createErrorResponse(`Failed to open folder: ${result}`, 'SHELL_OPEN_FAILED')
+    }
+    logger.info('Opened app folder', { folder, folderPath })
+    return createSuccessResponse(undefined)
+  } catch (error) {
+    logger.error('Failed to open app folder', error as Error, { folder, folderPath })
+    return createErrorResponse('Failed to open folder', 'SHELL_OPEN_FAILED')
+  }
+}
+
 /**
  * Shell Operation Handlers
  * Security: Only allows opening files within allowed directories and with safe extensions
@@ -165,16 +223,7 @@ export function setupShellHandlers(): void {
         )
       }
 
-      const result = await shell.openPath(pathValidation.value!)
-
-      // shell.openPath returns empty string on success, error message on failure
-      if (result) {
-        logger.error('Failed to open file', new Error(result), { filePath })
-        return createErrorResponse(`Failed to open file: ${result}`, 'SHELL_OPEN_FAILED')
-      }
-
-      logger.info('Opened file in default application', { filePath: pathValidation.value })
-      return createSuccessResponse(undefined)
+      return await openPathInDefaultApp(pathValidation.value!)
     } catch (error) {
       logger.error('Failed to open file path', error as Error, { filePath })
       return createErrorResponse('Failed to open file', 'SHELL_OPEN_FAILED')
@@ -211,6 +260,15 @@ export function setupShellHandlers(): void {
     }
   })
 
+  // App folders are fixed paths, so unlike the handlers above there is nothing to validate
+  ipcMain.handle(IPC_CHANNELS.SHELL_OPEN_APP_DATA_FOLDER, () =>
+    openAppFolder('appData', platform.getAppDataDir('clipy')),
+  )
+  ipcMain.handle(IPC_CHANNELS.SHELL_OPEN_CACHE_FOLDER, () =>
+    openAppFolder('cache', StorageManager.getInstance().getCacheDirectory()),
+  )
+  ipcMain.handle(IPC_CHANNELS.SHELL_OPEN_LOGS_FOLDER, () => openAppFolder('logs', logger.getLogsDirectory()))
+
   ipcMain.handle(IPC_CHANNELS.SHELL_OPEN_EXTERNAL, async (_event, url: string) => {
     try {
       if (typeof url !== 'string' || !url.trim()) {
@@ -313,37 +371,7 @@ export function setupThemeHandlers(): void {
 export function setupSystemHandlers(): void {
   ipcMain.handle(IPC_CHANNELS.SYSTEM_INFO, async () => {
     try {
-      // Load package.json to get app metadata
-      let packageInfo = null
-      try {
-        const packagePath = app.isPackaged
-          ? join(process.resourcesPath, 'app', 'package.json')
-          : join(app.getAppPath(), 'package.json')
-        const packageData = readFileSync(packagePath, 'utf-8')
-        packageInfo = JSON.parse(packageData)
-      } catch (pkgError) {
-        logger.warn('Failed to load package.json', pkgError as Error)
-      }
-
-      // Map platform to user-friendly OS name
-      const osNameMap: Record<string, string> = {
-        win32: 'Windows',
-        darwin: 'macOS',
-        linux: 'Linux',
-        freebsd: 'FreeBSD',
-        sunos: 'SunOS',
-      }
-
-      const systemInfo = {
-        appName: app.getName(),
-        appVersion: app.getVersion(),
-        os: osNameMap[process.platform] || process.platform,
-        arch: process.arch,
-        nodeVersion: process.version,
-        electronVersion: process.versions.electron,
-        packageInfo,
-      }
-      return createSuccessResponse(systemInfo)
+      return createSuccessResponse(getSystemInfo())
     } catch (error) {
       logger.error('Failed to get system info', error as Error)
       return createErrorResponse('Failed to get system info', 'SYSTEM_INFO_FAILED')
@@ -369,6 +397,226 @@ export function setupSystemHandlers(): void {
       return createErrorResponse('Failed to save dialog', 'DIALOG_SAVE_FAILED')
     }
   })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_AVAILABLE_BROWSERS, async () => {
+    try {
+      return createSuccessResponse(platform.getInstalledBrowsers())
+    } catch (error) {
+      logger.error('Failed to detect installed browsers', error as Error)
+      return createErrorResponse('Failed to detect installed browsers', 'BROWSER_DETECTION_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_HEALTH_CHECK, async () => {
+    try {
+      return createSuccessResponse(await runHealthCheck())
+    } catch (error) {
+      logger.error('Failed to run health check', error as Error)
+      return createErrorResponse('Failed to run health check', 'HEALTH_CHECK_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_TEST_CONNECTIVITY, async () => {
+    try {
+      return createSuccessResponse(await testConnectivity())
+    } catch (error) {
+      logger.error('Failed to test connectivity', error as Error)
+      return createErrorResponse((error as Error).message, 'CONNECTIVITY_TEST_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_MEMORY_USAGE, async () => {
+    try {
+      return createSuccessResponse(PlatformUtils.getInstance().getProcessMemory())
+    } catch (error) {
+      logger.error('Failed to get memory usage', error as Error)
+      return createErrorResponse('Failed to get memory usage', 'SYSTEM_INFO_FAILED')
+    }
+  })
+
+  memoryEvents.on('high-memory-warning', (warning: HighMemoryWarning) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('high-memory-warning', warning)
+      }
+    })
+  })
+  startMemoryMonitor()
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_RUN_DIAGNOSTICS, async () => {
+    try {
+      return createSuccessResponse(await runDiagnostics())
+    } catch (error) {
+      logger.error('Failed to run diagnostics', error as Error)
+      return createErrorResponse('Failed to run diagnostics', 'DIAGNOSTICS_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_EXPORT_DIAGNOSTICS, async (_event, outputPath: string) => {
+    try {
+      const normalizedPath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+      if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+        return createErrorResponse('Invalid output path', 'INVALID_PATH')
+      }
+      if (extname(normalizedPath).toLowerCase() !== '.json') {
+        return createErrorResponse('Diagnostics report must be a .json file', 'INVALID_PATH')
+      }
+      if (!existsSync(dirname(normalizedPath))) {
+        return createErrorResponse('Output directory does not exist', 'INVALID_PATH')
+      }
+
+      await exportDiagnosticsReport(normalizedPath)
+      return createSuccessResponse({ outputPath: normalizedPath })
+    } catch (error) {
+      logger.error('Failed to export diagnostics', error as Error, { outputPath })
+      return createErrorResponse(`Failed to export diagnostics: ${(error as Error).message}`, 'DIAGNOSTICS_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_SUPPORTED_SITES, async () => {
+    try {
+      return createSuccessResponse(await getSupportedExtractors())
+    } catch (error) {
+      logger.error('Failed to get supported sites', error as Error)
+      return createErrorResponse('Failed to get supported sites', 'SUPPORTED_SITES_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_INSTALL_FFMPEG, async () => {
+    try {
+      const result = await installFfmpeg((progress: BinaryInstallProgress) => {
+        BrowserWindow.getAllWindows().forEach(window => {
+          if (!window.isDestroyed()) {
+            window.webContents.send('ffmpeg-install-progress', progress)
+          }
+        })
+      })
+      invalidateBinaryVersions()
+      return createSuccessResponse(result)
+    } catch (error) {
+      logger.error('Failed to install FFmpeg', error as Error)
+      return createErrorResponse(`Failed to install FFmpeg: ${(error as Error).message}`, 'FFMPEG_INSTALL_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_AVAILABLE_LANGUAGES, async () => {
+    return createSuccessResponse(getAvailableLanguages())
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_PREFETCH_THUMBNAILS, async () => {
+    try {
+      return createSuccessResponse(await ThumbnailCache.getInstance().prefetchLibraryThumbnails())
+    } catch (error) {
+      logger.error('Failed to prefetch library thumbnails', error as Error)
+      return createErrorResponse('Failed to prefetch thumbnails', 'THUMBNAIL_PREFETCH_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_YTDLP_VERSION, async () => {
+    try {
+      return createSuccessResponse(await getYtdlpVersion())
+    } catch (error) {
+      logger.error('Failed to get yt-dlp version', error as Error)
+      return createErrorResponse(`Failed to get yt-dlp version: ${(error as Error).message}`, 'VERSION_CHECK_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_FFMPEG_VERSION, async () => {
+    try {
+      return createSuccessResponse(await getFfmpegVersion())
+    } catch (error) {
+      logger.error('Failed to get FFmpeg version', error as Error)
+      return createErrorResponse(`Failed to get FFmpeg version: ${(error as Error).message}`, 'VERSION_CHECK_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_INVALIDATE_VERSIONS, async () => {
+    invalidateBinaryVersions()
+    return createSuccessResponse(undefined)
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_VALIDATE_BINARY_PATH, async (_event, binaryPath: string, type: BinaryType) => {
+    try {
+      if (typeof binaryPath !== 'string' || (type !== 'ytdlp' && type !== 'ffmpeg')) {
+        return createErrorResponse('Invalid binary path or type', 'INVALID_BINARY')
+      }
+      return createSuccessResponse(await validateCustomBinaryPath(binaryPath, type))
+    } catch (error) {
+      logger.warn('Custom binary path rejected', { binaryPath, type, error: (error as Error).message })
+      return createErrorResponse((error as Error).message, 'INVALID_BINARY')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.SYSTEM_THUMBNAIL_CACHE_STATS, async () => {
+    try {
+      return createSuccessResponse(ThumbnailCache.getInstance().getCacheStats())
+    } catch (error) {
+      logger.error('Failed to get thumbnail cache stats', error as Error)
+      return createErrorResponse('Failed to get thumbnail cache stats', 'THUMBNAIL_STATS_FAILED')
+    }
+  })
+}
+
+/**
+ * Validate a backup archive path: absolute, no null bytes, .zip extension
+ */
+function validateBackupPath(filePath: string): { isValid: boolean; error?: string; path?: string } {
+  if (!filePath || typeof filePath !== 'string' || filePath.includes('\0')) {
+    return { isValid: false, error: 'Invalid backup path' }
+  }
+
+  const normalizedPath = normalize(filePath)
+  if (!isAbsolute(normalizedPath)) {
+    return { isValid: false, error: 'Backup path must be absolute' }
+  }
+  if (extname(normalizedPath).toLowerCase() !== '.zip') {
+    return { isValid: false, error: 'Backup file must be a .zip archive' }
+  }
+
+  return { isValid: true, path: normalizedPath }
+}
+
+/**
+ * Backup & Restore Handlers
+ */
+export function setupBackupHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.BACKUP_CREATE, async (_event, outputPath: string, includeThumbnails?: boolean) => {
+    try {
+      const validation = validateBackupPath(outputPath)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid backup path', 'INVALID_PATH')
+      }
+
+      const result = await backupManager.createBackup(validation.path!, includeThumbnails === true)
+      return createSuccessResponse(result)
+    } catch (error) {
+      logger.error('Failed to create backup', error as Error, { outputPath })
+      return createErrorResponse(`Failed to create backup: ${(error as Error).message}`, 'BACKUP_CREATE_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.BACKUP_RESTORE, async (_event, zipPath: string, includeThumbnails?: boolean) => {
+    try {
+      const validation = validateBackupPath(zipPath)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid backup path', 'INVALID_PATH')
+      }
+
+      const result = await backupManager.restoreBackup(validation.path!, includeThumbnails === true)
+      return createSuccessResponse(result)
+    } catch (error) {
+      logger.error('Failed to restore backup', error as Error, { zipPath })
+      return createErrorResponse(`Failed to restore backup: ${(error as Error).message}`, 'BACKUP_RESTORE_FAILED')
+    }
+  })
+
+  backupManager.on('progress', (progress: BackupProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('backup-progress', progress)
+      }
+    })
+  })
 }
 
 /**
@@ -381,6 +629,7 @@ export function setupCoreHandlers(): void {
   setupShellHandlers()
   setupThemeHandlers()
   setupSystemHandlers()
+  setupBackupHandlers()
 
   logger.info('Core IPC handlers initialized successfully')
 }
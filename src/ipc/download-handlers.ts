@@ -4,48 +4,181 @@
  */
 
 import { BrowserWindow, ipcMain } from 'electron'
-import type { DownloadFilter, DownloadListData, DownloadOptions, DownloadProgress } from '../types/download'
+import { existsSync, statSync } from 'fs'
+import type {
+  BatchEnqueueProgress,
+  BatchEnqueueReport,
+  CookieChoice,
+  DownloadDeleteOptions,
+  DownloadFilter,
+  DownloadListData,
+  DownloadOptions,
+  DownloadProgress,
+  QueueCompletedEvent,
+  QueueCompletionAction,
+  QueueCompletionSummary,
+  VideoInfo,
+} from '../types/download'
 import { createErrorResponse, createSuccessResponse } from '../types/api'
 
 import { ConfigManager } from '../utils/config'
 import { DownloadManager } from '../services/download-manager'
+import {
+  deleteDownloadProfile,
+  getDownloadProfile,
+  getDownloadProfiles,
+  saveDownloadProfile,
+} from '../services/download-profiles'
 import { IPC_CHANNELS } from './channels'
 import { Logger } from '../utils/logger'
 import { PlatformUtils } from '../utils/platform'
 import { StorageManager } from '../services/storage-manager'
+import { ThumbnailCache } from '../services/thumbnail-cache'
 import { ValidationUtils } from '../utils/validation'
+import { batchEnqueueEvents, enqueueUrls, enqueueUrlsFromFile } from '../services/batch-enqueue'
 import { getVideoInfoWithStreamingUrl } from '../services/downloader/yt-dlp-manager'
+import { probeDirectMedia } from '../services/downloader/direct-download'
+import { redactDownloadOptions } from '../services/downloader/yt-dlp-environment'
+import {
+  checkUrlSupported,
+  downloadThumbnailOnly,
+  findVideoFormat,
+  getVideoComments,
+} from '../services/downloader/yt-dlp-provider'
+import { getDownloadLogsDirectory, readDownloadLogTail } from '../services/downloader/download-log'
+import { openPathInDefaultApp } from './core-handlers'
+import { dirname, isAbsolute, join, normalize, resolve } from 'path'
 import { getProxyUrl, isProxyRunning, getProxyPort } from '../services/streaming-proxy'
+import { sanitizePathComponent } from '../utils/paths'
+import { assertSiteAllowed } from '../utils/site-policy'
+import { isProtectedOutputPath } from '../utils/output-file'
+import { syncLibraryWatcher } from '../services/library-watcher'
+import { applyBinaryPathSettings } from '../services/binary-versions'
+import {
+  cancelCompletionAction,
+  getQueueCompletionAction,
+  handleQueueCompleted,
+  queueCompletionEvents,
+  setQueueCompletionAction,
+} from '../services/queue-completion'
+import { importSettings, previewSettingsImport, writeSettingsExport } from '../services/settings-transfer'
 
 const logger = Logger.getInstance()
 const downloadManager = DownloadManager.getInstance()
 const storageManager = StorageManager.getInstance()
 const configManager = ConfigManager.getInstance()
+const thumbnailCache = ThumbnailCache.getInstance()
+
+const DEFAULT_MAX_COMMENTS = 100
+const MAX_COMMENTS = 2000
+
+/**
+ * Validate a download request from the renderer and queue it
+ */
+async function startDownload(url: string, options?: DownloadOptions) {
+  try {
+    // Validate input
+    const urlValidation = ValidationUtils.validateDownloadUrl(url)
+    if (!urlValidation.isValid) {
+      return ValidationUtils.toErrorResponse(urlValidation, 'Invalid URL', 'INVALID_URL')
+    }
+
+    const validatedOptions = ValidationUtils.validateDownloadOptions(options)
+
+    // Fail fast rather than after fetching video info and spawning yt-dlp
+    const outputPath = validatedOptions.value?.outputPath || configManager.get('download').downloadPath
+    const writable = ValidationUtils.validateOutputPathWritable(outputPath)
+    if (!writable.isValid) {
+      return ValidationUtils.toErrorResponse(writable, 'Download folder is not writable', 'PERMISSION_DENIED')
+    }
+
+    logger.info('Starting download', { url, options: redactDownloadOptions(validatedOptions.value) })
+
+    const result = await downloadManager.startDownload(url, validatedOptions.value)
+    // A new batch started during the countdown: don't sleep or shut down under it
+    cancelCompletionAction()
+
+    return createSuccessResponse({
+      downloadId: result.downloadId,
+      message: 'Download started successfully',
+    })
+  } catch (error) {
+    logger.error('Failed to start download', error as Error, { url, options: redactDownloadOptions(options) })
+    return ValidationUtils.handleDownloadError(error)
+  }
+}
+
+/**
+ * Queue a batch of URLs after the same option and download folder checks as a single download
+ */
+async function enqueueBatch(
+  options: DownloadOptions | undefined,
+  enqueue: (options: DownloadOptions) => Promise<BatchEnqueueReport>,
+) {
+  const validatedOptions = ValidationUtils.validateDownloadOptions(options)
+  const outputPath = validatedOptions.value?.outputPath || configManager.get('download').downloadPath
+  const writable = ValidationUtils.validateOutputPathWritable(outputPath)
+  if (!writable.isValid) {
+    return ValidationUtils.toErrorResponse(writable, 'Download folder is not writable', 'PERMISSION_DENIED')
+  }
+
+  const report = await enqueue(validatedOptions.value ?? {})
+  if (report.accepted.length > 0) {
+    cancelCompletionAction() // As for a single download: the queue isn't done anymore
+  }
+  return createSuccessResponse(report)
+}
 
 /**
  * Download Operation Handlers
  */
 export function setupDownloadOperationHandlers(): void {
   ipcMain.handle(IPC_CHANNELS.DOWNLOAD_START, async (_event, url: string, options?: DownloadOptions) => {
-    try {
-      // Validate input
-      const urlValidation = ValidationUtils.validateUrl(url)
-      if (!urlValidation.isValid) {
-        return createErrorResponse(urlValidation.error || 'Invalid URL', 'INVALID_URL')
-      }
+    return startDownload(url, options)
+  })
+
+  // The profile's options apply to this download only; the settings are left alone
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_START_WITH_PROFILE, async (_event, url: string, profileId: string) => {
+    const profile = typeof profileId === 'string' ? getDownloadProfile(profileId) : null
+    if (!profile) {
+      return createErrorResponse('Download profile not found', 'PROFILE_NOT_FOUND')
+    }
+    logger.info('Starting download with profile', { url, profileId, profile: profile.name })
+    return startDownload(url, profile.options)
+  })
 
-      const validatedOptions = ValidationUtils.validateDownloadOptions(options)
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_PROFILES, async () => {
+    return createSuccessResponse(getDownloadProfiles())
+  })
 
-      logger.info('Starting download', { url, options: validatedOptions.value })
+  ipcMain.handle(
+    IPC_CHANNELS.DOWNLOAD_SAVE_PROFILE,
+    async (_event, name: string, options: DownloadOptions, profileId?: string) => {
+      try {
+        if (typeof name !== 'string') {
+          return createErrorResponse('Profile name is required', 'INVALID_PARAMS')
+        }
+        const validatedOptions = ValidationUtils.validateDownloadOptions(options)
+        if (!validatedOptions.isValid) {
+          return ValidationUtils.toErrorResponse(validatedOptions, 'Invalid download options', 'INVALID_PARAMS')
+        }
+        return createSuccessResponse(saveDownloadProfile(name, validatedOptions.value ?? {}, profileId))
+      } catch (error) {
+        return createErrorResponse((error as Error).message, 'PROFILE_SAVE_FAILED')
+      }
+    },
+  )
 
-      const result = await downloadManager.startDownload(url, validatedOptions.value)
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_DELETE_PROFILE, async (_event, profileId: string) => {
+    return createSuccessResponse({ deleted: deleteDownloadProfile(profileId) })
+  })
 
-      return createSuccessResponse({
-        downloadId: result.downloadId,
-        message: 'Download started successfully',
-      })
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_CANCEL_ALL, async () => {
+    try {
+      const downloadIds = downloadManager.cancelAllDownloads()
+      return createSuccessResponse({ downloadIds })
     } catch (error) {
-      logger.error('Failed to start download', error as Error, { url, options })
+      logger.error('Failed to cancel all downloads', error as Error)
       return ValidationUtils.handleDownloadError(error)
     }
   })
@@ -74,20 +207,24 @@ export function setupDownloadOperationHandlers(): void {
     }
   })
 
-  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_DELETE, async (_event, downloadId: string) => {
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_DELETE, async (_event, downloadId: string, options?: DownloadDeleteOptions) => {
     try {
       const validation = ValidationUtils.validateDownloadId(downloadId)
       if (!validation.isValid) {
         return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
       }
 
-      const success = await downloadManager.deleteDownload(downloadId)
+      const result = await downloadManager.deleteDownload(downloadId, sanitizeDeleteOptions(options))
 
-      if (success) {
+      if (result.code) {
+        return createErrorResponse(result.error || 'Failed to remove file', result.code)
+      } else if (result.deleted) {
         logger.info('Download deleted', { downloadId })
         return createSuccessResponse({
           downloadId,
           message: 'Download deleted successfully',
+          files: result.files,
+          failedFiles: result.failedFiles,
         })
       } else {
         return createErrorResponse('Download not found', 'DOWNLOAD_NOT_FOUND')
@@ -98,6 +235,28 @@ export function setupDownloadOperationHandlers(): void {
     }
   })
 
+  ipcMain.handle(
+    IPC_CHANNELS.DOWNLOAD_BULK_DELETE,
+    async (_event, downloadIds: string[], options?: DownloadDeleteOptions) => {
+      try {
+        if (!Array.isArray(downloadIds)) {
+          return createErrorResponse('Download IDs must be an array', 'INVALID_DOWNLOAD_ID')
+        }
+        const invalidId = downloadIds.find(id => !ValidationUtils.validateDownloadId(id).isValid)
+        if (invalidId !== undefined) {
+          return createErrorResponse(`Invalid download ID: ${invalidId}`, 'INVALID_DOWNLOAD_ID')
+        }
+
+        // Runs in the background; follow it with 'library-bulk-progress' or the bulk job status
+        const jobId = downloadManager.startBulkDelete(downloadIds, sanitizeDeleteOptions(options))
+        return createSuccessResponse({ jobId })
+      } catch (error) {
+        logger.error('Failed to delete downloads', error as Error, { count: downloadIds?.length })
+        return ValidationUtils.handleDownloadError(error)
+      }
+    },
+  )
+
   ipcMain.handle(IPC_CHANNELS.DOWNLOAD_RETRY, async (_event, downloadId: string) => {
     try {
       const validation = ValidationUtils.validateDownloadId(downloadId)
@@ -118,6 +277,60 @@ export function setupDownloadOperationHandlers(): void {
     }
   })
 
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_PAUSE, async (_event, downloadId: string) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+
+      // Active downloads report 'paused' through a progress event once their partial files are recorded
+      if (!(await downloadManager.pauseDownload(downloadId))) {
+        return createErrorResponse('Download not found or not running', 'DOWNLOAD_NOT_FOUND')
+      }
+      logger.info('Download paused', { downloadId })
+      return createSuccessResponse({ downloadId })
+    } catch (error) {
+      logger.error('Failed to pause download', error as Error, { downloadId })
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_RESUME, async (_event, downloadId: string) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+
+      if (!(await downloadManager.resumeDownload(downloadId))) {
+        return createErrorResponse('Download not found or not paused', 'DOWNLOAD_NOT_FOUND')
+      }
+      logger.info('Download resumed', { downloadId })
+      cancelCompletionAction() // Don't sleep or shut down under a resumed download
+      return createSuccessResponse({ downloadId })
+    } catch (error) {
+      logger.error('Failed to resume download', error as Error, { downloadId })
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_COMPLETION_ACTION, async () => {
+    return createSuccessResponse(getQueueCompletionAction())
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_SET_COMPLETION_ACTION, async (_event, action: QueueCompletionAction) => {
+    if (!['none', 'sleep', 'shutdown', 'quit'].includes(action)) {
+      return createErrorResponse('Invalid completion action', 'INVALID_ACTION')
+    }
+    setQueueCompletionAction(action)
+    return createSuccessResponse(undefined)
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_CANCEL_COMPLETION_ACTION, async () => {
+    return createSuccessResponse({ cancelled: cancelCompletionAction() })
+  })
+
   ipcMain.handle(IPC_CHANNELS.DOWNLOAD_PROGRESS, async (_event, downloadId?: string) => {
     try {
       if (downloadId) {
@@ -160,14 +373,75 @@ export function setupDownloadOperationHandlers(): void {
     }
   })
 
-  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_INFO, async (_event, url: string) => {
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_BY_ID, async (_event, downloadId: string) => {
     try {
-      const urlValidation = ValidationUtils.validateUrl(url)
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+      return createSuccessResponse(downloadManager.getDownload(downloadId))
+    } catch (error) {
+      logger.error('Failed to get download', error as Error, { downloadId })
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_STATUS, async (_event, downloadId: string) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+      return createSuccessResponse(downloadManager.getDownloadStatus(downloadId))
+    } catch (error) {
+      logger.error('Failed to get download status', error as Error, { downloadId })
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_SYNC_STATE, async () => {
+    try {
+      return createSuccessResponse(downloadManager.getStateSnapshot())
+    } catch (error) {
+      logger.error('Failed to get download state snapshot', error as Error)
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_QUEUE_ETA, async () => {
+    try {
+      return createSuccessResponse(downloadManager.getQueueEta())
+    } catch (error) {
+      logger.error('Failed to estimate queue time', error as Error)
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_AVERAGE_SPEED, async () => {
+    try {
+      return createSuccessResponse(downloadManager.getAverageDownloadSpeed())
+    } catch (error) {
+      logger.error('Failed to get average download speed', error as Error)
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_INFO, async (_event, url: string, useCookies?: CookieChoice) => {
+    try {
+      const urlValidation = ValidationUtils.validateDownloadUrl(url)
       if (!urlValidation.isValid) {
-        return createErrorResponse(urlValidation.error || 'Invalid URL', 'INVALID_URL')
+        return ValidationUtils.toErrorResponse(urlValidation, 'Invalid URL', 'INVALID_URL')
+      }
+      let cookieChoice: CookieChoice | undefined
+      if (useCookies !== undefined) {
+        const cookieValidation = ValidationUtils.validateCookieChoice(useCookies)
+        if (!cookieValidation.isValid) {
+          return ValidationUtils.toErrorResponse(cookieValidation, 'Invalid cookie choice', 'INVALID_INPUT')
+        }
+        cookieChoice = cookieValidation.value
       }
 
-      const videoInfo = await downloadManager.getVideoInfo(url)
+      const videoInfo = await downloadManager.getVideoInfo(url, cookieChoice)
 
       return createSuccessResponse(videoInfo)
     } catch (error) {
@@ -176,6 +450,36 @@ export function setupDownloadOperationHandlers(): void {
     }
   })
 
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_RELATED_VIDEOS, async (_event, url: string) => {
+    try {
+      const urlValidation = ValidationUtils.validateUrl(url)
+      if (!urlValidation.isValid) {
+        return ValidationUtils.toErrorResponse(urlValidation, 'Invalid URL', 'INVALID_URL')
+      }
+
+      const videoInfo = await downloadManager.getVideoInfo(url)
+      return createSuccessResponse(videoInfo.relatedVideos ?? [])
+    } catch (error) {
+      logger.error('Failed to get related videos', error as Error, { url })
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_FORMAT_DETAILS, async (_event, videoInfo: VideoInfo, formatId: string) => {
+    try {
+      if (!videoInfo || !Array.isArray(videoInfo.formats)) {
+        return createErrorResponse('Invalid video info', 'INVALID_VIDEO_INFO')
+      }
+      if (typeof formatId !== 'string' || !formatId.trim()) {
+        return createErrorResponse('Invalid format ID', 'INVALID_FORMAT')
+      }
+      return createSuccessResponse(findVideoFormat(videoInfo, formatId.trim()))
+    } catch (error) {
+      logger.error('Failed to get format details', error as Error, { formatId })
+      return createErrorResponse('Failed to get format details', 'FORMAT_DETAILS_FAILED')
+    }
+  })
+
   // Get video info with streaming URL for editor preview
   // NOTE: We return RAW YouTube URLs (not proxied) because Electron's webRequest API
   // handles CORS bypass at the browser level. This is more reliable than Node.js proxy
@@ -185,7 +489,7 @@ export function setupDownloadOperationHandlers(): void {
     try {
       const urlValidation = ValidationUtils.validateUrl(url)
       if (!urlValidation.isValid) {
-        return createErrorResponse(urlValidation.error || 'Invalid URL', 'INVALID_URL')
+        return ValidationUtils.toErrorResponse(urlValidation, 'Invalid URL', 'INVALID_URL')
       }
 
       const { videoInfo, streamingUrl, audioUrl, fallbackUrl } = await getVideoInfoWithStreamingUrl(url)
@@ -211,9 +515,269 @@ export function setupDownloadOperationHandlers(): void {
       return ValidationUtils.handleDownloadError(error)
     }
   })
+
+  // Fetch storyboard sprite sheets into the thumbnail cache for hover-scrub previews.
+  // Only storyboards reported by yt-dlp for this video can be downloaded.
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_STORYBOARD, async (_event, videoId: string, url: string) => {
+    try {
+      if (typeof videoId !== 'string' || !/^[a-zA-Z0-9_-]{11}$/.test(videoId)) {
+        return createErrorResponse('Invalid video ID', 'INVALID_VIDEO_ID')
+      }
+
+      const videoInfo = await downloadManager.getVideoInfo(`https://www.youtube.com/watch?v=${videoId}`)
+      // Signed query strings change between info fetches, so match on the sheet path only
+      const stripQuery = (value: string) => String(value).split('?')[0]
+      const storyboard = videoInfo.storyboards?.find(
+        sb => stripQuery(sb.url) === stripQuery(url) || sb.fragments.some(f => stripQuery(f) === stripQuery(url)),
+      )
+
+      if (!storyboard) {
+        return createErrorResponse('Storyboard not found for this video', 'STORYBOARD_NOT_FOUND')
+      }
+
+      const result = await thumbnailCache.downloadStoryboard(videoId, storyboard)
+      return createSuccessResponse(result)
+    } catch (error) {
+      logger.error('Failed to download storyboard', error as Error, { videoId })
+      return createErrorResponse(`Failed to download storyboard: ${(error as Error).message}`, 'STORYBOARD_FAILED')
+    }
+  })
+
+  // Queue every URL in a text file picked by the user (one per line)
+  ipcMain.handle(
+    IPC_CHANNELS.DOWNLOAD_ENQUEUE_FILE,
+    async (_event, filePath: string, options?: DownloadOptions, prefetchInfo?: boolean) => {
+      try {
+        const normalizedPath = typeof filePath === 'string' ? normalize(filePath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid file path', 'INVALID_PATH')
+        }
+        if (!existsSync(normalizedPath) || !statSync(normalizedPath).isFile()) {
+          return createErrorResponse('URL list file not found', 'FILE_NOT_FOUND')
+        }
+
+        return await enqueueBatch(options, resolved =>
+          enqueueUrlsFromFile(normalizedPath, resolved, prefetchInfo === true),
+        )
+      } catch (error) {
+        logger.error('Failed to enqueue URLs from file', error as Error, { filePath })
+        return createErrorResponse(`Failed to enqueue URLs: ${(error as Error).message}`, 'BATCH_ENQUEUE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.DOWNLOAD_ENQUEUE_URLS,
+    async (_event, urls: string[], options?: DownloadOptions, prefetchInfo?: boolean) => {
+      try {
+        if (!Array.isArray(urls) || urls.some(url => typeof url !== 'string')) {
+          return createErrorResponse('URLs must be a list of strings', 'INVALID_URL')
+        }
+
+        return await enqueueBatch(options, resolved => enqueueUrls(urls, resolved, prefetchInfo === true))
+      } catch (error) {
+        logger.error('Failed to enqueue URLs', error as Error, { count: Array.isArray(urls) ? urls.length : 0 })
+        return createErrorResponse(`Failed to enqueue URLs: ${(error as Error).message}`, 'BATCH_ENQUEUE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_LOG, async (_event, downloadId: string, tailLines: number = 200) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+
+      const logPath = await getValidatedDownloadLogPath(downloadId)
+      if (!logPath) {
+        return createErrorResponse('No log available for this download', 'DOWNLOAD_LOG_NOT_FOUND')
+      }
+
+      const lines = Number.isInteger(tailLines) && tailLines > 0 ? Math.min(tailLines, 10000) : 200
+      return createSuccessResponse(readDownloadLogTail(logPath, lines))
+    } catch (error) {
+      logger.error('Failed to read download log', error as Error, { downloadId })
+      return createErrorResponse('Failed to read download log', 'DOWNLOAD_LOG_READ_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_OPEN_LOG, async (_event, downloadId: string) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+
+      const logPath = await getValidatedDownloadLogPath(downloadId)
+      if (!logPath) {
+        return createErrorResponse('No log available for this download', 'DOWNLOAD_LOG_NOT_FOUND')
+      }
+
+      return await openPathInDefaultApp(logPath)
+    } catch (error) {
+      logger.error('Failed to open download log', error as Error, { downloadId })
+      return createErrorResponse('Failed to open download log', 'SHELL_OPEN_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_TAG, async (_event, downloadId: string, tags: string[]) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+      const tagValidation = ValidationUtils.validateTags(tags)
+      if (!tagValidation.isValid) {
+        return createErrorResponse(tagValidation.error || 'Invalid tags', 'INVALID_TAGS')
+      }
+
+      const updated = await downloadManager.tagDownload(downloadId, tagValidation.value!)
+      if (!updated) {
+        return createErrorResponse('Download not found', 'DOWNLOAD_NOT_FOUND')
+      }
+      return createSuccessResponse({ downloadId, tags: tagValidation.value! })
+    } catch (error) {
+      logger.error('Failed to tag download', error as Error, { downloadId })
+      return createErrorResponse('Failed to tag download', 'TAG_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_IS_SITE_SUPPORTED, async (_event, url: string) => {
+    try {
+      // Fast path: YouTube URLs are always supported, no need to spawn yt-dlp
+      if (ValidationUtils.validateUrl(url).isValid) {
+        return createSuccessResponse(true)
+      }
+
+      let parsed: URL
+      try {
+        parsed = new URL(typeof url === 'string' ? url.trim() : '')
+      } catch {
+        return createErrorResponse('Invalid URL format', 'INVALID_URL')
+      }
+      if (parsed.protocol !== 'http:' && parsed.protocol !== 'https:') {
+        return createErrorResponse('Only http and https URLs are supported', 'INVALID_URL')
+      }
+
+      // yt-dlp has no extractor for a bare media file link, but it can still be downloaded directly
+      const supported = await checkUrlSupported(parsed.href)
+      return createSuccessResponse(supported || (await probeDirectMedia(parsed.href)) !== null)
+    } catch (error) {
+      logger.error('Failed to check if site is supported', error as Error, { url })
+      return createErrorResponse('Failed to check if site is supported', 'SITE_CHECK_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_GET_COMMENTS, async (_event, url: string, maxComments?: number) => {
+    try {
+      let parsed: URL
+      try {
+        parsed = new URL(typeof url === 'string' ? url.trim() : '')
+      } catch {
+        return createErrorResponse('Invalid URL format', 'INVALID_URL')
+      }
+      if (parsed.protocol !== 'http:' && parsed.protocol !== 'https:') {
+        return createErrorResponse('Only http and https URLs are supported', 'INVALID_URL')
+      }
+      const limit =
+        typeof maxComments === 'number' && Number.isInteger(maxComments) && maxComments > 0
+          ? Math.min(maxComments, MAX_COMMENTS)
+          : DEFAULT_MAX_COMMENTS
+
+      assertSiteAllowed(parsed.href)
+      return createSuccessResponse(await getVideoComments(parsed.href, limit))
+    } catch (error) {
+      logger.error('Failed to get video comments', error as Error, { url })
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_THUMBNAIL, async (_event, url: string, outputPath?: string) => {
+    try {
+      const urlValidation = ValidationUtils.validateUrl(url)
+      if (!urlValidation.isValid) {
+        return ValidationUtils.toErrorResponse(urlValidation, 'Invalid URL', 'INVALID_URL')
+      }
+      if (
+        outputPath !== undefined &&
+        (typeof outputPath !== 'string' || !isAbsolute(outputPath) || outputPath.includes('\0'))
+      ) {
+        return createErrorResponse('Output path must be an absolute path', 'INVALID_PATH')
+      }
+      assertSiteAllowed(url)
+
+      // A folder (the download folder by default) gets the thumbnail named after the video
+      let target = normalize(outputPath ?? configManager.get('download').downloadPath)
+      if (existsSync(target) && statSync(target).isDirectory()) {
+        const info = await downloadManager.getVideoInfo(url)
+        target = join(target, `${sanitizePathComponent(info.title, info.id)}.jpg`)
+      }
+      if (isProtectedOutputPath(target)) {
+        return createErrorResponse('Cannot save into the app temp or cache folders', 'INVALID_PATH')
+      }
+
+      const path = await downloadThumbnailOnly(url, target)
+      return createSuccessResponse({ path })
+    } catch (error) {
+      logger.error('Failed to download thumbnail', error as Error, { url, outputPath })
+      return ValidationUtils.handleDownloadError(error)
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.DOWNLOAD_CHECK_PATH_WRITABLE, async (_event, dirPath: string) => {
+    try {
+      const result = ValidationUtils.validateOutputPathWritable(dirPath)
+      return createSuccessResponse({ writable: result.isValid, error: result.error })
+    } catch (error) {
+      logger.error('Failed to check download folder', error as Error, { dirPath })
+      return createErrorResponse('Failed to check download folder', 'PATH_CHECK_FAILED')
+    }
+  })
+
+  ipcMain.handle(
+    IPC_CHANNELS.DOWNLOAD_PREVIEW_FILENAME,
+    async (_event, template: string, videoInfo: VideoInfo, options?: DownloadOptions) => {
+      try {
+        if (typeof template !== 'string' || !videoInfo?.id || !videoInfo.channel) {
+          return createErrorResponse('Template and video info are required', 'INVALID_INPUT')
+        }
+
+        // The template as typed, so characters validation would strip show up as errors
+        const validated = ValidationUtils.validateDownloadOptions({ ...options, filenameTemplate: undefined })
+        const preview = downloadManager.previewOutputPath(videoInfo, {
+          ...(validated.isValid ? validated.value : {}),
+          filenameTemplate: template.trim().substring(0, 200) || undefined,
+        })
+        return createSuccessResponse(preview)
+      } catch (error) {
+        logger.error('Failed to preview filename template', error as Error, { template })
+        return createErrorResponse('Failed to preview filename template', 'TEMPLATE_PREVIEW_FAILED')
+      }
+    },
+  )
 }
 
-import { join } from 'path'
+/**
+ * Keep only the boolean flags of renderer-supplied delete options
+ */
+function sanitizeDeleteOptions(options?: DownloadDeleteOptions): DownloadDeleteOptions {
+  return {
+    deleteFile: options?.deleteFile === true,
+    permanentlyDelete: typeof options?.permanentlyDelete === 'boolean' ? options.permanentlyDelete : undefined,
+  }
+}
+
+/**
+ * Resolve a download's log file, only returning paths inside the download logs directory
+ */
+async function getValidatedDownloadLogPath(downloadId: string): Promise<string | null> {
+  const logPath = await downloadManager.getDownloadLogPath(downloadId)
+  if (!logPath || dirname(resolve(logPath)) !== resolve(getDownloadLogsDirectory())) {
+    return null
+  }
+  return existsSync(logPath) || existsSync(`${logPath}.1`) ? logPath : null
+}
 
 const platform = PlatformUtils.getInstance()
 
@@ -394,7 +958,20 @@ export function setupConfigHandlers(): void {
 
   ipcMain.handle(IPC_CHANNELS.CONFIG_UPDATE, async (_event, updates: any) => {
     try {
+      const proxyUrl = updates?.advanced?.proxyUrl
+      if (typeof proxyUrl === 'string' && proxyUrl.trim()) {
+        const proxy = ValidationUtils.validateProxyUrl(proxyUrl)
+        if (!proxy.isValid) {
+          return createErrorResponse(`Invalid proxy URL: ${proxy.error}`, 'INVALID_PROXY_URL')
+        }
+        updates.advanced.proxyUrl = proxyUrl.trim()
+      }
       configManager.update(updates)
+      downloadManager.updateConfig()
+      syncLibraryWatcher()
+      if (updates?.advanced && ('ffmpegPath' in updates.advanced || 'ytDlpPath' in updates.advanced)) {
+        applyBinaryPathSettings()
+      }
       logger.info('Configuration updated', { updates })
       return createSuccessResponse(configManager.getAll())
     } catch (error) {
@@ -406,6 +983,9 @@ export function setupConfigHandlers(): void {
   ipcMain.handle(IPC_CHANNELS.CONFIG_RESET, async () => {
     try {
       configManager.reset()
+      downloadManager.updateConfig()
+      syncLibraryWatcher()
+      applyBinaryPathSettings()
       logger.info('Configuration reset to defaults')
       return createSuccessResponse(configManager.getAll())
     } catch (error) {
@@ -413,6 +993,53 @@ export function setupConfigHandlers(): void {
       return createErrorResponse('Failed to reset configuration', 'CONFIG_RESET_FAILED')
     }
   })
+
+  ipcMain.handle(IPC_CHANNELS.CONFIG_EXPORT, async (_event, outputPath: string, redactSecrets: boolean = true) => {
+    try {
+      if (!outputPath || typeof outputPath !== 'string' || !isAbsolute(outputPath)) {
+        return createErrorResponse('An absolute output path is required', 'INVALID_PATH')
+      }
+      const exported = writeSettingsExport(outputPath, redactSecrets !== false)
+      logger.info('Settings exported', { outputPath, redacted: exported.redacted })
+      return createSuccessResponse(exported)
+    } catch (error) {
+      logger.error('Failed to export settings', error as Error, { outputPath })
+      return createErrorResponse('Failed to export settings', 'CONFIG_EXPORT_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.CONFIG_IMPORT_PREVIEW, async (_event, filePath: string) => {
+    try {
+      if (!filePath || typeof filePath !== 'string' || !existsSync(filePath)) {
+        return createErrorResponse('Settings file not found', 'FILE_NOT_FOUND')
+      }
+      return createSuccessResponse(previewSettingsImport(filePath))
+    } catch (error) {
+      logger.warn('Settings file rejected', { filePath, error: (error as Error).message })
+      return createErrorResponse((error as Error).message, 'CONFIG_IMPORT_INVALID')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.CONFIG_IMPORT, async (_event, filePath: string) => {
+    try {
+      if (!filePath || typeof filePath !== 'string' || !existsSync(filePath)) {
+        return createErrorResponse('Settings file not found', 'FILE_NOT_FOUND')
+      }
+      const result = importSettings(filePath)
+      if (result.changes.length > 0) {
+        downloadManager.updateConfig()
+        syncLibraryWatcher()
+        if (result.changes.some(change => /^advanced\.(ffmpegPath|ytDlpPath)$/.test(change.path))) {
+          applyBinaryPathSettings()
+        }
+      }
+      logger.info('Settings imported', { filePath, changed: result.changes.length, warnings: result.warnings })
+      return createSuccessResponse({ ...result, config: configManager.getAll() })
+    } catch (error) {
+      logger.warn('Settings import failed', { filePath, error: (error as Error).message })
+      return createErrorResponse((error as Error).message, 'CONFIG_IMPORT_FAILED')
+    }
+  })
 }
 
 /**
@@ -455,6 +1082,35 @@ export function setupProgressBroadcasting(): void {
       }
     })
   })
+
+  batchEnqueueEvents.on('progress', (progress: BatchEnqueueProgress) => {
+    const windows = BrowserWindow.getAllWindows()
+    windows.forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('batch-enqueue-progress', progress)
+      }
+    })
+  })
+
+  downloadManager.on('queue-completed', (summary: QueueCompletionSummary) => {
+    handleQueueCompleted(summary)
+  })
+
+  queueCompletionEvents.on('queue-completed', (event: QueueCompletedEvent) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('queue-completed', event)
+      }
+    })
+  })
+
+  queueCompletionEvents.on('action-cancelled', () => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('queue-completion-cancelled')
+      }
+    })
+  })
 }
 
 /**
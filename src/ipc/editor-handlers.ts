@@ -0,0 +1,1199 @@
+/**
+ * Editor IPC Handlers
+ * Handles project lifecycle, undoable project edits, markers, and export
+ */
+
+import { BrowserWindow, ipcMain } from 'electron'
+import { existsSync, mkdirSync, unlinkSync } from 'fs'
+import { basename, dirname, extname, isAbsolute, join, normalize } from 'path'
+import { createErrorResponse, createSuccessResponse } from '../types/api'
+import type {
+  AnalysisJobEvent,
+  AudioMergeOptions,
+  BatchExportProgress,
+  EditingTranscodeProgress,
+  Effect,
+  ExportJob,
+  Project,
+  ProjectCommand,
+  ProjectExportProgress,
+  ProjectExportSettings,
+  SceneAnalysisProgress,
+  SlideshowOptions,
+  TextProperties,
+} from '../types/project'
+import { type RemuxStreamSelection, isCompletedStatus } from '../types/download'
+import type { ProcessPriority } from '../types/system'
+import { IPC_CHANNELS } from './channels'
+import { Logger } from '../utils/logger'
+import {
+  EFFECT_DEFINITIONS,
+  REMUX_CONTAINERS,
+  getExportIncompatibility,
+  isAudioExportFormat,
+  isEditingTranscodeProfile,
+  isGifDither,
+  isRemuxContainer,
+  normalizeGifOptions,
+} from '../utils/ffmpeg-args'
+import { isProtectedOutputPath } from '../utils/output-file'
+import { PROCESS_PRIORITIES, isProcessPriority } from '../utils/process-priority'
+import { analysisPool, cancelAnalysisJobs, runAnalysisJob } from '../services/analysis-pool'
+import { getExportReports } from '../services/export-reports'
+import { ProjectExporter } from '../services/project-exporter'
+import { diffProjects } from '../services/project-diff'
+import { getMediaUrl, unregisterMedia } from '../services/media-server'
+import { getStoredDownloadById } from '../services/download-storage'
+import { getLibraryEntryByPath } from '../services/library'
+import { deleteAutosave, getAutosaveList } from '../services/project-autosave'
+import { getPreviewFrame } from '../services/preview-frames'
+import { ProjectManager } from '../services/project-manager'
+import {
+  cancelSceneAnalysis,
+  getSceneAnalysisStatus,
+  sceneAnalysisEvents,
+  startSceneAnalysis,
+} from '../services/scene-analysis'
+import { getOrTranscodeForEditing, isCachedTranscode, transcodeEvents } from '../services/transcode-cache'
+import { VideoProcessor } from '../services/video-processor'
+import { PlatformUtils } from '../utils/platform'
+import { ValidationUtils } from '../utils/validation'
+
+const logger = Logger.getInstance()
+const projectManager = ProjectManager.getInstance()
+const projectExporter = ProjectExporter.getInstance()
+const videoProcessor = VideoProcessor.getInstance()
+
+const PROJECT_FILE_EXTENSIONS = ['.clipy']
+const PREVIEW_IMAGE_EXTENSIONS = ['.jpg', '.jpeg', '.png']
+const MAX_EXTRACTED_FRAMES = 10000
+const MAX_SLIDESHOW_IMAGES = 1000
+
+/**
+ * Validate a project file path: absolute, no null bytes, .clipy extension
+ */
+function validateProjectPath(filePath: string): { isValid: boolean; error?: string; path?: string } {
+  if (!filePath || typeof filePath !== 'string' || filePath.includes('\0')) {
+    return { isValid: false, error: 'Invalid project path' }
+  }
+
+  const normalizedPath = normalize(filePath)
+  if (!isAbsolute(normalizedPath)) {
+    return { isValid: false, error: 'Project path must be absolute' }
+  }
+
+  const extValidation = ValidationUtils.validateFileExtension(normalizedPath, PROJECT_FILE_EXTENSIONS)
+  if (!extValidation.isValid) {
+    return { isValid: false, error: extValidation.error }
+  }
+
+  return { isValid: true, path: normalizedPath }
+}
+
+/**
+ * Setup project lifecycle handlers
+ */
+function setupProjectHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.EDITOR_PROJECT_CREATE, async (_event, name: string) => {
+    try {
+      return createSuccessResponse(projectManager.createProject(typeof name === 'string' ? name : ''))
+    } catch (error) {
+      logger.error('Failed to create project', error as Error)
+      return createErrorResponse('Failed to create project', 'PROJECT_CREATE_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_PROJECT_OPEN, async (_event, filePath: string) => {
+    try {
+      const validation = validateProjectPath(filePath)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid project path', 'INVALID_PATH')
+      }
+
+      const project = await projectManager.openProject(validation.path!)
+      return createSuccessResponse(project)
+    } catch (error) {
+      logger.error('Failed to open project', error as Error, { filePath })
+      return createErrorResponse(`Failed to open project: ${(error as Error).message}`, 'PROJECT_OPEN_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_PROJECT_SAVE, async (_event, projectId: string, filePath?: string) => {
+    try {
+      let targetPath: string | undefined
+      if (filePath) {
+        const validation = validateProjectPath(filePath)
+        if (!validation.isValid) {
+          return createErrorResponse(validation.error || 'Invalid project path', 'INVALID_PATH')
+        }
+        targetPath = validation.path
+      }
+
+      const project = await projectManager.saveProject(projectId, targetPath)
+      return createSuccessResponse(project)
+    } catch (error) {
+      logger.error('Failed to save project', error as Error, { projectId, filePath })
+      return createErrorResponse(`Failed to save project: ${(error as Error).message}`, 'PROJECT_SAVE_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_PROJECT_GET, async (_event, projectId: string) => {
+    try {
+      return createSuccessResponse(projectManager.getProject(projectId))
+    } catch (error) {
+      return createErrorResponse((error as Error).message, 'PROJECT_NOT_FOUND')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_PROJECT_CLOSE, async (_event, projectId: string) => {
+    projectManager.closeProject(projectId)
+    cancelAnalysisJobs(projectId)
+    // Sources only this project used can't be previewed anymore
+    unregisterMedia(isEditorMediaPath)
+    return createSuccessResponse(undefined)
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_GET_AUTOSAVES, async () => {
+    try {
+      return createSuccessResponse(getAutosaveList())
+    } catch (error) {
+      logger.error('Failed to list project autosaves', error as Error)
+      return createErrorResponse(`Failed to list autosaves: ${(error as Error).message}`, 'AUTOSAVE_LIST_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_DELETE_AUTOSAVE, async (_event, projectId: string) => {
+    try {
+      if (typeof projectId !== 'string') {
+        return createErrorResponse('Invalid project ID', 'INVALID_PARAMS')
+      }
+      deleteAutosave(projectId)
+      return createSuccessResponse(undefined)
+    } catch (error) {
+      logger.error('Failed to delete project autosave', error as Error, { projectId })
+      return createErrorResponse(`Failed to delete autosave: ${(error as Error).message}`, 'AUTOSAVE_DELETE_FAILED')
+    }
+  })
+}
+
+/**
+ * Setup undo/redo command handlers
+ */
+function setupCommandHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.EDITOR_EXECUTE_COMMAND, async (_event, projectId: string, command: ProjectCommand) => {
+    try {
+      if (!command || typeof command !== 'object' || typeof command.type !== 'string') {
+        return createErrorResponse('Invalid project command', 'INVALID_COMMAND')
+      }
+
+      return createSuccessResponse(projectManager.executeCommand(projectId, command))
+    } catch (error) {
+      logger.error('Failed to execute project command', error as Error, { projectId, type: command?.type })
+      return createErrorResponse(`Failed to execute command: ${(error as Error).message}`, 'COMMAND_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_UNDO, async (_event, projectId: string) => {
+    try {
+      return createSuccessResponse(projectManager.undo(projectId))
+    } catch (error) {
+      return createErrorResponse((error as Error).message, 'UNDO_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_REDO, async (_event, projectId: string) => {
+    try {
+      return createSuccessResponse(projectManager.redo(projectId))
+    } catch (error) {
+      return createErrorResponse((error as Error).message, 'REDO_FAILED')
+    }
+  })
+
+  // Split a clip at every detected scene cut within its source range
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_AUTO_SPLIT_CLIP,
+    async (_event, projectId: string, clipId: string, threshold?: number) => {
+      try {
+        const clip = projectManager.getClip(projectId, clipId)
+        if (clip.type !== 'video') {
+          return createErrorResponse('Only video clips can be split by scene', 'INVALID_CLIP_TYPE')
+        }
+
+        const scenes = await videoProcessor.detectSceneChanges(clip.sourcePath, threshold ?? 0.4, {
+          start: clip.sourceStart,
+          end: clip.sourceEnd,
+        })
+        const project = projectManager.splitClip(projectId, clipId, scenes.map(scene => scene.time))
+        return createSuccessResponse(project)
+      } catch (error) {
+        logger.error('Failed to auto-split clip', error as Error, { projectId, clipId })
+        return createErrorResponse(`Failed to split clip: ${(error as Error).message}`, 'AUTO_SPLIT_FAILED')
+      }
+    },
+  )
+
+  // Split a clip at chosen source times, e.g. scene cuts the user accepted
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_SPLIT_CLIP_AT_TIMES,
+    async (_event, projectId: string, clipId: string, times: number[]) => {
+      try {
+        if (!Array.isArray(times) || !times.every(time => typeof time === 'number' && Number.isFinite(time))) {
+          return createErrorResponse('Split times must be a list of numbers', 'INVALID_PARAMS')
+        }
+        return createSuccessResponse(projectManager.splitClip(projectId, clipId, times))
+      } catch (error) {
+        logger.error('Failed to split clip', error as Error, { projectId, clipId })
+        return createErrorResponse(`Failed to split clip: ${(error as Error).message}`, 'SPLIT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_IMPORT_LIBRARY_CLIP,
+    async (_event, projectId: string, libraryId: string, trackId: string, insertAtTime: number) => {
+      try {
+        if (typeof insertAtTime !== 'number' || !Number.isFinite(insertAtTime) || insertAtTime < 0) {
+          return createErrorResponse('Insert time must be a non-negative number', 'INVALID_PARAMS')
+        }
+        const entry = getStoredDownloadById(libraryId)
+        if (!isCompletedStatus(entry?.status) || !entry?.filePath || !existsSync(entry.filePath)) {
+          return createErrorResponse('Library video not found', 'FILE_NOT_FOUND')
+        }
+
+        const metadata = await videoProcessor.getVideoMetadata(entry.filePath)
+        if (!(metadata.duration > 0)) {
+          return createErrorResponse('Could not read the video duration', 'METADATA_FAILED')
+        }
+
+        // Cover art gives an audio file a width, so look at its streams
+        const audioOnly = await videoProcessor.isAudioOnlyFile(entry.filePath).catch(() => !metadata.width)
+        const track = projectManager.getProject(projectId).tracks.find(t => t.id === trackId)
+        const result = projectManager.insertSourceClip(
+          projectId,
+          trackId,
+          {
+            type: track?.kind === 'audio' || audioOnly ? 'audio' : 'video',
+            name: entry.title || basename(entry.filePath, extname(entry.filePath)),
+            sourcePath: entry.filePath,
+            duration: metadata.duration,
+          },
+          insertAtTime,
+        )
+
+        // Warm the thumbnail cache so the timeline strip shows up without waiting
+        runAnalysisJob(
+          'thumbnails',
+          signal => videoProcessor.generateTimelineThumbnails(entry.filePath!, { signal }),
+          { projectId, priority: 'low' },
+        ).catch(error => {
+          logger.warn('Failed to generate timeline thumbnails', { libraryId, error: (error as Error).message })
+        })
+
+        return createSuccessResponse(result)
+      } catch (error) {
+        logger.error('Failed to import library clip', error as Error, { projectId, libraryId, trackId })
+        return createErrorResponse(`Failed to import clip: ${(error as Error).message}`, 'IMPORT_FAILED')
+      }
+    },
+  )
+
+  // Add part of an audio file (e.g. an .mp3 or .m4a from the library) as an audio clip
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_ADD_AUDIO_CLIP,
+    async (
+      _event,
+      projectId: string,
+      trackId: string,
+      filePath: string,
+      startTime: number,
+      sourceStart?: number,
+      sourceEnd?: number,
+    ) => {
+      try {
+        const normalizedPath = typeof filePath === 'string' ? normalize(filePath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid audio path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+        if (!existsSync(normalizedPath)) {
+          return createErrorResponse('Audio file not found', 'FILE_NOT_FOUND')
+        }
+        if (typeof startTime !== 'number' || !Number.isFinite(startTime) || startTime < 0) {
+          return createErrorResponse('Start time must be a non-negative number', 'INVALID_PARAMS')
+        }
+        if (!(await videoProcessor.isAudioOnlyFile(normalizedPath))) {
+          return createErrorResponse('File is not an audio-only file', 'INVALID_FORMAT')
+        }
+
+        const metadata = await videoProcessor.getVideoMetadata(normalizedPath)
+        if (!(metadata.duration > 0)) {
+          return createErrorResponse('Could not read the audio duration', 'METADATA_FAILED')
+        }
+        const start = sourceStart ?? 0
+        const end = sourceEnd ?? metadata.duration
+        if (typeof start !== 'number' || typeof end !== 'number' || !(start >= 0 && end > start)) {
+          return createErrorResponse('Source range must start at 0 or later and end after it starts', 'INVALID_PARAMS')
+        }
+        if (end > metadata.duration + 0.001) {
+          return createErrorResponse('Source range ends after the audio does', 'INVALID_PARAMS')
+        }
+
+        const libraryEntry = getLibraryEntryByPath(normalizedPath)
+        const result = projectManager.insertSourceClip(
+          projectId,
+          trackId,
+          {
+            type: 'audio',
+            name: libraryEntry?.title || basename(normalizedPath, extname(normalizedPath)),
+            sourcePath: normalizedPath,
+            duration: metadata.duration,
+            sourceStart: start,
+            sourceEnd: Math.min(end, metadata.duration),
+          },
+          startTime,
+        )
+        return createSuccessResponse(result)
+      } catch (error) {
+        logger.error('Failed to add audio clip', error as Error, { projectId, trackId, filePath })
+        return createErrorResponse(`Failed to add audio clip: ${(error as Error).message}`, 'IMPORT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_UNDO_STACK_SIZE, async (_event, projectId: string) => {
+    try {
+      return createSuccessResponse(projectManager.getUndoRedoState(projectId))
+    } catch (error) {
+      return createErrorResponse((error as Error).message, 'PROJECT_NOT_FOUND')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_PROJECT_DIFF, async (_event, before: Project, after: Project) => {
+    try {
+      if (!Array.isArray(before?.tracks) || !Array.isArray(after?.tracks)) {
+        return createErrorResponse('Invalid project', 'INVALID_PROJECT')
+      }
+      return createSuccessResponse(diffProjects(before, after))
+    } catch (error) {
+      logger.error('Failed to diff projects', error as Error)
+      return createErrorResponse(`Failed to diff projects: ${(error as Error).message}`, 'PROJECT_DIFF_FAILED')
+    }
+  })
+}
+
+/**
+ * Setup marker handlers (clip and track chapter/cue points)
+ */
+function setupMarkerHandlers(): void {
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_MARKER_ADD,
+    async (_event, projectId: string, clipId: string, time: number, label: string, color?: string) => {
+      try {
+        if (typeof time !== 'number' || !isFinite(time)) {
+          return createErrorResponse('Invalid marker time', 'INVALID_MARKER')
+        }
+        return createSuccessResponse(projectManager.addMarker(projectId, clipId, time, label, color))
+      } catch (error) {
+        return createErrorResponse((error as Error).message, 'MARKER_ADD_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_MARKER_REMOVE,
+    async (_event, projectId: string, clipId: string, markerId: string) => {
+      try {
+        return createSuccessResponse(projectManager.removeMarker(projectId, clipId, markerId))
+      } catch (error) {
+        return createErrorResponse((error as Error).message, 'MARKER_REMOVE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_MARKER_UPDATE,
+    async (_event, projectId: string, clipId: string, markerId: string, label?: string, color?: string) => {
+      try {
+        return createSuccessResponse(projectManager.updateMarker(projectId, clipId, markerId, label, color))
+      } catch (error) {
+        return createErrorResponse((error as Error).message, 'MARKER_UPDATE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_TRACK_MARKER_ADD,
+    async (_event, projectId: string, trackId: string, time: number, label: string, color?: string) => {
+      try {
+        if (typeof time !== 'number' || !isFinite(time)) {
+          return createErrorResponse('Invalid marker time', 'INVALID_MARKER')
+        }
+        return createSuccessResponse(projectManager.addTrackMarker(projectId, trackId, time, label, color))
+      } catch (error) {
+        return createErrorResponse((error as Error).message, 'MARKER_ADD_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_TRACK_MARKER_REMOVE,
+    async (_event, projectId: string, trackId: string, markerId: string) => {
+      try {
+        return createSuccessResponse(projectManager.removeTrackMarker(projectId, trackId, markerId))
+      } catch (error) {
+        return createErrorResponse((error as Error).message, 'MARKER_REMOVE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_TRACK_MARKER_UPDATE,
+    async (_event, projectId: string, trackId: string, markerId: string, label?: string, color?: string) => {
+      try {
+        return createSuccessResponse(projectManager.updateTrackMarker(projectId, trackId, markerId, label, color))
+      } catch (error) {
+        return createErrorResponse((error as Error).message, 'MARKER_UPDATE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_MARKERS_GET, async (_event, projectId: string) => {
+    try {
+      return createSuccessResponse(projectManager.getMarkers(projectId))
+    } catch (error) {
+      return createErrorResponse((error as Error).message, 'PROJECT_NOT_FOUND')
+    }
+  })
+}
+
+/**
+ * Why export settings can't be used, with the error code; null when they can
+ */
+function getExportSettingsError(settings: ProjectExportSettings): { message: string; code: string } | null {
+  const outputPath = settings?.outputPath ? normalize(settings.outputPath) : ''
+  if (!outputPath || !isAbsolute(outputPath) || outputPath.includes('\0')) {
+    return { message: 'Invalid output path', code: 'INVALID_PATH' }
+  }
+  if (isProtectedOutputPath(outputPath)) {
+    return { message: 'Cannot export into the app temp or cache folders', code: 'INVALID_PATH' }
+  }
+  if (settings.audioOnly) {
+    const audioFormat = settings.audioFormat ?? 'mp3'
+    if (!isAudioExportFormat(audioFormat)) {
+      return { message: `Unsupported audio format: ${audioFormat}`, code: 'INCOMPATIBLE_EXPORT_SETTINGS' }
+    }
+    if (extname(outputPath).toLowerCase() !== `.${audioFormat}`) {
+      return { message: 'Output path extension does not match audio format', code: 'INVALID_PATH' }
+    }
+    const { audioBitrate } = settings
+    const bitrateValid = Number.isInteger(audioBitrate) && audioBitrate! >= 32 && audioBitrate! <= 512
+    if (audioBitrate !== undefined && !bitrateValid) {
+      return { message: 'Audio bitrate must be between 32 and 512 kbps', code: 'INVALID_PARAMS' }
+    }
+  } else {
+    // PNG sequences are written into a directory, so there's no extension to check
+    if (settings.format !== 'png-sequence' && extname(outputPath).toLowerCase() !== `.${settings.format}`) {
+      return { message: 'Output path extension does not match export format', code: 'INVALID_PATH' }
+    }
+    const incompatibility = getExportIncompatibility(settings)
+    if (incompatibility) {
+      return { message: incompatibility, code: 'INCOMPATIBLE_EXPORT_SETTINGS' }
+    }
+    if (settings.gif?.dither !== undefined && !isGifDither(settings.gif.dither)) {
+      return { message: `Unsupported GIF dither: ${settings.gif.dither}`, code: 'INVALID_PARAMS' }
+    }
+  }
+  return null
+}
+
+/**
+ * Export settings as passed on to the exporter: the output path normalized, and GIF fps and
+ * max width clamped to positive integers since they go into the filter graph as text
+ */
+function normalizeExportSettings(settings: ProjectExportSettings): ProjectExportSettings {
+  return {
+    ...settings,
+    outputPath: normalize(settings.outputPath),
+    gif: settings.gif ? normalizeGifOptions(settings.gif) : undefined,
+  }
+}
+
+/**
+ * Setup project export handlers and progress broadcasting
+ */
+function setupExportHandlers(): void {
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_EXPORT_PROJECT,
+    async (_event, projectId: string, settings: ProjectExportSettings) => {
+      try {
+        const settingsError = getExportSettingsError(settings)
+        if (settingsError) {
+          return createErrorResponse(settingsError.message, settingsError.code)
+        }
+
+        const project = projectManager.getProject(projectId)
+        const result = await projectExporter.exportProject(project, normalizeExportSettings(settings))
+        return createSuccessResponse(result)
+      } catch (error) {
+        logger.error('Failed to export project', error as Error, { projectId })
+        return createErrorResponse(`Failed to export project: ${(error as Error).message}`, 'EXPORT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_BATCH_EXPORT, async (_event, jobs: ExportJob[]) => {
+    try {
+      if (!Array.isArray(jobs) || jobs.length === 0) {
+        return createErrorResponse('No export jobs given', 'INVALID_PARAMS')
+      }
+      // Checked up front, so a bad job doesn't surface hours into an overnight batch
+      for (const job of jobs) {
+        if (typeof job?.id !== 'string' || typeof job.projectPath !== 'string' || !isAbsolute(job.projectPath)) {
+          return createErrorResponse('Each job needs an ID and an absolute project path', 'INVALID_PARAMS')
+        }
+        const settingsError = getExportSettingsError(job.settings)
+        if (settingsError) {
+          return createErrorResponse(`Job ${job.id}: ${settingsError.message}`, settingsError.code)
+        }
+      }
+
+      const normalized = jobs.map(job => ({
+        ...job,
+        projectPath: normalize(job.projectPath),
+        settings: normalizeExportSettings(job.settings),
+      }))
+      const results = await projectExporter.exportBatch(normalized, filePath =>
+        projectManager.readProjectFile(filePath),
+      )
+      return createSuccessResponse(results)
+    } catch (error) {
+      logger.error('Failed to run batch export', error as Error, { jobs: jobs?.length })
+      return createErrorResponse(`Failed to run batch export: ${(error as Error).message}`, 'EXPORT_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_GET_EXPORT_FORMATS, async () => {
+    try {
+      return createSuccessResponse(await projectExporter.getExportFormats())
+    } catch (error) {
+      logger.error('Failed to get export formats', error as Error)
+      return createErrorResponse('Failed to get export formats', 'EXPORT_FORMATS_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_CANCEL_EXPORT, async () => {
+    return createSuccessResponse({ cancelled: projectExporter.cancelExport() })
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_GET_EXPORT_REPORTS, async (_event, projectId: string) => {
+    if (typeof projectId !== 'string' || !projectId) {
+      return createErrorResponse('Invalid project ID', 'INVALID_PARAMS')
+    }
+    return createSuccessResponse(getExportReports(projectId))
+  })
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_RENDER_PREVIEW_FRAME,
+    async (_event, projectId: string, time: number, outputPath: string) => {
+      try {
+        if (typeof time !== 'number' || !Number.isFinite(time) || time < 0) {
+          return createErrorResponse('Time must be a non-negative number', 'INVALID_PARAMS')
+        }
+        const targetPath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+        if (!targetPath || !isAbsolute(targetPath) || targetPath.includes('\0')) {
+          return createErrorResponse('Invalid output path', 'INVALID_PATH')
+        }
+        if (!['.jpg', '.jpeg'].includes(extname(targetPath).toLowerCase())) {
+          return createErrorResponse('Preview frames are written as .jpg', 'INVALID_FORMAT')
+        }
+
+        mkdirSync(dirname(targetPath), { recursive: true })
+        await projectExporter.renderFrame(projectManager.getProject(projectId), time, targetPath)
+        return createSuccessResponse(undefined)
+      } catch (error) {
+        if ((error as Error).message === 'Preview render cancelled') {
+          return createErrorResponse('Preview render cancelled', 'CANCELLED')
+        }
+        logger.error('Failed to render preview frame', error as Error, { projectId, time })
+        return createErrorResponse(`Failed to render preview frame: ${(error as Error).message}`, 'PREVIEW_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_CANCEL_PREVIEW_RENDER, async () => {
+    return createSuccessResponse({ cancelled: projectExporter.cancelPreviewRender() })
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_SET_EXPORT_PRIORITY, async (_event, priority: ProcessPriority) => {
+    if (!isProcessPriority(priority)) {
+      return createErrorResponse(`Priority must be one of: ${PROCESS_PRIORITIES.join(', ')}`, 'INVALID_PARAMS')
+    }
+    return createSuccessResponse({ changed: projectExporter.setActiveExportPriority(priority) })
+  })
+
+  projectExporter.on('progress', (progress: ProjectExportProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('editor-export-progress', progress)
+      }
+    })
+  })
+
+  projectExporter.on('batch-progress', (progress: BatchExportProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('batch-export-progress', progress)
+      }
+    })
+  })
+}
+
+/**
+ * The renderer may only read media that is in the library, used by an open project, or an
+ * editing transcode of such media
+ */
+function isEditorMediaPath(normalizedPath: string): boolean {
+  return (
+    getLibraryEntryByPath(normalizedPath) !== null ||
+    projectManager.isOpenProjectSource(normalizedPath) ||
+    isCachedTranscode(normalizedPath)
+  )
+}
+
+/**
+ * Setup the preview media URL handler. Only library files and sources of open projects are served.
+ */
+function setupMediaHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.EDITOR_GET_MEDIA_URL, async (_event, filePath: string) => {
+    try {
+      const normalizedPath = typeof filePath === 'string' ? normalize(filePath) : ''
+      if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+        return createErrorResponse('Invalid media path', 'INVALID_PATH')
+      }
+
+      if (!isEditorMediaPath(normalizedPath)) {
+        logger.warn('Blocked media URL request for unregistered file', { filePath: normalizedPath })
+        return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+      }
+
+      return createSuccessResponse(await getMediaUrl(normalizedPath))
+    } catch (error) {
+      logger.error('Failed to get media URL', error as Error, { filePath })
+      return createErrorResponse(`Failed to get media URL: ${(error as Error).message}`, 'MEDIA_URL_FAILED')
+    }
+  })
+
+  // Grab a frame of the background video and draw the text over it, before adding a text clip
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_RENDER_TEXT_PREVIEW,
+    async (_event, backgroundVideoPath: string, timeOffset: number, text: TextProperties, outputPath?: string) => {
+      const tempDir = PlatformUtils.getInstance().getTempDir('clipy')
+      const framePath = join(tempDir, `text_background_${Date.now()}.jpg`)
+      try {
+        const normalizedPath = typeof backgroundVideoPath === 'string' ? normalize(backgroundVideoPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid background video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+        if (typeof timeOffset !== 'number' || !isFinite(timeOffset) || timeOffset < 0) {
+          return createErrorResponse('Time offset must be a non-negative number', 'INVALID_PARAMS')
+        }
+
+        const textValidation = ValidationUtils.validateTextProperties(text)
+        if (!textValidation.isValid) {
+          return createErrorResponse(textValidation.error || 'Invalid text properties', 'INVALID_PARAMS')
+        }
+
+        let previewPath = join(tempDir, `text_preview_${Date.now()}.jpg`)
+        if (outputPath !== undefined) {
+          previewPath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+          if (!previewPath || !isAbsolute(previewPath) || previewPath.includes('\0')) {
+            return createErrorResponse('Invalid output path', 'INVALID_PATH')
+          }
+          if (!PREVIEW_IMAGE_EXTENSIONS.includes(extname(previewPath).toLowerCase())) {
+            return createErrorResponse('Preview must be a .jpg or .png image', 'INVALID_PATH')
+          }
+        }
+
+        mkdirSync(tempDir, { recursive: true })
+        await videoProcessor.generatePreview(normalizedPath, timeOffset, framePath)
+        const result = await videoProcessor.renderTextPreview(framePath, textValidation.value!, previewPath)
+        return createSuccessResponse({ outputPath: result })
+      } catch (error) {
+        logger.error('Failed to render text preview', error as Error, { backgroundVideoPath, timeOffset })
+        return createErrorResponse(`Failed to render text preview: ${(error as Error).message}`, 'PREVIEW_FAILED')
+      } finally {
+        if (existsSync(framePath)) {
+          unlinkSync(framePath)
+        }
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_GET_VIDEO_STREAMS, async (_event, filePath: string) => {
+    try {
+      const normalizedPath = typeof filePath === 'string' ? normalize(filePath) : ''
+      if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+        return createErrorResponse('Invalid video path', 'INVALID_PATH')
+      }
+      if (!isEditorMediaPath(normalizedPath)) {
+        return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+      }
+      if (!existsSync(normalizedPath)) {
+        return createErrorResponse('Video file not found', 'FILE_NOT_FOUND')
+      }
+      return createSuccessResponse(await videoProcessor.getVideoStreams(normalizedPath))
+    } catch (error) {
+      logger.error('Failed to get video streams', error as Error, { filePath })
+      return createErrorResponse(`Failed to get video streams: ${(error as Error).message}`, 'VIDEO_INFO_FAILED')
+    }
+  })
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_EXTRACT_FRAMES,
+    async (_event, videoPath: string, outputDir: string, start: number, end: number, fps: number) => {
+      try {
+        const normalizedPath = typeof videoPath === 'string' ? normalize(videoPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+
+        const targetDir = typeof outputDir === 'string' ? normalize(outputDir) : ''
+        if (!targetDir || !isAbsolute(targetDir) || targetDir.includes('\0')) {
+          return createErrorResponse('Invalid output folder', 'INVALID_PATH')
+        }
+        if (isProtectedOutputPath(targetDir)) {
+          return createErrorResponse('Cannot export into the app temp or cache folders', 'INVALID_PATH')
+        }
+
+        // Unlike validateTimeRange, sub-second ranges are fine here
+        if (typeof start !== 'number' || typeof end !== 'number' || !(start >= 0) || !(end > start)) {
+          return createErrorResponse('Invalid time range', 'INVALID_PARAMS')
+        }
+        if (typeof fps !== 'number' || !isFinite(fps) || fps <= 0 || fps > 120) {
+          return createErrorResponse('Frame rate must be between 0 and 120', 'INVALID_PARAMS')
+        }
+        if ((end - start) * fps > MAX_EXTRACTED_FRAMES) {
+          return createErrorResponse(`At most ${MAX_EXTRACTED_FRAMES} frames can be extracted`, 'INVALID_PARAMS')
+        }
+
+        const frames = await videoProcessor.extractFrames(normalizedPath, targetDir, { start, end }, fps)
+        return createSuccessResponse(frames)
+      } catch (error) {
+        logger.error('Failed to extract frames', error as Error, { videoPath, outputDir })
+        return createErrorResponse(`Failed to extract frames: ${(error as Error).message}`, 'EXTRACT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_EXTRACT_SINGLE_FRAME,
+    async (_event, videoPath: string, time: number, outputPath: string) => {
+      try {
+        const normalizedPath = typeof videoPath === 'string' ? normalize(videoPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+        if (typeof time !== 'number' || !isFinite(time) || time < 0) {
+          return createErrorResponse('Time must be a non-negative number', 'INVALID_PARAMS')
+        }
+
+        const framePath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+        if (!framePath || !isAbsolute(framePath) || framePath.includes('\0')) {
+          return createErrorResponse('Invalid output path', 'INVALID_PATH')
+        }
+        if (!PREVIEW_IMAGE_EXTENSIONS.includes(extname(framePath).toLowerCase())) {
+          return createErrorResponse('Frame must be a .jpg or .png image', 'INVALID_PATH')
+        }
+        if (isProtectedOutputPath(framePath)) {
+          return createErrorResponse('Cannot export into the app temp or cache folders', 'INVALID_PATH')
+        }
+
+        mkdirSync(dirname(framePath), { recursive: true })
+        await videoProcessor.generatePreview(normalizedPath, time, framePath)
+        return createSuccessResponse(undefined)
+      } catch (error) {
+        logger.error('Failed to extract frame', error as Error, { videoPath, time })
+        return createErrorResponse(`Failed to extract frame: ${(error as Error).message}`, 'EXTRACT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_GET_PREVIEW_FRAME,
+    async (_event, videoPath: string, time: number, maxWidth: number) => {
+      try {
+        const normalizedPath = typeof videoPath === 'string' ? normalize(videoPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+        if (typeof time !== 'number' || !isFinite(time) || time < 0) {
+          return createErrorResponse('Time must be a non-negative number', 'INVALID_PARAMS')
+        }
+        if (typeof maxWidth !== 'number' || !Number.isInteger(maxWidth) || maxWidth < 16 || maxWidth > 3840) {
+          return createErrorResponse('Width must be a whole number between 16 and 3840', 'INVALID_PARAMS')
+        }
+
+        const frame = await getPreviewFrame(normalizedPath, time, maxWidth)
+        return createSuccessResponse(`data:image/jpeg;base64,${frame.toString('base64')}`)
+      } catch (error) {
+        logger.error('Failed to get preview frame', error as Error, { videoPath, time })
+        return createErrorResponse(`Failed to get preview frame: ${(error as Error).message}`, 'EXTRACT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_TRANSCODE_FOR_EDITING,
+    async (_event, inputPath: string, profile: string, projectId?: string) => {
+      try {
+        const normalizedPath = typeof inputPath === 'string' ? normalize(inputPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid input path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+        if (!existsSync(normalizedPath)) {
+          return createErrorResponse('Input file not found', 'FILE_NOT_FOUND')
+        }
+        if (!isEditingTranscodeProfile(profile)) {
+          return createErrorResponse('Unknown transcode profile', 'INVALID_PARAMS')
+        }
+
+        const result = await getOrTranscodeForEditing(normalizedPath, profile, {
+          projectId: typeof projectId === 'string' ? projectId : undefined,
+        })
+        return createSuccessResponse(result)
+      } catch (error) {
+        logger.error('Failed to transcode for editing', error as Error, { inputPath, profile })
+        return createErrorResponse(`Failed to transcode for editing: ${(error as Error).message}`, 'TRANSCODE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_DETECT_KEYFRAMES,
+    async (_event, videoPath: string, start: number, end: number) => {
+      try {
+        const normalizedPath = typeof videoPath === 'string' ? normalize(videoPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+        if (!existsSync(normalizedPath)) {
+          return createErrorResponse('Video file not found', 'FILE_NOT_FOUND')
+        }
+        if (typeof start !== 'number' || typeof end !== 'number' || !isFinite(end) || !(start >= 0 && end > start)) {
+          return createErrorResponse('Range must start at 0 or later and end after it starts', 'INVALID_PARAMS')
+        }
+
+        return createSuccessResponse(await videoProcessor.getKeyframes(normalizedPath, start, end))
+      } catch (error) {
+        logger.error('Failed to detect keyframes', error as Error, { videoPath, start, end })
+        return createErrorResponse(`Failed to detect keyframes: ${(error as Error).message}`, 'VIDEO_INFO_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_REMUX_VIDEO,
+    async (_event, inputPath: string, outputPath: string, streams?: RemuxStreamSelection) => {
+      try {
+        const normalizedPath = typeof inputPath === 'string' ? normalize(inputPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+
+        const targetPath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+        if (!targetPath || !isAbsolute(targetPath) || targetPath.includes('\0')) {
+          return createErrorResponse('Invalid output path', 'INVALID_PATH')
+        }
+        if (!isRemuxContainer(extname(targetPath).slice(1).toLowerCase())) {
+          return createErrorResponse(`Output must be one of: ${REMUX_CONTAINERS.join(', ')}`, 'INVALID_FORMAT')
+        }
+        if (isProtectedOutputPath(targetPath)) {
+          return createErrorResponse('Cannot export into the app temp or cache folders', 'INVALID_PATH')
+        }
+
+        const streamValidation = ValidationUtils.validateRemuxStreams(streams)
+        if (!streamValidation.isValid) {
+          return ValidationUtils.toErrorResponse(streamValidation, 'Invalid stream selection', 'INVALID_PARAMS')
+        }
+
+        // Never overwritten: an existing file (including the input) gets a ' (N)' suffixed name
+        const finalPath = await videoProcessor.remuxVideo(normalizedPath, targetPath, streamValidation.value)
+        return createSuccessResponse(finalPath)
+      } catch (error) {
+        logger.error('Failed to remux video', error as Error, { inputPath, outputPath })
+        return createErrorResponse(`Failed to remux video: ${(error as Error).message}`, 'REMUX_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_MERGE_AUDIO,
+    async (_event, videoPath: string, audioPath: string, outputPath: string, options?: AudioMergeOptions) => {
+      try {
+        const normalizedVideo = typeof videoPath === 'string' ? normalize(videoPath) : ''
+        if (!normalizedVideo || !isAbsolute(normalizedVideo) || normalizedVideo.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedVideo)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+
+        // The audio usually comes from elsewhere (a separate recording), so any existing file is accepted
+        const normalizedAudio = typeof audioPath === 'string' ? normalize(audioPath) : ''
+        if (!normalizedAudio || !isAbsolute(normalizedAudio) || normalizedAudio.includes('\0')) {
+          return createErrorResponse('Invalid audio path', 'INVALID_PATH')
+        }
+        if (!existsSync(normalizedAudio)) {
+          return createErrorResponse('Audio file not found', 'FILE_NOT_FOUND')
+        }
+
+        const targetPath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+        if (!targetPath || !isAbsolute(targetPath) || targetPath.includes('\0')) {
+          return createErrorResponse('Invalid output path', 'INVALID_PATH')
+        }
+        if (!isRemuxContainer(extname(targetPath).slice(1).toLowerCase())) {
+          return createErrorResponse(`Output must be one of: ${REMUX_CONTAINERS.join(', ')}`, 'INVALID_FORMAT')
+        }
+        if (isProtectedOutputPath(targetPath)) {
+          return createErrorResponse('Cannot export into the app temp or cache folders', 'INVALID_PATH')
+        }
+
+        const optionsValidation = ValidationUtils.validateAudioMergeOptions(options)
+        if (!optionsValidation.isValid) {
+          return ValidationUtils.toErrorResponse(optionsValidation, 'Invalid audio merge options', 'INVALID_PARAMS')
+        }
+
+        // Never overwritten: an existing file (including the inputs) gets a ' (N)' suffixed name
+        const finalPath = await videoProcessor.mergeAudioToVideo(
+          normalizedVideo,
+          normalizedAudio,
+          targetPath,
+          optionsValidation.value,
+        )
+        return createSuccessResponse(finalPath)
+      } catch (error) {
+        logger.error('Failed to merge audio into video', error as Error, { videoPath, audioPath, outputPath })
+        return createErrorResponse(`Failed to merge audio: ${(error as Error).message}`, 'MERGE_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_CREATE_SLIDESHOW,
+    async (_event, images: string[], outputPath: string, options?: SlideshowOptions) => {
+      try {
+        if (!Array.isArray(images) || images.length === 0 || images.length > MAX_SLIDESHOW_IMAGES) {
+          return createErrorResponse(`Between 1 and ${MAX_SLIDESHOW_IMAGES} images are required`, 'INVALID_PARAMS')
+        }
+        // Like merged audio, the images usually come from outside the library, so any existing file is accepted
+        const imagePaths: string[] = []
+        for (const image of images) {
+          const normalizedImage = typeof image === 'string' ? normalize(image) : ''
+          if (!normalizedImage || !isAbsolute(normalizedImage) || normalizedImage.includes('\0')) {
+            return createErrorResponse('Invalid image path', 'INVALID_PATH')
+          }
+          if (!existsSync(normalizedImage)) {
+            return createErrorResponse(`Image not found: ${normalizedImage}`, 'FILE_NOT_FOUND')
+          }
+          imagePaths.push(normalizedImage)
+        }
+
+        const targetPath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+        if (!targetPath || !isAbsolute(targetPath) || targetPath.includes('\0')) {
+          return createErrorResponse('Invalid output path', 'INVALID_PATH')
+        }
+        if (!isRemuxContainer(extname(targetPath).slice(1).toLowerCase())) {
+          return createErrorResponse(`Output must be one of: ${REMUX_CONTAINERS.join(', ')}`, 'INVALID_FORMAT')
+        }
+        if (isProtectedOutputPath(targetPath)) {
+          return createErrorResponse('Cannot export into the app temp or cache folders', 'INVALID_PATH')
+        }
+
+        const optionsValidation = ValidationUtils.validateSlideshowOptions(options)
+        if (!optionsValidation.isValid) {
+          return ValidationUtils.toErrorResponse(optionsValidation, 'Invalid slideshow options', 'INVALID_PARAMS')
+        }
+        const slideshowOptions = optionsValidation.value!
+        if (slideshowOptions.audioPath) {
+          const normalizedAudio = normalize(slideshowOptions.audioPath)
+          if (!isAbsolute(normalizedAudio) || normalizedAudio.includes('\0')) {
+            return createErrorResponse('Invalid audio path', 'INVALID_PATH')
+          }
+          if (!existsSync(normalizedAudio)) {
+            return createErrorResponse('Audio file not found', 'FILE_NOT_FOUND')
+          }
+          slideshowOptions.audioPath = normalizedAudio
+        }
+
+        // Never overwritten: an existing file gets a ' (N)' suffixed name
+        const finalPath = await videoProcessor.createSlideshow(imagePaths, targetPath, slideshowOptions)
+        return createSuccessResponse(finalPath)
+      } catch (error) {
+        logger.error('Failed to create slideshow', error as Error, { images: images?.length, outputPath })
+        return createErrorResponse(`Failed to create slideshow: ${(error as Error).message}`, 'SLIDESHOW_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_GET_AVAILABLE_EFFECTS, async () => {
+    return createSuccessResponse(EFFECT_DEFINITIONS)
+  })
+
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_APPLY_EFFECT_PREVIEW,
+    async (_event, videoPath: string, effect: Effect, outputPath: string) => {
+      try {
+        const normalizedPath = typeof videoPath === 'string' ? normalize(videoPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+
+        const targetPath = typeof outputPath === 'string' ? normalize(outputPath) : ''
+        if (!targetPath || !isAbsolute(targetPath) || targetPath.includes('\0')) {
+          return createErrorResponse('Invalid output path', 'INVALID_PATH')
+        }
+        if (extname(targetPath).toLowerCase() !== '.mp4') {
+          return createErrorResponse('Effect previews are written as .mp4', 'INVALID_FORMAT')
+        }
+        if (isProtectedOutputPath(targetPath)) {
+          return createErrorResponse('Cannot export into the app temp or cache folders', 'INVALID_PATH')
+        }
+
+        const effectValidation = ValidationUtils.validateEffect(effect)
+        if (!effectValidation.isValid) {
+          return ValidationUtils.toErrorResponse(effectValidation, 'Invalid effect', 'INVALID_PARAMS')
+        }
+
+        // Previews are re-rendered as the params change, so the same file is replaced each time
+        mkdirSync(dirname(targetPath), { recursive: true })
+        await videoProcessor.applyEffectPreview(normalizedPath, effectValidation.value!, targetPath, {
+          overwrite: true,
+        })
+        return createSuccessResponse(undefined)
+      } catch (error) {
+        logger.error('Failed to render effect preview', error as Error, { videoPath, outputPath })
+        return createErrorResponse(`Failed to render effect preview: ${(error as Error).message}`, 'PREVIEW_FAILED')
+      }
+    },
+  )
+}
+
+/**
+ * Setup background scene analysis handlers and progress broadcasting
+ */
+function setupSceneAnalysisHandlers(): void {
+  ipcMain.handle(
+    IPC_CHANNELS.EDITOR_ANALYZE_SCENES,
+    async (_event, videoPath: string, threshold?: number, projectId?: string) => {
+      try {
+        const normalizedPath = typeof videoPath === 'string' ? normalize(videoPath) : ''
+        if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+          return createErrorResponse('Invalid video path', 'INVALID_PATH')
+        }
+        if (!isEditorMediaPath(normalizedPath)) {
+          return createErrorResponse('File is not in the library or an open project', 'ACCESS_DENIED')
+        }
+        if (!existsSync(normalizedPath)) {
+          return createErrorResponse('Video file not found', 'FILE_NOT_FOUND')
+        }
+        if (threshold !== undefined && (typeof threshold !== 'number' || !(threshold > 0 && threshold < 1))) {
+          return createErrorResponse('Threshold must be between 0 and 1', 'INVALID_PARAMS')
+        }
+        const jobId = startSceneAnalysis(
+          normalizedPath,
+          threshold ?? 0.4,
+          typeof projectId === 'string' ? projectId : undefined,
+        )
+        return createSuccessResponse({ jobId })
+      } catch (error) {
+        logger.error('Failed to start scene analysis', error as Error, { videoPath })
+        return createErrorResponse(`Failed to analyze scenes: ${(error as Error).message}`, 'SCENE_DETECTION_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_SCENE_ANALYSIS_STATUS, async (_event, jobId: string) => {
+    const status = typeof jobId === 'string' ? getSceneAnalysisStatus(jobId) : null
+    if (!status) {
+      return createErrorResponse('Scene analysis not found', 'JOB_NOT_FOUND')
+    }
+    return createSuccessResponse(status)
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_SCENE_ANALYSIS_CANCEL, async (_event, jobId: string) => {
+    return createSuccessResponse({ cancelled: typeof jobId === 'string' && cancelSceneAnalysis(jobId) })
+  })
+
+  sceneAnalysisEvents.on('progress', (progress: SceneAnalysisProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('editor-scene-analysis-progress', progress)
+      }
+    })
+  })
+
+  ipcMain.handle(IPC_CHANNELS.EDITOR_CANCEL_ANALYSIS_JOBS, async (_event, projectId: string) => {
+    if (typeof projectId !== 'string' || !projectId) {
+      return createErrorResponse('Invalid project ID', 'INVALID_PARAMS')
+    }
+    return createSuccessResponse({ cancelled: cancelAnalysisJobs(projectId) })
+  })
+
+  transcodeEvents.on('progress', (progress: EditingTranscodeProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('editor-transcode-progress', progress)
+      }
+    })
+  })
+
+  analysisPool.on('job', (event: AnalysisJobEvent) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('editor-analysis-job', event)
+      }
+    })
+  })
+}
+
+/**
+ * Setup all editor handlers
+ */
+export function setupEditorHandlers(): void {
+  logger.info('Setting up editor IPC handlers')
+
+  setupProjectHandlers()
+  setupCommandHandlers()
+  setupMarkerHandlers()
+  setupExportHandlers()
+  setupMediaHandlers()
+  setupSceneAnalysisHandlers()
+
+  logger.info('Editor IPC handlers initialized')
+}
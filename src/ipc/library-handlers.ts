@@ -0,0 +1,524 @@
+/**
+ * Library IPC Handlers
+ * Handles queries and import/export over the library of completed downloads
+ */
+
+import { BrowserWindow, ipcMain, shell } from 'electron'
+import { dirname, extname, isAbsolute, normalize } from 'path'
+import { existsSync, statSync } from 'fs'
+import { createErrorResponse, createSuccessResponse } from '../types/api'
+import { IPC_CHANNELS } from './channels'
+import { ConfigManager } from '../utils/config'
+import { Logger } from '../utils/logger'
+import { ValidationUtils } from '../utils/validation'
+import {
+  downloadMissingThumbnails,
+  exportLibraryCsv,
+  exportLibraryCsvToFile,
+  getLibraryEntriesByFormat,
+  getLibraryEntryByUrl,
+  getLibraryFormats,
+  importLibraryCsv,
+  libraryEvents,
+} from '../services/library'
+import {
+  cancelLibraryConversion,
+  extractLibraryAudio,
+  getLibraryConversionStatus,
+  libraryConversionEvents,
+  remuxLibraryVideo,
+} from '../services/library-conversions'
+import { getChannels, getVideosByChannel } from '../services/channels'
+import { writeLibraryReport } from '../services/library-report'
+import { searchLibraryEntries } from '../services/library-search'
+import { listSavedPlaylists, saveSearchAsPlaylist } from '../services/library-playlists'
+import {
+  getContinueWatching,
+  getPlaybackPosition,
+  markWatched,
+  setPlaybackPosition,
+} from '../services/watch-history'
+import { AUDIO_EXTRACT_FORMATS, REMUX_CONTAINERS, isRemuxContainer } from '../utils/ffmpeg-args'
+import { libraryWatcherEvents, startLibraryWatcher, stopLibraryWatcher } from '../services/library-watcher'
+import { bulkJobEvents, cancelBulkJob, getBulkJobStatus } from '../services/bulk-jobs'
+import type {
+  AudioExtractFormat,
+  BulkJobProgress,
+  DownloadProgress,
+  LibraryConversionProgress,
+  RemuxStreamSelection,
+} from '../types/download'
+
+const logger = Logger.getInstance()
+
+/**
+ * Validate an export/import path: absolute, no null bytes, expected extension
+ */
+function validateLibraryFilePath(
+  filePath: string,
+  extension: string,
+): { isValid: boolean; error?: string; path?: string } {
+  if (!filePath || typeof filePath !== 'string' || filePath.includes('\0')) {
+    return { isValid: false, error: 'Invalid file path' }
+  }
+
+  const normalizedPath = normalize(filePath)
+  if (!isAbsolute(normalizedPath)) {
+    return { isValid: false, error: 'File path must be absolute' }
+  }
+  if (extname(normalizedPath).toLowerCase() !== extension) {
+    return { isValid: false, error: `File must have a ${extension} extension` }
+  }
+
+  return { isValid: true, path: normalizedPath }
+}
+
+/**
+ * Filter a renderer-supplied list of video IDs down to strings
+ */
+function sanitizeVideoIds(videoIds?: unknown): string[] | undefined {
+  if (!Array.isArray(videoIds)) return undefined
+  return videoIds.filter((id): id is string => typeof id === 'string' && id.length > 0)
+}
+
+/**
+ * Setup library query handlers
+ */
+function setupLibraryQueryHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_GET_FORMATS, async () => {
+    try {
+      return createSuccessResponse(getLibraryFormats())
+    } catch (error) {
+      logger.error('Failed to get library formats', error as Error)
+      return createErrorResponse('Failed to get library formats', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_GET_BY_FORMAT, async (_event, format: string) => {
+    try {
+      if (typeof format !== 'string' || !/^\.?[\w-]{1,16}$/.test(format.trim())) {
+        return createErrorResponse('Invalid format', 'INVALID_FORMAT')
+      }
+      return createSuccessResponse(getLibraryEntriesByFormat(format))
+    } catch (error) {
+      logger.error('Failed to get library videos by format', error as Error, { format })
+      return createErrorResponse('Failed to get library videos', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_SEARCH, async (_event, query: string, limit?: number) => {
+    try {
+      if (typeof query !== 'string' || query.length > 500) {
+        return createErrorResponse('Invalid search query', 'INVALID_PARAMS')
+      }
+      if (limit !== undefined && (typeof limit !== 'number' || !Number.isInteger(limit) || limit < 1)) {
+        return createErrorResponse('Limit must be a positive whole number', 'INVALID_PARAMS')
+      }
+      return createSuccessResponse(searchLibraryEntries(query, limit))
+    } catch (error) {
+      logger.error('Failed to search library', error as Error, { query })
+      return createErrorResponse('Failed to search library', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_GET_CHANNELS, async () => {
+    try {
+      return createSuccessResponse(getChannels())
+    } catch (error) {
+      logger.error('Failed to get library channels', error as Error)
+      return createErrorResponse('Failed to get library channels', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_GET_BY_CHANNEL, async (_event, channelId: string) => {
+    try {
+      if (typeof channelId !== 'string' || !/^[\w-]{1,64}$/.test(channelId)) {
+        return createErrorResponse('Invalid channel ID', 'INVALID_CHANNEL')
+      }
+      return createSuccessResponse(getVideosByChannel(channelId))
+    } catch (error) {
+      logger.error('Failed to get library videos by channel', error as Error, { channelId })
+      return createErrorResponse('Failed to get library videos', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+
+  // Lets the UI warn before downloading a video whose file is still in the library
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_CHECK_ALREADY_DOWNLOADED, async (_event, url: string) => {
+    try {
+      const urlValidation = ValidationUtils.validateUrl(url)
+      if (!urlValidation.isValid) {
+        return ValidationUtils.toErrorResponse(urlValidation, 'Invalid URL', 'INVALID_URL')
+      }
+      const entry = getLibraryEntryByUrl(urlValidation.value!)
+      return createSuccessResponse(entry?.filePath && existsSync(entry.filePath) ? entry : null)
+    } catch (error) {
+      logger.error('Failed to check library for URL', error as Error, { url })
+      return createErrorResponse('Failed to check library', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+}
+
+/**
+ * Setup watch history handlers
+ */
+function setupWatchHistoryHandlers(): void {
+  ipcMain.handle(
+    IPC_CHANNELS.LIBRARY_SET_PLAYBACK_POSITION,
+    async (_event, downloadId: string, seconds: number, duration?: number) => {
+      try {
+        const validation = ValidationUtils.validateDownloadId(downloadId)
+        if (!validation.isValid) {
+          return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+        }
+        if (typeof seconds !== 'number' || !Number.isFinite(seconds) || seconds < 0) {
+          return createErrorResponse('Position must be a non-negative number of seconds', 'INVALID_PARAMS')
+        }
+        const validDuration = typeof duration === 'number' && Number.isFinite(duration) && duration > 0
+        return createSuccessResponse(setPlaybackPosition(downloadId, seconds, validDuration ? duration : undefined))
+      } catch (error) {
+        logger.error('Failed to save playback position', error as Error, { downloadId })
+        return createErrorResponse(`Failed to save playback position: ${(error as Error).message}`, 'PLAYBACK_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_GET_PLAYBACK_POSITION, async (_event, downloadId: string) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+      return createSuccessResponse(getPlaybackPosition(downloadId))
+    } catch (error) {
+      logger.error('Failed to get playback position', error as Error, { downloadId })
+      return createErrorResponse('Failed to get playback position', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_MARK_WATCHED, async (_event, downloadId: string, watched: boolean) => {
+    try {
+      const validation = ValidationUtils.validateDownloadId(downloadId)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid download ID', 'INVALID_DOWNLOAD_ID')
+      }
+      return createSuccessResponse(markWatched(downloadId, watched === true))
+    } catch (error) {
+      logger.error('Failed to mark video watched', error as Error, { downloadId, watched })
+      return createErrorResponse(`Failed to mark video watched: ${(error as Error).message}`, 'PLAYBACK_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_GET_CONTINUE_WATCHING, async (_event, limit?: number) => {
+    try {
+      const validLimit = typeof limit === 'number' && Number.isInteger(limit) && limit > 0 ? Math.min(limit, 100) : 20
+      return createSuccessResponse(getContinueWatching(validLimit))
+    } catch (error) {
+      logger.error('Failed to get continue watching list', error as Error)
+      return createErrorResponse('Failed to get continue watching list', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+}
+
+/**
+ * Setup library export handlers
+ */
+function setupLibraryExportHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_EXPORT_CSV, async (_event, videoIds?: string[]) => {
+    try {
+      return createSuccessResponse(exportLibraryCsv(sanitizeVideoIds(videoIds)))
+    } catch (error) {
+      logger.error('Failed to export library as CSV', error as Error)
+      return createErrorResponse('Failed to export library', 'LIBRARY_EXPORT_FAILED')
+    }
+  })
+
+  ipcMain.handle(
+    IPC_CHANNELS.LIBRARY_EXPORT_CSV_TO_FILE,
+    async (_event, outputPath: string, videoIds?: string[]) => {
+      try {
+        const validation = validateLibraryFilePath(outputPath, '.csv')
+        if (!validation.isValid) {
+          return createErrorResponse(validation.error || 'Invalid output path', 'INVALID_PATH')
+        }
+        if (!existsSync(dirname(validation.path!))) {
+          return createErrorResponse('Output directory does not exist', 'INVALID_PATH')
+        }
+
+        const bytesWritten = exportLibraryCsvToFile(validation.path!, sanitizeVideoIds(videoIds))
+        return createSuccessResponse({ outputPath: validation.path!, bytesWritten })
+      } catch (error) {
+        logger.error('Failed to write library CSV', error as Error, { outputPath })
+        return createErrorResponse(`Failed to export library: ${(error as Error).message}`, 'LIBRARY_EXPORT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_GENERATE_REPORT, async (_event, outputPath: string) => {
+    try {
+      const validation = validateLibraryFilePath(outputPath, '.html')
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid output path', 'INVALID_PATH')
+      }
+      if (!existsSync(dirname(validation.path!))) {
+        return createErrorResponse('Output directory does not exist', 'INVALID_PATH')
+      }
+
+      const bytesWritten = writeLibraryReport(validation.path!)
+      const openError = await shell.openPath(validation.path!)
+      if (openError) {
+        logger.warn('Failed to open library report', { outputPath: validation.path, error: openError })
+      }
+      return createSuccessResponse({ outputPath: validation.path!, bytesWritten })
+    } catch (error) {
+      logger.error('Failed to write library report', error as Error, { outputPath })
+      return createErrorResponse(`Failed to generate report: ${(error as Error).message}`, 'LIBRARY_EXPORT_FAILED')
+    }
+  })
+
+  ipcMain.handle(
+    IPC_CHANNELS.LIBRARY_SAVE_SEARCH_PLAYLIST,
+    async (_event, query: string, name: string, outputPath?: string) => {
+      try {
+        if (typeof query !== 'string' || typeof name !== 'string' || !name.trim() || name.length > 200) {
+          return createErrorResponse('A search and a playlist name are required', 'INVALID_PARAMS')
+        }
+        let targetPath: string | undefined
+        if (outputPath !== undefined) {
+          const validation = validateLibraryFilePath(outputPath, '.m3u')
+          if (!validation.isValid) {
+            return createErrorResponse(validation.error || 'Invalid output path', 'INVALID_PATH')
+          }
+          if (!existsSync(dirname(validation.path!))) {
+            return createErrorResponse('Output directory does not exist', 'INVALID_PATH')
+          }
+          targetPath = validation.path
+        }
+
+        return createSuccessResponse(saveSearchAsPlaylist(query, name, targetPath))
+      } catch (error) {
+        logger.error('Failed to save library search as playlist', error as Error, { query, outputPath })
+        return createErrorResponse(`Failed to save playlist: ${(error as Error).message}`, 'LIBRARY_EXPORT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_LIST_PLAYLISTS, async () => {
+    try {
+      return createSuccessResponse(listSavedPlaylists())
+    } catch (error) {
+      logger.error('Failed to list saved playlists', error as Error)
+      return createErrorResponse('Failed to list saved playlists', 'LIBRARY_QUERY_FAILED')
+    }
+  })
+}
+
+/**
+ * Setup library import handlers
+ */
+function setupLibraryImportHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_IMPORT_CSV, async (_event, csvPath: string, skipMissingFiles?: boolean) => {
+    try {
+      const validation = validateLibraryFilePath(csvPath, '.csv')
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid CSV path', 'INVALID_PATH')
+      }
+      if (!existsSync(validation.path!)) {
+        return createErrorResponse('CSV file not found', 'FILE_NOT_FOUND')
+      }
+
+      const result = importLibraryCsv(validation.path!, skipMissingFiles === true)
+      logger.info('Library CSV imported', {
+        csvPath,
+        imported: result.imported,
+        skipped: result.skipped,
+        errors: result.errors.length,
+      })
+      return createSuccessResponse(result)
+    } catch (error) {
+      logger.error('Failed to import library CSV', error as Error, { csvPath })
+      return createErrorResponse(`Failed to import library: ${(error as Error).message}`, 'LIBRARY_IMPORT_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_DOWNLOAD_MISSING_THUMBNAILS, async () => {
+    try {
+      // Each saved thumbnail is announced with 'library-video-updated'
+      return createSuccessResponse(await downloadMissingThumbnails())
+    } catch (error) {
+      logger.error('Failed to download missing thumbnails', error as Error)
+      return createErrorResponse('Failed to download missing thumbnails', 'THUMBNAIL_DOWNLOAD_FAILED')
+    }
+  })
+}
+
+/**
+ * Setup download folder watcher handlers and broadcast auto-imported videos
+ */
+function setupLibraryWatcherHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_START_WATCHER, async (_event, folderPath: string) => {
+    try {
+      if (!ConfigManager.getInstance().get('download').watchDownloadFolder) {
+        return createErrorResponse('Watching the download folder is disabled in settings', 'WATCHER_DISABLED')
+      }
+
+      const normalizedPath = typeof folderPath === 'string' ? normalize(folderPath) : ''
+      if (!normalizedPath || !isAbsolute(normalizedPath) || normalizedPath.includes('\0')) {
+        return createErrorResponse('Invalid folder path', 'INVALID_PATH')
+      }
+      if (!existsSync(normalizedPath) || !statSync(normalizedPath).isDirectory()) {
+        return createErrorResponse('Folder not found', 'FILE_NOT_FOUND')
+      }
+
+      startLibraryWatcher(normalizedPath)
+      return createSuccessResponse(undefined)
+    } catch (error) {
+      logger.error('Failed to start library watcher', error as Error, { folderPath })
+      return createErrorResponse(`Failed to watch folder: ${(error as Error).message}`, 'WATCHER_FAILED')
+    }
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_STOP_WATCHER, async () => {
+    stopLibraryWatcher()
+    return createSuccessResponse(undefined)
+  })
+
+  libraryWatcherEvents.on('video-added', (entry: DownloadProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('library-video-added', entry)
+      }
+    })
+  })
+}
+
+/**
+ * Broadcast library entries once they are complete (probed, fingerprinted, thumbnailed)
+ */
+function setupLibraryEventForwarding(): void {
+  libraryEvents.on('video-updated', (entry: DownloadProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('library-video-updated', entry)
+      }
+    })
+  })
+}
+
+/**
+ * Setup handlers that convert library videos into new library entries, and progress broadcasting
+ */
+function setupLibraryConversionHandlers(): void {
+  ipcMain.handle(
+    IPC_CHANNELS.LIBRARY_REMUX_VIDEO,
+    async (_event, downloadId: string, container: string, streams?: RemuxStreamSelection) => {
+      try {
+        const idValidation = ValidationUtils.validateDownloadId(downloadId)
+        if (!idValidation.isValid) {
+          return ValidationUtils.toErrorResponse(idValidation, 'Invalid video ID', 'INVALID_ID')
+        }
+
+        const format = typeof container === 'string' ? container.trim().replace(/^\./, '').toLowerCase() : ''
+        if (!isRemuxContainer(format)) {
+          return createErrorResponse(`Format must be one of: ${REMUX_CONTAINERS.join(', ')}`, 'INVALID_FORMAT')
+        }
+
+        const streamValidation = ValidationUtils.validateRemuxStreams(streams)
+        if (!streamValidation.isValid) {
+          return ValidationUtils.toErrorResponse(streamValidation, 'Invalid stream selection', 'INVALID_PARAMS')
+        }
+
+        return createSuccessResponse({ jobId: remuxLibraryVideo(idValidation.value!, format, streamValidation.value) })
+      } catch (error) {
+        logger.error('Failed to remux library video', error as Error, { downloadId, container })
+        return createErrorResponse(`Failed to remux video: ${(error as Error).message}`, 'REMUX_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(
+    IPC_CHANNELS.LIBRARY_EXTRACT_AUDIO,
+    async (_event, downloadId: string, format: AudioExtractFormat, bitrate?: number) => {
+      try {
+        const idValidation = ValidationUtils.validateDownloadId(downloadId)
+        if (!idValidation.isValid) {
+          return ValidationUtils.toErrorResponse(idValidation, 'Invalid video ID', 'INVALID_ID')
+        }
+        if (typeof format !== 'string' || !Object.hasOwn(AUDIO_EXTRACT_FORMATS, format)) {
+          const formats = Object.keys(AUDIO_EXTRACT_FORMATS).join(', ')
+          return createErrorResponse(`Format must be one of: ${formats}`, 'INVALID_FORMAT')
+        }
+        if (bitrate !== undefined && (!Number.isInteger(bitrate) || bitrate < 32 || bitrate > 320)) {
+          return createErrorResponse('Bitrate must be between 32 and 320 kbps', 'INVALID_PARAMS')
+        }
+
+        return createSuccessResponse({ jobId: extractLibraryAudio(idValidation.value!, format, bitrate) })
+      } catch (error) {
+        logger.error('Failed to extract library audio', error as Error, { downloadId, format })
+        return createErrorResponse(`Failed to extract audio: ${(error as Error).message}`, 'EXTRACT_FAILED')
+      }
+    },
+  )
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_CONVERSION_STATUS, async (_event, jobId: string) => {
+    const status = typeof jobId === 'string' ? getLibraryConversionStatus(jobId) : null
+    if (!status) {
+      return createErrorResponse('Conversion not found', 'JOB_NOT_FOUND')
+    }
+    return createSuccessResponse(status)
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_CONVERSION_CANCEL, async (_event, jobId: string) => {
+    return createSuccessResponse({ cancelled: typeof jobId === 'string' && cancelLibraryConversion(jobId) })
+  })
+
+  libraryConversionEvents.on('progress', (progress: LibraryConversionProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('library-conversion-progress', progress)
+      }
+    })
+  })
+}
+
+/**
+ * Setup bulk job status/cancel handlers and progress broadcasting
+ */
+function setupBulkJobHandlers(): void {
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_BULK_JOB_STATUS, async (_event, jobId: string) => {
+    const status = typeof jobId === 'string' ? getBulkJobStatus(jobId) : null
+    if (!status) {
+      return createErrorResponse('Bulk job not found', 'JOB_NOT_FOUND')
+    }
+    return createSuccessResponse(status)
+  })
+
+  ipcMain.handle(IPC_CHANNELS.LIBRARY_BULK_JOB_CANCEL, async (_event, jobId: string) => {
+    return createSuccessResponse({ cancelled: typeof jobId === 'string' && cancelBulkJob(jobId) })
+  })
+
+  bulkJobEvents.on('progress', (progress: BulkJobProgress) => {
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('library-bulk-progress', progress)
+      }
+    })
+  })
+}
+
+/**
+ * Setup all library handlers
+ */
+export function setupLibraryHandlers(): void {
+  logger.info('Setting up library IPC handlers')
+
+  setupLibraryQueryHandlers()
+  setupWatchHistoryHandlers()
+  setupLibraryExportHandlers()
+  setupLibraryImportHandlers()
+  setupLibraryWatcherHandlers()
+  setupLibraryConversionHandlers()
+  setupBulkJobHandlers()
+  setupLibraryEventForwarding()
+
+  logger.info('Library IPC handlers initialized')
+}
@@ -8,11 +8,15 @@ import { createErrorResponse, createSuccessResponse } from '../types/api'
 import { IPC_CHANNELS } from './channels'
 import { Logger } from '../utils/logger'
 import { PlatformUtils } from '../utils/platform'
+import { applyFFmpegPriority, withFFmpegThreadLimit } from '../utils/process-priority'
 import { VideoProcessor, TimeRange, ProcessingOptions } from '../services/video-processor'
 import { spawn } from 'child_process'
-import { existsSync } from 'fs'
+import { existsSync, readFileSync, writeFileSync } from 'fs'
 import { join, dirname, basename, extname, normalize, isAbsolute, resolve } from 'path'
 import { FileSystemUtils } from '../utils/file-system'
+import { getMediaArtifactDir } from '../services/media-cache'
+import { type AnalysisJobOptions, isAnalysisJobPriority, runAnalysisJob } from '../services/analysis-pool'
+import type { AnalysisJobPriority } from '../types/project'
 
 const logger = Logger.getInstance()
 const videoProcessor = VideoProcessor.getInstance()
@@ -117,11 +121,25 @@ export interface ThumbnailOptions {
   count?: number
   interval?: number // seconds between thumbnails
   width?: number
+  projectId?: string // Cancelled with the project's other analysis jobs when it closes
+  priority?: AnalysisJobPriority
 }
 
 export interface WaveformOptions {
   inputPath: string
   samples?: number // number of samples to return
+  projectId?: string
+  priority?: AnalysisJobPriority
+}
+
+/**
+ * Analysis pool options from renderer-supplied values
+ */
+function getAnalysisJobOptions(options: { projectId?: unknown; priority?: unknown }): AnalysisJobOptions {
+  return {
+    projectId: typeof options.projectId === 'string' ? options.projectId : undefined,
+    priority: isAnalysisJobPriority(options.priority) ? options.priority : undefined,
+  }
 }
 
 /**
@@ -232,6 +250,26 @@ export function setupVideoHandlers(): void {
     }
   })
 
+  // Detect scene cuts
+  ipcMain.handle(IPC_CHANNELS.VIDEO_DETECT_SCENES, async (_event, filePath: string, threshold?: number) => {
+    try {
+      const validation = validateVideoPath(filePath)
+      if (!validation.isValid) {
+        return createErrorResponse(validation.error || 'Invalid file path', 'INVALID_PATH')
+      }
+
+      if (!existsSync(validation.path!)) {
+        return createErrorResponse('Video file not found', 'FILE_NOT_FOUND')
+      }
+
+      const scenes = await videoProcessor.detectSceneChanges(validation.path!, threshold ?? 0.4)
+      return createSuccessResponse(scenes)
+    } catch (error) {
+      logger.error('Failed to detect scene changes', error as Error, { filePath, threshold })
+      return createErrorResponse(`Failed to detect scenes: ${(error as Error).message}`, 'SCENE_DETECTION_FAILED')
+    }
+  })
+
   // Generate preview/thumbnail
   ipcMain.handle(IPC_CHANNELS.VIDEO_PREVIEW, async (_event, inputPath: string, timePosition: number) => {
     try {
@@ -268,68 +306,13 @@ export function setupVideoHandlers(): void {
         return createErrorResponse('Video file not found', 'FILE_NOT_FOUND')
       }
 
-      // Get video duration first
-      const metadata = await videoProcessor.getVideoMetadata(validation.path!)
-      const duration = metadata.duration
-
-      // Calculate interval if not provided
-      const thumbInterval = interval || duration / count
-
-      // Generate output directory
-      const thumbDir = outputDir || join(dirname(validation.path!), '.thumbnails')
-      await fileSystem.ensureDirectory(thumbDir)
-
-      const thumbnails: string[] = []
-      const ffmpegPath = getFFmpegPath()
-
-      // Generate thumbnails at intervals
-      for (let i = 0; i < count; i++) {
-        const time = Math.min(i * thumbInterval, duration - 0.1)
-        const outputPath = join(thumbDir, `thumb_${i.toString().padStart(3, '0')}.jpg`)
-
-        await new Promise<void>((resolve, reject) => {
-          const args = [
-            '-ss',
-            time.toString(),
-            '-i',
-            validation.path!,
-            '-vframes',
-            '1',
-            '-vf',
-            `scale=${width}:-1`,
-            '-q:v',
-            '3',
-            '-y',
-            outputPath,
-          ]
-
-          const ffmpeg = spawn(ffmpegPath, args, { stdio: ['pipe', 'pipe', 'pipe'] })
-
-          ffmpeg.on('close', code => {
-            if (code === 0) {
-              thumbnails.push(outputPath)
-              resolve()
-            } else {
-              // Don't fail entirely if one thumbnail fails
-              logger.warn('Failed to generate thumbnail', { time, code })
-              resolve()
-            }
-          })
-
-          ffmpeg.on('error', err => {
-            logger.warn('FFmpeg error generating thumbnail', { error: err.message })
-            resolve()
-          })
-        })
-      }
-
-      logger.info('Thumbnails generated', { count: thumbnails.length, inputPath: validation.path })
-
-      return createSuccessResponse({
-        thumbnails,
-        interval: thumbInterval,
-        duration,
-      })
+      const result = await runAnalysisJob(
+        'thumbnails',
+        signal =>
+          videoProcessor.generateTimelineThumbnails(validation.path!, { outputDir, count, interval, width, signal }),
+        getAnalysisJobOptions(options),
+      )
+      return createSuccessResponse(result)
     } catch (error) {
       logger.error('Failed to generate thumbnails', error as Error, { options })
       return createErrorResponse(`Failed to generate thumbnails: ${(error as Error).message}`, 'THUMBNAILS_FAILED')
@@ -351,69 +334,90 @@ export function setupVideoHandlers(): void {
         return createErrorResponse('Video file not found', 'FILE_NOT_FOUND')
       }
 
+      const cachePath = join(getMediaArtifactDir(validation.path!), `waveform_${samples}.json`)
+      if (existsSync(cachePath)) {
+        try {
+          const waveform: number[] = JSON.parse(readFileSync(cachePath, 'utf8'))
+          return createSuccessResponse({ waveform, samples: waveform.length })
+        } catch {
+          // Corrupt cache file: extract again
+        }
+      }
+
       const ffmpegPath = getFFmpegPath()
+      let extracted = false
 
       // Use FFmpeg to extract audio peaks
       // This outputs raw audio samples that we'll analyze
-      const waveformData = await new Promise<number[]>((resolve, reject) => {
-        const args = [
-          '-i',
-          validation.path!,
-          '-ac',
-          '1', // Convert to mono
-          '-filter:a',
-          `aresample=8000,asetnsamples=n=${samples}`, // Resample and limit samples
-          '-f',
-          's16le', // 16-bit signed little-endian
-          '-acodec',
-          'pcm_s16le',
-          'pipe:1', // Output to stdout
-        ]
-
-        const ffmpeg = spawn(ffmpegPath, args, {
-          stdio: ['pipe', 'pipe', 'pipe'],
-        })
-
-        const chunks: Buffer[] = []
+      const extractPeaks = (signal: AbortSignal) =>
+        new Promise<number[]>((resolve, reject) => {
+          const args = [
+            '-i',
+            validation.path!,
+            '-ac',
+            '1', // Convert to mono
+            '-filter:a',
+            `aresample=8000,asetnsamples=n=${samples}`, // Resample and limit samples
+            '-f',
+            's16le', // 16-bit signed little-endian
+            '-acodec',
+            'pcm_s16le',
+            'pipe:1', // Output to stdout
+          ]
 
-        ffmpeg.stdout?.on('data', (data: Buffer) => {
-          chunks.push(data)
-        })
+          const ffmpeg = spawn(ffmpegPath, withFFmpegThreadLimit(args), {
+            stdio: ['pipe', 'pipe', 'pipe'],
+          })
+          applyFFmpegPriority(ffmpeg.pid)
+          signal.addEventListener('abort', () => ffmpeg.kill('SIGTERM'))
 
-        ffmpeg.on('close', code => {
-          if (code === 0 || chunks.length > 0) {
-            const buffer = Buffer.concat(chunks)
-            const peaks: number[] = []
+          const chunks: Buffer[] = []
 
-            // Convert 16-bit samples to normalized peaks
-            const samplesPerPeak = Math.max(1, Math.floor(buffer.length / 2 / samples))
+          ffmpeg.stdout?.on('data', (data: Buffer) => {
+            chunks.push(data)
+          })
 
-            for (let i = 0; i < samples && i * samplesPerPeak * 2 < buffer.length; i++) {
-              let max = 0
-              for (let j = 0; j < samplesPerPeak && (i * samplesPerPeak + j) * 2 + 1 < buffer.length; j++) {
-                const offset = (i * samplesPerPeak + j) * 2
-                const sample = buffer.readInt16LE(offset)
-                max = Math.max(max, Math.abs(sample))
+          ffmpeg.on('close', code => {
+            if (signal.aborted) {
+              reject(new Error('Waveform extraction cancelled'))
+            } else if (code === 0 || chunks.length > 0) {
+              const buffer = Buffer.concat(chunks)
+              const peaks: number[] = []
+
+              // Convert 16-bit samples to normalized peaks
+              const samplesPerPeak = Math.max(1, Math.floor(buffer.length / 2 / samples))
+
+              for (let i = 0; i < samples && i * samplesPerPeak * 2 < buffer.length; i++) {
+                let max = 0
+                for (let j = 0; j < samplesPerPeak && (i * samplesPerPeak + j) * 2 + 1 < buffer.length; j++) {
+                  const offset = (i * samplesPerPeak + j) * 2
+                  const sample = buffer.readInt16LE(offset)
+                  max = Math.max(max, Math.abs(sample))
+                }
+                // Normalize to 0-1 range
+                peaks.push(max / 32768)
               }
-              // Normalize to 0-1 range
-              peaks.push(max / 32768)
+
+              extracted = true
+              resolve(peaks)
+            } else {
+              // Return empty waveform if extraction fails
+              logger.warn('Waveform extraction returned non-zero', { code })
+              resolve(Array(samples).fill(0.1))
             }
+          })
 
-            resolve(peaks)
-          } else {
-            // Return empty waveform if extraction fails
-            logger.warn('Waveform extraction returned non-zero', { code })
+          ffmpeg.on('error', err => {
+            logger.warn('FFmpeg error extracting waveform', { error: err.message })
             resolve(Array(samples).fill(0.1))
-          }
-        })
-
-        ffmpeg.on('error', err => {
-          logger.warn('FFmpeg error extracting waveform', { error: err.message })
-          resolve(Array(samples).fill(0.1))
+          })
         })
-      })
+      const waveformData = await runAnalysisJob('waveform', extractPeaks, getAnalysisJobOptions(options))
 
       logger.info('Waveform extracted', { samples: waveformData.length, inputPath: validation.path })
+      if (extracted) {
+        writeFileSync(cachePath, JSON.stringify(waveformData), 'utf8')
+      }
 
       return createSuccessResponse({
         waveform: waveformData,
@@ -122,11 +122,49 @@ export const en = {
     settingsDownloadThumbnailsDesc: 'Save video thumbnails and artwork',
     settingsSaveMetadata: 'Save Metadata',
     settingsSaveMetadataDesc: 'Include video info, description, tags',
+    settingsCookiesFromBrowser: 'Use Cookies From Browser',
+    settingsCookiesFromBrowserDesc: 'Sign in to YouTube with cookies from an installed browser',
+    settingsCookiesNone: 'None (app cookie file)',
     settingsPerformance: 'Performance',
     settingsConcurrentDownloads: 'Concurrent Downloads',
     settingsConcurrentDownloadsDesc: 'Maximum simultaneous downloads',
     settingsAutoRetryFailed: 'Auto-retry Failed Downloads',
     settingsAutoRetryFailedDesc: 'Automatically retry with exponential backoff',
+    settingsPermanentlyDelete: 'Delete Files Permanently',
+    settingsPermanentlyDeleteDesc: 'Skip the recycle bin when deleting downloaded files',
+    settingsWatchDownloadFolder: 'Watch Download Folder',
+    settingsWatchDownloadFolderDesc: 'Add videos copied into the download folder to the library automatically',
+    settingsUseTempDir: 'Download Through Temp Folder',
+    settingsUseTempDirDesc:
+      'Keep fragments and merge files in the app temp folder; only the finished video goes to the download folder',
+    settingsMaxPerHost: 'Downloads Per Site',
+    settingsMaxPerHostDesc: 'Limit simultaneous downloads from the same site so others are not stuck waiting',
+    settingsQualityFallback: 'Unavailable Quality',
+    settingsQualityFallbackDesc: 'What to do when a video is only offered above the chosen quality',
+    settingsQualityFallbackClosest: 'Closest',
+    settingsQualityFallbackFail: 'Fail',
+    settingsFinalContainer: 'Final Container',
+    settingsFinalContainerDesc: 'Remux every download into this container once it finishes',
+    settingsFinalContainerKeep: 'Keep original',
+    settingsSponsorBlock: 'SponsorBlock',
+    settingsSponsorBlockDesc: 'Cut out or mark sponsor segments and other community-flagged parts of YouTube videos',
+    sponsorBlockOff: 'Off',
+    sponsorBlockRemove: 'Remove',
+    sponsorBlockMark: 'Mark as chapters',
+    sponsorBlockMarkAndRemove: 'Mark and remove',
+    settingsSponsorBlockRemoveCategories: 'Cut out',
+    settingsSponsorBlockMarkCategories: 'Mark as chapters',
+    sponsorBlockCategorySponsor: 'Sponsor',
+    sponsorBlockCategoryIntro: 'Intro',
+    sponsorBlockCategoryOutro: 'Outro',
+    sponsorBlockCategorySelfpromo: 'Self-promotion',
+    sponsorBlockCategoryPreview: 'Preview',
+    sponsorBlockCategoryFiller: 'Filler',
+    sponsorBlockCategoryInteraction: 'Interaction reminder',
+    sponsorBlockCategoryMusicOfftopic: 'Non-music',
+    sponsorBlockCategoryPoiHighlight: 'Highlight',
+    sponsorBlockCategoryChapter: 'Chapter labels',
+    settingsUnlimited: 'Unlimited',
     settingsPerformanceImpactTitle: 'Performance Impact',
     settingsPerformanceImpactDesc:
       'Higher concurrent downloads may impact system performance and network stability. Adjust based on your hardware capabilities.',
@@ -157,6 +195,7 @@ export const en = {
     settingsNotSet: 'Not set',
     settingsUnknown: 'Unknown',
     settingsSelectDownloadFolder: 'Select Download Folder',
+    settingsDownloadFolderNotWritable: 'This folder is not writable. Choose another download folder.',
     settingsAutoCleanupTitle: 'Auto-cleanup',
     settingsAutoCleanupDesc: 'Cache files are automatically cleaned when they exceed 1GB or are older than 30 days.',
     settingsDownloadLocations: 'Download Locations',
@@ -186,6 +225,9 @@ export const en = {
     settingsArchitecture: 'Architecture',
     settingsNodeJs: 'Node.js',
     settingsElectron: 'Electron',
+    settingsCopyDiagnostics: 'Copy diagnostics',
+    settingsDiagnosticsCopied: 'Diagnostics copied to clipboard',
+    settingsDiagnosticsFailed: 'Failed to collect diagnostics',
     settingsLegalResources: 'Resources',
     settingsOpenSourceLicense: 'Open Source License',
     settingsMITLicense: 'MIT License',
@@ -215,8 +257,13 @@ export const en = {
     downloadStart: 'Download',
     downloadNew: 'Download New',
     downloadHistory: 'Download History',
+    stopAllDownloads: 'Stop all',
+    confirmStopAllTitle: 'Stop all downloads?',
+    confirmStopAllDescription: '{{count}} queued or running download(s) will be cancelled. Paused downloads are kept.',
     downloadCompleted: 'Download Completed',
     downloadFailed: 'Download Failed',
+    downloadQualityFallback: 'Downloaded at {{actual}} instead of {{requested}}',
+    downloadQualityFallbackPending: 'Downloading at {{actual}} instead of {{requested}}',
     downloadCancelled: 'Download Cancelled',
 
     // Video Preview
@@ -269,6 +316,7 @@ export const en = {
     filterActive: 'Active',
     filterCompleted: 'Completed',
     filterFailed: 'Failed',
+    filterContinueWatching: 'Continue Watching',
     noDownloads: 'No Downloads Yet',
     noDownloadsDesc: 'Start downloading videos to see them here',
     startDownloading: 'Start Downloading',
@@ -276,9 +324,13 @@ export const en = {
     filesComingSoon: 'File Management Coming Soon',
     filesComingSoonDesc: 'Advanced file management features will be available in future updates',
     statusDownloading: 'Downloading',
+    statusProcessing: 'Processing',
     statusCompleted: 'Completed',
+    statusCompletedWithErrors: 'Completed with errors',
     statusFailed: 'Failed',
     statusPaused: 'Paused',
+    downloadResumeRestarted: 'The partial files changed while paused, so the download started over',
+    downloadCompletedWithErrors: 'The video was saved, but part of the download failed:',
     loadingVideo: 'Loading video...',
     browserVideoUnsupported: 'Your browser does not support the video tag.',
     previewSize: 'Size:',
@@ -298,14 +350,18 @@ export const en = {
     msgDownloadFailed: 'Download failed',
     msgDownloadCancelled: 'Download cancelled',
     msgDownloadCancelFailed: 'Failed to cancel download',
+    msgDownloadsStopped: 'Stopped {{count}} download(s)',
     msgDownloadDeleted: 'Download deleted successfully',
     msgDownloadDeleteFailed: 'Failed to delete download',
     msgDownloadRetried: 'Download retry started',
     msgDownloadRetryFailed: 'Failed to retry download',
+    msgDownloadPauseFailed: 'Failed to pause download',
+    msgDownloadResumeFailed: 'Failed to resume download',
     msgFileOpened: 'File opened successfully',
     msgFileOpenFailed: 'Failed to open file',
     msgFolderOpened: 'Folder opened successfully',
     msgFolderOpenFailed: 'Failed to open folder',
+    msgMarkWatchedFailed: 'Failed to update watched status',
     msgDownloadAlreadyInProgress: 'Download already in progress',
     msgDownloadAlreadyCompleted: 'Download already completed',
     msgDownloadStartFailed: 'Failed to start download',
@@ -342,8 +398,13 @@ export const en = {
     actionPreview: 'Preview',
     actionOpenFile: 'Open File',
     actionOpenFolder: 'Open Folder',
+    actionMarkWatched: 'Mark as Watched',
+    actionMarkUnwatched: 'Mark as Unwatched',
+    libraryWatched: 'Watched',
     actionDelete: 'Delete',
     actionRetry: 'Retry',
+    actionPause: 'Pause',
+    actionResume: 'Resume',
     actionTryAgain: 'Try Again',
 
     // Confirmations
@@ -361,6 +422,28 @@ export const en = {
     errorPreviewUnavailable: 'Preview unavailable',
     errorYouTubeRestricted: 'YouTube videos cannot be streamed directly due to browser restrictions.',
     errorFailedToLoadPreview: 'Failed to load video preview.',
+    errorBinaryNotFound: '{{binary}} was not found. Install it or set its path in Settings > Advanced.',
+    errorUrlRequired: 'URL is required',
+    errorInvalidUrl: 'Invalid URL format',
+    errorDownloadFolderNotAbsolute: 'Download folder must be an absolute path',
+    errorDownloadFolderNotWritable: 'Cannot write to download folder {{path}}: {{reason}}',
+    errorDownloadFailed: 'Download failed: {{reason}}',
+    errorDownloadCancelled: 'Download cancelled by user',
+    errorDownloadPaused: 'Download paused',
+    errorDownloadPartiallyFailed: 'Downloaded, but with {{count}} error(s): {{reason}}',
+    errorDownloadTimeout: 'Download timeout reached',
+    errorDownloadStalled: 'Download stalled - no activity',
+    errorDownloadedFileNotFound: 'Downloaded file not found',
+    errorSiteNotSupported: 'Site not supported',
+    errorVideoPrivate: 'This video is private. Sign in with cookies from an account that has access.',
+    errorVideoAgeRestricted: 'This video is age-restricted. Sign in with cookies to download it.',
+    errorVideoLoginRequired: 'This video requires signing in. Retry with cookies from an account that can watch it.',
+    errorSiteBlocked: 'Downloads from {{host}} are not allowed by the site policy',
+    errorFormatUnavailable: 'This video is not available in {{requested}}. Available qualities: {{available}}',
+    errorDiskSpace: 'Not enough disk space: {{reason}}',
+    errorYtdlpTooOld: 'yt-dlp {{version}} is too old ({{minimum}} or newer is required). Update it with "yt-dlp -U".',
+    errorYtdlpIsYoutubeDl:
+      'The installed yt-dlp is actually youtube-dl, which Clipy does not support. Install yt-dlp instead.',
 
     // Editor Page
     editorExportTrimmedVideo: 'Export Trimmed Video',
@@ -427,7 +510,14 @@ export const en = {
     previewBest: 'Best',
     previewFormat: 'Format',
     previewAudioOnly: 'Audio only - no video will be included',
+    previewChapterFiles: 'Chapter files',
+    previewPodcastFeed: 'Podcast feed',
+    previewFilename: 'Filename',
+    previewFilenamePlaceholder: 'Default from settings, e.g. %(title)s [%(id)s].%(ext)s',
     previewVideoAudio: 'Video + Audio',
+    previewAudioLanguage: 'Audio language',
+    previewAudioLanguageDefault: 'Default',
+    previewAllAudioTracks: 'All tracks (MKV)',
     previewSubtitles: 'Subtitles',
     previewThumbnail: 'Thumbnail',
     previewMetadata: 'Metadata',
@@ -489,6 +579,19 @@ export const en = {
     settingsTrimBehavior: 'Trim Behavior',
     settingsPreferFastTrim: 'Prefer Fast Trim',
     settingsPreferFastTrimDesc: 'Use stream copy for faster trimming (may have slight seek inaccuracy)',
+    settingsProcessingPerformance: 'Processing Performance',
+    settingsProcessPriority: 'Process Priority',
+    settingsProcessPriorityDesc: 'Lower priority keeps the computer responsive during exports and conversions',
+    priorityNormal: 'Normal',
+    priorityBelowNormal: 'Below Normal',
+    priorityIdle: 'Idle',
+    settingsThreadsLimit: 'FFmpeg Threads',
+    settingsThreadsLimitDesc: 'Maximum CPU threads each export or conversion may use',
+    settingsMaxAnalysisJobs: 'Background analysis jobs',
+    settingsMaxAnalysisJobsDesc: 'How many thumbnail, waveform and scene jobs may run at once',
+    settingsPreviewCache: 'Preview Frame Cache',
+    settingsPreviewCacheDesc: 'Memory kept for scrubber frames, so going back over the same part of a video is instant',
+    threadsAutomatic: 'Automatic',
 
     // Notifications Settings
     settingsNotificationsTitle: 'Notifications',
@@ -526,10 +629,47 @@ export const en = {
     settingsBinaryVersions: 'Binary Versions',
     settingsNotFound: 'Not found',
     settingsNotInstalled: 'Not installed',
+    settingsCustomFfmpegPath: 'Custom FFmpeg path',
+    settingsCustomYtdlpPath: 'Custom yt-dlp path',
+    settingsCustomBinaryPathDesc:
+      'Use a system-managed binary instead of the bundled one. Leave empty for the bundled one.',
+    settingsBundledBinary: 'Bundled',
+    settingsBinaryPathSaved: 'Using version {{version}}',
+    settingsRespectYtdlpConfig: 'Use My yt-dlp Config',
+    settingsRespectYtdlpConfigDesc:
+      'Apply your global yt-dlp config file to Clipy downloads. Off by default, as its options can break downloads.',
+    settingsInvalidBinaryPath: 'Path not saved: {{error}}',
+    settingsProxyUrl: 'Proxy',
+    settingsProxyUrlDesc: 'HTTP or SOCKS proxy for downloads, with the port. Leave empty to connect directly.',
+    settingsInvalidProxyUrl: 'Proxy not saved: {{error}}',
+    settingsTestConnection: 'Test connection',
+    settingsTestConnectionDesc:
+      'Check that the internet, GitHub and YouTube can be reached, through the proxy if one is set.',
+    settingsConnectivityInternet: 'Internet',
+    settingsConnectivityGithub: 'GitHub',
+    settingsConnectivityYoutube: 'YouTube',
+    settingsConnectivityProxy: 'Proxy',
+    settingsConnectivityUnreachable: 'unreachable',
+    settingsConnectivityFailed: 'Connection test failed: {{error}}',
     settingsCheckForUpdates: 'Check for updates',
     settingsDebugging: 'Debugging',
     settingsDebugLogging: 'Debug Logging',
     settingsDebugLoggingDesc: 'Enable verbose logging for troubleshooting',
+    settingsMaxLogFileSize: 'Max Log File Size',
+    settingsMaxLogFileSizeDesc: 'Start a new log file once the current one reaches this size',
+    settingsMaxLogFiles: 'Log Files to Keep',
+    settingsMaxLogFilesDesc: 'Older log files are deleted automatically',
+    settingsThumbnailCacheDays: 'Thumbnail Cache Age',
+    settingsThumbnailCacheDaysDesc: 'Cached thumbnails older than this are downloaded again',
+    settingsMaxMemory: 'Memory Warning',
+    settingsMaxMemoryDesc: 'Warn when Clipy uses more memory than this, so the cache can be cleared',
+    settingsAppFolders: 'App Folders',
+    settingsAppFoldersDesc: 'Open the folders Clipy keeps its data, cache and logs in',
+    settingsOpenAppDataFolder: 'App Data',
+    settingsOpenCacheFolder: 'Cache',
+    settingsOpenLogsFolder: 'Logs',
+    settingsOpenFolderFailed: 'Could not open folder: {{error}}',
+    settingsDayCount: '{{count}} days',
     settingsDangerZone: 'Danger Zone',
     settingsResetAllSettings: 'Reset All Settings',
     settingsResetAllSettingsDesc: 'Restore all settings to their default values',
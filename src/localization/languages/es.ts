@@ -98,11 +98,51 @@ export const es = {
     settingsDownloadThumbnailsDesc: 'Guardar miniaturas de vídeo y obras de arte',
     settingsSaveMetadata: 'Guardar metadatos',
     settingsSaveMetadataDesc: 'Incluir información de vídeo, descripción, etiquetas',
+    settingsCookiesFromBrowser: 'Usar cookies del navegador',
+    settingsCookiesFromBrowserDesc: 'Inicia sesión en YouTube con las cookies de un navegador instalado',
+    settingsCookiesNone: 'Ninguno (archivo de cookies de la app)',
     settingsPerformance: 'Rendimiento',
     settingsConcurrentDownloads: 'Descargas simultáneas',
     settingsConcurrentDownloadsDesc: 'Máximo de descargas simultáneas',
     settingsAutoRetryFailed: 'Descargas fallidas de auto-reintento',
     settingsAutoRetryFailedDesc: 'Reintentar automáticamente con retroceso exponencial',
+    settingsPermanentlyDelete: 'Eliminar archivos permanentemente',
+    settingsPermanentlyDeleteDesc: 'No usar la papelera de reciclaje al eliminar archivos descargados',
+    settingsWatchDownloadFolder: 'Vigilar la carpeta de descargas',
+    settingsWatchDownloadFolderDesc:
+      'Añadir automáticamente a la biblioteca los videos copiados en la carpeta de descargas',
+    settingsUseTempDir: 'Descargar mediante carpeta temporal',
+    settingsUseTempDirDesc:
+      'Guarda los fragmentos y archivos de unión en la carpeta temporal; solo el video terminado va a la carpeta de descargas',
+    settingsMaxPerHost: 'Descargas por sitio',
+    settingsMaxPerHostDesc: 'Limita las descargas simultáneas del mismo sitio para que otras no queden en espera',
+    settingsQualityFallback: 'Calidad no disponible',
+    settingsQualityFallbackDesc: 'Qué hacer cuando un video solo se ofrece por encima de la calidad elegida',
+    settingsQualityFallbackClosest: 'Más cercana',
+    settingsQualityFallbackFail: 'Fallar',
+    settingsFinalContainer: 'Contenedor final',
+    settingsFinalContainerDesc: 'Remultiplexar cada descarga a este contenedor al terminar',
+    settingsFinalContainerKeep: 'Mantener original',
+    settingsSponsorBlock: 'SponsorBlock',
+    settingsSponsorBlockDesc:
+      'Recorta o marca patrocinios y otras partes de videos de YouTube señaladas por la comunidad',
+    sponsorBlockOff: 'Desactivado',
+    sponsorBlockRemove: 'Eliminar',
+    sponsorBlockMark: 'Marcar como capítulos',
+    sponsorBlockMarkAndRemove: 'Marcar y eliminar',
+    settingsSponsorBlockRemoveCategories: 'Recortar',
+    settingsSponsorBlockMarkCategories: 'Marcar como capítulos',
+    sponsorBlockCategorySponsor: 'Patrocinio',
+    sponsorBlockCategoryIntro: 'Intro',
+    sponsorBlockCategoryOutro: 'Cierre',
+    sponsorBlockCategorySelfpromo: 'Autopromoción',
+    sponsorBlockCategoryPreview: 'Avance',
+    sponsorBlockCategoryFiller: 'Relleno',
+    sponsorBlockCategoryInteraction: 'Recordatorio de interacción',
+    sponsorBlockCategoryMusicOfftopic: 'Sin música',
+    sponsorBlockCategoryPoiHighlight: 'Momento destacado',
+    sponsorBlockCategoryChapter: 'Nombres de capítulos',
+    settingsUnlimited: 'Ilimitado',
     settingsPerformanceImpactTitle: 'Impacto en el desempeño (%)',
     settingsPerformanceImpactDesc:
       'Las descargas simultáneas más altas pueden afectar el rendimiento del sistema y la estabilidad de la red. Ajuste en función de sus capacidades de hardware.',
@@ -131,6 +171,7 @@ export const es = {
     settingsNotSet: 'No establecido',
     settingsUnknown: 'Desconocido',
     settingsSelectDownloadFolder: 'Seleccionar carpeta de descarga',
+    settingsDownloadFolderNotWritable: 'No se puede escribir en esta carpeta. Elige otra carpeta de descarga.',
     settingsAutoCleanupTitle: 'Limpieza automática',
     settingsAutoCleanupDesc:
       'Los archivos de caché se limpian automáticamente cuando superan los 1 GB o tienen más de 30 días.',
@@ -159,6 +200,9 @@ export const es = {
     settingsArchitecture: 'Arquitectura',
     settingsNodeJs: 'Node.js',
     settingsElectron: 'Electron',
+    settingsCopyDiagnostics: 'Copiar diagnóstico',
+    settingsDiagnosticsCopied: 'Diagnóstico copiado al portapapeles',
+    settingsDiagnosticsFailed: 'No se pudo recopilar el diagnóstico',
     settingsLegalResources: 'Recursos',
     settingsOpenSourceLicense: 'Licencia de código abierto',
     settingsMITLicense: 'Licencia MIT',
@@ -184,8 +228,14 @@ export const es = {
     downloadStart: 'Descargar',
     downloadNew: 'Descargar nuevos mapasAction for toggling the navigation panel',
     downloadHistory: 'Historial de Descargas',
+    stopAllDownloads: 'Detener todo',
+    confirmStopAllTitle: '¿Detener todas las descargas?',
+    confirmStopAllDescription:
+      'Se cancelarán {{count}} descarga(s) en cola o en curso. Las descargas en pausa se conservan.',
     downloadCompleted: 'Descarga completa',
     downloadFailed: 'Descarga Fallida',
+    downloadQualityFallback: 'Descargado en {{actual}} en lugar de {{requested}}',
+    downloadQualityFallbackPending: 'Descargando en {{actual}} en lugar de {{requested}}',
     downloadCancelled: 'Descarga cancelada',
     toolTrim: 'Recortar video',
     trimDuration: 'Duración del recorte',
@@ -235,6 +285,7 @@ export const es = {
     filterActive: 'Activo',
     filterCompleted: 'Completado',
     filterFailed: 'Fallido',
+    filterContinueWatching: 'Seguir viendo',
     noDownloads: 'No hay descargas',
     noDownloadsDesc: 'Empieza a descargar vídeos para verlos aquí',
     startDownloading: 'Comience a descargar',
@@ -243,9 +294,13 @@ export const es = {
     filesComingSoonDesc:
       'Las funciones avanzadas de gestión de archivos estarán disponibles en futuras actualizaciones',
     statusDownloading: 'Descargando',
+    statusProcessing: 'Procesando',
     statusCompleted: 'Completado',
+    statusCompletedWithErrors: 'Completado con errores',
     statusFailed: 'Fallido',
     statusPaused: 'En pausa',
+    downloadResumeRestarted: 'Los archivos parciales cambiaron durante la pausa, así que la descarga empezó de nuevo',
+    downloadCompletedWithErrors: 'El video se guardó, pero parte de la descarga falló:',
     loadingVideo: 'Cargando vídeo...',
     browserVideoUnsupported: 'Este navegador no soporta la etiqueta de vídeo.',
     previewSize: 'Tamaño:',
@@ -263,14 +318,18 @@ export const es = {
     msgDownloadFailed: 'Error de descarga',
     msgDownloadCancelled: 'Descarga cancelada',
     msgDownloadCancelFailed: 'No se ha podido cancelar la descarga',
+    msgDownloadsStopped: 'Se detuvieron {{count}} descarga(s)',
     msgDownloadDeleted: 'Descarga eliminada correctamente',
     msgDownloadDeleteFailed: 'Error al eliminar la descarga',
     msgDownloadRetried: 'Reintento de descarga iniciado',
     msgDownloadRetryFailed: 'No se ha podido volver a intentar la descarga',
+    msgDownloadPauseFailed: 'No se pudo pausar la descarga',
+    msgDownloadResumeFailed: 'No se pudo reanudar la descarga',
     msgFileOpened: 'Archivo abierto correctamente',
     msgFileOpenFailed: 'No se ha podido abrir el archivo',
     msgFolderOpened: 'Carpeta abierta correctamente',
     msgFolderOpenFailed: 'Fallo al abrir la carpeta',
+    msgMarkWatchedFailed: 'No se pudo actualizar el estado de visto',
     msgDownloadAlreadyInProgress: 'Descarga ya en curso',
     msgDownloadAlreadyCompleted: 'Descarga ya completada',
     msgDownloadStartFailed: 'Error al iniciar la descarga',
@@ -300,8 +359,13 @@ export const es = {
     actionPreview: 'Vista previa',
     actionOpenFile: 'Open File',
     actionOpenFolder: 'Open Folder',
+    actionMarkWatched: 'Marcar como visto',
+    actionMarkUnwatched: 'Marcar como no visto',
+    libraryWatched: 'Visto',
     actionDelete: 'Eliminar',
     actionRetry: 'Reintentar',
+    actionPause: 'Pausar',
+    actionResume: 'Reanudar',
     actionTryAgain: 'Intentar de nuevo',
     confirmDeleteTitle: 'Eliminar descarga',
     confirmDeleteDescription: '¿Estás seguro de que quieres eliminar este paso? Esta acción no se puede deshacer.',
@@ -316,6 +380,30 @@ export const es = {
     errorYouTubeRestricted:
       'Los vídeos de YouTube no se pueden transmitir directamente debido a las restricciones del navegador.',
     errorFailedToLoadPreview: 'No se ha podido cargar la vista previa del vídeo.',
+    errorBinaryNotFound: 'No se encontró {{binary}}. Instálalo o indica su ruta en Configuración > Avanzado.',
+    errorUrlRequired: 'La URL es obligatoria',
+    errorInvalidUrl: 'Formato de URL no válido',
+    errorDownloadFolderNotAbsolute: 'La carpeta de descargas debe ser una ruta absoluta',
+    errorDownloadFolderNotWritable: 'No se puede escribir en la carpeta de descargas {{path}}: {{reason}}',
+    errorDownloadFailed: 'La descarga falló: {{reason}}',
+    errorDownloadCancelled: 'Descarga cancelada por el usuario',
+    errorDownloadPaused: 'Descarga en pausa',
+    errorDownloadPartiallyFailed: 'Descargado, pero con {{count}} error(es): {{reason}}',
+    errorDownloadTimeout: 'Se agotó el tiempo de descarga',
+    errorDownloadStalled: 'La descarga se detuvo: sin actividad',
+    errorDownloadedFileNotFound: 'No se encontró el archivo descargado',
+    errorSiteNotSupported: 'Sitio no compatible',
+    errorVideoPrivate: 'Este video es privado. Inicia sesión con las cookies de una cuenta que tenga acceso.',
+    errorVideoAgeRestricted: 'Este video tiene restricción de edad. Inicia sesión con cookies para descargarlo.',
+    errorVideoLoginRequired:
+      'Este video requiere iniciar sesión. Reintenta con las cookies de una cuenta que pueda verlo.',
+    errorSiteBlocked: 'La política de sitios no permite descargas desde {{host}}',
+    errorFormatUnavailable: 'Este video no está disponible en {{requested}}. Calidades disponibles: {{available}}',
+    errorDiskSpace: 'No hay suficiente espacio en disco: {{reason}}',
+    errorYtdlpTooOld:
+      'yt-dlp {{version}} es demasiado antiguo (se requiere {{minimum}} o posterior). Actualízalo con "yt-dlp -U".',
+    errorYtdlpIsYoutubeDl:
+      'El yt-dlp instalado es en realidad youtube-dl, que Clipy no admite. Instala yt-dlp en su lugar.',
     editorExportTrimmedVideo: 'Exportar vídeo recortado',
     editorLoadingVideo: 'Cargando vídeo...',
     editorBackToLibrary: 'Volver a la biblioteca',
@@ -377,7 +465,14 @@ export const es = {
     previewBest: 'El mejor',
     previewFormat: 'Formato',
     previewAudioOnly: 'Solo audio: no se incluirá ningún vídeo',
+    previewChapterFiles: 'Archivos de capítulos',
+    previewPodcastFeed: 'Feed de pódcast',
+    previewFilename: 'Nombre de archivo',
+    previewFilenamePlaceholder: 'El de la configuración, p. ej. %(title)s [%(id)s].%(ext)s',
     previewVideoAudio: 'Vídeo/Audio',
+    previewAudioLanguage: 'Idioma del audio',
+    previewAudioLanguageDefault: 'Predeterminado',
+    previewAllAudioTracks: 'Todas las pistas (MKV)',
     previewSubtitles: 'Subtítulos',
     previewThumbnail: 'Miniatura',
     previewMetadata: 'Metadatos',
@@ -432,6 +527,22 @@ export const es = {
     settingsPreferFastTrim: 'Prefiero el ajuste rápido',
     settingsPreferFastTrimDesc:
       'Utilice la copia de flujo para un recorte más rápido (puede tener una ligera inexactitud de búsqueda)',
+    settingsProcessingPerformance: 'Rendimiento del procesamiento',
+    settingsProcessPriority: 'Prioridad del proceso',
+    settingsProcessPriorityDesc:
+      'Una prioridad más baja mantiene el equipo ágil durante exportaciones y conversiones',
+    priorityNormal: 'Normal',
+    priorityBelowNormal: 'Por debajo de lo normal',
+    priorityIdle: 'Inactiva',
+    settingsThreadsLimit: 'Hilos de FFmpeg',
+    settingsThreadsLimitDesc: 'Máximo de hilos de CPU que puede usar cada exportación o conversión',
+    settingsMaxAnalysisJobs: 'Análisis en segundo plano',
+    settingsMaxAnalysisJobsDesc:
+      'Cuántas miniaturas, formas de onda y detecciones de escenas pueden generarse a la vez',
+    settingsPreviewCache: 'Caché de fotogramas de vista previa',
+    settingsPreviewCacheDesc:
+      'Memoria para los fotogramas del deslizador, para que volver a la misma parte del video sea instantáneo',
+    threadsAutomatic: 'Automático',
     settingsNotificationsTitle: 'Notificaciones',
     settingsNotificationsDescription: 'Configurar preferencias de notificación',
     settingsDesktopNotifications: 'Notificaciones de escritorio',
@@ -463,10 +574,48 @@ export const es = {
     settingsBinaryVersions: 'Versiones binarias',
     settingsNotFound: 'No se ha encontrado',
     settingsNotInstalled: 'No instalado',
+    settingsCustomFfmpegPath: 'Ruta personalizada de FFmpeg',
+    settingsCustomYtdlpPath: 'Ruta personalizada de yt-dlp',
+    settingsCustomBinaryPathDesc:
+      'Usa un binario gestionado por el sistema en lugar del incluido. Déjalo vacío para usar el incluido.',
+    settingsBundledBinary: 'Incluido',
+    settingsBinaryPathSaved: 'Usando la versión {{version}}',
+    settingsRespectYtdlpConfig: 'Usar mi configuración de yt-dlp',
+    settingsRespectYtdlpConfigDesc:
+      'Aplica tu archivo de configuración global de yt-dlp a las descargas de Clipy. Desactivado por defecto, ya que sus opciones pueden romper las descargas.',
+    settingsInvalidBinaryPath: 'No se guardó la ruta: {{error}}',
+    settingsProxyUrl: 'Proxy',
+    settingsProxyUrlDesc:
+      'Proxy HTTP o SOCKS para las descargas, con el puerto. Déjalo vacío para conectar directamente.',
+    settingsInvalidProxyUrl: 'No se guardó el proxy: {{error}}',
+    settingsTestConnection: 'Probar conexión',
+    settingsTestConnectionDesc:
+      'Comprueba que se puede acceder a internet, GitHub y YouTube, a través del proxy si hay uno.',
+    settingsConnectivityInternet: 'Internet',
+    settingsConnectivityGithub: 'GitHub',
+    settingsConnectivityYoutube: 'YouTube',
+    settingsConnectivityProxy: 'Proxy',
+    settingsConnectivityUnreachable: 'sin acceso',
+    settingsConnectivityFailed: 'Falló la prueba de conexión: {{error}}',
     settingsCheckForUpdates: 'Buscar actualizaciones',
     settingsDebugging: 'Depuración',
     settingsDebugLogging: 'Logueo de depuración',
     settingsDebugLoggingDesc: 'Habilitar el registro detallado para la solución de problemas',
+    settingsMaxLogFileSize: 'Tamaño máximo del archivo de registro',
+    settingsMaxLogFileSizeDesc: 'Crear un nuevo archivo de registro cuando el actual alcance este tamaño',
+    settingsMaxLogFiles: 'Archivos de registro a conservar',
+    settingsMaxLogFilesDesc: 'Los archivos de registro más antiguos se eliminan automáticamente',
+    settingsThumbnailCacheDays: 'Antigüedad de miniaturas',
+    settingsThumbnailCacheDaysDesc: 'Las miniaturas en caché más antiguas se descargan de nuevo',
+    settingsMaxMemory: 'Aviso de memoria',
+    settingsMaxMemoryDesc: 'Avisar cuando Clipy use más memoria que esto, para poder vaciar la caché',
+    settingsAppFolders: 'Carpetas de la app',
+    settingsAppFoldersDesc: 'Abre las carpetas donde Clipy guarda sus datos, caché y registros',
+    settingsOpenAppDataFolder: 'Datos',
+    settingsOpenCacheFolder: 'Caché',
+    settingsOpenLogsFolder: 'Registros',
+    settingsOpenFolderFailed: 'No se pudo abrir la carpeta: {{error}}',
+    settingsDayCount: '{{count}} días',
     settingsDangerZone: 'Zona de peligro',
     settingsResetAllSettings: 'Restablecer todos los ajustes',
     settingsResetAllSettingsDesc: 'Restaurar todos los ajustes a sus valores por defecto',
@@ -98,11 +98,51 @@ export const fr = {
     settingsDownloadThumbnailsDesc: 'Enregistrer les vignettes vidéo et les illustrations',
     settingsSaveMetadata: 'Enregistrer les métadonnées',
     settingsSaveMetadataDesc: 'Inclure les infos vidéo, la description, les tags',
+    settingsCookiesFromBrowser: 'Utiliser les cookies du navigateur',
+    settingsCookiesFromBrowserDesc: "Se connecter à YouTube avec les cookies d'un navigateur installé",
+    settingsCookiesNone: "Aucun (fichier de cookies de l'app)",
     settingsPerformance: 'Performance',
     settingsConcurrentDownloads: 'Téléchargements simultanés',
     settingsConcurrentDownloadsDesc: 'Nombre maximum de téléchargements simultanés',
     settingsAutoRetryFailed: 'Échec de la tentative automatique de téléchargement',
     settingsAutoRetryFailedDesc: 'Réessayez automatiquement avec un backoff exponentiel',
+    settingsPermanentlyDelete: 'Supprimer définitivement les fichiers',
+    settingsPermanentlyDeleteDesc: 'Ne pas utiliser la corbeille lors de la suppression des fichiers téléchargés',
+    settingsWatchDownloadFolder: 'Surveiller le dossier de téléchargement',
+    settingsWatchDownloadFolderDesc:
+      'Ajouter automatiquement à la bibliothèque les vidéos copiées dans le dossier de téléchargement',
+    settingsUseTempDir: 'Télécharger via un dossier temporaire',
+    settingsUseTempDirDesc:
+      'Garde les fragments et fichiers de fusion dans le dossier temporaire ; seule la vidéo terminée va dans le dossier de téléchargement',
+    settingsMaxPerHost: 'Téléchargements par site',
+    settingsMaxPerHostDesc: "Limiter les téléchargements simultanés d'un même site pour ne pas bloquer les autres",
+    settingsQualityFallback: 'Qualité indisponible',
+    settingsQualityFallbackDesc: "Que faire quand une vidéo n'est proposée qu'au-dessus de la qualité choisie",
+    settingsQualityFallbackClosest: 'La plus proche',
+    settingsQualityFallbackFail: 'Échouer',
+    settingsFinalContainer: 'Conteneur final',
+    settingsFinalContainerDesc: 'Remultiplexer chaque téléchargement dans ce conteneur une fois terminé',
+    settingsFinalContainerKeep: "Garder l'original",
+    settingsSponsorBlock: 'SponsorBlock',
+    settingsSponsorBlockDesc:
+      'Coupez ou marquez les sponsors et autres passages signalés par la communauté dans les vidéos YouTube',
+    sponsorBlockOff: 'Désactivé',
+    sponsorBlockRemove: 'Supprimer',
+    sponsorBlockMark: 'Marquer en chapitres',
+    sponsorBlockMarkAndRemove: 'Marquer et supprimer',
+    settingsSponsorBlockRemoveCategories: 'Couper',
+    settingsSponsorBlockMarkCategories: 'Marquer en chapitres',
+    sponsorBlockCategorySponsor: 'Sponsor',
+    sponsorBlockCategoryIntro: 'Intro',
+    sponsorBlockCategoryOutro: 'Outro',
+    sponsorBlockCategorySelfpromo: 'Autopromotion',
+    sponsorBlockCategoryPreview: 'Aperçu',
+    sponsorBlockCategoryFiller: 'Remplissage',
+    sponsorBlockCategoryInteraction: "Rappel d'interaction",
+    sponsorBlockCategoryMusicOfftopic: 'Hors musique',
+    sponsorBlockCategoryPoiHighlight: 'Moment fort',
+    sponsorBlockCategoryChapter: 'Noms de chapitres',
+    settingsUnlimited: 'Illimité',
     settingsPerformanceImpactTitle: 'Impact sur les performances',
     settingsPerformanceImpactDesc:
       'Des téléchargements simultanés plus élevés peuvent avoir un impact sur les performances du système et la stabilité du réseau Ajustez en fonction de vos capacités matérielles.',
@@ -131,6 +171,8 @@ export const fr = {
     settingsNotSet: 'Non défini',
     settingsUnknown: 'Inconnu',
     settingsSelectDownloadFolder: 'Sélectionner le dossier de téléchargement',
+    settingsDownloadFolderNotWritable:
+      "Ce dossier n'est pas accessible en écriture. Choisissez un autre dossier de téléchargement.",
     settingsAutoCleanupTitle: 'Nettoyage automatique',
     settingsAutoCleanupDesc:
       "Les fichiers du cache sont automatiquement nettoyés lorsqu'ils dépassent 1 Go ou lorsqu'ils datent de plus de 30 jours.",
@@ -159,6 +201,9 @@ export const fr = {
     settingsArchitecture: 'Architecture',
     settingsNodeJs: 'Node.js',
     settingsElectron: '           Électron.',
+    settingsCopyDiagnostics: 'Copier le diagnostic',
+    settingsDiagnosticsCopied: 'Diagnostic copié dans le presse-papiers',
+    settingsDiagnosticsFailed: 'Impossible de collecter le diagnostic',
     settingsLegalResources: 'Ressources',
     settingsOpenSourceLicense: 'Licence libre',
     settingsMITLicense: 'Licence MIT',
@@ -184,8 +229,14 @@ export const fr = {
     downloadStart: 'Télécharger',
     downloadNew: 'Télécharger un nouveau',
     downloadHistory: 'Historique des Téléchargements',
+    stopAllDownloads: 'Tout arrêter',
+    confirmStopAllTitle: 'Arrêter tous les téléchargements ?',
+    confirmStopAllDescription:
+      "{{count}} téléchargement(s) en file d'attente ou en cours seront annulés. Les téléchargements en pause sont conservés.",
     downloadCompleted: 'Téléchargement terminé',
     downloadFailed: 'Échec du téléchargement',
+    downloadQualityFallback: 'Téléchargé en {{actual}} au lieu de {{requested}}',
+    downloadQualityFallbackPending: 'Téléchargement en {{actual}} au lieu de {{requested}}',
     downloadCancelled: 'Téléchargement Annulé',
     toolTrim: 'Découpez la Vidéo',
     trimDuration: 'Durée du rognage',
@@ -235,6 +286,7 @@ export const fr = {
     filterActive: 'Actif',
     filterCompleted: 'Terminé',
     filterFailed: 'Échec',
+    filterContinueWatching: 'Reprendre la lecture',
     noDownloads: 'Aucun téléchargement',
     noDownloadsDesc: 'Commencez à télécharger des vidéos pour les voir ici',
     startDownloading: 'Démarrer le téléchargement',
@@ -243,9 +295,13 @@ export const fr = {
     filesComingSoonDesc:
       'Des fonctionnalités avancées de gestion de fichiers seront disponibles dans les futures mises à jour',
     statusDownloading: 'Téléchargement',
+    statusProcessing: 'Traitement',
     statusCompleted: 'Terminé',
+    statusCompletedWithErrors: 'Terminé avec des erreurs',
     statusFailed: 'Échec',
     statusPaused: 'En pause',
+    downloadResumeRestarted: 'Les fichiers partiels ont changé pendant la pause, le téléchargement a donc recommencé',
+    downloadCompletedWithErrors: 'La vidéo a été enregistrée, mais une partie du téléchargement a échoué :',
     loadingVideo: 'Chargement video…',
     browserVideoUnsupported: 'Votre navigateur ne prend pas en charge la balise vidéo.',
     previewSize: 'Taille :',
@@ -263,14 +319,18 @@ export const fr = {
     msgDownloadFailed: 'Le téléchargement a échoué',
     msgDownloadCancelled: 'Téléchargement annulé',
     msgDownloadCancelFailed: "Échec de l'annulation du téléchargement",
+    msgDownloadsStopped: '{{count}} téléchargement(s) arrêté(s)',
     msgDownloadDeleted: 'Téléchargement supprimé avec succès',
     msgDownloadDeleteFailed: 'Échec de la suppression du téléchargement',
     msgDownloadRetried: 'Téléchargement réessayé commencé',
     msgDownloadRetryFailed: 'Impossible de réessayer le téléchargement',
+    msgDownloadPauseFailed: 'Impossible de mettre le téléchargement en pause',
+    msgDownloadResumeFailed: 'Impossible de reprendre le téléchargement',
     msgFileOpened: 'Fichier ouvert avec succès',
     msgFileOpenFailed: "Impossible d'ouvrir le fichier",
     msgFolderOpened: 'Dossier ouvert avec succès',
     msgFolderOpenFailed: "Impossible d'ouvrir le dossier",
+    msgMarkWatchedFailed: 'Impossible de mettre à jour le statut de visionnage',
     msgDownloadAlreadyInProgress: 'Téléchargement déjà en cours.&#10;',
     msgDownloadAlreadyCompleted: 'Téléchargement déjà terminé',
     msgDownloadStartFailed: 'Échec du lancement du téléchargement du logiciel',
@@ -300,8 +360,13 @@ export const fr = {
     actionPreview: 'Aperçu',
     actionOpenFile: 'Ouvrir Fichier',
     actionOpenFolder: 'Ouvrir le dossier',
+    actionMarkWatched: 'Marquer comme vu',
+    actionMarkUnwatched: 'Marquer comme non vu',
+    libraryWatched: 'Vu',
     actionDelete: 'Effacer',
     actionRetry: 'Réessayer',
+    actionPause: 'Mettre en pause',
+    actionResume: 'Reprendre',
     actionTryAgain: 'Réessayez',
     confirmDeleteTitle: 'Supprimer le téléchargement',
     confirmDeleteDescription: 'Êtes-vous sûr de vouloir supprimer cette zone ? Cette action ne peut pas être annulée.',
@@ -316,6 +381,31 @@ export const fr = {
     errorYouTubeRestricted:
       'Les vidéos YouTube ne peuvent pas être diffusées directement en raison des restrictions du navigateur.',
     errorFailedToLoadPreview: "Échec du chargement de l'aperçu vidéo.",
+    errorBinaryNotFound: '{{binary}} est introuvable. Installez-le ou indiquez son chemin dans Paramètres > Avancé.',
+    errorUrlRequired: "L'URL est obligatoire",
+    errorInvalidUrl: "Format d'URL invalide",
+    errorDownloadFolderNotAbsolute: 'Le dossier de téléchargement doit être un chemin absolu',
+    errorDownloadFolderNotWritable: "Impossible d'écrire dans le dossier de téléchargement {{path}} : {{reason}}",
+    errorDownloadFailed: 'Échec du téléchargement : {{reason}}',
+    errorDownloadCancelled: "Téléchargement annulé par l'utilisateur",
+    errorDownloadPaused: 'Téléchargement en pause',
+    errorDownloadPartiallyFailed: 'Téléchargé, mais avec {{count}} erreur(s) : {{reason}}',
+    errorDownloadTimeout: 'Délai de téléchargement dépassé',
+    errorDownloadStalled: 'Téléchargement bloqué : aucune activité',
+    errorDownloadedFileNotFound: 'Fichier téléchargé introuvable',
+    errorSiteNotSupported: 'Site non pris en charge',
+    errorVideoPrivate: "Cette vidéo est privée. Connectez-vous avec les cookies d'un compte qui y a accès.",
+    errorVideoAgeRestricted:
+      "Cette vidéo est soumise à une limite d'âge. Connectez-vous avec des cookies pour la télécharger.",
+    errorVideoLoginRequired:
+      "Cette vidéo nécessite une connexion. Réessayez avec les cookies d'un compte qui peut la voir.",
+    errorSiteBlocked: 'La politique des sites ne permet pas les téléchargements depuis {{host}}',
+    errorFormatUnavailable: "Cette vidéo n'est pas disponible en {{requested}}. Qualités disponibles : {{available}}",
+    errorDiskSpace: "Pas assez d'espace disque : {{reason}}",
+    errorYtdlpTooOld:
+      'yt-dlp {{version}} est trop ancien ({{minimum}} ou plus récent requis). Mettez-le à jour avec "yt-dlp -U".',
+    errorYtdlpIsYoutubeDl:
+      'Le yt-dlp installé est en fait youtube-dl, que Clipy ne prend pas en charge. Installez yt-dlp.',
     editorExportTrimmedVideo: 'Exportez la vidéo découpée.',
     editorLoadingVideo: 'Chargement video…',
     editorBackToLibrary: 'Retour à la Bibliothèque',
@@ -376,7 +466,14 @@ export const fr = {
     previewBest: 'Meilleur',
     previewFormat: 'Format',
     previewAudioOnly: 'Audio uniquement - aucune vidéo ne sera incluse',
+    previewChapterFiles: 'Fichiers de chapitres',
+    previewPodcastFeed: 'Flux de podcast',
+    previewFilename: 'Nom du fichier',
+    previewFilenamePlaceholder: 'Celui des paramètres, ex. %(title)s [%(id)s].%(ext)s',
     previewVideoAudio: 'Vidéo et audio',
+    previewAudioLanguage: 'Langue audio',
+    previewAudioLanguageDefault: 'Par défaut',
+    previewAllAudioTracks: 'Toutes les pistes (MKV)',
     previewSubtitles: 'Sous-titres',
     previewThumbnail: 'Vignette',
     previewMetadata: 'Métadonnées',
@@ -431,6 +528,21 @@ export const fr = {
     settingsPreferFastTrim: 'Préférez les garnitures rapides',
     settingsPreferFastTrimDesc:
       'Utilisez la copie de flux pour un découpage plus rapide (peut avoir une légère imprécision de recherche)',
+    settingsProcessingPerformance: 'Performances du traitement',
+    settingsProcessPriority: 'Priorité du processus',
+    settingsProcessPriorityDesc:
+      "Une priorité plus basse garde l'ordinateur réactif pendant les exports et conversions",
+    priorityNormal: 'Normale',
+    priorityBelowNormal: 'Inférieure à la normale',
+    priorityIdle: 'Inactive',
+    settingsThreadsLimit: 'Threads FFmpeg',
+    settingsThreadsLimitDesc: 'Nombre maximal de threads CPU par export ou conversion',
+    settingsMaxAnalysisJobs: 'Analyses en arrière-plan',
+    settingsMaxAnalysisJobsDesc: "Nombre de miniatures, formes d'onde et détections de scènes générées en même temps",
+    settingsPreviewCache: "Cache des images d'aperçu",
+    settingsPreviewCacheDesc:
+      "Mémoire réservée aux images du curseur, pour revenir instantanément sur la même partie d'une vidéo",
+    threadsAutomatic: 'Automatique',
     settingsNotificationsTitle: 'Notifications',
     settingsNotificationsDescription: 'Configurer les préférences de notification',
     settingsDesktopNotifications: 'Notifications de bureau',
@@ -462,10 +574,48 @@ export const fr = {
     settingsBinaryVersions: 'Versions binaires',
     settingsNotFound: 'Introuvable',
     settingsNotInstalled: 'Non installé',
+    settingsCustomFfmpegPath: 'Chemin FFmpeg personnalisé',
+    settingsCustomYtdlpPath: 'Chemin yt-dlp personnalisé',
+    settingsCustomBinaryPathDesc:
+      'Utilise un binaire géré par le système au lieu de celui fourni. Laissez vide pour utiliser celui fourni.',
+    settingsBundledBinary: 'Fourni',
+    settingsBinaryPathSaved: 'Version {{version}} utilisée',
+    settingsRespectYtdlpConfig: 'Utiliser ma config yt-dlp',
+    settingsRespectYtdlpConfigDesc:
+      'Appliquer votre fichier de configuration yt-dlp global aux téléchargements de Clipy. Désactivé par défaut, car ses options peuvent casser les téléchargements.',
+    settingsInvalidBinaryPath: 'Chemin non enregistré : {{error}}',
+    settingsProxyUrl: 'Proxy',
+    settingsProxyUrlDesc:
+      'Proxy HTTP ou SOCKS pour les téléchargements, avec le port. Laissez vide pour une connexion directe.',
+    settingsInvalidProxyUrl: 'Proxy non enregistré : {{error}}',
+    settingsTestConnection: 'Tester la connexion',
+    settingsTestConnectionDesc:
+      "Vérifie que l'accès à internet, GitHub et YouTube fonctionne, via le proxy s'il y en a un.",
+    settingsConnectivityInternet: 'Internet',
+    settingsConnectivityGithub: 'GitHub',
+    settingsConnectivityYoutube: 'YouTube',
+    settingsConnectivityProxy: 'Proxy',
+    settingsConnectivityUnreachable: 'inaccessible',
+    settingsConnectivityFailed: 'Échec du test de connexion : {{error}}',
     settingsCheckForUpdates: 'Vérifier les mises à jour',
     settingsDebugging: 'Débogage',
     settingsDebugLogging: 'Debug Logging',
     settingsDebugLoggingDesc: 'Activer la journalisation verbale pour le dépannage',
+    settingsMaxLogFileSize: 'Taille maximale du fichier journal',
+    settingsMaxLogFileSizeDesc: 'Créer un nouveau fichier journal lorsque le fichier actuel atteint cette taille',
+    settingsMaxLogFiles: 'Fichiers journaux à conserver',
+    settingsMaxLogFilesDesc: 'Les fichiers journaux les plus anciens sont supprimés automatiquement',
+    settingsThumbnailCacheDays: 'Âge des miniatures',
+    settingsThumbnailCacheDaysDesc: 'Les miniatures en cache plus anciennes sont téléchargées à nouveau',
+    settingsMaxMemory: 'Alerte mémoire',
+    settingsMaxMemoryDesc: 'Prévenir quand Clipy utilise plus de mémoire, pour pouvoir vider le cache',
+    settingsAppFolders: "Dossiers de l'app",
+    settingsAppFoldersDesc: 'Ouvrir les dossiers où Clipy garde ses données, son cache et ses journaux',
+    settingsOpenAppDataFolder: 'Données',
+    settingsOpenCacheFolder: 'Cache',
+    settingsOpenLogsFolder: 'Journaux',
+    settingsOpenFolderFailed: "Impossible d'ouvrir le dossier : {{error}}",
+    settingsDayCount: '{{count}} jours',
     settingsDangerZone: 'Zone dangereuse',
     settingsResetAllSettings: 'Réinitialiser tous les paramètres',
     settingsResetAllSettingsDesc: 'Réglez tous les paramètres sur leurs valeurs par défaut',
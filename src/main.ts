@@ -8,9 +8,16 @@
  * - Security policies (navigation, permissions, etc.)
  */
 
-import { BrowserWindow, app, net, protocol, screen, session } from 'electron'
+import { BrowserWindow, app, net, powerMonitor, protocol, screen, session } from 'electron'
 import { startStreamingProxy, stopStreamingProxy } from './services/streaming-proxy'
+import { stopMediaServer } from './services/media-server'
+import { stopLibraryWatcher, syncLibraryWatcher } from './services/library-watcher'
+import { applyBinaryPathSettings } from './services/binary-versions'
+import { DownloadManager } from './services/download-manager'
 import { saveDownloadStorage } from './services/download-storage'
+import { SHUTDOWN_TIMEOUT, type ShutdownStep, requestShutdown } from './services/lifecycle'
+import { pruneAutosaves } from './services/project-autosave'
+import { ThumbnailCache } from './services/thumbnail-cache'
 
 import { ConfigManager } from './utils/config'
 import { Logger } from './utils/logger'
@@ -19,6 +26,8 @@ import path from 'path'
 import { pathToFileURL } from 'url'
 import { setupCoreHandlers } from './ipc/core-handlers'
 import { setupDownloadHandlers } from './ipc/download-handlers'
+import { setupEditorHandlers } from './ipc/editor-handlers'
+import { setupLibraryHandlers } from './ipc/library-handlers'
 import { setupVideoHandlers } from './ipc/video-handlers'
 
 declare const MAIN_WINDOW_VITE_DEV_SERVER_URL: string
@@ -224,6 +233,11 @@ function createWindow() {
     if (saveTimeout) clearTimeout(saveTimeout)
     saveWindowState(mainWindow)
   })
+  // Windows logoff/shutdown: hold it until downloads are paused (capped, see services/lifecycle)
+  mainWindow.on('query-session-end', event => {
+    event.preventDefault()
+    app.quit()
+  })
   mainWindow.on('resize', () => {
     if (!mainWindow.isMaximized()) {
       debouncedSave()
@@ -282,6 +296,8 @@ function createWindow() {
   setupCoreHandlers()
   setupDownloadHandlers()
   setupVideoHandlers()
+  setupEditorHandlers()
+  setupLibraryHandlers()
 
   // Setup CORS bypass for YouTube streaming - allows direct fetch from googlevideo.com
   // This is more reliable than a proxy server (which gets socket hangup errors)
@@ -339,6 +355,8 @@ app.whenReady().then(() => {
         path.join(process.cwd(), 'resources'),
         // Also allow the configured download path
         config.download?.downloadPath || app.getPath('downloads'),
+        // Cached storyboards and thumbnails
+        ThumbnailCache.getInstance().getCacheDirectory(),
       ].filter(Boolean) as string[]
 
       // Windows paths are case-insensitive
@@ -395,18 +413,86 @@ app.whenReady().then(() => {
 
   configManager.getAll()
   createWindow()
-})
 
-// Ensure download storage is saved before quit
-app.on('before-quit', () => {
-  logger.info('App quitting - ensuring download storage is saved')
-  // Force a final save of download storage to ensure all completed downloads are persisted
+  // Pick up manual edits to config.json (or a synced copy) without a restart, applying them
+  // like a settings import does
+  configManager.watchConfigFile(config => {
+    DownloadManager.getInstance().updateConfig()
+    syncLibraryWatcher()
+    applyBinaryPathSettings()
+    BrowserWindow.getAllWindows().forEach(window => {
+      if (!window.isDestroyed()) {
+        window.webContents.send('settings-changed', config)
+      }
+    })
+  })
+
+  // Import videos other apps drop into the download folder, if enabled
+  syncLibraryWatcher()
+
+  // Autosaves nobody recovered within a week are dropped
   try {
-    saveDownloadStorage()
-    logger.info('Download storage saved on quit')
+    pruneAutosaves()
   } catch (error) {
-    logger.error('Failed to save download storage on quit', error as Error)
+    logger.warn('Failed to prune project autosaves', { error: (error as Error).message })
+  }
+
+  // macOS/Linux shutdown: hold it until downloads are paused (capped, see services/lifecycle)
+  powerMonitor.on('shutdown', event => {
+    event.preventDefault()
+    app.quit()
+  })
+
+  // Cache library thumbnails in the background so the library doesn't depend on remote images
+  ThumbnailCache.getInstance()
+    .prefetchLibraryThumbnails()
+    .catch(error => {
+      logger.warn('Library thumbnail prefetch failed', error)
+    })
+})
+
+/**
+ * What has to happen before the app exits, in order
+ */
+function getShutdownSteps(): ShutdownStep[] {
+  return [
+    {
+      name: 'notify-windows',
+      run: () => {
+        BrowserWindow.getAllWindows().forEach(window => {
+          if (!window.isDestroyed()) {
+            window.webContents.send('shutdown-in-progress')
+          }
+        })
+      },
+    },
+    {
+      name: 'stop-watchers',
+      run: () => {
+        configManager.stopWatchingConfigFile()
+        stopLibraryWatcher()
+      },
+    },
+    // Paused downloads are saved with their partial files and resume on the next launch
+    { name: 'pause-downloads', run: () => DownloadManager.getInstance().shutdown(), timeoutMs: SHUTDOWN_TIMEOUT },
+    // Force a final save of download storage to ensure all completed downloads are persisted
+    { name: 'save-download-storage', run: () => saveDownloadStorage() },
+  ]
+}
+
+let readyToQuit = false
+
+// Hold the exit until downloads are paused and state is saved
+app.on('before-quit', event => {
+  if (readyToQuit) {
+    return
   }
+  event.preventDefault()
+  requestShutdown(getShutdownSteps()).then(result => {
+    logger.info('App quitting', { ...result })
+    readyToQuit = true
+    app.quit()
+  })
 })
 
 // Clean up resources when all windows are closed
@@ -414,6 +500,9 @@ app.on('window-all-closed', () => {
   stopStreamingProxy().catch(error => {
     logger.warn('Error stopping streaming proxy', error)
   })
+  stopMediaServer().catch(error => {
+    logger.warn('Error stopping media server', error)
+  })
 
   if (process.platform !== 'darwin') {
     app.quit()
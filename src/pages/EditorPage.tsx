@@ -209,6 +209,7 @@ export default function EditorPage() {
         inputPath: path,
         count: 20,
         width: 160,
+        priority: 'high', // The clip in view goes before background warm-ups
       })
 
       if (isSuccessResponse(response) && response.data.thumbnails) {
@@ -238,6 +239,7 @@ export default function EditorPage() {
       const response = await window.electronAPI.videoProcessor.getWaveform({
         inputPath: path,
         samples: 500,
+        priority: 'high',
       })
 
       if (isSuccessResponse(response) && response.data.waveform) {
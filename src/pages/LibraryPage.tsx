@@ -3,14 +3,14 @@
  *
  * Download history and management interface. Features:
  * - Real-time download progress via IPC events
- * - Filter by status (all, active, completed, failed)
+ * - Filter by status (all, active, completed, failed) or continue watching
  * - Video preview modal with blob URL playback
  * - Actions: cancel, delete, retry, open folder, edit
  * - Stats cards showing download counts by status
  */
 
 import { Download, Pause, Play, Square } from 'lucide-react'
-import type { DownloadFilter, DownloadProgress } from '@/types/download'
+import { type DownloadFilter, type DownloadProgress, type LibraryChannel, isCompletedStatus } from '@/types/download'
 import { DownloadsList } from '@/components/library/downloads-list'
 import { LibraryHeader } from '@/components/library/library-header'
 import { LibraryStats } from '@/components/library/library-stats'
@@ -27,6 +27,7 @@ export default function LibraryPage() {
   const { t } = useTranslation()
   const navigate = useNavigate()
   const [downloads, setDownloads] = useState<DownloadProgress[]>([])
+  const [channels, setChannels] = useState<LibraryChannel[]>([])
   const [filter, setFilter] = useState<DownloadFilter>('all')
   const [searchQuery, setSearchQuery] = useState('')
   const [isLoading, setIsLoading] = useState(true)
@@ -41,6 +42,11 @@ export default function LibraryPage() {
     const handleDownloadProgress = (progress: DownloadProgress) => {
       setDownloads(prev => {
         const existingIndex = prev.findIndex(d => d.downloadId === progress.downloadId)
+        const existingSequence = prev[existingIndex]?.sequence ?? 0
+        if (progress.sequence !== undefined && progress.sequence < existingSequence) {
+          // Delivered after a newer state (e.g. from a list refresh) was already shown
+          return prev
+        }
         if (existingIndex >= 0) {
           // Update existing download
           const updated = [...prev]
@@ -55,6 +61,7 @@ export default function LibraryPage() {
 
     const handleDownloadCompleted = (progress: DownloadProgress) => {
       setDownloads(prev => prev.map(d => (d.downloadId === progress.downloadId ? progress : d)))
+      loadChannels()
       toast.success(`${t('msgDownloadCompletedToast')}: ${progress.title}`)
     }
 
@@ -76,10 +83,16 @@ export default function LibraryPage() {
       handleDownloadDeleted(downloadId)
     }
 
+    // A new entry's thumbnail, duration and resolution arrive once its file has been probed
+    const handleIpcVideoUpdated = (_event: any, entry: DownloadProgress) => {
+      setDownloads(prev => prev.map(d => (d.downloadId === entry.downloadId ? { ...d, ...entry } : d)))
+    }
+
     // Listen for IPC events from main process
     window.electronAPI.on('download-progress-update', handleIpcProgress)
     window.electronAPI.on('download-completed', handleIpcCompleted)
     window.electronAPI.on('download-deleted', handleIpcDeleted)
+    window.electronAPI.on('library-video-updated', handleIpcVideoUpdated)
 
     // Keep polling as backup but make it less frequent
     const interval = setInterval(() => {
@@ -101,9 +114,25 @@ export default function LibraryPage() {
       window.electronAPI.removeListener('download-progress-update', handleIpcProgress)
       window.electronAPI.removeListener('download-completed', handleIpcCompleted)
       window.electronAPI.removeListener('download-deleted', handleIpcDeleted)
+      window.electronAPI.removeListener('library-video-updated', handleIpcVideoUpdated)
     }
   }, [filter, selectedDownload])
 
+  React.useEffect(() => {
+    loadChannels()
+  }, [])
+
+  const loadChannels = async () => {
+    try {
+      const response = await window.electronAPI.library.getChannels()
+      if (isSuccessResponse(response)) {
+        setChannels(response.data)
+      }
+    } catch (error) {
+      console.error('Failed to load channels:', error)
+    }
+  }
+
   const loadDownloads = async () => {
     try {
       const response = await window.electronAPI.downloadManager.list(filter)
@@ -131,6 +160,19 @@ export default function LibraryPage() {
     }
   }
 
+  const handleCancelAll = async () => {
+    try {
+      const response = await window.electronAPI.downloadManager.cancelAll()
+      if (isSuccessResponse(response)) {
+        toast.success(t('msgDownloadsStopped', { count: response.data.downloadIds.length }))
+        loadDownloads()
+      }
+    } catch (error) {
+      console.error('Failed to cancel all downloads:', error)
+      toast.error(t('msgDownloadCancelFailed'))
+    }
+  }
+
   const handleDeleteDownload = async (downloadId: string) => {
     try {
       const response = await window.electronAPI.downloadManager.delete(downloadId)
@@ -165,13 +207,13 @@ export default function LibraryPage() {
   const stats = {
     total: downloads.length,
     active: downloads.filter(d => d.status === 'downloading').length,
-    completed: downloads.filter(d => d.status === 'completed').length,
+    completed: downloads.filter(d => isCompletedStatus(d.status)).length,
     failed: downloads.filter(d => d.status === 'failed').length,
   }
 
   // Enhanced preview functionality
   const handlePreviewDownload = async (download: DownloadProgress) => {
-    if (download.status === 'completed' && download.filePath) {
+    if (downloaisCompletedStatus(d.status) && download.filePath) {
       setIsVideoLoading(true)
       try {
         // Check if file exists via IPC
@@ -223,7 +265,7 @@ export default function LibraryPage() {
 
   // Navigate to editor for trimming/editing
   const handleEditDownload = (download: DownloadProgress) => {
-    if (download.status === 'completed' && download.filePath) {
+    if (downloaisCompletedStatus(d.status) && download.filePath) {
       navigate({
         to: '/editor',
         search: { path: download.filePath },
@@ -248,6 +290,13 @@ export default function LibraryPage() {
             {t('statusCompleted')}
           </Badge>
         )
+      case 'completed-with-errors':
+        return (
+          <Badge variant="default">
+            <Play className="mr-1 h-3 w-3" />
+            {t('statusCompletedWithErrors')}
+          </Badge>
+        )
       case 'failed':
         return (
           <Badge variant="destructive">
@@ -277,12 +326,14 @@ export default function LibraryPage() {
       {/* Enhanced Downloads List with Better Features */}
       <DownloadsList
         downloads={downloads}
+        channels={channels}
         filter={filter}
         searchQuery={searchQuery}
         isLoading={isLoading}
         onFilterChange={setFilter}
         onSearchChange={setSearchQuery}
         onCancelDownload={handleCancelDownload}
+        onCancelAll={handleCancelAll}
         onDeleteDownload={handleDeleteDownload}
         onRetryDownload={handleRetryDownload}
         onPreviewDownload={handlePreviewDownload}
@@ -292,7 +343,7 @@ export default function LibraryPage() {
       />
 
       {/* Enhanced Preview Modal for Completed Downloads */}
-      {selectedDownload && selectedDownload.status === 'completed' && (
+      {selectedDownload && selectedDownloaisCompletedStatus(d.status) && (
         <VideoPreviewModal
           download={selectedDownload}
           isLoading={isVideoLoading}
@@ -0,0 +1,199 @@
+/**
+ * Analysis Pool
+ * Every background FFmpeg analysis of the editor (timeline thumbnails, waveforms, scene
+ * detection) runs through one queue, so opening a project with many sources doesn't start
+ * an FFmpeg per source at once. At most editor.maxAnalysisJobs run together (0: half the
+ * CPU cores); the rest wait, higher priority first (the clips in view), then in order.
+ *
+ * Jobs can be tagged with the project they are for. Closing the project cancels its jobs:
+ * waiting ones never start and running ones get their abort signal. 'job' events report
+ * each job's ID, type and status.
+ *
+ * AnalysisScheduler holds just the queueing and knows nothing of FFmpeg.
+ */
+
+import { EventEmitter } from 'events'
+import { availableParallelism } from 'os'
+
+import type { AnalysisJobEvent, AnalysisJobPriority, AnalysisJobType } from '../types/project'
+import { ConfigManager } from '../utils/config'
+import { Logger } from '../utils/logger'
+
+const logger = Logger.getInstance()
+
+const PRIORITY_ORDER: Record<AnalysisJobPriority, number> = { high: 2, normal: 1, low: 0 }
+
+export function isAnalysisJobPriority(value: unknown): value is AnalysisJobPriority {
+  return typeof value === 'string' && value in PRIORITY_ORDER
+}
+
+export interface AnalysisJobOptions {
+  projectId?: string
+  priority?: AnalysisJobPriority
+}
+
+interface QueuedJob {
+  id: string
+  type: AnalysisJobType
+  projectId?: string
+  priority: AnalysisJobPriority
+  sequence: number // Keeps jobs of the same priority in the order they came
+  controller: AbortController
+  run: (signal: AbortSignal) => Promise<unknown>
+  resolve: (value: any) => void
+  reject: (error: Error) => void
+}
+
+export class AnalysisCancelledError extends Error {
+  constructor() {
+    super('Analysis job cancelled')
+    this.name = 'AnalysisCancelledError'
+  }
+}
+
+/**
+ * Priority queue of jobs that runs at most `getConcurrency()` of them at a time. Emits
+ * 'job' with AnalysisJobEvent whenever a job is queued, starts or finishes.
+ */
+export class AnalysisScheduler extends EventEmitter {
+  private queue: QueuedJob[] = []
+  private running = new Map<string, QueuedJob>()
+  private sequence = 0
+
+  constructor(private getConcurrency: () => number) {
+    super()
+  }
+
+  /**
+   * Queue a job. Resolves with its result; rejects with AnalysisCancelledError when it is
+   * cancelled before it starts.
+   */
+  schedule<T>(
+    type: AnalysisJobType,
+    run: (signal: AbortSignal) => Promise<T>,
+    options: AnalysisJobOptions = {},
+  ): { jobId: string; result: Promise<T> } {
+    const sequence = this.sequence++
+    const id = `analysis_${Date.now()}_${sequence.toString(36)}`
+    let job!: QueuedJob
+    const result = new Promise<T>((resolve, reject) => {
+      job = {
+        id,
+        type,
+        projectId: options.projectId,
+        priority: options.priority ?? 'normal',
+        sequence,
+        controller: new AbortController(),
+        run,
+        resolve,
+        reject,
+      }
+    })
+    this.queue.push(job)
+    this.emitJob(job, 'queued')
+    this.drain()
+    return { jobId: id, result }
+  }
+
+  /**
+   * Cancel the jobs of a project, or every job without one. Returns how many were cancelled.
+   */
+  cancel(projectId?: string): number {
+    const matches = (job: QueuedJob) => projectId === undefined || job.projectId === projectId
+    const waiting = this.queue.filter(matches)
+    this.queue = this.queue.filter(job => !matches(job))
+    for (const job of waiting) {
+      this.emitJob(job, 'cancelled')
+      job.reject(new AnalysisCancelledError())
+    }
+    const running = Array.from(this.running.values()).filter(matches)
+    running.forEach(job => job.controller.abort())
+    return waiting.length + running.length
+  }
+
+  /** Jobs waiting to start, in the order they will */
+  getQueuedJobIds(): string[] {
+    return this.orderedQueue().map(job => job.id)
+  }
+
+  getRunningCount(): number {
+    return this.running.size
+  }
+
+  private orderedQueue(): QueuedJob[] {
+    return [...this.queue].sort(
+      (a, b) => PRIORITY_ORDER[b.priority] - PRIORITY_ORDER[a.priority] || a.sequence - b.sequence,
+    )
+  }
+
+  private drain(): void {
+    const limit = Math.max(1, this.getConcurrency())
+    while (this.running.size < limit && this.queue.length > 0) {
+      const job = this.orderedQueue()[0]
+      this.queue = this.queue.filter(queued => queued !== job)
+      this.start(job)
+    }
+  }
+
+  private start(job: QueuedJob): void {
+    this.running.set(job.id, job)
+    this.emitJob(job, 'running')
+    Promise.resolve()
+      .then(() => job.run(job.controller.signal))
+      .then(
+        value => {
+          this.emitJob(job, 'completed')
+          job.resolve(value)
+        },
+        error => {
+          this.emitJob(job, job.controller.signal.aborted ? 'cancelled' : 'failed', (error as Error).message)
+          job.reject(error as Error)
+        },
+      )
+      .finally(() => {
+        this.running.delete(job.id)
+        this.drain()
+      })
+  }
+
+  private emitJob(job: QueuedJob, status: AnalysisJobEvent['status'], error?: string): void {
+    const event: AnalysisJobEvent = { jobId: job.id, type: job.type, projectId: job.projectId, status, error }
+    this.emit('job', event)
+  }
+}
+
+/**
+ * editor.maxAnalysisJobs, or half the CPU cores when it is 0
+ */
+function getMaxAnalysisJobs(): number {
+  const configured = ConfigManager.getInstance().get('editor')?.maxAnalysisJobs
+  if (Number.isInteger(configured) && configured! > 0) {
+    return configured!
+  }
+  return Math.max(1, Math.floor(availableParallelism() / 2))
+}
+
+/** The pool every editor analysis job goes through */
+export const analysisPool = new AnalysisScheduler(getMaxAnalysisJobs)
+
+/**
+ * Run an analysis job through the pool and wait for its result
+ */
+export function runAnalysisJob<T>(
+  type: AnalysisJobType,
+  run: (signal: AbortSignal) => Promise<T>,
+  options?: AnalysisJobOptions,
+): Promise<T> {
+  return analysisPool.schedule(type, run, options).result
+}
+
+/**
+ * Cancel the analysis jobs of a project, e.g. when it is closed
+ */
+export function cancelAnalysisJobs(projectId: string): number {
+  const cancelled = analysisPool.cancel(projectId)
+  if (cancelled > 0) {
+    logger.info('Analysis jobs cancelled', { projectId, cancelled })
+  }
+  return cancelled
+}
@@ -0,0 +1,290 @@
+/**
+ * Backup Manager Service
+ * Packs the app's persistent state (config, download library, optionally the
+ * thumbnail cache) into a single zip and restores it again.
+ *
+ * Note: Clipy has no separate download archive file; download history lives in
+ * downloads.json, which is included as the library.
+ */
+
+import { EventEmitter } from 'events'
+import { existsSync, readFileSync, readdirSync, renameSync, rmSync, statSync, writeFileSync } from 'fs'
+import { basename, dirname, join, relative, sep } from 'path'
+import { app } from 'electron'
+
+import { ConfigManager } from '../utils/config'
+import { FileSystemUtils } from '../utils/file-system'
+import { Logger } from '../utils/logger'
+import { ThumbnailCache } from './thumbnail-cache'
+import { createZip, readZip, type ZipEntry } from '../utils/zip'
+import type { BackupManifest, BackupProgress, BackupResult, RestoreResult } from '../types/system'
+import {
+  getDownloadStorageFilePath,
+  loadDownloadStorage,
+  reloadDownloadStorage,
+  saveDownloadStorage,
+} from './download-storage'
+
+const MANIFEST_NAME = 'manifest.json'
+const BACKUP_FORMAT_VERSION = 1
+const CONFIG_ENTRY = 'config.json'
+const LIBRARY_ENTRY = 'downloads.json'
+const THUMBNAILS_PREFIX = 'thumbnails/'
+
+/**
+ * Compare two semver-like version strings. Returns <0, 0 or >0.
+ * Pre-release suffixes are ignored.
+ */
+function compareVersions(a: string, b: string): number {
+  const parse = (version: string) => version.split('-')[0].split('.').map(part => parseInt(part, 10) || 0)
+  const left = parse(a)
+  const right = parse(b)
+  for (let i = 0; i < Math.max(left.length, right.length); i++) {
+    const diff = (left[i] ?? 0) - (right[i] ?? 0)
+    if (diff !== 0) return diff
+  }
+  return 0
+}
+
+export class BackupManager extends EventEmitter {
+  private static instance: BackupManager
+  private logger = Logger.getInstance()
+  private configManager = ConfigManager.getInstance()
+  private thumbnailCache = ThumbnailCache.getInstance()
+  private fileSystem = FileSystemUtils.getInstance()
+
+  private constructor() {
+    super()
+  }
+
+  static getInstance(): BackupManager {
+    if (!BackupManager.instance) {
+      BackupManager.instance = new BackupManager()
+    }
+    return BackupManager.instance
+  }
+
+  /**
+   * Write a backup zip to outputPath. Emits 'progress' with BackupProgress.
+   */
+  async createBackup(outputPath: string, includeThumbnails: boolean = false): Promise<BackupResult> {
+    this.emitProgress('create', 'collecting', 0, 0)
+
+    // Flush the library so the backup matches what the user sees (config is saved on every change)
+    loadDownloadStorage()
+    saveDownloadStorage()
+
+    const sources: { name: string; path: string }[] = []
+    const configPath = this.configManager.getConfigFilePath()
+    if (existsSync(configPath)) {
+      sources.push({ name: CONFIG_ENTRY, path: configPath })
+    }
+    const libraryPath = getDownloadStorageFilePath()
+    if (existsSync(libraryPath)) {
+      sources.push({ name: LIBRARY_ENTRY, path: libraryPath })
+    }
+    if (includeThumbnails) {
+      const cacheDir = this.thumbnailCache.getCacheDirectory()
+      for (const file of this.listFiles(cacheDir)) {
+        sources.push({ name: THUMBNAILS_PREFIX + relative(cacheDir, file).split(sep).join('/'), path: file })
+      }
+    }
+
+    const entries: ZipEntry[] = []
+    const files: BackupManifest['files'] = []
+    for (let i = 0; i < sources.length; i++) {
+      const { name, path } = sources[i]
+      const data = readFileSync(path)
+      entries.push({ name, data })
+      files.push({ name, size: data.length, modifiedAt: statSync(path).mtimeMs })
+      this.emitProgress('create', 'collecting', i + 1, sources.length)
+    }
+
+    const manifest: BackupManifest = {
+      formatVersion: BACKUP_FORMAT_VERSION,
+      appVersion: app.getVersion(),
+      createdAt: Date.now(),
+      files,
+      includesThumbnails: includeThumbnails,
+    }
+    entries.unshift({ name: MANIFEST_NAME, data: Buffer.from(JSON.stringify(manifest, null, 2), 'utf8') })
+
+    this.emitProgress('create', 'writing', sources.length, sources.length)
+    await this.fileSystem.ensureDirectory(dirname(outputPath))
+    this.writeFileAtomic(outputPath, createZip(entries))
+
+    this.emitProgress('create', 'complete', sources.length, sources.length)
+    this.logger.info('Backup created', { outputPath, files: files.length, includeThumbnails })
+    return { path: outputPath, manifest }
+  }
+
+  /**
+   * Restore a backup zip over the current app data and reload in-memory state.
+   * Refuses backups made by a newer app version. A thumbnail cache in the backup is
+   * extracted next to the live one and swapped in only once every file is written, so a
+   * failed restore leaves the current cache as it was.
+   */
+  async restoreBackup(zipPath: string, includeThumbnails: boolean = false): Promise<RestoreResult> {
+    this.emitProgress('restore', 'reading', 0, 0)
+
+    const entries = readZip(readFileSync(zipPath))
+    const manifest = this.validateManifest(entries)
+
+    const restorable = entries.filter(entry => {
+      if (entry.name === CONFIG_ENTRY || entry.name === LIBRARY_ENTRY) return true
+      return includeThumbnails && entry.name.startsWith(THUMBNAILS_PREFIX)
+    })
+
+    const cacheDir = this.thumbnailCache.getCacheDirectory()
+    // Replace the cache wholesale so stale sprite sheets don't linger next to the restored index
+    const replaceCache = includeThumbnails && manifest.includesThumbnails
+    const stagingDir = `${cacheDir}.restore-${process.pid}`
+    const thumbnailsDir = replaceCache ? stagingDir : cacheDir
+    const targets = restorable.map(entry => ({ entry, target: this.resolveTarget(entry.name, thumbnailsDir) }))
+    const isThumbnail = (name: string) => name.startsWith(THUMBNAILS_PREFIX)
+
+    const restored: string[] = []
+    const restore = async ({ entry, target }: (typeof targets)[number]) => {
+      await this.fileSystem.ensureDirectory(dirname(target))
+      this.writeFileAtomic(target, entry.data)
+      restored.push(entry.name)
+      this.emitProgress('restore', 'restoring', restored.length, targets.length)
+    }
+
+    // Thumbnails first, so a failure while staging them leaves config and library alone too
+    try {
+      rmSync(stagingDir, { recursive: true, force: true })
+      for (const target of targets.filter(({ entry }) => isThumbnail(entry.name))) {
+        await restore(target)
+      }
+      if (replaceCache) {
+        this.swapDirectory(stagingDir, cacheDir)
+      }
+    } finally {
+      rmSync(stagingDir, { recursive: true, force: true })
+    }
+    for (const target of targets.filter(({ entry }) => !isThumbnail(entry.name))) {
+      await restore(target)
+    }
+
+    this.emitProgress('restore', 'reloading', targets.length, targets.length)
+    if (restored.includes(CONFIG_ENTRY)) {
+      this.configManager.reload()
+    }
+    if (restored.includes(LIBRARY_ENTRY)) {
+      reloadDownloadStorage()
+    }
+    if (restored.some(isThumbnail)) {
+      await this.fileSystem.ensureDirectory(cacheDir)
+      await this.thumbnailCache.reloadIndex()
+    }
+
+    this.emitProgress('restore', 'complete', targets.length, targets.length)
+    this.logger.info('Backup restored', { zipPath, restored: restored.length, backupVersion: manifest.appVersion })
+    return { manifest, restored }
+  }
+
+  private validateManifest(entries: ZipEntry[]): BackupManifest {
+    const manifestEntry = entries.find(entry => entry.name === MANIFEST_NAME)
+    if (!manifestEntry) {
+      throw new Error('Backup manifest not found')
+    }
+
+    let manifest: BackupManifest
+    try {
+      manifest = JSON.parse(manifestEntry.data.toString('utf8'))
+    } catch {
+      throw new Error('Backup manifest is not valid JSON')
+    }
+
+    if (typeof manifest.formatVersion !== 'number' || typeof manifest.appVersion !== 'string') {
+      throw new Error('Backup manifest is missing required fields')
+    }
+    if (manifest.formatVersion > BACKUP_FORMAT_VERSION) {
+      throw new Error(`Unsupported backup format version ${manifest.formatVersion}`)
+    }
+    if (compareVersions(manifest.appVersion, app.getVersion()) > 0) {
+      throw new Error(`Backup was created by a newer version of Clipy (${manifest.appVersion})`)
+    }
+
+    const names = new Set(entries.map(entry => entry.name))
+    for (const file of manifest.files ?? []) {
+      if (!names.has(file.name)) {
+        throw new Error(`Backup is missing ${file.name}`)
+      }
+    }
+
+    return manifest
+  }
+
+  /**
+   * Map an archive entry name to its destination, rejecting paths that escape the cache dir
+   */
+  private resolveTarget(name: string, cacheDir: string): string {
+    if (name === CONFIG_ENTRY) return this.configManager.getConfigFilePath()
+    if (name === LIBRARY_ENTRY) return getDownloadStorageFilePath()
+
+    const target = join(cacheDir, ...name.slice(THUMBNAILS_PREFIX.length).split('/'))
+    const rel = relative(cacheDir, target)
+    if (!rel || rel.startsWith('..') || rel.includes('\0')) {
+      throw new Error(`Invalid backup entry: ${name}`)
+    }
+    return target
+  }
+
+  private listFiles(dir: string): string[] {
+    if (!existsSync(dir)) return []
+    const files: string[] = []
+    for (const item of readdirSync(dir, { withFileTypes: true })) {
+      const fullPath = join(dir, item.name)
+      if (item.isDirectory()) {
+        files.push(...this.listFiles(fullPath))
+      } else if (item.isFile()) {
+        files.push(fullPath)
+      }
+    }
+    return files
+  }
+
+  /**
+   * Move a fully written folder into place, replacing the one there. The old folder is
+   * renamed aside first and only deleted once the new one is in.
+   */
+  private swapDirectory(newDir: string, targetDir: string): void {
+    const oldDir = `${targetDir}.old-${process.pid}`
+    rmSync(oldDir, { recursive: true, force: true })
+    if (existsSync(newDir)) {
+      if (existsSync(targetDir)) {
+        renameSync(targetDir, oldDir)
+      }
+      try {
+        renameSync(newDir, targetDir)
+      } catch (error) {
+        if (existsSync(oldDir)) {
+          renameSync(oldDir, targetDir)
+        }
+        throw error
+      }
+    } else if (existsSync(targetDir)) {
+      // The backup's cache was empty
+      renameSync(targetDir, oldDir)
+    }
+    rmSync(oldDir, { recursive: true, force: true })
+  }
+
+  private writeFileAtomic(filePath: string, data: Buffer): void {
+    const tempPath = join(dirname(filePath), `.${basename(filePath)}.${process.pid}.tmp`)
+    writeFileSync(tempPath, data)
+    renameSync(tempPath, filePath)
+  }
+
+  private emitProgress(
+    operation: BackupProgress['operation'],
+    phase: BackupProgress['phase'],
+    processed: number,
+    total: number,
+  ): void {
+    const progress: BackupProgress = { operation, phase, processed, total }
+    this.emit('progress', progress)
+  }
+}
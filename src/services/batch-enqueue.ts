@@ -0,0 +1,223 @@
+/**
+ * Batch Enqueue Service
+ * Queues downloads for a list of URLs: a text file with one URL per line ('#' comments and
+ * blank lines are ignored) or URLs pasted in the UI. Every line ends up accepted, skipped as
+ * a duplicate of a video that is already queued, downloading or downloaded, or invalid, and
+ * the report carries the line numbers. Videos are matched by YouTube video ID, so watch,
+ * youtu.be and shorts links of one video are duplicates; other sites are matched by URL.
+ * URLs the site policy blocks are reported as invalid.
+ *
+ * Files are streamed and handled in chunks that yield to the event loop in between, so a list
+ * of thousands of URLs doesn't stall the main process. 'progress' events on batchEnqueueEvents
+ * report BatchEnqueueProgress after each chunk.
+ */
+
+import { createReadStream } from 'fs'
+import { EventEmitter } from 'events'
+import { createInterface } from 'readline'
+
+import type { BatchEnqueueLine, BatchEnqueueProgress, BatchEnqueueReport, DownloadOptions } from '../types/download'
+import { ConfigManager } from '../utils/config'
+import { formatMessage } from '../utils/i18n'
+import { Logger } from '../utils/logger'
+import { isUrlAllowedByPolicy } from '../utils/site-policy'
+import { ValidationUtils } from '../utils/validation'
+import { DownloadManager } from './download-manager'
+import { getVideoIdFromUrl } from './library'
+
+const logger = Logger.getInstance()
+
+export const BATCH_CHUNK_SIZE = 200
+export const PREFETCH_CONCURRENCY = 4
+
+/** Emits 'progress' with BatchEnqueueProgress */
+export const batchEnqueueEvents = new EventEmitter()
+
+/**
+ * The URL on a list line, or null for blank lines and '#' comments
+ */
+export function parseUrlLine(text: string): string | null {
+  const trimmed = text.trim()
+  return trimmed && !trimmed.startsWith('#') ? trimmed : null
+}
+
+/**
+ * Stream the URL lines of a list file without reading it into memory
+ */
+export async function* readUrlFile(filePath: string): AsyncGenerator<BatchEnqueueLine> {
+  const lines = createInterface({ input: createReadStream(filePath, 'utf-8'), crlfDelay: Infinity })
+  let line = 0
+  for await (const text of lines) {
+    line++
+    const url = parseUrlLine(text)
+    if (url) {
+      yield { line, url }
+    }
+  }
+}
+
+export function* urlListLines(urls: string[]): Generator<BatchEnqueueLine> {
+  for (let i = 0; i < urls.length; i++) {
+    const url = parseUrlLine(String(urls[i] ?? ''))
+    if (url) {
+      yield { line: i + 1, url }
+    }
+  }
+}
+
+/**
+ * Fill the options the caller left out from the download settings, as the download dialog does
+ */
+export function resolveDefaultOptions(options: DownloadOptions = {}): DownloadOptions {
+  const settings = ConfigManager.getInstance().get('download')
+  return {
+    quality: settings.defaultVideoQuality,
+    format: settings.videoFormat,
+    downloadSubtitles: settings.downloadSubtitles,
+    downloadThumbnail: settings.downloadThumbnails,
+    saveMetadata: settings.saveMetadata,
+    createSubdirectories: settings.createSubdirectories,
+    ...options,
+  }
+}
+
+/**
+ * Run task over items with at most `limit` running at once
+ */
+async function runBounded<T>(items: T[], limit: number, task: (item: T) => Promise<void>): Promise<void> {
+  let next = 0
+  const worker = async () => {
+    while (next < items.length) {
+      await task(items[next++])
+    }
+  }
+  await Promise.all(Array.from({ length: Math.min(limit, items.length) }, worker))
+}
+
+const yieldToEventLoop = () => new Promise<void>(resolve => setImmediate(resolve))
+
+/**
+ * Enqueue the URLs from a list source. With prefetchInfo, each accepted URL is looked up
+ * before it is queued (a few at a time), so videos that can't be fetched are reported as
+ * invalid instead of failing later in the queue.
+ */
+export async function enqueueUrlLines(
+  source: AsyncIterable<BatchEnqueueLine> | Iterable<BatchEnqueueLine>,
+  options: DownloadOptions = {},
+  prefetchInfo = false,
+): Promise<BatchEnqueueReport> {
+  const downloadManager = DownloadManager.getInstance()
+  const resolvedOptions = resolveDefaultOptions(options)
+  const knownKeys = downloadManager.getKnownDownloadKeys()
+  const sitePolicy = ConfigManager.getInstance().get('sitePolicy')
+  const report: BatchEnqueueReport = {
+    batchId: `batch_${Date.now()}_${Math.random().toString(36).slice(2, 11)}`,
+    accepted: [],
+    duplicates: [],
+    invalid: [],
+  }
+  let processedLines = 0
+
+  const emitProgress = (done: boolean) => {
+    const progress: BatchEnqueueProgress = {
+      batchId: report.batchId,
+      processedLines,
+      accepted: report.accepted.length,
+      duplicates: report.duplicates.length,
+      invalid: report.invalid.length,
+      done,
+    }
+    batchEnqueueEvents.emit('progress', progress)
+  }
+
+  const processChunk = async (chunk: BatchEnqueueLine[]) => {
+    const toEnqueue: BatchEnqueueLine[] = []
+    for (const entry of chunk) {
+      const validation = ValidationUtils.validateDownloadUrl(entry.url)
+      if (!validation.isValid) {
+        report.invalid.push({ ...entry, error: validation.error || 'Invalid URL' })
+        continue
+      }
+
+      const url = validation.value!
+      const videoId = getVideoIdFromUrl(url) ?? undefined
+      const key = videoId ?? url
+      if (sitePolicy && !isUrlAllowedByPolicy(url, sitePolicy)) {
+        const params = { host: new URL(url).hostname }
+        report.invalid.push({
+          ...entry,
+          error: formatMessage('errorSiteBlocked', params),
+          messageKey: 'errorSiteBlocked',
+          params,
+        })
+      } else if (knownKeys.has(key)) {
+        report.duplicates.push({ ...entry, videoId })
+      } else {
+        // Later lines with the same video are duplicates of this one
+        knownKeys.add(key)
+        toEnqueue.push({ ...entry, url })
+      }
+    }
+
+    if (prefetchInfo) {
+      await runBounded(toEnqueue, PREFETCH_CONCURRENCY, async entry => {
+        try {
+          const { downloadId } = await downloadManager.startDownload(entry.url, resolvedOptions)
+          report.accepted.push({ ...entry, downloadId })
+        } catch (error) {
+          report.invalid.push({ ...entry, error: (error as Error).message })
+        }
+      })
+    } else {
+      for (const entry of toEnqueue) {
+        const { downloadId } = downloadManager.enqueueDownload(entry.url, resolvedOptions)
+        report.accepted.push({ ...entry, downloadId })
+      }
+    }
+
+    processedLines += chunk.length
+    emitProgress(false)
+    await yieldToEventLoop()
+  }
+
+  let chunk: BatchEnqueueLine[] = []
+  for await (const entry of source) {
+    chunk.push(entry)
+    if (chunk.length >= BATCH_CHUNK_SIZE) {
+      await processChunk(chunk)
+      chunk = []
+    }
+  }
+  if (chunk.length > 0) {
+    await processChunk(chunk)
+  }
+
+  // Concurrent lookups finish out of order
+  report.accepted.sort((a, b) => a.line - b.line)
+  report.invalid.sort((a, b) => a.line - b.line)
+  emitProgress(true)
+
+  logger.info('Batch enqueue finished', {
+    batchId: report.batchId,
+    accepted: report.accepted.length,
+    duplicates: report.duplicates.length,
+    invalid: report.invalid.length,
+  })
+  return report
+}
+
+export function enqueueUrlsFromFile(
+  filePath: string,
+  options?: DownloadOptions,
+  prefetchInfo?: boolean,
+): Promise<BatchEnqueueReport> {
+  return enqueueUrlLines(readUrlFile(filePath), options, prefetchInfo)
+}
+
+export function enqueueUrls(
+  urls: string[],
+  options?: DownloadOptions,
+  prefetchInfo?: boolean,
+): Promise<BatchEnqueueReport> {
+  return enqueueUrlLines(urlListLines(urls), options, prefetchInfo)
+}
@@ -0,0 +1,200 @@
+/**
+ * Binary Installer
+ * Downloads an FFmpeg build into the app data bin/ directory, which resolveExecutable
+ * searches after the bundled resources/, so a user without FFmpeg can fix that from the app.
+ *
+ * Progress is reported per chunk while downloading, then as a separate 'extracting' stage
+ * (extraction has no meaningful byte progress). Windows and macOS builds are zips read with
+ * our own zip reader; the Linux builds are only published as .tar.xz, which the system tar handles.
+ */
+
+import { execFile } from 'child_process'
+import { chmodSync, createWriteStream, mkdirSync, readFileSync, readdirSync, rmSync, writeFileSync } from 'fs'
+import { basename, join } from 'path'
+import { randomUUID } from 'crypto'
+import { net } from 'electron'
+
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+import { readZip } from '../utils/zip'
+import { refreshFfmpegPath } from './downloader/yt-dlp-provider'
+import type { BinaryInstallProgress, BinaryInstallResult } from '../types/system'
+
+const logger = Logger.getInstance()
+
+const PROGRESS_INTERVAL = 250 // ms between download progress reports
+const EXTRACT_TIMEOUT = 5 * 60 * 1000
+
+const BTBN_RELEASE_URL = 'https://github.com/BtbN/FFmpeg-Builds/releases/download/latest'
+
+/** Archives to download per platform; each contains ffmpeg, ffprobe or both */
+function getFfmpegArchiveUrls(): string[] {
+  const { isWindows, isMacOS, arch } = PlatformUtils.getInstance().getPlatformInfo()
+  if (isWindows) {
+    return [`${BTBN_RELEASE_URL}/ffmpeg-master-latest-win64-gpl.zip`]
+  }
+  if (isMacOS) {
+    // No official macOS builds; evermeet.cx publishes ffmpeg and ffprobe as separate zips
+    return ['https://evermeet.cx/ffmpeg/getrelease/zip', 'https://evermeet.cx/ffmpeg/getrelease/ffprobe/zip']
+  }
+  const linuxArch = arch === 'arm64' ? 'linuxarm64' : 'linux64'
+  return [`${BTBN_RELEASE_URL}/ffmpeg-master-latest-${linuxArch}-gpl.tar.xz`]
+}
+
+let installInProgress: Promise<BinaryInstallResult> | null = null
+
+/**
+ * Download and install FFmpeg and FFprobe. Concurrent calls share the same install.
+ */
+export function installFfmpeg(onProgress?: (progress: BinaryInstallProgress) => void): Promise<BinaryInstallResult> {
+  if (!installInProgress) {
+    installInProgress = runFfmpegInstall(onProgress ?? (() => {})).finally(() => {
+      installInProgress = null
+    })
+  }
+  return installInProgress
+}
+
+async function runFfmpegInstall(onProgress: (progress: BinaryInstallProgress) => void): Promise<BinaryInstallResult> {
+  const platform = PlatformUtils.getInstance()
+  const binDir = platform.getInstalledBinariesDir()
+  const workDir = platform.getTempDir(`clipy-ffmpeg-${randomUUID()}`)
+  const urls = getFfmpegArchiveUrls()
+  const extension = platform.getPlatformInfo().isWindows ? '.exe' : ''
+  const wanted = [`ffmpeg${extension}`, `ffprobe${extension}`]
+
+  mkdirSync(workDir, { recursive: true })
+  mkdirSync(binDir, { recursive: true })
+  logger.info('Installing FFmpeg', { urls, binDir })
+
+  try {
+    const archives: string[] = []
+    for (let i = 0; i < urls.length; i++) {
+      const archivePath = join(workDir, `archive-${i}${urls[i].endsWith('.tar.xz') ? '.tar.xz' : '.zip'}`)
+      await downloadArchive(urls[i], archivePath, (bytesDownloaded, bytesTotal) => {
+        // Each archive is an equal share of the overall percentage
+        const fraction = bytesTotal ? bytesDownloaded / bytesTotal : null
+        onProgress({
+          binary: 'ffmpeg',
+          stage: 'downloading',
+          bytesDownloaded,
+          bytesTotal,
+          percent: fraction === null ? null : Math.round(((i + fraction) / urls.length) * 1000) / 10,
+        })
+      })
+      archives.push(archivePath)
+    }
+
+    onProgress({ binary: 'ffmpeg', stage: 'extracting', bytesDownloaded: 0, bytesTotal: null, percent: 100 })
+    const extractDir = join(workDir, 'extracted')
+    mkdirSync(extractDir, { recursive: true })
+    for (const archivePath of archives) {
+      await extractArchive(archivePath, extractDir, wanted)
+    }
+
+    const installed: Record<string, string> = {}
+    for (const name of wanted) {
+      const found = findFile(extractDir, name)
+      if (!found) {
+        throw new Error(`${name} was not found in the downloaded archive`)
+      }
+      const target = join(binDir, name)
+      writeFileSync(target, readFileSync(found))
+      if (!extension) {
+        chmodSync(target, 0o755)
+      }
+      installed[name] = target
+    }
+
+    const ffmpegPath = refreshFfmpegPath()
+    onProgress({ binary: 'ffmpeg', stage: 'completed', bytesDownloaded: 0, bytesTotal: null, percent: 100 })
+    logger.info('FFmpeg installed', { ffmpegPath, binDir })
+    return { ffmpegPath: ffmpegPath ?? installed[wanted[0]], ffprobePath: installed[wanted[1]] }
+  } finally {
+    rmSync(workDir, { recursive: true, force: true })
+  }
+}
+
+/**
+ * Stream a download to disk, reporting bytes at most every PROGRESS_INTERVAL
+ */
+async function downloadArchive(
+  url: string,
+  outputPath: string,
+  onBytes: (bytesDownloaded: number, bytesTotal: number | null) => void,
+): Promise<void> {
+  const response = await net.fetch(url)
+  if (!response.ok || !response.body) {
+    throw new Error(`Download failed with HTTP ${response.status}: ${url}`)
+  }
+
+  const contentLength = parseInt(response.headers.get('content-length') ?? '', 10)
+  const bytesTotal = contentLength > 0 ? contentLength : null
+  const file = createWriteStream(outputPath)
+  const reader = response.body.getReader()
+  let bytesDownloaded = 0
+  let lastReport = 0
+
+  try {
+    for (;;) {
+      const { done, value } = await reader.read()
+      if (done) break
+
+      bytesDownloaded += value.length
+      if (!file.write(value)) {
+        await new Promise(resolveDrain => file.once('drain', resolveDrain))
+      }
+
+      const now = Date.now()
+      if (now - lastReport >= PROGRESS_INTERVAL) {
+        lastReport = now
+        onBytes(bytesDownloaded, bytesTotal)
+      }
+    }
+  } finally {
+    await new Promise<void>(resolveClose => file.end(() => resolveClose()))
+  }
+
+  onBytes(bytesDownloaded, bytesTotal ?? bytesDownloaded)
+}
+
+/**
+ * Extract only the wanted binaries from an archive
+ */
+async function extractArchive(archivePath: string, outputDir: string, wanted: string[]): Promise<void> {
+  if (archivePath.endsWith('.zip')) {
+    const entries = readZip(readFileSync(archivePath), name => wanted.includes(basename(name)))
+    for (const entry of entries) {
+      writeFileSync(join(outputDir, basename(entry.name)), entry.data)
+    }
+    return
+  }
+
+  await new Promise<void>((resolveExtract, reject) => {
+    execFile(
+      'tar',
+      ['-xJf', archivePath, '-C', outputDir, '--wildcards', ...wanted.map(name => `*/bin/${name}`)],
+      { timeout: EXTRACT_TIMEOUT, windowsHide: true },
+      (error, _stdout, stderr) => {
+        if (error) {
+          reject(new Error(`Failed to extract ${basename(archivePath)}: ${stderr.trim() || error.message}`))
+          return
+        }
+        resolveExtract()
+      },
+    )
+  })
+}
+
+function findFile(directory: string, name: string): string | null {
+  for (const entry of readdirSync(directory, { withFileTypes: true })) {
+    const entryPath = join(directory, entry.name)
+    if (entry.isDirectory()) {
+      const found = findFile(entryPath, name)
+      if (found) return found
+    } else if (entry.name === name) {
+      return entryPath
+    }
+  }
+  return null
+}
@@ -0,0 +1,122 @@
+/**
+ * Binary Versions
+ * Versions of the installed yt-dlp and FFmpeg, for callers that only need the version
+ * string and not the full health check. Each binary is asked once per session; call
+ * invalidateBinaryVersions after an update, or applyBinaryPathSettings after the custom
+ * paths in Settings > Advanced changed, to detect them again.
+ *
+ * The yt-dlp version comes from the capabilities probe downloads already run, which is
+ * also re-probed when the binary file changes on disk.
+ */
+
+import { execFile } from 'child_process'
+import { existsSync } from 'fs'
+import { isAbsolute } from 'path'
+
+import type { BinaryType, BinaryVersion } from '../types/system'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+import { assertYtdlpSupported, getYtdlpCapabilities, resetYtdlpCapabilities } from './downloader/yt-dlp-capabilities'
+import { getFfmpegPath, getYtdlpPath, refreshBinaryPaths } from './downloader/yt-dlp-provider'
+
+const logger = Logger.getInstance()
+
+const VERSION_PROBE_TIMEOUT = 10000
+
+let ffmpegVersion: { path: string; version: Promise<string> } | null = null
+
+/** 'ffmpeg version 6.1.1-full_build-www.gyan.dev Copyright ...' -> '6.1.1-full_build-www.gyan.dev' */
+function parseFfmpegVersion(firstLine: string): string | null {
+  return firstLine.match(/^ffmpeg version (\S+)/)?.[1] ?? null
+}
+
+/** First line of `ffmpeg -version` */
+function probeFfmpegVersion(ffmpegPath: string): Promise<string> {
+  return new Promise((resolve, reject) => {
+    const options = { timeout: VERSION_PROBE_TIMEOUT, windowsHide: true }
+    execFile(ffmpegPath, ['-hide_banner', '-version'], options, (error, stdout) => {
+      if (error) {
+        reject(error)
+        return
+      }
+      resolve(stdout.trim().split(/\r?\n/)[0] ?? '')
+    })
+  })
+}
+
+/**
+ * Installed yt-dlp version and path; null if yt-dlp isn't installed
+ */
+export async function getYtdlpVersion(): Promise<BinaryVersion | null> {
+  const ytdlpPath = getYtdlpPath()
+  if (!ytdlpPath) {
+    return null
+  }
+  const { version } = await getYtdlpCapabilities(ytdlpPath)
+  return { path: ytdlpPath, version: version ?? 'unknown' }
+}
+
+/**
+ * Installed FFmpeg version and path; null if FFmpeg isn't installed
+ */
+export async function getFfmpegVersion(): Promise<BinaryVersion | null> {
+  const ffmpegPath = getFfmpegPath()
+  if (!ffmpegPath) {
+    return null
+  }
+
+  let cached = ffmpegVersion
+  if (cached?.path !== ffmpegPath) {
+    const version = probeFfmpegVersion(ffmpegPath).then(
+      firstLine => parseFfmpegVersion(firstLine) ?? firstLine,
+      error => {
+        ffmpegVersion = null // Don't cache a failure
+        throw error
+      },
+    )
+    cached = ffmpegVersion = { path: ffmpegPath, version }
+  }
+  return { path: ffmpegPath, version: await cached.version }
+}
+
+/** Forget the detected versions so the next call runs the binaries again */
+export function invalidateBinaryVersions(): void {
+  ffmpegVersion = null
+  resetYtdlpCapabilities()
+  logger.debug('Binary version cache cleared')
+}
+
+/**
+ * Pick up changed custom binary paths from settings: detect the binaries and their
+ * versions again
+ */
+export function applyBinaryPathSettings(): void {
+  refreshBinaryPaths()
+  invalidateBinaryVersions()
+}
+
+/**
+ * Check that a path chosen for a custom binary is the binary it claims to be, before it
+ * is saved to settings. Resolves with its version; yt-dlp must also be recent enough.
+ */
+export async function validateCustomBinaryPath(binaryPath: string, type: BinaryType): Promise<string> {
+  const platform = PlatformUtils.getInstance()
+  if (!isAbsolute(binaryPath) || !existsSync(binaryPath) || !platform.isExecutable(binaryPath)) {
+    throw new Error(`Not an executable file: ${binaryPath}`)
+  }
+
+  if (type === 'ffmpeg') {
+    const version = parseFfmpegVersion(await probeFfmpegVersion(binaryPath))
+    if (!version) {
+      throw new Error(`Not an FFmpeg binary: ${binaryPath}`)
+    }
+    return version
+  }
+
+  const capabilities = await getYtdlpCapabilities(binaryPath)
+  assertYtdlpSupported(capabilities)
+  if (!capabilities.version) {
+    throw new Error(`Not a yt-dlp binary: ${binaryPath}`)
+  }
+  return capabilities.version
+}
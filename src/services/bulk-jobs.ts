@@ -0,0 +1,171 @@
+/**
+ * Bulk Jobs
+ * Operations over many library items (e.g. deleting hundreds of downloads with their
+ * files) run in the background: the caller gets a job ID at once, progress is emitted
+ * as it goes and failures are collected per item instead of only being logged.
+ *
+ * The loop yields to the event loop between items so IPC keeps flowing, and checks
+ * for cancellation before each item.
+ */
+
+import { EventEmitter } from 'events'
+
+import type { BulkJobItemError, BulkJobKind, BulkJobProgress } from '../types/download'
+import { Logger } from '../utils/logger'
+
+const logger = Logger.getInstance()
+
+/** ms between progress events of background jobs */
+export const PROGRESS_INTERVAL = 100
+const MAX_FINISHED_JOBS = 20 // Finished jobs kept for status queries
+
+/**
+ * Background jobs by ID. Finished jobs stay queryable until MAX_FINISHED_JOBS newer ones finish.
+ * Shared by bulk jobs and library conversions.
+ */
+export class JobRegistry<T extends { progress: { jobId: string; status: string } }> {
+  private jobs = new Map<string, T>()
+
+  constructor(private readonly idPrefix: string) {}
+
+  generateJobId(): string {
+    return `${this.idPrefix}_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`
+  }
+
+  add(job: T): void {
+    this.jobs.set(job.progress.jobId, job)
+  }
+
+  get(jobId: string): T | undefined {
+    return this.jobs.get(jobId)
+  }
+
+  pruneFinishedJobs(): void {
+    const finished = Array.from(this.jobs.values()).filter(job => job.progress.status !== 'running')
+    for (const job of finished.slice(0, Math.max(0, finished.length - MAX_FINISHED_JOBS))) {
+      this.jobs.delete(job.progress.jobId)
+    }
+  }
+}
+
+export interface BulkJobItem {
+  id: string
+  title?: string
+}
+
+export interface BulkJobItemOutcome {
+  ok: boolean
+  code?: string
+  error?: string
+}
+
+/** Processes one item; a thrown error counts as that item failing */
+export type BulkJobWorker = (id: string) => Promise<BulkJobItemOutcome>
+
+interface BulkJob {
+  progress: BulkJobProgress
+  cancelled: boolean
+}
+
+const jobs = new JobRegistry<BulkJob>('bulk')
+
+/** Emits 'progress' with BulkJobProgress */
+export const bulkJobEvents = new EventEmitter()
+
+function snapshot(job: BulkJob): BulkJobProgress {
+  return { ...job.progress, errors: [...job.progress.errors] }
+}
+
+async function runJob(job: BulkJob, items: BulkJobItem[], worker: BulkJobWorker): Promise<void> {
+  let lastEmit = 0
+  const emitProgress = (force: boolean) => {
+    const now = Date.now()
+    if (force || now - lastEmit >= PROGRESS_INTERVAL) {
+      lastEmit = now
+      bulkJobEvents.emit('progress', snapshot(job))
+    }
+  }
+
+  for (const item of items) {
+    if (job.cancelled) break
+
+    job.progress.currentTitle = item.title
+    emitProgress(false)
+
+    let outcome: BulkJobItemOutcome
+    try {
+      outcome = await worker(item.id)
+    } catch (error) {
+      outcome = { ok: false, error: (error as Error).message }
+    }
+
+    job.progress.done++
+    if (outcome.ok) {
+      job.progress.succeeded++
+    } else {
+      const itemError: BulkJobItemError = { id: item.id, error: outcome.error || 'Operation failed' }
+      if (outcome.code) itemError.code = outcome.code
+      job.progress.errors.push(itemError)
+    }
+
+    // Let IPC and timers run between items
+    await new Promise(resolve => setImmediate(resolve))
+  }
+
+  job.progress.status = job.cancelled ? 'cancelled' : 'completed'
+  job.progress.currentTitle = undefined
+  job.progress.finishedAt = Date.now()
+  emitProgress(true)
+  logger.info('Bulk job finished', {
+    jobId: job.progress.jobId,
+    kind: job.progress.kind,
+    status: job.progress.status,
+    done: job.progress.done,
+    total: job.progress.total,
+    errors: job.progress.errors.length,
+  })
+  jobs.pruneFinishedJobs()
+}
+
+/**
+ * Start processing items in the background. Returns the job ID.
+ */
+export function startBulkJob(kind: BulkJobKind, items: BulkJobItem[], worker: BulkJobWorker): string {
+  const job: BulkJob = {
+    progress: {
+      jobId: jobs.generateJobId(),
+      kind,
+      status: 'running',
+      done: 0,
+      total: items.length,
+      succeeded: 0,
+      errors: [],
+      startedAt: Date.now(),
+    },
+    cancelled: false,
+  }
+  jobs.add(job)
+  logger.info('Bulk job started', { jobId: job.progress.jobId, kind, total: items.length })
+
+  runJob(job, items, worker).catch(error => {
+    logger.error('Bulk job failed', error as Error, { jobId: job.progress.jobId })
+  })
+  return job.progress.jobId
+}
+
+export function getBulkJobStatus(jobId: string): BulkJobProgress | null {
+  const job = jobs.get(jobId)
+  return job ? snapshot(job) : null
+}
+
+/**
+ * Stop a running job before its next item. Items already processed stay processed.
+ */
+export function cancelBulkJob(jobId: string): boolean {
+  const job = jobs.get(jobId)
+  if (!job || job.progress.status !== 'running') {
+    return false
+  }
+  job.cancelled = true
+  return true
+}
@@ -0,0 +1,175 @@
+/**
+ * Channels
+ * Channels of library videos, kept in channels.json next to downloads.json. Library entries
+ * store the channel ID, so a channel's videos stay in one group when it is renamed; its
+ * earlier names are kept so searching for them still finds its videos.
+ *
+ * Every download completion updates its channel's name and last-seen time and caches the
+ * avatar. The library stays the source of truth: channels of entries this file doesn't
+ * know yet (e.g. after restoring a backup) are added from the entries.
+ */
+
+import { existsSync, readFileSync, writeFileSync } from 'fs'
+import { join } from 'path'
+import { app } from 'electron'
+
+import type { DownloadProgress, LibraryChannel, LibraryChannelSummary, VideoChannel } from '../types/download'
+import { Logger } from '../utils/logger'
+import { getStoredDownloads, saveDownloadStorage } from './download-storage'
+import { getChannelAvatarUrl } from './downloader/yt-dlp-provider'
+import { getLibraryEntries } from './library'
+import { ThumbnailCache } from './thumbnail-cache'
+
+const logger = Logger.getInstance()
+
+const channelsFilePath = join(app.getPath('userData'), 'channels.json')
+
+let channels: Map<string, LibraryChannel> | null = null
+
+function loadChannels(): Map<string, LibraryChannel> {
+  if (channels) {
+    return channels
+  }
+
+  try {
+    const stored = existsSync(channelsFilePath) ? JSON.parse(readFileSync(channelsFilePath, 'utf-8')) : {}
+    const list: LibraryChannel[] = stored.channels ?? []
+    channels = new Map(list.map(channel => [channel.id, channel]))
+  } catch (error) {
+    logger.warn('Error loading channels, starting empty', error as Error)
+    channels = new Map()
+  }
+  backfillChannelIds()
+  return channels
+}
+
+function saveChannels(): void {
+  try {
+    const data = { channels: Array.from(loadChannels().values()), lastUpdated: Date.now() }
+    writeFileSync(channelsFilePath, JSON.stringify(data, null, 2), 'utf-8')
+  } catch (error) {
+    logger.error('Failed to save channels', error as Error)
+  }
+}
+
+/**
+ * Give library entries saved before channel IDs were recorded the ID of the channel that
+ * has (or had) their channel name. Names used by more than one channel are left alone.
+ */
+function backfillChannelIds(): void {
+  const idsByName = new Map<string, string | null>()
+  for (const channel of loadChannels().values()) {
+    for (const name of [channel.name, ...channel.previousNames]) {
+      const existing = idsByName.get(name)
+      idsByName.set(name, existing === undefined || existing === channel.id ? channel.id : null)
+    }
+  }
+
+  let updated = 0
+  for (const download of getStoredDownloads()) {
+    const channelId = !download.channelId && download.channel ? idsByName.get(download.channel) : null
+    if (channelId) {
+      download.channelId = channelId
+      updated++
+    }
+  }
+  if (updated > 0) {
+    saveDownloadStorage()
+    logger.info('Channel IDs backfilled from channel names', { updated })
+  }
+}
+
+/**
+ * Cache the channel's avatar if it isn't cached, looking up its URL on the channel page
+ * when the video info had none
+ */
+async function cacheChannelAvatar(channel: LibraryChannel): Promise<void> {
+  if (channel.avatarPath && existsSync(channel.avatarPath)) {
+    return
+  }
+
+  try {
+    const url = channel.avatarUrl || (await getChannelAvatarUrl(channel.id))
+    if (!url) return
+    channel.avatarUrl = url
+    channel.avatarPath = await ThumbnailCache.getInstance().cacheChannelAvatar(channel.id, url)
+    saveChannels()
+  } catch (error) {
+    logger.debug('Failed to cache channel avatar', { channelId: channel.id, error: (error as Error).message })
+  }
+}
+
+/**
+ * Update a channel after one of its videos was downloaded: follow a rename, mark it seen
+ * and cache its avatar
+ */
+export async function recordChannel(videoChannel: VideoChannel): Promise<void> {
+  const { id, name, thumbnail } = videoChannel
+  if (!id || !name) return
+
+  const known = loadChannels()
+  let channel = known.get(id)
+  if (!channel) {
+    channel = { id, name, previousNames: [], avatarUrl: thumbnail || undefined, lastSeen: Date.now() }
+    known.set(id, channel)
+  } else {
+    if (name !== channel.name) {
+      logger.info('Channel renamed', { channelId: id, from: channel.name, to: name })
+      channel.previousNames = [channel.name, ...channel.previousNames.filter(previous => previous !== name)]
+      channel.name = name
+    }
+    if (thumbnail && thumbnail !== channel.avatarUrl) {
+      channel.avatarUrl = thumbnail
+      channel.avatarPath = undefined // Cache the new avatar
+    }
+    channel.lastSeen = Date.now()
+  }
+  saveChannels()
+
+  backfillChannelIds() // Older entries may carry this channel's name without its ID
+  await cacheChannelAvatar(channel)
+}
+
+/**
+ * Channels with videos in the library, most videos first
+ */
+export function getChannels(): LibraryChannelSummary[] {
+  const known = loadChannels()
+  const counts = new Map<string, number>()
+  let added = false
+
+  // Entries are newest first, so a channel missing from channels.json gets its latest name
+  for (const entry of getLibraryEntries()) {
+    if (!entry.channelId) continue
+    counts.set(entry.channelId, (counts.get(entry.channelId) ?? 0) + 1)
+    if (!known.has(entry.channelId)) {
+      known.set(entry.channelId, {
+        id: entry.channelId,
+        name: entry.channel || entry.channelId,
+        previousNames: [],
+        lastSeen: entry.completedAt ?? entry.startTime,
+      })
+      added = true
+    }
+  }
+  if (added) {
+    saveChannels()
+  }
+
+  return Array.from(known.values())
+    .filter(channel => counts.has(channel.id))
+    .map(channel => ({
+      ...channel,
+      avatarPath: channel.avatarPath && existsSync(channel.avatarPath) ? channel.avatarPath : undefined,
+      videoCount: counts.get(channel.id)!,
+    }))
+    .sort((a, b) => b.videoCount - a.videoCount || a.name.localeCompare(b.name))
+}
+
+/**
+ * Library videos of a channel, newest first
+ */
+export function getVideosByChannel(channelId: string): DownloadProgress[] {
+  loadChannels() // Runs the name backfill for entries saved before channel IDs
+  return getLibraryEntries().filter(entry => entry.channelId === channelId)
+}
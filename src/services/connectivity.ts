@@ -0,0 +1,161 @@
+/**
+ * Connectivity Test
+ * "Test connection" in the advanced settings: HEAD requests to google.com (internet),
+ * github.com (yt-dlp and FFmpeg updates) and youtube.com, timed, each with its own
+ * CONNECTIVITY_TIMEOUT.
+ *
+ * The requests go through advanced.proxyUrl when it is set, like yt-dlp's do, in a session
+ * of their own so the app's other requests keep their proxy. The proxy itself is checked
+ * by connecting to it, which tells an unreachable proxy apart from one that can't get
+ * through. Chromium can't log in to SOCKS proxies, so with a SOCKS proxy that needs a
+ * password the sites show as unreachable even when yt-dlp gets through.
+ */
+
+import { createConnection } from 'net'
+import { type Session, net, session } from 'electron'
+
+import type { ConnectivityReport, ProxyInfo } from '../types/system'
+import { ConfigManager } from '../utils/config'
+import { Logger } from '../utils/logger'
+import { ValidationUtils } from '../utils/validation'
+
+const logger = Logger.getInstance()
+
+const CONNECTIVITY_TIMEOUT = 5000 // ms per request
+const CONNECTIVITY_PARTITION = 'connectivity-test' // In memory only, no 'persist:' prefix
+
+const TARGETS = {
+  internet: 'https://www.google.com/',
+  github: 'https://github.com/',
+  youtube: 'https://www.youtube.com/',
+} as const
+
+type Target = keyof typeof TARGETS
+
+export interface ProxyCredentials {
+  username: string
+  password: string
+}
+
+/**
+ * Time a HEAD request in ms; any HTTP response counts as reachable
+ */
+function timeHeadRequest(ses: Session, url: string, credentials: ProxyCredentials | null): Promise<number> {
+  return new Promise((resolve, reject) => {
+    const startedAt = Date.now()
+    const request = net.request({ method: 'HEAD', url, session: ses })
+    const timer = setTimeout(() => {
+      request.abort()
+      reject(new Error(`No response within ${CONNECTIVITY_TIMEOUT / 1000} seconds`))
+    }, CONNECTIVITY_TIMEOUT)
+
+    request.on('login', (authInfo, callback) => {
+      if (authInfo.isProxy && credentials) {
+        callback(credentials.username, credentials.password)
+      } else {
+        callback()
+      }
+    })
+    request.on('response', response => {
+      clearTimeout(timer)
+      response.on('data', () => {})
+      resolve(Date.now() - startedAt)
+    })
+    request.on('error', error => {
+      clearTimeout(timer)
+      reject(error)
+    })
+    request.end()
+  })
+}
+
+/**
+ * Time a TCP connection to the proxy in ms
+ */
+function timeProxyConnection(host: string, port: number): Promise<number> {
+  return new Promise((resolve, reject) => {
+    const startedAt = Date.now()
+    const socket = createConnection({ host, port, timeout: CONNECTIVITY_TIMEOUT })
+    socket.once('connect', () => {
+      socket.destroy()
+      resolve(Date.now() - startedAt)
+    })
+    socket.once('timeout', () => {
+      socket.destroy()
+      reject(new Error(`No response within ${CONNECTIVITY_TIMEOUT / 1000} seconds`))
+    })
+    socket.once('error', reject)
+  })
+}
+
+/**
+ * Chromium proxy rule for the configured proxy. It resolves names through SOCKS proxies
+ * anyway, so socks4a and socks5h map to socks4 and socks5.
+ */
+function toProxyRule(scheme: string, host: string, port: number): string {
+  const chromiumScheme = scheme === 'socks4a' ? 'socks4' : scheme === 'socks5h' ? 'socks5' : scheme
+  return `${chromiumScheme}://${host.includes(':') ? `[${host}]` : host}:${port}`
+}
+
+/**
+ * Send a session's requests through advanced.proxyUrl, or the system proxy when it is empty.
+ * Returns the proxy, and its login for answering the 'login' event of those requests.
+ */
+export async function applyConfiguredProxy(
+  ses: Session,
+): Promise<{ proxy: ProxyInfo | null; credentials: ProxyCredentials | null }> {
+  const proxyUrl = ConfigManager.getInstance().get('advanced')?.proxyUrl?.trim() ?? ''
+  if (!proxyUrl) {
+    await ses.setProxy({ mode: 'system' })
+    return { proxy: null, credentials: null }
+  }
+
+  const proxy = ValidationUtils.validateProxyUrl(proxyUrl)
+  if (!proxy.isValid || !proxy.value) {
+    throw new Error(`Invalid proxy URL: ${proxy.error}`)
+  }
+  const { scheme, host, port, hasAuth } = proxy.value
+  let credentials: ProxyCredentials | null = null
+  if (hasAuth) {
+    const parsed = new URL(proxyUrl)
+    credentials = { username: decodeURIComponent(parsed.username), password: decodeURIComponent(parsed.password) }
+  }
+  await ses.setProxy({ proxyRules: toProxyRule(scheme, host, port) })
+  return { proxy: proxy.value, credentials }
+}
+
+export async function testConnectivity(): Promise<ConnectivityReport> {
+  const ses = session.fromPartition(CONNECTIVITY_PARTITION)
+  const latencyMs: Record<string, number> = {}
+  const errors: Record<string, string> = {}
+  let proxyOk: boolean | null = null
+
+  const { proxy, credentials } = await applyConfiguredProxy(ses)
+  if (proxy) {
+    try {
+      latencyMs.proxy = await timeProxyConnection(proxy.host, proxy.port)
+      proxyOk = true
+    } catch (error) {
+      errors.proxy = (error as Error).message
+      proxyOk = false
+    }
+  }
+  await ses.closeAllConnections() // Otherwise a repeated test reuses the last run's connections
+
+  const results = await Promise.all(
+    (Object.keys(TARGETS) as Target[]).map(async target => {
+      try {
+        latencyMs[target] = await timeHeadRequest(ses, TARGETS[target], credentials)
+        return true
+      } catch (error) {
+        errors[target] = (error as Error).message
+        return false
+      }
+    }),
+  )
+  const [internetOk, githubOk, youtubeOk] = results
+
+  const report: ConnectivityReport = { internetOk, githubOk, youtubeOk, proxyOk, latencyMs, errors }
+  logger.info('Connectivity tested', { ...report, viaProxy: proxyOk !== null })
+  return report
+}
@@ -0,0 +1,156 @@
+/**
+ * Diagnostics Service
+ * Collects what a bug report needs (app and OS versions, health check results, FFmpeg
+ * build features, file locations, queue and cache state) into one report that can be
+ * copied to the clipboard or saved as JSON.
+ *
+ * The report must never contain settings values or library contents: only paths,
+ * counts and versions.
+ */
+
+import { execFile } from 'child_process'
+import { readFileSync, writeFileSync } from 'fs'
+import { join } from 'path'
+import { app } from 'electron'
+
+import type { DiagnosticsReport, FfmpegFeatures, StorageUsage, SystemInfo } from '../types/system'
+import { ConfigManager } from '../utils/config'
+import { AUDIO_ENCODERS, VIDEO_ENCODERS } from '../utils/ffmpeg-args'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+import { DownloadManager } from './download-manager'
+import { getDownloadStorageFilePath } from './download-storage'
+import { getFfmpegPath } from './downloader/yt-dlp-provider'
+import { getYtdlpEnvironmentInfo } from './downloader/yt-dlp-environment'
+import { runHealthCheck } from './health-check'
+import { ProjectExporter } from './project-exporter'
+import { StorageManager } from './storage-manager'
+import { VideoCache } from './video-cache'
+
+const logger = Logger.getInstance()
+
+const FFMPEG_PROBE_TIMEOUT = 10000
+
+/**
+ * App, OS and runtime versions, with package.json metadata when it can be read
+ */
+export function getSystemInfo(): SystemInfo {
+  let packageInfo = null
+  try {
+    const packagePath = app.isPackaged
+      ? join(process.resourcesPath, 'app', 'package.json')
+      : join(app.getAppPath(), 'package.json')
+    packageInfo = JSON.parse(readFileSync(packagePath, 'utf-8'))
+  } catch (pkgError) {
+    logger.warn('Failed to load package.json', pkgError as Error)
+  }
+
+  return {
+    appName: app.getName(),
+    appVersion: app.getVersion(),
+    os: PlatformUtils.getInstance().getOsVersionString(),
+    arch: process.arch,
+    nodeVersion: process.version,
+    electronVersion: process.versions.electron,
+    packageInfo,
+  }
+}
+
+function runFfmpeg(ffmpegPath: string, args: string[]): Promise<string> {
+  return new Promise((resolve, reject) => {
+    execFile(ffmpegPath, args, { timeout: FFMPEG_PROBE_TIMEOUT, windowsHide: true }, (error, stdout) => {
+      if (error) {
+        reject(error)
+        return
+      }
+      resolve(stdout)
+    })
+  })
+}
+
+async function getFfmpegFeatures(): Promise<FfmpegFeatures | null> {
+  const ffmpegPath = getFfmpegPath()
+  if (!ffmpegPath) {
+    return null
+  }
+
+  const [versionOutput, hwaccelOutput, encoders] = await Promise.all([
+    runFfmpeg(ffmpegPath, ['-hide_banner', '-version']).catch(() => ''),
+    runFfmpeg(ffmpegPath, ['-hide_banner', '-hwaccels']).catch(() => ''),
+    ProjectExporter.getInstance().getAvailableEncoders(),
+  ])
+
+  // -hwaccels prints a 'Hardware acceleration methods:' header, then one method per line
+  const hwaccels = hwaccelOutput
+    .split(/\r?\n/)
+    .slice(1)
+    .map(line => line.trim())
+    .filter(Boolean)
+  const exportEncoders = [...Object.values(VIDEO_ENCODERS).flat(), ...Object.values(AUDIO_ENCODERS)]
+
+  return {
+    version: versionOutput.match(/ffmpeg version (\S+)/)?.[1] ?? null,
+    encoders: exportEncoders.filter(encoder => encoders.has(encoder)),
+    hwaccels,
+  }
+}
+
+async function getStorageUsage(): Promise<StorageUsage | null> {
+  try {
+    const stats = await StorageManager.getInstance().getStorageStats()
+    return {
+      totalUsed: stats.totalSize,
+      available: stats.availableSpace,
+      downloads: stats.downloadsSize,
+      cache: stats.cacheSize,
+      temp: stats.tempSize,
+    }
+  } catch (error) {
+    logger.warn('Failed to get storage usage for diagnostics', { error: (error as Error).message })
+    return null
+  }
+}
+
+/**
+ * Run every check in parallel and assemble the report
+ */
+export async function runDiagnostics(): Promise<DiagnosticsReport> {
+  const configManager = ConfigManager.getInstance()
+
+  const [health, ffmpegFeatures, storage] = await Promise.all([
+    runHealthCheck(),
+    getFfmpegFeatures().catch(() => null),
+    getStorageUsage(),
+  ])
+  const videoCache = VideoCache.getInstance().getCacheStats()
+
+  const report: DiagnosticsReport = {
+    generatedAt: Date.now(),
+    system: getSystemInfo(),
+    health,
+    ffmpegFeatures,
+    paths: {
+      config: configManager.getConfigFilePath(),
+      library: getDownloadStorageFilePath(),
+      logs: logger.getLogsDirectory(),
+      appData: PlatformUtils.getInstance().getAppDataDir('clipy'),
+      downloads: configManager.get('download').downloadPath,
+    },
+    queue: DownloadManager.getInstance().getStats(),
+    caches: { videoCacheSize: videoCache.totalSize, videoCacheCount: videoCache.totalVideos, storage },
+    memory: PlatformUtils.getInstance().getProcessMemory(),
+    ytdlpEnvironment: getYtdlpEnvironmentInfo(),
+    warnings: health.items.filter(item => item.severity !== 'ok').map(item => item.message),
+  }
+  logger.info('Diagnostics collected', { warnings: report.warnings.length })
+  return report
+}
+
+/**
+ * Write the diagnostics report to a file as indented JSON
+ */
+export async function exportDiagnosticsReport(outputPath: string): Promise<DiagnosticsReport> {
+  const report = await runDiagnostics()
+  writeFileSync(outputPath, JSON.stringify(report, null, 2), 'utf-8')
+  return report
+}
@@ -6,26 +6,72 @@
  * - Job state tracking (active, completed, failed)
  * - Event-based progress updates to renderer
  * - Retry support for failed downloads
+ * - Pausing downloads, kept across restarts with the partial files they left
  *
  * Uses yt-dlp-manager internally for actual download execution.
  * This is the main entry point for download operations from IPC handlers.
  */
 
-import type { DownloadFilter, DownloadOptions, DownloadProgress, VideoInfo } from '../types/download'
+import type {
+  CookieChoice,
+  DownloadDeleteOptions,
+  DownloadDeleteResult,
+  DownloadFilter,
+  DownloadOptions,
+  DownloadProgress,
+  DownloadStateSnapshot,
+  DownloadStatus,
+  FileRemovalResult,
+  PausedDownloadState,
+  OutputTemplatePreview,
+  QueueCompletionSummary,
+  VideoChapter,
+  VideoInfo,
+} from '../types/download'
+import { DownloadErrorCode, createDownloadError, isCompletedStatus, summarizeVideoInfo } from '../types/download'
 import {
   addEventListener,
   cancelDownload,
   getVideoInfo,
   initializeDownloadManager,
+  pauseDownload,
   startDownload,
 } from './downloader/yt-dlp-manager'
 
 import { EventEmitter } from 'events'
+import { basename, dirname, extname, join } from 'path'
+import { DEFAULT_DOWNLOAD_SPEED, estimateDownloadSize, parseEta, parseSpeed } from './downloader/queue-eta'
+import { selectStartableJobs } from './downloader/queue-scheduler'
+import { formatHeight, getExpectedResolution, matchQuality } from './downloader/quality-fallback'
+import { removePartialFiles } from './downloader/partial-downloads'
+import { redactDownloadOptions } from './downloader/yt-dlp-environment'
 import { ConfigManager } from '../utils/config'
+import { isRemuxContainer } from '../utils/ffmpeg-args'
+import { createLocalizedDownloadError } from '../utils/i18n'
+import { getDownloadPathSettings, getExpectedExtension, isAudioOnlyFormat, renderOutputTemplate } from '../utils/paths'
 import { Logger } from '../utils/logger'
+import { removeFile } from '../utils/file-removal'
 import { VideoCache } from './video-cache'
 import { VideoProcessor } from './video-processor'
-import { removeDownloadFromStorage, getStoredDownloads, addDownloadToStorage } from './download-storage'
+import { startBulkJob } from './bulk-jobs'
+import { getQueueCompletionState } from './queue-completion'
+import { recordChannel } from './channels'
+import { enrichLibraryEntry, getVideoIdFromUrl } from './library'
+import { hasPodcastFeed, isAudioFile, writeChapterFiles, writePodcastFeed } from './podcast-output'
+import { getContinueWatching, removePlaybackPosition, withPlaybackPositions } from './watch-history'
+import { SHUTDOWN_TIMEOUT } from './lifecycle'
+import {
+  removeDownloadFromStorage,
+  getStoredDownloadById,
+  getStoredDownloads,
+  addDownloadToStorage,
+  updateDownloadInStorage,
+} from './download-storage'
+
+const SHUTDOWN_POLL_INTERVAL = 100 // ms between checks for downloads still stopping
+// How long downloads get to pause on exit before they are killed; inside SHUTDOWN_TIMEOUT, so
+// no yt-dlp outlives the app
+const SHUTDOWN_PAUSE_TIMEOUT = SHUTDOWN_TIMEOUT - 2000
 
 /** Represents a download task in the queue */
 export interface DownloadJob {
@@ -37,18 +83,31 @@ export interface DownloadJob {
   startedAt?: number // Timestamp when download actually started
   completedAt?: number // Timestamp when download finished
   ytDlpDownloadId?: string // Internal yt-dlp ID, used only for event mapping
+  qualityFallback?: DownloadProgress['qualityFallback'] // Requested vs. actual quality, when they differ
+  channelThumbnail?: string // Channel avatar URL from the video info, cached on completion
+  estimatedBytes?: number // Expected size from the video info's formats, for the queue ETA
+  chapters?: VideoChapter[] // From the video info, for chapter files
+  cancelled?: boolean // Set by cancelAllDownloads; a job still finishing up stops short
+  remuxController?: AbortController // While remuxing into the final container
 }
 
+type DownloadEventName = 'progress' | 'completed' | 'failed' | 'queued' | 'cancelled'
+
 export class DownloadManager extends EventEmitter {
   private static instance: DownloadManager
   private activeJobs = new Map<string, DownloadJob>()
   private completedJobs = new Map<string, DownloadJob>()
   private failedJobs = new Map<string, DownloadJob>()
+  private pausedJobs = new Map<string, DownloadJob>() // Not started again until resumed
   private jobQueue: DownloadJob[] = []
   private maxConcurrentDownloads: number
+  private maxConcurrentPerHost: number // 0 = unlimited
   private isProcessing = false
+  private shuttingDown = false // Set by shutdown(); nothing new starts after that
+  private batchSummary: QueueCompletionSummary = { completed: 0, failed: 0, cancelled: 0 }
   // Maps yt-dlp downloadId to job.id for event lookup
   private downloadIdToJobId = new Map<string, string>()
+  private eventSequence = 0 // Last sequence number stamped on a download event
 
   private configManager = ConfigManager.getInstance()
   private logger = Logger.getInstance()
@@ -58,6 +117,7 @@ export class DownloadManager extends EventEmitter {
   private constructor() {
     super()
     this.maxConcurrentDownloads = this.configManager.getNested<number>('download.maxConcurrentDownloads') ?? 3
+    this.maxConcurrentPerHost = this.configManager.getNested<number>('download.maxConcurrentPerHost') ?? 0
 
     this.initializeAsync()
   }
@@ -72,6 +132,7 @@ export class DownloadManager extends EventEmitter {
       })
 
       this.setupEventForwarding()
+      this.restorePausedDownloads()
       this.startQueueProcessor()
 
       this.logger.info('DownloadManager initialized successfully')
@@ -88,6 +149,15 @@ export class DownloadManager extends EventEmitter {
     return DownloadManager.instance
   }
 
+  /**
+   * Emit a download event, stamping the progress with the next sequence number so a
+   * renderer can drop events older than a state snapshot it already has
+   */
+  private emitDownloadEvent(event: DownloadEventName, progress: DownloadProgress): void {
+    progress.sequence = ++this.eventSequence
+    this.emit(event, progress)
+  }
+
   /**
    * Setup event forwarding from yt-dlp manager
    */
@@ -101,9 +171,15 @@ export class DownloadManager extends EventEmitter {
         const job = this.activeJobs.get(jobId)
         if (job) {
           // Update job progress but preserve OUR job.id as the public downloadId
-          job.progress = { ...ytDlpProgress, downloadId: job.id }
+          job.progress = {
+            ...ytDlpProgress,
+            downloadId: job.id,
+            tags: job.options.tags,
+            qualityFallback: job.qualityFallback,
+            sourceInfo: job.progress.sourceInfo,
+          }
           // Emit with our consistent job.id
-          this.emit('progress', job.progress)
+          this.emitDownloadEvent('progress', job.progress)
         }
       }
     })
@@ -115,20 +191,66 @@ export class DownloadManager extends EventEmitter {
       const job = jobId ? this.activeJobs.get(jobId) : null
       if (job) {
         // Update job progress but preserve OUR job.id as the public downloadId
-        job.progress = { ...ytDlpProgress, downloadId: job.id }
-        job.completedAt = Date.now()
-        this.completedJobs.set(job.id, job)
-        this.activeJobs.delete(job.id)
+        job.progress = {
+          ...ytDlpProgress,
+          downloadId: job.id,
+          tags: job.options.tags,
+          qualityFallback: job.qualityFallback,
+          sourceInfo: job.progress.sourceInfo,
+        }
+        this.fillFromSourceInfo(job.progress)
         this.downloadIdToJobId.delete(ytDlpProgress.downloadId)
 
-        // Save to storage with OUR job.id so delete/retry works correctly
-        // This overwrites any entry saved by yt-dlp-provider with the correct ID
-        addDownloadToStorage(job.progress)
-
-        this.logger.info('Download completed', { jobId: job.id, ytDlpId: ytDlpProgress.downloadId })
-        // Emit with our consistent job.id
-        this.emit('completed', job.progress)
-        this.processQueue()
+        // The job stays active while it is remuxed into the final container
+        let announced = false
+        this.convertToFinalContainer(job)
+          .then(() => (job.cancelled ? undefined : this.writeChapterSidecars(job)))
+          .then(() => {
+            if (job.cancelled) return
+            job.completedAt = Date.now()
+            this.completedJobs.set(job.id, job)
+            this.activeJobs.delete(job.id)
+
+            // Save to storage with OUR job.id so delete/retry works correctly
+            // This overwrites any entry saved by yt-dlp-provider with the correct ID
+            addDownloadToStorage(job.progress)
+            this.updatePodcastFeed(job)
+            // Fills in what yt-dlp didn't report (duration, thumbnail, ...) and emits library-video-updated
+            enrichLibraryEntry(job.id).catch(error => {
+              this.logger.warn('Failed to enrich library entry', { jobId: job.id, error: (error as Error).message })
+            })
+            this.recordResolution(job)
+            recordChannel({
+              id: job.progress.channelId ?? '',
+              name: job.progress.channel ?? '',
+              thumbnail: job.channelThumbnail,
+            })
+
+            this.logger.info('Download completed', { jobId: job.id, ytDlpId: ytDlpProgress.downloadId })
+            // Emit with our consistent job.id
+            this.emitDownloadEvent('completed', job.progress)
+            this.batchSummary.completed++
+            announced = true
+          })
+          .catch(error => {
+            // Don't leave the job active, or the queue stalls behind it
+            this.logger.error('Failed to finish download', error as Error, { jobId: job.id })
+            if (this.activeJobs.delete(job.id)) {
+              job.progress.status = 'failed'
+              job.progress.error = createDownloadError((error as Error).message, DownloadErrorCode.UNKNOWN_ERROR)
+              this.failedJobs.set(job.id, job)
+              this.emitDownloadEvent('failed', job.progress)
+              this.batchSummary.failed++
+            } else if (!announced) {
+              // The file is complete; only saving or recording it failed
+              this.emitDownloadEvent('completed', job.progress)
+              this.batchSummary.completed++
+            }
+          })
+          .finally(() => {
+            this.processQueue()
+            this.checkQueueDrained()
+          })
       } else {
         this.logger.warn('Received completion for unknown download', { ytDlpId: ytDlpProgress.downloadId })
       }
@@ -141,7 +263,13 @@ export class DownloadManager extends EventEmitter {
       const job = jobId ? this.activeJobs.get(jobId) : null
       if (job) {
         // Update job progress but preserve OUR job.id as the public downloadId
-        job.progress = { ...ytDlpProgress, downloadId: job.id }
+        job.progress = {
+          ...ytDlpProgress,
+          downloadId: job.id,
+          tags: job.options.tags,
+          qualityFallback: job.qualityFallback,
+          sourceInfo: job.progress.sourceInfo,
+        }
         this.failedJobs.set(job.id, job)
         this.activeJobs.delete(job.id)
         this.downloadIdToJobId.delete(ytDlpProgress.downloadId)
@@ -151,12 +279,99 @@ export class DownloadManager extends EventEmitter {
 
         this.logger.info('Download failed', { jobId: job.id, ytDlpId: ytDlpProgress.downloadId })
         // Emit with our consistent job.id
-        this.emit('failed', job.progress)
+        this.emitDownloadEvent('failed', job.progress)
+        this.batchSummary.failed++
         this.processQueue()
+        this.checkQueueDrained()
       } else {
         this.logger.warn('Received failure for unknown download', { ytDlpId: ytDlpProgress.downloadId })
       }
     })
+
+    addEventListener('paused', (...args: unknown[]) => {
+      this.handlePaused(args[0] as DownloadProgress)
+    })
+  }
+
+  /**
+   * Fill in the library metadata the download didn't report (a direct download reports none)
+   * from the video info it was started with
+   */
+  private fillFromSourceInfo(progress: DownloadProgress): void {
+    const source = progress.sourceInfo
+    if (!source) {
+      return
+    }
+    progress.channel ||= source.channel || undefined
+    progress.channelId ||= source.channelId
+    progress.duration ||= source.duration || undefined
+    progress.thumbnailUrl ||= source.thumbnail
+  }
+
+  /**
+   * Take a download that yt-dlp stopped for a pause out of the active jobs. It is saved with
+   * where its partial files are, so it can be resumed after a restart too.
+   */
+  private handlePaused(ytDlpProgress: DownloadProgress): void {
+    const jobId = this.downloadIdToJobId.get(ytDlpProgress.downloadId)
+    const job = jobId ? this.activeJobs.get(jobId) : null
+    if (!job) {
+      this.logger.warn('Received pause for unknown download', { ytDlpId: ytDlpProgress.downloadId })
+      return
+    }
+
+    job.progress = {
+      ...ytDlpProgress,
+      downloadId: job.id,
+      tags: job.options.tags,
+      qualityFallback: job.qualityFallback,
+      sourceInfo: job.progress.sourceInfo,
+      status: 'paused',
+      pausedState: ytDlpProgress.pausedState ?? this.getPausedState(job),
+    }
+    this.pausedJobs.set(job.id, job)
+    this.activeJobs.delete(job.id)
+    this.downloadIdToJobId.delete(ytDlpProgress.downloadId)
+    addDownloadToStorage(job.progress)
+
+    this.logger.info('Download paused', {
+      jobId: job.id,
+      partialFiles: job.progress.pausedState?.partialFiles.length,
+      downloadedBytes: job.progress.pausedState?.downloadedBytes,
+    })
+    this.emitDownloadEvent('progress', job.progress)
+    this.processQueue()
+    this.checkQueueDrained()
+  }
+
+  /**
+   * State for a job paused before yt-dlp left any partial files: a resumed job that is
+   * paused again keeps the files it was resumed from, anything else starts over
+   */
+  private getPausedState(job: DownloadJob): PausedDownloadState {
+    const { resumeFrom, ...options } = job.options
+    return resumeFrom ?? { options, outputTemplate: '', partialFiles: [], downloadedBytes: 0, pausedAt: Date.now() }
+  }
+
+  /**
+   * Downloads that were paused when the app last closed, back as paused jobs; they don't
+   * start until resumed
+   */
+  private restorePausedDownloads(): void {
+    for (const download of getStoredDownloads()) {
+      if (download.status !== 'paused' || this.pausedJobs.has(download.downloadId)) continue
+      this.pausedJobs.set(download.downloadId, {
+        id: download.downloadId,
+        url: download.url,
+        options: download.pausedState?.options ?? {},
+        progress: download,
+        createdAt: download.startTime,
+        qualityFallback: download.qualityFallback,
+      })
+    }
+    if (this.pausedJobs.size > 0) {
+      this.logger.info('Paused downloads restored', { count: this.pausedJobs.size })
+    }
   }
 
   /**
@@ -174,19 +389,29 @@ export class DownloadManager extends EventEmitter {
    * Process download queue
    */
   private async processQueue(): Promise<void> {
-    if (this.isProcessing || this.activeJobs.size >= this.maxConcurrentDownloads) {
+    if (this.isProcessing || this.activeJobs.size >= this.maxConcurrentDownloads || this.jobQueue.length === 0) {
+      return
+    }
+    if (this.shuttingDown) {
       return
     }
 
     this.isProcessing = true
 
     try {
-      while (this.activeJobs.size < this.maxConcurrentDownloads && this.jobQueue.length > 0) {
-        const job = this.jobQueue.shift()!
+      // Skip jobs whose host is at its per-host quota instead of blocking on the queue head
+      const startable = new Set(
+        selectStartableJobs(Array.from(this.activeJobs.values()), this.jobQueue, this.getQueueLimits()),
+      )
+      const jobsToStart = this.jobQueue.filter((_job, index) => startable.has(index))
+      this.jobQueue = this.jobQueue.filter((_job, index) => !startable.has(index))
+
+      for (const job of jobsToStart) {
         await this.startJob(job)
       }
     } finally {
       this.isProcessing = false
+      this.checkQueueDrained() // Covers jobs that failed to start
     }
   }
 
@@ -196,15 +421,27 @@ export class DownloadManager extends EventEmitter {
   async startDownload(url: string, options: DownloadOptions = {}): Promise<{ downloadId: string }> {
     try {
       // Validate URL and get video info
-      const videoInfo = await this.getVideoInfo(url)
+      const videoInfo = await this.getVideoInfo(url, options.useCookies)
+      const { options: resolvedOptions, qualityFallback } = this.resolveQuality(videoInfo, options)
+      // A template of this download's own that can't work fails now, not once yt-dlp runs
+      if (options.filenameTemplate) {
+        const { errors } = this.previewOutputPath(videoInfo, resolvedOptions)
+        if (errors.length > 0) {
+          throw createDownloadError(errors.join('; '), DownloadErrorCode.INVALID_FILENAME_TEMPLATE)
+        }
+      }
 
       // Create download job
       const jobId = this.generateJobId()
       const job: DownloadJob = {
         id: jobId,
         url,
+        qualityFallback,
+        channelThumbnail: videoInfo.channel.thumbnail,
+        estimatedBytes: estimateDownloadSize(videoInfo.formats ?? [], resolvedOptions.quality) ?? undefined,
+        chapters: videoInfo.chapters,
         options: {
-          ...options,
+          ...resolvedOptions,
           // Ensure we download full video for caching
           startTime: undefined, // Remove trim for caching
           endTime: undefined,
@@ -223,17 +460,20 @@ export class DownloadManager extends EventEmitter {
           filePath: '',
           startTime: Date.now(),
           retryCount: 0,
+          tags: options.tags,
+          qualityFallback,
+          sourceInfo: summarizeVideoInfo(videoInfo),
         },
         createdAt: Date.now(),
       }
 
       // Add to queue or start immediately
-      if (this.activeJobs.size < this.maxConcurrentDownloads) {
+      if (this.canStartImmediately(url)) {
         await this.startJob(job)
       } else {
         this.jobQueue.push(job)
         job.progress.status = 'queued'
-        this.emit('queued', job.progress)
+        this.emitDownloadEvent('queued', job.progress)
       }
 
       this.logger.info('Download job created', {
@@ -245,11 +485,95 @@ export class DownloadManager extends EventEmitter {
 
       return { downloadId: job.id }
     } catch (error) {
-      this.logger.error('Failed to create download job', error as Error, { url, options })
+      this.logger.error('Failed to create download job', error as Error, {
+        url,
+        options: redactDownloadOptions(options),
+      })
       throw error
     }
   }
 
+  /**
+   * Queue a download without fetching its info first; the queue processor starts it when a
+   * slot is free and yt-dlp looks the video up then. Used by batch enqueues, where fetching
+   * info for every line up front would hold the whole batch up.
+   */
+  enqueueDownload(url: string, options: DownloadOptions = {}): { downloadId: string } {
+    const jobId = this.generateJobId()
+    const job: DownloadJob = {
+      id: jobId,
+      url,
+      options: { ...options, startTime: undefined, endTime: undefined },
+      progress: {
+        downloadId: jobId,
+        url,
+        title: url, // Replaced by the video title once yt-dlp reports progress
+        progress: 0,
+        speed: '0 B/s',
+        eta: '--:--',
+        size: '0 B',
+        downloadedBytes: 0,
+        totalBytes: 0,
+        status: 'queued',
+        filePath: '',
+        startTime: Date.now(),
+        retryCount: 0,
+      },
+      createdAt: Date.now(),
+    }
+
+    this.jobQueue.push(job)
+    this.emitDownloadEvent('queued', job.progress)
+    return { downloadId: job.id }
+  }
+
+  /**
+   * Where a download of this video with these options would be written, from the same
+   * template rendering, sanitizing and path length check the download uses
+   */
+  previewOutputPath(videoInfo: VideoInfo, options: DownloadOptions): OutputTemplatePreview {
+    const downloadSettings = this.configManager.get('download')
+    const extension = getExpectedExtension(options, downloadSettings.finalContainer)
+    const resolution = isAudioOnlyFormat(options.format)
+      ? 'audio only'
+      : getExpectedResolution(videoInfo.formats ?? [], options.quality)
+    const pathSettings = getDownloadPathSettings(options, downloadSettings)
+    return renderOutputTemplate(pathSettings, videoInfo, { extension, resolution })
+  }
+
+  /**
+   * Check the requested quality against the video's formats before queueing. When every
+   * format is above it, fail fast or switch to the closest one (download.qualityFallback).
+   * Any difference between requested and actual quality is returned for the UI.
+   */
+  private resolveQuality(
+    videoInfo: VideoInfo,
+    options: DownloadOptions,
+  ): { options: DownloadOptions; qualityFallback?: DownloadJob['qualityFallback'] } {
+    const requested = options.quality ?? ''
+    const match = matchQuality(videoInfo.formats ?? [], requested)
+
+    if (match.kind === 'lower') {
+      return { options, qualityFallback: { requested, actual: formatHeight(match.height) } }
+    }
+    if (match.kind !== 'none') {
+      return { options }
+    }
+
+    const available = match.available.map(formatHeight)
+    if (this.configManager.get('download')?.qualityFallback === 'fail') {
+      throw createLocalizedDownloadError(
+        'errorFormatUnavailable',
+        { requested, available: available.join(', ') },
+        DownloadErrorCode.NO_FORMAT_AVAILABLE,
+      )
+    }
+
+    const actual = available[available.length - 1]
+    this.logger.info('Requested quality unavailable, using the closest one', { requested, actual })
+    return { options: { ...options, quality: actual }, qualityFallback: { requested, actual } }
+  }
+
   /**
    * Start processing a job
    */
@@ -263,6 +587,12 @@ export class DownloadManager extends EventEmitter {
       // Use yt-dlp manager for actual download
       const ytDlpId = await startDownload(job.url, job.options)
 
+      // Cancelled (by cancelAllDownloads) before yt-dlp was started: stop it right away
+      if (this.activeJobs.get(job.id) !== job) {
+        cancelDownload(ytDlpId)
+        return
+      }
+
       // Store yt-dlp ID separately for internal event mapping (NOT in progress.downloadId!)
       job.ytDlpDownloadId = ytDlpId
 
@@ -275,15 +605,47 @@ export class DownloadManager extends EventEmitter {
       job.progress.status = 'failed'
       this.failedJobs.set(job.id, job)
       this.activeJobs.delete(job.id)
-      this.emit('failed', job.progress)
+      this.emitDownloadEvent('failed', job.progress)
+      this.batchSummary.failed++
+      this.checkQueueDrained()
     }
   }
 
+  /**
+   * Emit 'queue-completed' once the last active or queued download has finished.
+   * Failed and cancelled downloads count as finished.
+   */
+  private checkQueueDrained(): void {
+    if (this.activeJobs.size > 0 || this.jobQueue.length > 0 || this.isProcessing) {
+      return
+    }
+    const summary = this.batchSummary
+    if (summary.completed + summary.failed + summary.cancelled === 0) {
+      return
+    }
+    this.batchSummary = { completed: 0, failed: 0, cancelled: 0 }
+    this.logger.info('Download queue completed', { ...summary })
+    this.emit('queue-completed', summary)
+  }
+
   /**
    * Cancel download
    */
   async cancelDownload(downloadId: string): Promise<boolean> {
     try {
+      const pausedJob = this.pausedJobs.get(downloadId)
+      if (pausedJob) {
+        // Nothing is running: drop the partial files and the saved entry
+        if (pausedJob.progress.pausedState) {
+          removePartialFiles(pausedJob.progress.pausedState)
+        }
+        this.pausedJobs.delete(downloadId)
+        removeDownloadFromStorage(downloadId)
+        pausedJob.progress = { ...pausedJob.progress, status: 'cancelled', pausedState: undefined }
+        this.emitDownloadEvent('cancelled', pausedJob.progress)
+        return true
+      }
+
       const job = this.activeJobs.get(downloadId)
       if (!job) {
         return false
@@ -300,8 +662,10 @@ export class DownloadManager extends EventEmitter {
         if (ytDlpId) {
           this.downloadIdToJobId.delete(ytDlpId)
         }
-        this.emit('cancelled', job.progress)
+        this.emitDownloadEvent('cancelled', job.progress)
+        this.batchSummary.cancelled++
         this.processQueue() // Process next in queue
+        this.checkQueueDrained()
       }
 
       return cancelled
@@ -312,12 +676,165 @@ export class DownloadManager extends EventEmitter {
   }
 
   /**
-   * Delete download
+   * Stop every queued and running download at once, the "stop everything" button. Paused
+   * downloads are left as they are. Returns the IDs of the cancelled downloads.
+   */
+  cancelAllDownloads(): string[] {
+    const jobs = [...this.jobQueue, ...this.activeJobs.values()]
+    this.jobQueue = []
+    this.activeJobs.clear()
+
+    for (const job of jobs) {
+      job.cancelled = true
+      if (job.ytDlpDownloadId) {
+        cancelDownload(job.ytDlpDownloadId)
+        this.downloadIdToJobId.delete(job.ytDlpDownloadId)
+      }
+      job.remuxController?.abort()
+      // A resumed download still waiting in the queue has partial files but no yt-dlp to remove them
+      if (!job.ytDlpDownloadId && job.options.resumeFrom) {
+        removePartialFiles(job.options.resumeFrom)
+        removeDownloadFromStorage(job.id)
+      }
+      job.progress.status = 'cancelled'
+      this.emitDownloadEvent('cancelled', job.progress)
+      this.batchSummary.cancelled++
+    }
+
+    if (jobs.length > 0) {
+      this.logger.info('All downloads cancelled', { count: jobs.length })
+    }
+    this.checkQueueDrained()
+    return jobs.map(job => job.id)
+  }
+
+  /**
+   * Pause a download. An active download stops once yt-dlp has exited and its partial files
+   * are recorded; a queued one is taken out of the queue.
    */
-  async deleteDownload(downloadId: string): Promise<boolean> {
+  async pauseDownload(downloadId: string): Promise<boolean> {
     try {
+      const job = this.activeJobs.get(downloadId)
+      if (job) {
+        return job.ytDlpDownloadId ? pauseDownload(job.ytDlpDownloadId) : false
+      }
+
+      const queued = this.jobQueue.find(candidate => candidate.id === downloadId)
+      if (!queued) {
+        return false
+      }
+      this.jobQueue = this.jobQueue.filter(candidate => candidate !== queued)
+      queued.progress = { ...queued.progress, status: 'paused', pausedState: this.getPausedState(queued) }
+      this.pausedJobs.set(queued.id, queued)
+      addDownloadToStorage(queued.progress)
+      this.emitDownloadEvent('progress', queued.progress)
+      this.checkQueueDrained()
+      return true
+    } catch (error) {
+      this.logger.error('Failed to pause download', error as Error, { downloadId })
+      return false
+    }
+  }
+
+  /**
+   * Pause every download for an app exit, so all of them resume on the next launch: queued
+   * ones right away, running ones once yt-dlp has exited and their partial files are saved.
+   * Resolves when no download is running any more. Downloads still running after
+   * `pauseTimeoutMs` are cancelled instead, so their yt-dlp doesn't outlive the app.
+   */
+  async shutdown(pauseTimeoutMs: number = SHUTDOWN_PAUSE_TIMEOUT): Promise<void> {
+    this.shuttingDown = true
+    this.logger.info('Pausing downloads for shutdown', { active: this.activeJobs.size, queued: this.jobQueue.length })
+
+    for (const job of [...this.jobQueue]) {
+      await this.pauseDownload(job.id)
+    }
+
+    // Re-checked until empty: a job that was still starting has no yt-dlp process to stop yet
+    const deadline = Date.now() + pauseTimeoutMs
+    while (this.activeJobs.size > 0) {
+      if (Date.now() >= deadline) {
+        const cancelled = this.cancelAllDownloads()
+        this.logger.warn('Downloads did not pause in time, cancelled them', { cancelled })
+        return
+      }
+      for (const jobId of this.activeJobs.keys()) {
+        await this.pauseDownload(jobId)
+      }
+      await new Promise(resolve => setTimeout(resolve, SHUTDOWN_POLL_INTERVAL))
+    }
+  }
+
+  /**
+   * Resume a paused download, in this session or a previous one. yt-dlp continues from the
+   * partial files if they are unchanged, else starts over and sets resumeRestarted.
+   */
+  async resumeDownload(downloadId: string): Promise<boolean> {
+    const job = this.pausedJobs.get(downloadId)
+    if (!job) {
+      return false
+    }
+
+    this.pausedJobs.delete(downloadId)
+    const pausedState = job.progress.pausedState
+    job.options = { ...(pausedState?.options ?? job.options), resumeFrom: pausedState }
+    job.progress = { ...job.progress, resumeRestarted: undefined }
+
+    if (this.canStartImmediately(job.url)) {
+      await this.startJob(job)
+    } else {
+      this.jobQueue.push(job)
+      job.progress.status = 'queued'
+      this.emitDownloadEvent('queued', job.progress)
+    }
+
+    this.logger.info('Download resumed', { jobId: job.id, partialFiles: pausedState?.partialFiles.length ?? 0 })
+    return true
+  }
+
+  /**
+   * Delete download. With deleteFile, its files go to the recycle bin (or are deleted
+   * permanently per options/settings). If the downloaded file itself can't be removed the
+   * download is kept; once it is gone the download is removed, and a thumbnail or sidecar
+   * file that couldn't be removed is reported in failedFiles.
+   */
+  async deleteDownload(downloadId: string, options: DownloadDeleteOptions = {}): Promise<DownloadDeleteResult> {
+    try {
+      const files: FileRemovalResult[] = []
+      if (options.deleteFile) {
+        const progress =
+          (await this.getDownloadProgress(downloadId)) ?? getStoredDownloadById(downloadId)
+        const permanent = options.permanentlyDelete ?? this.configManager.get('download').permanentlyDeleteFiles
+
+        // Keep the download if its file couldn't be removed, so the user can decide what to do
+        if (progress?.filePath) {
+          const primary = await removeFile(progress.filePath, permanent)
+          files.push(primary)
+          if (primary.code) {
+            this.logger.warn('Failed to remove download file', { downloadId, path: primary.path, code: primary.code })
+            return { downloadId, deleted: false, files, code: primary.code, error: primary.error }
+          }
+        }
+
+        for (const filePath of [progress?.thumbnailPath, ...(progress?.sidecarFiles ?? [])]) {
+          if (filePath) {
+            files.push(await removeFile(filePath, permanent))
+          }
+        }
+      }
+
       let deletedFromMemory = false
 
+      // Paused downloads leave partial files behind
+      const pausedJob = this.pausedJobs.get(downloadId)
+      if (pausedJob) {
+        if (pausedJob.progress.pausedState) {
+          removePartialFiles(pausedJob.progress.pausedState)
+        }
+        this.pausedJobs.delete(downloadId)
+        deletedFromMemory = true
+      }
+
       // Check active jobs
       if (this.activeJobs.has(downloadId)) {
         await this.cancelDownload(downloadId)
@@ -339,6 +856,7 @@ export class DownloadManager extends EventEmitter {
 
       // Remove from persistent storage
       const deletedFromStorage = removeDownloadFromStorage(downloadId)
+      removePlaybackPosition(downloadId)
 
       const deleted = deletedFromMemory || deletedFromStorage
 
@@ -350,13 +868,41 @@ export class DownloadManager extends EventEmitter {
         this.logger.warn('Download not found for deletion', { downloadId })
       }
 
-      return deleted
+      const failedFiles = files.filter(file => file.code)
+      if (failedFiles.length > 0) {
+        this.logger.warn('Some download files were left behind', {
+          downloadId,
+          paths: failedFiles.map(file => file.path),
+        })
+        return { downloadId, deleted, files, failedFiles }
+      }
+      return { downloadId, deleted, files }
     } catch (error) {
       this.logger.error('Failed to delete download', error as Error, { downloadId })
-      return false
+      return { downloadId, deleted: false, files: [] }
     }
   }
 
+  /**
+   * Delete several downloads as a background bulk job. Returns the job ID; progress and
+   * per-download failures are reported through the job.
+   */
+  startBulkDelete(downloadIds: string[], options: DownloadDeleteOptions = {}): string {
+    const titles = new Map(getStoredDownloads().map(download => [download.downloadId, download.title]))
+    for (const job of [...this.activeJobs.values(), ...this.completedJobs.values(), ...this.failedJobs.values()]) {
+      titles.set(job.id, job.progress.title)
+    }
+
+    const items = downloadIds.map(id => ({ id, title: titles.get(id) }))
+    return startBulkJob('delete', items, async downloadId => {
+      const result = await this.deleteDownload(downloadId, options)
+      if (result.deleted) {
+        return { ok: true }
+      }
+      return { ok: false, code: result.code ?? 'NOT_FOUND', error: result.error ?? 'Download not found' }
+    })
+  }
+
   /**
    * Retry download
    */
@@ -371,8 +917,8 @@ export class DownloadManager extends EventEmitter {
         this.failedJobs.delete(downloadId)
       } else {
         // Check persistent storage for failed downloads
-        const storedDownloads = getStoredDownloads()
-        failedProgress = storedDownloads.find(d => d.downloadId === downloadId && d.status === 'failed')
+        const storedDownload = getStoredDownloadById(downloadId)
+        failedProgress = storedDownload?.status === 'failed' ? storedDownload : undefined
       }
 
       if (!failedProgress) {
@@ -399,12 +945,12 @@ export class DownloadManager extends EventEmitter {
       }
 
       // Start immediately or queue
-      if (this.activeJobs.size < this.maxConcurrentDownloads) {
+      if (this.canStartImmediately(newJob.url)) {
         await this.startJob(newJob)
       } else {
         this.jobQueue.push(newJob)
         newJob.progress.status = 'queued'
-        this.emit('queued', newJob.progress)
+        this.emitDownloadEvent('queued', newJob.progress)
       }
 
       return { downloadId: newJob.id }
@@ -436,7 +982,83 @@ export class DownloadManager extends EventEmitter {
       return failedJob.progress
     }
 
-    return null
+    return this.pausedJobs.get(downloadId)?.progress ?? null
+  }
+
+  /**
+   * Look up a single download without building the full list: in-memory jobs first
+   * (active, queued, completed, failed), then the persisted history
+   */
+  getDownload(downloadId: string): DownloadProgress | null {
+    const job =
+      this.activeJobs.get(downloadId) ??
+      this.jobQueue.find(queued => queued.id === downloadId) ??
+      this.completedJobs.get(downloadId) ??
+      this.failedJobs.get(downloadId) ??
+      this.pausedJobs.get(downloadId)
+    return job?.progress ?? getStoredDownloadById(downloadId) ?? null
+  }
+
+  /**
+   * Status of a single download, for callers that only need to poll the state
+   */
+  getDownloadStatus(downloadId: string): DownloadStatus | null {
+    return this.getDownload(downloadId)?.status ?? null
+  }
+
+  /**
+   * Every in-memory download (active, queued, and finished this session) plus the queue
+   * completion action, for a renderer that attaches after events were already sent.
+   * Built synchronously, so no progress event can interleave with it; events with a
+   * sequence at or below the snapshot's are already reflected in it.
+   */
+  getStateSnapshot(): DownloadStateSnapshot {
+    const jobs = [
+      ...this.activeJobs.values(),
+      ...this.jobQueue,
+      ...this.completedJobs.values(),
+      ...this.failedJobs.values(),
+      ...this.pausedJobs.values(),
+    ]
+    return {
+      sequence: this.eventSequence,
+      downloads: jobs.map(job => ({ ...job.progress })),
+      stats: this.getStats(),
+      completion: getQueueCompletionState(),
+    }
+  }
+
+  /**
+   * Replace a download's tags. Queued and active downloads carry them into the library
+   * when they complete; finished downloads are updated in storage.
+   */
+  async tagDownload(downloadId: string, tags: string[]): Promise<boolean> {
+    const job =
+      this.activeJobs.get(downloadId) ??
+      this.jobQueue.find(queued => queued.id === downloadId) ??
+      this.completedJobs.get(downloadId) ??
+      this.failedJobs.get(downloadId) ??
+      this.pausedJobs.get(downloadId)
+
+    if (job) {
+      job.options.tags = tags
+      job.progress.tags = tags
+    }
+    const stored = updateDownloadInStorage(downloadId, { tags })
+
+    if (!job && !stored) {
+      return false
+    }
+    this.logger.info('Download tags updated', { downloadId, tags })
+    return true
+  }
+
+  /**
+   * Path of a download's yt-dlp log, from memory or the persisted history
+   */
+  async getDownloadLogPath(downloadId: string): Promise<string | null> {
+    const progress = (await this.getDownloadProgress(downloadId)) ?? getStoredDownloadById(downloadId)
+    return progress?.logPath ?? null
   }
 
   /**
@@ -455,6 +1077,7 @@ export class DownloadManager extends EventEmitter {
     const completedProgress = Array.from(this.completedJobs.values()).map(job => job.progress)
     const failedProgress = Array.from(this.failedJobs.values()).map(job => job.progress)
     const queuedProgress = this.jobQueue.map(job => job.progress)
+    const pausedProgress = Array.from(this.pausedJobs.values()).map(job => job.progress)
 
     // Get persisted downloads from storage (includes downloads from previous sessions)
     const storedDownloads = getStoredDownloads()
@@ -465,6 +1088,7 @@ export class DownloadManager extends EventEmitter {
       ...completedProgress.map(p => p.downloadId),
       ...failedProgress.map(p => p.downloadId),
       ...queuedProgress.map(p => p.downloadId),
+      ...pausedProgress.map(p => p.downloadId),
     ])
 
     // Only include stored downloads that aren't already in memory
@@ -474,12 +1098,12 @@ export class DownloadManager extends EventEmitter {
 
     switch (filter) {
       case 'active':
-        downloads = activeProgress
+        downloads = [...activeProgress, ...pausedProgress]
         break
       case 'completed':
         downloads = [
           ...completedProgress,
-          ...persistedOnly.filter(d => d.status === 'completed'),
+          ...persistedOnly.filter(d => isCompletedStatus(d.status)),
         ]
         break
       case 'failed':
@@ -488,6 +1112,8 @@ export class DownloadManager extends EventEmitter {
           ...persistedOnly.filter(d => d.status === 'failed'),
         ]
         break
+      case 'continue-watching':
+        return getContinueWatching() // Most recently played first
       case 'all':
       default:
         downloads = [
@@ -495,20 +1121,37 @@ export class DownloadManager extends EventEmitter {
           ...completedProgress,
           ...failedProgress,
           ...queuedProgress,
+          ...pausedProgress,
           ...persistedOnly,
         ]
         break
     }
 
-    return downloads.sort((a, b) => b.startTime - a.startTime)
+    return withPlaybackPositions(downloads).sort((a, b) => b.startTime - a.startTime)
+  }
+
+  /**
+   * Video ids (the URL for sites without one) of every download that is queued, running,
+   * paused, or in the download history, library imports included
+   */
+  getKnownDownloadKeys(): Set<string> {
+    const jobs = [
+      ...this.jobQueue,
+      ...this.activeJobs.values(),
+      ...this.pausedJobs.values(),
+      ...this.completedJobs.values(),
+      ...this.failedJobs.values(),
+    ]
+    const downloads = [...jobs.map(job => job.progress), ...getStoredDownloads()]
+    return new Set(downloads.map(download => download.videoId ?? getVideoIdFromUrl(download.url) ?? download.url))
   }
 
   /**
-   * Get video info
+   * Get video info, signing in with the given cookies (settings by default)
    */
-  async getVideoInfo(url: string): Promise<VideoInfo> {
+  async getVideoInfo(url: string, useCookies?: CookieChoice): Promise<VideoInfo> {
     try {
-      return await getVideoInfo(url)
+      return await getVideoInfo(url, useCookies)
     } catch (error) {
       this.logger.error('Failed to get video info', error as Error, { url })
       throw error
@@ -534,11 +1177,169 @@ export class DownloadManager extends EventEmitter {
     }
   }
 
+  /**
+   * Average speed of the running downloads in bytes/s; 0 while none reports a speed
+   */
+  getAverageDownloadSpeed(): number {
+    const speeds = Array.from(this.activeJobs.values())
+      .map(job => parseSpeed(job.progress.speed))
+      .filter(speed => speed > 0)
+    return speeds.length > 0 ? Math.round(speeds.reduce((sum, speed) => sum + speed, 0) / speeds.length) : 0
+  }
+
+  /**
+   * Estimated seconds until every active and queued download is done. Active downloads
+   * use their own ETA; queued ones their expected size at the average speed.
+   */
+  getQueueEta(): number {
+    const speed = this.getAverageDownloadSpeed() || DEFAULT_DOWNLOAD_SPEED
+    const remainingSeconds = (job: DownloadJob): number => {
+      const eta = job.progress.status === 'downloading' ? parseEta(job.progress.eta) : null
+      if (eta !== null) return eta
+      return job.estimatedBytes ? (job.estimatedBytes * (1 - job.progress.progress / 100)) / speed : 0
+    }
+
+    const total = [...this.activeJobs.values(), ...this.jobQueue].reduce((sum, job) => sum + remainingSeconds(job), 0)
+    return Math.round(total)
+  }
+
   /**
    * Update configuration
    */
   updateConfig(): void {
     this.maxConcurrentDownloads = this.configManager.get('download')?.maxConcurrentDownloads ?? 3
+    this.maxConcurrentPerHost = this.configManager.get('download')?.maxConcurrentPerHost ?? 0
+  }
+
+  private getQueueLimits() {
+    return { maxConcurrent: this.maxConcurrentDownloads, maxPerHost: this.maxConcurrentPerHost }
+  }
+
+  /**
+   * Whether a new job for this URL can start now without queueing.
+   * Jobs already waiting go first, so a new job never overtakes the queue.
+   */
+  private canStartImmediately(url: string): boolean {
+    if (this.jobQueue.length > 0) {
+      return false
+    }
+    return selectStartableJobs(Array.from(this.activeJobs.values()), [{ url }], this.getQueueLimits()).length > 0
+  }
+
+  /**
+   * Remux a finished download into its final container (the download's finalContainer
+   * option, else download.finalContainer), re-encoding the video only if the container
+   * can't hold it. Reported as a processing phase. If the remux fails, the original file
+   * is kept and the download completes with a warning. cancelAllDownloads stops the remux
+   * and leaves the status alone. Never rejects.
+   */
+  private async convertToFinalContainer(job: DownloadJob): Promise<void> {
+    const target = job.options.finalContainer ?? this.configManager.get('download')?.finalContainer ?? 'keep'
+    const sourcePath = job.progress.filePath
+    const source = sourcePath ? extname(sourcePath).slice(1).toLowerCase() : ''
+    // Audio-only downloads (m4a, mp3, ...) are left alone, and so are MKVs with every audio track
+    if (target === 'keep' || !sourcePath || source === target || !isRemuxContainer(source)) {
+      return
+    }
+    if (job.options.downloadAllAudioTracks && source === 'mkv') {
+      return
+    }
+
+    const duration = job.progress.duration ?? 0
+    job.progress.status = 'processing'
+    job.progress.progress = 0
+    job.progress.speed = ''
+    job.progress.eta = ''
+    this.emitDownloadEvent('progress', job.progress)
+
+    const targetPath = join(dirname(sourcePath), `${basename(sourcePath, extname(sourcePath))}.${target}`)
+    job.remuxController = new AbortController()
+    try {
+      const finalPath = await this.videoProcessor.remuxVideo(sourcePath, targetPath, {
+        reencodeVideo: true,
+        signal: job.remuxController.signal,
+        onProgress: seconds => {
+          if (duration <= 0) return
+          job.progress.progress = Math.min(100, Math.round((seconds / duration) * 100))
+          this.emitDownloadEvent('progress', job.progress)
+        },
+      })
+      await removeFile(sourcePath, true)
+      job.progress.filePath = finalPath
+      job.progress.format = target
+      this.logger.info('Download remuxed into final container', { jobId: job.id, from: source, to: target })
+    } catch (error) {
+      if (job.cancelled) return // The remux's partial file is already removed
+      const message = (error as Error).message
+      const kept = source.toUpperCase()
+      job.progress.warning = `Could not convert to ${target.toUpperCase()}, kept the ${kept} file: ${message}`
+      this.logger.warn('Final container remux failed, keeping the original file', { jobId: job.id, error: message })
+    } finally {
+      job.remuxController = undefined
+    }
+    if (job.cancelled) return
+    job.progress.status = job.progress.partialErrors ? 'completed-with-errors' : 'completed'
+    job.progress.progress = 100
+  }
+
+  /**
+   * Write .chapters.json and .cue next to an audio-only download (writeChaptersFile).
+   * A download without chapters gets none. Never rejects.
+   */
+  private async writeChapterSidecars(job: DownloadJob): Promise<void> {
+    const filePath = job.progress.filePath
+    if (!job.options.writeChaptersFile || !filePath || !isAudioFile(filePath)) return
+
+    try {
+      const embedded = await this.videoProcessor.getChapters(filePath).catch(() => [])
+      const chapters = embedded.length > 0 ? embedded : (job.chapters ?? [])
+      const written = writeChapterFiles(filePath, chapters, job.progress.title, job.progress.channel)
+      if (written.length > 0) {
+        job.progress.sidecarFiles = written
+      }
+      this.logger.info('Chapter files written', { jobId: job.id, chapters: chapters.length })
+    } catch (error) {
+      job.progress.warning = `Could not write chapter files: ${(error as Error).message}`
+      this.logger.warn('Failed to write chapter files', { jobId: job.id, error: (error as Error).message })
+    }
+  }
+
+  /**
+   * Regenerate the destination folder's feed.xml after an audio download, when the download
+   * asks for one (writePodcastFeed) or the folder already has one
+   */
+  private updatePodcastFeed(job: DownloadJob): void {
+    const filePath = job.progress.filePath
+    if (!filePath || !isAudioFile(filePath)) return
+    const folder = dirname(filePath)
+    if (!job.options.writePodcastFeed && !hasPodcastFeed(folder)) return
+
+    try {
+      writePodcastFeed(folder)
+    } catch (error) {
+      this.logger.warn('Failed to write podcast feed', { jobId: job.id, folder, error: (error as Error).message })
+    }
+  }
+
+  /**
+   * Probe the finished file and store its resolution for the library
+   */
+  private async recordResolution(job: DownloadJob): Promise<void> {
+    const filePath = job.progress.filePath
+    if (!filePath) return
+
+    try {
+      const metadata = await this.videoProcessor.getVideoMetadata(filePath)
+      if (metadata.width > 0 && metadata.height > 0) {
+        job.progress.resolution = `${metadata.width}x${metadata.height}`
+        updateDownloadInStorage(job.id, { resolution: job.progress.resolution })
+      }
+    } catch (error) {
+      this.logger.debug('Could not probe resolution of downloaded file', {
+        jobId: job.id,
+        error: (error as Error).message,
+      })
+    }
   }
 
   /**
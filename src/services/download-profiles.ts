@@ -0,0 +1,100 @@
+/**
+ * Download Profiles
+ * Named sets of download options ("Archive", "Quick audio", ...) kept in
+ * download-profiles.json next to downloads.json. A profile applies to the one download it
+ * is used for; the download settings stay as they are. Options a profile doesn't set come
+ * from the settings, as for any download.
+ */
+
+import { randomUUID } from 'crypto'
+import { existsSync, readFileSync, writeFileSync } from 'fs'
+import { join } from 'path'
+import { app } from 'electron'
+
+import type { DownloadOptions, DownloadProfile } from '../types/download'
+import { Logger } from '../utils/logger'
+
+const logger = Logger.getInstance()
+
+const profilesFilePath = join(app.getPath('userData'), 'download-profiles.json')
+
+let profiles: DownloadProfile[] | null = null
+
+function loadProfiles(): DownloadProfile[] {
+  if (profiles) {
+    return profiles
+  }
+
+  try {
+    const stored = existsSync(profilesFilePath) ? JSON.parse(readFileSync(profilesFilePath, 'utf-8')) : {}
+    profiles = Array.isArray(stored.profiles) ? stored.profiles : []
+  } catch (error) {
+    logger.warn('Error loading download profiles, starting empty', { error: (error as Error).message })
+    profiles = []
+  }
+  return profiles!
+}
+
+function saveProfiles(): void {
+  try {
+    const data = { profiles: loadProfiles(), lastUpdated: Date.now() }
+    writeFileSync(profilesFilePath, JSON.stringify(data, null, 2), 'utf-8')
+  } catch (error) {
+    logger.error('Failed to save download profiles', error as Error)
+  }
+}
+
+/**
+ * Profiles sorted by name
+ */
+export function getDownloadProfiles(): DownloadProfile[] {
+  return [...loadProfiles()].sort((a, b) => a.name.localeCompare(b.name))
+}
+
+export function getDownloadProfile(profileId: string): DownloadProfile | null {
+  return loadProfiles().find(profile => profile.id === profileId) ?? null
+}
+
+/**
+ * Create a profile, or update the one with this ID. Names must be unique (ignoring case).
+ */
+export function saveDownloadProfile(name: string, options: DownloadOptions, profileId?: string): DownloadProfile {
+  const trimmed = name.trim()
+  if (!trimmed) {
+    throw new Error('Profile name is required')
+  }
+
+  const list = loadProfiles()
+  const existing = profileId ? list.find(profile => profile.id === profileId) : undefined
+  if (profileId && !existing) {
+    throw new Error(`Profile not found: ${profileId}`)
+  }
+  const duplicate = list.find(
+    profile => profile.id !== profileId && profile.name.toLowerCase() === trimmed.toLowerCase(),
+  )
+  if (duplicate) {
+    throw new Error(`A profile named "${duplicate.name}" already exists`)
+  }
+
+  const now = Date.now()
+  const profile: DownloadProfile = existing
+    ? Object.assign(existing, { name: trimmed, options, updatedAt: now })
+    : { id: randomUUID(), name: trimmed, options, createdAt: now, updatedAt: now }
+  if (!existing) {
+    list.push(profile)
+  }
+  saveProfiles()
+  logger.info('Download profile saved', { profileId: profile.id, name: profile.name })
+  return profile
+}
+
+export function deleteDownloadProfile(profileId: string): boolean {
+  const list = loadProfiles()
+  const index = list.findIndex(profile => profile.id === profileId)
+  if (index === -1) {
+    return false
+  }
+  list.splice(index, 1)
+  saveProfiles()
+  return true
+}
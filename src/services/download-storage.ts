@@ -9,7 +9,7 @@ import { existsSync, readFileSync, writeFileSync } from 'fs'
 import type { DownloadProgress } from '../types/download'
 import { Logger } from '../utils/logger'
 import { app } from 'electron'
-import { join } from 'path'
+import { join, normalize } from 'path'
 
 const logger = Logger.getInstance()
 
@@ -27,6 +27,27 @@ const defaultStorage: DownloadStorageData = {
 
 let downloadStorage: DownloadStorageData
 
+/** Goes up on every change, so caches built from the downloads know to rebuild */
+let storageRevision = 0
+
+/** Lookup maps over the stored downloads; rebuilt lazily after any change */
+let lookupIndex: { byId: Map<string, DownloadProgress>; byPath: Map<string, DownloadProgress> } | null = null
+
+function getLookupIndex() {
+  if (!lookupIndex) {
+    const downloads = loadDownloadStorage().downloads
+    lookupIndex = {
+      byId: new Map(downloads.map(download => [download.downloadId, download])),
+      byPath: new Map(
+        downloads
+          .filter(download => download.filePath)
+          .map(download => [normalize(download.filePath!), download] as [string, DownloadProgress]),
+      ),
+    }
+  }
+  return lookupIndex
+}
+
 /**
  * Load download storage from disk. Returns cached data if already loaded.
  * Creates default empty storage if file doesn't exist.
@@ -55,8 +76,23 @@ export function loadDownloadStorage(): DownloadStorageData {
   return downloadStorage
 }
 
+/** Drop the in-memory copy and re-read storage from disk */
+export function reloadDownloadStorage(): DownloadStorageData {
+  downloadStorage = undefined as unknown as DownloadStorageData
+  lookupIndex = null
+  storageRevision++
+  return loadDownloadStorage()
+}
+
+/** Path of the downloads.json storage file */
+export function getDownloadStorageFilePath(): string {
+  return downloadsFilePath
+}
+
 /** Persist current storage state to disk */
 export function saveDownloadStorage(): void {
+  lookupIndex = null
+  storageRevision++
   try {
     downloadStorage.lastUpdated = Date.now()
     writeFileSync(downloadsFilePath, JSON.stringify(downloadStorage, null, 2), 'utf-8')
@@ -65,11 +101,26 @@ export function saveDownloadStorage(): void {
   }
 }
 
+/** Revision of the stored downloads; changes whenever they do */
+export function getStorageRevision(): number {
+  return storageRevision
+}
+
 /** Get all stored downloads from memory (loads from disk if needed) */
 export function getStoredDownloads(): DownloadProgress[] {
   return loadDownloadStorage().downloads
 }
 
+/** Stored download with this ID, without scanning the whole history */
+export function getStoredDownloadById(downloadId: string): DownloadProgress | undefined {
+  return getLookupIndex().byId.get(downloadId)
+}
+
+/** Stored download whose file is at this path */
+export function getStoredDownloadByPath(filePath: string): DownloadProgress | undefined {
+  return getLookupIndex().byPath.get(normalize(filePath))
+}
+
 /** Add or update a download in storage. Updates existing if downloadId matches. */
 export function addDownloadToStorage(download: DownloadProgress): void {
   const storage = loadDownloadStorage()
@@ -85,6 +136,15 @@ export function addDownloadToStorage(download: DownloadProgress): void {
   saveDownloadStorage()
 }
 
+/** Append several downloads and save once (bulk import). */
+export function addDownloadsToStorage(downloads: DownloadProgress[]): void {
+  if (downloads.length === 0) return
+  const storage = loadDownloadStorage()
+  storage.downloads.push(...downloads)
+  downloadStorage = storage
+  saveDownloadStorage()
+}
+
 /** Remove a download from storage by ID. Returns true if found and removed. */
 export function removeDownloadFromStorage(downloadId: string): boolean {
   const storage = loadDownloadStorage()
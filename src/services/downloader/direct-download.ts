@@ -0,0 +1,472 @@
+/**
+ * Direct Download
+ * Fallback for URLs that point straight at a media file, such as a signed CDN link, rather
+ * than at a page yt-dlp has an extractor for. yt-dlp only gets YouTube URLs; any other
+ * http(s) URL is checked here, and counts as media when its path ends in a media extension
+ * or a HEAD request answers with a video/* or audio/* content type.
+ *
+ * yt-dlp is never tried first for other sites, so there is no fallback after its "Unsupported
+ * URL". The yt-dlp provider works on YouTube video IDs: info extraction, format selection and
+ * streaming all rebuild the watch URL from the ID. For a link to a media file, yt-dlp's
+ * generic extractor would only find the same single file this module fetches.
+ *
+ * The file is streamed to <name>.part through advanced.proxyUrl, like yt-dlp's requests,
+ * and renamed once complete. Progress goes out on the same events as yt-dlp downloads: a
+ * percentage of the Content-Length, or just the bytes received when the server doesn't
+ * send one. Pausing keeps the .part file and resuming asks for the rest with a Range
+ * request, starting over when the server answers with the whole file instead.
+ *
+ * HLS and DASH playlists (.m3u8, .mpd) are not media files and are not fetched this way.
+ */
+
+import { createHash } from 'crypto'
+import { EventEmitter } from 'events'
+import { createWriteStream, existsSync, mkdirSync, renameSync, rmSync, statSync } from 'fs'
+import { basename, dirname, extname } from 'path'
+import { type ClientRequest, type IncomingMessage, type Session, net, session } from 'electron'
+
+import type { DirectMediaInfo, DownloadOptions, DownloadProgress, VideoInfo } from '../../types/download'
+import { DownloadErrorCode } from '../../types/download'
+import { ConfigManager } from '../../utils/config'
+import { createLocalizedDownloadError } from '../../utils/i18n'
+import { Logger } from '../../utils/logger'
+import { getDownloadPathSettings, renderOutputTemplate } from '../../utils/paths'
+import { type ProxyCredentials, applyConfiguredProxy } from '../connectivity'
+import { DOWNLOAD_PAUSE_REASON } from './yt-dlp-provider'
+
+const logger = Logger.getInstance()
+
+const DIRECT_PARTITION = 'direct-download' // In memory only, no 'persist:' prefix
+const RESPONSE_TIMEOUT = 30000 // ms until the server answers
+const PROGRESS_INTERVAL = 500 // ms between progress events
+const PART_EXTENSION = '.part'
+const YTDLP_FIELD_PATTERN = /%\([^)]*\)[a-z]/g // Fields only yt-dlp fills in; it writes NA for unknown ones
+
+const EXTENSION_CONTENT_TYPES: Record<string, string> = {
+  mp4: 'video/mp4',
+  m4v: 'video/mp4',
+  mov: 'video/quicktime',
+  webm: 'video/webm',
+  mkv: 'video/x-matroska',
+  avi: 'video/x-msvideo',
+  flv: 'video/x-flv',
+  mp3: 'audio/mpeg',
+  m4a: 'audio/mp4',
+  aac: 'audio/aac',
+  ogg: 'audio/ogg',
+  opus: 'audio/ogg',
+  flac: 'audio/flac',
+  wav: 'audio/wav',
+}
+
+function getUrlExtension(url: URL): string {
+  return extname(url.pathname).slice(1).toLowerCase()
+}
+
+function getUrlFileName(url: URL): string {
+  const name = basename(url.pathname)
+  try {
+    return decodeURIComponent(name) || url.hostname
+  } catch {
+    return name || url.hostname
+  }
+}
+
+function isHttpUrl(url: URL): boolean {
+  return url.protocol === 'http:' || url.protocol === 'https:'
+}
+
+/**
+ * video/* or audio/*, apart from the playlist types some servers send as audio/mpegurl
+ */
+function isMediaContentType(contentType: string): boolean {
+  return /^(video|audio)\//i.test(contentType) && !/mpegurl/i.test(contentType)
+}
+
+function getExtensionForContentType(contentType: string): string | undefined {
+  const type = contentType.split(';')[0].trim().toLowerCase()
+  return Object.keys(EXTENSION_CONTENT_TYPES).find(extension => EXTENSION_CONTENT_TYPES[extension] === type)
+}
+
+function getHeader(response: IncomingMessage, name: string): string | undefined {
+  const value = response.headers[name]
+  return Array.isArray(value) ? value[0] : value
+}
+
+/**
+ * File name from a Content-Disposition header, e.g. attachment; filename="clip.mp4"
+ */
+function getDispositionFileName(disposition: string | undefined): string | undefined {
+  const encoded = disposition?.match(/filename\*=(?:UTF-8'')?([^;]+)/i)?.[1]
+  if (encoded) {
+    try {
+      return decodeURIComponent(encoded.trim().replace(/^"|"$/g, ''))
+    } catch {
+      // Fall back to the plain filename parameter
+    }
+  }
+  return disposition?.match(/filename="?([^";]+)"?/i)?.[1]?.trim()
+}
+
+/**
+ * Whether the URL's path ends in a media extension
+ */
+export function isDirectMediaUrl(url: string): boolean {
+  try {
+    const parsed = new URL(url)
+    return isHttpUrl(parsed) && getUrlExtension(parsed) in EXTENSION_CONTENT_TYPES
+  } catch {
+    return false
+  }
+}
+
+async function getDirectSession(): Promise<{ ses: Session; credentials: ProxyCredentials | null }> {
+  const ses = session.fromPartition(DIRECT_PARTITION)
+  const { credentials } = await applyConfiguredProxy(ses)
+  return { ses, credentials }
+}
+
+/**
+ * Send a request and wait for the response headers. Redirects are followed.
+ */
+function sendRequest(
+  url: string,
+  method: 'HEAD' | 'GET',
+  headers: Record<string, string> = {},
+  signal?: AbortSignal,
+): Promise<{ request: ClientRequest; response: IncomingMessage }> {
+  return getDirectSession().then(
+    ({ ses, credentials }) =>
+      new Promise((resolve, reject) => {
+        const request = net.request({ method, url, session: ses, redirect: 'follow' })
+        Object.entries(headers).forEach(([name, value]) => request.setHeader(name, value))
+        const timer = setTimeout(() => {
+          request.abort()
+          reject(new Error(`No response within ${RESPONSE_TIMEOUT / 1000} seconds`))
+        }, RESPONSE_TIMEOUT)
+        const onAbort = () => {
+          clearTimeout(timer)
+          request.abort()
+        }
+        signal?.addEventListener('abort', onAbort, { once: true })
+
+        request.on('login', (authInfo, callback) => {
+          if (authInfo.isProxy && credentials) {
+            callback(credentials.username, credentials.password)
+          } else {
+            callback()
+          }
+        })
+        request.on('response', response => {
+          clearTimeout(timer)
+          signal?.removeEventListener('abort', onAbort)
+          resolve({ request, response })
+        })
+        request.on('error', error => {
+          clearTimeout(timer)
+          signal?.removeEventListener('abort', onAbort)
+          reject(error)
+        })
+        request.end()
+      }),
+  )
+}
+
+/**
+ * What the server says about the file at a URL; null when it isn't a media file. A server
+ * that refuses HEAD requests is taken at the URL's extension.
+ */
+export async function probeDirectMedia(url: string): Promise<(DirectMediaInfo & { fileName: string }) | null> {
+  let parsed: URL
+  try {
+    parsed = new URL(url)
+  } catch {
+    return null
+  }
+  if (!isHttpUrl(parsed)) {
+    return null
+  }
+
+  const urlExtension = getUrlExtension(parsed)
+  const urlFileName = getUrlFileName(parsed)
+  const fromUrl = urlExtension in EXTENSION_CONTENT_TYPES
+  try {
+    const { response } = await sendRequest(url, 'HEAD')
+    response.on('data', () => {})
+    const contentType = getHeader(response, 'content-type') ?? ''
+    if (response.statusCode >= 400 || !(isMediaContentType(contentType) || fromUrl)) {
+      return fromUrl && response.statusCode === 405 ? mediaFromUrl(urlExtension, urlFileName) : null
+    }
+
+    const fileName = getDispositionFileName(getHeader(response, 'content-disposition')) ?? urlFileName
+    const fileExtension = extname(fileName).slice(1).toLowerCase()
+    const extension =
+      (fileExtension in EXTENSION_CONTENT_TYPES ? fileExtension : undefined) ??
+      (fromUrl ? urlExtension : undefined) ??
+      getExtensionForContentType(contentType) ??
+      'mp4'
+    const length = Number(getHeader(response, 'content-length'))
+    return {
+      contentType: isMediaContentType(contentType) ? contentType : EXTENSION_CONTENT_TYPES[extension],
+      extension,
+      size: Number.isFinite(length) && length > 0 ? length : undefined,
+      fileName,
+    }
+  } catch (error) {
+    logger.debug('Direct media probe failed', { url, error: (error as Error).message })
+    return fromUrl ? mediaFromUrl(urlExtension, urlFileName) : null
+  }
+}
+
+function mediaFromUrl(extension: string, fileName: string): DirectMediaInfo & { fileName: string } {
+  return { contentType: EXTENSION_CONTENT_TYPES[extension], extension, fileName }
+}
+
+/**
+ * Video info for a direct media URL, named after the file. Fails as an unsupported site
+ * when the URL isn't a media file.
+ */
+export async function getDirectVideoInfo(url: string): Promise<VideoInfo> {
+  const media = await probeDirectMedia(url)
+  if (!media) {
+    throw createLocalizedDownloadError('errorSiteNotSupported', undefined, DownloadErrorCode.UNSUPPORTED_SITE)
+  }
+
+  const { fileName, ...direct } = media
+  const host = new URL(url).hostname
+  logger.info('Using direct download for media URL', { host, contentType: direct.contentType, size: direct.size })
+  return {
+    id: `direct_${createHash('sha1').update(url).digest('hex').slice(0, 11)}`,
+    title: basename(fileName, extname(fileName)) || host,
+    description: '',
+    duration: 0,
+    durationFormatted: '',
+    channel: { name: host, id: '', thumbnail: '', verified: false, subscriberCount: 0 },
+    thumbnails: [],
+    views: 0,
+    viewsFormatted: '',
+    uploadDate: '',
+    tags: [],
+    isLive: false,
+    isPrivate: false,
+    ageRestricted: false,
+    formats: [],
+    availableQualities: [],
+    direct,
+  }
+}
+
+function formatBytes(bytes: number): string {
+  const units = ['B', 'KiB', 'MiB', 'GiB']
+  let value = bytes
+  let unit = 0
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024
+    unit++
+  }
+  return `${value.toFixed(unit === 0 ? 0 : 2)}${units[unit]}`
+}
+
+/**
+ * mm:ss or h:mm:ss, as yt-dlp prints its ETA
+ */
+function formatEta(seconds: number): string {
+  const total = Math.round(seconds)
+  const pad = (value: number) => String(value).padStart(2, '0')
+  const hours = Math.floor(total / 3600)
+  const rest = `${pad(Math.floor((total % 3600) / 60))}:${pad(total % 60)}`
+  return hours > 0 ? `${hours}:${rest}` : rest
+}
+
+/**
+ * Where the file goes, from the same filename template as yt-dlp downloads
+ */
+function getDirectOutputPath(options: DownloadOptions, videoInfo: VideoInfo): string {
+  const settings = getDownloadPathSettings(options, ConfigManager.getInstance().get('download'))
+  const { path } = renderOutputTemplate(settings, videoInfo, { extension: videoInfo.direct!.extension })
+  return path.replace(YTDLP_FIELD_PATTERN, 'NA')
+}
+
+/**
+ * Download a direct media URL into the download folder. Resolves once the file is complete
+ * and 'completed' was emitted; rejects as paused or cancelled when the controller aborts.
+ */
+export async function downloadDirect(
+  url: string,
+  options: DownloadOptions,
+  progress: DownloadProgress,
+  videoInfo: VideoInfo,
+  eventEmitter: EventEmitter,
+  controller: AbortController,
+): Promise<void> {
+  // A resumed download continues the .part file it was paused with
+  const resumeFrom = options.resumeFrom?.outputTemplate ? options.resumeFrom : undefined
+  const outputPath = resumeFrom?.outputTemplate ?? getDirectOutputPath(options, videoInfo)
+  const partPath = `${outputPath}${PART_EXTENSION}`
+  logger.info('Starting direct download', { downloadId: progress.downloadId, outputPath, resuming: !!resumeFrom })
+
+  const complete = () => {
+    progress.filePath = outputPath
+    progress.videoId = videoInfo.id
+    progress.channel = videoInfo.channel.name
+    progress.format = extname(outputPath).slice(1).toLowerCase()
+    progress.completedAt = Date.now()
+    progress.status = 'completed'
+    progress.progress = 100
+    eventEmitter.emit('progress', progress)
+    eventEmitter.emit('completed', progress)
+    logger.info('Direct download completed', { filePath: outputPath })
+  }
+
+  // As yt-dlp does, a file that is already there counts as downloaded
+  if (!resumeFrom && existsSync(outputPath)) {
+    logger.info('File already downloaded, skipping', { outputPath })
+    complete()
+    return
+  }
+
+  mkdirSync(dirname(outputPath), { recursive: true })
+  const offset = existsSync(partPath) ? statSync(partPath).size : 0
+  const rangeHeaders: Record<string, string> = offset > 0 ? { Range: `bytes=${offset}-` } : {}
+
+  const stopped = () => {
+    const pausing = controller.signal.reason === DOWNLOAD_PAUSE_REASON
+    if (!pausing) {
+      rmSync(partPath, { force: true })
+      return createLocalizedDownloadError('errorDownloadCancelled', undefined, DownloadErrorCode.DOWNLOAD_CANCELLED)
+    }
+    const downloadedBytes = existsSync(partPath) ? statSync(partPath).size : 0
+    progress.pausedState = {
+      options: { ...options, resumeFrom: undefined },
+      outputTemplate: outputPath,
+      partialFiles: downloadedBytes > 0 ? [{ path: partPath, size: downloadedBytes }] : [],
+      downloadedBytes,
+      pausedAt: Date.now(),
+    }
+    progress.downloadedBytes = downloadedBytes
+    progress.status = 'paused'
+    logger.info('Direct download paused', { downloadId: progress.downloadId, downloadedBytes })
+    return createLocalizedDownloadError('errorDownloadPaused', undefined, DownloadErrorCode.DOWNLOAD_PAUSED)
+  }
+
+  let request: ClientRequest
+  let response: IncomingMessage
+  try {
+    ;({ request, response } = await sendRequest(url, 'GET', rangeHeaders, controller.signal))
+  } catch (error) {
+    if (controller.signal.aborted) {
+      throw stopped()
+    }
+    throw createLocalizedDownloadError(
+      'errorDownloadFailed',
+      { reason: (error as Error).message },
+      DownloadErrorCode.NETWORK_ERROR,
+    )
+  }
+
+  if (response.statusCode >= 400) {
+    response.on('data', () => {})
+    throw createLocalizedDownloadError(
+      'errorDownloadFailed',
+      { reason: `HTTP ${response.statusCode}` },
+      DownloadErrorCode.NETWORK_ERROR,
+    )
+  }
+
+  // 206 continues the .part file; a 200 is the whole file, so it starts over
+  const resumed = offset > 0 && response.statusCode === 206
+  const startBytes = resumed ? offset : 0
+  const length = Number(getHeader(response, 'content-length'))
+  const totalBytes = Number.isFinite(length) && length > 0 ? startBytes + length : 0
+  if (offset > 0) {
+    logger.info(resumed ? 'Resuming direct download' : 'Server ignored the range, starting over', { offset })
+  }
+
+  return new Promise<void>((resolve, reject) => {
+    const file = createWriteStream(partPath, { flags: resumed ? 'a' : 'w' })
+    let receivedBytes = startBytes
+    let lastEmit = { time: Date.now(), bytes: startBytes }
+    let settled = false
+    const fail = (error: unknown) => {
+      if (settled) return
+      settled = true
+      file.destroy()
+      reject(error)
+    }
+
+    const emitProgress = () => {
+      const now = Date.now()
+      const speed = ((receivedBytes - lastEmit.bytes) * 1000) / Math.max(1, now - lastEmit.time)
+      lastEmit = { time: now, bytes: receivedBytes }
+      progress.status = 'downloading'
+      progress.downloadedBytes = receivedBytes
+      progress.totalBytes = totalBytes
+      progress.speed = `${formatBytes(speed)}/s`
+      if (totalBytes > 0) {
+        progress.progress = Math.round((receivedBytes / totalBytes) * 1000) / 10
+        progress.size = formatBytes(totalBytes)
+        progress.eta = speed > 0 ? formatEta((totalBytes - receivedBytes) / speed) : '--:--'
+      } else {
+        progress.size = formatBytes(receivedBytes)
+        progress.eta = '--:--'
+      }
+      eventEmitter.emit('progress', progress)
+    }
+
+    controller.signal.addEventListener(
+      'abort',
+      () => {
+        request.abort()
+        response.removeAllListeners('data')
+        file.end(() => fail(stopped()))
+      },
+      { once: true },
+    )
+
+    response.on('data', chunk => {
+      receivedBytes += chunk.length
+      if (!file.write(chunk)) {
+        response.pause()
+        file.once('drain', () => response.resume())
+      }
+      if (Date.now() - lastEmit.time >= PROGRESS_INTERVAL) {
+        emitProgress()
+      }
+    })
+    response.on('end', () => {
+      file.end(() => {
+        if (settled || controller.signal.aborted) return
+        if (totalBytes > 0 && receivedBytes < totalBytes) {
+          fail(
+            createLocalizedDownloadError(
+              'errorDownloadFailed',
+              { reason: `Connection closed after ${formatBytes(receivedBytes)} of ${formatBytes(totalBytes)}` },
+              DownloadErrorCode.NETWORK_ERROR,
+            ),
+          )
+          return
+        }
+        try {
+          renameSync(partPath, outputPath)
+        } catch (error) {
+          fail(error)
+          return
+        }
+        settled = true
+        complete()
+        resolve()
+      })
+    })
+    response.on('error', (error: Error) => {
+      if (controller.signal.aborted) return
+      fail(
+        createLocalizedDownloadError('errorDownloadFailed', { reason: error.message }, DownloadErrorCode.NETWORK_ERROR),
+      )
+    })
+    file.on('error', error => {
+      fail(
+        createLocalizedDownloadError('errorDownloadFailed', { reason: error.message }, DownloadErrorCode.UNKNOWN_ERROR),
+      )
+    })
+  })
+}
@@ -0,0 +1,135 @@
+/**
+ * Download Logs
+ * Captures the full stdout/stderr of each yt-dlp run into logs/downloads/<downloadId>.log
+ * so a failed download's explanation isn't buried in the shared app log.
+ *
+ * Each log is capped at MAX_LOG_SIZE; when exceeded (e.g. live streams running for hours)
+ * the current file rotates to <downloadId>.log.1 and a fresh file is started.
+ */
+
+import { appendFileSync, existsSync, mkdirSync, readFileSync, readdirSync, renameSync, statSync, unlinkSync } from 'fs'
+import { join } from 'path'
+
+import { Logger } from '../../utils/logger'
+
+const MAX_LOG_SIZE = 5 * 1024 * 1024 // 5MB per file, plus one rotated file
+const STDERR_TAIL_LINES = 20
+
+const logger = Logger.getInstance()
+
+export function getDownloadLogsDirectory(): string {
+  return join(logger.getLogsDirectory(), 'downloads')
+}
+
+/**
+ * Log file path for a download. IDs are restricted to safe characters.
+ */
+export function getDownloadLogPath(downloadId: string): string {
+  return join(getDownloadLogsDirectory(), `${downloadId.replace(/[^\w-]/g, '_')}.log`)
+}
+
+export class DownloadLog {
+  readonly path: string
+  private size = 0
+  private stderrTail: string[] = []
+  private partialStderrLine = ''
+
+  constructor(downloadId: string) {
+    this.path = getDownloadLogPath(downloadId)
+    try {
+      mkdirSync(getDownloadLogsDirectory(), { recursive: true })
+      this.size = existsSync(this.path) ? statSync(this.path).size : 0
+    } catch (error) {
+      logger.warn('Failed to prepare download log directory', { path: this.path, error: (error as Error).message })
+    }
+  }
+
+  /**
+   * Write a header line (e.g. the command being run)
+   */
+  writeLine(line: string): void {
+    this.write(`[${new Date().toISOString()}] ${line}\n`)
+  }
+
+  /**
+   * Append raw process output
+   */
+  append(stream: 'stdout' | 'stderr', text: string): void {
+    if (stream === 'stderr') {
+      this.trackStderr(text)
+    }
+    this.write(stream === 'stderr' ? text.replace(/^(?=.)/gm, '[stderr] ') : text)
+  }
+
+  /**
+   * Last lines written to stderr, for showing inline with a failure
+   */
+  getStderrTail(): string[] {
+    const tail = this.partialStderrLine ? [...this.stderrTail, this.partialStderrLine] : this.stderrTail
+    return tail.slice(-STDERR_TAIL_LINES)
+  }
+
+  private trackStderr(text: string): void {
+    const lines = (this.partialStderrLine + text).split(/\r?\n/)
+    this.partialStderrLine = lines.pop() ?? ''
+    this.stderrTail.push(...lines.filter(line => line.trim()))
+    if (this.stderrTail.length > STDERR_TAIL_LINES) {
+      this.stderrTail = this.stderrTail.slice(-STDERR_TAIL_LINES)
+    }
+  }
+
+  private write(text: string): void {
+    try {
+      if (this.size + Buffer.byteLength(text) > MAX_LOG_SIZE) {
+        renameSync(this.path, `${this.path}.1`)
+        this.size = 0
+      }
+      appendFileSync(this.path, text, 'utf8')
+      this.size += Buffer.byteLength(text)
+    } catch {
+      // Never fail a download because its log couldn't be written
+    }
+  }
+}
+
+/**
+ * Read the last N lines of a download's log, including the rotated file when needed
+ */
+export function readDownloadLogTail(logPath: string, tailLines: number): string[] {
+  const files = [`${logPath}.1`, logPath].filter(file => existsSync(file))
+  let lines: string[] = []
+  for (const file of files) {
+    lines = lines.concat(readFileSync(file, 'utf8').split(/\r?\n/))
+  }
+  while (lines.length > 0 && lines[lines.length - 1] === '') {
+    lines.pop()
+  }
+  return tailLines > 0 ? lines.slice(-tailLines) : lines
+}
+
+/**
+ * Delete download logs not modified within the retention period. Returns the number removed.
+ */
+export function cleanupDownloadLogs(retentionDays: number): number {
+  const directory = getDownloadLogsDirectory()
+  if (!existsSync(directory)) return 0
+
+  const cutoff = Date.now() - retentionDays * 24 * 60 * 60 * 1000
+  let removed = 0
+  for (const file of readdirSync(directory)) {
+    const filePath = join(directory, file)
+    try {
+      if (statSync(filePath).mtimeMs < cutoff) {
+        unlinkSync(filePath)
+        removed++
+      }
+    } catch {
+      // Skip files that disappear or can't be accessed
+    }
+  }
+
+  if (removed > 0) {
+    logger.info('Old download logs removed', { removed, retentionDays })
+  }
+  return removed
+}
@@ -0,0 +1,68 @@
+/**
+ * Partial Downloads
+ * Finds and checks the partial files yt-dlp leaves behind when a download is stopped, so a
+ * paused download can continue with --continue, also after Clipy restarts. It only continues
+ * from files that are exactly as they were when it was paused: a file that is gone or has
+ * a different size means something else touched it, and the download starts over instead.
+ */
+
+import { existsSync, readdirSync, rmSync, statSync } from 'node:fs'
+import { basename, dirname, join } from 'node:path'
+
+import type { PartialFile, PausedDownloadState } from '../../types/download'
+import { EXTENSION_PLACEHOLDER } from '../../utils/paths'
+
+/** .part files (whole file or per fragment) and yt-dlp's fragment resume state */
+const PARTIAL_FILE_PATTERN = /\.part(-Frag\d+)?(\.part)?$|\.ytdl$/
+
+/**
+ * Partial files of a download run with this output template, in its temp folder when it
+ * used one, else next to where the finished file would go
+ */
+export function findPartialFiles(outputTemplate: string, tempDir?: string): PartialFile[] {
+  const base = outputTemplate.endsWith(EXTENSION_PLACEHOLDER)
+    ? outputTemplate.slice(0, -EXTENSION_PLACEHOLDER.length)
+    : outputTemplate
+  const name = basename(base).replace(/%%/g, '%')
+  const dir = tempDir ?? dirname(base).replace(/%%/g, '%')
+  if (!existsSync(dir)) {
+    return []
+  }
+
+  return readdirSync(dir)
+    .filter(file => file.startsWith(name) && PARTIAL_FILE_PATTERN.test(file))
+    .map(file => {
+      const path = join(dir, file)
+      return { path, size: statSync(path).size }
+    })
+}
+
+/**
+ * Why a paused download can't continue from its partial files, or null when every file is
+ * still there with the size it had when the download was paused
+ */
+export function findPartialFileMismatch(state: PausedDownloadState): string | null {
+  for (const file of state.partialFiles) {
+    if (!existsSync(file.path)) {
+      return `${basename(file.path)} is missing`
+    }
+    const size = statSync(file.path).size
+    if (size !== file.size) {
+      return `${basename(file.path)} is ${size} bytes, expected ${file.size}`
+    }
+  }
+  return null
+}
+
+/**
+ * Remove a paused download's partial files (and its temp folder), e.g. when it is
+ * cancelled or has to start over
+ */
+export function removePartialFiles(state: PausedDownloadState): void {
+  for (const file of state.partialFiles) {
+    rmSync(file.path, { force: true })
+  }
+  if (state.tempDir) {
+    rmSync(state.tempDir, { recursive: true, force: true })
+  }
+}
@@ -0,0 +1,80 @@
+/**
+ * Quality Fallback
+ * Checks a requested quality against the formats a video actually offers before the
+ * download is queued, so a selector that can't match fails fast with the available
+ * qualities instead of yt-dlp's "Requested format is not available", or is swapped for
+ * the closest available quality (settings: download.qualityFallback). Either way the
+ * substitution is recorded on the download so the UI can say what was actually fetched.
+ *
+ * Only direct (non-HLS) video formats count, matching what the format selectors allow.
+ */
+
+import type { VideoFormatInfo } from '../../types/download'
+
+export type QualityMatch =
+  | { kind: 'any' } // No height limit requested, or nothing to check against
+  | { kind: 'exact'; height: number }
+  | { kind: 'lower'; height: number } // Selector works, but the video tops out below the request
+  | { kind: 'none'; available: number[] } // Every format is above the request; heights, highest first
+
+const HLS_PROTOCOLS = ['m3u8', 'm3u8_native']
+
+/**
+ * Height limit of a quality option ('1080p', '4K', 'hd_720p', ...); null for 'best' and unknown values
+ */
+export function getRequestedHeight(quality: string | undefined): number | null {
+  if (!quality) return null
+  if (quality.toUpperCase() === '4K') return 2160
+  const match = quality.match(/(\d{3,4})p$/i)
+  return match ? parseInt(match[1], 10) : null
+}
+
+/**
+ * Distinct heights of the direct video formats, highest first
+ */
+export function getAvailableHeights(formats: VideoFormatInfo[]): number[] {
+  const heights = formats
+    .filter(format => format.hasVideo && format.height && !HLS_PROTOCOLS.includes(format.protocol ?? ''))
+    .map(format => format.height!)
+  return Array.from(new Set(heights)).sort((a, b) => b - a)
+}
+
+/**
+ * How a requested quality matches a video's formats
+ */
+export function matchQuality(formats: VideoFormatInfo[], quality: string | undefined): QualityMatch {
+  const requested = getRequestedHeight(quality)
+  const available = getAvailableHeights(formats)
+  if (requested === null || available.length === 0) {
+    return { kind: 'any' }
+  }
+
+  const best = available.find(height => height <= requested)
+  if (best === undefined) {
+    return { kind: 'none', available }
+  }
+  return best === requested ? { kind: 'exact', height: best } : { kind: 'lower', height: best }
+}
+
+/**
+ * Resolution of the format a quality would pick, e.g. '1920x1080', or undefined when the
+ * formats don't say. A quality every format is above gets the closest one, as with the
+ * 'closest' fallback.
+ */
+export function getExpectedResolution(formats: VideoFormatInfo[], quality: string | undefined): string | undefined {
+  const match = matchQuality(formats, quality)
+  const available = getAvailableHeights(formats)
+  const height =
+    match.kind === 'exact' || match.kind === 'lower'
+      ? match.height
+      : match.kind === 'none'
+        ? available[available.length - 1]
+        : available[0]
+  const format = formats.find(candidate => candidate.hasVideo && candidate.height === height && candidate.width)
+  return format ? `${format.width}x${format.height}` : undefined
+}
+
+/** Quality label as shown to users, e.g. '1440p' */
+export function formatHeight(height: number): string {
+  return `${height}p`
+}
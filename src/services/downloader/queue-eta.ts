@@ -0,0 +1,59 @@
+/**
+ * Queue ETA
+ * Estimates how long the download queue still needs. Active downloads report their own
+ * ETA; queued ones are sized from the formats their video info listed and divided by the
+ * average speed of the running downloads, or an assumed 1 MB/s before any has a speed.
+ * Downloads whose size isn't known yet add nothing, so the estimate errs on the low side.
+ */
+
+import type { VideoFormatInfo } from '../../types/download'
+import { getRequestedHeight } from './quality-fallback'
+
+export const DEFAULT_DOWNLOAD_SPEED = 1024 * 1024 // Bytes/s assumed while no download reports a speed
+
+const SIZE_UNITS: Record<string, number> = {
+  B: 1,
+  KIB: 1024,
+  MIB: 1024 ** 2,
+  GIB: 1024 ** 3,
+  KB: 1000,
+  MB: 1000 ** 2,
+  GB: 1000 ** 3,
+}
+
+/**
+ * Bytes per second from a yt-dlp speed such as '1.23MiB/s'; 0 when unknown
+ */
+export function parseSpeed(speed: string | undefined): number {
+  const match = speed?.match(/^([\d.]+)\s*([KMG]?i?B)\/s$/i)
+  if (!match) return 0
+  const value = parseFloat(match[1]) * (SIZE_UNITS[match[2].toUpperCase()] ?? 0)
+  return Number.isFinite(value) ? value : 0
+}
+
+/**
+ * Seconds from a yt-dlp ETA such as '01:23' or '1:02:03'; null when unknown
+ */
+export function parseEta(eta: string | undefined): number | null {
+  if (!eta || !/^\d+(:\d+){0,2}$/.test(eta)) return null
+  return eta.split(':').reduce((seconds, part) => seconds * 60 + parseInt(part, 10), 0)
+}
+
+/**
+ * Expected download size for a quality: the largest video format at the height that
+ * would be picked plus the largest audio-only format. null when the formats list no sizes.
+ */
+export function estimateDownloadSize(formats: VideoFormatInfo[], quality: string | undefined): number | null {
+  const requested = getRequestedHeight(quality)
+  const videoFormats = formats.filter(format => format.hasVideo && format.contentLength)
+  const heights = videoFormats
+    .map(format => format.height ?? 0)
+    .filter(height => requested === null || height <= requested)
+  const height = heights.length > 0 ? Math.max(...heights) : null
+  const video = Math.max(0, ...videoFormats.filter(f => (f.height ?? 0) === height).map(f => f.contentLength!))
+
+  const audioFormats = formats.filter(format => format.hasAudio && !format.hasVideo && format.contentLength)
+  const audio = Math.max(0, ...audioFormats.map(format => format.contentLength!))
+
+  return video + audio > 0 ? video + audio : null
+}
@@ -0,0 +1,68 @@
+/**
+ * Queue Scheduler
+ * Pure selection logic for the download queue: which pending jobs may start,
+ * given the global concurrency limit and an optional per-host limit.
+ *
+ * Pending jobs are considered in queue order, but a job whose host is already
+ * at its quota is skipped so jobs from other hosts can use the free slots.
+ */
+
+export interface QueueLimits {
+  maxConcurrent: number
+  maxPerHost?: number // 0 or undefined = unlimited
+}
+
+/**
+ * Extract a normalized host from a URL for per-host accounting.
+ * Strips www./m./music. prefixes and maps youtu.be to youtube.com.
+ * Returns '' for unparseable URLs.
+ */
+export function getUrlHost(url: string): string {
+  let hostname: string
+  try {
+    hostname = new URL(url).hostname.toLowerCase()
+  } catch {
+    return ''
+  }
+
+  hostname = hostname.replace(/^(www|m|music)\./, '')
+  if (hostname === 'youtu.be' || hostname === 'youtube-nocookie.com') {
+    return 'youtube.com'
+  }
+  return hostname
+}
+
+/**
+ * Pick the indices (into `pending`) of jobs that can start now, in queue order
+ */
+export function selectStartableJobs(
+  active: readonly { url: string }[],
+  pending: readonly { url: string }[],
+  limits: QueueLimits,
+): number[] {
+  const selected: number[] = []
+  let freeSlots = limits.maxConcurrent - active.length
+  if (freeSlots <= 0) {
+    return selected
+  }
+
+  const perHostLimit = limits.maxPerHost && limits.maxPerHost > 0 ? limits.maxPerHost : Infinity
+  const hostCounts = new Map<string, number>()
+  for (const job of active) {
+    const host = getUrlHost(job.url)
+    hostCounts.set(host, (hostCounts.get(host) ?? 0) + 1)
+  }
+
+  for (let i = 0; i < pending.length && freeSlots > 0; i++) {
+    const host = getUrlHost(pending[i].url)
+    const count = hostCounts.get(host) ?? 0
+    if (count >= perHostLimit) {
+      continue
+    }
+    hostCounts.set(host, count + 1)
+    selected.push(i)
+    freeSlots--
+  }
+
+  return selected
+}
@@ -0,0 +1,150 @@
+/**
+ * yt-dlp Capabilities
+ * Probes the installed yt-dlp binary (--version and --help) so downloads can adapt to what
+ * it supports instead of failing with "no such option" on older builds.
+ *
+ * - The probe runs on first use and again whenever the binary changes (e.g. after yt-dlp -U)
+ * - youtube-dl installed under the yt-dlp name, or a yt-dlp older than MIN_YTDLP_VERSION,
+ *   is rejected with YTDLP_TOO_OLD
+ * - Newer flags that are missing are left out, with a legacy equivalent where one exists
+ */
+
+import { execFile } from 'child_process'
+import { getYtdlpBaseArgs, getYtdlpEnv } from './yt-dlp-environment'
+import { statSync } from 'fs'
+
+import { DownloadErrorCode, createDownloadError } from '../../types/download'
+import { createLocalizedDownloadError } from '../../utils/i18n'
+import { Logger } from '../../utils/logger'
+
+const logger = Logger.getInstance()
+
+/** Oldest release whose options, format selectors and output we rely on */
+export const MIN_YTDLP_VERSION = '2021.12.01'
+
+const PROBE_TIMEOUT = 15000
+
+export interface YtdlpCapabilities {
+  version: string | null
+  /** youtube-dl (or a fork of it) rather than yt-dlp */
+  isYoutubeDl: boolean
+  /** Long options listed by --help, e.g. '--download-sections' */
+  flags: Set<string>
+}
+
+let capabilitiesCache: { ytdlpPath: string; mtimeMs: number; capabilities: Promise<YtdlpCapabilities> } | null = null
+
+/**
+ * Version string from --version output ('2024.08.06', or with a nightly/patch suffix)
+ */
+export function parseYtdlpVersion(output: string): string | null {
+  const match = output.match(/\d{4}\.\d{1,2}\.\d{1,2}(?:\.\d+)?/)
+  return match ? match[0] : null
+}
+
+/**
+ * Long options listed in --help output
+ */
+export function parseHelpFlags(helpText: string): Set<string> {
+  const flags = new Set<string>()
+  for (const line of helpText.split(/\r?\n/)) {
+    // Option lines are indented and list aliases first: "    -f, --format FORMAT   Video format code"
+    const optionList = line.match(/^\s+(-[^\s].*?)(?:\s{2,}|$)/)
+    if (!optionList) continue
+    for (const flag of optionList[1].matchAll(/--[a-z][\w-]*/gi)) {
+      flags.add(flag[0])
+    }
+  }
+  return flags
+}
+
+/**
+ * Compare dotted date versions; negative when a is older than b
+ */
+export function compareYtdlpVersions(a: string, b: string): number {
+  const partsA = a.split('.').map(Number)
+  const partsB = b.split('.').map(Number)
+  for (let i = 0; i < Math.max(partsA.length, partsB.length); i++) {
+    const difference = (partsA[i] ?? 0) - (partsB[i] ?? 0)
+    if (difference !== 0) return difference
+  }
+  return 0
+}
+
+function runProbe(ytdlpPath: string, args: string[]): Promise<string> {
+  return new Promise((resolve, reject) => {
+    const options = { timeout: PROBE_TIMEOUT, windowsHide: true, env: getYtdlpEnv() }
+    execFile(ytdlpPath, [...getYtdlpBaseArgs(), ...args], options, (error, stdout) => {
+      if (error) {
+        reject(error)
+        return
+      }
+      resolve(stdout)
+    })
+  })
+}
+
+async function probeCapabilities(ytdlpPath: string): Promise<YtdlpCapabilities> {
+  const [versionOutput, helpText] = await Promise.all([
+    runProbe(ytdlpPath, ['--version']),
+    runProbe(ytdlpPath, ['--help']),
+  ])
+  const flags = parseHelpFlags(helpText)
+  const capabilities: YtdlpCapabilities = {
+    version: parseYtdlpVersion(versionOutput),
+    // --compat-options only exists in yt-dlp
+    isYoutubeDl: /usage:\s*youtube-dl/i.test(helpText) || !flags.has('--compat-options'),
+    flags,
+  }
+  logger.info('Probed yt-dlp capabilities', {
+    ytdlpPath,
+    version: capabilities.version,
+    isYoutubeDl: capabilities.isYoutubeDl,
+    flagCount: flags.size,
+  })
+  return capabilities
+}
+
+/**
+ * Capabilities of the binary at ytdlpPath, probed once per binary version
+ */
+export function getYtdlpCapabilities(ytdlpPath: string): Promise<YtdlpCapabilities> {
+  let mtimeMs = 0
+  try {
+    mtimeMs = statSync(ytdlpPath).mtimeMs
+  } catch {
+    // Resolved from PATH by name; re-probed only when the cache is reset
+  }
+
+  if (capabilitiesCache?.ytdlpPath === ytdlpPath && capabilitiesCache.mtimeMs === mtimeMs) {
+    return capabilitiesCache.capabilities
+  }
+
+  const capabilities = probeCapabilities(ytdlpPath).catch(error => {
+    capabilitiesCache = null // Don't cache a failure
+    throw createDownloadError(`Failed to run yt-dlp: ${(error as Error).message}`, DownloadErrorCode.UNKNOWN_ERROR)
+  })
+  capabilitiesCache = { ytdlpPath, mtimeMs, capabilities }
+  return capabilities
+}
+
+/** Forget the probed capabilities, e.g. after yt-dlp was updated or replaced */
+export function resetYtdlpCapabilities(): void {
+  capabilitiesCache = null
+}
+
+/**
+ * Throw YTDLP_TOO_OLD for youtube-dl or a yt-dlp older than MIN_YTDLP_VERSION
+ */
+export function assertYtdlpSupported(capabilities: YtdlpCapabilities): void {
+  if (capabilities.isYoutubeDl) {
+    throw createLocalizedDownloadError('errorYtdlpIsYoutubeDl', undefined, DownloadErrorCode.YTDLP_TOO_OLD)
+  }
+  if (capabilities.version && compareYtdlpVersions(capabilities.version, MIN_YTDLP_VERSION) < 0) {
+    throw createLocalizedDownloadError(
+      'errorYtdlpTooOld',
+      { version: capabilities.version, minimum: MIN_YTDLP_VERSION },
+      DownloadErrorCode.YTDLP_TOO_OLD,
+    )
+  }
+}
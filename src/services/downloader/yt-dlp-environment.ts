@@ -0,0 +1,152 @@
+/**
+ * yt-dlp Environment
+ * Every yt-dlp run (downloads, info lookups, version and capability probes) gets the same
+ * controlled setup, so nothing outside Clipy changes how it behaves:
+ * - --ignore-config, unless advanced.respectUserYtdlpConfig is on. A global yt-dlp config
+ *   with e.g. --extract-audio would otherwise apply to every download.
+ * - Proxy variables are removed from the environment, so only a proxy set in Clipy
+ *   (advanced.proxyUrl, passed as --proxy) applies
+ * - yt-dlp's own cache lives in Clipy's cache folder, so clearing the cache clears it too
+ * - Output is UTF-8 regardless of the Windows console code page
+ *
+ * Commands are logged through redactYtdlpArgs and download options through
+ * redactDownloadOptions, so where the user's cookies come from never ends up in the app log
+ * or a download's log.
+ */
+
+import { join } from 'path'
+
+import type { CookieChoice, DownloadOptions } from '../../types/download'
+import type { YtdlpEnvironmentInfo } from '../../types/system'
+import { ConfigManager } from '../../utils/config'
+import { StorageManager, YTDLP_CACHE_FOLDER } from '../storage-manager'
+
+/** Matched case-insensitively; Windows environment names aren't case-sensitive */
+const PROXY_VARIABLES = ['http_proxy', 'https_proxy', 'all_proxy', 'ftp_proxy', 'no_proxy']
+
+/** Arguments whose value is left out of logged commands */
+const REDACTED_ARGS = ['--cookies', '--cookies-from-browser']
+const REDACTED = '<redacted>'
+
+function getProxyUrl(): string {
+  return ConfigManager.getInstance().get('advanced')?.proxyUrl?.trim() ?? ''
+}
+
+function respectsUserConfig(): boolean {
+  return ConfigManager.getInstance().get('advanced')?.respectUserYtdlpConfig === true
+}
+
+/**
+ * Folder yt-dlp keeps its cache in (signature functions and the like)
+ */
+export function getYtdlpCacheDir(): string {
+  return join(StorageManager.getInstance().getCacheDirectory(), YTDLP_CACHE_FOLDER)
+}
+
+/**
+ * Environment for a yt-dlp process: the app's own, without proxy variables
+ */
+export function getYtdlpEnv(baseEnv: NodeJS.ProcessEnv = process.env): NodeJS.ProcessEnv {
+  const env: NodeJS.ProcessEnv = {}
+  for (const [name, value] of Object.entries(baseEnv)) {
+    if (!PROXY_VARIABLES.includes(name.toLowerCase())) {
+      env[name] = value
+    }
+  }
+  env.PYTHONIOENCODING = 'utf-8' // yt-dlp writes in the console code page on Windows otherwise
+  return env
+}
+
+/**
+ * Arguments every yt-dlp run starts with
+ */
+export function getYtdlpBaseArgs(respectUserConfig: boolean = respectsUserConfig()): string[] {
+  const args = respectUserConfig ? [] : ['--ignore-config']
+  args.push('--cache-dir', getYtdlpCacheDir())
+  const proxyUrl = getProxyUrl()
+  if (proxyUrl) {
+    args.push('--proxy', proxyUrl)
+  }
+  return args
+}
+
+/**
+ * yt-dlp arguments fit for a log: the value of each cookie argument, given either as the next
+ * argument or as --cookies=..., is replaced
+ */
+export function redactYtdlpArgs(args: string[]): string[] {
+  return args.map((arg, i) => {
+    if (i > 0 && REDACTED_ARGS.includes(args[i - 1])) {
+      return REDACTED
+    }
+    const name = REDACTED_ARGS.find(redacted => arg.startsWith(`${redacted}=`))
+    return name ? `${name}=${REDACTED}` : arg
+  })
+}
+
+/**
+ * Cookie arguments for a download or lookup. 'auto' uses the configured browser, else the app
+ * cookie file if there is one; any other choice overrides both.
+ */
+export function buildCookieArgs(
+  choice: CookieChoice = { mode: 'auto' },
+  appCookieFile: string | null = null,
+): string[] {
+  switch (choice.mode) {
+    case 'never':
+      return []
+    case 'browser':
+      return ['--cookies-from-browser', choice.browser]
+    case 'file':
+      return ['--cookies', choice.path]
+  }
+  const cookiesFromBrowser = ConfigManager.getInstance().get('download')?.cookiesFromBrowser
+  if (cookiesFromBrowser) {
+    return ['--cookies-from-browser', cookiesFromBrowser]
+  }
+  return appCookieFile ? ['--cookies', appCookieFile] : []
+}
+
+/**
+ * A download's cookie choice; the older cookiesFromBrowser option counts as a browser choice
+ */
+export function getDownloadCookieChoice(options: DownloadOptions): CookieChoice | undefined {
+  if (options.useCookies) {
+    return options.useCookies
+  }
+  return options.cookiesFromBrowser ? { mode: 'browser', browser: options.cookiesFromBrowser } : undefined
+}
+
+/**
+ * Download options fit for a log: the cookie mode is kept, the browser or file it names is
+ * replaced, here and in the options of a paused download being resumed
+ */
+export function redactDownloadOptions(options: DownloadOptions | undefined): DownloadOptions | undefined {
+  if (!options || typeof options !== 'object') {
+    return options
+  }
+  const redacted: DownloadOptions = { ...options }
+  if (redacted.cookiesFromBrowser) {
+    redacted.cookiesFromBrowser = REDACTED
+  }
+  if (redacted.useCookies?.mode === 'browser') {
+    redacted.useCookies = { mode: 'browser', browser: REDACTED }
+  } else if (redacted.useCookies?.mode === 'file') {
+    redacted.useCookies = { mode: 'file', path: REDACTED }
+  }
+  if (redacted.resumeFrom) {
+    redacted.resumeFrom = { ...redacted.resumeFrom, options: redactDownloadOptions(redacted.resumeFrom.options)! }
+  }
+  return redacted
+}
+
+/**
+ * Which outside sources can still affect yt-dlp, for diagnostics
+ */
+export function getYtdlpEnvironmentInfo(): YtdlpEnvironmentInfo {
+  return {
+    userConfigIgnored: !respectsUserConfig(),
+    proxyVariablesRemoved: Object.keys(process.env).filter(name => PROXY_VARIABLES.includes(name.toLowerCase())),
+    cacheDir: getYtdlpCacheDir(),
+  }
+}
@@ -6,12 +6,20 @@
  * - Event forwarding to renderer process
  * - Retry logic for failed downloads
  *
- * Uses yt-dlp-provider.ts for actual download execution.
+ * Uses yt-dlp-provider.ts for actual download execution, and direct-download.ts for URLs
+ * that point straight at a media file.
  */
 
 import { existsSync, mkdirSync } from 'node:fs'
-import type { DownloadConfig, DownloadFilter, DownloadOptions, DownloadProgress, VideoInfo } from '../../types/download'
-import { DownloadErrorCode, createDownloadError, isDownloadError } from '../../types/download'
+import type {
+  CookieChoice,
+  DownloadConfig,
+  DownloadFilter,
+  DownloadOptions,
+  DownloadProgress,
+  VideoInfo,
+} from '../../types/download'
+import { DownloadErrorCode, createDownloadError, isCompletedStatus, isDownloadError } from '../../types/download'
 import {
   clearOldDownloads,
   loadDownloadStorage,
@@ -19,6 +27,7 @@ import {
   updateDownloadInStorage,
 } from '../download-storage'
 import {
+  DOWNLOAD_PAUSE_REASON,
   downloadWithYtdlp,
   getVideoInfoFromYtdlp,
   initializeYtdlp,
@@ -26,11 +35,13 @@ import {
   getStreamingUrl,
   getStreamingUrls,
 } from './yt-dlp-provider'
+import { downloadDirect, getDirectVideoInfo } from './direct-download'
 
 import { app } from 'electron'
 import { EventEmitter } from 'node:events'
 import { join } from 'node:path'
 import { Logger } from '../../utils/logger'
+import { assertSiteAllowed } from '../../utils/site-policy'
 
 const logger = Logger.getInstance()
 
@@ -143,17 +154,19 @@ async function ensureOutputDirectory(outputPath: string): Promise<void> {
   }
 }
 
-export async function getVideoInfo(url: string): Promise<VideoInfo> {
+export async function getVideoInfo(url: string, useCookies?: CookieChoice): Promise<VideoInfo> {
   const state = ensureState()
+  assertSiteAllowed(url)
 
   const videoId = extractVideoId(url)
 
-  if (!videoId) {
+  if (!videoId && !isHttpUrl(url)) {
     throw createDownloadError(`Invalid YouTube URL: ${url}`, DownloadErrorCode.INVALID_URL)
   }
 
-  // Check cache first
-  const cacheKey = videoId
+  // Check cache first; a lookup with other cookies may see a different video (or none), so it is cached apart
+  const cookieKey = useCookies && useCookies.mode !== 'auto' ? `|${JSON.stringify(useCookies)}` : ''
+  const cacheKey = `${videoId ?? url}${cookieKey}`
   const cached = videoInfoCache.get(cacheKey)
   if (cached && Date.now() - cached.timestamp < VIDEO_INFO_CACHE_TTL) {
     logger.debug('Returning cached video info', { videoId })
@@ -162,6 +175,13 @@ export async function getVideoInfo(url: string): Promise<VideoInfo> {
 
   logger.debug('Getting video info', { url })
 
+  // Not a YouTube URL: only a link straight to a media file can be downloaded
+  if (!videoId) {
+    const info = await getDirectVideoInfo(url)
+    videoInfoCache.set(cacheKey, { info, timestamp: Date.now() })
+    return info
+  }
+
   if (!state.ytdlpReady || !isYtdlpInitialized()) {
     throw createDownloadError(
       'yt-dlp is not initialized. Please ensure the download manager is properly set up.',
@@ -171,7 +191,7 @@ export async function getVideoInfo(url: string): Promise<VideoInfo> {
 
   try {
     logger.debug('Fetching video info via yt-dlp')
-    const info = await getVideoInfoFromYtdlp(videoId)
+    const info = await getVideoInfoFromYtdlp(videoId, useCookies)
     if (info.formats.length === 0) {
       throw createDownloadError('No formats available for this video', DownloadErrorCode.NO_FORMAT_AVAILABLE)
     }
@@ -190,6 +210,7 @@ export async function getVideoInfo(url: string): Promise<VideoInfo> {
 
 export async function startDownload(url: string, options: DownloadOptions = {}): Promise<string> {
   const state = ensureState()
+  assertSiteAllowed(url)
 
   // Check if this URL is already being actively downloaded
   // Only consider it "in progress" if we have an active AbortController for it
@@ -218,7 +239,7 @@ export async function startDownload(url: string, options: DownloadOptions = {}):
   const controller = new AbortController()
   const videoId = extractVideoId(url)
 
-  if (!videoId) {
+  if (!videoId && !isHttpUrl(url)) {
     throw createDownloadError(`Invalid YouTube URL: ${url}`, DownloadErrorCode.INVALID_URL)
   }
 
@@ -249,12 +270,18 @@ export async function startDownload(url: string, options: DownloadOptions = {}):
       state.eventEmitter.emit('progress', progress)
 
       // Fetch video info first
-      videoInfo = await getVideoInfo(url)
+      videoInfo = await getVideoInfo(url, options.useCookies)
       progress.title = videoInfo.title
       progress.status = 'initializing'
       // NOTE: Storage is handled by download-manager.ts, not here
       state.eventEmitter.emit('progress', progress)
 
+      if (videoInfo.direct) {
+        progress.usedProvider = 'direct'
+        await downloadDirect(url, options, progress, videoInfo, state.eventEmitter, controller)
+        return
+      }
+
       const selectedProvider = options.provider || 'auto'
       logger.debug('Selected download provider', { provider: selectedProvider })
 
@@ -262,14 +289,15 @@ export async function startDownload(url: string, options: DownloadOptions = {}):
         try {
           logger.debug('Attempting download with yt-dlp')
           progress.usedProvider = 'ytdlp'
-          await downloadWithYtdlp(videoId, options, progress, videoInfo!, state.eventEmitter, controller)
+          await downloadWithYtdlp(videoId!, options, progress, videoInfo!, state.eventEmitter, controller)
           return
         } catch (ytdlpError: unknown) {
           logger.warn(
             'yt-dlp download failed',
             ytdlpError instanceof Error ? ytdlpError : new Error(String(ytdlpError)),
           )
-          if (selectedProvider === 'ytdlp') {
+          const paused = isDownloadError(ytdlpError) && ytdlpError.code === DownloadErrorCode.DOWNLOAD_PAUSED
+          if (selectedProvider === 'ytdlp' || paused) {
             throw ytdlpError
           }
           progress.status = 'retrying'
@@ -283,6 +311,12 @@ export async function startDownload(url: string, options: DownloadOptions = {}):
       const finalError = isDownloadError(error)
         ? error
         : createDownloadError(String(error), DownloadErrorCode.UNKNOWN_ERROR)
+      if (progress && finalError.code === DownloadErrorCode.DOWNLOAD_PAUSED) {
+        // Not a failure: the provider recorded the partial files on the progress
+        progress.status = 'paused'
+        state.eventEmitter.emit('paused', progress)
+        return
+      }
       if (progress) {
         progress.status = 'failed'
         progress.error = finalError
@@ -315,6 +349,20 @@ export function cancelDownload(downloadId: string): boolean {
   return false
 }
 
+/**
+ * Stop a download but keep its partial files; 'paused' is emitted once they are recorded
+ */
+export function pauseDownload(downloadId: string): boolean {
+  const state = ensureState()
+
+  const controller = state.activeDownloads.get(downloadId)
+  if (controller) {
+    controller.abort(DOWNLOAD_PAUSE_REASON)
+    return true
+  }
+  return false
+}
+
 export function getActiveDownloads(): DownloadProgress[] {
   const state = ensureState()
 
@@ -342,7 +390,7 @@ export function getDownloadsByFilter(filter: DownloadFilter): DownloadProgress[]
       downloads = getActiveDownloads()
       break
     case 'completed':
-      downloads = getDownloadHistory().filter(d => d.status === 'completed')
+      downloads = getDownloadHistory().filter(d => isCompletedStatus(d.status))
       break
     case 'failed':
       downloads = getDownloadHistory().filter(d => d.status === 'failed')
@@ -375,6 +423,15 @@ function generateDownloadId(): string {
   return `clipy_dl_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`
 }
 
+function isHttpUrl(url: string): boolean {
+  try {
+    const { protocol } = new URL(url)
+    return protocol === 'http:' || protocol === 'https:'
+  } catch {
+    return false
+  }
+}
+
 function extractVideoId(url: string): string | null {
   const patterns = [
     /(?:youtube\.com\/watch\?v=|youtu\.be\/|youtube\.com\/embed\/)([^&\n?#]+)/,
@@ -8,32 +8,88 @@
  * - Format selection (avoiding HLS streams that get 403 blocked)
  * - Progress parsing from yt-dlp stdout
  * - Video info extraction via --dump-json
+ * - Supported site lookup via --list-extractors
+ * - Adapting arguments to the installed yt-dlp version (see yt-dlp-capabilities)
  *
  * Based on the Python yt-dlp wrapper patterns.
  */
 
 import { DownloadErrorCode, createDownloadError } from '../../types/download'
-import type { DownloadOptions, DownloadProgress, VideoFormatInfo, VideoInfo } from '../../types/download'
-import { dirname, extname, join } from 'node:path'
-import { existsSync, mkdirSync, readFileSync, writeFileSync } from 'node:fs'
-
+import type {
+  AudioLanguage,
+  CookieChoice,
+  DownloadError,
+  DownloadOptions,
+  DownloadProgress,
+  RelatedVideo,
+  StoryboardInfo,
+  VideoChapter,
+  VideoComment,
+  VideoFormatInfo,
+  VideoInfo,
+  VideoThumbnail,
+} from '../../types/download'
+import type { DownloadConfig } from '../../types/system'
+import { basename, dirname, extname, join } from 'node:path'
+import { existsSync, mkdirSync, readFileSync, rmSync, writeFileSync } from 'node:fs'
+import { randomUUID } from 'node:crypto'
+
+import { ConfigManager } from '../../utils/config'
+import { DownloadLog } from './download-log'
+import { assertYtdlpSupported, getYtdlpCapabilities } from './yt-dlp-capabilities'
+import {
+  buildCookieArgs,
+  getDownloadCookieChoice,
+  getYtdlpBaseArgs,
+  getYtdlpEnv,
+  redactYtdlpArgs,
+} from './yt-dlp-environment'
+import { findPartialFileMismatch, findPartialFiles, removePartialFiles } from './partial-downloads'
+import { getRequestedHeight } from './quality-fallback'
+import { VideoProcessor } from '../video-processor'
+import { createLocalizedDownloadError } from '../../utils/i18n'
+import {
+  DOWNLOADED_FILE_EXTENSIONS,
+  EXTENSION_PLACEHOLDER,
+  fitOutputTemplateToPathLimit,
+  getDownloadPathForVideo,
+  getDownloadPathSettings,
+  getExpectedExtension,
+  getUniqueOutputTemplate,
+  sanitizePathComponent,
+} from '../../utils/paths'
 import { EventEmitter } from 'events'
 import { PlatformUtils } from '../../utils/platform'
 import { Logger } from '../../utils/logger'
 import { get } from 'https'
-import { homedir } from 'os'
-import { spawn } from 'child_process'
+import { type ChildProcess, execFile, spawn } from 'child_process'
 // NOTE: Storage operations are handled by download-manager.ts, not here
 
 const logger = Logger.getInstance()
 
-// Cross-platform binary detection
+/**
+ * Binary path set in Settings > Advanced, if it points at an executable file. Lets users
+ * run e.g. a pip-managed yt-dlp instead of the bundled one.
+ */
+function getConfiguredBinaryPath(setting: 'ffmpegPath' | 'ytDlpPath'): string | null {
+  const configured = ConfigManager.getInstance().get('advanced')?.[setting]?.trim()
+  if (!configured) {
+    return null
+  }
+  if (existsSync(configured) && PlatformUtils.getInstance().isExecutable(configured)) {
+    return configured
+  }
+  logger.warn('Configured binary is not an executable file, using the bundled one', { setting, path: configured })
+  return null
+}
+
+// Cross-platform binary detection; a path from settings wins over the bundled binaries
 function detectFfmpegPath(): string | null {
-  return PlatformUtils.getInstance().resolveExecutable('ffmpeg')
+  return getConfiguredBinaryPath('ffmpegPath') ?? PlatformUtils.getInstance().resolveExecutable('ffmpeg')
 }
 
 function detectYtdlpPath(): string | null {
-  return PlatformUtils.getInstance().resolveExecutable('yt-dlp')
+  return getConfiguredBinaryPath('ytDlpPath') ?? PlatformUtils.getInstance().resolveExecutable('yt-dlp')
 }
 
 // Cookie Manager (matching Python CookieManager class)
@@ -80,8 +136,8 @@ class CookieManager {
 
 // Global instances (matching Python globals)
 const cookieManager = new CookieManager()
-const FFMPEG_PATH = detectFfmpegPath()
-const YTDLP_PATH = detectYtdlpPath()
+let FFMPEG_PATH = detectFfmpegPath()
+let YTDLP_PATH = detectYtdlpPath()
 
 // Enhanced yt-dlp options (matching Python get_enhanced_ydl_opts)
 function getEnhancedYtdlpOptions(baseOpts: Record<string, any> = {}): Record<string, any> {
@@ -98,23 +154,23 @@ function getEnhancedYtdlpOptions(baseOpts: Record<string, any> = {}): Record<str
     simpleOpts.ffmpegLocation = FFMPEG_PATH
   }
 
-  // Add cookies if available
-  if (cookieManager.hasValidCookies()) {
-    simpleOpts.cookiefile = cookieManager.getCookieFilePath()
-  }
-
   return { ...simpleOpts, ...baseOpts }
 }
 
 // Format selection (matching Python get_format_selector and get_audio_format_selector)
 // IMPORTANT: Avoid HLS (m3u8) formats as YouTube blocks them with 403 errors
 // Maps user-selected quality (4K, 1080p, etc.) to yt-dlp format selectors
-function getFormatSelector(quality: string, audioFormatId: string): string | null {
+function getFormatSelector(quality: string, audioFormatId: string, audioLanguage?: string): string | null {
   // Convert format ids to yt-dlp selectors
   // Prefer AAC/MP3 audio over Opus for better compatibility with media players
   // Use [protocol!=m3u8] to avoid HLS streams that get 403 blocked
-  const compatibleAudioSelector =
+  const defaultAudioSelector =
     'bestaudio[ext=m4a][protocol!=m3u8]/bestaudio[acodec=aac][protocol!=m3u8]/bestaudio[ext=mp3][protocol!=m3u8]/bestaudio[protocol!=m3u8]'
+  // Grouped, so a video without the language falls back to its other audio instead of the next video option
+  const languageAudio = `bestaudio[language^=${audioLanguage}]`
+  const compatibleAudioSelector = audioLanguage
+    ? `(${languageAudio}[ext=m4a][protocol!=m3u8]/${languageAudio}[protocol!=m3u8]/${defaultAudioSelector})`
+    : defaultAudioSelector
 
   // Map user-selected quality to yt-dlp format selectors
   // IMPORTANT: Respect user's quality choice for downloads!
@@ -147,12 +203,31 @@ function getFormatSelector(quality: string, audioFormatId: string): string | nul
     case 'best':
     case 'highest':
     case 'auto':
-    default:
       // Best available quality - NO height restriction
       return `bestvideo[protocol!=m3u8][protocol!=m3u8_native]+${compatibleAudioSelector}/best[protocol!=m3u8][protocol!=m3u8_native]`
+    default: {
+      // Any other height, e.g. the closest available quality picked by the quality fallback
+      const height = quality.match(/^(\d{3,4})p$/)?.[1]
+      if (height) {
+        return `bestvideo[height<=${height}][protocol!=m3u8][protocol!=m3u8_native]+${compatibleAudioSelector}/best[height<=${height}][protocol!=m3u8]`
+      }
+      return `bestvideo[protocol!=m3u8][protocol!=m3u8_native]+${compatibleAudioSelector}/best[protocol!=m3u8][protocol!=m3u8_native]`
+    }
   }
 }
 
+/**
+ * Format selector merging one audio track per language with the video for a quality.
+ * Needs --audio-multistreams; falls back to the single-audio selector.
+ */
+function getMultiAudioFormatSelector(quality: string, languages: string[]): string {
+  const height = getRequestedHeight(quality)
+  const video = `bestvideo${height ? `[height<=${height}]` : ''}[protocol!=m3u8][protocol!=m3u8_native]`
+  const audio = languages.map(language => `bestaudio[language=${language}][protocol!=m3u8]`)
+  const fallback = getFormatSelector(quality, 'auto_audio')
+  return fallback ? `${video}+${audio.join('+')}/${fallback}` : `${video}+${audio.join('+')}`
+}
+
 function getAudioFormatSelector(formatId: string): string {
   // Prefer AAC/MP3 audio codecs over Opus for better media player compatibility
   const compatibleAudio =
@@ -178,8 +253,24 @@ function getYtdlpOptsWithTimeRange(
   return baseOpts
 }
 
+/**
+ * Path yt-dlp wrote to a --print-to-file file (UTF-8, one line per print), if that file
+ * exists on disk. The print file is removed.
+ */
+function readPrintedFilepath(printFile: string): string | null {
+  try {
+    const lines = readFileSync(printFile, 'utf-8').split(/\r?\n/).filter(Boolean)
+    const printed = lines[lines.length - 1]
+    return printed && existsSync(printed) ? printed : null
+  } catch {
+    return null
+  } finally {
+    rmSync(printFile, { force: true })
+  }
+}
+
 // Download and save thumbnail image
-async function downloadThumbnail(thumbnailUrl: string, outputPath: string): Promise<string | null> {
+export async function downloadThumbnail(thumbnailUrl: string, outputPath: string): Promise<string | null> {
   return new Promise(resolve => {
     // Ensure output directory exists
     const outputDir = dirname(outputPath)
@@ -239,46 +330,84 @@ export async function downloadWithYtdlp(
   eventEmitter: EventEmitter,
   controller: AbortController,
 ): Promise<void> {
+  // Kept for the whole download even if the binary paths are refreshed meanwhile
+  const ytdlpPath = YTDLP_PATH
   logger.info('Starting download', {
     videoId,
     downloadId: progress.downloadId,
     hasCookies: cookieManager.hasValidCookies(),
     hasFFmpeg: FFMPEG_PATH !== null,
-    hasYtdlp: YTDLP_PATH !== null,
+    hasYtdlp: ytdlpPath !== null,
   })
 
-  if (!YTDLP_PATH) {
-    throw createDownloadError('yt-dlp not found', DownloadErrorCode.UNKNOWN_ERROR)
+  if (!ytdlpPath) {
+    throw createLocalizedDownloadError('errorBinaryNotFound', { binary: 'yt-dlp' }, DownloadErrorCode.UNKNOWN_ERROR)
   }
 
   return new Promise<void>((resolve, reject) => {
+    let activeProcess: ChildProcess | null = null
+    // Fragments and intermediate files of this download, when download.useTempDirForDownloads is on
+    let tempDir: string | null = null
+    const removeTempDir = () => {
+      if (!tempDir) return
+      try {
+        rmSync(tempDir, { recursive: true, force: true })
+      } catch (error) {
+        logger.warn('Failed to remove download temp folder', { tempDir, error: (error as Error).message })
+      }
+    }
+
     const cleanupAndReject = (error: unknown) => {
       reject(error)
     }
 
     controller.signal.addEventListener('abort', () => {
-      logger.info('Download aborted', { downloadId: progress.downloadId })
-      cleanupAndReject(createDownloadError('Download cancelled by user', DownloadErrorCode.DOWNLOAD_CANCELLED))
+      const pausing = controller.signal.reason === DOWNLOAD_PAUSE_REASON
+      logger.info(pausing ? 'Download paused' : 'Download aborted', { downloadId: progress.downloadId })
+      if (activeProcess && activeProcess.exitCode === null) {
+        // The temp folder is removed (or, when pausing, its partial files recorded) once the
+        // process has exited and released its files
+        activeProcess.kill('SIGTERM')
+        if (pausing) return
+      } else if (!pausing) {
+        removeTempDir()
+      }
+      cleanupAndReject(
+        pausing
+          ? createLocalizedDownloadError('errorDownloadPaused', undefined, DownloadErrorCode.DOWNLOAD_PAUSED)
+          : createLocalizedDownloadError('errorDownloadCancelled', undefined, DownloadErrorCode.DOWNLOAD_CANCELLED),
+      )
     })
     ;(async () => {
       try {
-        // Generate output filename (matching Python logic)
-        const title = sanitizeFilename(`video_${videoId}`) // Simplified - would get real title
-        const quality = options.quality || '720p'
-        const timestamp = Date.now() % 100000
-
-        let outputTemplate = join(
-          options.outputPath || join(homedir(), 'Downloads', 'Clipy'),
-          `${title}_${quality}_${timestamp}.%(ext)s`,
-        )
+        // Build the output template from download settings (channel subfolder, date prefix, filename template)
+        const downloadSettings = ConfigManager.getInstance().get('download')
+        let outputTemplate = getDownloadPathForVideo(getDownloadPathSettings(options, downloadSettings), videoInfo)
 
         if (options.startTime || options.endTime) {
           const startStr = options.startTime ? formatTimeForFilename(options.startTime!) : '00m00s'
           const endStr = options.endTime ? formatTimeForFilename(options.endTime!) : 'end'
-          outputTemplate = join(
-            options.outputPath || join(homedir(), 'Downloads', 'Clipy'),
-            `${title}_${quality}_trimmed_${startStr}-${endStr}_${timestamp}.%(ext)s`,
-          )
+          const base = outputTemplate.slice(0, -EXTENSION_PLACEHOLDER.length)
+          outputTemplate = `${base}_trimmed_${startStr}-${endStr}${EXTENSION_PLACEHOLDER}`
+        }
+
+        // Downloading a video again keeps the earlier file and numbers the new one
+        outputTemplate = getUniqueOutputTemplate(outputTemplate, [
+          getExpectedExtension(options, downloadSettings.finalContainer),
+          ...DOWNLOADED_FILE_EXTENSIONS,
+        ])
+
+        // Long titles can push the path past MAX_PATH on Windows; shorten before yt-dlp fails on it
+        const fitted = fitOutputTemplateToPathLimit(outputTemplate)
+        if (fitted.truncated) {
+          logger.info('Output filename truncated to fit path length limit', { original: outputTemplate })
+          outputTemplate = fitted.template
+        }
+
+        // A resumed download runs with the template it was paused with, so yt-dlp finds its partial files
+        const resumeFrom = options.resumeFrom?.outputTemplate ? options.resumeFrom : undefined
+        if (resumeFrom) {
+          outputTemplate = resumeFrom.outputTemplate
         }
 
         // Build yt-dlp options
@@ -295,7 +424,17 @@ export async function downloadWithYtdlp(
         // options.quality comes from the UI (4K, 1080p, 720p, etc.)
         const userQuality = options.quality || 'best' // Default to best if not specified
         logger.debug('Using user-selected quality for download', { quality: userQuality })
-        const formatSelector = getFormatSelector(userQuality, 'auto_audio')
+        // Every audio language as its own track only works in MKV
+        const audioLanguages = (videoInfo.audioLanguages ?? []).map(language => language.code)
+        const allAudioTracks = options.downloadAllAudioTracks === true && audioLanguages.length > 1
+        if (allAudioTracks) {
+          baseOpts.mergeOutputFormat = 'mkv'
+        }
+        const formatSelector =
+          resumeFrom?.formatSelector ??
+          (allAudioTracks
+            ? getMultiAudioFormatSelector(userQuality, audioLanguages)
+            : getFormatSelector(userQuality, 'auto_audio', options.audioLanguage))
         if (formatSelector) {
           baseOpts.format = formatSelector
         }
@@ -312,6 +451,9 @@ export async function downloadWithYtdlp(
           }
         }
 
+        const capabilities = await getYtdlpCapabilities(ytdlpPath)
+        assertYtdlpSupported(capabilities)
+
         const opts = getYtdlpOptsWithTimeRange(baseOpts, baseOpts.timeRange)
         const finalOpts = getEnhancedYtdlpOptions(opts)
 
@@ -319,37 +461,97 @@ export async function downloadWithYtdlp(
 
         logger.debug('Starting yt-dlp process')
 
+        // Only continue from partial files that are exactly as they were when the download was paused
+        let continuePartial = false
+        if (resumeFrom) {
+          const mismatch = findPartialFileMismatch(resumeFrom)
+          if (mismatch) {
+            logger.info('Partial files changed since the download was paused, starting over', { mismatch })
+            removePartialFiles(resumeFrom)
+            progress.resumeRestarted = true
+            eventEmitter.emit('progress', progress)
+          } else {
+            continuePartial = true
+          }
+        }
+
         // Convert options to command line args (matching Python subprocess call)
-        const args: string[] = []
+        const args: string[] = getYtdlpBaseArgs()
 
         // Don't use --quiet so we can parse progress output
         // if (finalOpts.quiet) args.push('--quiet')
         args.push('--progress') // Ensure progress output is shown
         args.push('--newline') // Output progress in newlines for parsing
         if (finalOpts.noWarnings) args.push('--no-warnings')
-        if (finalOpts.outtmpl) args.push('-o', finalOpts.outtmpl)
+        if (finalOpts.outtmpl && downloadSettings.useTempDirForDownloads !== false) {
+          // yt-dlp ignores --paths for an absolute -o, so split the template into home path and name
+          const downloadsTempDir = join(PlatformUtils.getInstance().getTempDir('clipy'), 'downloads')
+          tempDir = resumeFrom?.tempDir ?? join(downloadsTempDir, progress.downloadId)
+          mkdirSync(tempDir, { recursive: true })
+          args.push('-P', `home:${dirname(finalOpts.outtmpl).replace(/%%/g, '%')}`, '-P', `temp:${tempDir}`)
+          args.push('-o', basename(finalOpts.outtmpl))
+        } else if (finalOpts.outtmpl) {
+          args.push('-o', finalOpts.outtmpl)
+        }
         if (finalOpts.format) args.push('-f', finalOpts.format)
+        if (allAudioTracks) args.push('--audio-multistreams')
+        if (resumeFrom) args.push(continuePartial ? '--continue' : '--no-continue')
         if (finalOpts.mergeOutputFormat) args.push('--merge-output-format', finalOpts.mergeOutputFormat)
-        if (finalOpts.cookiefile) args.push('--cookies', finalOpts.cookiefile)
+        args.push(...getCookieArgs(getDownloadCookieChoice(options)))
         if (finalOpts.ffmpegLocation) args.push('--ffmpeg-location', finalOpts.ffmpegLocation)
-        if (finalOpts.downloadSections) args.push('--download-sections', finalOpts.downloadSections)
+        if (finalOpts.downloadSections && capabilities.flags.has('--download-sections')) {
+          args.push('--download-sections', finalOpts.downloadSections)
+        } else if (finalOpts.timeRange && FFMPEG_PATH) {
+          // Builds before --download-sections: let FFmpeg fetch only the range
+          const { start, end } = finalOpts.timeRange
+          args.push('--external-downloader', 'ffmpeg')
+          args.push('--external-downloader-args', `ffmpeg_i:-ss ${start} -to ${end}`)
+        }
         // Force AAC audio codec for maximum compatibility
         if (finalOpts.audioCodec) args.push('--audio-format', finalOpts.audioCodec)
         if (finalOpts.audioQuality) args.push('--audio-quality', finalOpts.audioQuality)
+        args.push(...getSponsorBlockArgs(options, downloadSettings))
+
+        // Have yt-dlp report the final path in a file of its own, rather than guessing the
+        // extension afterwards or picking it out of the progress output
+        let printFile: string | null = null
+        if (capabilities.flags.has('--print-to-file')) {
+          const printDir = PlatformUtils.getInstance().getTempDir('clipy')
+          mkdirSync(printDir, { recursive: true })
+          printFile = join(printDir, `${progress.downloadId}.filepath`)
+          rmSync(printFile, { force: true }) // yt-dlp appends to it
+          args.push('--print-to-file', 'after_move:filepath', printFile)
+        }
 
         args.push(`https://www.youtube.com/watch?v=${videoId}`)
 
-        logger.debug('Running yt-dlp', { command: `${YTDLP_PATH} ${args.join(' ')}` })
+        logger.debug('Running yt-dlp', { command: `${ytdlpPath} ${redactYtdlpArgs(args).join(' ')}` })
 
         // Spawn yt-dlp process (matching Python subprocess)
-        const ytdlpProcess = spawn(YTDLP_PATH, args, {
+        const ytdlpProcess = spawn(ytdlpPath, args, {
           stdio: ['pipe', 'pipe', 'pipe'],
           cwd: process.cwd(),
+          env: getYtdlpEnv(),
         })
+        // Decode as a stream, so a character split across two chunks isn't garbled
+        ytdlpProcess.stdout?.setEncoding('utf8')
+        ytdlpProcess.stderr?.setEncoding('utf8')
+        activeProcess = ytdlpProcess
+        if (controller.signal.aborted) {
+          ytdlpProcess.kill('SIGTERM') // Cancelled while the options were being prepared
+        }
 
         let stderr = ''
         let lastActivityTime = Date.now()
 
+        // Capture the full yt-dlp output for this download (viewable from the UI)
+        const downloadLog = new DownloadLog(progress.downloadId)
+        progress.logPath = downloadLog.path
+        downloadLog.writeLine(`${ytdlpPath} ${redactYtdlpArgs(args).join(' ')}`)
+        const attachStderrTail = () => {
+          progress.stderrTail = downloadLog.getStderrTail()
+        }
+
         // Track highest progress seen to prevent regression (yt-dlp can output lower values during network fluctuations)
         let highestProgress = 0
         let lastValidSpeed = '0 B/s'
@@ -359,6 +561,7 @@ export async function downloadWithYtdlp(
         ytdlpProcess.stdout?.on('data', data => {
           lastActivityTime = Date.now() // Reset activity timer
           const output = data.toString()
+          downloadLog.append('stdout', output)
 
           // Log all output for debugging
           logger.debug('yt-dlp output', { stdout: output.trim() })
@@ -445,6 +648,7 @@ export async function downloadWithYtdlp(
           lastActivityTime = Date.now() // Reset activity timer
           const errOutput = data.toString()
           stderr += errOutput
+          downloadLog.append('stderr', errOutput)
           // Log stderr for debugging (yt-dlp often outputs info to stderr)
           logger.debug('yt-dlp stderr', { stderr: errOutput.trim() })
         })
@@ -457,7 +661,9 @@ export async function downloadWithYtdlp(
         const timeout = setTimeout(() => {
           logger.warn('Download timeout reached, killing process')
           progress.status = 'failed'
-          progress.error = createDownloadError('Download timeout reached', DownloadErrorCode.TIMEOUT)
+          progress.error = createLocalizedDownloadError('errorDownloadTimeout', undefined, DownloadErrorCode.TIMEOUT)
+          downloadLog.writeLine('Download timeout reached, killing process')
+          attachStderrTail()
           // NOTE: Storage is handled by download-manager.ts
           eventEmitter.emit('failed', progress)
           ytdlpProcess.kill('SIGTERM')
@@ -470,7 +676,9 @@ export async function downloadWithYtdlp(
             logger.warn('Download stalled, killing process', { inactiveSeconds: Math.round(timeSinceActivity / 1000) })
             clearInterval(stallCheck)
             progress.status = 'failed'
-            progress.error = createDownloadError('Download stalled - no activity', DownloadErrorCode.TIMEOUT)
+            progress.error = createLocalizedDownloadError('errorDownloadStalled', undefined, DownloadErrorCode.TIMEOUT)
+            downloadLog.writeLine('Download stalled, killing process')
+            attachStderrTail()
             // NOTE: Storage is handled by download-manager.ts
             eventEmitter.emit('failed', progress)
             ytdlpProcess.kill('SIGTERM')
@@ -480,39 +688,84 @@ export async function downloadWithYtdlp(
         ytdlpProcess.on('close', async code => {
           clearTimeout(timeout)
           clearInterval(stallCheck)
-          if (code === 0) {
-            logger.info('yt-dlp completed successfully')
-
-            // Find the downloaded file (matching Python's robust file detection)
-            const baseName = outputTemplate.replace('.%(ext)s', '')
-            const extensions = ['mp4', 'm4a', 'webm', 'mkv', 'mov', 'avi']
-            let actualFile: string | null = null
-
-            logger.debug('Looking for downloaded file', { baseName })
-
-            for (const ext of extensions) {
-              const testFile = `${baseName}.${ext}`
-              logger.debug('Checking for file', { path: testFile })
-              if (existsSync(testFile)) {
-                actualFile = testFile
-                logger.debug('Found downloaded file', { path: actualFile })
-                break
-              }
+          const pausing = controller.signal.reason === DOWNLOAD_PAUSE_REASON
+          if (!pausing) {
+            removeTempDir()
+          }
+          const printedPath = printFile ? readPrintedFilepath(printFile) : null
+          downloadLog.writeLine(`yt-dlp exited with code ${code}`)
+          if (pausing) {
+            // Record where the partial files are, so the download can continue after a restart
+            const partialFiles = findPartialFiles(outputTemplate, tempDir ?? undefined)
+            const downloadedBytes = partialFiles.reduce((total, file) => total + file.size, 0)
+            progress.pausedState = {
+              options: { ...options, resumeFrom: undefined },
+              outputTemplate,
+              formatSelector: baseOpts.format,
+              tempDir: tempDir ?? undefined,
+              partialFiles,
+              downloadedBytes,
+              pausedAt: Date.now(),
+            }
+            progress.downloadedBytes = downloadedBytes
+            progress.status = 'paused'
+            downloadLog.writeLine(`Paused with ${partialFiles.length} partial file(s), ${downloadedBytes} bytes`)
+            reject(createLocalizedDownloadError('errorDownloadPaused', undefined, DownloadErrorCode.DOWNLOAD_PAUSED))
+            return
+          }
+          if (controller.signal.aborted) {
+            return // Already rejected as cancelled
+          }
+
+          // Find the downloaded file: the path yt-dlp printed, else probe the likely extensions
+          const baseName = outputTemplate.slice(0, -EXTENSION_PLACEHOLDER.length).replace(/%%/g, '%')
+          let actualFile: string | null = printedPath && existsSync(printedPath) ? printedPath : null
+
+          logger.debug('Looking for downloaded file', { baseName, printedPath })
+
+          for (const ext of actualFile ? [] : DOWNLOADED_FILE_EXTENSIONS) {
+            const testFile = `${baseName}.${ext}`
+            logger.debug('Checking for file', { path: testFile })
+            if (existsSync(testFile)) {
+              actualFile = testFile
+              logger.debug('Found downloaded file', { path: actualFile })
+              break
+            }
+          }
+
+          // A non-zero exit can still leave a complete file, e.g. when only the subtitles failed.
+          // The file counts when FFmpeg can read it; the errors are kept on the download.
+          const partialErrors = code !== 0 && actualFile && (await isReadableMedia(actualFile)) ? stderr : null
+
+          if (code === 0 || partialErrors !== null) {
+            if (code === 0) {
+              logger.info('yt-dlp completed successfully')
             }
 
             if (!actualFile) {
-              logger.warn('Downloaded file not found', { baseName, searchedExtensions: extensions })
-              throw createDownloadError('Downloaded file not found', DownloadErrorCode.UNKNOWN_ERROR)
+              logger.warn('Downloaded file not found', { baseName, searchedExtensions: DOWNLOADED_FILE_EXTENSIONS })
+              throw createLocalizedDownloadError(
+                'errorDownloadedFileNotFound',
+                undefined,
+                DownloadErrorCode.UNKNOWN_ERROR,
+              )
             }
 
             progress.filePath = actualFile
+            progress.videoId = videoInfo.id || videoId
+            progress.channel = videoInfo.channel.name
+            progress.channelId = videoInfo.channel.id || undefined
+            progress.duration = videoInfo.duration
+            progress.format = extname(actualFile).slice(1).toLowerCase()
+            progress.thumbnailUrl = videoInfo.thumbnails[videoInfo.thumbnails.length - 1]?.url
+            progress.completedAt = Date.now()
 
             // Download thumbnail if requested
             if (options.downloadThumbnail && videoInfo.thumbnails.length > 0) {
               logger.debug('Downloading thumbnail')
               const thumbnailUrl = videoInfo.thumbnails[videoInfo.thumbnails.length - 1]?.url // Use highest quality thumbnail
               if (thumbnailUrl) {
-                const thumbnailFilename = `${sanitizeFilename(videoInfo.title)}_thumbnail.jpg`
+                const thumbnailFilename = `${sanitizePathComponent(videoInfo.title, videoInfo.id)}_thumbnail.jpg`
                 const thumbnailPath = join(dirname(actualFile), thumbnailFilename)
                 const savedThumbnailPath = await downloadThumbnail(thumbnailUrl, thumbnailPath)
                 if (savedThumbnailPath) {
@@ -523,6 +776,18 @@ export async function downloadWithYtdlp(
 
             progress.status = 'completed'
             progress.progress = 100
+            if (partialErrors !== null) {
+              const errors = getYtdlpErrors(partialErrors, code)
+              logger.warn('yt-dlp failed, but the downloaded file is complete', { code, errors })
+              downloadLog.writeLine(`Kept the downloaded file despite ${errors.length} error(s)`)
+              progress.status = 'completed-with-errors'
+              progress.partialErrors = errors
+              progress.error = createLocalizedDownloadError(
+                'errorDownloadPartiallyFailed',
+                { count: errors.length, reason: errors[0] },
+                DownloadErrorCode.PARTIAL_FAILURE,
+              )
+            }
 
             // NOTE: Storage is handled by download-manager.ts
             // Emit events so download-manager can save with correct job.id
@@ -534,14 +799,36 @@ export async function downloadWithYtdlp(
             resolve()
           } else {
             logger.error('yt-dlp failed', new Error(`Exit code ${code}: ${stderr}`))
-            reject(createDownloadError(`yt-dlp failed: ${stderr}`, DownloadErrorCode.UNKNOWN_ERROR))
+            const nameTooLong = /WinError 206|File name too long|ENAMETOOLONG/i.test(stderr)
+            attachStderrTail()
+            if (isUnsupportedUrlError(stderr)) {
+              reject(
+                createLocalizedDownloadError('errorSiteNotSupported', undefined, DownloadErrorCode.UNSUPPORTED_SITE),
+              )
+              return
+            }
+            const restrictedError = createRestrictedVideoError(stderr)
+            if (restrictedError) {
+              reject(restrictedError)
+              return
+            }
+            reject(
+              createLocalizedDownloadError(
+                'errorDownloadFailed',
+                { reason: stderr.trim() },
+                nameTooLong ? DownloadErrorCode.FILENAME_TOO_LONG : DownloadErrorCode.UNKNOWN_ERROR,
+              ),
+            )
           }
         })
 
         ytdlpProcess.on('error', error => {
           clearTimeout(timeout)
           clearInterval(stallCheck)
+          removeTempDir()
           logger.error('yt-dlp process error', error)
+          downloadLog.writeLine(`Process error: ${error.message}`)
+          attachStderrTail()
           progress.status = 'failed'
           const downloadError = createDownloadError(`Process error: ${error.message}`, DownloadErrorCode.UNKNOWN_ERROR)
           progress.error = downloadError
@@ -558,34 +845,123 @@ export async function downloadWithYtdlp(
   })
 }
 
+/**
+ * The ERROR: lines of yt-dlp's stderr, or the exit code when it printed none
+ */
+function getYtdlpErrors(stderr: string, code: number | null): string[] {
+  const errors = stderr
+    .split(/\r?\n/)
+    .map(line => line.match(/^ERROR:\s*(.+)$/)?.[1]?.trim())
+    .filter((message): message is string => !!message)
+  return errors.length > 0 ? errors : [`yt-dlp exited with code ${code}`]
+}
+
+/**
+ * Whether FFmpeg can read a file as media with a duration, i.e. it wasn't left half-written
+ */
+async function isReadableMedia(filePath: string): Promise<boolean> {
+  try {
+    const metadata = await VideoProcessor.getInstance().getVideoMetadata(filePath)
+    return metadata.duration > 0
+  } catch {
+    return false
+  }
+}
+
 // Legacy exports for compatibility (keeping youtubei.js interface)
 export async function initializeYtdlp(): Promise<void> {
   // No-op - we're using yt-dlp now
   logger.info('yt-dlp provider initialized')
 }
 
-export async function getVideoInfoFromYtdlp(videoId: string): Promise<VideoInfo> {
+const THUMBNAIL_EXTENSIONS = ['.jpg', '.png', '.webp']
+
+/** Abort reason that pauses a download: its partial files are kept and recorded instead of removed */
+export const DOWNLOAD_PAUSE_REASON = 'pause'
+
+/**
+ * SponsorBlock arguments for a download; the download's options take precedence over the
+ * settings. A mode whose category list is empty adds nothing.
+ */
+function getSponsorBlockArgs(options: DownloadOptions, settings: Partial<DownloadConfig> | undefined): string[] {
+  const mode = options.sponsorBlockMode ?? settings?.sponsorBlockMode ?? 'off'
+  const removeCategories = options.sponsorBlockRemoveCategories ?? settings?.sponsorBlockRemoveCategories ?? []
+  const markCategories = options.sponsorBlockMarkCategories ?? settings?.sponsorBlockMarkCategories ?? []
+
+  const args: string[] = []
+  if ((mode === 'mark' || mode === 'mark-and-remove') && markCategories.length > 0) {
+    args.push('--sponsorblock-mark', markCategories.join(','))
+  }
+  if ((mode === 'remove' || mode === 'mark-and-remove') && removeCategories.length > 0) {
+    args.push('--sponsorblock-remove', removeCategories.join(','))
+  }
+  return args
+}
+
+/**
+ * Cookie arguments for a download or lookup, falling back to the app cookie file
+ */
+function getCookieArgs(choice?: CookieChoice): string[] {
+  return buildCookieArgs(choice, cookieManager.hasValidCookies() ? cookieManager.getCookieFilePath() : null)
+}
+
+/**
+ * Error code for a video yt-dlp couldn't get without signing in, or null for any other failure
+ */
+function getRestrictedVideoErrorCode(stderr: string): DownloadErrorCode | null {
+  if (/Private video/i.test(stderr)) {
+    return DownloadErrorCode.VIDEO_PRIVATE
+  }
+  if (/confirm your age|age-restricted|inappropriate for some users/i.test(stderr)) {
+    return DownloadErrorCode.AGE_RESTRICTED
+  }
+  if (/members-only|channel's members|Sign in to confirm|--cookies/i.test(stderr)) {
+    return DownloadErrorCode.LOGIN_REQUIRED
+  }
+  return null
+}
+
+const RESTRICTED_VIDEO_MESSAGES = {
+  [DownloadErrorCode.VIDEO_PRIVATE]: 'errorVideoPrivate',
+  [DownloadErrorCode.AGE_RESTRICTED]: 'errorVideoAgeRestricted',
+  [DownloadErrorCode.LOGIN_REQUIRED]: 'errorVideoLoginRequired',
+} as const
+
+/**
+ * The error for a failed yt-dlp run when the video needs signing in, so the UI can offer a
+ * retry with cookies; null otherwise
+ */
+function createRestrictedVideoError(stderr: string): DownloadError | null {
+  const code = getRestrictedVideoErrorCode(stderr)
+  if (!code) {
+    return null
+  }
+  const key = RESTRICTED_VIDEO_MESSAGES[code as keyof typeof RESTRICTED_VIDEO_MESSAGES]
+  return createLocalizedDownloadError(key, undefined, code, new Error(stderr.trim()))
+}
+
+export async function getVideoInfoFromYtdlp(videoId: string, useCookies?: CookieChoice): Promise<VideoInfo> {
   if (!YTDLP_PATH) {
-    throw createDownloadError('yt-dlp not found', DownloadErrorCode.UNKNOWN_ERROR)
+    throw createLocalizedDownloadError('errorBinaryNotFound', { binary: 'yt-dlp' }, DownloadErrorCode.UNKNOWN_ERROR)
   }
+  assertYtdlpSupported(await getYtdlpCapabilities(YTDLP_PATH))
 
   try {
     // Use yt-dlp to extract video info (similar to Python extract_video_info_with_fallback)
     // NOTE: Don't use --quiet as it may suppress format URLs in some yt-dlp versions
     // Use --no-warnings only to keep stderr clean while preserving full JSON output
-    const args = ['--no-warnings', '--dump-json', `https://www.youtube.com/watch?v=${videoId}`]
-
-    // Add cookies if available
-    if (cookieManager.hasValidCookies()) {
-      args.splice(2, 0, '--cookies', cookieManager.getCookieFilePath())
-    }
+    const args = [...getYtdlpBaseArgs(), '--no-warnings', '--dump-json', ...getCookieArgs(useCookies)]
+    args.push(`https://www.youtube.com/watch?v=${videoId}`)
 
-    logger.debug('Running yt-dlp info extraction', { command: args.join(' ') })
+    logger.debug('Running yt-dlp info extraction', { command: redactYtdlpArgs(args).join(' ') })
 
     const ytProcess = spawn(YTDLP_PATH, args, {
       stdio: ['pipe', 'pipe', 'pipe'],
       cwd: process.cwd(),
+      env: getYtdlpEnv(),
     })
+    ytProcess.stdout?.setEncoding('utf8')
+    ytProcess.stderr?.setEncoding('utf8')
 
     let stdout = ''
     let stderr = ''
@@ -603,6 +979,7 @@ export async function getVideoInfoFromYtdlp(videoId: string): Promise<VideoInfo>
         if (code === 0 && stdout) {
           try {
             const info = JSON.parse(stdout.trim())
+            const formats = extractFormats(info.formats || [])
 
             // Convert yt-dlp info to our VideoInfo format
             const videoInfo: VideoInfo = {
@@ -618,11 +995,7 @@ export async function getVideoInfoFromYtdlp(videoId: string): Promise<VideoInfo>
                 verified: info.channel_is_verified || false,
                 subscriberCount: info.channel_follower_count || 0,
               },
-              thumbnails: (info.thumbnails || []).map((t: any) => ({
-                url: t.url,
-                width: t.width,
-                height: t.height,
-              })),
+              thumbnails: extractThumbnails(info.thumbnails || []),
               views: info.view_count || 0,
               viewsFormatted: formatViewCount(info.view_count || 0),
               uploadDate: info.upload_date || '',
@@ -630,8 +1003,12 @@ export async function getVideoInfoFromYtdlp(videoId: string): Promise<VideoInfo>
               isLive: info.is_live || false,
               isPrivate: info.availability === 'private',
               ageRestricted: info.age_limit && info.age_limit >= 18,
-              formats: extractFormats(info.formats || []),
+              formats,
+              audioLanguages: extractAudioLanguages(formats),
               availableQualities: extractAvailableQualities(info.formats || []),
+              storyboards: extractStoryboards(info.formats || []),
+              relatedVideos: info.related_videos ? extractRelatedVideos(info.related_videos) : undefined,
+              chapters: info.chapters?.length ? extractChapters(info.chapters) : undefined,
             }
 
             logger.info('Extracted video info', { title: info.title })
@@ -645,7 +1022,14 @@ export async function getVideoInfoFromYtdlp(videoId: string): Promise<VideoInfo>
           }
         } else {
           logger.error('yt-dlp info extraction failed', new Error(`Exit code ${code}: ${stderr}`))
-          reject(createDownloadError(`Failed to get video info: ${stderr}`, DownloadErrorCode.NO_FORMAT_AVAILABLE))
+          if (isUnsupportedUrlError(stderr)) {
+            reject(createLocalizedDownloadError('errorSiteNotSupported', undefined, DownloadErrorCode.UNSUPPORTED_SITE))
+            return
+          }
+          reject(
+            createRestrictedVideoError(stderr) ??
+              createDownloadError(`Failed to get video info: ${stderr}`, DownloadErrorCode.NO_FORMAT_AVAILABLE),
+          )
         }
       })
 
@@ -660,6 +1044,263 @@ export async function getVideoInfoFromYtdlp(videoId: string): Promise<VideoInfo>
   }
 }
 
+/**
+ * yt-dlp reports URLs no extractor (not even the generic one) can handle as 'Unsupported URL'
+ */
+function isUnsupportedUrlError(stderr: string): boolean {
+  return /ERROR: Unsupported URL/i.test(stderr)
+}
+
+// The extractor list only changes with the yt-dlp binary, so cache it per binary path
+let extractorListCache: { ytdlpPath: string; extractors: Promise<string[]> } | null = null
+
+/**
+ * Names of all yt-dlp extractors (supported sites), one per line of --list-extractors
+ */
+export function getSupportedExtractors(): Promise<string[]> {
+  if (!YTDLP_PATH) {
+    return Promise.reject(
+      createLocalizedDownloadError('errorBinaryNotFound', { binary: 'yt-dlp' }, DownloadErrorCode.UNKNOWN_ERROR),
+    )
+  }
+  if (extractorListCache?.ytdlpPath === YTDLP_PATH) {
+    return extractorListCache.extractors
+  }
+
+  const extractors = new Promise<string[]>((resolve, reject) => {
+    execFile(
+      YTDLP_PATH!,
+      [...getYtdlpBaseArgs(), '--list-extractors'],
+      { maxBuffer: 10 * 1024 * 1024, windowsHide: true, env: getYtdlpEnv() },
+      (error, stdout) => {
+        if (error) {
+          extractorListCache = null // Don't cache a failure
+          reject(createDownloadError(`Failed to list extractors: ${error.message}`, DownloadErrorCode.UNKNOWN_ERROR))
+          return
+        }
+        resolve(
+          stdout
+            .split(/\r?\n/)
+            .map(line => line.trim())
+            .filter(Boolean),
+        )
+      },
+    )
+  })
+  extractorListCache = { ytdlpPath: YTDLP_PATH, extractors }
+  return extractors
+}
+
+/**
+ * Ask yt-dlp whether a URL can be handled, without downloading anything.
+ * Needs network access, since extractors may have to resolve the page.
+ */
+export function checkUrlSupported(url: string): Promise<boolean> {
+  if (!YTDLP_PATH) {
+    return Promise.reject(
+      createLocalizedDownloadError('errorBinaryNotFound', { binary: 'yt-dlp' }, DownloadErrorCode.UNKNOWN_ERROR),
+    )
+  }
+
+  const args = [...getYtdlpBaseArgs(), '--simulate', '--no-playlist', '--no-warnings', '--print', 'extractor_key', url]
+  const execOptions = { timeout: 30000, windowsHide: true, env: getYtdlpEnv() }
+  return new Promise((resolve, reject) => {
+    execFile(YTDLP_PATH!, args, execOptions, (error, _stdout, stderr) => {
+      if (!error) {
+        resolve(true)
+      } else if (isUnsupportedUrlError(stderr)) {
+        resolve(false)
+      } else {
+        reject(createDownloadError(`Failed to check URL: ${stderr || error.message}`, DownloadErrorCode.UNKNOWN_ERROR))
+      }
+    })
+  })
+}
+
+/**
+ * Avatar URL of a YouTube channel, read from the channel page without listing its videos.
+ * Resolves null if the page has no avatar.
+ */
+export function getChannelAvatarUrl(channelId: string): Promise<string | null> {
+  if (!YTDLP_PATH) {
+    return Promise.reject(
+      createLocalizedDownloadError('errorBinaryNotFound', { binary: 'yt-dlp' }, DownloadErrorCode.UNKNOWN_ERROR),
+    )
+  }
+
+  const url = `https://www.youtube.com/channel/${encodeURIComponent(channelId)}`
+  const args = [
+    ...getYtdlpBaseArgs(),
+    '--no-warnings',
+    '--flat-playlist',
+    '--playlist-items',
+    '0',
+    '--dump-single-json',
+    url,
+  ]
+  const execOptions = { timeout: 30000, maxBuffer: 10 * 1024 * 1024, windowsHide: true, env: getYtdlpEnv() }
+  return new Promise((resolve, reject) => {
+    execFile(YTDLP_PATH!, args, execOptions, (error, stdout, stderr) => {
+      if (error) {
+        const message = `Failed to get channel info: ${stderr || error.message}`
+        reject(createDownloadError(message, DownloadErrorCode.UNKNOWN_ERROR))
+        return
+      }
+      try {
+        const thumbnails: { id?: string; url?: string; width?: number; height?: number }[] =
+          JSON.parse(stdout).thumbnails ?? []
+        // The uncropped avatar when listed, else the largest square image (banners are wide)
+        const avatar =
+          thumbnails.find(thumbnail => thumbnail.id === 'avatar_uncropped') ??
+          thumbnails
+            .filter(thumbnail => thumbnail.width && thumbnail.width === thumbnail.height)
+            .sort((a, b) => (b.width ?? 0) - (a.width ?? 0))[0]
+        resolve(avatar?.url ?? null)
+      } catch (parseError) {
+        reject(parseError)
+      }
+    })
+  })
+}
+
+/**
+ * Nest yt-dlp's flat comment list (each with a `parent` of 'root' or a comment ID) into
+ * threads, keeping yt-dlp's order
+ */
+function buildCommentThreads(comments: any[]): VideoComment[] {
+  const byId = new Map<string, VideoComment>()
+  const threads: VideoComment[] = []
+  for (const comment of comments) {
+    const timestamp = comment.timestamp ? new Date(comment.timestamp * 1000).toISOString() : comment._time_text
+    const entry: VideoComment = {
+      id: String(comment.id),
+      author: comment.author || 'Unknown',
+      authorThumbnail: comment.author_thumbnail || undefined,
+      text: comment.text || '',
+      timestamp: timestamp || '',
+      likes: comment.like_count || 0,
+      isPinned: comment.is_pinned === true,
+      isUploader: comment.author_is_uploader === true,
+      replies: [],
+    }
+    byId.set(entry.id, entry)
+    const parent = comment.parent && comment.parent !== 'root' ? byId.get(String(comment.parent)) : undefined
+    if (parent) {
+      parent.replies.push(entry)
+    } else {
+      threads.push(entry)
+    }
+  }
+  return threads
+}
+
+/**
+ * Comments of a video (up to maxComments, replies included), fetched without downloading
+ * it. yt-dlp only writes comments into the info JSON, so that is written to a temp folder
+ * and read back.
+ */
+export async function getVideoComments(url: string, maxComments: number): Promise<VideoComment[]> {
+  if (!YTDLP_PATH) {
+    throw createLocalizedDownloadError('errorBinaryNotFound', { binary: 'yt-dlp' }, DownloadErrorCode.UNKNOWN_ERROR)
+  }
+  assertYtdlpSupported(await getYtdlpCapabilities(YTDLP_PATH))
+
+  const tempDir = join(PlatformUtils.getInstance().getTempDir('clipy'), 'comments', randomUUID())
+  mkdirSync(tempDir, { recursive: true })
+  const args = [
+    ...getYtdlpBaseArgs(),
+    '--skip-download',
+    '--write-info-json',
+    '--write-comments',
+    '--no-playlist',
+    '--no-warnings',
+    // Stops YouTube's comment paging early; other sites return what they have and are cut below
+    '--extractor-args',
+    `youtube:max_comments=${maxComments}`,
+    ...getCookieArgs(),
+    '-o',
+    join(tempDir, 'video.%(ext)s'),
+    url,
+  ]
+
+  try {
+    await new Promise<void>((resolve, reject) => {
+      // Videos with many comments take a while to page through
+      const execOptions = { timeout: 5 * 60 * 1000, windowsHide: true, env: getYtdlpEnv() }
+      execFile(YTDLP_PATH!, args, execOptions, (error, _stdout, stderr) => {
+        if (!error) {
+          resolve()
+        } else if (isUnsupportedUrlError(stderr)) {
+          reject(createLocalizedDownloadError('errorSiteNotSupported', undefined, DownloadErrorCode.UNSUPPORTED_SITE))
+        } else {
+          const message = `Failed to get comments: ${stderr || error.message}`
+          reject(createDownloadError(message, DownloadErrorCode.UNKNOWN_ERROR))
+        }
+      })
+    })
+
+    const infoPath = join(tempDir, 'video.info.json')
+    if (!existsSync(infoPath)) {
+      throw createDownloadError('yt-dlp did not write the video info', DownloadErrorCode.UNKNOWN_ERROR)
+    }
+    const comments: any[] = JSON.parse(readFileSync(infoPath, 'utf-8')).comments ?? []
+    const threads = buildCommentThreads(comments.slice(0, maxComments))
+    logger.info('Fetched video comments', { url, comments: Math.min(comments.length, maxComments) })
+    return threads
+  } finally {
+    rmSync(tempDir, { recursive: true, force: true })
+  }
+}
+
+/**
+ * Save only a video's thumbnail, converted to JPEG, without downloading the video. The
+ * extension of outputPath is replaced by that of the written file; returns its path.
+ */
+export async function downloadThumbnailOnly(url: string, outputPath: string): Promise<string> {
+  if (!YTDLP_PATH) {
+    throw createLocalizedDownloadError('errorBinaryNotFound', { binary: 'yt-dlp' }, DownloadErrorCode.UNKNOWN_ERROR)
+  }
+  assertYtdlpSupported(await getYtdlpCapabilities(YTDLP_PATH))
+
+  const base = outputPath.slice(0, outputPath.length - extname(outputPath).length)
+  mkdirSync(dirname(base), { recursive: true })
+  const args = [
+    ...getYtdlpBaseArgs(),
+    '--write-thumbnail',
+    '--skip-download',
+    '--convert-thumbnails',
+    'jpg',
+    '--no-playlist',
+    '--no-warnings',
+    ...getCookieArgs(),
+    ...(FFMPEG_PATH ? ['--ffmpeg-location', FFMPEG_PATH] : []),
+    '-o',
+    `${base.replace(/%/g, '%%')}.%(ext)s`,
+    url,
+  ]
+
+  await new Promise<void>((resolve, reject) => {
+    execFile(YTDLP_PATH!, args, { timeout: 60000, windowsHide: true, env: getYtdlpEnv() }, (error, _stdout, stderr) => {
+      if (!error) {
+        resolve()
+      } else if (isUnsupportedUrlError(stderr)) {
+        reject(createLocalizedDownloadError('errorSiteNotSupported', undefined, DownloadErrorCode.UNSUPPORTED_SITE))
+      } else {
+        const message = `Failed to download thumbnail: ${stderr || error.message}`
+        reject(createDownloadError(message, DownloadErrorCode.UNKNOWN_ERROR))
+      }
+    })
+  })
+
+  // Without FFmpeg the thumbnail keeps the format the site served
+  const written = THUMBNAIL_EXTENSIONS.map(ext => `${base}${ext}`).find(path => existsSync(path))
+  if (!written) {
+    throw createDownloadError('yt-dlp did not write a thumbnail', DownloadErrorCode.NO_FORMAT_AVAILABLE)
+  }
+  logger.info('Thumbnail downloaded', { url, path: written })
+  return written
+}
+
 export function isYtdlpInitialized(): boolean {
   return YTDLP_PATH !== null
 }
@@ -669,14 +1310,6 @@ export function cleanupYtdlp(): void {
 }
 
 // Helper functions
-function sanitizeFilename(filename: string): string {
-  return filename
-    .replace(/[<>:"/\\|?*]/g, '')
-    .replace(/\s+/g, ' ')
-    .trim()
-    .substring(0, 200)
-}
-
 function secondsToTimeString(seconds: number): string {
   const hours = Math.floor(seconds / 3600)
   const minutes = Math.floor((seconds % 3600) / 60)
@@ -713,10 +1346,90 @@ function formatViewCount(views: number): string {
   return views.toString()
 }
 
+function extractRelatedVideos(entries: any[]): RelatedVideo[] {
+  return entries
+    .filter(entry => entry?.id && entry.title)
+    .map(entry => ({
+      id: String(entry.id),
+      title: entry.title,
+      url: entry.webpage_url || entry.url || `https://www.youtube.com/watch?v=${entry.id}`,
+      duration: entry.duration || undefined,
+      viewCount: entry.view_count ?? undefined,
+      thumbnail: entry.thumbnail || entry.thumbnails?.[entry.thumbnails.length - 1]?.url || undefined,
+      channel: entry.channel || entry.uploader || undefined,
+    }))
+}
+
+function extractChapters(chapters: any[]): VideoChapter[] {
+  return chapters
+    .filter(chapter => Number.isFinite(chapter?.start_time))
+    .map((chapter, i) => ({
+      start: chapter.start_time,
+      end: Number.isFinite(chapter.end_time) ? chapter.end_time : chapter.start_time,
+      title: chapter.title || `Chapter ${i + 1}`,
+    }))
+}
+
+function isStoryboardFormat(format: any): boolean {
+  return format.format_note === 'storyboard' || format.protocol === 'mhtml'
+}
+
+function extractThumbnails(thumbnails: any[]): VideoThumbnail[] {
+  // yt-dlp lists thumbnails from lowest to highest preference; keep that order
+  return thumbnails
+    .filter(t => typeof t.url === 'string' && t.url)
+    .map(t => ({
+      url: t.url,
+      width: t.width || 0,
+      height: t.height || 0,
+    }))
+}
+
+function extractStoryboards(formats: any[]): StoryboardInfo[] {
+  return formats
+    .filter(format => isStoryboardFormat(format) && format.columns && format.rows)
+    .map(format => {
+      const fragments: string[] = (format.fragments || []).map((f: any) => f.url).filter(Boolean)
+      // Storyboard fps is frames per second of video; fall back to the first sheet's duration
+      let interval = format.fps ? 1 / format.fps : 0
+      if (!interval && format.fragments?.[0]?.duration) {
+        interval = format.fragments[0].duration / (format.columns * format.rows)
+      }
+
+      return {
+        formatId: String(format.format_id),
+        url: fragments[0] || format.url,
+        fragments: fragments.length > 0 ? fragments : [format.url],
+        columns: format.columns,
+        rows: format.rows,
+        frameWidth: format.width || 0,
+        frameHeight: format.height || 0,
+        interval,
+      }
+    })
+}
+
+/**
+ * Languages of the audio-only formats, when there is more than one to choose from
+ */
+function extractAudioLanguages(formats: VideoFormatInfo[]): AudioLanguage[] | undefined {
+  const languages = new Map<string, string>()
+  for (const format of formats) {
+    // Codes end up inside format selectors, so anything unusual is skipped
+    if (!format.hasAudio || format.hasVideo || !format.language || !/^[\w-]+$/.test(format.language)) continue
+    if (!languages.has(format.language)) {
+      // 'English (United States) original, medium' -> 'English (United States) original'
+      languages.set(format.language, format.formatNote?.split(',')[0].trim() || format.language)
+    }
+  }
+  return languages.size > 1 ? Array.from(languages, ([code, label]) => ({ code, label })) : undefined
+}
+
 function extractFormats(formats: any[]): VideoFormatInfo[] {
-  // Convert yt-dlp formats to our format structure
-  const result = formats.map(format => ({
+  // Convert yt-dlp formats to our format structure (storyboards are exposed separately)
+  const result = formats.filter(format => !isStoryboardFormat(format)).map(format => ({
     itag: parseInt(format.format_id) || 0,
+    formatId: format.format_id ? String(format.format_id) : undefined,
     quality: format.format_note || format.quality || 'Unknown',
     format: format.format || format.ext || 'mp4',
     container: format.ext || 'mp4',
@@ -731,10 +1444,14 @@ function extractFormats(formats: any[]): VideoFormatInfo[] {
     videoCodec: format.vcodec,
     mimeType: format.mime_type,
     url: format.url,
-    contentLength: format.filesize,
+    contentLength: format.filesize || format.filesize_approx || undefined, // Approximate for most DASH formats
     // Include protocol to distinguish direct HTTPS URLs from HLS/DASH manifests
     // 'https' = direct download, 'm3u8'/'m3u8_native' = HLS stream (problematic for proxying)
     protocol: format.protocol,
+    dynamicRange: format.dynamic_range || undefined,
+    audioChannels: format.audio_channels || undefined,
+    language: format.language || undefined,
+    formatNote: format.format_note || undefined,
   }))
 
   // Debug: Log format URL availability
@@ -896,6 +1613,17 @@ export function getStreamingUrls(videoInfo: VideoInfo): DualStreamUrls {
   }
 }
 
+/**
+ * Format of a video by its yt-dlp format ID (or itag, for older callers)
+ */
+export function findVideoFormat(videoInfo: VideoInfo, formatId: string): VideoFormatInfo | null {
+  return (
+    videoInfo.formats.find(format => format.formatId === formatId) ??
+    videoInfo.formats.find(format => !format.formatId && String(format.itag) === formatId) ??
+    null
+  )
+}
+
 /**
  * Legacy single-URL function for backward compatibility.
  * @deprecated Use getStreamingUrls() for dual-stream support
@@ -931,6 +1659,31 @@ export function isYtdlpAvailable(): boolean {
   return YTDLP_PATH !== null
 }
 
+export function getYtdlpPath(): string | null {
+  return YTDLP_PATH
+}
+
+export function getFfmpegPath(): string | null {
+  return FFMPEG_PATH
+}
+
+/**
+ * Look for FFmpeg again, e.g. after it was installed while the app is running
+ */
+export function refreshFfmpegPath(): string | null {
+  FFMPEG_PATH = detectFfmpegPath()
+  return FFMPEG_PATH
+}
+
+/**
+ * Detect both binaries again, e.g. after their paths were changed in settings
+ */
+export function refreshBinaryPaths(): void {
+  FFMPEG_PATH = detectFfmpegPath()
+  YTDLP_PATH = detectYtdlpPath()
+  logger.info('Binary paths refreshed', { ffmpeg: FFMPEG_PATH, ytdlp: YTDLP_PATH })
+}
+
 export function hasValidCookies(): boolean {
   return cookieManager.hasValidCookies()
 }
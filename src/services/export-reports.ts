@@ -0,0 +1,115 @@
+/**
+ * Export Reports
+ * What each export produced: file size, duration, bitrate, streams, the encoder and how
+ * fast it ran. buildExportReport assembles a report from what the exporter recorded while
+ * FFmpeg ran and a probe of the finished file; it is pure, so a report can be rebuilt from
+ * a recorded run.
+ *
+ * A file whose probed duration is more than DURATION_TOLERANCE off the expected one gets a
+ * 'duration-mismatch' warning: a broken filter graph tends to end the output early
+ * without FFmpeg failing.
+ *
+ * The last MAX_REPORTS_PER_PROJECT reports of each project are kept in export-reports.json
+ * in the app data, newest first.
+ */
+
+import { existsSync, readFileSync, writeFileSync } from 'fs'
+import { join } from 'path'
+import { app } from 'electron'
+
+import type { ExportReport, ExportReportWarning, ProjectExportResult } from '../types/project'
+import { isHardwareEncoder } from '../utils/ffmpeg-args'
+import { Logger } from '../utils/logger'
+
+const logger = Logger.getInstance()
+
+const reportsFilePath = join(app.getPath('userData'), 'export-reports.json')
+
+const MAX_REPORTS_PER_PROJECT = 10
+const DURATION_TOLERANCE = 0.02 // Fraction of the expected duration
+
+/** What the exporter knows once FFmpeg has finished */
+export interface ExportRunRecord {
+  projectId: string
+  result: ProjectExportResult
+  expectedDuration: number
+  startedAt: number // ms since epoch
+  finishedAt: number
+  frames: number // Last frame= of FFmpeg's progress; 0 without video
+}
+
+/** The finished file, from ffprobe and stat */
+export interface ExportProbe {
+  fileSize: number
+  duration: number
+  streams: { streamType: string; codec: string; bitrate?: number }[] // bitrate in bits per second
+}
+
+let reports: Record<string, ExportReport[]> | null = null
+
+export function buildExportReport(run: ExportRunRecord, probe: ExportProbe): ExportReport {
+  const encodeSeconds = Math.max(0, (run.finishedAt - run.startedAt) / 1000)
+  const duration = probe.duration > 0 ? probe.duration : 0
+  const warnings: ExportReportWarning[] = []
+  const deviation = run.expectedDuration > 0 ? Math.abs(duration - run.expectedDuration) / run.expectedDuration : 0
+  if (deviation > DURATION_TOLERANCE) {
+    warnings.push('duration-mismatch')
+  }
+
+  const encoder = run.result.encoder ?? null
+  return {
+    projectId: run.projectId,
+    outputPath: run.result.outputPath,
+    createdAt: run.finishedAt,
+    method: run.result.method,
+    encoder,
+    hardwareAccelerated: encoder !== null && isHardwareEncoder(encoder),
+    fileSize: probe.fileSize,
+    duration,
+    expectedDuration: run.expectedDuration,
+    averageBitrate: duration > 0 ? Math.round((probe.fileSize * 8) / duration / 1000) : 0,
+    encodeSeconds: Math.round(encodeSeconds * 10) / 10,
+    averageFps: run.frames > 0 && encodeSeconds > 0 ? Math.round((run.frames / encodeSeconds) * 10) / 10 : null,
+    streams: probe.streams
+      .filter(stream => ['video', 'audio', 'subtitle'].includes(stream.streamType))
+      .map(stream => ({
+        type: stream.streamType,
+        codec: stream.codec,
+        ...(stream.bitrate ? { bitrate: Math.round(stream.bitrate / 1000) } : {}),
+      })),
+    warnings,
+  }
+}
+
+function loadReports(): Record<string, ExportReport[]> {
+  if (reports) {
+    return reports
+  }
+  try {
+    reports = existsSync(reportsFilePath) ? (JSON.parse(readFileSync(reportsFilePath, 'utf-8')).reports ?? {}) : {}
+  } catch (error) {
+    logger.warn('Error loading export reports, starting empty', error as Error)
+    reports = {}
+  }
+  return reports!
+}
+
+/**
+ * Keep a report, dropping the project's oldest beyond MAX_REPORTS_PER_PROJECT
+ */
+export function recordExportReport(report: ExportReport): void {
+  const all = loadReports()
+  all[report.projectId] = [report, ...(all[report.projectId] ?? [])].slice(0, MAX_REPORTS_PER_PROJECT)
+  try {
+    writeFileSync(reportsFilePath, JSON.stringify({ reports: all, lastUpdated: Date.now() }, null, 2), 'utf-8')
+  } catch (error) {
+    logger.error('Failed to save export reports', error as Error)
+  }
+}
+
+/**
+ * Reports of a project's recent exports, newest first
+ */
+export function getExportReports(projectId: string): ExportReport[] {
+  return loadReports()[projectId] ?? []
+}
@@ -0,0 +1,336 @@
+/**
+ * Health Check Service
+ * Aggregates the usual environment problems (missing binaries, unwritable download
+ * folder, full disk, corrupt library/config, no network) into one structured report
+ * for the diagnostics panel.
+ *
+ * Every probe runs in parallel under its own timeout, so one hanging probe
+ * (e.g. a network request or a stuck binary) can't block the whole report.
+ */
+
+import { execFile } from 'child_process'
+import { existsSync, readFileSync, unlinkSync, writeFileSync } from 'fs'
+import { statfs } from 'fs/promises'
+import { join } from 'path'
+import { net } from 'electron'
+
+import { ConfigManager } from '../utils/config'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+import { getDownloadStorageFilePath } from './download-storage'
+import { getFfmpegPath, getYtdlpPath } from './downloader/yt-dlp-provider'
+import { getYtdlpBaseArgs, getYtdlpEnv } from './downloader/yt-dlp-environment'
+import type { HealthCheckItem, HealthReport, HealthSeverity } from '../types/system'
+
+const logger = Logger.getInstance()
+
+const PROBE_TIMEOUT = 10000 // 10 seconds per probe
+const NETWORK_TIMEOUT = 8000
+const LOW_DISK_SPACE_WARNING = 5 * 1024 * 1024 * 1024 // 5GB
+const LOW_DISK_SPACE_ERROR = 500 * 1024 * 1024 // 500MB
+const YTDLP_OUTDATED_DAYS = 90 // YouTube changes often; older releases tend to break
+
+/**
+ * yt-dlp releases with known breakage on YouTube, with the reason shown to the user
+ */
+const KNOWN_BROKEN_YTDLP_VERSIONS: Record<string, string> = {
+  '2023.03.03': 'fails on YouTube with "Unable to extract uploader id"',
+}
+
+type Probe = () => Promise<HealthCheckItem>
+
+/**
+ * Run a probe with a timeout; a timeout or unexpected exception becomes an item of its own
+ */
+async function runProbe(check: HealthCheckItem['check'], probe: Probe): Promise<HealthCheckItem> {
+  let timer: NodeJS.Timeout | undefined
+  const timeout = new Promise<HealthCheckItem>(resolve => {
+    timer = setTimeout(
+      () =>
+        resolve({
+          check,
+          severity: 'warning',
+          code: 'CHECK_TIMED_OUT',
+          message: `The ${check} check did not finish within ${PROBE_TIMEOUT / 1000} seconds`,
+        }),
+      PROBE_TIMEOUT,
+    )
+  })
+
+  try {
+    return await Promise.race([probe(), timeout])
+  } catch (error) {
+    logger.warn('Health check probe failed', { check, error: (error as Error).message })
+    return { check, severity: 'error', code: 'CHECK_FAILED', message: (error as Error).message }
+  } finally {
+    clearTimeout(timer)
+  }
+}
+
+/**
+ * Run a binary with a version flag and return the first line of its output
+ */
+function getBinaryVersion(binaryPath: string, args: string[], env: NodeJS.ProcessEnv = process.env): Promise<string> {
+  return new Promise((resolve, reject) => {
+    execFile(binaryPath, args, { timeout: PROBE_TIMEOUT - 1000, windowsHide: true, env }, (error, stdout) => {
+      if (error) {
+        reject(error)
+        return
+      }
+      resolve(stdout.trim().split(/\r?\n/)[0] ?? '')
+    })
+  })
+}
+
+async function checkYtdlp(): Promise<HealthCheckItem> {
+  const ytdlpPath = getYtdlpPath()
+  if (!ytdlpPath) {
+    return {
+      check: 'ytdlp',
+      severity: 'error',
+      code: 'YTDLP_MISSING',
+      message: 'yt-dlp was not found. Downloads will not work.',
+      fixAction: 'update-ytdlp',
+    }
+  }
+
+  const version = await getBinaryVersion(ytdlpPath, [...getYtdlpBaseArgs(), '--version'], getYtdlpEnv())
+  const details = { path: ytdlpPath, version }
+  const brokenReason = KNOWN_BROKEN_YTDLP_VERSIONS[version]
+  if (brokenReason) {
+    return {
+      check: 'ytdlp',
+      severity: 'error',
+      code: 'YTDLP_KNOWN_BROKEN',
+      message: `yt-dlp ${version} ${brokenReason}. Update yt-dlp.`,
+      details,
+      fixAction: 'update-ytdlp',
+    }
+  }
+
+  // yt-dlp versions are release dates: YYYY.MM.DD[.patch]
+  const match = version.match(/^(\d{4})\.(\d{2})\.(\d{2})/)
+  if (match) {
+    const released = new Date(Number(match[1]), Number(match[2]) - 1, Number(match[3]))
+    const ageDays = Math.floor((Date.now() - released.getTime()) / (24 * 60 * 60 * 1000))
+    if (ageDays > YTDLP_OUTDATED_DAYS) {
+      return {
+        check: 'ytdlp',
+        severity: 'warning',
+        code: 'YTDLP_OUTDATED',
+        message: `yt-dlp ${version} is ${ageDays} days old. Updating may fix download errors.`,
+        details: { ...details, ageDays },
+        fixAction: 'update-ytdlp',
+      }
+    }
+  }
+
+  return { check: 'ytdlp', severity: 'ok', code: 'YTDLP_OK', message: `yt-dlp ${version}`, details }
+}
+
+async function checkFfmpeg(): Promise<HealthCheckItem> {
+  const ffmpegPath = getFfmpegPath()
+  if (!ffmpegPath) {
+    return {
+      check: 'ffmpeg',
+      severity: 'warning',
+      code: 'FFMPEG_MISSING',
+      message: 'FFmpeg was not found. High quality downloads and video editing will not work.',
+      fixAction: 'install-ffmpeg',
+    }
+  }
+
+  const versionLine = await getBinaryVersion(ffmpegPath, ['-version'])
+  const version = versionLine.match(/ffmpeg version (\S+)/)?.[1] ?? versionLine
+  return {
+    check: 'ffmpeg',
+    severity: 'ok',
+    code: 'FFMPEG_OK',
+    message: `FFmpeg ${version}`,
+    details: { path: ffmpegPath, version },
+  }
+}
+
+/**
+ * Probe the download folder by writing and removing a small file
+ */
+async function checkDownloadPath(downloadPath: string): Promise<HealthCheckItem> {
+  if (!existsSync(downloadPath)) {
+    return {
+      check: 'download-path',
+      severity: 'warning',
+      code: 'DOWNLOAD_PATH_MISSING',
+      message: `Download folder does not exist yet: ${downloadPath}`,
+      details: { path: downloadPath },
+      fixAction: 'choose-download-folder',
+    }
+  }
+
+  const probePath = join(downloadPath, `.clipy-write-test-${process.pid}`)
+  try {
+    writeFileSync(probePath, 'ok')
+    unlinkSync(probePath)
+  } catch (error) {
+    return {
+      check: 'download-path',
+      severity: 'error',
+      code: 'DOWNLOAD_PATH_NOT_WRITABLE',
+      message: `Download folder is not writable: ${(error as Error).message}`,
+      details: { path: downloadPath },
+      fixAction: 'choose-download-folder',
+    }
+  }
+
+  return {
+    check: 'download-path',
+    severity: 'ok',
+    code: 'DOWNLOAD_PATH_OK',
+    message: 'Download folder is writable',
+    details: { path: downloadPath },
+  }
+}
+
+async function checkDiskSpace(label: string, path: string): Promise<HealthCheckItem> {
+  const stats = await statfs(existsSync(path) ? path : PlatformUtils.getInstance().getAppDataDir('clipy'))
+  const freeBytes = stats.bavail * stats.bsize
+  const details = { volume: label, path, freeBytes }
+
+  if (freeBytes < LOW_DISK_SPACE_ERROR) {
+    return {
+      check: 'disk-space',
+      severity: 'error',
+      code: 'DISK_FULL',
+      message: `Almost no free space left for the ${label}`,
+      details,
+    }
+  }
+  if (freeBytes < LOW_DISK_SPACE_WARNING) {
+    return {
+      check: 'disk-space',
+      severity: 'warning',
+      code: 'LOW_DISK_SPACE',
+      message: `Low free space for the ${label}`,
+      details,
+    }
+  }
+  return {
+    check: 'disk-space',
+    severity: 'ok',
+    code: 'DISK_SPACE_OK',
+    message: `Enough free space for the ${label}`,
+    details,
+  }
+}
+
+/**
+ * Clipy has no database; the library lives in downloads.json, so check that it parses
+ */
+async function checkLibrary(): Promise<HealthCheckItem> {
+  const libraryPath = getDownloadStorageFilePath()
+  if (!existsSync(libraryPath)) {
+    return { check: 'library', severity: 'ok', code: 'LIBRARY_OK', message: 'Library is empty' }
+  }
+
+  try {
+    const data = JSON.parse(readFileSync(libraryPath, 'utf8'))
+    if (!Array.isArray(data.downloads)) {
+      throw new Error('downloads list is missing')
+    }
+    return {
+      check: 'library',
+      severity: 'ok',
+      code: 'LIBRARY_OK',
+      message: `Library contains ${data.downloads.length} downloads`,
+    }
+  } catch (error) {
+    return {
+      check: 'library',
+      severity: 'error',
+      code: 'LIBRARY_CORRUPT',
+      message: `Library file is corrupt: ${(error as Error).message}`,
+      details: { path: libraryPath },
+    }
+  }
+}
+
+async function checkConfig(): Promise<HealthCheckItem> {
+  const { isValid, errors } = ConfigManager.getInstance().validate()
+  if (!isValid) {
+    return {
+      check: 'config',
+      severity: 'warning',
+      code: 'CONFIG_INVALID',
+      message: `Settings contain invalid values: ${errors.join('; ')}`,
+      details: { errors },
+      fixAction: 'reset-config',
+    }
+  }
+  return { check: 'config', severity: 'ok', code: 'CONFIG_OK', message: 'Settings are valid' }
+}
+
+/**
+ * HEAD youtube.com through Electron's network stack, so the session/system proxy applies
+ */
+async function checkNetwork(): Promise<HealthCheckItem> {
+  const controller = new AbortController()
+  const timer = setTimeout(() => controller.abort(), NETWORK_TIMEOUT)
+  const startedAt = Date.now()
+  try {
+    const response = await net.fetch('https://www.youtube.com/', { method: 'HEAD', signal: controller.signal })
+    const details = { status: response.status, latencyMs: Date.now() - startedAt }
+    if (response.status >= 500) {
+      return {
+        check: 'network',
+        severity: 'warning',
+        code: 'YOUTUBE_UNAVAILABLE',
+        message: `youtube.com responded with HTTP ${response.status}`,
+        details,
+      }
+    }
+    return { check: 'network', severity: 'ok', code: 'NETWORK_OK', message: 'youtube.com is reachable', details }
+  } catch (error) {
+    const timedOut = controller.signal.aborted
+    return {
+      check: 'network',
+      severity: 'error',
+      code: 'NETWORK_UNREACHABLE',
+      message: timedOut
+        ? `youtube.com did not respond within ${NETWORK_TIMEOUT / 1000} seconds`
+        : `Cannot reach youtube.com: ${(error as Error).message}`,
+    }
+  } finally {
+    clearTimeout(timer)
+  }
+}
+
+function worstSeverity(items: HealthCheckItem[]): HealthSeverity {
+  if (items.some(item => item.severity === 'error')) return 'error'
+  if (items.some(item => item.severity === 'warning')) return 'warning'
+  return 'ok'
+}
+
+/**
+ * Run all health probes and return the combined report
+ */
+export async function runHealthCheck(): Promise<HealthReport> {
+  const downloadPath = ConfigManager.getInstance().get('download').downloadPath
+  const appDataDir = PlatformUtils.getInstance().getAppDataDir('clipy')
+
+  const items = await Promise.all([
+    runProbe('ytdlp', checkYtdlp),
+    runProbe('ffmpeg', checkFfmpeg),
+    runProbe('download-path', () => checkDownloadPath(downloadPath)),
+    runProbe('disk-space', () => checkDiskSpace('download folder', downloadPath)),
+    runProbe('disk-space', () => checkDiskSpace('app data folder', appDataDir)),
+    runProbe('library', checkLibrary),
+    runProbe('config', checkConfig),
+    runProbe('network', checkNetwork),
+  ])
+
+  const report: HealthReport = { checkedAt: Date.now(), severity: worstSeverity(items), items }
+  logger.info('Health check completed', {
+    severity: report.severity,
+    problems: items.filter(item => item.severity !== 'ok').map(item => item.code),
+  })
+  return report
+}
@@ -0,0 +1,178 @@
+/**
+ * Library Conversions
+ * Turns a library video into another file next to it, either the same streams in another
+ * container (remux) or just its audio, and adds the result to the library as a new entry
+ * linked to the source through parentId.
+ *
+ * Conversions run in the background: the caller gets a job ID at once, 'progress' events
+ * follow FFmpeg's position, and a job can be cancelled. Existing files are never
+ * overwritten; the output gets a ' (N)' suffixed name instead.
+ */
+
+import { EventEmitter } from 'events'
+import { existsSync } from 'fs'
+import { basename, dirname, extname, join } from 'path'
+
+import type {
+  AudioExtractFormat,
+  DownloadProgress,
+  LibraryConversionKind,
+  LibraryConversionProgress,
+  RemuxStreamSelection,
+} from '../types/download'
+import { isCompletedStatus } from '../types/download'
+import type { RemuxContainer } from '../utils/ffmpeg-args'
+import { Logger } from '../utils/logger'
+import { JobRegistry, PROGRESS_INTERVAL } from './bulk-jobs'
+import { getStoredDownloadById } from './download-storage'
+import { enrichAndRegister } from './library'
+import { type FFmpegRunOptions, VideoProcessor } from './video-processor'
+
+const logger = Logger.getInstance()
+
+interface ConversionJob {
+  progress: LibraryConversionProgress
+  controller: AbortController
+}
+
+/** Writes the converted file; resolves with the path actually written */
+type ConversionRenderer = (sourcePath: string, outputPath: string, run: FFmpegRunOptions) => Promise<string>
+
+const jobs = new JobRegistry<ConversionJob>('convert')
+
+/** Emits 'progress' with LibraryConversionProgress */
+export const libraryConversionEvents = new EventEmitter()
+
+/**
+ * Completed library entry with its file on disk; throws otherwise
+ */
+function getConvertibleEntry(downloadId: string): DownloadProgress & { filePath: string } {
+  const source = getStoredDownloadById(downloadId)
+  if (!isCompletedStatus(source?.status) || !source?.filePath || !existsSync(source.filePath)) {
+    throw new Error('Library video not found')
+  }
+  return source as DownloadProgress & { filePath: string }
+}
+
+async function runConversion(
+  job: ConversionJob,
+  source: DownloadProgress & { filePath: string },
+  outputPath: string,
+  render: ConversionRenderer,
+): Promise<void> {
+  const duration =
+    source.duration ||
+    (await VideoProcessor.getInstance()
+      .getVideoMetadata(source.filePath)
+      .then(metadata => metadata.duration)
+      .catch(() => 0))
+
+  let lastEmit = 0
+  const onProgress = (seconds: number) => {
+    const now = Date.now()
+    if (duration > 0 && now - lastEmit >= PROGRESS_INTERVAL) {
+      lastEmit = now
+      job.progress.progress = Math.min(99, (seconds / duration) * 100)
+      libraryConversionEvents.emit('progress', { ...job.progress })
+    }
+  }
+
+  try {
+    const finalPath = await render(source.filePath, outputPath, { onProgress, signal: job.controller.signal })
+    const { url, title, channel, tags } = source
+    const entry = await enrichAndRegister(finalPath, { url, title, channel, tags, parentId: source.downloadId })
+    if (!entry) {
+      throw new Error('Converted file is already in the library')
+    }
+    job.progress.status = 'completed'
+    job.progress.progress = 100
+    job.progress.entry = entry
+  } catch (error) {
+    job.progress.status = job.controller.signal.aborted ? 'cancelled' : 'failed'
+    if (job.progress.status === 'failed') {
+      job.progress.error = (error as Error).message
+    }
+  }
+
+  job.progress.finishedAt = Date.now()
+  libraryConversionEvents.emit('progress', { ...job.progress })
+  logger.info('Library conversion finished', {
+    jobId: job.progress.jobId,
+    kind: job.progress.kind,
+    sourceId: job.progress.sourceId,
+    status: job.progress.status,
+  })
+  jobs.pruneFinishedJobs()
+}
+
+function startConversion(
+  kind: LibraryConversionKind,
+  downloadId: string,
+  extension: string,
+  render: ConversionRenderer,
+): string {
+  const source = getConvertibleEntry(downloadId)
+  const outputPath = join(
+    dirname(source.filePath),
+    `${basename(source.filePath, extname(source.filePath))}.${extension}`,
+  )
+
+  const job: ConversionJob = {
+    progress: {
+      jobId: jobs.generateJobId(),
+      kind,
+      sourceId: downloadId,
+      status: 'running',
+      progress: 0,
+      startedAt: Date.now(),
+    },
+    controller: new AbortController(),
+  }
+  jobs.add(job)
+  logger.info('Library conversion started', { jobId: job.progress.jobId, kind, downloadId, extension })
+
+  runConversion(job, source, outputPath, render).catch(error => {
+    logger.error('Library conversion failed', error as Error, { jobId: job.progress.jobId })
+  })
+  return job.progress.jobId
+}
+
+/**
+ * Copy a library video into another container without re-encoding the video.
+ * Audio the container can't hold is re-encoded. Returns the job ID.
+ */
+export function remuxLibraryVideo(
+  downloadId: string,
+  container: RemuxContainer,
+  streams: RemuxStreamSelection = {},
+): string {
+  return startConversion('remux', downloadId, container, (sourcePath, outputPath, run) =>
+    VideoProcessor.getInstance().remuxVideo(sourcePath, outputPath, { ...streams, ...run }),
+  )
+}
+
+/**
+ * Extract a library video's audio into an audio-only file. Returns the job ID.
+ */
+export function extractLibraryAudio(downloadId: string, format: AudioExtractFormat, bitrate?: number): string {
+  return startConversion('extract-audio', downloadId, format, (sourcePath, outputPath, run) =>
+    VideoProcessor.getInstance().extractAudio(sourcePath, outputPath, { format, bitrate, ...run }),
+  )
+}
+
+export function getLibraryConversionStatus(jobId: string): LibraryConversionProgress | null {
+  const job = jobs.get(jobId)
+  return job ? { ...job.progress } : null
+}
+
+/**
+ * Stop a running conversion; its partial output is removed
+ */
+export function cancelLibraryConversion(jobId: string): boolean {
+  const job = jobs.get(jobId)
+  if (!job || job.progress.status !== 'running') {
+    return false
+  }
+  job.controller.abort()
+  return true
+}
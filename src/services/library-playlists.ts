@@ -0,0 +1,101 @@
+/**
+ * Library Playlists
+ * A library search saved as an M3U playlist, to play the matching videos in order in VLC or
+ * any other player. Only entries whose file still exists are listed, by absolute path.
+ * Playlists go to the playlists folder in the app data unless another path is given; the
+ * ones there are listed with their entry counts.
+ *
+ * The playlist is a snapshot: videos downloaded later that match the search are only
+ * added by saving it again.
+ */
+
+import { existsSync, mkdirSync, readFileSync, readdirSync, statSync, writeFileSync } from 'fs'
+import { basename, extname, join } from 'path'
+import { app } from 'electron'
+
+import type { DownloadProgress, PlaylistFile } from '../types/download'
+import { Logger } from '../utils/logger'
+import { sanitizePathComponent } from '../utils/paths'
+import { searchLibraryEntries } from './library-search'
+
+const logger = Logger.getInstance()
+
+const PLAYLIST_EXTENSION = '.m3u'
+const VLC_NETWORK_CACHING_MS = 1000
+
+export function getPlaylistsDir(): string {
+  return join(app.getPath('userData'), 'playlists')
+}
+
+/**
+ * Extended M3U of the entries, each with its duration and "channel - title"
+ */
+export function buildM3uPlaylist(name: string, entries: DownloadProgress[]): string {
+  const lines = ['#EXTM3U', `#PLAYLIST:${name}`, `#EXTVLCOPT:network-caching=${VLC_NETWORK_CACHING_MS}`]
+  for (const entry of entries) {
+    const duration = entry.duration ? Math.round(entry.duration) : -1
+    const title = entry.channel ? `${entry.channel} - ${entry.title}` : entry.title
+    lines.push(`#EXTINF:${duration},${title.replace(/[\r\n]+/g, ' ')}`, entry.filePath!)
+  }
+  return `${lines.join('\n')}\n`
+}
+
+/**
+ * Save the library videos matching a search as a playlist. Without an output path it goes
+ * to the playlists folder, named after the playlist. Returns where it went and how many
+ * videos it lists.
+ */
+export function saveSearchAsPlaylist(
+  query: string,
+  name: string,
+  outputPath?: string,
+): { outputPath: string; count: number } {
+  const playlistName = name.trim()
+  if (!playlistName) {
+    throw new Error('Playlist name is required')
+  }
+
+  let target = outputPath
+  if (!target) {
+    mkdirSync(getPlaylistsDir(), { recursive: true })
+    target = join(getPlaylistsDir(), `${sanitizePathComponent(playlistName, 'playlist')}${PLAYLIST_EXTENSION}`)
+  }
+
+  const entries = searchLibraryEntries(query).filter(entry => entry.filePath && existsSync(entry.filePath))
+  writeFileSync(target, buildM3uPlaylist(playlistName, entries), 'utf-8')
+  logger.info('Library search saved as playlist', { query, outputPath: target, count: entries.length })
+  return { outputPath: target, count: entries.length }
+}
+
+function readPlaylistFile(filePath: string): PlaylistFile {
+  const content = readFileSync(filePath, 'utf-8')
+  const lines = content.split(/\r?\n/)
+  const name = lines.find(line => line.startsWith('#PLAYLIST:'))?.slice('#PLAYLIST:'.length).trim()
+  return {
+    name: name || basename(filePath, extname(filePath)),
+    path: filePath,
+    entryCount: lines.filter(line => line.trim() && !line.startsWith('#')).length,
+    modifiedAt: statSync(filePath).mtimeMs,
+  }
+}
+
+/**
+ * Playlists in the playlists folder, most recently saved first
+ */
+export function listSavedPlaylists(): PlaylistFile[] {
+  const dir = getPlaylistsDir()
+  if (!existsSync(dir)) {
+    return []
+  }
+
+  const playlists: PlaylistFile[] = []
+  for (const file of readdirSync(dir)) {
+    if (!['.m3u', '.m3u8'].includes(extname(file).toLowerCase())) continue
+    try {
+      playlists.push(readPlaylistFile(join(dir, file)))
+    } catch (error) {
+      logger.warn('Failed to read playlist', { file, error: (error as Error).message })
+    }
+  }
+  return playlists.sort((a, b) => b.modifiedAt - a.modifiedAt)
+}
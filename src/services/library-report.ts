@@ -0,0 +1,216 @@
+/**
+ * Library Report
+ * A standalone HTML overview of the library to keep or share: totals, a pie chart of the
+ * space each format takes, every video grouped by channel, and the entries whose file is
+ * gone. Styles and the chart are inline, so the file opens anywhere without other files;
+ * thumbnails are linked from the local thumbnail cache and simply don't show elsewhere.
+ */
+
+import { existsSync, writeFileSync } from 'fs'
+import { pathToFileURL } from 'url'
+
+import type { DownloadProgress } from '../types/download'
+import { getChannels } from './channels'
+import { getEntryFormat, getFileSize, getLibraryEntries, getLibraryFormats } from './library'
+
+const PIE_COLORS = ['#6366f1', '#22c55e', '#f59e0b', '#ef4444', '#06b6d4', '#a855f7', '#84cc16', '#f97316']
+const PIE_RADIUS = 80
+const UNKNOWN_CHANNEL = 'Unknown channel'
+
+const STYLES = `
+body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 1100px; color: #1f2937; padding: 0 1rem; }
+h1 { margin-bottom: 0.25rem; }
+.generated { color: #6b7280; margin-top: 0; }
+.stats { display: flex; flex-wrap: wrap; gap: 1rem; margin: 1.5rem 0; }
+.stat { background: #f3f4f6; border-radius: 8px; padding: 0.75rem 1rem; min-width: 140px; }
+.stat strong { display: block; font-size: 1.4rem; }
+.breakdown { display: flex; align-items: center; gap: 2rem; }
+.legend span { display: inline-block; width: 0.8rem; height: 0.8rem; border-radius: 2px; margin-right: 0.4rem; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 2rem; }
+th, td { text-align: left; padding: 0.4rem 0.6rem; border-bottom: 1px solid #e5e7eb; vertical-align: middle; }
+td.thumb { width: 128px; }
+td.thumb img { width: 120px; border-radius: 4px; display: block; }
+.missing { background: #fef2f2; border: 1px solid #fecaca; border-radius: 8px; padding: 0.5rem 1rem; }
+`
+
+function escapeHtml(value: string | number | undefined): string {
+  return String(value ?? '')
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+}
+
+function formatSize(bytes: number | undefined): string {
+  if (!bytes) return '-'
+  const units = ['B', 'KB', 'MB', 'GB', 'TB']
+  let value = bytes
+  let unit = 0
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024
+    unit++
+  }
+  return unit === 0 ? `${value} B` : `${value.toFixed(1)} ${units[unit]}`
+}
+
+function formatDuration(seconds: number | undefined): string {
+  if (!seconds) return '-'
+  const hours = Math.floor(seconds / 3600)
+  const minutes = Math.floor((seconds % 3600) / 60)
+  const secs = Math.floor(seconds % 60)
+  const padded = `${String(minutes).padStart(hours > 0 ? 2 : 1, '0')}:${String(secs).padStart(2, '0')}`
+  return hours > 0 ? `${hours}:${padded}` : padded
+}
+
+function hasFile(entry: DownloadProgress): boolean {
+  return !!entry.filePath && existsSync(entry.filePath)
+}
+
+function getLocalThumbnail(entry: DownloadProgress): string | null {
+  const path = [entry.cachedThumbnailPath, entry.thumbnailPath].find(candidate => candidate && existsSync(candidate))
+  return path ? pathToFileURL(path).href : null
+}
+
+/**
+ * Inline SVG pie of the space each format takes, with its legend
+ */
+function buildStorageChart(): string {
+  const formats = getLibraryFormats().filter(format => format.totalSize > 0)
+  const total = formats.reduce((sum, format) => sum + format.totalSize, 0)
+  if (total === 0) {
+    return '<p>No file sizes known yet.</p>'
+  }
+
+  const size = PIE_RADIUS * 2
+  let slices: string
+  if (formats.length === 1) {
+    slices = `<circle cx="${PIE_RADIUS}" cy="${PIE_RADIUS}" r="${PIE_RADIUS}" fill="${PIE_COLORS[0]}" />`
+  } else {
+    let angle = -Math.PI / 2 // Start at 12 o'clock
+    slices = formats
+      .map((format, i) => {
+        const sweep = (format.totalSize / total) * Math.PI * 2
+        const start = [PIE_RADIUS + PIE_RADIUS * Math.cos(angle), PIE_RADIUS + PIE_RADIUS * Math.sin(angle)]
+        angle += sweep
+        const end = [PIE_RADIUS + PIE_RADIUS * Math.cos(angle), PIE_RADIUS + PIE_RADIUS * Math.sin(angle)]
+        const largeArc = sweep > Math.PI ? 1 : 0
+        const path =
+          `M${PIE_RADIUS},${PIE_RADIUS} L${start[0].toFixed(2)},${start[1].toFixed(2)} ` +
+          `A${PIE_RADIUS},${PIE_RADIUS} 0 ${largeArc} 1 ${end[0].toFixed(2)},${end[1].toFixed(2)} Z`
+        return `<path d="${path}" fill="${PIE_COLORS[i % PIE_COLORS.length]}" />`
+      })
+      .join('')
+  }
+
+  const legend = formats
+    .map((format, i) => {
+      const share = ((format.totalSize / total) * 100).toFixed(1)
+      const color = PIE_COLORS[i % PIE_COLORS.length]
+      return (
+        `<div><span style="background:${color}"></span>${escapeHtml(format.format)}: ` +
+        `${formatSize(format.totalSize)} (${share}%, ${format.count} videos)</div>`
+      )
+    })
+    .join('')
+
+  return (
+    `<div class="breakdown"><svg width="${size}" height="${size}" viewBox="0 0 ${size} ${size}" role="img">` +
+    `${slices}</svg><div class="legend">${legend}</div></div>`
+  )
+}
+
+function buildVideoTable(entries: DownloadProgress[]): string {
+  const rows = entries.map(entry => {
+    const thumbnail = getLocalThumbnail(entry)
+    const image = thumbnail ? `<img src="${escapeHtml(thumbnail)}" alt="" loading="lazy" />` : ''
+    return (
+      `<tr><td class="thumb">${image}</td><td>${escapeHtml(entry.title)}</td>` +
+      `<td>${formatDuration(entry.duration)}</td><td>${formatSize(getFileSize(entry))}</td>` +
+      `<td>${escapeHtml(getEntryFormat(entry))}</td></tr>`
+    )
+  })
+  return (
+    '<table><thead><tr><th></th><th>Title</th><th>Duration</th><th>Size</th><th>Format</th></tr></thead>' +
+    `<tbody>${rows.join('')}</tbody></table>`
+  )
+}
+
+/**
+ * Library entries grouped by channel (by ID, so renamed channels stay together), largest
+ * group first
+ */
+function groupByChannel(entries: DownloadProgress[]): { name: string; entries: DownloadProgress[] }[] {
+  const names = new Map(getChannels().map(channel => [channel.id, channel.name]))
+  const groups = new Map<string, { name: string; entries: DownloadProgress[] }>()
+  for (const entry of entries) {
+    const name = (entry.channelId && names.get(entry.channelId)) || entry.channel || UNKNOWN_CHANNEL
+    const key = entry.channelId || name
+    const group = groups.get(key) ?? { name, entries: [] }
+    group.entries.push(entry)
+    groups.set(key, group)
+  }
+  return Array.from(groups.values()).sort((a, b) => b.entries.length - a.entries.length || a.name.localeCompare(b.name))
+}
+
+/**
+ * The library report as an HTML document
+ */
+export function buildLibraryReport(): string {
+  const entries = getLibraryEntries()
+  const missing = entries.filter(entry => !hasFile(entry))
+  const totalSize = entries.reduce((sum, entry) => sum + (hasFile(entry) ? (getFileSize(entry) ?? 0) : 0), 0)
+  const totalDuration = entries.reduce((sum, entry) => sum + (entry.duration ?? 0), 0)
+  const channels = groupByChannel(entries)
+
+  const stats = [
+    ['Videos', entries.length],
+    ['Channels', channels.filter(channel => channel.name !== UNKNOWN_CHANNEL).length],
+    ['Total size', formatSize(totalSize)],
+    ['Total duration', formatDuration(totalDuration)],
+    ['Missing files', missing.length],
+  ]
+    .map(([label, value]) => `<div class="stat"><strong>${escapeHtml(value)}</strong>${label}</div>`)
+    .join('')
+
+  const channelSections = channels
+    .map(({ name, entries: videos }) => `<h3>${escapeHtml(name)} (${videos.length})</h3>${buildVideoTable(videos)}`)
+    .join('\n')
+
+  const missingItems = missing
+    .map(entry => `<li>${escapeHtml(entry.title)} - ${escapeHtml(entry.filePath || 'no path')}</li>`)
+    .join('')
+  const missingSection =
+    missing.length > 0
+      ? '<div class="missing"><h2>Missing files</h2><p>These library entries point to files that no longer exist.</p>' +
+        `<ul>${missingItems}</ul></div>`
+      : ''
+
+  return `<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8" />
+<title>Clipy library report</title>
+<style>${STYLES}</style>
+</head>
+<body>
+<h1>Clipy library report</h1>
+<p class="generated">Generated ${escapeHtml(new Date().toLocaleString())}</p>
+<div class="stats">${stats}</div>
+${missingSection}
+<h2>Storage by format</h2>
+${buildStorageChart()}
+<h2>Videos by channel</h2>
+${channelSections || '<p>The library is empty.</p>'}
+</body>
+</html>
+`
+}
+
+/**
+ * Write the library report to a file. Returns the number of bytes written.
+ */
+export function writeLibraryReport(outputPath: string): number {
+  const data = Buffer.from(buildLibraryReport(), 'utf8')
+  writeFileSync(outputPath, data)
+  return data.length
+}
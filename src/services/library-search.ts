@@ -0,0 +1,198 @@
+/**
+ * Library Search
+ * Ranked search over the library's titles, channels, tags, file names and video IDs.
+ *
+ * The index is an inverted index in memory, built from the stored downloads the first time
+ * it is needed and rebuilt after any change to them, so renames and deletions are searched
+ * as they are. Words are folded before they are indexed or looked up: case, accents and ß
+ * don't matter, so 'cafe' finds 'Café'. Results are ranked by BM25 with a match in the
+ * title counting most.
+ *
+ * Every word of the query has to match; the last one, or any ending in *, matches as a
+ * prefix, for searching as you type. A word without any letters or digits (say '++') can't
+ * be looked up in the index, so such queries fall back to a plain substring match.
+ */
+
+import { basename } from 'path'
+
+import type { DownloadProgress } from '../types/download'
+import { getStorageRevision } from './download-storage'
+import { getLibraryEntries } from './library'
+
+const BM25_K1 = 1.2
+const BM25_B = 0.75
+
+/** How much a word counts in each field, relative to a file name */
+const FIELD_WEIGHTS = {
+  title: 3,
+  channel: 2,
+  tags: 1.5,
+  fileName: 1,
+  videoId: 1,
+} as const
+
+interface QueryTerm {
+  text: string
+  prefix: boolean
+}
+
+interface IndexedEntry {
+  entry: DownloadProgress
+  order: number // Position in the library, newest first; breaks ties in the ranking
+  length: number // Weighted word count
+  termWeights: Map<string, number>
+}
+
+interface SearchIndex {
+  revision: number
+  entries: IndexedEntry[]
+  postings: Map<string, IndexedEntry[]>
+  averageLength: number
+}
+
+let searchIndex: SearchIndex | null = null
+
+/**
+ * The words of a text, folded for indexing
+ */
+export function tokenizeSearchText(text: string): string[] {
+  return text
+    .normalize('NFKD')
+    .replace(/\p{M}+/gu, '')
+    .toLowerCase()
+    .replace(/ß/g, 'ss')
+    .split(/[^\p{L}\p{N}]+/u)
+    .filter(Boolean)
+}
+
+function buildSearchIndex(revision: number): SearchIndex {
+  const postings = new Map<string, IndexedEntry[]>()
+  const entries = getLibraryEntries().map((entry, order) => {
+    const fields: Record<keyof typeof FIELD_WEIGHTS, string> = {
+      title: entry.title,
+      channel: entry.channel ?? '',
+      tags: (entry.tags ?? []).join(' '),
+      fileName: entry.filePath ? basename(entry.filePath) : '',
+      videoId: entry.videoId ?? '',
+    }
+    const indexed: IndexedEntry = { entry, order, length: 0, termWeights: new Map() }
+    for (const [field, weight] of Object.entries(FIELD_WEIGHTS)) {
+      for (const term of tokenizeSearchText(fields[field as keyof typeof FIELD_WEIGHTS])) {
+        indexed.termWeights.set(term, (indexed.termWeights.get(term) ?? 0) + weight)
+        indexed.length += weight
+      }
+    }
+    for (const term of indexed.termWeights.keys()) {
+      const list = postings.get(term)
+      if (list) {
+        list.push(indexed)
+      } else {
+        postings.set(term, [indexed])
+      }
+    }
+    return indexed
+  })
+
+  const totalLength = entries.reduce((sum, indexed) => sum + indexed.length, 0)
+  return { revision, entries, postings, averageLength: entries.length > 0 ? totalLength / entries.length : 0 }
+}
+
+function getSearchIndex(): SearchIndex {
+  const revision = getStorageRevision()
+  if (!searchIndex || searchIndex.revision !== revision) {
+    searchIndex = buildSearchIndex(revision)
+  }
+  return searchIndex
+}
+
+/**
+ * The query's terms, or null when a word of it has nothing the index can look up
+ */
+export function parseSearchQuery(query: string): QueryTerm[] | null {
+  const words = query.trim().split(/\s+/).filter(Boolean)
+  const endsInSpace = /\s$/.test(query)
+  const terms: QueryTerm[] = []
+  for (const [i, word] of words.entries()) {
+    const tokens = tokenizeSearchText(word)
+    if (tokens.length === 0) {
+      return null
+    }
+    // 'foo-bar' is two words in the index, so it is two terms here
+    tokens.forEach((text, j) => {
+      const last = j === tokens.length - 1
+      terms.push({ text, prefix: last && (word.endsWith('*') || (i === words.length - 1 && !endsInSpace)) })
+    })
+  }
+  return terms
+}
+
+/**
+ * Indexed words a query term matches: itself, or every word it starts when it is a prefix
+ */
+function expandTerm(index: SearchIndex, term: QueryTerm): string[] {
+  if (!term.prefix) {
+    return index.postings.has(term.text) ? [term.text] : []
+  }
+  return Array.from(index.postings.keys()).filter(word => word.startsWith(term.text))
+}
+
+function bm25(index: SearchIndex, word: string, indexed: IndexedEntry): number {
+  const weight = indexed.termWeights.get(word) ?? 0
+  if (weight === 0) {
+    return 0
+  }
+  const documentFrequency = index.postings.get(word)!.length
+  const idf = Math.log(1 + (index.entries.length - documentFrequency + 0.5) / (documentFrequency + 0.5))
+  const lengthRatio = index.averageLength > 0 ? indexed.length / index.averageLength : 1
+  return (idf * weight * (BM25_K1 + 1)) / (weight + BM25_K1 * (1 - BM25_B + BM25_B * lengthRatio))
+}
+
+/**
+ * Entries containing every word of the query in their title, channel, tags, URL or file
+ * name, ignoring case; the search used when the query can't go through the index
+ */
+function substringSearch(query: string): DownloadProgress[] {
+  const words = query.toLowerCase().split(/\s+/).filter(Boolean)
+  return getLibraryEntries().filter(download => {
+    const fileName = download.filePath ? basename(download.filePath) : ''
+    const text = [download.title, download.channel ?? '', ...(download.tags ?? []), download.url, fileName]
+      .join('\n')
+      .toLowerCase()
+    return words.every(word => text.includes(word))
+  })
+}
+
+/**
+ * Library entries matching every word of the query, best match first. An empty query
+ * matches everything, newest first.
+ */
+export function searchLibraryEntries(query: string, limit?: number): DownloadProgress[] {
+  const terms = parseSearchQuery(query)
+  if (terms === null) {
+    return substringSearch(query).slice(0, limit)
+  }
+  const index = getSearchIndex()
+  if (terms.length === 0) {
+    return index.entries.slice(0, limit).map(indexed => indexed.entry)
+  }
+
+  // Entries that matched every term so far, with their score; a prefix term scores by its best word
+  let scores = new Map<IndexedEntry, number>()
+  for (const [i, term] of terms.entries()) {
+    const termScores = new Map<IndexedEntry, number>()
+    for (const word of expandTerm(index, term)) {
+      for (const indexed of index.postings.get(word)!) {
+        if (i === 0 || scores.has(indexed)) {
+          termScores.set(indexed, Math.max(termScores.get(indexed) ?? 0, bm25(index, word, indexed)))
+        }
+      }
+    }
+    termScores.forEach((score, indexed) => termScores.set(indexed, score + (scores.get(indexed) ?? 0)))
+    scores = termScores
+  }
+
+  return Array.from(scores.entries())
+    .sort(([a, scoreA], [b, scoreB]) => scoreB - scoreA || a.order - b.order)
+    .slice(0, limit)
+    .map(([indexed]) => indexed.entry)
+}
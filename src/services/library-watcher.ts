@@ -0,0 +1,164 @@
+/**
+ * Library Watcher
+ * Watches the download folder and imports video files other apps drop into it, so they
+ * show up in the library without a manual import. Enabled by download.watchDownloadFolder.
+ *
+ * - A file is imported once it has stopped changing for IMPORT_DEBOUNCE, so half-copied
+ *   files aren't probed
+ * - Intermediate files (yt-dlp format parts, merge temps, hidden partial exports) are ignored
+ * - While Clipy's own downloads are running, imports wait: finished downloads are added to
+ *   the library by the download manager, and the watcher then sees them as already present
+ * - A library file that changes on disk has its cached thumbnails/waveforms invalidated
+ */
+
+import { EventEmitter } from 'events'
+import { FSWatcher, existsSync, statSync, watch } from 'fs'
+import { basename, extname, join, resolve } from 'path'
+
+import { ConfigManager } from '../utils/config'
+import { Logger } from '../utils/logger'
+import { DownloadManager } from './download-manager'
+import { enrichAndRegister, isFileInLibrary } from './library'
+import { invalidateMediaCache } from './media-cache'
+
+const logger = Logger.getInstance()
+
+const IMPORT_DEBOUNCE = 1000 // ms without changes before a new file is imported
+
+const VIDEO_EXTENSIONS = ['.mp4', '.webm', '.mkv', '.mov', '.avi', '.m4v', '.wmv', '.flv', '.ts', '.mpg', '.mpeg']
+
+/** yt-dlp format parts (name.f137.mp4) and merge temps (name.temp.mp4) */
+const INTERMEDIATE_FILE = /\.(f\d+|temp)\.\w+$/i
+
+interface LibraryWatcherState {
+  watcher: FSWatcher | null
+  folderPath: string | null
+  pending: Map<string, { timer: NodeJS.Timeout; size: number }>
+}
+
+const state: LibraryWatcherState = {
+  watcher: null,
+  folderPath: null,
+  pending: new Map(),
+}
+
+/** Emits 'video-added' with the new library entry */
+export const libraryWatcherEvents = new EventEmitter()
+
+function isWatchedVideo(filePath: string): boolean {
+  const name = basename(filePath)
+  return (
+    !name.startsWith('.') && VIDEO_EXTENSIONS.includes(extname(name).toLowerCase()) && !INTERMEDIATE_FILE.test(name)
+  )
+}
+
+function scheduleImport(filePath: string): void {
+  const previous = state.pending.get(filePath)
+  if (previous) clearTimeout(previous.timer)
+
+  let size: number
+  try {
+    size = statSync(filePath).size
+  } catch {
+    state.pending.delete(filePath) // Deleted or renamed away again
+    return
+  }
+
+  const timer = setTimeout(() => {
+    tryImport(filePath, size).catch(error => {
+      logger.warn('Failed to import watched file', { filePath, error: (error as Error).message })
+    })
+  }, IMPORT_DEBOUNCE)
+  state.pending.set(filePath, { timer, size })
+}
+
+async function tryImport(filePath: string, scheduledSize: number): Promise<void> {
+  if (!existsSync(filePath)) {
+    state.pending.delete(filePath)
+    return
+  }
+
+  // Still being written, or our own downloads are running: check again later
+  if (statSync(filePath).size !== scheduledSize || DownloadManager.getInstance().getStats().active > 0) {
+    scheduleImport(filePath)
+    return
+  }
+
+  state.pending.delete(filePath)
+  const entry = await enrichAndRegister(filePath)
+  if (entry) {
+    logger.info('Imported video dropped into the download folder', { filePath })
+    libraryWatcherEvents.emit('video-added', entry)
+  }
+}
+
+/**
+ * Watch a folder (recursively) for new video files. Replaces any previous watch.
+ */
+export function startLibraryWatcher(folderPath: string): void {
+  const target = resolve(folderPath)
+  if (state.watcher && state.folderPath === target) {
+    return
+  }
+  stopLibraryWatcher()
+
+  const watcher = watch(target, { recursive: true }, (_eventType, filename) => {
+    if (!filename) return
+    const filePath = join(target, filename.toString())
+    if (!isWatchedVideo(filePath)) return
+
+    if (isFileInLibrary(filePath)) {
+      // Replaced or re-trimmed in place: previews made from the old content are stale
+      invalidateMediaCache(filePath)
+      return
+    }
+    scheduleImport(filePath)
+  })
+  watcher.on('error', error => {
+    logger.warn('Library watcher failed', { folderPath: target, error: error.message })
+    stopLibraryWatcher()
+  })
+
+  state.watcher = watcher
+  state.folderPath = target
+  logger.info('Watching download folder for new videos', { folderPath: target })
+}
+
+export function stopLibraryWatcher(): void {
+  for (const { timer } of state.pending.values()) {
+    clearTimeout(timer)
+  }
+  state.pending.clear()
+
+  if (state.watcher) {
+    state.watcher.close()
+    logger.info('Stopped watching download folder', { folderPath: state.folderPath })
+  }
+  state.watcher = null
+  state.folderPath = null
+}
+
+export function getWatchedFolder(): string | null {
+  return state.folderPath
+}
+
+/**
+ * Start or stop watching to match the current settings (download.watchDownloadFolder, downloadPath)
+ */
+export function syncLibraryWatcher(): void {
+  const { watchDownloadFolder, downloadPath } = ConfigManager.getInstance().get('download')
+  if (!watchDownloadFolder) {
+    stopLibraryWatcher()
+    return
+  }
+
+  try {
+    if (!existsSync(downloadPath)) {
+      stopLibraryWatcher()
+      return
+    }
+    startLibraryWatcher(downloadPath)
+  } catch (error) {
+    logger.warn('Failed to watch download folder', { downloadPath, error: (error as Error).message })
+  }
+}
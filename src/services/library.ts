@@ -0,0 +1,514 @@
+/**
+ * Library Service
+ * Views over the download history: the "library" is every completed download
+ * in downloads.json. Provides spreadsheet-friendly CSV export and import, and
+ * import of single video files that didn't come from a download.
+ *
+ * Every path that puts new media into the library (download completion, imports, exports,
+ * conversions) goes through enrichAndRegister or enrichLibraryEntry, which probe the file
+ * for what the entry is missing and emit 'video-updated' with the finished entry.
+ */
+
+import { EventEmitter } from 'events'
+import { existsSync, readFileSync, statSync, writeFileSync } from 'fs'
+import { basename, dirname, extname, isAbsolute, join } from 'path'
+
+import type { DownloadProgress, LibraryFormatSummary, LibraryImportResult } from '../types/download'
+import { isCompletedStatus } from '../types/download'
+import { Logger } from '../utils/logger'
+import { assertSiteAllowed } from '../utils/site-policy'
+import {
+  addDownloadsToStorage,
+  getStoredDownloadById,
+  getStoredDownloadByPath,
+  getStoredDownloads,
+  updateDownloadInStorage,
+} from './download-storage'
+import { downloadThumbnail, downloadThumbnailOnly } from './downloader/yt-dlp-provider'
+import { getMediaArtifactDir, getMediaFingerprint } from './media-cache'
+import { VideoProcessor } from './video-processor'
+
+const logger = Logger.getInstance()
+
+/** Emits 'video-updated' (DownloadProgress) once a new library entry is complete */
+export const libraryEvents = new EventEmitter()
+
+export const LIBRARY_CSV_COLUMNS = [
+  'id',
+  'video_id',
+  'title',
+  'channel',
+  'duration_seconds',
+  'file_path',
+  'file_size_bytes',
+  'format',
+  'resolution',
+  'downloaded_at',
+  'source_url',
+] as const
+
+const UTF8_BOM = '\uFEFF'
+
+const THUMBNAIL_POSITION = 0.1 // Fraction of the duration the generated thumbnail is taken at
+
+/** What's already known about a file entering the library; overrides what's probed */
+export type LibraryVideoSeed = Partial<
+  Pick<DownloadProgress, 'url' | 'title' | 'channel' | 'tags' | 'parentId' | 'resolution'>
+>
+
+/**
+ * Extract the YouTube video ID from a watch/short/embed URL
+ */
+export function getVideoIdFromUrl(url: string): string | null {
+  const match = url.match(/(?:[?&]v=|youtu\.be\/|\/embed\/|\/shorts\/|\/v\/)([\w-]{11})/)
+  return match ? match[1] : null
+}
+
+/**
+ * Completed downloads, newest first
+ */
+export function getLibraryEntries(): DownloadProgress[] {
+  return getStoredDownloads()
+    .filter(download => isCompletedStatus(download.status))
+    .sort((a, b) => (b.completedAt ?? b.startTime) - (a.completedAt ?? a.startTime))
+}
+
+/**
+ * Library entry whose file is at this path
+ */
+export function getLibraryEntryByPath(filePath: string): DownloadProgress | null {
+  const download = getStoredDownloadByPath(filePath)
+  return download && isCompletedStatus(download.status) ? download : null
+}
+
+/**
+ * Newest library entry downloaded from this URL, matched by YouTube video ID when the
+ * URL has one (so youtu.be, shorts and watch links of the same video all match)
+ */
+export function getLibraryEntryByUrl(url: string): DownloadProgress | null {
+  const videoId = getVideoIdFromUrl(url)
+  const wanted = url.trim()
+  return (
+    getLibraryEntries().find(download =>
+      videoId ? (download.videoId ?? getVideoIdFromUrl(download.url)) === videoId : download.url === wanted,
+    ) ?? null
+  )
+}
+
+/**
+ * Container format of a library entry: the recorded format, else the file extension
+ */
+export function getEntryFormat(download: DownloadProgress): string {
+  const format = download.format || (download.filePath ? extname(download.filePath).slice(1) : '')
+  return format.toLowerCase() || 'unknown'
+}
+
+/**
+ * Count and total size of library videos per format, largest total first
+ */
+export function getLibraryFormats(): LibraryFormatSummary[] {
+  const summaries = new Map<string, LibraryFormatSummary>()
+  for (const download of getLibraryEntries()) {
+    const format = getEntryFormat(download)
+    const summary = summaries.get(format) ?? { format, count: 0, totalSize: 0 }
+    summary.count++
+    summary.totalSize += getFileSize(download) ?? 0
+    summaries.set(format, summary)
+  }
+  return Array.from(summaries.values()).sort((a, b) => b.totalSize - a.totalSize || b.count - a.count)
+}
+
+/**
+ * Library videos in the given format (case-insensitive, leading dot optional), newest first
+ */
+export function getLibraryEntriesByFormat(format: string): DownloadProgress[] {
+  const wanted = format.trim().replace(/^\./, '').toLowerCase()
+  return getLibraryEntries().filter(download => getEntryFormat(download) === wanted)
+}
+
+/**
+ * Quote a CSV field if it contains a delimiter, quote or line break (RFC 4180)
+ */
+function escapeCsvField(value: string | number | undefined): string {
+  if (value === undefined || value === null) return ''
+  const text = String(value)
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text
+}
+
+/**
+ * Size of a library entry's file on disk, else the size reported during download
+ */
+export function getFileSize(download: DownloadProgress): number | undefined {
+  if (download.filePath && existsSync(download.filePath)) {
+    try {
+      return statSync(download.filePath).size
+    } catch {
+      // Fall back to the size reported during download
+    }
+  }
+  return download.totalBytes || undefined
+}
+
+function toCsvRow(download: DownloadProgress): string {
+  const fields = [
+    download.downloadId,
+    download.videoId ?? getVideoIdFromUrl(download.url) ?? '',
+    download.title,
+    download.channel,
+    download.duration !== undefined ? Math.round(download.duration) : undefined,
+    download.filePath,
+    getFileSize(download),
+    download.format,
+    download.resolution,
+    new Date(download.completedAt ?? download.startTime).toISOString(),
+    download.url,
+  ]
+  return fields.map(escapeCsvField).join(',')
+}
+
+/**
+ * Build a UTF-8 (with BOM, so Excel detects the encoding) CSV of the library.
+ * If videoIds is given, only rows for those videos are included.
+ */
+export function exportLibraryCsv(videoIds?: string[]): string {
+  let entries = getLibraryEntries()
+  if (videoIds && videoIds.length > 0) {
+    const wanted = new Set(videoIds)
+    entries = entries.filter(download => wanted.has(download.videoId ?? getVideoIdFromUrl(download.url) ?? ''))
+  }
+
+  const lines = [LIBRARY_CSV_COLUMNS.join(','), ...entries.map(toCsvRow)]
+  return UTF8_BOM + lines.join('\r\n') + '\r\n'
+}
+
+/**
+ * Write the library CSV to a file. Returns the number of bytes written.
+ */
+export function exportLibraryCsvToFile(outputPath: string, videoIds?: string[]): number {
+  const data = Buffer.from(exportLibraryCsv(videoIds), 'utf8')
+  writeFileSync(outputPath, data)
+  return data.length
+}
+
+/**
+ * Human readable size in yt-dlp's style, e.g. '12.34MiB'
+ */
+function formatBytes(bytes: number): string {
+  const units = ['B', 'KiB', 'MiB', 'GiB', 'TiB']
+  let value = bytes
+  let unit = 0
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024
+    unit++
+  }
+  return unit === 0 ? `${value} B` : `${value.toFixed(2)}${units[unit]}`
+}
+
+function generateImportId(): string {
+  return `dl_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`
+}
+
+/**
+ * Whether a file is already in the library (or download history) under this path
+ */
+export function isFileInLibrary(filePath: string): boolean {
+  return getStoredDownloadByPath(filePath) !== undefined
+}
+
+/**
+ * Fill in what a library entry doesn't know yet about its file: duration and resolution
+ * from ffprobe, the content fingerprint, and a thumbnail taken at 10% of the video when
+ * it has none. Each step is best-effort; what fails stays unset.
+ */
+async function probeLibraryFile(
+  filePath: string,
+  known: Partial<DownloadProgress>,
+): Promise<Partial<DownloadProgress>> {
+  const processor = VideoProcessor.getInstance()
+  const updates: Partial<DownloadProgress> = {}
+
+  const metadata = await processor.getVideoMetadata(filePath).catch(() => null)
+  const hasDimensions = metadata !== null && metadata.width > 0 && metadata.height > 0
+  if (!known.duration && metadata?.duration) {
+    updates.duration = metadata.duration
+  }
+  if (!known.resolution && hasDimensions) {
+    updates.resolution = `${metadata.width}x${metadata.height}`
+  }
+
+  try {
+    updates.fingerprint = getMediaFingerprint(filePath)
+  } catch (error) {
+    logger.debug('Failed to fingerprint library file', { filePath, error: (error as Error).message })
+  }
+
+  const hasThumbnail = [known.thumbnailPath, known.cachedThumbnailPath].some(path => path && existsSync(path))
+  if (!hasThumbnail && hasDimensions) {
+    try {
+      const thumbnailPath = join(getMediaArtifactDir(filePath), 'library-thumbnail.jpg')
+      const time = (metadata.duration || 0) * THUMBNAIL_POSITION
+      updates.cachedThumbnailPath = await processor.generatePreview(filePath, time, thumbnailPath)
+    } catch (error) {
+      logger.debug('Failed to generate library thumbnail', { filePath, error: (error as Error).message })
+    }
+  }
+  return updates
+}
+
+/**
+ * Complete an entry that is already in the library (a finished download) from its file
+ * and announce it. Resolves null if the entry or its file is gone.
+ */
+export async function enrichLibraryEntry(downloadId: string): Promise<DownloadProgress | null> {
+  const entry = getStoredDownloadById(downloadId)
+  if (!entry?.filePath || !existsSync(entry.filePath)) {
+    return null
+  }
+
+  const updates = await probeLibraryFile(entry.filePath, entry)
+  updateDownloadInStorage(downloadId, updates)
+  const enriched = getStoredDownloadById(downloadId) ?? { ...entry, ...updates }
+  libraryEvents.emit('video-updated', enriched)
+  return enriched
+}
+
+/**
+ * Add a local media file to the library, completed from the file (see probeLibraryFile),
+ * and announce it. The seed (e.g. the source URL and title of a converted copy) overrides
+ * what's derived from the file. Returns null if the file is already in the library.
+ */
+export async function enrichAndRegister(
+  filePath: string,
+  seed: LibraryVideoSeed = {},
+): Promise<DownloadProgress | null> {
+  if (isFileInLibrary(filePath)) {
+    return null
+  }
+
+  const stats = statSync(filePath)
+  const updates = await probeLibraryFile(filePath, seed)
+  const seeded = Object.fromEntries(Object.entries(seed).filter(([, value]) => value !== undefined))
+
+  const entry: DownloadProgress = {
+    downloadId: generateImportId(),
+    url: '',
+    title: basename(filePath, extname(filePath)),
+    progress: 100,
+    speed: '0 B/s',
+    eta: '--:--',
+    size: formatBytes(stats.size),
+    downloadedBytes: stats.size,
+    totalBytes: stats.size,
+    status: 'completed',
+    filePath,
+    startTime: stats.mtimeMs,
+    completedAt: Date.now(),
+    retryCount: 0,
+    format: extname(filePath).slice(1).toLowerCase() || undefined,
+    ...updates,
+    ...seeded,
+  }
+
+  addDownloadsToStorage([entry])
+  libraryEvents.emit('video-updated', entry)
+  return entry
+}
+
+/**
+ * Save a thumbnail next to every library video that has none: from the recorded thumbnail
+ * URL when there is one, else through yt-dlp from the source URL. Imported files without a
+ * source URL, and videos from sites the site policy blocks, are skipped. Returns the number
+ * of thumbnails saved.
+ */
+export async function downloadMissingThumbnails(): Promise<number> {
+  const missing = getLibraryEntries().filter(
+    entry =>
+      entry.filePath &&
+      existsSync(entry.filePath) &&
+      !(entry.thumbnailPath && existsSync(entry.thumbnailPath)) &&
+      (entry.thumbnailUrl || /^https?:\/\//.test(entry.url)),
+  )
+
+  let saved = 0
+  for (const entry of missing) {
+    const filePath = entry.filePath!
+    const outputPath = join(dirname(filePath), `${basename(filePath, extname(filePath))}_thumbnail.jpg`)
+    try {
+      assertSiteAllowed(entry.url)
+      const thumbnailPath = entry.thumbnailUrl
+        ? await downloadThumbnail(entry.thumbnailUrl, outputPath)
+        : await downloadThumbnailOnly(entry.url, outputPath)
+      if (!thumbnailPath) continue
+
+      updateDownloadInStorage(entry.downloadId, { thumbnailPath })
+      libraryEvents.emit('video-updated', getStoredDownloadById(entry.downloadId) ?? { ...entry, thumbnailPath })
+      saved++
+    } catch (error) {
+      const message = (error as Error).message
+      logger.debug('Failed to download library thumbnail', { downloadId: entry.downloadId, error: message })
+    }
+  }
+
+  logger.info('Missing library thumbnails downloaded', { saved, candidates: missing.length })
+  return saved
+}
+
+/**
+ * Parse CSV text (RFC 4180: quoted fields, escaped quotes, embedded line breaks).
+ * A leading UTF-8 BOM is ignored.
+ */
+export function parseCsv(text: string): string[][] {
+  const rows: string[][] = []
+  let row: string[] = []
+  let field = ''
+  let inQuotes = false
+  const input = text.startsWith(UTF8_BOM) ? text.slice(1) : text
+
+  for (let i = 0; i < input.length; i++) {
+    const char = input[i]
+    if (inQuotes) {
+      if (char === '"' && input[i + 1] === '"') {
+        field += '"'
+        i++
+      } else if (char === '"') {
+        inQuotes = false
+      } else {
+        field += char
+      }
+    } else if (char === '"') {
+      inQuotes = true
+    } else if (char === ',') {
+      row.push(field)
+      field = ''
+    } else if (char === '\n' || char === '\r') {
+      if (char === '\r' && input[i + 1] === '\n') i++
+      row.push(field)
+      rows.push(row)
+      row = []
+      field = ''
+    } else {
+      field += char
+    }
+  }
+
+  if (field !== '' || row.length > 0) {
+    row.push(field)
+    rows.push(row)
+  }
+  return rows
+}
+
+/**
+ * Import library rows from a CSV produced by exportLibraryCsv (or edited in a spreadsheet).
+ * Columns are matched by header name, so order doesn't matter; only file_path is required.
+ * Rows already in the library (same video_id and file_path) are skipped. Rows whose file
+ * doesn't exist are skipped when skipMissingFiles is set, otherwise reported as errors.
+ */
+export function importLibraryCsv(csvPath: string, skipMissingFiles: boolean): LibraryImportResult {
+  const rows = parseCsv(readFileSync(csvPath, 'utf8'))
+  const result: LibraryImportResult = { imported: 0, skipped: 0, errors: [] }
+  if (rows.length === 0) {
+    throw new Error('CSV file is empty')
+  }
+
+  const header = rows[0].map(column => column.trim().toLowerCase())
+  const columnIndex = new Map(header.map((column, index) => [column, index]))
+  if (!columnIndex.has('file_path')) {
+    throw new Error('CSV is missing the required file_path column')
+  }
+
+  const dedupeKey = (videoId: string, filePath: string) => `${videoId}\0${filePath}`
+  const seen = new Set(
+    getStoredDownloads().map(download =>
+      dedupeKey(download.videoId ?? getVideoIdFromUrl(download.url) ?? '', download.filePath ?? ''),
+    ),
+  )
+  const existingIds = new Set(getStoredDownloads().map(download => download.downloadId))
+  const toImport: DownloadProgress[] = []
+
+  for (let i = 1; i < rows.length; i++) {
+    const rowNumber = i + 1 // 1-based with the header as row 1; a quoted line break doesn't start a new row
+    const cells = rows[i]
+    if (cells.every(cell => cell.trim() === '')) continue
+
+    const get = (column: string) => {
+      const index = columnIndex.get(column)
+      return index === undefined ? '' : (cells[index] ?? '').trim()
+    }
+
+    try {
+      const filePath = get('file_path')
+      if (!filePath || !isAbsolute(filePath)) {
+        throw new Error('file_path must be an absolute path')
+      }
+      if (!existsSync(filePath)) {
+        if (skipMissingFiles) {
+          result.skipped++
+          continue
+        }
+        throw new Error(`File not found: ${filePath}`)
+      }
+
+      const sourceUrl = get('source_url')
+      const videoId = get('video_id') || getVideoIdFromUrl(sourceUrl) || ''
+      const key = dedupeKey(videoId, filePath)
+      if (seen.has(key)) {
+        result.skipped++
+        continue
+      }
+
+      const toNumber = (column: string) => {
+        const value = get(column)
+        if (!value) return undefined
+        const number = Number(value)
+        if (!isFinite(number) || number < 0) {
+          throw new Error(`Invalid ${column}: ${value}`)
+        }
+        return number
+      }
+      const duration = toNumber('duration_seconds')
+      const fileSize = toNumber('file_size_bytes') ?? statSync(filePath).size
+
+      const downloadedAtText = get('downloaded_at')
+      const downloadedAt = downloadedAtText ? Date.parse(downloadedAtText) : statSync(filePath).mtimeMs
+      if (isNaN(downloadedAt)) {
+        throw new Error(`Invalid downloaded_at: ${downloadedAtText}`)
+      }
+
+      let downloadId = get('id')
+      if (!downloadId || existingIds.has(downloadId)) {
+        downloadId = generateImportId()
+      }
+
+      toImport.push({
+        downloadId,
+        url: sourceUrl,
+        title: get('title') || basename(filePath, extname(filePath)),
+        progress: 100,
+        speed: '0 B/s',
+        eta: '--:--',
+        size: formatBytes(fileSize),
+        downloadedBytes: fileSize,
+        totalBytes: fileSize,
+        status: 'completed',
+        filePath,
+        startTime: downloadedAt,
+        completedAt: downloadedAt,
+        retryCount: 0,
+        videoId: videoId || undefined,
+        channel: get('channel') || undefined,
+        duration,
+        format: get('format') || extname(filePath).slice(1).toLowerCase() || undefined,
+        resolution: get('resolution') || undefined,
+      })
+      seen.add(key)
+      existingIds.add(downloadId)
+      result.imported++
+    } catch (error) {
+      result.errors.push({ row: rowNumber, message: (error as Error).message })
+    }
+  }
+
+  addDownloadsToStorage(toImport)
+  return result
+}
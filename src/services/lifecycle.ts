@@ -0,0 +1,89 @@
+/**
+ * App Lifecycle
+ * Orderly exit, also when the OS logs off or shuts down while downloads run. The first quit
+ * request is held back while the shutdown steps run in order:
+ * - 'shutdown-in-progress' tells the windows that Clipy is finishing up
+ * - every download is paused with its partial files saved, so it resumes on the next launch;
+ *   one that doesn't stop in time is cancelled, so no yt-dlp is left running
+ * - the library and other state is written to disk
+ * Then the app exits. Every step has its own timeout, so Clipy never holds up a logoff and a
+ * step that hangs can't keep the later ones (e.g. saving the library) from running; a step
+ * that fails or times out is logged and the next one still runs.
+ *
+ * The OS hooks in main.ts only call requestShutdown() and quit once it resolves.
+ */
+
+import { Logger } from '../utils/logger'
+
+const logger = Logger.getInstance()
+
+export const SHUTDOWN_TIMEOUT = 10 * 1000 // ms the slowest step (pausing downloads) gets
+const STEP_TIMEOUT = 2000 // ms any other step gets
+
+export interface ShutdownStep {
+  name: string
+  run: () => void | Promise<void>
+  timeoutMs?: number // Defaults to STEP_TIMEOUT
+}
+
+export interface ShutdownResult {
+  timedOutSteps: string[]
+  failedSteps: string[]
+}
+
+let shutdown: Promise<ShutdownResult> | null = null
+
+/**
+ * Run one step, resolving true if it was given up on after its timeout
+ */
+async function runStep(step: ShutdownStep): Promise<boolean> {
+  const timeoutMs = step.timeoutMs ?? STEP_TIMEOUT
+  let timer: NodeJS.Timeout | null = null
+  const timeout = new Promise<boolean>(resolve => {
+    timer = setTimeout(() => resolve(true), timeoutMs)
+  })
+
+  try {
+    const timedOut = await Promise.race([Promise.resolve(step.run()).then(() => false), timeout])
+    if (timedOut) {
+      logger.warn('Shutdown step timed out, continuing', { step: step.name, timeoutMs })
+    }
+    return timedOut
+  } finally {
+    if (timer) clearTimeout(timer)
+  }
+}
+
+/**
+ * Run the steps in order, each within its own timeout
+ */
+export async function runShutdownSteps(steps: ShutdownStep[]): Promise<ShutdownResult> {
+  const result: ShutdownResult = { timedOutSteps: [], failedSteps: [] }
+  for (const step of steps) {
+    try {
+      if (await runStep(step)) {
+        result.timedOutSteps.push(step.name)
+      }
+    } catch (error) {
+      result.failedSteps.push(step.name)
+      logger.error('Shutdown step failed', error as Error, { step: step.name })
+    }
+  }
+  return result
+}
+
+/**
+ * Start the shutdown, or join the one already running when the OS and the user both ask
+ * to quit
+ */
+export function requestShutdown(steps: ShutdownStep[]): Promise<ShutdownResult> {
+  if (!shutdown) {
+    logger.info('Shutting down', { steps: steps.map(step => step.name) })
+    shutdown = runShutdownSteps(steps)
+  }
+  return shutdown
+}
+
+export function isShuttingDown(): boolean {
+  return shutdown !== null
+}
@@ -0,0 +1,191 @@
+/**
+ * Media Cache
+ * Derived artifacts of local media files (timeline thumbnails, waveforms, preview
+ * registrations) are keyed by a content fingerprint rather than the path alone, so
+ * replacing a file with a re-trimmed version of the same name never shows stale previews.
+ *
+ * The fingerprint combines size, mtime and a hash of the first and last 64KB. It is
+ * computed once per file version and remembered in media-cache/index.json; artifacts
+ * live in media-cache/<fingerprint>/.
+ */
+
+import { createHash } from 'crypto'
+import {
+  closeSync,
+  existsSync,
+  mkdirSync,
+  openSync,
+  readFileSync,
+  readSync,
+  readdirSync,
+  rmSync,
+  statSync,
+  writeFileSync,
+} from 'fs'
+import { join, resolve } from 'path'
+
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+
+const logger = Logger.getInstance()
+
+const SAMPLE_SIZE = 64 * 1024 // Bytes hashed from each end of the file
+const INDEX_FILE = 'index.json'
+const INDEX_VERSION = 1
+
+interface FingerprintEntry {
+  size: number
+  mtimeMs: number
+  fingerprint: string
+}
+
+let index: Map<string, FingerprintEntry> | null = null
+
+export function getMediaCacheDir(): string {
+  return join(PlatformUtils.getInstance().getAppDataDir('clipy'), 'media-cache')
+}
+
+function getIndexPath(): string {
+  return join(getMediaCacheDir(), INDEX_FILE)
+}
+
+function loadIndex(): Map<string, FingerprintEntry> {
+  if (index) return index
+
+  index = new Map()
+  try {
+    if (existsSync(getIndexPath())) {
+      const data = JSON.parse(readFileSync(getIndexPath(), 'utf8'))
+      if (data.version === INDEX_VERSION && data.entries && typeof data.entries === 'object') {
+        index = new Map(Object.entries(data.entries as Record<string, FingerprintEntry>))
+      }
+    }
+  } catch (error) {
+    // A lost index only means fingerprints get recomputed
+    logger.warn('Failed to load media cache index', { error: (error as Error).message })
+  }
+  return index
+}
+
+function saveIndex(): void {
+  try {
+    mkdirSync(getMediaCacheDir(), { recursive: true })
+    const data = { version: INDEX_VERSION, entries: Object.fromEntries(loadIndex()) }
+    writeFileSync(getIndexPath(), JSON.stringify(data), 'utf8')
+  } catch (error) {
+    logger.warn('Failed to save media cache index', { error: (error as Error).message })
+  }
+}
+
+/**
+ * Fingerprint a file from its size, mtime and the first and last 64KB of content
+ */
+export function computeMediaFingerprint(filePath: string): string {
+  const stats = statSync(filePath)
+  const hash = createHash('sha256')
+  hash.update(`${stats.size}:${Math.floor(stats.mtimeMs)}:`)
+
+  const fd = openSync(filePath, 'r')
+  try {
+    const head = Buffer.alloc(Math.min(SAMPLE_SIZE, stats.size))
+    readSync(fd, head, 0, head.length, 0)
+    hash.update(head)
+
+    if (stats.size > SAMPLE_SIZE) {
+      const tail = Buffer.alloc(Math.min(SAMPLE_SIZE, stats.size - SAMPLE_SIZE))
+      readSync(fd, tail, 0, tail.length, stats.size - tail.length)
+      hash.update(tail)
+    }
+  } finally {
+    closeSync(fd)
+  }
+
+  return hash.digest('hex').slice(0, 32)
+}
+
+/**
+ * Fingerprint of a file, reusing the indexed one while size and mtime are unchanged
+ */
+export function getMediaFingerprint(filePath: string): string {
+  const key = resolve(filePath)
+  const stats = statSync(key)
+  const entries = loadIndex()
+
+  const cached = entries.get(key)
+  if (cached && cached.size === stats.size && cached.mtimeMs === stats.mtimeMs) {
+    return cached.fingerprint
+  }
+
+  if (cached) {
+    // The file changed: its old artifacts can never be used again
+    removeArtifacts(cached.fingerprint)
+  }
+
+  const fingerprint = computeMediaFingerprint(key)
+  entries.set(key, { size: stats.size, mtimeMs: stats.mtimeMs, fingerprint })
+  saveIndex()
+  return fingerprint
+}
+
+/**
+ * Directory for a file's cached artifacts (created if needed), specific to its current content
+ */
+export function getMediaArtifactDir(filePath: string): string {
+  const directory = join(getMediaCacheDir(), getMediaFingerprint(filePath))
+  mkdirSync(directory, { recursive: true })
+  return directory
+}
+
+function removeArtifacts(fingerprint: string): void {
+  const stillUsed = Array.from(loadIndex().values()).filter(entry => entry.fingerprint === fingerprint).length > 1
+  if (!stillUsed) {
+    rmSync(join(getMediaCacheDir(), fingerprint), { recursive: true, force: true })
+  }
+}
+
+/**
+ * Drop every cached artifact for a file, e.g. after it was moved or replaced
+ */
+export function invalidateMediaCache(filePath: string): void {
+  const key = resolve(filePath)
+  const entries = loadIndex()
+  const cached = entries.get(key)
+  if (!cached) return
+
+  removeArtifacts(cached.fingerprint)
+  entries.delete(key)
+  saveIndex()
+  logger.debug('Media cache invalidated', { path: key })
+}
+
+/**
+ * Forget files that no longer exist and delete artifact directories nothing refers to.
+ * Returns the number of index entries removed.
+ */
+export function pruneMediaCache(): number {
+  const entries = loadIndex()
+  let removed = 0
+  for (const [path, entry] of Array.from(entries)) {
+    if (!existsSync(path)) {
+      removeArtifacts(entry.fingerprint)
+      entries.delete(path)
+      removed++
+    }
+  }
+
+  const cacheDir = getMediaCacheDir()
+  if (existsSync(cacheDir)) {
+    const live = new Set(Array.from(entries.values()).map(entry => entry.fingerprint))
+    for (const name of readdirSync(cacheDir)) {
+      if (name !== INDEX_FILE && !live.has(name)) {
+        rmSync(join(cacheDir, name), { recursive: true, force: true })
+      }
+    }
+  }
+
+  if (removed > 0) {
+    saveIndex()
+    logger.info('Media cache pruned', { removed })
+  }
+  return removed
+}
@@ -0,0 +1,391 @@
+/**
+ * Media Server
+ * Local HTTP server that lets the editor preview sources the <video> element can't play
+ * directly (HEVC, ProRes, MKV, AVI, ...).
+ *
+ * - Files must be registered first (library downloads or clips of open projects); a URL
+ *   never maps to an arbitrary filesystem path
+ * - Web-playable files are served as-is, with byte-range support for seeking
+ * - Other files are served from their editing transcode when one is cached, else piped
+ *   through FFmpeg as fragmented MP4: a stream-copy remux when only the container is the
+ *   problem, a fast H.264 transcode when the codec is
+ * - Entries are dropped again once their file is no longer in the library or an open project
+ * - Binds to 127.0.0.1 on a random port, and every request must carry the session token
+ */
+
+import { ChildProcess, spawn } from 'child_process'
+import { createReadStream, existsSync, statSync } from 'fs'
+import { createServer, IncomingMessage, Server, ServerResponse } from 'http'
+import { randomBytes, randomUUID } from 'crypto'
+import { extname, resolve } from 'path'
+import { URL } from 'url'
+
+import type { MediaDeliveryMode, MediaUrlInfo } from '../types/project'
+import { getMediaFingerprint } from './media-cache'
+import { findCachedTranscode } from './transcode-cache'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+
+const logger = Logger.getInstance()
+
+/** Containers and codecs Chromium's <video> element plays natively */
+const WEB_CONTAINERS = ['.mp4', '.m4v', '.webm', '.mov']
+const WEB_VIDEO_CODECS = ['h264', 'vp8', 'vp9', 'av1']
+const WEB_AUDIO_CODECS = ['aac', 'mp3', 'opus', 'vorbis', 'flac']
+
+const MIME_TYPES: Record<string, string> = {
+  '.mp4': 'video/mp4',
+  '.m4v': 'video/mp4',
+  '.mov': 'video/quicktime',
+  '.webm': 'video/webm',
+}
+
+interface MediaEntry {
+  id: string
+  path: string
+  mode: MediaDeliveryMode
+  audioNeedsTranscode: boolean
+}
+
+interface MediaServerState {
+  server: Server | null
+  port: number
+  token: string
+  entries: Map<string, MediaEntry> // id -> entry
+  idsByPath: Map<string, string> // path + content fingerprint -> id, so a replaced file is probed again
+  streams: Set<ChildProcess> // Running FFmpeg pipes
+}
+
+const state: MediaServerState = {
+  server: null,
+  port: 0,
+  token: '',
+  entries: new Map(),
+  idsByPath: new Map(),
+  streams: new Set(),
+}
+
+/**
+ * Parse a single-range 'Range: bytes=...' header against a file size.
+ * Returns null when the whole file should be sent (no header, or a multi-range request we
+ * don't support), 'unsatisfiable' for ranges outside the file, otherwise the inclusive range.
+ */
+export function parseRangeHeader(
+  header: string | undefined,
+  size: number,
+): { start: number; end: number } | 'unsatisfiable' | null {
+  if (!header) return null
+
+  const match = header.trim().match(/^bytes=(\d*)-(\d*)$/)
+  if (!match) return null // Malformed or multiple ranges: ignore the header, per RFC 7233
+
+  const [, startText, endText] = match
+  if (!startText && !endText) return null
+
+  let start: number
+  let end: number
+  if (!startText) {
+    // Suffix range: the last N bytes
+    const suffixLength = parseInt(endText, 10)
+    if (suffixLength === 0) return 'unsatisfiable'
+    start = Math.max(0, size - suffixLength)
+    end = size - 1
+  } else {
+    start = parseInt(startText, 10)
+    end = endText ? Math.min(parseInt(endText, 10), size - 1) : size - 1
+  }
+
+  if (start >= size || start > end) return 'unsatisfiable'
+  return { start, end }
+}
+
+/**
+ * Start the media server. Returns the port it listens on.
+ */
+export async function startMediaServer(): Promise<number> {
+  if (state.server) {
+    return state.port
+  }
+
+  return new Promise((resolvePort, reject) => {
+    const server = createServer(handleRequest)
+
+    server.listen(0, '127.0.0.1', () => {
+      const address = server.address()
+      if (address && typeof address === 'object') {
+        state.server = server
+        state.port = address.port
+        state.token = randomBytes(24).toString('hex')
+        logger.info('Media server started', { port: state.port })
+        resolvePort(state.port)
+      } else {
+        reject(new Error('Failed to get server address'))
+      }
+    })
+
+    server.on('error', error => {
+      logger.error('Media server error', error)
+      reject(error)
+    })
+  })
+}
+
+/**
+ * Stop the media server and any FFmpeg pipes it is running
+ */
+export async function stopMediaServer(): Promise<void> {
+  for (const ffmpeg of state.streams) {
+    ffmpeg.kill('SIGKILL')
+  }
+  state.streams.clear()
+
+  if (!state.server) {
+    return
+  }
+
+  return new Promise(resolveStop => {
+    state.server!.close(() => {
+      logger.info('Media server stopped')
+      state.server = null
+      state.port = 0
+      state.token = ''
+      state.entries.clear()
+      state.idsByPath.clear()
+      resolveStop()
+    })
+  })
+}
+
+/**
+ * Register a file and return the URL the editor should load it from.
+ * The caller is responsible for checking the file is one the renderer may access.
+ */
+export async function getMediaUrl(filePath: string): Promise<MediaUrlInfo> {
+  const resolvedPath = resolve(filePath)
+  if (!existsSync(resolvedPath)) {
+    throw new Error(`File not found: ${filePath}`)
+  }
+
+  await startMediaServer()
+
+  const cacheKey = `${resolvedPath}\0${getMediaFingerprint(resolvedPath)}`
+  let entry = state.entries.get(state.idsByPath.get(cacheKey) ?? '')
+  if (entry && !existsSync(entry.path)) {
+    // Its transcode was evicted from the cache since
+    entry = undefined
+  }
+  if (!entry) {
+    const { videoCodec, audioCodec } = await probeCodecs(resolvedPath)
+    const videoPlayable = !videoCodec || WEB_VIDEO_CODECS.includes(videoCodec)
+    const audioPlayable = !audioCodec || WEB_AUDIO_CODECS.includes(audioCodec)
+    const containerPlayable = WEB_CONTAINERS.includes(extname(resolvedPath).toLowerCase())
+
+    let mode: MediaDeliveryMode = 'direct'
+    if (!videoPlayable) {
+      mode = 'transcode'
+    } else if (!containerPlayable || !audioPlayable) {
+      mode = 'remux'
+    }
+
+    // An editing transcode plays directly and seeks by byte range, unlike a pipe
+    const transcodePath = mode === 'direct' ? null : findCachedTranscode(resolvedPath)
+    entry = transcodePath
+      ? { id: randomUUID(), path: transcodePath, mode: 'direct', audioNeedsTranscode: false }
+      : { id: randomUUID(), path: resolvedPath, mode, audioNeedsTranscode: !audioPlayable }
+    state.entries.set(entry.id, entry)
+    state.idsByPath.set(cacheKey, entry.id)
+    logger.info('Media registered for preview', {
+      path: resolvedPath,
+      mode: entry.mode,
+      videoCodec,
+      audioCodec,
+      transcodePath,
+    })
+  }
+
+  return { url: `http://127.0.0.1:${state.port}/media/${entry.id}?token=${state.token}`, mode: entry.mode }
+}
+
+/**
+ * Drop registered files for which isAllowed no longer holds, e.g. sources of a project that
+ * was closed, so their URLs stop working. Returns how many were dropped.
+ */
+export function unregisterMedia(isAllowed: (sourcePath: string) => boolean): number {
+  let removed = 0
+  for (const [cacheKey, id] of state.idsByPath) {
+    const sourcePath = cacheKey.slice(0, cacheKey.indexOf('\0'))
+    if (!isAllowed(sourcePath)) {
+      state.idsByPath.delete(cacheKey)
+      state.entries.delete(id)
+      removed++
+    }
+  }
+  if (removed > 0) {
+    logger.debug('Media unregistered from preview', { removed })
+  }
+  return removed
+}
+
+/**
+ * Route requests; everything needs a valid token and a registered media ID
+ */
+function handleRequest(req: IncomingMessage, res: ServerResponse): void {
+  const requestUrl = new URL(req.url || '/', `http://127.0.0.1:${state.port}`)
+
+  if (req.method !== 'GET' && req.method !== 'HEAD') {
+    res.writeHead(405, { Allow: 'GET, HEAD' })
+    res.end()
+    return
+  }
+
+  if (!state.token || requestUrl.searchParams.get('token') !== state.token) {
+    res.writeHead(403, { 'Content-Type': 'text/plain' })
+    res.end('Forbidden')
+    return
+  }
+
+  const match = requestUrl.pathname.match(/^\/media\/([\w-]+)$/)
+  const entry = match ? state.entries.get(match[1]) : undefined
+  if (!entry || !existsSync(entry.path)) {
+    res.writeHead(404, { 'Content-Type': 'text/plain' })
+    res.end('Not Found')
+    return
+  }
+
+  if (entry.mode === 'direct') {
+    serveFile(req, res, entry)
+  } else {
+    // t= lets the player restart the pipe at a position, since piped output can't be range-seeked
+    const startTime = Math.max(0, parseFloat(requestUrl.searchParams.get('t') || '0') || 0)
+    serveFragmentedMp4(req, res, entry, startTime)
+  }
+}
+
+/**
+ * Serve a file as-is, honouring single byte ranges
+ */
+function serveFile(req: IncomingMessage, res: ServerResponse, entry: MediaEntry): void {
+  const size = statSync(entry.path).size
+  const contentType = MIME_TYPES[extname(entry.path).toLowerCase()] ?? 'application/octet-stream'
+  const range = parseRangeHeader(req.headers.range, size)
+
+  if (range === 'unsatisfiable') {
+    res.writeHead(416, { 'Content-Range': `bytes */${size}` })
+    res.end()
+    return
+  }
+
+  const { start, end } = range ?? { start: 0, end: size - 1 }
+  const headers = {
+    'Content-Type': contentType,
+    'Content-Length': String(end - start + 1),
+    'Accept-Ranges': 'bytes',
+    'Cache-Control': 'no-store',
+  }
+  if (range) {
+    res.writeHead(206, { ...headers, 'Content-Range': `bytes ${start}-${end}/${size}` })
+  } else {
+    res.writeHead(200, headers)
+  }
+
+  if (req.method === 'HEAD' || size === 0) {
+    res.end()
+    return
+  }
+
+  const stream = createReadStream(entry.path, { start, end })
+  stream.on('error', error => {
+    logger.warn('Media file stream failed', { path: entry.path, error: error.message })
+    res.destroy()
+  })
+  res.on('close', () => stream.destroy())
+  stream.pipe(res)
+}
+
+/**
+ * Pipe the file through FFmpeg as fragmented MP4 (playable while it is being written)
+ */
+function serveFragmentedMp4(req: IncomingMessage, res: ServerResponse, entry: MediaEntry, startTime: number): void {
+  res.writeHead(200, { 'Content-Type': 'video/mp4', 'Accept-Ranges': 'none', 'Cache-Control': 'no-store' })
+  if (req.method === 'HEAD') {
+    res.end()
+    return
+  }
+
+  const transcodeAudio = entry.mode === 'transcode' || entry.audioNeedsTranscode
+  const videoArgs =
+    entry.mode === 'transcode' ? ['-c:v', 'libx264', '-preset', 'ultrafast', '-crf', '23'] : ['-c:v', 'copy']
+  const audioArgs = transcodeAudio ? ['-c:a', 'aac', '-b:a', '192k'] : ['-c:a', 'copy']
+  const args = [
+    '-v',
+    'error',
+    ...(startTime > 0 ? ['-ss', startTime.toFixed(3)] : []),
+    '-i',
+    entry.path,
+    '-map',
+    '0:v:0?',
+    '-map',
+    '0:a:0?',
+    ...videoArgs,
+    ...audioArgs,
+    '-movflags',
+    'frag_keyframe+empty_moov+default_base_moof',
+    '-f',
+    'mp4',
+    'pipe:1',
+  ]
+
+  const ffmpegPath = PlatformUtils.getInstance().resolveExecutable('ffmpeg') || 'ffmpeg'
+  const ffmpeg = spawn(ffmpegPath, args, { stdio: ['ignore', 'pipe', 'pipe'] })
+  state.streams.add(ffmpeg)
+
+  let stderr = ''
+  ffmpeg.stderr?.on('data', data => {
+    stderr = (stderr + data.toString()).slice(-2000)
+  })
+  ffmpeg.stdout?.pipe(res)
+
+  // The player drops the connection when seeking or closing the clip; stop encoding then
+  res.on('close', () => ffmpeg.kill('SIGKILL'))
+  ffmpeg.on('close', code => {
+    state.streams.delete(ffmpeg)
+    if (code !== 0 && code !== null) {
+      logger.warn('Media pipe FFmpeg exited with an error', { path: entry.path, code, stderr })
+    }
+    res.end()
+  })
+  ffmpeg.on('error', error => {
+    state.streams.delete(ffmpeg)
+    logger.error('Failed to start media pipe FFmpeg', error)
+    res.destroy()
+  })
+}
+
+/**
+ * First video and audio codec names (ffprobe codec_name), null when the stream is absent
+ */
+function probeCodecs(filePath: string): Promise<{ videoCodec: string | null; audioCodec: string | null }> {
+  return new Promise(resolveCodecs => {
+    const ffprobePath = PlatformUtils.getInstance().resolveExecutable('ffprobe') || 'ffprobe'
+    const args = ['-v', 'error', '-show_entries', 'stream=codec_type,codec_name', '-of', 'json', filePath]
+    const ffprobe = spawn(ffprobePath, args, { stdio: ['ignore', 'pipe', 'pipe'] })
+
+    let stdout = ''
+    ffprobe.stdout?.on('data', data => {
+      stdout += data.toString()
+    })
+    ffprobe.on('close', () => {
+      try {
+        const streams: { codec_type?: string; codec_name?: string }[] = JSON.parse(stdout).streams ?? []
+        resolveCodecs({
+          videoCodec: streams.find(stream => stream.codec_type === 'video')?.codec_name ?? null,
+          audioCodec: streams.find(stream => stream.codec_type === 'audio')?.codec_name ?? null,
+        })
+      } catch {
+        // Unknown streams: let the player try the file directly
+        resolveCodecs({ videoCodec: null, audioCodec: null })
+      }
+    })
+    ffprobe.on('error', () => resolveCodecs({ videoCodec: null, audioCodec: null }))
+  })
+}
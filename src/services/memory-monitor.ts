@@ -0,0 +1,51 @@
+/**
+ * Memory Monitor
+ * Checks the main process's memory every MEMORY_CHECK_INTERVAL and emits 'high-memory-warning'
+ * with HighMemoryWarning once its resident size passes advanced.maxMemoryMb, so the UI can
+ * suggest clearing the cache. It warns once per crossing: only after dropping back below
+ * REARM_FRACTION of the limit can it warn again. A limit of 0 turns the check off.
+ */
+
+import { EventEmitter } from 'events'
+
+import type { HighMemoryWarning } from '../types/system'
+import { ConfigManager } from '../utils/config'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+
+const logger = Logger.getInstance()
+
+const MEMORY_CHECK_INTERVAL = 30000 // ms
+const REARM_FRACTION = 0.9
+
+/** Emits 'high-memory-warning' with HighMemoryWarning */
+export const memoryEvents = new EventEmitter()
+
+let timer: NodeJS.Timeout | null = null
+let warned = false
+
+function checkMemory(): void {
+  const limitMb = ConfigManager.getInstance().get('advanced')?.maxMemoryMb ?? 0
+  if (!(limitMb > 0)) {
+    warned = false
+    return
+  }
+
+  const memory = PlatformUtils.getInstance().getProcessMemory()
+  const limitBytes = limitMb * 1024 * 1024
+  if (memory.rssBytes > limitBytes && !warned) {
+    warned = true
+    logger.warn('Memory use is over the limit', { rssMb: Math.round(memory.rssBytes / 1024 / 1024), limitMb })
+    const warning: HighMemoryWarning = { memory, limitMb }
+    memoryEvents.emit('high-memory-warning', warning)
+  } else if (memory.rssBytes < limitBytes * REARM_FRACTION) {
+    warned = false
+  }
+}
+
+export function startMemoryMonitor(): void {
+  if (!timer) {
+    timer = setInterval(checkMemory, MEMORY_CHECK_INTERVAL)
+    timer.unref()
+  }
+}
@@ -0,0 +1,206 @@
+/**
+ * Podcast Output
+ * Extras for audio-only downloads that are listened to in a podcast app:
+ * - writeChaptersFile: the chapters as <name>.chapters.json (Podcasting 2.0 JSON chapters)
+ *   and <name>.cue next to the audio. The chapters embedded in the file are used when it
+ *   has them, as they match the file even after SponsorBlock cut parts out; otherwise
+ *   those from the video info.
+ * - writePodcastFeed: a feed.xml in the destination folder listing its audio files, so a
+ *   podcast app can subscribe to the folder. Once a folder has Clipy's feed.xml, the feed is
+ *   regenerated after every audio download into it, with or without the option.
+ *
+ * The builders are pure; only the write functions touch the disk.
+ */
+
+import { existsSync, readFileSync, statSync, writeFileSync } from 'fs'
+import { basename, dirname, extname, join, resolve } from 'path'
+
+import type { DownloadProgress, VideoChapter } from '../types/download'
+import { getLibraryEntries } from './library'
+
+const FEED_FILE_NAME = 'feed.xml'
+const FEED_GENERATOR = 'Clipy' // Marks feeds Clipy may regenerate
+const CUE_FRAMES_PER_SECOND = 75
+
+const AUDIO_MIME_TYPES: Record<string, string> = {
+  mp3: 'audio/mpeg',
+  m4a: 'audio/mp4',
+  aac: 'audio/aac',
+  opus: 'audio/ogg',
+  ogg: 'audio/ogg',
+  flac: 'audio/flac',
+  wav: 'audio/wav',
+}
+
+export interface PodcastFeedItem {
+  title: string
+  fileName: string // Relative to the feed
+  fileSize: number // Bytes
+  duration?: number // Seconds
+  pubDate: number // ms since epoch
+  guid: string
+}
+
+export function isAudioFile(filePath: string): boolean {
+  return extname(filePath).slice(1).toLowerCase() in AUDIO_MIME_TYPES
+}
+
+/**
+ * Chapters in the Podcasting 2.0 JSON chapters format
+ */
+export function buildChaptersJson(chapters: VideoChapter[]): string {
+  const items = chapters.map(chapter => ({
+    startTime: Math.round(chapter.start * 1000) / 1000,
+    ...(chapter.end > chapter.start ? { endTime: Math.round(chapter.end * 1000) / 1000 } : {}),
+    title: chapter.title,
+  }))
+  return JSON.stringify({ version: '1.2.0', chapters: items }, null, 2)
+}
+
+/**
+ * CUE timestamp: minutes:seconds:frames, 75 frames a second. Minutes go past 99 for long files.
+ */
+export function formatCueTime(seconds: number): string {
+  const totalFrames = Math.max(0, Math.round(seconds * CUE_FRAMES_PER_SECOND))
+  const frames = totalFrames % CUE_FRAMES_PER_SECOND
+  const totalSeconds = Math.floor(totalFrames / CUE_FRAMES_PER_SECOND)
+  const pad = (value: number) => String(value).padStart(2, '0')
+  return `${pad(Math.floor(totalSeconds / 60))}:${pad(totalSeconds % 60)}:${pad(frames)}`
+}
+
+function escapeCueText(text: string): string {
+  return text.replace(/"/g, "'").replace(/[\r\n]+/g, ' ')
+}
+
+/**
+ * CUE sheet with a track per chapter for one audio file
+ */
+export function buildCueSheet(
+  chapters: VideoChapter[],
+  audioFileName: string,
+  title: string,
+  performer?: string,
+): string {
+  const fileType = extname(audioFileName).toLowerCase() === '.mp3' ? 'MP3' : 'WAVE'
+  const lines = [
+    ...(performer ? [`PERFORMER "${escapeCueText(performer)}"`] : []),
+    `TITLE "${escapeCueText(title)}"`,
+    `FILE "${escapeCueText(audioFileName)}" ${fileType}`,
+  ]
+  chapters.forEach((chapter, i) => {
+    lines.push(`  TRACK ${String(i + 1).padStart(2, '0')} AUDIO`)
+    lines.push(`    TITLE "${escapeCueText(chapter.title)}"`)
+    if (performer) {
+      lines.push(`    PERFORMER "${escapeCueText(performer)}"`)
+    }
+    lines.push(`    INDEX 01 ${formatCueTime(chapter.start)}`)
+  })
+  return `${lines.join('\n')}\n`
+}
+
+function escapeXml(value: string): string {
+  return value
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&apos;')
+}
+
+function formatFeedDuration(seconds: number): string {
+  const total = Math.round(seconds)
+  const pad = (value: number) => String(value).padStart(2, '0')
+  return `${Math.floor(total / 3600)}:${pad(Math.floor((total % 3600) / 60))}:${pad(total % 60)}`
+}
+
+/**
+ * RSS 2.0 feed (with iTunes tags) of audio files next to it, newest first
+ */
+export function buildPodcastFeed(title: string, items: PodcastFeedItem[]): string {
+  const entries = [...items]
+    .sort((a, b) => b.pubDate - a.pubDate)
+    .map(item => {
+      const type = AUDIO_MIME_TYPES[extname(item.fileName).slice(1).toLowerCase()] ?? 'audio/mpeg'
+      const url = item.fileName.split('/').map(encodeURIComponent).join('/')
+      return [
+        '    <item>',
+        `      <title>${escapeXml(item.title)}</title>`,
+        `      <enclosure url="${escapeXml(url)}" length="${item.fileSize}" type="${type}" />`,
+        `      <guid isPermaLink="false">${escapeXml(item.guid)}</guid>`,
+        `      <pubDate>${new Date(item.pubDate).toUTCString()}</pubDate>`,
+        ...(item.duration ? [`      <itunes:duration>${formatFeedDuration(item.duration)}</itunes:duration>`] : []),
+        '    </item>',
+      ].join('\n')
+    })
+
+  return [
+    '<?xml version="1.0" encoding="UTF-8"?>',
+    '<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">',
+    '  <channel>',
+    `    <title>${escapeXml(title)}</title>`,
+    `    <description>${escapeXml(title)}</description>`,
+    `    <generator>${FEED_GENERATOR}</generator>`,
+    ...entries,
+    '  </channel>',
+    '</rss>',
+    '',
+  ].join('\n')
+}
+
+/**
+ * Write the chapter files next to an audio file. Returns their paths; none without chapters.
+ */
+export function writeChapterFiles(
+  audioPath: string,
+  chapters: VideoChapter[],
+  title: string,
+  performer?: string,
+): string[] {
+  if (chapters.length === 0) {
+    return []
+  }
+  const stem = join(dirname(audioPath), basename(audioPath, extname(audioPath)))
+  const jsonPath = `${stem}.chapters.json`
+  const cuePath = `${stem}.cue`
+  writeFileSync(jsonPath, buildChaptersJson(chapters), 'utf-8')
+  writeFileSync(cuePath, buildCueSheet(chapters, basename(audioPath), title, performer), 'utf-8')
+  return [jsonPath, cuePath]
+}
+
+/**
+ * Whether the folder has a feed.xml that Clipy wrote
+ */
+export function hasPodcastFeed(folder: string): boolean {
+  const feedPath = join(folder, FEED_FILE_NAME)
+  try {
+    return existsSync(feedPath) && readFileSync(feedPath, 'utf-8').includes(`<generator>${FEED_GENERATOR}</generator>`)
+  } catch {
+    return false
+  }
+}
+
+function toFeedItem(entry: DownloadProgress): PodcastFeedItem {
+  return {
+    title: entry.title,
+    fileName: basename(entry.filePath!),
+    fileSize: statSync(entry.filePath!).size,
+    duration: entry.duration,
+    pubDate: entry.completedAt ?? entry.startTime,
+    guid: entry.videoId || entry.downloadId,
+  }
+}
+
+/**
+ * Write the folder's feed.xml from the library's audio files in it. Returns its path.
+ */
+export function writePodcastFeed(folder: string): string {
+  const target = resolve(folder)
+  const items = getLibraryEntries()
+    .filter(entry => entry.filePath && isAudioFile(entry.filePath) && resolve(dirname(entry.filePath)) === target)
+    .filter(entry => existsSync(entry.filePath!))
+    .map(toFeedItem)
+
+  const feedPath = join(target, FEED_FILE_NAME)
+  writeFileSync(feedPath, buildPodcastFeed(basename(target), items), 'utf-8')
+  return feedPath
+}
@@ -0,0 +1,147 @@
+/**
+ * Preview Frames
+ * Single frames for the editor scrubber, extracted with FFmpeg so stepping is frame-exact
+ * without decoding video in the renderer.
+ *
+ * Frames are kept in memory, least recently used dropped first once they take more than
+ * editor.previewCacheMb, keyed by file content (so a moved file keeps its frames), time
+ * rounded to FRAME_TIME_PRECISION and width. After each request the frames a second
+ * before and after are extracted in the background, so scrubbing back and forth over the
+ * same stretch doesn't wait on FFmpeg.
+ */
+
+import { randomUUID } from 'crypto'
+import { mkdirSync, readFileSync, rmSync } from 'fs'
+import { join } from 'path'
+
+import { ConfigManager } from '../utils/config'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+import { getMediaFingerprint } from './media-cache'
+import { VideoProcessor } from './video-processor'
+
+const logger = Logger.getInstance()
+
+const FRAME_TIME_PRECISION = 0.01 // Seconds; finer than a frame at 60fps
+const PREFETCH_OFFSETS = [-1, 1] // Seconds around the requested frame
+const DEFAULT_CACHE_MB = 64
+
+/**
+ * Byte-bounded least-recently-used cache. Map iteration follows insertion order, so the
+ * first key is always the least recently used one.
+ */
+export class LruCache<T extends { length: number }> {
+  private entries = new Map<string, T>()
+  private size = 0
+
+  constructor(private maxBytes: number) {}
+
+  get(key: string): T | undefined {
+    const value = this.entries.get(key)
+    if (value !== undefined) {
+      this.entries.delete(key)
+      this.entries.set(key, value)
+    }
+    return value
+  }
+
+  has(key: string): boolean {
+    return this.entries.has(key)
+  }
+
+  set(key: string, value: T): void {
+    this.delete(key)
+    if (value.length > this.maxBytes) {
+      return // Would evict everything and still not fit
+    }
+    this.entries.set(key, value)
+    this.size += value.length
+    this.evict()
+  }
+
+  delete(key: string): void {
+    const existing = this.entries.get(key)
+    if (existing !== undefined) {
+      this.entries.delete(key)
+      this.size -= existing.length
+    }
+  }
+
+  setMaxBytes(maxBytes: number): void {
+    this.maxBytes = maxBytes
+    this.evict()
+  }
+
+  private evict(): void {
+    for (const [key, value] of this.entries) {
+      if (this.size <= this.maxBytes) break
+      this.entries.delete(key)
+      this.size -= value.length
+    }
+  }
+}
+
+const frameCache = new LruCache<Buffer>(DEFAULT_CACHE_MB * 1024 * 1024)
+const pending = new Map<string, Promise<Buffer>>() // Extractions in progress, shared by equal requests
+
+function getCacheBytes(): number {
+  const megabytes = ConfigManager.getInstance().get('editor')?.previewCacheMb
+  return (Number.isFinite(megabytes) && megabytes! > 0 ? megabytes! : DEFAULT_CACHE_MB) * 1024 * 1024
+}
+
+function roundFrameTime(time: number): number {
+  return Math.max(0, Math.round(time / FRAME_TIME_PRECISION) * FRAME_TIME_PRECISION)
+}
+
+async function extractFrame(key: string, videoPath: string, time: number, maxWidth: number): Promise<Buffer> {
+  const inProgress = pending.get(key)
+  if (inProgress) {
+    return inProgress
+  }
+
+  const extraction = (async () => {
+    const tempDir = PlatformUtils.getInstance().getTempDir('clipy')
+    const framePath = join(tempDir, `preview_frame_${randomUUID()}.jpg`)
+    try {
+      mkdirSync(tempDir, { recursive: true })
+      await VideoProcessor.getInstance().extractPreviewFrame(videoPath, time, maxWidth, framePath)
+      const frame = readFileSync(framePath)
+      frameCache.setMaxBytes(getCacheBytes())
+      frameCache.set(key, frame)
+      return frame
+    } finally {
+      rmSync(framePath, { force: true })
+    }
+  })()
+  pending.set(key, extraction)
+  try {
+    return await extraction
+  } finally {
+    pending.delete(key)
+  }
+}
+
+function prefetchNeighbors(fingerprint: string, videoPath: string, time: number, maxWidth: number): void {
+  for (const offset of PREFETCH_OFFSETS) {
+    const neighbor = roundFrameTime(time + offset)
+    const key = `${fingerprint}:${neighbor.toFixed(2)}:${maxWidth}`
+    if (neighbor === time || frameCache.has(key) || pending.has(key)) continue
+    extractFrame(key, videoPath, neighbor, maxWidth).catch(error => {
+      logger.debug('Preview frame prefetch failed', { videoPath, time: neighbor, error: (error as Error).message })
+    })
+  }
+}
+
+/**
+ * JPEG of the frame at a time, at most maxWidth wide, from the cache when it has it
+ */
+export async function getPreviewFrame(videoPath: string, time: number, maxWidth: number): Promise<Buffer> {
+  const fingerprint = getMediaFingerprint(videoPath)
+  const frameTime = roundFrameTime(time)
+  const width = Math.round(maxWidth)
+  const key = `${fingerprint}:${frameTime.toFixed(2)}:${width}`
+
+  const frame = frameCache.get(key) ?? (await extractFrame(key, videoPath, frameTime, width))
+  prefetchNeighbors(fingerprint, videoPath, frameTime, width)
+  return frame
+}
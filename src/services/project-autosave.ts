@@ -0,0 +1,111 @@
+/**
+ * Project Autosave
+ * Open projects are written to <temp>/clipy/autosave_<projectId>.autosave.clipy a few
+ * seconds after each edit, so the work survives a crash even if it was never saved. An
+ * autosave is removed once the project is saved or closed; what is left over is offered
+ * for recovery, and autosaves older than a week are deleted on startup.
+ */
+
+import { existsSync, readdirSync, readFileSync, rmSync, statSync } from 'fs'
+import { join } from 'path'
+
+import type { AutosaveInfo, Project } from '../types/project'
+import { FileSystemUtils } from '../utils/file-system'
+import { Logger } from '../utils/logger'
+import { AUTOSAVE_EXTENSION, getAutosaveDir, getProjectAutosavePath } from '../utils/paths'
+
+const logger = Logger.getInstance()
+
+const AUTOSAVE_DELAY = 5000 // ms after the last edit
+const MAX_AUTOSAVE_AGE = 7 * 24 * 60 * 60 * 1000
+
+const pending = new Map<string, NodeJS.Timeout>()
+
+/**
+ * Write a project's autosave now
+ */
+export async function writeAutosave(project: Project): Promise<void> {
+  await FileSystemUtils.getInstance().writeJsonFile(getProjectAutosavePath(project.id), project)
+}
+
+/**
+ * Autosave a project once it has gone AUTOSAVE_DELAY without edits. The project is read
+ * when the timer fires, so it includes every edit made until then.
+ */
+export function scheduleAutosave(projectId: string, getProject: () => Project | null): void {
+  clearTimeout(pending.get(projectId))
+  pending.set(
+    projectId,
+    setTimeout(() => {
+      pending.delete(projectId)
+      const project = getProject()
+      if (!project) return
+      writeAutosave(project).catch(error => {
+        logger.warn('Failed to autosave project', { projectId, error: (error as Error).message })
+      })
+    }, AUTOSAVE_DELAY),
+  )
+}
+
+/**
+ * Autosaves in the temp folder, newest first. Files that can't be read are skipped.
+ */
+export function getAutosaveList(): AutosaveInfo[] {
+  const dir = getAutosaveDir()
+  if (!existsSync(dir)) {
+    return []
+  }
+
+  const autosaves: AutosaveInfo[] = []
+  for (const file of readdirSync(dir).filter(name => name.endsWith(AUTOSAVE_EXTENSION))) {
+    const path = join(dir, file)
+    try {
+      const project = JSON.parse(readFileSync(path, 'utf-8')) as Project
+      autosaves.push({
+        projectId: project.id,
+        projectName: project.name,
+        savedAt: statSync(path).mtimeMs,
+        path,
+      })
+    } catch (error) {
+      logger.debug('Skipping unreadable autosave', { path, error: (error as Error).message })
+    }
+  }
+  return autosaves.sort((a, b) => b.savedAt - a.savedAt)
+}
+
+/**
+ * Remove a project's autosave, and any autosave still waiting to be written
+ */
+export function deleteAutosave(projectId: string): void {
+  clearTimeout(pending.get(projectId))
+  pending.delete(projectId)
+  rmSync(getProjectAutosavePath(projectId), { force: true })
+}
+
+/**
+ * Delete autosaves older than a week. Returns how many were removed.
+ */
+export function pruneAutosaves(): number {
+  const dir = getAutosaveDir()
+  if (!existsSync(dir)) {
+    return 0
+  }
+
+  let removed = 0
+  for (const file of readdirSync(dir).filter(name => name.endsWith(AUTOSAVE_EXTENSION))) {
+    const path = join(dir, file)
+    try {
+      if (Date.now() - statSync(path).mtimeMs > MAX_AUTOSAVE_AGE) {
+        rmSync(path, { force: true })
+        removed++
+      }
+    } catch (error) {
+      logger.debug('Failed to check autosave age', { path, error: (error as Error).message })
+    }
+  }
+  if (removed > 0) {
+    logger.info('Old project autosaves removed', { removed })
+  }
+  return removed
+}
@@ -0,0 +1,124 @@
+/**
+ * Project Diff
+ * Compares two snapshots of a project and lists what changed: tracks and clips added or
+ * removed, and the properties that differ on the ones present in both. The editor keeps
+ * these instead of full project copies for its history, and identical snapshots can be
+ * recognized without comparing whole projects.
+ *
+ * Tracks and clips are matched by ID. modifiedAt, the derived duration and filePath are
+ * bookkeeping and never count as changes.
+ */
+
+import type { Clip, Project, ProjectDiff, ProjectDiffChange, ProjectPropertyChange, Track } from '../types/project'
+
+const IGNORED_PROJECT_KEYS = ['id', 'tracks', 'duration', 'createdAt', 'modifiedAt', 'filePath']
+const IGNORED_TRACK_KEYS = ['id', 'clips']
+const IGNORED_CLIP_KEYS = ['id']
+
+function isPlainObject(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value)
+}
+
+/**
+ * Collect the values that differ between two objects. Nested objects are compared key by
+ * key; arrays (markers) and other values are compared as a whole.
+ */
+function diffValues(
+  before: Record<string, unknown>,
+  after: Record<string, unknown>,
+  ignoredKeys: string[] = [],
+  prefix: string = '',
+  changes: ProjectPropertyChange[] = [],
+): ProjectPropertyChange[] {
+  const keys = new Set([...Object.keys(before), ...Object.keys(after)])
+  for (const key of keys) {
+    if (ignoredKeys.includes(key)) continue
+
+    const from = before[key]
+    const to = after[key]
+    const path = prefix ? `${prefix}.${key}` : key
+    if (isPlainObject(from) && isPlainObject(to)) {
+      diffValues(from, to, [], path, changes)
+    } else if (JSON.stringify(from) !== JSON.stringify(to)) {
+      changes.push({ path, from, to })
+    }
+  }
+  return changes
+}
+
+function diffClips(trackId: string, before: Clip[], after: Clip[], changes: ProjectDiffChange[]): void {
+  const beforeById = new Map(before.map(clip => [clip.id, clip]))
+  const afterIds = new Set(after.map(clip => clip.id))
+
+  for (const clip of before) {
+    if (!afterIds.has(clip.id)) {
+      changes.push({ type: 'clip-removed', trackId, clipId: clip.id })
+    }
+  }
+
+  after.forEach((clip, index) => {
+    const previous = beforeById.get(clip.id)
+    if (!previous) {
+      changes.push({ type: 'clip-added', trackId, clip, index })
+      return
+    }
+    const clipChanges = diffValues(
+      previous as unknown as Record<string, unknown>,
+      clip as unknown as Record<string, unknown>,
+      IGNORED_CLIP_KEYS,
+    )
+    if (clipChanges.length > 0) {
+      changes.push({ type: 'clip-changed', trackId, clipId: clip.id, changes: clipChanges })
+    }
+  })
+}
+
+/**
+ * Changes that turn project `before` into project `after`
+ */
+export function diffProjects(before: Project, after: Project): ProjectDiff {
+  const changes: ProjectDiffChange[] = []
+
+  const projectChanges = diffValues(
+    before as unknown as Record<string, unknown>,
+    after as unknown as Record<string, unknown>,
+    IGNORED_PROJECT_KEYS,
+  )
+  // Track order decides layering, so a reorder is a change even when every track is unchanged
+  const beforeOrder = before.tracks.map(track => track.id).filter(id => after.tracks.some(track => track.id === id))
+  const afterOrder = after.tracks.map(track => track.id).filter(id => before.tracks.some(track => track.id === id))
+  if (beforeOrder.join() !== afterOrder.join()) {
+    projectChanges.push({ path: 'trackOrder', from: beforeOrder, to: afterOrder })
+  }
+  if (projectChanges.length > 0) {
+    changes.push({ type: 'project-changed', changes: projectChanges })
+  }
+
+  const beforeTracks = new Map(before.tracks.map(track => [track.id, track]))
+  const afterIds = new Set(after.tracks.map(track => track.id))
+
+  for (const track of before.tracks) {
+    if (!afterIds.has(track.id)) {
+      changes.push({ type: 'track-removed', trackId: track.id })
+    }
+  }
+
+  after.tracks.forEach((track: Track, index) => {
+    const previous = beforeTracks.get(track.id)
+    if (!previous) {
+      changes.push({ type: 'track-added', track, index })
+      return
+    }
+    const trackChanges = diffValues(
+      previous as unknown as Record<string, unknown>,
+      track as unknown as Record<string, unknown>,
+      IGNORED_TRACK_KEYS,
+    )
+    if (trackChanges.length > 0) {
+      changes.push({ type: 'track-changed', trackId: track.id, changes: trackChanges })
+    }
+    diffClips(track.id, previous.clips, track.clips, changes)
+  })
+
+  return { identical: changes.length === 0, changes }
+}
@@ -0,0 +1,1234 @@
+/**
+ * Project Exporter Service
+ *
+ * Renders an editor project to a single file with FFmpeg:
+ * - Video tracks are composited bottom-up over a black canvas (filter_complex overlay chain)
+ * - Image clips are looped stills, optionally with a Ken Burns pan/zoom (zoompan)
+ * - Clip and track audio is trimmed, delayed to its timeline position, and mixed
+ * - Markers are written as chapters through an FFMETADATA input
+ *
+ * Audio-only exports (podcast mode) build just the audio side: clips without audio never
+ * become inputs, so no video is decoded.
+ *
+ * A project that is just one untouched clip is stream-copied (-c copy) instead, which is
+ * near-instant; anything else that changes the pixels or audio forces a re-encode.
+ *
+ * GIF exports run two passes (palettegen, then paletteuse) over the same composite;
+ * PNG sequence exports write numbered frames into the output directory.
+ *
+ * Every export but a PNG sequence comes with a report of what was written (see
+ * export-reports), which is also kept for the project.
+ *
+ * Only one export runs at a time. A batch exports several saved projects one after the
+ * other; cancelling the export cancels the rest of the batch too.
+ *
+ * The editor preview can also render the composited frame at a single timeline time to a
+ * JPEG, so what it shows matches the export frame for frame.
+ */
+
+import { ChildProcess, spawn } from 'child_process'
+import { EventEmitter } from 'events'
+import { dirname, join } from 'path'
+import { existsSync, statSync, unlinkSync, writeFileSync } from 'fs'
+
+import type {
+  BatchExportProgress,
+  Clip,
+  ExportAudioCodec,
+  ExportFormat,
+  ExportFormatInfo,
+  ExportJob,
+  ExportJobResult,
+  ExportReport,
+  ExportVideoCodec,
+  Project,
+  ProjectExportProgress,
+  ProjectExportResult,
+  ProjectExportSettings,
+  Track,
+} from '../types/project'
+import type { ProcessPriority } from '../types/system'
+import {
+  AUDIO_ENCODERS,
+  AUDIO_EXPORT_FORMATS,
+  EXPORT_FORMAT_CODECS,
+  PNG_SEQUENCE_PATTERN,
+  buildAtempoFilter,
+  buildEffectChainFilter,
+  buildGifFrameFilter,
+  buildKenBurnsFilter,
+  buildPaletteGenFilter,
+  buildPaletteUseFilter,
+  buildVideoEncoderArgs,
+  getExportIncompatibility,
+  getPassProgress,
+  selectVideoEncoder,
+} from '../utils/ffmpeg-args'
+import { FileSystemUtils } from '../utils/file-system'
+import { Logger } from '../utils/logger'
+import { renderToPartialOutput } from '../utils/output-file'
+import { PlatformUtils } from '../utils/platform'
+import {
+  applyFFmpegPriority,
+  getFFmpegPriority,
+  setProcessPriority,
+  withFFmpegThreadLimit,
+} from '../utils/process-priority'
+import { buildExportReport, recordExportReport } from './export-reports'
+import { enrichAndRegister } from './library'
+import { VideoProcessor } from './video-processor'
+
+const AUDIO_BITRATE_BY_QUALITY = { low: '128k', medium: '192k', high: '320k' } as const
+const KEYFRAME_SEARCH_WINDOW = 30 // Seconds before the in point to look for a keyframe
+const KEN_BURNS_SUPERSAMPLE = 2 // Pan/zoom over an upscaled still so the motion doesn't step in whole pixels
+
+/** ffprobe codec_name to export codec */
+const PROBED_VIDEO_CODECS: Record<string, ExportVideoCodec> = { h264: 'h264', hevc: 'h265', vp9: 'vp9', av1: 'av1' }
+const PROBED_AUDIO_CODECS: Record<string, ExportAudioCodec> = { aac: 'aac', mp3: 'mp3', opus: 'opus' }
+
+/** Source stream details that decide whether a project can be stream-copied */
+export interface StreamCopySource {
+  videoCodec: string | null // ffprobe codec_name
+  audioCodec: string | null
+  width: number
+  height: number
+  frameRate: number
+}
+
+/** A chapter derived from a marker, in timeline seconds */
+export interface ExportChapter {
+  start: number
+  end: number
+  title: string
+}
+
+/** Composited timeline: clip inputs and the filter graph ending in [videoLabel] and audioLabels */
+interface CompositeGraph {
+  inputs: string[]
+  filters: string[]
+  videoLabel: string
+  audioLabels: string[]
+  inputCount: number
+}
+
+interface StreamCopyPlan {
+  copy: { clip: Clip; start: number } | null // Null when the project has to be re-encoded
+  reencodeReasons: string[]
+}
+
+interface ActiveExport {
+  projectId: string
+  process: ChildProcess
+  cancelled: boolean
+}
+
+/**
+ * Length of a clip on the timeline, accounting for playback speed
+ */
+export function getClipTimelineDuration(clip: Clip): number {
+  return Math.max(0, clip.sourceEnd - clip.sourceStart) / (clip.properties.speed || 1)
+}
+
+/**
+ * End of the last clip across all tracks
+ */
+export function getProjectDuration(project: Project): number {
+  let duration = 0
+  for (const track of project.tracks) {
+    for (const clip of track.clips) {
+      duration = Math.max(duration, clip.startTime + getClipTimelineDuration(clip))
+    }
+  }
+  return duration
+}
+
+/**
+ * Scale a frame to fit width x height, keeping the aspect ratio, and pad the rest black
+ */
+function fitToFrame(width: number, height: number): string {
+  return `scale=${width}:${height}:force_original_aspect_ratio=decrease,pad=${width}:${height}:(ow-iw)/2:(oh-ih)/2`
+}
+
+/**
+ * Input arguments for a clip: image clips are looped for their display time
+ */
+function buildClipInput(clip: Clip, frameRate: number): string[] {
+  if (clip.type === 'image') {
+    const duration = getClipTimelineDuration(clip).toFixed(3)
+    return ['-loop', '1', '-framerate', String(frameRate), '-t', duration, '-i', clip.sourcePath]
+  }
+  return ['-i', clip.sourcePath]
+}
+
+/**
+ * Filters taking a clip's video input to a frame at project resolution starting at PTS 0
+ */
+export function buildClipVideoFilter(clip: Clip, settings: Project['settings']): string {
+  const { width, height, frameRate } = settings
+  if (clip.type !== 'image') {
+    const trim = `trim=start=${clip.sourceStart}:end=${clip.sourceEnd}`
+    return `${trim},setpts=(PTS-STARTPTS)/${clip.properties.speed || 1},${fitToFrame(width, height)}`
+  }
+
+  const { kenBurns } = clip.properties
+  if (!kenBurns) {
+    return `${fitToFrame(width, height)},setsar=1,setpts=PTS-STARTPTS`
+  }
+  const zoompan = buildKenBurnsFilter(kenBurns, width, height, frameRate, getClipTimelineDuration(clip))
+  const canvas = fitToFrame(width * KEN_BURNS_SUPERSAMPLE, height * KEN_BURNS_SUPERSAMPLE)
+  return `${canvas},${zoompan},setsar=1,setpts=PTS-STARTPTS`
+}
+
+/**
+ * Whether a clip's audio goes into the mix; its source may still turn out to have no audio
+ */
+function isClipAudible(track: Track, clip: Clip): boolean {
+  return clip.type !== 'image' && !track.muted && !clip.properties.muted
+}
+
+/**
+ * Filters taking input `index`'s audio to the clip's part, speed and volume, delayed to
+ * its timeline position
+ */
+function buildClipAudioFilter(clip: Clip, index: number, label: string): string {
+  const { speed, volume } = clip.properties
+  const delayMs = Math.round(clip.startTime * 1000)
+  return (
+    `[${index}:a]atrim=start=${clip.sourceStart}:end=${clip.sourceEnd},asetpts=PTS-STARTPTS,` +
+    `${buildAtempoFilter(speed)},volume=${volume},adelay=${delayMs}|${delayMs}[${label}]`
+  )
+}
+
+/**
+ * Build chapters from clip markers (offset by the clip position) and track markers.
+ * Each chapter runs until the next marker, the last one until the end of the project.
+ */
+export function buildChapters(project: Project, duration: number): ExportChapter[] {
+  const points: { time: number; title: string }[] = []
+
+  for (const track of project.tracks) {
+    for (const marker of track.markers) {
+      points.push({ time: marker.time, title: marker.label })
+    }
+    for (const clip of track.clips) {
+      for (const marker of clip.markers) {
+        points.push({ time: clip.startTime + marker.time, title: marker.label })
+      }
+    }
+  }
+
+  const sorted = points.filter(p => p.time >= 0 && p.time < duration).sort((a, b) => a.time - b.time)
+  return sorted.map((point, i) => ({
+    start: point.time,
+    end: i < sorted.length - 1 ? sorted[i + 1].time : duration,
+    title: point.title,
+  }))
+}
+
+/**
+ * Reasons a project has to be re-encoded; empty when it can be exported with stream copy.
+ * Only settings-level checks: whether the in point is on a keyframe needs a probe of the source.
+ */
+export function getStreamCopyBlockers(
+  project: Project,
+  settings: ProjectExportSettings,
+  source: StreamCopySource | null,
+): string[] {
+  if (settings.format === 'gif' || settings.format === 'png-sequence') {
+    return ['Image exports are always rendered']
+  }
+
+  const clips = project.tracks.flatMap(track => track.clips.map(clip => ({ clip, track })))
+  if (clips.length !== 1) {
+    return ['Project has more than one clip']
+  }
+
+  const reasons: string[] = []
+  const { clip, track } = clips[0]
+  if (track.kind !== 'video' || clip.type !== 'video') {
+    reasons.push('Clip is not a video clip')
+  }
+  if (clip.startTime > 0) {
+    reasons.push('Clip does not start at the beginning of the timeline')
+  }
+  if (clip.properties.speed !== 1) {
+    reasons.push('Clip speed is changed')
+  }
+  if (clip.properties.opacity !== 1) {
+    reasons.push('Clip opacity is changed')
+  }
+  if (clip.properties.effectChain && clip.properties.effectChain.length > 0) {
+    reasons.push('Clip has effects')
+  }
+
+  if (!source) {
+    reasons.push('Source streams could not be probed')
+    return reasons
+  }
+
+  const { width, height, frameRate } = project.settings
+  if (source.width !== width || source.height !== height) {
+    reasons.push(`Project resolution ${width}x${height} differs from the source (${source.width}x${source.height})`)
+  }
+  if (Math.abs(source.frameRate - frameRate) > 0.01) {
+    reasons.push(`Project frame rate ${frameRate} differs from the source (${source.frameRate})`)
+  }
+
+  const videoCodec = source.videoCodec ? PROBED_VIDEO_CODECS[source.videoCodec] : undefined
+  if (videoCodec !== settings.videoCodec) {
+    reasons.push(`Source video is ${source.videoCodec ?? 'missing'}, not ${settings.videoCodec}`)
+  }
+  if (source.audioCodec) {
+    const { volume, muted } = clip.properties
+    if (volume !== 1 || muted || track.muted) {
+      reasons.push('Clip audio is adjusted or muted')
+    }
+    if (PROBED_AUDIO_CODECS[source.audioCodec] !== settings.audioCodec) {
+      reasons.push(`Source audio is ${source.audioCodec}, not ${settings.audioCodec}`)
+    }
+  }
+  return reasons
+}
+
+/**
+ * Escape a value for the FFMETADATA format ('=', ';', '#', '\' and newlines)
+ */
+function escapeMetadataValue(value: string): string {
+  return value.replace(/[=;#\\\n]/g, match => `\\${match}`)
+}
+
+function createMetadataFile(chapters: ExportChapter[]): string {
+  const lines = [';FFMETADATA1']
+  for (const chapter of chapters) {
+    lines.push(
+      '[CHAPTER]',
+      'TIMEBASE=1/1000',
+      `START=${Math.round(chapter.start * 1000)}`,
+      `END=${Math.round(chapter.end * 1000)}`,
+      `title=${escapeMetadataValue(chapter.title)}`,
+    )
+  }
+  return lines.join('\n') + '\n'
+}
+
+export class ProjectExporter extends EventEmitter {
+  private static instance: ProjectExporter
+  private logger = Logger.getInstance()
+  private fileSystem = FileSystemUtils.getInstance()
+  private platform = PlatformUtils.getInstance()
+  private activeExport: ActiveExport | null = null
+  private activePreview: ActiveExport | null = null // Preview frame render; a newer request replaces it
+  private exportPriority: ProcessPriority = 'normal' // Of the running export; changes carry over to later passes
+  private activeBatch: { cancelled: boolean } | null = null
+  private encodedFrames = 0 // Last frame= of the last FFmpeg run, for the export report
+  private availableEncoders: Promise<Set<string>> | null = null
+
+  private constructor() {
+    super()
+  }
+
+  static getInstance(): ProjectExporter {
+    if (!ProjectExporter.instance) {
+      ProjectExporter.instance = new ProjectExporter()
+    }
+    return ProjectExporter.instance
+  }
+
+  isExporting(): boolean {
+    return this.activeExport !== null || this.activeBatch !== null
+  }
+
+  /**
+   * Export formats with the codecs each supports and the encoder that would be used
+   */
+  async getExportFormats(): Promise<ExportFormatInfo[]> {
+    const encoders = await this.getAvailableEncoders()
+    return (Object.keys(EXPORT_FORMAT_CODECS) as ExportFormat[]).map(format => {
+      const { label, videoCodecs, audioCodecs } = EXPORT_FORMAT_CODECS[format]
+      return {
+        format,
+        label,
+        videoCodecs: videoCodecs.map(codec => ({ codec, encoder: selectVideoEncoder(codec, encoders) })),
+        audioCodecs: audioCodecs.filter(codec => encoders.has(AUDIO_ENCODERS[codec])),
+      }
+    })
+  }
+
+  /**
+   * Render the project to settings.outputPath. Emits 'progress' with ProjectExportProgress.
+   * The render goes to a partial file that only replaces the destination on success; unless
+   * settings.overwrite is set, an existing destination is kept and a suffixed name used instead.
+   */
+  async exportProject(project: Project, settings: ProjectExportSettings): Promise<ProjectExportResult> {
+    if (!this.activeExport) {
+      this.exportPriority = getFFmpegPriority()
+    }
+    const startedAt = Date.now()
+    const { result, outputPath } = await renderToPartialOutput(
+      settings.outputPath,
+      settings.overwrite === true,
+      partialPath => this.renderProject(project, { ...settings, outputPath: partialPath }),
+    )
+    this.logger.info('Project export saved', { projectId: project.id, outputPath })
+
+    const exported: ProjectExportResult = { ...result, outputPath }
+    if (settings.format !== 'png-sequence' || settings.audioOnly) {
+      exported.report = await this.createReport(project, exported, startedAt).catch(error => {
+        this.logger.warn('Failed to create export report', { outputPath, error: (error as Error).message })
+        return undefined
+      })
+    }
+    if (settings.addToLibrary && settings.format !== 'png-sequence') {
+      const resolution = settings.audioOnly ? 'audio' : undefined
+      const entry = await enrichAndRegister(outputPath, { title: project.name, resolution }).catch(error => {
+        this.logger.warn('Failed to add export to the library', { outputPath, error: (error as Error).message })
+        return null
+      })
+      exported.libraryEntryId = entry?.downloadId
+    }
+    return exported
+  }
+
+  /**
+   * Probe the exported file and record its report. The expected duration is the project's,
+   * plus what a keyframe snap added in front.
+   */
+  private async createReport(project: Project, result: ProjectExportResult, startedAt: number): Promise<ExportReport> {
+    const finishedAt = Date.now()
+    const videoProcessor = VideoProcessor.getInstance()
+    const [metadata, streams] = await Promise.all([
+      videoProcessor.getVideoMetadata(result.outputPath),
+      videoProcessor.getVideoStreams(result.outputPath),
+    ])
+    const firstClip = project.tracks.flatMap(track => track.clips)[0]
+    const snapOffset = result.snappedStart !== undefined && firstClip ? firstClip.sourceStart - result.snappedStart : 0
+
+    const report = buildExportReport(
+      {
+        projectId: project.id,
+        result,
+        expectedDuration: getProjectDuration(project) + snapOffset,
+        startedAt,
+        finishedAt,
+        frames: this.encodedFrames,
+      },
+      { fileSize: statSync(result.outputPath).size, duration: metadata.duration, streams },
+    )
+    recordExportReport(report)
+    if (report.warnings.length > 0) {
+      this.logger.warn('Exported file looks wrong', { outputPath: result.outputPath, warnings: report.warnings })
+    }
+    return report
+  }
+
+  /**
+   * Export the jobs one at a time, in order. A failed job doesn't stop the batch; after a
+   * cancel the remaining jobs are reported as cancelled. Emits 'batch-progress' with
+   * BatchExportProgress before each job and once all are done.
+   */
+  async exportBatch(
+    jobs: ExportJob[],
+    loadProject: (filePath: string) => Promise<Project>,
+  ): Promise<ExportJobResult[]> {
+    if (this.isExporting()) {
+      throw new Error('Another export is already in progress')
+    }
+    const batch = { cancelled: false }
+    this.activeBatch = batch
+    const results: ExportJobResult[] = []
+    const emitProgress = (currentJobId: string | null) => {
+      const progress: BatchExportProgress = { completed: results.length, total: jobs.length, currentJobId }
+      this.emit('batch-progress', progress)
+    }
+
+    try {
+      for (const job of jobs) {
+        if (batch.cancelled) {
+          results.push({ jobId: job.id, success: false, error: 'Export cancelled', elapsedSeconds: 0 })
+          continue
+        }
+        emitProgress(job.id)
+        const startedAt = Date.now()
+        try {
+          const project = await loadProject(job.projectPath)
+          const { outputPath } = await this.exportProject(project, job.settings)
+          const elapsedSeconds = Math.round((Date.now() - startedAt) / 1000)
+          results.push({ jobId: job.id, success: true, outputPath, elapsedSeconds })
+        } catch (error) {
+          const elapsedSeconds = Math.round((Date.now() - startedAt) / 1000)
+          results.push({ jobId: job.id, success: false, error: (error as Error).message, elapsedSeconds })
+          this.logger.warn('Batch export job failed', { jobId: job.id, error: (error as Error).message })
+        }
+      }
+    } finally {
+      this.activeBatch = null
+    }
+    emitProgress(null)
+
+    const exported = results.filter(result => result.success).length
+    this.logger.info('Batch export finished', { total: jobs.length, exported, cancelled: batch.cancelled })
+    return results
+  }
+
+  private async renderProject(project: Project, settings: ProjectExportSettings): Promise<ProjectExportResult> {
+    if (this.activeExport) {
+      throw new Error('Another export is already in progress')
+    }
+
+    const duration = getProjectDuration(project)
+    if (duration <= 0) {
+      throw new Error('Project has no clips to export')
+    }
+
+    for (const track of project.tracks) {
+      for (const clip of track.clips) {
+        if (!existsSync(clip.sourcePath)) {
+          throw new Error(`Source file not found: ${clip.sourcePath}`)
+        }
+        if (clip.type === 'image' && getClipTimelineDuration(clip) <= 0) {
+          throw new Error(`Image clip "${clip.name}" needs a duration`)
+        }
+      }
+    }
+
+    if (settings.audioOnly) {
+      await this.exportAudio(project, settings, duration)
+      const encoder = AUDIO_EXPORT_FORMATS[settings.audioFormat ?? 'mp3']?.encoder
+      return { outputPath: settings.outputPath, method: 're-encode', encoder }
+    }
+
+    if (settings.format === 'gif' || settings.format === 'png-sequence') {
+      const outputPath = await this.exportImages(project, settings, duration)
+      return { outputPath, method: 're-encode', encoder: settings.format === 'gif' ? 'gif' : 'png' }
+    }
+
+    const incompatibility = getExportIncompatibility(settings)
+    if (incompatibility) {
+      throw new Error(incompatibility)
+    }
+
+    const plan = await this.planStreamCopy(project, settings)
+    let encoder: string | null = null
+    if (!plan.copy) {
+      encoder = selectVideoEncoder(settings.videoCodec, await this.getAvailableEncoders())
+      if (!encoder) {
+        throw new Error(`No ${settings.videoCodec.toUpperCase()} encoder is available in this FFmpeg build`)
+      }
+    }
+
+    await this.fileSystem.ensureDirectory(dirname(settings.outputPath))
+
+    const chapters = settings.includeChapters === false ? [] : buildChapters(project, duration)
+    const metadataPath = await this.writeChapterMetadata(project, chapters)
+
+    try {
+      const result: ProjectExportResult = { outputPath: settings.outputPath, method: 're-encode' }
+      if (plan.copy) {
+        const { clip, start } = plan.copy
+        const copyDuration = clip.sourceEnd - start
+        const args = this.buildStreamCopyArgs(clip.sourcePath, settings, start, copyDuration, metadataPath)
+        await this.runExport(project.id, args, copyDuration)
+        result.method = 'stream-copy'
+        if (start !== clip.sourceStart) {
+          result.snappedStart = start
+        }
+      } else if (encoder) {
+        const args = await this.buildExportArgs(project, settings, encoder, duration, metadataPath)
+        await this.runExport(project.id, args, duration)
+        result.reencodeReasons = plan.reencodeReasons
+        result.encoder = encoder
+      }
+
+      this.logger.info('Project exported', {
+        projectId: project.id,
+        outputPath: settings.outputPath,
+        chapters: chapters.length,
+        method: result.method,
+        reencodeReasons: plan.reencodeReasons,
+      })
+      return result
+    } finally {
+      if (metadataPath && existsSync(metadataPath)) {
+        unlinkSync(metadataPath)
+      }
+    }
+  }
+
+  /**
+   * FFMETADATA file with the chapters, in the temp folder; null when there are none
+   */
+  private async writeChapterMetadata(project: Project, chapters: ExportChapter[]): Promise<string | null> {
+    if (chapters.length === 0) {
+      return null
+    }
+    const metadataPath = join(this.platform.getTempDir('clipy'), `chapters_${project.id}_${Date.now()}.txt`)
+    await this.fileSystem.ensureDirectory(dirname(metadataPath))
+    writeFileSync(metadataPath, createMetadataFile(chapters), 'utf8')
+    return metadataPath
+  }
+
+  /**
+   * Audio-only export in settings.audioFormat, mixed by the same rules as a video export
+   */
+  private async exportAudio(project: Project, settings: ProjectExportSettings, duration: number): Promise<void> {
+    const format = AUDIO_EXPORT_FORMATS[settings.audioFormat ?? 'mp3']
+    if (!format) {
+      throw new Error(`Unsupported audio format: ${settings.audioFormat}`)
+    }
+    if (!(await this.getAvailableEncoders()).has(format.encoder)) {
+      throw new Error(`No ${format.label} encoder is available in this FFmpeg build`)
+    }
+
+    const { inputs, filters, audioLabels } = await this.buildAudioGraph(project)
+    if (audioLabels.length === 0) {
+      throw new Error('Project has no audio to export')
+    }
+
+    await this.fileSystem.ensureDirectory(dirname(settings.outputPath))
+    const chapters = settings.includeChapters === false ? [] : buildChapters(project, duration)
+    const metadataPath = await this.writeChapterMetadata(project, chapters)
+
+    try {
+      const args = [...inputs]
+      if (metadataPath) {
+        args.push('-f', 'ffmetadata', '-i', metadataPath)
+      }
+      filters.push(`${audioLabels.join('')}amix=inputs=${audioLabels.length}:normalize=0[aout]`)
+      args.push('-filter_complex', filters.join(';'), '-map', '[aout]', '-vn')
+      if (metadataPath) {
+        args.push('-map_metadata', String(audioLabels.length), '-map_chapters', String(audioLabels.length))
+      }
+      args.push('-c:a', format.encoder)
+      if (!format.lossless) {
+        const bitrate = settings.audioBitrate ? `${settings.audioBitrate}k` : AUDIO_BITRATE_BY_QUALITY[settings.quality]
+        args.push('-b:a', bitrate ?? '192k')
+      }
+      args.push('-t', duration.toFixed(3), '-progress', 'pipe:1', '-y', settings.outputPath)
+
+      await this.runExport(project.id, args, duration)
+      this.logger.info('Project exported as audio', {
+        projectId: project.id,
+        outputPath: settings.outputPath,
+        format: settings.audioFormat ?? 'mp3',
+        clips: audioLabels.length,
+      })
+    } finally {
+      if (metadataPath && existsSync(metadataPath)) {
+        unlinkSync(metadataPath)
+      }
+    }
+  }
+
+  /**
+   * GIF (two palette passes) or PNG sequence export. Audio and chapters don't apply.
+   */
+  private async exportImages(project: Project, settings: ProjectExportSettings, duration: number): Promise<string> {
+    const graph = await this.buildCompositeGraph(project, duration, false)
+
+    if (settings.format === 'png-sequence') {
+      await this.fileSystem.ensureDirectory(settings.outputPath)
+      const args = [
+        ...graph.inputs,
+        '-filter_complex',
+        [...graph.filters, `[${graph.videoLabel}]format=rgb24[vout]`].join(';'),
+        '-map',
+        '[vout]',
+        '-t',
+        duration.toFixed(3),
+        '-progress',
+        'pipe:1',
+        '-y',
+        join(settings.outputPath, PNG_SEQUENCE_PATTERN),
+      ]
+      await this.runExport(project.id, args, duration)
+      this.logger.info('Project exported as PNG sequence', { projectId: project.id, outputPath: settings.outputPath })
+      return settings.outputPath
+    }
+
+    await this.fileSystem.ensureDirectory(dirname(settings.outputPath))
+    const frameFilter = buildGifFrameFilter(settings.gif, project.settings.frameRate)
+    const palettePath = join(this.platform.getTempDir('clipy'), `palette_${project.id}_${Date.now()}.png`)
+    await this.fileSystem.ensureDirectory(dirname(palettePath))
+
+    try {
+      // Pass 1: the [frames] branch goes to the null muxer so -progress reports timeline position
+      // while palettegen (which only emits at the end) collects statistics
+      const paletteArgs = [
+        ...graph.inputs,
+        '-filter_complex',
+        [
+          ...graph.filters,
+          `[${graph.videoLabel}]${frameFilter},split[frames][stats]`,
+          `[stats]${buildPaletteGenFilter()}[palette]`,
+        ].join(';'),
+        '-progress',
+        'pipe:1',
+        '-map',
+        '[palette]',
+        '-y',
+        palettePath,
+        '-map',
+        '[frames]',
+        '-t',
+        duration.toFixed(3),
+        '-f',
+        'null',
+        '-',
+      ]
+      await this.runExport(project.id, paletteArgs, duration, 0, 2)
+
+      // Pass 2: map the same frames onto the palette
+      const gifArgs = [
+        ...graph.inputs,
+        '-i',
+        palettePath,
+        '-filter_complex',
+        [
+          ...graph.filters,
+          `[${graph.videoLabel}]${frameFilter}[frames]`,
+          `[frames][${graph.inputCount}:v]${buildPaletteUseFilter(settings.gif?.dither)}[vout]`,
+        ].join(';'),
+        '-map',
+        '[vout]',
+        '-t',
+        duration.toFixed(3),
+        '-progress',
+        'pipe:1',
+        '-y',
+        settings.outputPath,
+      ]
+      await this.runExport(project.id, gifArgs, duration, 1, 2)
+
+      this.logger.info('Project exported as GIF', { projectId: project.id, outputPath: settings.outputPath })
+      return settings.outputPath
+    } finally {
+      if (existsSync(palettePath)) {
+        unlinkSync(palettePath)
+      }
+    }
+  }
+
+  /**
+   * Change the priority of the running export, including passes that haven't started yet.
+   * Returns false when nothing is exporting or the OS refused (e.g. raising priority on Unix).
+   */
+  setActiveExportPriority(priority: ProcessPriority): boolean {
+    if (!this.activeExport) {
+      return false
+    }
+    this.exportPriority = priority
+    const changed = setProcessPriority(this.activeExport.process.pid, priority)
+    this.logger.info('Export priority changed', { projectId: this.activeExport.projectId, priority, changed })
+    return changed
+  }
+
+  /**
+   * Cancel the running export, if any, and the rest of its batch
+   */
+  cancelExport(): boolean {
+    if (this.activeBatch) {
+      this.activeBatch.cancelled = true
+    }
+    if (!this.activeExport) {
+      return this.activeBatch !== null
+    }
+    this.activeExport.cancelled = true
+    this.activeExport.process.kill('SIGTERM')
+    return true
+  }
+
+  /**
+   * Render the frame the export would show at a timeline time to a JPEG: every video clip
+   * showing at that moment, composited bottom-up with its opacity and effects. Starting a
+   * new preview (e.g. while scrubbing) cancels the one still rendering.
+   */
+  async renderFrame(project: Project, time: number, outputPath: string): Promise<void> {
+    this.cancelPreviewRender()
+
+    const { width, height, frameRate } = project.settings
+    const inputs: string[] = []
+    const filters: string[] = [`color=c=black:s=${width}x${height}:r=${frameRate}:d=1[base]`]
+    let videoLabel = 'base'
+    let index = 0
+
+    for (const track of project.tracks) {
+      if (track.kind !== 'video') continue
+      for (const clip of track.clips) {
+        if (clip.type === 'audio') continue // An audio file has no frame to show, even on a video track
+        const offset = time - clip.startTime
+        if (offset < 0 || offset >= getClipTimelineDuration(clip)) continue
+
+        let clipFilter: string
+        if (clip.type === 'image') {
+          // Ken Burns motion depends on the time into the clip, so render up to it
+          inputs.push(...buildClipInput(clip, frameRate))
+          const trim = `trim=start=${offset.toFixed(3)},setpts=PTS-STARTPTS`
+          clipFilter = `${buildClipVideoFilter(clip, project.settings)},${trim}`
+        } else {
+          const sourceTime = clip.sourceStart + offset * (clip.properties.speed || 1)
+          inputs.push('-ss', sourceTime.toFixed(3), '-i', clip.sourcePath)
+          clipFilter = `${fitToFrame(width, height)},setsar=1`
+        }
+        const effects = buildEffectChainFilter(clip.properties.effectChain, `c${index}`)
+        if (effects) {
+          clipFilter += `,${effects}`
+        }
+
+        const { opacity } = clip.properties
+        filters.push(`[${index}:v]${clipFilter},format=yuva420p,colorchannelmixer=aa=${opacity}[v${index}]`)
+        filters.push(`[${videoLabel}][v${index}]overlay=eof_action=repeat[o${index}]`)
+        videoLabel = `o${index}`
+        index++
+      }
+    }
+
+    const args = [...inputs, '-filter_complex', filters.join(';'), '-map', `[${videoLabel}]`, '-frames:v', '1']
+    args.push('-q:v', '2', '-y')
+
+    // Written next to the target and moved into place, so a cancelled render keeps the previous frame
+    await renderToPartialOutput(outputPath, true, partialPath =>
+      this.runPreviewRender(project.id, [...args, partialPath]),
+    )
+  }
+
+  /**
+   * Cancel the preview frame render in progress, if any
+   */
+  cancelPreviewRender(): boolean {
+    if (!this.activePreview) {
+      return false
+    }
+    this.activePreview.cancelled = true
+    this.activePreview.process.kill('SIGTERM')
+    this.activePreview = null
+    return true
+  }
+
+  private runPreviewRender(projectId: string, args: string[]): Promise<void> {
+    return new Promise((resolve, reject) => {
+      const ffmpegPath = this.platform.resolveExecutable('ffmpeg') || 'ffmpeg'
+      const ffmpeg = spawn(ffmpegPath, withFFmpegThreadLimit(args), { stdio: ['ignore', 'ignore', 'pipe'] })
+      applyFFmpegPriority(ffmpeg.pid)
+      const preview: ActiveExport = { projectId, process: ffmpeg, cancelled: false }
+      this.activePreview = preview
+
+      let stderr = ''
+      ffmpeg.stderr?.on('data', data => {
+        stderr = (stderr + data.toString()).slice(-4000)
+      })
+
+      ffmpeg.on('close', code => {
+        if (this.activePreview === preview) {
+          this.activePreview = null
+        }
+        if (preview.cancelled) {
+          reject(new Error('Preview render cancelled'))
+        } else if (code === 0) {
+          resolve()
+        } else {
+          reject(new Error(`FFmpeg failed with code ${code}: ${stderr}`))
+        }
+      })
+
+      ffmpeg.on('error', error => {
+        if (this.activePreview === preview) {
+          this.activePreview = null
+        }
+        reject(error)
+      })
+    })
+  }
+
+  /**
+   * Composite all clips over a black canvas; audio filters are only built when includeAudio is set
+   */
+  private async buildCompositeGraph(
+    project: Project,
+    duration: number,
+    includeAudio: boolean,
+  ): Promise<CompositeGraph> {
+    const { width, height, frameRate } = project.settings
+    const inputs: string[] = []
+    const filters: string[] = [`color=c=black:s=${width}x${height}:r=${frameRate}:d=${duration.toFixed(3)}[base]`]
+    const audioLabels: string[] = []
+    let videoLabel = 'base'
+    let inputIndex = 0
+
+    for (const track of project.tracks) {
+      for (const clip of track.clips) {
+        inputs.push(...buildClipInput(clip, frameRate))
+        const index = inputIndex++
+        const { opacity } = clip.properties
+        const start = clip.startTime
+        const end = start + getClipTimelineDuration(clip)
+
+        // Audio clips on a video track only go into the mix; their input has no video to filter
+        if (track.kind === 'video' && clip.type !== 'audio') {
+          const label = `v${index}`
+          const effects = buildEffectChainFilter(clip.properties.effectChain, `c${index}`)
+          const clipFilter = buildClipVideoFilter(clip, project.settings) + (effects ? `,${effects}` : '')
+          filters.push(
+            `[${index}:v]${clipFilter},format=yuva420p,` +
+              `colorchannelmixer=aa=${opacity},setpts=PTS+${start}/TB[${label}]`,
+          )
+          filters.push(
+            `[${videoLabel}][${label}]overlay=eof_action=pass:enable='between(t,${start},${end})'[o${index}]`,
+          )
+          videoLabel = `o${index}`
+        }
+
+        if (includeAudio && isClipAudible(track, clip) && (await this.hasAudioStream(clip.sourcePath))) {
+          const label = `a${index}`
+          filters.push(buildClipAudioFilter(clip, index, label))
+          audioLabels.push(`[${label}]`)
+        }
+      }
+    }
+
+    return { inputs, filters, videoLabel, audioLabels, inputCount: inputIndex }
+  }
+
+  /**
+   * Just the audio side of the timeline. Only clips with audio become inputs, so nothing
+   * else is decoded.
+   */
+  private async buildAudioGraph(project: Project): Promise<Pick<CompositeGraph, 'inputs' | 'filters' | 'audioLabels'>> {
+    const inputs: string[] = []
+    const filters: string[] = []
+    const audioLabels: string[] = []
+
+    for (const track of project.tracks) {
+      for (const clip of track.clips) {
+        if (!isClipAudible(track, clip) || !(await this.hasAudioStream(clip.sourcePath))) continue
+        const index = audioLabels.length
+        inputs.push('-i', clip.sourcePath)
+        filters.push(buildClipAudioFilter(clip, index, `a${index}`))
+        audioLabels.push(`[a${index}]`)
+      }
+    }
+
+    return { inputs, filters, audioLabels }
+  }
+
+  /**
+   * Build the full FFmpeg argument list for a video project export
+   */
+  private async buildExportArgs(
+    project: Project,
+    settings: ProjectExportSettings,
+    encoder: string,
+    duration: number,
+    metadataPath: string | null,
+  ): Promise<string[]> {
+    const { inputs, filters, videoLabel, audioLabels, inputCount } = await this.buildCompositeGraph(
+      project,
+      duration,
+      true,
+    )
+
+    filters.push(`[${videoLabel}]format=yuv420p[vout]`)
+    if (audioLabels.length > 0) {
+      filters.push(`${audioLabels.join('')}amix=inputs=${audioLabels.length}:normalize=0[aout]`)
+    }
+
+    const args = [...inputs]
+    if (metadataPath) {
+      args.push('-f', 'ffmetadata', '-i', metadataPath)
+    }
+
+    args.push('-filter_complex', filters.join(';'), '-map', '[vout]')
+    if (audioLabels.length > 0) {
+      args.push('-map', '[aout]')
+    }
+    if (metadataPath) {
+      args.push('-map_metadata', String(inputCount), '-map_chapters', String(inputCount))
+    }
+
+    args.push(
+      ...buildVideoEncoderArgs(encoder, settings),
+      '-c:a',
+      AUDIO_ENCODERS[settings.audioCodec] ?? 'aac',
+      '-b:a',
+      AUDIO_BITRATE_BY_QUALITY[settings.quality] ?? '192k',
+      '-t',
+      duration.toFixed(3),
+      '-progress',
+      'pipe:1',
+      '-y',
+      settings.outputPath,
+    )
+
+    return args
+  }
+
+  /**
+   * Decide whether the project can be stream-copied, and from which source time.
+   * An in point between keyframes can only be copied by moving it back to the previous
+   * keyframe, which is done only when settings.allowKeyframeSnap is set.
+   */
+  private async planStreamCopy(project: Project, settings: ProjectExportSettings): Promise<StreamCopyPlan> {
+    const clips = project.tracks.flatMap(track => track.clips)
+    const source = clips.length === 1 ? await this.probeSource(clips[0]) : null
+    const reencodeReasons = getStreamCopyBlockers(project, settings, source)
+    if (reencodeReasons.length > 0) {
+      return { copy: null, reencodeReasons }
+    }
+
+    const clip = clips[0]
+    if (clip.sourceStart <= 0) {
+      return { copy: { clip, start: 0 }, reencodeReasons }
+    }
+
+    const keyframe = await this.findKeyframeAtOrBefore(clip.sourcePath, clip.sourceStart)
+    if (keyframe !== null && clip.sourceStart - keyframe < 0.5 / (source?.frameRate || 30)) {
+      return { copy: { clip, start: clip.sourceStart }, reencodeReasons }
+    }
+    if (keyframe !== null && settings.allowKeyframeSnap) {
+      return { copy: { clip, start: keyframe }, reencodeReasons }
+    }
+    return { copy: null, reencodeReasons: ['Clip does not start on a keyframe'] }
+  }
+
+  /**
+   * Remux a range of the source without re-encoding. -ss before -i seeks to the keyframe at copyStart.
+   */
+  private buildStreamCopyArgs(
+    sourcePath: string,
+    settings: ProjectExportSettings,
+    copyStart: number,
+    copyDuration: number,
+    metadataPath: string | null,
+  ): string[] {
+    const args = ['-ss', copyStart.toFixed(3), '-i', sourcePath]
+    if (metadataPath) {
+      args.push('-f', 'ffmetadata', '-i', metadataPath)
+    }
+    args.push('-map', '0:v:0', '-map', '0:a?')
+    if (metadataPath) {
+      args.push('-map_metadata', '1', '-map_chapters', '1')
+    }
+    args.push(
+      '-c',
+      'copy',
+      '-t',
+      copyDuration.toFixed(3),
+      '-avoid_negative_ts',
+      'make_zero',
+      '-progress',
+      'pipe:1',
+      '-y',
+      settings.outputPath,
+    )
+    return args
+  }
+
+  /**
+   * Codecs, resolution and frame rate of a clip's source, or null if it can't be probed
+   */
+  private probeSource(clip: Clip): Promise<StreamCopySource | null> {
+    return new Promise(resolve => {
+      const ffprobePath = this.platform.resolveExecutable('ffprobe') || 'ffprobe'
+      const args = [
+        '-v',
+        'error',
+        '-show_entries',
+        'stream=codec_type,codec_name,width,height,r_frame_rate',
+        '-of',
+        'json',
+        clip.sourcePath,
+      ]
+      const ffprobe = spawn(ffprobePath, args, { stdio: ['pipe', 'pipe', 'pipe'] })
+
+      let stdout = ''
+      ffprobe.stdout?.on('data', data => {
+        stdout += data.toString()
+      })
+      ffprobe.on('close', code => {
+        try {
+          if (code !== 0) throw new Error(`ffprobe exited with code ${code}`)
+          const streams: any[] = JSON.parse(stdout).streams ?? []
+          const video = streams.find(stream => stream.codec_type === 'video')
+          const audio = streams.find(stream => stream.codec_type === 'audio')
+          const [num, den] = String(video?.r_frame_rate ?? '0/1').split('/').map(Number)
+          resolve({
+            videoCodec: video?.codec_name ?? null,
+            audioCodec: audio?.codec_name ?? null,
+            width: video?.width ?? 0,
+            height: video?.height ?? 0,
+            frameRate: den ? num / den : 0,
+          })
+        } catch (error) {
+          this.logger.warn('Failed to probe export source', { path: clip.sourcePath, error: (error as Error).message })
+          resolve(null)
+        }
+      })
+      ffprobe.on('error', () => resolve(null))
+    })
+  }
+
+  /**
+   * Time of the last video keyframe at or before the given source time, or null if none was found
+   * within KEYFRAME_SEARCH_WINDOW. Reads packet flags only, so nothing is decoded.
+   */
+  private findKeyframeAtOrBefore(filePath: string, time: number): Promise<number | null> {
+    return new Promise(resolve => {
+      const ffprobePath = this.platform.resolveExecutable('ffprobe') || 'ffprobe'
+      const windowStart = Math.max(0, time - KEYFRAME_SEARCH_WINDOW)
+      const args = [
+        '-v',
+        'error',
+        '-select_streams',
+        'v:0',
+        '-read_intervals',
+        `${windowStart}%${time + 1}`,
+        '-show_entries',
+        'packet=pts_time,flags',
+        '-of',
+        'csv=p=0',
+        filePath,
+      ]
+      const ffprobe = spawn(ffprobePath, args, { stdio: ['pipe', 'pipe', 'pipe'] })
+
+      let stdout = ''
+      ffprobe.stdout?.on('data', data => {
+        stdout += data.toString()
+      })
+      ffprobe.on('close', () => {
+        // Lines look like '12.345000,K__'; allow a millisecond of rounding past the in point
+        let keyframe: number | null = null
+        for (const line of stdout.split(/\r?\n/)) {
+          const [ptsTime, flags] = line.split(',')
+          const pts = parseFloat(ptsTime)
+          if (flags?.startsWith('K') && !isNaN(pts) && pts <= time + 0.001 && (keyframe === null || pts > keyframe)) {
+            keyframe = pts
+          }
+        }
+        resolve(keyframe)
+      })
+      ffprobe.on('error', () => resolve(null))
+    })
+  }
+
+  /**
+   * Run FFmpeg, translating -progress output into 'progress' events.
+   * For multi-pass exports, each pass covers an equal share of the overall progress.
+   */
+  private runExport(projectId: string, args: string[], duration: number, pass = 0, passCount = 1): Promise<void> {
+    return new Promise((resolve, reject) => {
+      const ffmpegPath = this.platform.resolveExecutable('ffmpeg') || 'ffmpeg'
+      this.logger.debug('Executing project export', { projectId, pass: pass + 1, passCount, args })
+      const passInfo = passCount > 1 ? { pass: pass + 1, totalPasses: passCount } : {}
+
+      const ffmpeg = spawn(ffmpegPath, withFFmpegThreadLimit(args), { stdio: ['pipe', 'pipe', 'pipe'] })
+      if (this.exportPriority !== 'normal') {
+        setProcessPriority(ffmpeg.pid, this.exportPriority)
+      }
+      this.activeExport = { projectId, process: ffmpeg, cancelled: false }
+      this.encodedFrames = 0
+
+      let stderr = ''
+      ffmpeg.stderr?.on('data', data => {
+        // Keep only the tail; FFmpeg is chatty on long exports
+        stderr = (stderr + data.toString()).slice(-4000)
+      })
+
+      ffmpeg.stdout?.on('data', (data: Buffer) => {
+        const text = data.toString()
+        const frames = Array.from(text.matchAll(/frame=(\d+)/g)).pop()
+        if (frames) {
+          this.encodedFrames = parseInt(frames[1], 10)
+        }
+        const match = text.match(/out_time_ms=(\d+)/)
+        if (match) {
+          const currentTime = parseInt(match[1], 10) / 1_000_000
+          const progress: ProjectExportProgress = {
+            projectId,
+            progress: getPassProgress(currentTime / duration, pass, passCount),
+            currentTime,
+            duration,
+            ...passInfo,
+          }
+          this.emit('progress', progress)
+        }
+      })
+
+      ffmpeg.on('close', code => {
+        const cancelled = this.activeExport?.cancelled
+        this.activeExport = null
+
+        if (cancelled) {
+          reject(new Error('Export cancelled'))
+        } else if (code === 0) {
+          this.emit('progress', {
+            projectId,
+            progress: getPassProgress(1, pass, passCount),
+            currentTime: duration,
+            duration,
+            ...passInfo,
+          })
+          resolve()
+        } else {
+          const error = new Error(`FFmpeg failed with code ${code}: ${stderr}`)
+          this.logger.error('Project export failed', error, { projectId, code })
+          reject(error)
+        }
+      })
+
+      ffmpeg.on('error', error => {
+        this.activeExport = null
+        this.logger.error('FFmpeg process error during export', error)
+        reject(error)
+      })
+    })
+  }
+
+  /**
+   * Encoders compiled into the FFmpeg build (probed once with -encoders)
+   */
+  getAvailableEncoders(): Promise<Set<string>> {
+    if (!this.availableEncoders) {
+      this.availableEncoders = new Promise(resolve => {
+        const ffmpegPath = this.platform.resolveExecutable('ffmpeg') || 'ffmpeg'
+        const ffmpeg = spawn(ffmpegPath, ['-hide_banner', '-encoders'], { stdio: ['pipe', 'pipe', 'pipe'] })
+
+        let stdout = ''
+        ffmpeg.stdout?.on('data', data => {
+          stdout += data.toString()
+        })
+        ffmpeg.on('close', () => {
+          // Lines look like ' V....D libx264              libx264 H.264 / AVC ...'
+          const encoders = new Set<string>()
+          for (const match of stdout.matchAll(/^\s*[VAS][.A-Z]{5}\s+(\S+)/gm)) {
+            encoders.add(match[1])
+          }
+          this.logger.debug('FFmpeg encoders probed', { count: encoders.size })
+          resolve(encoders)
+        })
+        ffmpeg.on('error', error => {
+          this.logger.warn('Failed to probe FFmpeg encoders', { error: error.message })
+          this.availableEncoders = null // Retry next time, e.g. after FFmpeg is installed
+          resolve(new Set())
+        })
+      })
+    }
+    return this.availableEncoders
+  }
+
+  /**
+   * Check whether a media file has at least one audio stream
+   */
+  private hasAudioStream(filePath: string): Promise<boolean> {
+    return new Promise(resolve => {
+      const ffprobePath = this.platform.resolveExecutable('ffprobe') || 'ffprobe'
+      const args = ['-v', 'error', '-select_streams', 'a', '-show_entries', 'stream=index', '-of', 'csv=p=0', filePath]
+      const ffprobe = spawn(ffprobePath, args, { stdio: ['pipe', 'pipe', 'pipe'] })
+
+      let stdout = ''
+      ffprobe.stdout?.on('data', data => {
+        stdout += data.toString()
+      })
+      ffprobe.on('close', () => resolve(stdout.trim().length > 0))
+      ffprobe.on('error', () => resolve(false))
+    })
+  }
+}
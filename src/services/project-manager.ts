@@ -0,0 +1,669 @@
+/**
+ * Project Manager Service
+ *
+ * Owns editor projects loaded in the main process. Provides:
+ * - Project creation, loading, and saving (.clipy JSON files)
+ * - Undoable edits via ProjectCommand (command pattern)
+ * - Per-project undo/redo stacks capped at MAX_UNDO_STACK_SIZE
+ * - Autosaving to the temp folder after edits (see project-autosave)
+ *
+ * Each applied command yields its inverse; the inverse is pushed onto the undo
+ * stack, and undoing it yields the original command for the redo stack.
+ */
+
+import type {
+  Clip,
+  ClipProperties,
+  Marker,
+  MarkerWithClipId,
+  Project,
+  ProjectCommand,
+  Track,
+  UndoRedoState,
+} from '../types/project'
+
+import { FileSystemUtils } from '../utils/file-system'
+import { Logger } from '../utils/logger'
+import { AUTOSAVE_EXTENSION } from '../utils/paths'
+import { deleteAutosave, scheduleAutosave } from './project-autosave'
+import { getProjectDuration } from './project-exporter'
+
+const MAX_UNDO_STACK_SIZE = 50
+
+const DEFAULT_MARKER_COLOR = '#f59e0b'
+
+/** Clip properties that are unset by default but can still be set through update-clip-property */
+const OPTIONAL_CLIP_PROPERTIES: string[] = ['kenBurns', 'effectChain']
+
+const DEFAULT_CLIP_PROPERTIES: ClipProperties = {
+  volume: 1,
+  speed: 1,
+  opacity: 1,
+  muted: false,
+}
+
+/** Undo/redo history for a single project */
+interface EditSession {
+  project: Project
+  undoStack: ProjectCommand[]
+  redoStack: ProjectCommand[]
+}
+
+export class ProjectManager {
+  private static instance: ProjectManager
+  private sessions = new Map<string, EditSession>()
+  private logger = Logger.getInstance()
+  private fileSystem = FileSystemUtils.getInstance()
+
+  private constructor() {}
+
+  static getInstance(): ProjectManager {
+    if (!ProjectManager.instance) {
+      ProjectManager.instance = new ProjectManager()
+    }
+    return ProjectManager.instance
+  }
+
+  /**
+   * Create a new empty project with one video and one audio track
+   */
+  createProject(name: string): Project {
+    const now = Date.now()
+    const project: Project = {
+      id: this.generateId('proj'),
+      name: name.trim() || 'Untitled Project',
+      tracks: [this.createTrack('video', 'Video 1'), this.createTrack('audio', 'Audio 1')],
+      settings: { width: 1920, height: 1080, frameRate: 30 },
+      duration: 0,
+      createdAt: now,
+      modifiedAt: now,
+    }
+
+    this.sessions.set(project.id, { project, undoStack: [], redoStack: [] })
+    this.logger.info('Project created', { projectId: project.id, name: project.name })
+    return project
+  }
+
+  /**
+   * Load a project file and open an edit session for it. A recovered autosave keeps the
+   * path the project was last saved to, so saving doesn't write into the temp folder.
+   */
+  async openProject(filePath: string): Promise<Project> {
+    const project = await this.readProjectFile(filePath)
+    this.sessions.set(project.id, { project, undoStack: [], redoStack: [] })
+    this.logger.info('Project opened', { projectId: project.id, filePath })
+    return project
+  }
+
+  /**
+   * Load a project file without opening an edit session, e.g. to export it
+   */
+  async readProjectFile(filePath: string): Promise<Project> {
+    const data = (await this.fileSystem.readJsonFile(filePath)) as Project
+    if (!data || typeof data.id !== 'string' || !Array.isArray(data.tracks)) {
+      throw new Error('Invalid project file')
+    }
+
+    // Backfill fields added after the project file was written
+    const project: Project = {
+      ...data,
+      tracks: data.tracks.map(track => ({
+        ...track,
+        markers: track.markers ?? [],
+        clips: track.clips.map(clip => ({ ...clip, markers: clip.markers ?? [] })),
+      })),
+      filePath: filePath.endsWith(AUTOSAVE_EXTENSION) ? data.filePath : filePath,
+    }
+    project.duration = getProjectDuration(project)
+    return project
+  }
+
+  /**
+   * Save a project to disk. Uses the last saved path when none is given.
+   */
+  async saveProject(projectId: string, filePath?: string): Promise<Project> {
+    const session = this.getSession(projectId)
+    const targetPath = filePath || session.project.filePath
+    if (!targetPath) {
+      throw new Error('No file path specified for project')
+    }
+
+    session.project.filePath = targetPath
+    this.touchProject(session.project)
+    await this.fileSystem.writeJsonFile(targetPath, session.project)
+    this.discardAutosave(projectId)
+    this.logger.info('Project saved', { projectId, filePath: targetPath })
+    return session.project
+  }
+
+  /**
+   * Mark a project as modified now and recompute its duration from the clips
+   * (end of the last clip across tracks, with playback speed applied)
+   */
+  touchProject(project: Project): Project {
+    project.modifiedAt = Date.now()
+    project.duration = getProjectDuration(project)
+    return project
+  }
+
+  getProject(projectId: string): Project {
+    return this.getSession(projectId).project
+  }
+
+  getClip(projectId: string, clipId: string): Clip {
+    return this.findClip(this.getSession(projectId).project, clipId).clip
+  }
+
+  getTrack(projectId: string, trackId: string): Track {
+    return this.findTrack(this.getSession(projectId).project, trackId)
+  }
+
+  /**
+   * Whether a file is the source of a clip in any open project
+   */
+  isOpenProjectSource(filePath: string): boolean {
+    for (const { project } of this.sessions.values()) {
+      if (project.tracks.some(track => track.clips.some(clip => clip.sourcePath === filePath))) {
+        return true
+      }
+    }
+    return false
+  }
+
+  /**
+   * Drop a project and its edit history from memory
+   */
+  closeProject(projectId: string): void {
+    if (this.sessions.delete(projectId)) {
+      this.discardAutosave(projectId)
+    }
+  }
+
+  /**
+   * Apply a command and record its inverse for undo. Clears the redo stack.
+   */
+  executeCommand(projectId: string, command: ProjectCommand): Project {
+    const session = this.getSession(projectId)
+    const inverse = this.applyCommand(session.project, command)
+
+    this.pushCapped(session.undoStack, inverse)
+    session.redoStack = []
+    this.autosaveLater(projectId)
+
+    this.logger.debug('Project command executed', { projectId, type: command.type })
+    return session.project
+  }
+
+  /**
+   * Revert the most recent command
+   */
+  undo(projectId: string): Project {
+    const session = this.getSession(projectId)
+    const command = session.undoStack.pop()
+    if (!command) {
+      throw new Error('Nothing to undo')
+    }
+
+    this.pushCapped(session.redoStack, this.applyCommand(session.project, command))
+    this.autosaveLater(projectId)
+    return session.project
+  }
+
+  /**
+   * Re-apply the most recently undone command
+   */
+  redo(projectId: string): Project {
+    const session = this.getSession(projectId)
+    const command = session.redoStack.pop()
+    if (!command) {
+      throw new Error('Nothing to redo')
+    }
+
+    this.pushCapped(session.undoStack, this.applyCommand(session.project, command))
+    this.autosaveLater(projectId)
+    return session.project
+  }
+
+  getUndoStackSize(projectId: string): number {
+    return this.getSession(projectId).undoStack.length
+  }
+
+  getUndoRedoState(projectId: string): UndoRedoState {
+    const session = this.getSession(projectId)
+    return {
+      projectId,
+      undoStackSize: session.undoStack.length,
+      redoStackSize: session.redoStack.length,
+    }
+  }
+
+  /**
+   * Split a clip at the given source times as a single undoable step.
+   * The original clip keeps the first segment; each later segment becomes a new clip.
+   */
+  splitClip(projectId: string, clipId: string, sourceTimes: number[], minSegmentLength: number = 0.5): Project {
+    const { project } = this.getSession(projectId)
+    const { track, clip } = this.findClip(project, clipId)
+    const speed = clip.properties.speed || 1
+
+    const cuts = [...new Set(sourceTimes)]
+      .filter(t => t > clip.sourceStart + minSegmentLength && t < clip.sourceEnd - minSegmentLength)
+      .sort((a, b) => a - b)
+      .filter((t, i, arr) => i === 0 || t - arr[i - 1] >= minSegmentLength)
+
+    if (cuts.length === 0) {
+      return project
+    }
+
+    const boundaries = [clip.sourceStart, ...cuts, clip.sourceEnd]
+    const clipIndex = track.clips.findIndex(c => c.id === clip.id)
+    const commands: ProjectCommand[] = [
+      { type: 'resize-clip', clipId: clip.id, sourceStart: boundaries[0], sourceEnd: boundaries[1] },
+    ]
+
+    for (let i = 1; i < boundaries.length - 1; i++) {
+      const segment = this.createClip({
+        type: clip.type,
+        name: `${clip.name} (${i + 1})`,
+        sourcePath: clip.sourcePath,
+        startTime: clip.startTime + (boundaries[i] - clip.sourceStart) / speed,
+        sourceStart: boundaries[i],
+        sourceEnd: boundaries[i + 1],
+        properties: clip.properties,
+      })
+      commands.push({ type: 'add-clip', trackId: track.id, clip: segment, index: clipIndex + i })
+    }
+
+    return this.executeCommand(projectId, { type: 'batch', commands })
+  }
+
+  /**
+   * Add a clip of a source file to a track at a timeline position, as an undoable step. The
+   * clip spans the whole file unless a source range is given, and is placed among the
+   * track's clips by start time.
+   */
+  insertSourceClip(
+    projectId: string,
+    trackId: string,
+    source: {
+      type: Clip['type']
+      name: string
+      sourcePath: string
+      duration: number
+      sourceStart?: number
+      sourceEnd?: number
+    },
+    startTime: number,
+  ): { project: Project; clip: Clip } {
+    const track = this.findTrack(this.getSession(projectId).project, trackId)
+    if (track.locked) {
+      throw new Error(`Track is locked: ${track.name}`)
+    }
+
+    const clip = this.createClip({
+      type: source.type,
+      name: source.name,
+      sourcePath: source.sourcePath,
+      startTime: Math.max(0, startTime),
+      sourceStart: source.sourceStart ?? 0,
+      sourceEnd: source.sourceEnd ?? source.duration,
+    })
+    const index = track.clips.filter(c => c.startTime <= clip.startTime).length
+    const project = this.executeCommand(projectId, { type: 'add-clip', trackId, clip, index })
+    return { project, clip: this.getClip(projectId, clip.id) }
+  }
+
+  /**
+   * Add a marker to a clip, as an undoable step. Time is relative to the clip start and
+   * clamped to its length.
+   */
+  addMarker(projectId: string, clipId: string, time: number, label: string, color?: string): Clip {
+    const clip = this.getClip(projectId, clipId)
+    const clipLength = (clip.sourceEnd - clip.sourceStart) / (clip.properties.speed || 1)
+
+    const marker: Marker = {
+      id: this.generateId('marker'),
+      time: Math.min(Math.max(0, time), clipLength),
+      label: label?.trim() || `Marker ${clip.markers.length + 1}`,
+      color: color || DEFAULT_MARKER_COLOR,
+    }
+
+    this.executeCommand(projectId, { type: 'add-marker', clipId, marker })
+    return this.getClip(projectId, clipId)
+  }
+
+  removeMarker(projectId: string, clipId: string, markerId: string): Clip {
+    this.executeCommand(projectId, { type: 'remove-marker', clipId, markerId })
+    return this.getClip(projectId, clipId)
+  }
+
+  updateMarker(projectId: string, clipId: string, markerId: string, label?: string, color?: string): Clip {
+    this.executeCommand(projectId, { type: 'update-marker', clipId, markerId, label, color })
+    return this.getClip(projectId, clipId)
+  }
+
+  /**
+   * Add a marker to a track, as an undoable step. Time is on the timeline.
+   */
+  addTrackMarker(projectId: string, trackId: string, time: number, label: string, color?: string): Track {
+    const track = this.getTrack(projectId, trackId)
+
+    const marker: Marker = {
+      id: this.generateId('marker'),
+      time: Math.max(0, time),
+      label: label?.trim() || `Marker ${track.markers.length + 1}`,
+      color: color || DEFAULT_MARKER_COLOR,
+    }
+
+    this.executeCommand(projectId, { type: 'add-track-marker', trackId, marker })
+    return this.getTrack(projectId, trackId)
+  }
+
+  removeTrackMarker(projectId: string, trackId: string, markerId: string): Track {
+    this.executeCommand(projectId, { type: 'remove-track-marker', trackId, markerId })
+    return this.getTrack(projectId, trackId)
+  }
+
+  updateTrackMarker(projectId: string, trackId: string, markerId: string, label?: string, color?: string): Track {
+    this.executeCommand(projectId, { type: 'update-track-marker', trackId, markerId, label, color })
+    return this.getTrack(projectId, trackId)
+  }
+
+  /**
+   * Flat list of all clip and track markers in the project, ordered by timeline position
+   */
+  getMarkers(projectId: string): MarkerWithClipId[] {
+    const { project } = this.getSession(projectId)
+    const markers: { marker: MarkerWithClipId; timelineTime: number }[] = []
+
+    for (const track of project.tracks) {
+      for (const marker of track.markers) {
+        markers.push({ marker: { ...marker, trackId: track.id }, timelineTime: marker.time })
+      }
+      for (const clip of track.clips) {
+        for (const marker of clip.markers) {
+          markers.push({
+            marker: { ...marker, clipId: clip.id, trackId: track.id },
+            timelineTime: clip.startTime + marker.time,
+          })
+        }
+      }
+    }
+
+    return markers.sort((a, b) => a.timelineTime - b.timelineTime).map(entry => entry.marker)
+  }
+
+  /**
+   * Build a clip with default properties
+   */
+  createClip(clip: Omit<Clip, 'id' | 'properties' | 'markers'> & { properties?: Partial<ClipProperties> }): Clip {
+    return {
+      ...clip,
+      id: this.generateId('clip'),
+      properties: { ...DEFAULT_CLIP_PROPERTIES, ...clip.properties },
+      markers: [],
+    }
+  }
+
+  createTrack(kind: Track['kind'], name: string): Track {
+    return {
+      id: this.generateId('track'),
+      name,
+      kind,
+      clips: [],
+      markers: [],
+      muted: false,
+      locked: false,
+    }
+  }
+
+  /**
+   * Push onto an undo or redo stack, dropping the oldest entry past MAX_UNDO_STACK_SIZE
+   */
+  private pushCapped(stack: ProjectCommand[], command: ProjectCommand): void {
+    stack.push(command)
+    if (stack.length > MAX_UNDO_STACK_SIZE) {
+      stack.shift()
+    }
+  }
+
+  private autosaveLater(projectId: string): void {
+    scheduleAutosave(projectId, () => this.sessions.get(projectId)?.project ?? null)
+  }
+
+  private discardAutosave(projectId: string): void {
+    try {
+      deleteAutosave(projectId)
+    } catch (error) {
+      this.logger.debug('Failed to remove project autosave', { projectId, error: (error as Error).message })
+    }
+  }
+
+  private getSession(projectId: string): EditSession {
+    const session = this.sessions.get(projectId)
+    if (!session) {
+      throw new Error(`Project not found: ${projectId}`)
+    }
+    return session
+  }
+
+  /**
+   * Mutate the project according to the command and return the inverse command
+   */
+  private applyCommand(project: Project, command: ProjectCommand): ProjectCommand {
+    let inverse: ProjectCommand
+
+    switch (command.type) {
+      case 'add-clip': {
+        const track = this.findTrack(project, command.trackId)
+        const index = command.index ?? track.clips.length
+        track.clips.splice(index, 0, {
+          ...command.clip,
+          properties: { ...command.clip.properties },
+          markers: [...(command.clip.markers ?? [])],
+        })
+        inverse = { type: 'remove-clip', trackId: track.id, clipId: command.clip.id }
+        break
+      }
+
+      case 'remove-clip': {
+        const track = this.findTrack(project, command.trackId)
+        const index = track.clips.findIndex(c => c.id === command.clipId)
+        if (index === -1) {
+          throw new Error(`Clip not found: ${command.clipId}`)
+        }
+        const [clip] = track.clips.splice(index, 1)
+        inverse = { type: 'add-clip', trackId: track.id, clip, index }
+        break
+      }
+
+      case 'move-clip': {
+        const { track, clip } = this.findClip(project, command.clipId)
+        const target = this.findTrack(project, command.toTrackId)
+        const index = track.clips.indexOf(clip)
+        inverse = { type: 'move-clip', clipId: clip.id, toTrackId: track.id, startTime: clip.startTime, index }
+
+        clip.startTime = Math.max(0, command.startTime)
+        if (target.id !== track.id || command.index !== undefined) {
+          // Undo puts the clip back where it was among the track's clips, not at the end
+          track.clips.splice(index, 1)
+          const targetIndex = Math.min(command.index ?? target.clips.length, target.clips.length)
+          target.clips.splice(targetIndex, 0, clip)
+        }
+        break
+      }
+
+      case 'resize-clip': {
+        const { clip } = this.findClip(project, command.clipId)
+        if (command.sourceEnd <= command.sourceStart) {
+          throw new Error('Clip out point must be after in point')
+        }
+        inverse = {
+          type: 'resize-clip',
+          clipId: clip.id,
+          sourceStart: clip.sourceStart,
+          sourceEnd: clip.sourceEnd,
+          startTime: clip.startTime,
+        }
+
+        clip.sourceStart = command.sourceStart
+        clip.sourceEnd = command.sourceEnd
+        if (command.startTime !== undefined) {
+          clip.startTime = Math.max(0, command.startTime)
+        }
+        break
+      }
+
+      case 'update-clip-property': {
+        const { clip } = this.findClip(project, command.clipId)
+        if (command.property === 'name') {
+          inverse = { ...command, value: clip.name }
+          clip.name = String(command.value)
+        } else {
+          if (!(command.property in clip.properties) && !OPTIONAL_CLIP_PROPERTIES.includes(command.property)) {
+            throw new Error(`Unknown clip property: ${command.property}`)
+          }
+          inverse = { ...command, value: clip.properties[command.property] }
+          ;(clip.properties as unknown as Record<string, unknown>)[command.property] = command.value
+        }
+        break
+      }
+
+      case 'add-track': {
+        const index = command.index ?? project.tracks.length
+        project.tracks.splice(index, 0, { ...command.track, clips: [...command.track.clips] })
+        inverse = { type: 'remove-track', trackId: command.track.id }
+        break
+      }
+
+      case 'remove-track': {
+        const index = project.tracks.findIndex(t => t.id === command.trackId)
+        if (index === -1) {
+          throw new Error(`Track not found: ${command.trackId}`)
+        }
+        const [track] = project.tracks.splice(index, 1)
+        inverse = { type: 'add-track', track, index }
+        break
+      }
+
+      case 'add-marker': {
+        const { clip } = this.findClip(project, command.clipId)
+        clip.markers.push({ ...command.marker })
+        clip.markers.sort((a, b) => a.time - b.time)
+        inverse = { type: 'remove-marker', clipId: clip.id, markerId: command.marker.id }
+        break
+      }
+
+      case 'remove-marker': {
+        const { clip } = this.findClip(project, command.clipId)
+        const marker = clip.markers.find(m => m.id === command.markerId)
+        if (!marker) {
+          throw new Error(`Marker not found: ${command.markerId}`)
+        }
+        clip.markers = clip.markers.filter(m => m.id !== marker.id)
+        inverse = { type: 'add-marker', clipId: clip.id, marker }
+        break
+      }
+
+      case 'update-marker': {
+        const { clip } = this.findClip(project, command.clipId)
+        const marker = clip.markers.find(m => m.id === command.markerId)
+        if (!marker) {
+          throw new Error(`Marker not found: ${command.markerId}`)
+        }
+        inverse = {
+          type: 'update-marker',
+          clipId: clip.id,
+          markerId: marker.id,
+          label: marker.label,
+          color: marker.color,
+        }
+        if (command.label !== undefined) marker.label = command.label
+        if (command.color !== undefined) marker.color = command.color
+        break
+      }
+
+      case 'add-track-marker': {
+        const track = this.findTrack(project, command.trackId)
+        track.markers.push({ ...command.marker })
+        track.markers.sort((a, b) => a.time - b.time)
+        inverse = { type: 'remove-track-marker', trackId: track.id, markerId: command.marker.id }
+        break
+      }
+
+      case 'remove-track-marker': {
+        const track = this.findTrack(project, command.trackId)
+        const marker = track.markers.find(m => m.id === command.markerId)
+        if (!marker) {
+          throw new Error(`Marker not found: ${command.markerId}`)
+        }
+        track.markers = track.markers.filter(m => m.id !== marker.id)
+        inverse = { type: 'add-track-marker', trackId: track.id, marker }
+        break
+      }
+
+      case 'update-track-marker': {
+        const track = this.findTrack(project, command.trackId)
+        const marker = track.markers.find(m => m.id === command.markerId)
+        if (!marker) {
+          throw new Error(`Marker not found: ${command.markerId}`)
+        }
+        inverse = {
+          type: 'update-track-marker',
+          trackId: track.id,
+          markerId: marker.id,
+          label: marker.label,
+          color: marker.color,
+        }
+        if (command.label !== undefined) marker.label = command.label
+        if (command.color !== undefined) marker.color = command.color
+        break
+      }
+
+      case 'batch': {
+        const inverses: ProjectCommand[] = []
+        try {
+          for (const child of command.commands) {
+            inverses.unshift(this.applyCommand(project, child))
+          }
+        } catch (error) {
+          // Roll back the commands that already ran so the batch stays atomic
+          for (const undoCommand of inverses) {
+            this.applyCommand(project, undoCommand)
+          }
+          throw error
+        }
+        inverse = { type: 'batch', commands: inverses }
+        break
+      }
+
+      default:
+        throw new Error(`Unknown project command: ${(command as ProjectCommand).type}`)
+    }
+
+    this.touchProject(project)
+    return inverse
+  }
+
+  private findTrack(project: Project, trackId: string): Track {
+    const track = project.tracks.find(t => t.id === trackId)
+    if (!track) {
+      throw new Error(`Track not found: ${trackId}`)
+    }
+    return track
+  }
+
+  private findClip(project: Project, clipId: string): { track: Track; clip: Clip } {
+    for (const track of project.tracks) {
+      const clip = track.clips.find(c => c.id === clipId)
+      if (clip) {
+        return { track, clip }
+      }
+    }
+    throw new Error(`Clip not found: ${clipId}`)
+  }
+
+  private generateId(prefix: string): string {
+    return `${prefix}_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`
+  }
+}
@@ -0,0 +1,102 @@
+/**
+ * Queue Completion Action
+ * Optionally sleeps, shuts down or quits once every queued download has finished, for
+ * unattended overnight batches.
+ *
+ * - The action is a runtime option only: it is never persisted and resets each launch,
+ *   and after it has run once
+ * - Failed and cancelled downloads count as finished; the 'queue-completed' payload carries
+ *   their counts so the UI can warn during the countdown
+ * - The action waits COMPLETION_GRACE_PERIOD and can be cancelled until then
+ */
+
+import { EventEmitter } from 'events'
+import { app } from 'electron'
+
+import type {
+  QueueCompletedEvent,
+  QueueCompletionAction,
+  QueueCompletionState,
+  QueueCompletionSummary,
+} from '../types/download'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+
+const logger = Logger.getInstance()
+
+const COMPLETION_GRACE_PERIOD = 60 * 1000 // ms before the action runs
+
+const state: QueueCompletionState & { timer: NodeJS.Timeout | null } = {
+  action: 'none',
+  executeAt: null,
+  timer: null,
+}
+
+/** Emits 'queue-completed' with QueueCompletedEvent and 'action-cancelled' */
+export const queueCompletionEvents = new EventEmitter()
+
+export function getQueueCompletionAction(): QueueCompletionAction {
+  return state.action
+}
+
+export function getQueueCompletionState(): QueueCompletionState {
+  return { action: state.action, executeAt: state.timer ? state.executeAt : null }
+}
+
+export function setQueueCompletionAction(action: QueueCompletionAction): void {
+  state.action = action
+  logger.info('Queue completion action set', { action })
+}
+
+/**
+ * Stop a pending countdown. Returns false if no action was pending.
+ */
+export function cancelCompletionAction(): boolean {
+  if (!state.timer) {
+    return false
+  }
+  clearTimeout(state.timer)
+  state.timer = null
+  logger.info('Queue completion action cancelled', { action: state.action })
+  queueCompletionEvents.emit('action-cancelled')
+  return true
+}
+
+async function runCompletionAction(action: QueueCompletionAction): Promise<void> {
+  state.timer = null
+  // One-shot: the next batch must opt in again
+  state.action = 'none'
+  logger.info('Running queue completion action', { action })
+
+  try {
+    if (action === 'quit') {
+      app.quit()
+    } else if (action === 'sleep' || action === 'shutdown') {
+      await PlatformUtils.getInstance().runPowerAction(action)
+    }
+  } catch (error) {
+    logger.error('Queue completion action failed', error as Error, { action })
+  }
+}
+
+/**
+ * Called when the download queue drains: announce it and start the countdown if an action is set
+ */
+export function handleQueueCompleted(summary: QueueCompletionSummary): void {
+  const action = state.action
+  const executeAt = action === 'none' ? null : Date.now() + COMPLETION_GRACE_PERIOD
+
+  if (state.timer) {
+    clearTimeout(state.timer)
+    state.timer = null
+  }
+  state.executeAt = executeAt
+  if (executeAt !== null) {
+    state.timer = setTimeout(() => {
+      runCompletionAction(action).catch(() => {})
+    }, COMPLETION_GRACE_PERIOD)
+  }
+
+  const event: QueueCompletedEvent = { ...summary, action, executeAt }
+  queueCompletionEvents.emit('queue-completed', event)
+}
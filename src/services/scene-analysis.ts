@@ -0,0 +1,173 @@
+/**
+ * Scene Analysis
+ * Finds the scene cuts of a whole video in the background, to suggest where its clips
+ * could be split. The caller gets a job ID at once, 'progress' events follow FFmpeg's
+ * position through the file, and a job can be cancelled.
+ *
+ * Results are cached per file content and threshold in the media cache, so analysing the
+ * same file again completes immediately, while a replaced file is analysed afresh.
+ * Analyses wait their turn in the analysis pool, and closing their project cancels them.
+ */
+
+import { EventEmitter } from 'events'
+import { existsSync, readFileSync, writeFileSync } from 'fs'
+import { join } from 'path'
+
+import type { SceneAnalysisProgress, SceneChange } from '../types/project'
+import { Logger } from '../utils/logger'
+import { AnalysisCancelledError, runAnalysisJob } from './analysis-pool'
+import { getMediaArtifactDir } from './media-cache'
+import { VideoProcessor } from './video-processor'
+
+const logger = Logger.getInstance()
+
+const PROGRESS_INTERVAL = 250 // ms between progress events
+const MAX_FINISHED_JOBS = 20 // Finished jobs kept for status queries
+
+interface SceneAnalysisJob {
+  progress: SceneAnalysisProgress
+  controller: AbortController
+}
+
+const jobs = new Map<string, SceneAnalysisJob>()
+
+/** Emits 'progress' with SceneAnalysisProgress */
+export const sceneAnalysisEvents = new EventEmitter()
+
+function generateJobId(): string {
+  return `scenes_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`
+}
+
+function pruneFinishedJobs(): void {
+  const finished = Array.from(jobs.values()).filter(job => job.progress.status !== 'running')
+  for (const job of finished.slice(0, Math.max(0, finished.length - MAX_FINISHED_JOBS))) {
+    jobs.delete(job.progress.jobId)
+  }
+}
+
+function getCachePath(videoPath: string, threshold: number): string {
+  return join(getMediaArtifactDir(videoPath), `scenes_${threshold}.json`)
+}
+
+function readCachedScenes(cachePath: string): SceneChange[] | null {
+  try {
+    return existsSync(cachePath) ? (JSON.parse(readFileSync(cachePath, 'utf-8')) as SceneChange[]) : null
+  } catch {
+    return null // Unreadable cache; analyse again
+  }
+}
+
+async function runAnalysis(job: SceneAnalysisJob, cachePath: string): Promise<void> {
+  const { videoPath, threshold } = job.progress
+  const videoProcessor = VideoProcessor.getInstance()
+  const duration = await videoProcessor
+    .getVideoMetadata(videoPath)
+    .then(metadata => metadata.duration)
+    .catch(() => 0)
+
+  let lastEmit = 0
+  const onProgress = (seconds: number) => {
+    const now = Date.now()
+    if (duration > 0 && now - lastEmit >= PROGRESS_INTERVAL) {
+      lastEmit = now
+      job.progress.progress = Math.min(99, (seconds / duration) * 100)
+      sceneAnalysisEvents.emit('progress', { ...job.progress })
+    }
+  }
+
+  try {
+    if (job.controller.signal.aborted) {
+      throw new Error('Scene analysis cancelled') // Cancelled while waiting in the pool
+    }
+    const scenes = await videoProcessor.detectSceneChanges(videoPath, threshold, undefined, {
+      onProgress,
+      signal: job.controller.signal,
+    })
+    try {
+      writeFileSync(cachePath, JSON.stringify(scenes), 'utf-8')
+    } catch (error) {
+      logger.warn('Failed to cache scene analysis', { videoPath, error: (error as Error).message })
+    }
+    job.progress.status = 'completed'
+    job.progress.progress = 100
+    job.progress.scenes = scenes
+  } catch (error) {
+    job.progress.status = job.controller.signal.aborted ? 'cancelled' : 'failed'
+    if (job.progress.status === 'failed') {
+      job.progress.error = (error as Error).message
+    }
+  }
+
+  job.progress.finishedAt = Date.now()
+  sceneAnalysisEvents.emit('progress', { ...job.progress })
+  logger.info('Scene analysis finished', {
+    jobId: job.progress.jobId,
+    status: job.progress.status,
+    scenes: job.progress.scenes?.length,
+  })
+  pruneFinishedJobs()
+}
+
+/**
+ * Start finding the scene cuts of a video. Returns the job ID; a cached result is
+ * reported as completed right away.
+ */
+export function startSceneAnalysis(videoPath: string, threshold: number = 0.4, projectId?: string): string {
+  const job: SceneAnalysisJob = {
+    progress: {
+      jobId: generateJobId(),
+      videoPath,
+      threshold,
+      status: 'running',
+      progress: 0,
+      startedAt: Date.now(),
+    },
+    controller: new AbortController(),
+  }
+  jobs.set(job.progress.jobId, job)
+
+  const cachePath = getCachePath(videoPath, threshold)
+  const cached = readCachedScenes(cachePath)
+  if (cached) {
+    Object.assign(job.progress, { status: 'completed', progress: 100, scenes: cached, cached: true })
+    job.progress.finishedAt = Date.now()
+    // Emitted after returning, so the caller knows the job ID before its events arrive
+    setImmediate(() => sceneAnalysisEvents.emit('progress', { ...job.progress }))
+    pruneFinishedJobs()
+    return job.progress.jobId
+  }
+
+  logger.info('Scene analysis started', { jobId: job.progress.jobId, videoPath, threshold })
+  runAnalysisJob(
+    'scenes',
+    signal => {
+      signal.addEventListener('abort', () => job.controller.abort())
+      return runAnalysis(job, cachePath)
+    },
+    { projectId },
+  ).catch(error => {
+    if (error instanceof AnalysisCancelledError) {
+      job.progress.status = 'cancelled'
+      job.progress.finishedAt = Date.now()
+      sceneAnalysisEvents.emit('progress', { ...job.progress })
+      pruneFinishedJobs()
+    } else {
+      logger.error('Scene analysis failed', error as Error, { jobId: job.progress.jobId })
+    }
+  })
+  return job.progress.jobId
+}
+
+export function getSceneAnalysisStatus(jobId: string): SceneAnalysisProgress | null {
+  const job = jobs.get(jobId)
+  return job ? { ...job.progress } : null
+}
+
+export function cancelSceneAnalysis(jobId: string): boolean {
+  const job = jobs.get(jobId)
+  if (!job || job.progress.status !== 'running') {
+    return false
+  }
+  job.controller.abort()
+  return true
+}
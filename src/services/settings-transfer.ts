@@ -0,0 +1,236 @@
+/**
+ * Settings Transfer
+ * Export the settings to a file and import them again, on this machine or another one.
+ *
+ * The export is an envelope of the app version, a SHA-256 checksum and the settings. By
+ * default it is redacted, as exported settings tend to end up in bug reports: the cookie
+ * browser is blanked, credentials are removed from URLs and paths in the home directory are
+ * written as ~/... Importing a redacted file keeps the current value of a blanked field, keeps
+ * the current credentials of a URL that only lost them (a proxy with a password stays usable)
+ * and expands ~ to this machine's home directory.
+ *
+ * Import also takes a plain config.json without the envelope. A checksum that doesn't match
+ * (a hand-edited file) is a warning, not an error; settings that fail validation are not
+ * imported. The preview lists every field the import would change, for the UI to confirm.
+ */
+
+import { createHash } from 'crypto'
+import { readFileSync, writeFileSync } from 'fs'
+import { homedir } from 'os'
+import { app } from 'electron'
+
+import type { AppConfig } from '../types/config'
+import type { SettingsChange, SettingsExport, SettingsImportPreview } from '../types/system'
+import { ConfigManager } from '../utils/config'
+import { ValidationUtils } from '../utils/validation'
+
+const SECRET_FIELDS = ['download.cookiesFromBrowser'] // Blanked in redacted exports
+const MACHINE_FIELDS = ['windowState'] // Never exported
+
+interface ParsedSettings {
+  version: string | null
+  settings: Partial<AppConfig>
+  warnings: string[]
+}
+
+function isPlainObject(value: unknown): value is Record<string, any> {
+  return value !== null && typeof value === 'object' && !Array.isArray(value)
+}
+
+function checksumOf(settings: Record<string, any>): string {
+  return createHash('sha256').update(JSON.stringify(settings)).digest('hex')
+}
+
+/**
+ * The object holding a dotted field and the field's key in it
+ */
+function locateField(settings: Record<string, any>, path: string): [Record<string, any> | undefined, string] {
+  const keys = path.split('.')
+  const key = keys.pop()!
+  const parent = keys.reduce<any>((value, part) => (isPlainObject(value) ? value[part] : undefined), settings)
+  return [isPlainObject(parent) ? parent : undefined, key]
+}
+
+function getField(settings: Record<string, any>, path: string): unknown {
+  const [parent, key] = locateField(settings, path)
+  return parent?.[key]
+}
+
+function deleteField(settings: Record<string, any>, path: string): void {
+  const [parent, key] = locateField(settings, path)
+  if (parent) delete parent[key]
+}
+
+/**
+ * Apply `transform` to every string in a JSON value, keeping its structure
+ */
+function mapStrings(value: unknown, transform: (text: string) => string): any {
+  if (typeof value === 'string') return transform(value)
+  if (Array.isArray(value)) return value.map(item => mapStrings(item, transform))
+  if (isPlainObject(value)) {
+    return Object.fromEntries(Object.entries(value).map(([key, item]) => [key, mapStrings(item, transform)]))
+  }
+  return value
+}
+
+/**
+ * Drop the user:password part of a URL
+ */
+export function stripUrlCredentials(text: string): string {
+  return text.replace(/^([a-z][a-z0-9+.-]*:\/\/)[^/@\s]*@/i, '$1')
+}
+
+/**
+ * Write a path in the home directory as ~/..., with the separator the path uses
+ */
+export function shortenHomePath(text: string, home: string = homedir()): string {
+  const root = home.replace(/[\\/]+$/, '')
+  if (!root || !text.toLowerCase().startsWith(root.toLowerCase())) return text
+  const rest = text.slice(root.length)
+  return rest === '' || rest.startsWith('/') || rest.startsWith('\\') ? `~${rest}` : text
+}
+
+export function expandHomePath(text: string, home: string = homedir()): string {
+  return text === '~' || text.startsWith('~/') || text.startsWith('~\\') ? `${home}${text.slice(1)}` : text
+}
+
+/**
+ * A copy of the settings that is safe to share
+ */
+export function redactSettings(settings: Record<string, any>, home?: string): Record<string, any> {
+  const redacted = mapStrings(settings, text => shortenHomePath(stripUrlCredentials(text), home))
+  for (const path of SECRET_FIELDS) {
+    const [parent, key] = locateField(redacted, path)
+    if (parent && typeof parent[key] === 'string') parent[key] = ''
+  }
+  return redacted
+}
+
+/**
+ * Put back the current value of every URL that only differs from the imported one by its
+ * removed credentials
+ */
+function restoreUrlCredentials(imported: unknown, current: unknown): any {
+  if (typeof imported === 'string') {
+    const keep = typeof current === 'string' && current !== imported && stripUrlCredentials(current) === imported
+    return keep ? current : imported
+  }
+  if (isPlainObject(imported)) {
+    const currentObject = isPlainObject(current) ? current : {}
+    return Object.fromEntries(
+      Object.entries(imported).map(([key, value]) => [key, restoreUrlCredentials(value, currentObject[key])]),
+    )
+  }
+  return imported
+}
+
+/**
+ * The export envelope for the current settings
+ */
+export function buildSettingsExport(redactSecrets: boolean = true): SettingsExport {
+  const settings: Record<string, any> = structuredClone(ConfigManager.getInstance().getAll())
+  MACHINE_FIELDS.forEach(path => deleteField(settings, path))
+  const exported = redactSecrets ? redactSettings(settings) : settings
+  return { version: app.getVersion(), checksum: checksumOf(exported), redacted: redactSecrets, settings: exported }
+}
+
+export function writeSettingsExport(outputPath: string, redactSecrets: boolean = true): SettingsExport {
+  const envelope = buildSettingsExport(redactSecrets)
+  writeFileSync(outputPath, JSON.stringify(envelope, null, 2), 'utf-8')
+  return envelope
+}
+
+/**
+ * Settings from the contents of an exported file or a plain config.json, ready to merge
+ * into the current configuration
+ */
+export function parseSettingsFile(content: string, home?: string): ParsedSettings {
+  let data: unknown
+  try {
+    data = JSON.parse(content)
+  } catch {
+    throw new Error('The file is not valid JSON')
+  }
+  if (!isPlainObject(data)) {
+    throw new Error('The file does not contain settings')
+  }
+
+  const warnings: string[] = []
+  const isEnvelope = isPlainObject(data.settings) && typeof data.checksum === 'string'
+  let settings: Record<string, any> = isEnvelope ? data.settings : data
+  if (isEnvelope && checksumOf(settings) !== data.checksum) {
+    warnings.push('The checksum does not match; the file was changed after it was exported')
+  }
+
+  const current: Record<string, any> = ConfigManager.getInstance().getAll()
+  const known = Object.keys(current)
+  const unknown = Object.keys(settings).filter(key => !known.includes(key))
+  if (unknown.length > 0) {
+    warnings.push(`Ignored unknown settings: ${unknown.join(', ')}`)
+  }
+  settings = Object.fromEntries(Object.entries(settings).filter(([key]) => known.includes(key)))
+  MACHINE_FIELDS.forEach(path => deleteField(settings, path))
+
+  if (isEnvelope && data.redacted) {
+    SECRET_FIELDS.filter(path => getField(settings, path) === '').forEach(path => deleteField(settings, path))
+  }
+  settings = mapStrings(settings, text => expandHomePath(text, home))
+  if (isEnvelope && data.redacted) {
+    settings = restoreUrlCredentials(settings, current)
+  }
+
+  return { version: isEnvelope && typeof data.version === 'string' ? data.version : null, settings, warnings }
+}
+
+/**
+ * Every leaf field whose value differs between two configurations
+ */
+export function diffSettings(current: Record<string, any>, next: Record<string, any>, prefix = ''): SettingsChange[] {
+  const changes: SettingsChange[] = []
+  const keys = new Set([...Object.keys(current), ...Object.keys(next)])
+  for (const key of keys) {
+    const path = prefix ? `${prefix}.${key}` : key
+    const from = current[key]
+    const to = next[key]
+    if (isPlainObject(from) && isPlainObject(to)) {
+      changes.push(...diffSettings(from, to, path))
+    } else if (JSON.stringify(from) !== JSON.stringify(to)) {
+      changes.push({ path, from, to })
+    }
+  }
+  return changes
+}
+
+function loadImport(filePath: string): ParsedSettings & { changes: SettingsChange[] } {
+  const configManager = ConfigManager.getInstance()
+  const parsed = parseSettingsFile(readFileSync(filePath, 'utf-8'))
+  const next = configManager.preview(parsed.settings)
+  const { errors } = configManager.validate(next)
+  const proxy = next.advanced.proxyUrl ? ValidationUtils.validateProxyUrl(next.advanced.proxyUrl) : null
+  if (proxy && !proxy.isValid) {
+    errors.push(`Invalid proxy URL: ${proxy.error}`)
+  }
+  if (errors.length > 0) {
+    throw new Error(`Invalid settings: ${errors.join('; ')}`)
+  }
+  return { ...parsed, changes: diffSettings(configManager.getAll(), next) }
+}
+
+/**
+ * What importing the file would change, without changing anything
+ */
+export function previewSettingsImport(filePath: string): SettingsImportPreview {
+  const { version, changes, warnings } = loadImport(filePath)
+  return { version, changes, warnings }
+}
+
+/**
+ * Import the file into the configuration. Returns what changed.
+ */
+export function importSettings(filePath: string): SettingsImportPreview {
+  const { version, settings, changes, warnings } = loadImport(filePath)
+  if (changes.length > 0) {
+    ConfigManager.getInstance().update(settings)
+  }
+  return { version, changes, warnings }
+}
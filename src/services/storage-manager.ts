@@ -7,12 +7,15 @@ import { basename, dirname, extname, join } from 'path'
 import { existsSync, mkdirSync, readdirSync, statSync, unlinkSync } from 'fs'
 
 import { ConfigManager } from '../utils/config'
-import type { DownloadProgress } from '../types/download'
+import { type DownloadProgress, isCompletedStatus } from '../types/download'
 import { FileSystemUtils } from '../utils/file-system'
 import { Logger } from '../utils/logger'
 import { PlatformUtils } from '../utils/platform'
 import { exec } from 'child_process'
 
+/** yt-dlp's cache folder inside the cache directory; cleaned along with the cache */
+export const YTDLP_CACHE_FOLDER = 'yt-dlp'
+
 export interface StorageStats {
   downloadsPath: string
   cachePath: string
@@ -171,6 +174,20 @@ export class StorageManager {
     return join(this.tempPath, filename)
   }
 
+  /**
+   * Get the temp directory (storage.tempPath)
+   */
+  getTempDirectory(): string {
+    return this.tempPath
+  }
+
+  /**
+   * Get the cache directory
+   */
+  getCacheDirectory(): string {
+    return this.cachePath
+  }
+
   /**
    * Get cache file path
    */
@@ -202,7 +219,7 @@ export class StorageManager {
    */
   async cleanCacheFiles(maxAge: number = 7 * 24 * 60 * 60 * 1000): Promise<number> {
     try {
-      return await this.cleanDirectory(this.cachePath, maxAge)
+      return await this.cleanDirectory(this.cachePath, maxAge, [YTDLP_CACHE_FOLDER])
     } catch (error) {
       this.logger.error('Failed to clean cache files', error as Error)
       return 0
@@ -217,7 +234,9 @@ export class StorageManager {
       const downloads = await this.loadDownloadHistory()
       const cutoffTime = Date.now() - maxAge
 
-      const toRemove = downloads.filter(download => download.status === 'completed' && download.startTime < cutoffTime)
+      const toRemove = downloads.filter(
+        download => isCompletedStatus(download.status) && download.startTime < cutoffTime,
+      )
 
       for (const download of toRemove) {
         if (download.filePath && existsSync(download.filePath)) {
@@ -369,9 +388,10 @@ export class StorageManager {
   }
 
   /**
-   * Clean directory of old files
+   * Clean directory of old files. Of its folders only the named ones are cleaned, fully;
+   * the rest (e.g. transcodes/, pruned by transcode-cache) are left alone.
    */
-  private async cleanDirectory(dirPath: string, maxAge: number): Promise<number> {
+  private async cleanDirectory(dirPath: string, maxAge: number, subfolders: string[] | 'all' = []): Promise<number> {
     try {
       if (!existsSync(dirPath)) {
         return 0
@@ -385,7 +405,12 @@ export class StorageManager {
         const filePath = join(dirPath, file)
         try {
           const stats = statSync(filePath)
-          if (stats.mtime.getTime() < cutoffTime) {
+          if (stats.isDirectory()) {
+            // The folder itself is kept
+            if (subfolders === 'all' || subfolders.includes(file)) {
+              removedCount += await this.cleanDirectory(filePath, maxAge, 'all')
+            }
+          } else if (stats.mtime.getTime() < cutoffTime) {
             unlinkSync(filePath)
             removedCount++
           }
@@ -0,0 +1,375 @@
+/**
+ * Thumbnail Cache Service
+ * Stores remote preview images (video thumbnails and storyboard sprite sheets) locally
+ * so the renderer can load them through clipy-file:// without CORS issues
+ */
+
+import { existsSync, mkdirSync, rmSync, statSync } from 'fs'
+
+import type { DownloadProgress, StoryboardDownload, StoryboardInfo } from '../types/download'
+import type { ThumbnailCacheStats } from '../types/system'
+import { ConfigManager } from '../utils/config'
+import { FileSystemUtils } from '../utils/file-system'
+import { Logger } from '../utils/logger'
+import { PlatformUtils } from '../utils/platform'
+import { get } from 'https'
+import { basename, join } from 'path'
+import { getLibraryEntries, getVideoIdFromUrl } from './library'
+import { updateDownloadInStorage } from './download-storage'
+
+export interface CachedThumbnailEntry {
+  videoId: string
+  files: string[]
+  size: number
+  createdAt: number
+  lastAccessed: number
+  thumbnail?: string
+  storyboard?: StoryboardDownload
+}
+
+/** Hosts that serve YouTube preview images */
+const ALLOWED_IMAGE_HOSTS = ['ytimg.com', 'ggpht.com', 'googleusercontent.com']
+
+const DAY_MS = 24 * 60 * 60 * 1000
+const DEFAULT_THUMBNAIL_MAX_AGE_DAYS = 30
+
+export class ThumbnailCache {
+  private static instance: ThumbnailCache
+  private cacheDir: string
+  private indexFile: string
+  private cacheIndex: Map<string, CachedThumbnailEntry> = new Map()
+  private configManager = ConfigManager.getInstance()
+  private logger = Logger.getInstance()
+  private fileSystem = FileSystemUtils.getInstance()
+  private platform = PlatformUtils.getInstance()
+
+  private readonly MAX_CACHE_SIZE = 500 * 1024 * 1024 // 500MB
+  private readonly DEFAULT_MAX_AGE = 7 * 24 * 60 * 60 * 1000 // 7 days
+  private readonly DEFAULT_CLEANUP_INTERVAL = 60 * 60 * 1000 // 1 hour
+
+  private constructor() {
+    this.cacheDir = join(this.platform.getAppDataDir('clipy'), 'thumbnail-cache')
+    this.indexFile = join(this.cacheDir, 'cache-index.json')
+    if (!existsSync(this.cacheDir)) {
+      mkdirSync(this.cacheDir, { recursive: true })
+    }
+    this.initializeAsync()
+  }
+
+  private async initializeAsync(): Promise<void> {
+    await this.loadCacheIndex()
+    const interval = this.configManager.get('cache')?.cleanupInterval ?? this.DEFAULT_CLEANUP_INTERVAL
+    setInterval(() => this.performCleanup(), interval)
+  }
+
+  static getInstance(): ThumbnailCache {
+    if (!ThumbnailCache.instance) {
+      ThumbnailCache.instance = new ThumbnailCache()
+    }
+    return ThumbnailCache.instance
+  }
+
+  getCacheDirectory(): string {
+    return this.cacheDir
+  }
+
+  /**
+   * Re-read the cache index from disk (e.g. after a backup restore replaced it)
+   */
+  async reloadIndex(): Promise<void> {
+    await this.loadCacheIndex()
+  }
+
+  /**
+   * Download all sprite sheets of a storyboard into the cache.
+   * Returns the cached copy if this video's storyboard was already fetched.
+   */
+  async downloadStoryboard(videoId: string, storyboard: StoryboardInfo): Promise<StoryboardDownload> {
+    const cached = this.cacheIndex.get(videoId)
+    if (
+      cached?.storyboard &&
+      cached.storyboard.formatId === storyboard.formatId &&
+      cached.files.every(file => existsSync(file))
+    ) {
+      cached.lastAccessed = Date.now()
+      this.saveCacheIndex()
+      return cached.storyboard
+    }
+
+    const videoDir = join(this.cacheDir, videoId)
+    await this.fileSystem.ensureDirectory(videoDir)
+
+    const paths: string[] = []
+    let size = 0
+    for (let i = 0; i < storyboard.fragments.length; i++) {
+      const outputPath = join(videoDir, `storyboard_${storyboard.formatId}_${i.toString().padStart(3, '0')}.jpg`)
+      const buffer = await this.fetchImage(storyboard.fragments[i])
+      await this.fileSystem.writeFile(outputPath, buffer)
+      paths.push(outputPath)
+      size += buffer.length
+    }
+
+    const result: StoryboardDownload = {
+      videoId,
+      formatId: storyboard.formatId,
+      paths,
+      columns: storyboard.columns,
+      rows: storyboard.rows,
+      frameWidth: storyboard.frameWidth,
+      frameHeight: storyboard.frameHeight,
+      interval: storyboard.interval,
+    }
+
+    // Keep a prefetched thumbnail that shares this video's entry
+    const thumbnail = cached?.thumbnail && existsSync(cached.thumbnail) ? cached.thumbnail : undefined
+    this.cacheIndex.set(videoId, {
+      videoId,
+      files: thumbnail ? [thumbnail, ...paths] : paths,
+      size: size + (thumbnail ? statSync(thumbnail).size : 0),
+      createdAt: Date.now(),
+      lastAccessed: Date.now(),
+      thumbnail,
+      storyboard: result,
+    })
+    this.saveCacheIndex()
+
+    this.logger.info('Storyboard cached', { videoId, sheets: paths.length, size })
+    return result
+  }
+
+  /**
+   * Local path of a video's cached thumbnail
+   */
+  getThumbnailCachePath(videoId: string): string {
+    return join(this.cacheDir, videoId, 'thumbnail.jpg')
+  }
+
+  /**
+   * Fetch a channel avatar into the cache, keyed by channel ID. Returns the local path.
+   */
+  async cacheChannelAvatar(channelId: string, url: string): Promise<string> {
+    const buffer = await this.fetchImage(url)
+    const outputPath = join(this.cacheDir, channelId, 'avatar.jpg')
+    await this.fileSystem.ensureDirectory(join(this.cacheDir, channelId))
+    await this.fileSystem.writeFile(outputPath, buffer)
+
+    const now = Date.now()
+    this.cacheIndex.set(channelId, {
+      videoId: channelId,
+      files: [outputPath],
+      size: buffer.length,
+      createdAt: now,
+      lastAccessed: now,
+      thumbnail: outputPath,
+    })
+    this.saveCacheIndex()
+    return outputPath
+  }
+
+  /**
+   * Whether a video's thumbnail is cached and was written less than maxAgeDays ago
+   */
+  isThumbnailFresh(videoId: string, maxAgeDays: number): boolean {
+    try {
+      const { mtimeMs } = statSync(this.getThumbnailCachePath(videoId))
+      return Date.now() - mtimeMs < maxAgeDays * DAY_MS
+    } catch {
+      return false
+    }
+  }
+
+  /**
+   * Number of cached videos, total size, and the age of the oldest cached thumbnail
+   */
+  getCacheStats(): ThumbnailCacheStats {
+    let size = 0
+    let oldest = Date.now()
+    for (const entry of this.cacheIndex.values()) {
+      size += entry.size
+      if (entry.thumbnail && existsSync(entry.thumbnail)) {
+        oldest = Math.min(oldest, statSync(entry.thumbnail).mtimeMs)
+      }
+    }
+    return { count: this.cacheIndex.size, size, oldestDays: Math.floor((Date.now() - oldest) / DAY_MS) }
+  }
+
+  /**
+   * Download thumbnails of library videos that aren't cached yet or whose cached copy is
+   * older than advanced.thumbnailCacheDays, at most maxConcurrent at a time.
+   * Each cached path is recorded on the download. Returns the number of thumbnails cached.
+   */
+  async prefetchLibraryThumbnails(maxConcurrent: number = 4): Promise<number> {
+    const maxAgeDays = this.configManager.get('advanced')?.thumbnailCacheDays || DEFAULT_THUMBNAIL_MAX_AGE_DAYS
+    const pending = getLibraryEntries().filter(download => {
+      const videoId = download.videoId ?? getVideoIdFromUrl(download.url)
+      if (!videoId || (download.thumbnailPath && existsSync(download.thumbnailPath))) return false
+      return !this.isThumbnailFresh(videoId, maxAgeDays)
+    })
+    if (pending.length === 0) return 0
+
+    let cachedCount = 0
+    let next = 0
+    const worker = async () => {
+      while (next < pending.length) {
+        const download = pending[next++]
+        if (await this.cacheLibraryThumbnail(download)) {
+          cachedCount++
+        }
+      }
+    }
+    await Promise.all(Array.from({ length: Math.max(1, Math.min(maxConcurrent, pending.length)) }, worker))
+
+    this.saveCacheIndex()
+    this.logger.info('Library thumbnails prefetched', { cached: cachedCount, candidates: pending.length })
+    return cachedCount
+  }
+
+  /**
+   * Fetch and cache one library video's thumbnail. Videos downloaded before thumbnail URLs
+   * were recorded fall back to YouTube's standard thumbnail URL.
+   */
+  private async cacheLibraryThumbnail(download: DownloadProgress): Promise<boolean> {
+    const videoId = (download.videoId ?? getVideoIdFromUrl(download.url))!
+    const url = download.thumbnailUrl || `https://i.ytimg.com/vi/${videoId}/hqdefault.jpg`
+    try {
+      const buffer = await this.fetchImage(url)
+      const outputPath = this.getThumbnailCachePath(videoId)
+      const replacedSize = existsSync(outputPath) ? statSync(outputPath).size : 0 // Stale copy being refreshed
+      await this.fileSystem.ensureDirectory(join(this.cacheDir, videoId))
+      await this.fileSystem.writeFile(outputPath, buffer)
+
+      const entry = this.cacheIndex.get(videoId)
+      const now = Date.now()
+      this.cacheIndex.set(videoId, {
+        videoId,
+        files: [outputPath, ...(entry?.files.filter(file => file !== outputPath) ?? [])],
+        size: Math.max(0, (entry?.size ?? 0) - replacedSize) + buffer.length,
+        createdAt: entry?.createdAt ?? now,
+        lastAccessed: now,
+        thumbnail: outputPath,
+        storyboard: entry?.storyboard,
+      })
+      updateDownloadInStorage(download.downloadId, { cachedThumbnailPath: outputPath })
+      return true
+    } catch (error) {
+      this.logger.debug('Failed to prefetch thumbnail', { videoId, error: (error as Error).message })
+      return false
+    }
+  }
+
+  /**
+   * Remove all cached images for a video
+   */
+  removeFromCache(videoId: string): boolean {
+    const entry = this.cacheIndex.get(videoId)
+    if (!entry) return false
+
+    try {
+      rmSync(join(this.cacheDir, videoId), { recursive: true, force: true })
+      this.cacheIndex.delete(videoId)
+      this.saveCacheIndex()
+      return true
+    } catch (error) {
+      this.logger.error('Failed to remove thumbnails from cache', error as Error, { videoId })
+      return false
+    }
+  }
+
+  /**
+   * Fetch an image over HTTPS from an allowed YouTube image host
+   */
+  private fetchImage(url: string): Promise<Buffer> {
+    return new Promise((resolve, reject) => {
+      let parsed: URL
+      try {
+        parsed = new URL(url)
+      } catch {
+        reject(new Error('Invalid image URL'))
+        return
+      }
+
+      const hostAllowed = ALLOWED_IMAGE_HOSTS.some(
+        host => parsed.hostname === host || parsed.hostname.endsWith(`.${host}`),
+      )
+      if (parsed.protocol !== 'https:' || !hostAllowed) {
+        reject(new Error(`Image host not allowed: ${parsed.hostname}`))
+        return
+      }
+
+      const request = get(parsed, response => {
+        if (response.statusCode !== 200) {
+          response.resume()
+          reject(new Error(`Image request failed with status ${response.statusCode}`))
+          return
+        }
+
+        const chunks: Buffer[] = []
+        response.on('data', chunk => chunks.push(chunk))
+        response.on('end', () => resolve(Buffer.concat(chunks)))
+        response.on('error', reject)
+      })
+
+      request.setTimeout(15000, () => request.destroy(new Error('Image request timed out')))
+      request.on('error', reject)
+    })
+  }
+
+  private async loadCacheIndex(): Promise<void> {
+    try {
+      if (existsSync(this.indexFile)) {
+        const data = await this.fileSystem.readJsonFile(this.indexFile)
+        if (data && typeof data === 'object') {
+          const entries = Object.entries(data) as [string, CachedThumbnailEntry][]
+          // Rebase stored paths onto the current cache dir (the index may come from a restored backup)
+          const rebase = (videoId: string, file: string) => join(this.cacheDir, videoId, basename(file))
+          for (const [videoId, entry] of entries) {
+            entry.files = entry.files.map(file => rebase(videoId, file))
+            if (entry.thumbnail) {
+              entry.thumbnail = rebase(videoId, entry.thumbnail)
+            }
+            if (entry.storyboard) {
+              entry.storyboard.paths = entry.storyboard.paths.map(file => rebase(videoId, file))
+            }
+          }
+          this.cacheIndex = new Map(entries)
+        }
+      }
+    } catch (error) {
+      this.logger.error('Failed to load thumbnail cache index', error as Error)
+      this.cacheIndex = new Map()
+    }
+  }
+
+  private saveCacheIndex(): void {
+    try {
+      this.fileSystem.writeJsonFile(this.indexFile, Object.fromEntries(this.cacheIndex))
+    } catch (error) {
+      this.logger.error('Failed to save thumbnail cache index', error as Error)
+    }
+  }
+
+  /**
+   * Evict entries past the configured max age, then least recently used entries
+   * until the cache is back under 80% of its size limit
+   */
+  private performCleanup(): void {
+    const maxAge = this.configManager.get('cache')?.maxAge ?? this.DEFAULT_MAX_AGE
+    const now = Date.now()
+
+    for (const [videoId, entry] of this.cacheIndex) {
+      if (now - entry.lastAccessed > maxAge) {
+        this.removeFromCache(videoId)
+      }
+    }
+
+    let totalSize = Array.from(this.cacheIndex.values()).reduce((sum, entry) => sum + entry.size, 0)
+    if (totalSize > this.MAX_CACHE_SIZE) {
+      const byAccess = Array.from(this.cacheIndex.values()).sort((a, b) => a.lastAccessed - b.lastAccessed)
+      for (const entry of byAccess) {
+        if (totalSize <= this.MAX_CACHE_SIZE * 0.8) break
+        this.removeFromCache(entry.videoId)
+        totalSize -= entry.size
+      }
+    }
+  }
+}
@@ -0,0 +1,194 @@
+/**
+ * Transcode Cache
+ * Editing transcodes (VideoProcessor.transcodeForEditing) are kept in the cache folder and
+ * registered in transcodes/index.json by the source's content fingerprint and the profile,
+ * so reopening a project reuses the copy made last time instead of transcoding the same
+ * source again. A source that was renamed or moved has the same fingerprint and still hits.
+ *
+ * Transcodes run through the analysis pool, one per source and profile at a time: asking
+ * again while one runs waits for it. 'progress' events report EditingTranscodeProgress.
+ *
+ * The transcodes count toward cache.maxSize together with the video cache; its maintenance
+ * evicts the least recently used ones, files included, once they don't fit.
+ */
+
+import { EventEmitter } from 'events'
+import { existsSync, mkdirSync, readFileSync, rmSync, statSync, utimesSync, writeFileSync } from 'fs'
+import { join, resolve } from 'path'
+
+import type { EditingTranscodeProfile, EditingTranscodeProgress, EditingTranscodeResult } from '../types/project'
+import { Logger } from '../utils/logger'
+import { type AnalysisJobOptions, runAnalysisJob } from './analysis-pool'
+import { getMediaFingerprint } from './media-cache'
+import { StorageManager } from './storage-manager'
+import { VideoProcessor } from './video-processor'
+
+const logger = Logger.getInstance()
+
+const INDEX_FILE = 'index.json'
+
+export interface TranscodeCacheEntry {
+  fingerprint: string // Of the source, from the media cache
+  profile: EditingTranscodeProfile
+  outputPath: string
+  createdAt: number
+  lastUsedAt: number
+  size: number // Bytes
+}
+
+/** Emits 'progress' with EditingTranscodeProgress */
+export const transcodeEvents = new EventEmitter()
+
+let entries: TranscodeCacheEntry[] | null = null
+const pendingTranscodes = new Map<string, Promise<EditingTranscodeResult>>()
+
+export function getTranscodeCacheDir(): string {
+  return join(StorageManager.getInstance().getCacheDirectory(), 'transcodes')
+}
+
+function loadEntries(): TranscodeCacheEntry[] {
+  if (entries) {
+    return entries
+  }
+  const indexPath = join(getTranscodeCacheDir(), INDEX_FILE)
+  try {
+    entries = existsSync(indexPath) ? (JSON.parse(readFileSync(indexPath, 'utf-8')).entries ?? []) : []
+  } catch (error) {
+    // A lost index only means the next open transcodes again
+    logger.warn('Error loading transcode cache index, starting empty', error as Error)
+    entries = []
+  }
+  return entries!
+}
+
+function saveEntries(): void {
+  try {
+    mkdirSync(getTranscodeCacheDir(), { recursive: true })
+    const data = JSON.stringify({ entries: loadEntries(), lastUpdated: Date.now() }, null, 2)
+    writeFileSync(join(getTranscodeCacheDir(), INDEX_FILE), data, 'utf-8')
+  } catch (error) {
+    logger.error('Failed to save transcode cache index', error as Error)
+  }
+}
+
+/**
+ * An editing copy of a source: the cached one when the source's content and the profile
+ * match and its file still exists, otherwise a new transcode, which is then registered
+ */
+export async function getOrTranscodeForEditing(
+  inputPath: string,
+  profile: EditingTranscodeProfile,
+  options: AnalysisJobOptions = {},
+): Promise<EditingTranscodeResult> {
+  const fingerprint = getMediaFingerprint(inputPath)
+  const cached = loadEntries().find(entry => entry.fingerprint === fingerprint && entry.profile === profile)
+  if (cached && existsSync(cached.outputPath)) {
+    cached.lastUsedAt = Date.now()
+    saveEntries()
+    // The cache folder's age-based cleanup goes by mtime; a transcode in use isn't old
+    const now = new Date(cached.lastUsedAt)
+    utimesSync(cached.outputPath, now, now)
+    logger.debug('Reusing editing transcode', { inputPath, profile, outputPath: cached.outputPath })
+    return { outputPath: cached.outputPath, cached: true }
+  }
+
+  const key = `${fingerprint}:${profile}`
+  let pending = pendingTranscodes.get(key)
+  if (!pending) {
+    pending = transcode(inputPath, fingerprint, profile, options).finally(() => pendingTranscodes.delete(key))
+    pendingTranscodes.set(key, pending)
+  }
+  return pending
+}
+
+async function transcode(
+  inputPath: string,
+  fingerprint: string,
+  profile: EditingTranscodeProfile,
+  options: AnalysisJobOptions,
+): Promise<EditingTranscodeResult> {
+  const videoProcessor = VideoProcessor.getInstance()
+  const outputPath = join(getTranscodeCacheDir(), `${fingerprint}-${profile}.mp4`)
+  const duration = await videoProcessor
+    .getVideoMetadata(inputPath)
+    .then(metadata => metadata.duration)
+    .catch(() => 0)
+
+  const emitProgress = (progress: number) => {
+    const event: EditingTranscodeProgress = { inputPath, profile, progress }
+    transcodeEvents.emit('progress', event)
+  }
+  emitProgress(0)
+  await runAnalysisJob(
+    'transcode',
+    signal =>
+      videoProcessor.transcodeForEditing(inputPath, outputPath, profile, {
+        signal,
+        onProgress: seconds => {
+          if (duration > 0) {
+            emitProgress(Math.min(99, Math.round((seconds / duration) * 100)))
+          }
+        },
+      }),
+    options,
+  )
+  emitProgress(100)
+
+  const now = Date.now()
+  const entry: TranscodeCacheEntry = {
+    fingerprint,
+    profile,
+    outputPath,
+    createdAt: now,
+    lastUsedAt: now,
+    size: statSync(outputPath).size,
+  }
+  entries = [...loadEntries().filter(other => other.fingerprint !== fingerprint || other.profile !== profile), entry]
+  saveEntries()
+  logger.info('Editing transcode cached', { inputPath, profile, size: entry.size })
+  return { outputPath, cached: false }
+}
+
+/**
+ * An existing editing transcode of a source, the full-resolution one if there are both;
+ * null when there is none. Never starts a transcode.
+ */
+export function findCachedTranscode(inputPath: string): string | null {
+  const fingerprint = getMediaFingerprint(inputPath)
+  const cached = loadEntries().filter(entry => entry.fingerprint === fingerprint && existsSync(entry.outputPath))
+  return (cached.find(entry => entry.profile === 'edit') ?? cached[0])?.outputPath ?? null
+}
+
+/**
+ * Whether a file is a cached transcode, so the editor may open it like its source
+ */
+export function isCachedTranscode(filePath: string): boolean {
+  const target = resolve(filePath)
+  return loadEntries().some(entry => resolve(entry.outputPath) === target)
+}
+
+/**
+ * Forget transcodes whose file is gone, then delete the least recently used ones until the
+ * rest fit in maxBytes. Returns how many entries were removed.
+ */
+export function pruneTranscodeCache(maxBytes: number): number {
+  const all = loadEntries()
+  const existing = all.filter(entry => existsSync(entry.outputPath))
+  let totalSize = existing.reduce((sum, entry) => sum + entry.size, 0)
+
+  const evicted = new Set<TranscodeCacheEntry>()
+  for (const entry of [...existing].sort((a, b) => a.lastUsedAt - b.lastUsedAt)) {
+    if (totalSize <= maxBytes) break
+    rmSync(entry.outputPath, { force: true })
+    evicted.add(entry)
+    totalSize -= entry.size
+  }
+
+  const removed = all.length - existing.length + evicted.size
+  if (removed > 0) {
+    entries = existing.filter(entry => !evicted.has(entry))
+    saveEntries()
+    logger.info('Transcode cache pruned', { removed, evicted: evicted.size, remainingSize: totalSize })
+  }
+  return removed
+}
@@ -9,6 +9,9 @@ import { ConfigManager } from '../utils/config'
 import { FileSystemUtils } from '../utils/file-system'
 import { Logger } from '../utils/logger'
 import { PlatformUtils } from '../utils/platform'
+import { cleanupDownloadLogs } from './downloader/download-log'
+import { pruneMediaCache } from './media-cache'
+import { pruneTranscodeCache } from './transcode-cache'
 import { join } from 'path'
 
 export interface CachedVideo {
@@ -28,6 +31,7 @@ export interface CacheOptions {
   maxCacheSize?: number // in bytes
   maxCacheAge?: number // in milliseconds
   cleanupInterval?: number // in milliseconds
+  downloadLogRetentionDays?: number
 }
 
 export class VideoCache {
@@ -44,6 +48,7 @@ export class VideoCache {
     maxCacheSize: 10 * 1024 * 1024 * 1024, // 10GB
     maxCacheAge: 7 * 24 * 60 * 60 * 1000, // 7 days
     cleanupInterval: 60 * 60 * 1000, // 1 hour
+    downloadLogRetentionDays: 14,
   }
 
   private constructor() {
@@ -316,6 +321,13 @@ export class VideoCache {
             }, 0),
         })
       }
+
+      // Editing transcodes share the size limit and get what the cached videos leave
+      pruneTranscodeCache(Math.max(0, maxSize - this.getCacheStats().totalSize))
+
+      // Per-download yt-dlp logs and media artifacts of deleted files share the maintenance schedule
+      cleanupDownloadLogs(config.cache?.downloadLogRetentionDays ?? this.DEFAULT_OPTIONS.downloadLogRetentionDays)
+      pruneMediaCache()
     } catch (error) {
       this.logger.error('Cache cleanup failed', error as Error)
     }
@@ -3,15 +3,42 @@
  * Handles video processing operations: trimming, preview generation, format conversion
  */
 
-import { dirname, extname } from 'path'
+import { dirname, extname, join } from 'path'
 
 import { ConfigManager } from '../utils/config'
+import {
+  AUDIO_EXTRACT_FORMATS,
+  buildDrawtextFilter,
+  buildEffectFilter,
+  buildSlideshowFilter,
+  getEditingTranscodeArgs,
+  getFrameSeek,
+  getRemuxAudioEncoder,
+  getRemuxIncompatibility,
+  getRemuxVideoEncoder,
+  isRemuxContainer,
+} from '../utils/ffmpeg-args'
 import { FileSystemUtils } from '../utils/file-system'
 import { Logger } from '../utils/logger'
+import { renderToPartialOutput } from '../utils/output-file'
+import { getMediaArtifactDir } from './media-cache'
+import type {
+  AudioMergeOptions,
+  EditingTranscodeProfile,
+  Effect,
+  SceneChange,
+  SlideshowOptions,
+  TextProperties,
+} from '../types/project'
+import type { AudioExtractFormat, RemuxStreamSelection, VideoChapter } from '../types/download'
 import { PlatformUtils } from '../utils/platform'
-import { existsSync, statSync } from 'fs'
+import { applyFFmpegPriority, withFFmpegThreadLimit } from '../utils/process-priority'
+import { existsSync, mkdirSync, readdirSync, statSync, unlinkSync } from 'fs'
 import { spawn } from 'child_process'
 
+/** ffprobe codec names of still image formats */
+const STILL_IMAGE_CODECS = ['png', 'mjpeg', 'webp', 'bmp', 'tiff']
+
 export interface TimeRange {
   start: number // in seconds
   end: number // in seconds
@@ -25,6 +52,66 @@ export interface VideoMetadata {
   codec: string
   size: number
   fps: number
+  isImage?: boolean // Still image (PNG, JPEG, ...); duration is 0, so clips need an explicit length
+  hasAudio?: boolean
+}
+
+export interface StreamInfo {
+  index: number
+  streamType: string // 'video', 'audio', 'subtitle', 'attachment' or 'data'
+  codec: string
+  language?: string
+  title?: string
+  bitrate?: number
+  width?: number
+  height?: number
+  channels?: number
+  sampleRate?: number
+}
+
+/** Progress reporting and cancellation for a single FFmpeg run */
+export interface FFmpegRunOptions {
+  onProgress?: (seconds: number) => void // Output position so far
+  signal?: AbortSignal // Aborting kills FFmpeg and rejects with 'Cancelled'
+}
+
+export interface RemuxOptions extends RemuxStreamSelection, FFmpegRunOptions {
+  overwrite?: boolean
+  reencodeVideo?: boolean // Re-encode video the container can't hold instead of failing
+}
+
+export interface AudioExtractOptions extends FFmpegRunOptions {
+  format: AudioExtractFormat
+  bitrate?: number // kbps; ignored for lossless formats
+  overwrite?: boolean
+}
+
+export interface AudioMergeRunOptions extends AudioMergeOptions, FFmpegRunOptions {
+  overwrite?: boolean
+}
+
+export interface SlideshowRunOptions extends SlideshowOptions, FFmpegRunOptions {
+  overwrite?: boolean
+}
+
+export interface EffectPreviewOptions extends FFmpegRunOptions {
+  startTime?: number // Seconds into the source; default 0
+  duration?: number // Seconds; default 5
+  overwrite?: boolean
+}
+
+export interface TimelineThumbnailOptions {
+  outputDir?: string // Default: cached per file content in the media cache
+  count?: number // Default 10
+  interval?: number // Seconds between thumbnails; default spreads count over the video
+  width?: number // Default 160
+  signal?: AbortSignal // Stops before the next thumbnail
+}
+
+export interface TimelineThumbnails {
+  thumbnails: string[]
+  interval: number
+  duration: number
 }
 
 export interface ProcessingOptions {
@@ -33,6 +120,7 @@ export interface ProcessingOptions {
   audioCodec?: 'aac' | 'mp3' | 'opus' | 'copy'
   videoCodec?: 'h264' | 'h265' | 'vp9' | 'copy'
   preset?: 'ultrafast' | 'fast' | 'medium' | 'slow'
+  overwrite?: boolean // Replace an existing output file instead of picking a ' (N)' suffixed name
 }
 
 export class VideoProcessor {
@@ -106,27 +194,49 @@ export class VideoProcessor {
   }
 
   /**
-   * Execute FFmpeg command
+   * Execute FFmpeg command, resolving with its stdout
    */
-  private async executeFFmpeg(args: string[]): Promise<void> {
+  private async executeFFmpeg(args: string[], run: FFmpegRunOptions = {}): Promise<string> {
     return new Promise((resolve, reject) => {
       if (!this.ffmpegPath) {
         reject(new Error('FFmpeg not available'))
         return
       }
+      if (run.signal?.aborted) {
+        reject(new Error('Cancelled'))
+        return
+      }
 
-      this.logger.debug('Executing FFmpeg command', { args })
+      // -progress writes key=value lines to stdout, mixed into whatever the command prints there
+      const progressArgs = run.onProgress ? ['-progress', 'pipe:1', '-nostats', ...args] : args
+      const fullArgs = withFFmpegThreadLimit(progressArgs)
+      this.logger.debug('Executing FFmpeg command', { args: fullArgs })
 
-      const ffmpeg = spawn(this.ffmpegPath, args, {
+      const ffmpeg = spawn(this.ffmpegPath, fullArgs, {
         stdio: ['pipe', 'pipe', 'pipe'],
         cwd: process.cwd(),
       })
+      applyFFmpegPriority(ffmpeg.pid)
+
+      let cancelled = false
+      const onAbort = () => {
+        cancelled = true
+        ffmpeg.kill('SIGTERM')
+      }
+      run.signal?.addEventListener('abort', onAbort, { once: true })
 
       let stderr = ''
       let stdout = ''
 
       ffmpeg.stdout?.on('data', data => {
-        stdout += data.toString()
+        const text = data.toString()
+        if (run.onProgress) {
+          const matches = [...text.matchAll(/out_time_ms=(\d+)/g)]
+          if (matches.length > 0) {
+            run.onProgress(parseInt(matches[matches.length - 1][1], 10) / 1_000_000)
+          }
+        }
+        stdout += text
       })
 
       ffmpeg.stderr?.on('data', data => {
@@ -134,9 +244,12 @@ export class VideoProcessor {
       })
 
       ffmpeg.on('close', code => {
-        if (code === 0) {
+        run.signal?.removeEventListener('abort', onAbort)
+        if (cancelled) {
+          reject(new Error('Cancelled'))
+        } else if (code === 0) {
           this.logger.debug('FFmpeg command completed successfully')
-          resolve()
+          resolve(stdout)
         } else {
           const error = new Error(`FFmpeg failed with code ${code}: ${stderr}`)
           this.logger.error('FFmpeg command failed', error, { code, stderr, stdout })
@@ -145,6 +258,7 @@ export class VideoProcessor {
       })
 
       ffmpeg.on('error', error => {
+        run.signal?.removeEventListener('abort', onAbort)
         this.logger.error('FFmpeg process error', error)
         reject(error)
       })
@@ -175,14 +289,20 @@ export class VideoProcessor {
           throw new Error('No video stream found')
         }
 
+        // Stills have a single frame; an MJPEG video stream has many
+        const isImage =
+          STILL_IMAGE_CODECS.includes(videoStream.codec_name) && !(parseInt(videoStream.nb_frames, 10) > 1)
+
         return {
-          duration: parseFloat(format.duration) || 0,
+          duration: isImage ? 0 : parseFloat(format.duration) || 0,
           width: videoStream.width || 0,
           height: videoStream.height || 0,
           bitrate: parseInt(format.bit_rate) || 0,
           codec: videoStream.codec_name || 'unknown',
           size: parseInt(format.size) || 0,
           fps: this.parseFrameRate(videoStream.r_frame_rate) || 0,
+          isImage,
+          hasAudio: data.streams.some((s: any) => s.codec_type === 'audio'),
         }
       }
     } catch (error) {
@@ -199,6 +319,169 @@ export class VideoProcessor {
     }
   }
 
+  /**
+   * Every stream in a file (video, audio, subtitles, attachments), in file order
+   */
+  async getVideoStreams(filePath: string): Promise<StreamInfo[]> {
+    const ffprobePath = this.ffmpegPath?.replace('ffmpeg', 'ffprobe') ?? 'ffprobe'
+    const output = await this.executeFFprobe(ffprobePath, [
+      '-v',
+      'quiet',
+      '-print_format',
+      'json',
+      '-show_streams',
+      filePath,
+    ])
+
+    const toNumber = (value: unknown) => {
+      const parsed = Number(value)
+      return Number.isFinite(parsed) && parsed > 0 ? parsed : undefined
+    }
+    const streams: any[] = JSON.parse(output).streams ?? []
+    return streams.map(stream => ({
+      index: stream.index,
+      streamType: stream.codec_type || 'unknown',
+      codec: stream.codec_name || stream.tags?.mimetype || 'unknown',
+      language: stream.tags?.language,
+      title: stream.tags?.title ?? stream.tags?.filename,
+      bitrate: toNumber(stream.bit_rate),
+      width: toNumber(stream.width),
+      height: toNumber(stream.height),
+      channels: toNumber(stream.channels),
+      sampleRate: toNumber(stream.sample_rate),
+    }))
+  }
+
+  /**
+   * Whether a file has audio and no video but, at most, cover art
+   */
+  async isAudioOnlyFile(filePath: string): Promise<boolean> {
+    const streams = await this.getVideoStreams(filePath)
+    return (
+      streams.some(stream => stream.streamType === 'audio') &&
+      streams.every(stream => stream.streamType !== 'video' || STILL_IMAGE_CODECS.includes(stream.codec))
+    )
+  }
+
+  /**
+   * Times of the video keyframes between start and end (seconds), in order. Trims that start
+   * on one of them can be exported by stream copy. Reads packet flags, so nothing is decoded.
+   */
+  async getKeyframes(filePath: string, start: number, end: number): Promise<number[]> {
+    const ffprobePath = this.ffmpegPath?.replace('ffmpeg', 'ffprobe') ?? 'ffprobe'
+    const output = await this.executeFFprobe(ffprobePath, [
+      '-v',
+      'error',
+      '-select_streams',
+      'v:0',
+      '-read_intervals',
+      `${start}%${end}`,
+      '-show_entries',
+      'packet=pts_time,flags',
+      '-of',
+      'csv=p=0',
+      filePath,
+    ])
+
+    // Lines look like '12.345000,K__'; -read_intervals starts at the keyframe before start
+    const keyframes = new Set<number>()
+    for (const line of output.split(/\r?\n/)) {
+      const [ptsTime, flags] = line.split(',')
+      const pts = parseFloat(ptsTime)
+      if (flags?.startsWith('K') && !isNaN(pts) && pts >= start && pts <= end) {
+        keyframes.add(pts)
+      }
+    }
+    return Array.from(keyframes).sort((a, b) => a - b)
+  }
+
+  /**
+   * Chapters embedded in a file, in order; empty when it has none
+   */
+  async getChapters(filePath: string): Promise<VideoChapter[]> {
+    const ffprobePath = this.ffmpegPath?.replace('ffmpeg', 'ffprobe') ?? 'ffprobe'
+    const output = await this.executeFFprobe(ffprobePath, [
+      '-v',
+      'quiet',
+      '-print_format',
+      'json',
+      '-show_chapters',
+      filePath,
+    ])
+
+    const chapters: any[] = JSON.parse(output).chapters ?? []
+    return chapters.map((chapter, i) => ({
+      start: Number(chapter.start_time) || 0,
+      end: Number(chapter.end_time) || 0,
+      title: chapter.tags?.title || `Chapter ${i + 1}`,
+    }))
+  }
+
+  /**
+   * Thumbnail strip for the timeline. Without an output folder the thumbnails are cached per
+   * file content and reused. A thumbnail that fails to render is left out.
+   */
+  async generateTimelineThumbnails(
+    inputPath: string,
+    options: TimelineThumbnailOptions = {},
+  ): Promise<TimelineThumbnails> {
+    const { outputDir, count = 10, interval, width = 160, signal } = options
+    const { duration } = await this.getVideoMetadata(inputPath)
+    const thumbInterval = interval || duration / count
+
+    const thumbDir =
+      outputDir || join(getMediaArtifactDir(inputPath), `thumbs_w${width}_n${count}_i${interval ?? 'auto'}`)
+    await this.fileSystem.ensureDirectory(thumbDir)
+
+    const thumbPath = (i: number) => join(thumbDir, `thumb_${i.toString().padStart(3, '0')}.jpg`)
+    if (!outputDir && Array.from({ length: count }, (_, i) => thumbPath(i)).every(path => existsSync(path))) {
+      return { thumbnails: Array.from({ length: count }, (_, i) => thumbPath(i)), interval: thumbInterval, duration }
+    }
+
+    const thumbnails: string[] = []
+    for (let i = 0; i < count; i++) {
+      if (signal?.aborted) {
+        throw new Error('Thumbnail generation cancelled')
+      }
+      const time = Math.min(i * thumbInterval, duration - 0.1)
+      const outputPath = thumbPath(i)
+      try {
+        const args = ['-ss', time.toString(), '-i', inputPath, '-vframes', '1', '-vf', `scale=${width}:-1`]
+        await this.executeFFmpeg([...args, '-q:v', '3', '-y', outputPath])
+        thumbnails.push(outputPath)
+      } catch (error) {
+        // Don't fail entirely if one thumbnail fails
+        this.logger.warn('Failed to generate thumbnail', { time, error: (error as Error).message })
+      }
+    }
+
+    this.logger.info('Thumbnails generated', { count: thumbnails.length, inputPath })
+    return { thumbnails, interval: thumbInterval, duration }
+  }
+
+  /**
+   * Frame at an exact time as a JPEG, at most maxWidth wide, for the editor scrubber
+   */
+  async extractPreviewFrame(inputPath: string, time: number, maxWidth: number, outputPath: string): Promise<void> {
+    const { inputSeek, outputSeek } = getFrameSeek(time)
+    await this.executeFFmpeg([
+      '-ss',
+      String(inputSeek),
+      '-i',
+      inputPath,
+      '-ss',
+      String(outputSeek),
+      '-frames:v',
+      '1',
+      '-vf',
+      `scale='min(${Math.round(maxWidth)},iw)':-2`,
+      '-q:v',
+      '4',
+      '-y',
+      outputPath,
+    ])
+  }
+
   /**
    * Generate video preview/thumbnail
    */
@@ -250,6 +533,83 @@ export class VideoProcessor {
     }
   }
 
+  /**
+   * Export the frames of a time range as a PNG sequence (frame_000001.png, ...) at the
+   * given rate. Frames are rendered to a partial directory and merged into outputDir on
+   * success. Returns the written file paths in frame order.
+   */
+  async extractFrames(inputPath: string, outputDir: string, range: TimeRange, fps: number): Promise<string[]> {
+    if (!existsSync(inputPath)) {
+      throw new Error('Input video file does not exist')
+    }
+
+    try {
+      const { result: frameNames } = await renderToPartialOutput(outputDir, true, async partialDir => {
+        mkdirSync(partialDir, { recursive: true })
+        await this.executeFFmpeg([
+          '-ss',
+          range.start.toString(),
+          '-i',
+          inputPath,
+          '-t',
+          (range.end - range.start).toString(),
+          '-vf',
+          `fps=${fps}`,
+          '-y',
+          join(partialDir, 'frame_%06d.png'),
+        ])
+        return readdirSync(partialDir)
+          .filter(name => /^frame_\d{6}\.png$/.test(name))
+          .sort()
+      })
+
+      this.logger.info('Frames extracted', { inputPath, outputDir, frames: frameNames.length, fps })
+      return frameNames.map(name => join(outputDir, name))
+    } catch (error) {
+      this.logger.error('Failed to extract frames', error as Error, { inputPath, outputDir })
+      throw new Error(`Failed to extract frames: ${(error as Error).message}`)
+    }
+  }
+
+  /**
+   * Draw a text overlay onto a still image (e.g. a frame from generatePreview)
+   */
+  async renderTextPreview(backgroundPath: string, text: TextProperties, outputPath: string): Promise<string> {
+    // drawtext reads the text from a file, so it needs no filtergraph escaping
+    const textFilePath = join(
+      PlatformUtils.getInstance().getTempDir('clipy'),
+      this.fileSystem.createTempFilePath('drawtext', 'txt'),
+    )
+    try {
+      await this.fileSystem.ensureDirectory(dirname(textFilePath))
+      await this.fileSystem.ensureDirectory(dirname(outputPath))
+      await this.fileSystem.writeTextFile(textFilePath, text.content)
+
+      await this.executeFFmpeg([
+        '-i',
+        backgroundPath,
+        '-vf',
+        buildDrawtextFilter(text, textFilePath),
+        '-frames:v',
+        '1',
+        '-q:v',
+        '2',
+        '-y',
+        outputPath,
+      ])
+
+      this.logger.info('Text preview rendered', { background: backgroundPath, output: outputPath })
+      return outputPath
+    } catch (error) {
+      this.logger.error('Failed to render text preview', error as Error, { backgroundPath, outputPath })
+      throw new Error(`Failed to render text preview: ${(error as Error).message}`)
+    } finally {
+      if (existsSync(textFilePath)) {
+        unlinkSync(textFilePath)
+      }
+    }
+  }
+
   /**
    * Trim video to specified time range
    */
@@ -323,9 +683,9 @@ export class VideoProcessor {
   }
 
   /**
-   * Convert video format
+   * Convert video format. Renders to a partial file first; returns the path actually written.
    */
-  async convertVideo(inputPath: string, outputPath: string, options: ProcessingOptions = {}): Promise<void> {
+  async convertVideo(inputPath: string, outputPath: string, options: ProcessingOptions = {}): Promise<string> {
     try {
       if (!existsSync(inputPath)) {
         throw new Error('Input video file does not exist')
@@ -345,15 +705,18 @@ export class VideoProcessor {
         args.push('-preset', options.preset)
       }
 
-      args.push('-y', outputPath)
-
-      await this.executeFFmpeg(args)
+      const { outputPath: finalPath } = await renderToPartialOutput(
+        outputPath,
+        options.overwrite === true,
+        partialPath => this.executeFFmpeg([...args, '-y', partialPath]),
+      )
 
       this.logger.info('Video converted successfully', {
         input: inputPath,
-        output: outputPath,
+        output: finalPath,
         options,
       })
+      return finalPath
     } catch (error) {
       this.logger.error('Failed to convert video', error as Error, {
         inputPath,
@@ -365,9 +728,98 @@ export class VideoProcessor {
   }
 
   /**
-   * Extract audio from video
+   * Transcode to an editing-friendly H.264 MP4 (see EDITING_TRANSCODE_PROFILES), overwriting
+   * outputPath. Renders to a partial file first; returns the path written.
    */
-  async extractAudio(inputPath: string, outputPath: string, options: ProcessingOptions = {}): Promise<void> {
+  async transcodeForEditing(
+    inputPath: string,
+    outputPath: string,
+    profile: EditingTranscodeProfile,
+    run: FFmpegRunOptions = {},
+  ): Promise<string> {
+    if (!existsSync(inputPath)) {
+      throw new Error('Input video file does not exist')
+    }
+    const args = ['-i', inputPath, ...getEditingTranscodeArgs(profile)]
+
+    await this.fileSystem.ensureDirectory(dirname(outputPath))
+    const { outputPath: finalPath } = await renderToPartialOutput(outputPath, true, partialPath =>
+      this.executeFFmpeg([...args, '-y', partialPath], run),
+    )
+    this.logger.info('Video transcoded for editing', { input: inputPath, output: finalPath, profile })
+    return finalPath
+  }
+
+  /**
+   * Change the container without re-encoding; the container comes from the output extension.
+   * With no streams selected FFmpeg keeps its default picks (one video and one audio stream);
+   * once any stream is selected, only the selected ones are kept. Audio the container can't
+   * hold (e.g. Opus in MP4) is re-encoded, as is such video when options.reencodeVideo is set,
+   * and text subtitles are converted to mov_text for MP4/MOV, which can't carry SRT or ASS
+   * as-is. Returns the path actually written.
+   */
+  async remuxVideo(inputPath: string, outputPath: string, options: RemuxOptions = {}): Promise<string> {
+    if (!existsSync(inputPath)) {
+      throw new Error('Input video file does not exist')
+    }
+    const container = extname(outputPath).slice(1).toLowerCase()
+    if (!isRemuxContainer(container)) {
+      throw new Error(`Cannot remux into .${container}`)
+    }
+
+    const streams = await this.getVideoStreams(inputPath)
+    const codecOf = (type: string, position = 0) =>
+      streams.filter(stream => stream.streamType === type)[position]?.codec ?? null
+    const videoCodec = codecOf('video', options.videoStream)
+    const incompatibility = getRemuxIncompatibility(container, videoCodec)
+    if (incompatibility && !options.reencodeVideo) {
+      throw new Error(incompatibility)
+    }
+    const videoEncoder = getRemuxVideoEncoder(container, videoCodec)
+    const audioEncoder = getRemuxAudioEncoder(container, codecOf('audio', options.audioStream))
+
+    const args = ['-i', inputPath]
+    if (options.videoStream !== undefined) args.push('-map', `0:v:${options.videoStream}`)
+    if (options.audioStream !== undefined) args.push('-map', `0:a:${options.audioStream}`)
+    if (options.subtitleStream !== undefined) args.push('-map', `0:s:${options.subtitleStream}`)
+    args.push('-c', 'copy')
+    if (videoEncoder) {
+      args.push('-c:v', videoEncoder, '-crf', videoEncoder === 'libvpx-vp9' ? '33' : '23', '-pix_fmt', 'yuv420p')
+      if (videoEncoder === 'libvpx-vp9') args.push('-b:v', '0')
+    }
+    if (audioEncoder) {
+      args.push('-c:a', audioEncoder)
+    }
+    if (options.subtitleStream !== undefined && ['mp4', 'm4v', 'mov'].includes(container)) {
+      args.push('-c:s', 'mov_text')
+    }
+
+    try {
+      await this.fileSystem.ensureDirectory(dirname(outputPath))
+      const { outputPath: finalPath } = await renderToPartialOutput(
+        outputPath,
+        options.overwrite === true,
+        partialPath => this.executeFFmpeg([...args, '-y', partialPath], options),
+      )
+
+      this.logger.info('Video remuxed successfully', {
+        input: inputPath,
+        output: finalPath,
+        videoEncoder,
+        audioEncoder,
+      })
+      return finalPath
+    } catch (error) {
+      this.logger.error('Failed to remux video', error as Error, { inputPath, outputPath })
+      throw new Error(`Failed to remux video: ${(error as Error).message}`)
+    }
+  }
+
+  /**
+   * Extract the audio track to an audio-only file. Renders to a partial file first;
+   * returns the path actually written.
+   */
+  async extractAudio(inputPath: string, outputPath: string, options: AudioExtractOptions): Promise<string> {
     try {
       if (!existsSync(inputPath)) {
         throw new Error('Input video file does not exist')
@@ -375,34 +827,37 @@ export class VideoProcessor {
 
       await this.fileSystem.ensureDirectory(dirname(outputPath))
 
+      const format = AUDIO_EXTRACT_FORMATS[options.format]
       const args = [
         '-i',
         inputPath,
         '-vn', // No video
+        '-sn',
         '-c:a',
-        options.audioCodec || 'libmp3lame',
+        format.encoder,
       ]
-
-      // Add audio quality settings
-      if (options.quality) {
-        const bitrate = this.getAudioBitrate(options.quality)
-        args.push('-b:a', bitrate)
+      if (!format.lossless && options.bitrate) {
+        args.push('-b:a', `${options.bitrate}k`)
       }
 
-      args.push('-y', outputPath)
-
-      await this.executeFFmpeg(args)
+      const { outputPath: finalPath } = await renderToPartialOutput(
+        outputPath,
+        options.overwrite === true,
+        partialPath => this.executeFFmpeg([...args, '-y', partialPath], options),
+      )
 
       this.logger.info('Audio extracted successfully', {
         input: inputPath,
-        output: outputPath,
-        options,
+        output: finalPath,
+        format: options.format,
+        bitrate: options.bitrate,
       })
+      return finalPath
     } catch (error) {
       this.logger.error('Failed to extract audio', error as Error, {
         inputPath,
         outputPath,
-        options,
+        format: options.format,
       })
       throw new Error(`Failed to extract audio: ${(error as Error).message}`)
     }
@@ -457,6 +912,230 @@ export class VideoProcessor {
     }
   }
 
+  /**
+   * Add an external audio file to a video: replace its audio, or mix the new audio in at
+   * the given volumes (a video without audio just gets the new track). The video stream is
+   * copied and the result runs as long as the video. Returns the path actually written.
+   */
+  async mergeAudioToVideo(
+    videoPath: string,
+    audioPath: string,
+    outputPath: string,
+    options: AudioMergeRunOptions = {},
+  ): Promise<string> {
+    if (!existsSync(videoPath) || !existsSync(audioPath)) {
+      throw new Error('Input files do not exist')
+    }
+    const container = extname(outputPath).slice(1).toLowerCase()
+    if (!isRemuxContainer(container)) {
+      throw new Error(`Cannot write .${container}`)
+    }
+
+    const streams = await this.getVideoStreams(videoPath)
+    const videoCodec = streams.find(stream => stream.streamType === 'video')?.codec ?? null
+    const incompatibility = getRemuxIncompatibility(container, videoCodec)
+    if (incompatibility) {
+      throw new Error(incompatibility)
+    }
+    const mix = !options.replace && streams.some(stream => stream.streamType === 'audio')
+
+    // Line the new audio up with the video: delay it, or drop its start
+    const offset = options.offset ?? 0
+    let shift = 'anull'
+    if (offset > 0) {
+      shift = `adelay=${Math.round(offset * 1000)}:all=1`
+    } else if (offset < 0) {
+      shift = `atrim=start=${-offset},asetpts=PTS-STARTPTS`
+    }
+    const weights = `${options.originalVolume ?? 1} ${options.newVolume ?? 1}`
+    const filter = mix
+      ? `[1:a]${shift}[new];[0:a][new]amix=inputs=2:duration=first:dropout_transition=0:weights=${weights}[aout]`
+      : `[1:a]${shift},apad[aout]` // Padded with silence when shorter than the video
+
+    const args = ['-i', videoPath, '-i', audioPath, '-filter_complex', filter]
+    args.push('-map', '0:v:0', '-map', '[aout]', '-c:v', 'copy')
+    args.push('-c:a', container === 'webm' ? 'libopus' : 'aac', '-shortest')
+
+    try {
+      await this.fileSystem.ensureDirectory(dirname(outputPath))
+      const { outputPath: finalPath } = await renderToPartialOutput(
+        outputPath,
+        options.overwrite === true,
+        partialPath => this.executeFFmpeg([...args, '-y', partialPath], options),
+      )
+
+      this.logger.info('Audio merged into video', {
+        video: videoPath,
+        audio: audioPath,
+        output: finalPath,
+        mode: mix ? 'mix' : 'replace',
+        offset,
+      })
+      return finalPath
+    } catch (error) {
+      this.logger.error('Failed to merge audio into video', error as Error, { videoPath, audioPath, outputPath })
+      throw new Error(`Failed to merge audio: ${(error as Error).message}`)
+    }
+  }
+
+  /**
+   * Render still images into a video, each shown for durationPerImage with a crossfade
+   * into the next, optionally over a soundtrack. Returns the path actually written.
+   */
+  async createSlideshow(images: string[], outputPath: string, options: SlideshowRunOptions = {}): Promise<string> {
+    if (images.length === 0) {
+      throw new Error('No images given')
+    }
+    const missing = images.find(image => !existsSync(image))
+    if (missing) {
+      throw new Error(`Image does not exist: ${missing}`)
+    }
+    if (options.audioPath && !existsSync(options.audioPath)) {
+      throw new Error('Audio file does not exist')
+    }
+    const container = extname(outputPath).slice(1).toLowerCase()
+    if (!isRemuxContainer(container)) {
+      throw new Error(`Cannot write .${container}`)
+    }
+
+    const settings = {
+      durationPerImage: options.durationPerImage ?? 4,
+      transitionDuration: images.length > 1 ? (options.transitionDuration ?? 1) : 0,
+      fps: options.fps ?? 30,
+      width: options.width ?? 1920,
+      height: options.height ?? 1080,
+      kenBurns: options.kenBurns ?? true,
+    }
+    const slideDuration = (settings.durationPerImage + settings.transitionDuration).toFixed(3)
+
+    const args: string[] = []
+    for (const image of images) {
+      args.push('-loop', '1', '-framerate', String(settings.fps), '-t', slideDuration, '-i', image)
+    }
+    if (options.audioPath) {
+      args.push('-i', options.audioPath)
+    }
+    args.push('-filter_complex', buildSlideshowFilter(images.length, settings), '-map', '[vout]')
+    if (container === 'webm') {
+      args.push('-c:v', 'libvpx-vp9', '-crf', '32', '-b:v', '0')
+    } else {
+      args.push('-c:v', 'libx264', '-crf', '23', '-preset', 'medium')
+    }
+    args.push('-pix_fmt', 'yuv420p', '-r', String(settings.fps))
+    if (options.audioPath) {
+      args.push('-map', `${images.length}:a:0`, '-c:a', container === 'webm' ? 'libopus' : 'aac', '-shortest')
+    }
+
+    try {
+      await this.fileSystem.ensureDirectory(dirname(outputPath))
+      const { outputPath: finalPath } = await renderToPartialOutput(
+        outputPath,
+        options.overwrite === true,
+        partialPath => this.executeFFmpeg([...args, '-y', partialPath], options),
+      )
+
+      this.logger.info('Slideshow created', {
+        images: images.length,
+        output: finalPath,
+        duration: images.length * settings.durationPerImage + settings.transitionDuration,
+        audio: options.audioPath ?? null,
+      })
+      return finalPath
+    } catch (error) {
+      this.logger.error('Failed to create slideshow', error as Error, { images: images.length, outputPath })
+      throw new Error(`Failed to create slideshow: ${(error as Error).message}`)
+    }
+  }
+
+  /**
+   * Render a short excerpt of a video with one effect applied, so it can be judged before
+   * being added to a clip's effect chain. Returns the path actually written.
+   */
+  async applyEffectPreview(
+    videoPath: string,
+    effect: Pick<Effect, 'effectType' | 'params'>,
+    outputPath: string,
+    options: EffectPreviewOptions = {},
+  ): Promise<string> {
+    if (!existsSync(videoPath)) {
+      throw new Error('Input file does not exist')
+    }
+
+    const args = ['-ss', String(options.startTime ?? 0), '-t', String(options.duration ?? 5), '-i', videoPath]
+    args.push('-filter_complex', `[0:v]${buildEffectFilter(effect, 'preview')},format=yuv420p[vout]`)
+    args.push('-map', '[vout]', '-map', '0:a:0?', '-c:v', 'libx264', '-preset', 'ultrafast', '-crf', '23')
+    args.push('-c:a', 'aac', '-b:a', '128k', '-movflags', '+faststart')
+
+    try {
+      await this.fileSystem.ensureDirectory(dirname(outputPath))
+      const { outputPath: finalPath } = await renderToPartialOutput(
+        outputPath,
+        options.overwrite === true,
+        partialPath => this.executeFFmpeg([...args, '-y', partialPath], options),
+      )
+
+      this.logger.info('Effect preview rendered', { input: videoPath, effect: effect.effectType, output: finalPath })
+      return finalPath
+    } catch (error) {
+      this.logger.error('Failed to render effect preview', error as Error, { videoPath, outputPath })
+      throw new Error(`Failed to render effect preview: ${(error as Error).message}`)
+    }
+  }
+
+  /**
+   * Detect scene cuts using FFmpeg's scene score.
+   * When a range is given, only that part of the file is analysed; times stay absolute.
+   */
+  async detectSceneChanges(
+    inputPath: string,
+    threshold: number = 0.4,
+    range?: TimeRange,
+    run: FFmpegRunOptions = {},
+  ): Promise<SceneChange[]> {
+    if (!existsSync(inputPath)) {
+      throw new Error('Input video file does not exist')
+    }
+
+    const clampedThreshold = Math.min(Math.max(threshold, 0.01), 1)
+    const args: string[] = []
+    if (range) {
+      args.push('-ss', range.start.toString(), '-to', range.end.toString())
+    }
+    args.push(
+      '-i',
+      inputPath,
+      '-vf',
+      `select='gt(scene,${clampedThreshold})',metadata=print:file=-`,
+      '-an',
+      '-f',
+      'null',
+      '-',
+    )
+
+    const output = await this.executeFFmpeg(args, run)
+    const offset = range?.start ?? 0
+    const changes: SceneChange[] = []
+    let pendingTime: number | null = null
+
+    // Output alternates "frame:N pts:X pts_time:T" and "lavfi.scene_score=S" lines
+    for (const line of output.split(/\r?\n/)) {
+      const timeMatch = line.match(/pts_time:([\d.]+)/)
+      if (timeMatch) {
+        pendingTime = parseFloat(timeMatch[1])
+        continue
+      }
+
+      const scoreMatch = line.match(/lavfi\.scene_score=([\d.]+)/)
+      if (scoreMatch && pendingTime !== null) {
+        changes.push({ time: pendingTime + offset, score: parseFloat(scoreMatch[1]) })
+        pendingTime = null
+      }
+    }
+
+    this.logger.info('Scene detection completed', { inputPath, threshold: clampedThreshold, scenes: changes.length })
+    return changes
+  }
+
   /**
    * Get CRF value for quality setting
    */
@@ -473,22 +1152,6 @@ export class VideoProcessor {
     }
   }
 
-  /**
-   * Get audio bitrate for quality setting
-   */
-  private getAudioBitrate(quality: 'low' | 'medium' | 'high'): string {
-    switch (quality) {
-      case 'low':
-        return '128k'
-      case 'medium':
-        return '192k'
-      case 'high':
-        return '320k'
-      default:
-        return '192k'
-    }
-  }
-
   /**
    * Execute ffprobe command
    */
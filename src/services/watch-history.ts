@@ -0,0 +1,150 @@
+/**
+ * Watch History
+ * Playback positions of library videos, kept in playback-positions.json next to
+ * downloads.json so the preview can resume where it stopped. Positions are saved every few
+ * seconds while a video plays, so they live in their own small file instead of rewriting
+ * the whole download history each time.
+ *
+ * A video counts as watched once playback passes WATCHED_THRESHOLD of its duration. The
+ * library stays the source of truth: positions of deleted entries are dropped.
+ */
+
+import { existsSync, readFileSync, writeFileSync } from 'fs'
+import { join } from 'path'
+import { app } from 'electron'
+
+import type { DownloadProgress, PlaybackPosition } from '../types/download'
+import { isCompletedStatus } from '../types/download'
+import { Logger } from '../utils/logger'
+import { getStoredDownloadById } from './download-storage'
+
+const logger = Logger.getInstance()
+
+const positionsFilePath = join(app.getPath('userData'), 'playback-positions.json')
+
+/** Fraction of the duration after which a video counts as watched */
+export const WATCHED_THRESHOLD = 0.95
+
+let positions: Map<string, PlaybackPosition> | null = null
+
+function loadPositions(): Map<string, PlaybackPosition> {
+  if (positions) {
+    return positions
+  }
+
+  try {
+    const stored = existsSync(positionsFilePath) ? JSON.parse(readFileSync(positionsFilePath, 'utf-8')) : {}
+    const list: PlaybackPosition[] = stored.positions ?? []
+    positions = new Map(list.map(position => [position.downloadId, position]))
+  } catch (error) {
+    logger.warn('Error loading playback positions, starting empty', error as Error)
+    positions = new Map()
+  }
+
+  // Entries deleted while the file wasn't loaded (or by an older version) leave their positions behind
+  const orphaned = Array.from(positions.keys()).filter(downloadId => !getStoredDownloadById(downloadId))
+  if (orphaned.length > 0) {
+    orphaned.forEach(downloadId => positions!.delete(downloadId))
+    savePositions()
+  }
+  return positions
+}
+
+function savePositions(): void {
+  try {
+    const data = { positions: Array.from(loadPositions().values()), lastUpdated: Date.now() }
+    writeFileSync(positionsFilePath, JSON.stringify(data, null, 2), 'utf-8')
+  } catch (error) {
+    logger.error('Failed to save playback positions', error as Error)
+  }
+}
+
+/**
+ * Whether a position is far enough into the video to count as watched
+ */
+export function isPastWatchedThreshold(position: number, duration: number | undefined): boolean {
+  return !!duration && duration > 0 && position >= duration * WATCHED_THRESHOLD
+}
+
+export function getPlaybackPosition(downloadId: string): PlaybackPosition | null {
+  return loadPositions().get(downloadId) ?? null
+}
+
+/**
+ * Record where playback of a library video is. Passing the watched threshold marks it
+ * watched; seeking back afterwards doesn't unmark it.
+ */
+export function setPlaybackPosition(downloadId: string, seconds: number, duration?: number): PlaybackPosition {
+  const entry = getStoredDownloadById(downloadId)
+  if (!entry || !isCompletedStatus(entry.status)) {
+    throw new Error('Video is not in the library')
+  }
+
+  const known = loadPositions()
+  const previous = known.get(downloadId)
+  const totalDuration = duration || previous?.duration || entry.duration
+  const position: PlaybackPosition = {
+    downloadId,
+    position: totalDuration ? Math.min(seconds, totalDuration) : seconds,
+    duration: totalDuration,
+    watched: previous?.watched === true || isPastWatchedThreshold(seconds, totalDuration),
+    updatedAt: Date.now(),
+  }
+  known.set(downloadId, position)
+  savePositions()
+  return position
+}
+
+/**
+ * Mark a library video watched or unwatched. Unmarking starts it over from the beginning.
+ */
+export function markWatched(downloadId: string, watched: boolean): PlaybackPosition {
+  const entry = getStoredDownloadById(downloadId)
+  if (!entry || !isCompletedStatus(entry.status)) {
+    throw new Error('Video is not in the library')
+  }
+
+  const known = loadPositions()
+  const previous = known.get(downloadId)
+  const position: PlaybackPosition = {
+    downloadId,
+    position: watched ? (previous?.position ?? 0) : 0,
+    duration: previous?.duration ?? entry.duration,
+    watched,
+    updatedAt: Date.now(),
+  }
+  known.set(downloadId, position)
+  savePositions()
+  return position
+}
+
+/** Forget a video's position; called when its library entry is deleted */
+export function removePlaybackPosition(downloadId: string): void {
+  if (loadPositions().delete(downloadId)) {
+    savePositions()
+  }
+}
+
+/**
+ * Library videos started but not finished, most recently played first
+ */
+export function getContinueWatching(limit: number = 20): DownloadProgress[] {
+  return Array.from(loadPositions().values())
+    .filter(position => !position.watched && position.position > 0)
+    .sort((a, b) => b.updatedAt - a.updatedAt)
+    .map(position => getStoredDownloadById(position.downloadId))
+    .filter((entry): entry is DownloadProgress => isCompletedStatus(entry?.status))
+    .slice(0, limit)
+    .map(entry => ({ ...entry, playback: getPlaybackPosition(entry.downloadId)! }))
+}
+
+/**
+ * Downloads with their playback positions attached, for the library list
+ */
+export function withPlaybackPositions(downloads: DownloadProgress[]): DownloadProgress[] {
+  const known = loadPositions()
+  return downloads.map(download => {
+    const playback = known.get(download.downloadId)
+    return playback ? { ...download, playback } : download
+  })
+}
@@ -0,0 +1,146 @@
+import { existsSync, mkdirSync, mkdtempSync, readFileSync, readdirSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+const paths = vi.hoisted(() => ({ config: '', library: '', thumbnails: '' }))
+
+const configManager = vi.hoisted(() => ({ getConfigFilePath: () => paths.config, reload: vi.fn() }))
+const thumbnailCache = vi.hoisted(() => ({ getCacheDirectory: () => paths.thumbnails, reloadIndex: vi.fn() }))
+
+vi.mock('../../utils/config', () => ({
+  ConfigManager: { getInstance: () => configManager },
+}))
+
+vi.mock('../../services/thumbnail-cache', () => ({
+  ThumbnailCache: { getInstance: () => thumbnailCache },
+}))
+
+vi.mock('../../services/download-storage', () => ({
+  getDownloadStorageFilePath: () => paths.library,
+  loadDownloadStorage: vi.fn(),
+  reloadDownloadStorage: vi.fn(),
+  saveDownloadStorage: vi.fn(),
+}))
+
+import { BackupManager } from '../../services/backup-manager'
+import { createZip } from '../../utils/zip'
+
+const backupManager = BackupManager.getInstance()
+let root: string
+
+function writeFile(filePath: string, content: string) {
+  mkdirSync(join(filePath, '..'), { recursive: true })
+  writeFileSync(filePath, content, 'utf-8')
+}
+
+const read = (filePath: string) => readFileSync(filePath, 'utf-8')
+
+beforeEach(() => {
+  vi.clearAllMocks()
+  root = mkdtempSync(join(tmpdir(), 'clipy-backup-'))
+  paths.config = join(root, 'config.json')
+  paths.library = join(root, 'downloads.json')
+  paths.thumbnails = join(root, 'thumbnails')
+})
+
+describe('backup round trip', () => {
+  it('restores config, library and thumbnails, replacing the thumbnail cache', async () => {
+    writeFile(paths.config, '{"theme":"dark"}')
+    writeFile(paths.library, '{"downloads":[]}')
+    writeFile(join(paths.thumbnails, 'index.json'), '{"entries":1}')
+    writeFile(join(paths.thumbnails, 'sprites', 'a.jpg'), 'sprite a')
+
+    const backupPath = join(root, 'backups', 'backup.zip')
+    const { manifest } = await backupManager.createBackup(backupPath, true)
+    expect(manifest.includesThumbnails).toBe(true)
+
+    writeFile(paths.config, '{"theme":"light"}')
+    writeFile(paths.library, '{"downloads":[1]}')
+    writeFile(join(paths.thumbnails, 'sprites', 'stale.jpg'), 'stale')
+
+    const { restored } = await backupManager.restoreBackup(backupPath, true)
+
+    expect(restored).toEqual(expect.arrayContaining(['config.json', 'downloads.json', 'thumbnails/sprites/a.jpg']))
+    expect(read(paths.config)).toBe('{"theme":"dark"}')
+    expect(read(paths.library)).toBe('{"downloads":[]}')
+    expect(read(join(paths.thumbnails, 'sprites', 'a.jpg'))).toBe('sprite a')
+    expect(existsSync(join(paths.thumbnails, 'sprites', 'stale.jpg'))).toBe(false)
+    expect(readdirSync(root).sort()).toEqual(['backups', 'config.json', 'downloads.json', 'thumbnails'])
+    expect(configManager.reload).toHaveBeenCalled()
+    expect(thumbnailCache.reloadIndex).toHaveBeenCalled()
+  })
+
+  it('leaves the thumbnail cache alone when thumbnails are not restored', async () => {
+    writeFile(paths.config, '{}')
+    writeFile(join(paths.thumbnails, 'index.json'), 'live')
+    const backupPath = join(root, 'backup.zip')
+    await backupManager.createBackup(backupPath, true)
+    writeFile(join(paths.thumbnails, 'index.json'), 'newer')
+
+    await backupManager.restoreBackup(backupPath, false)
+
+    expect(read(join(paths.thumbnails, 'index.json'))).toBe('newer')
+  })
+})
+
+describe('corrupt backups', () => {
+  const manifest = (files: string[]) =>
+    JSON.stringify({
+      formatVersion: 1,
+      appVersion: '1.0.0',
+      createdAt: Date.now(),
+      files: files.map(name => ({ name, size: 0, modifiedAt: 0 })),
+      includesThumbnails: true,
+    })
+
+  function writeBackup(entries: Record<string, string>): string {
+    const backupPath = join(root, 'backup.zip')
+    const zipEntries = Object.entries(entries).map(([name, data]) => ({ name, data: Buffer.from(data) }))
+    writeFileSync(backupPath, createZip(zipEntries))
+    return backupPath
+  }
+
+  beforeEach(() => {
+    writeFile(paths.config, 'live config')
+    writeFile(join(paths.thumbnails, 'index.json'), 'live index')
+  })
+
+  const expectUntouched = () => {
+    expect(read(paths.config)).toBe('live config')
+    expect(read(join(paths.thumbnails, 'index.json'))).toBe('live index')
+    expect(readdirSync(root).sort()).toEqual(['backup.zip', 'config.json', 'thumbnails'])
+  }
+
+  it('rejects a truncated archive without touching app data', async () => {
+    const backupPath = writeBackup({ 'manifest.json': manifest([]), 'config.json': 'restored' })
+    writeFileSync(backupPath, readFileSync(backupPath).subarray(0, 40))
+
+    await expect(backupManager.restoreBackup(backupPath, true)).rejects.toThrow()
+    expectUntouched()
+  })
+
+  it('rejects entries that escape the thumbnail cache', async () => {
+    const backupPath = writeBackup({
+      'manifest.json': manifest([]),
+      'config.json': 'restored',
+      'thumbnails/../../evil.txt': 'evil',
+    })
+
+    await expect(backupManager.restoreBackup(backupPath, true)).rejects.toThrow('Invalid backup entry')
+    expectUntouched()
+  })
+
+  it('keeps the live cache when extracting the thumbnails fails', async () => {
+    // A file and a folder of the same name can't both be written
+    const backupPath = writeBackup({
+      'manifest.json': manifest([]),
+      'config.json': 'restored',
+      'thumbnails/sprites': 'file',
+      'thumbnails/sprites/a.jpg': 'sprite',
+    })
+
+    await expect(backupManager.restoreBackup(backupPath, true)).rejects.toThrow()
+    expectUntouched()
+  })
+})
@@ -0,0 +1,187 @@
+import { mkdtempSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+import type { BatchEnqueueProgress } from '../../types/download'
+
+const downloadManager = vi.hoisted(() => ({
+  getKnownDownloadKeys: vi.fn(),
+  enqueueDownload: vi.fn(),
+  startDownload: vi.fn(),
+}))
+
+vi.mock('../../services/download-manager', () => ({
+  DownloadManager: { getInstance: () => downloadManager },
+}))
+
+const settings = vi.hoisted(() => ({
+  download: {
+    defaultVideoQuality: '720p',
+    videoFormat: 'mp4',
+    downloadSubtitles: false,
+    downloadThumbnails: true,
+    saveMetadata: true,
+    createSubdirectories: false,
+  },
+  sitePolicy: { mode: 'allow-all', patterns: [] as string[] },
+}))
+
+vi.mock('../../services/library', () => ({
+  getVideoIdFromUrl: (url: string) => url.match(/(?:[?&]v=|youtu\.be\/|\/shorts\/)([\w-]{11})/)?.[1] ?? null,
+}))
+
+vi.mock('../../utils/config', () => ({
+  ConfigManager: {
+    getInstance: () => ({ get: (key: keyof typeof settings) => settings[key] }),
+  },
+}))
+
+import {
+  BATCH_CHUNK_SIZE,
+  batchEnqueueEvents,
+  enqueueUrls,
+  enqueueUrlsFromFile,
+  parseUrlLine,
+  readUrlFile,
+} from '../../services/batch-enqueue'
+
+const watchUrl = (id: string) => `https://www.youtube.com/watch?v=${id}`
+
+function writeListFile(content: string): string {
+  const filePath = join(mkdtempSync(join(tmpdir(), 'clipy-batch-')), 'urls.txt')
+  writeFileSync(filePath, content, 'utf-8')
+  return filePath
+}
+
+beforeEach(() => {
+  vi.clearAllMocks()
+  settings.sitePolicy = { mode: 'allow-all', patterns: [] }
+  downloadManager.getKnownDownloadKeys.mockReturnValue(new Set<string>())
+  let nextId = 0
+  downloadManager.enqueueDownload.mockImplementation(() => ({ downloadId: `dl_${++nextId}` }))
+  downloadManager.startDownload.mockImplementation(async () => ({ downloadId: `dl_${++nextId}` }))
+})
+
+describe('parseUrlLine', () => {
+  it('trims URLs and skips blank lines and comments', () => {
+    expect(parseUrlLine('  https://youtu.be/abc  ')).toBe('https://youtu.be/abc')
+    expect(parseUrlLine('')).toBeNull()
+    expect(parseUrlLine('   \t')).toBeNull()
+    expect(parseUrlLine('# saved from my phone')).toBeNull()
+    expect(parseUrlLine('   # indented comment')).toBeNull()
+  })
+})
+
+describe('readUrlFile', () => {
+  it('yields URLs with their line numbers in the file', async () => {
+    const filePath = writeListFile('# list\r\n\r\nhttps://youtu.be/aaaaaaaaaaa\r\n  \r\nhttps://youtu.be/bbbbbbbbbbb\r\n')
+
+    const lines = []
+    for await (const line of readUrlFile(filePath)) {
+      lines.push(line)
+    }
+
+    expect(lines).toEqual([
+      { line: 3, url: 'https://youtu.be/aaaaaaaaaaa' },
+      { line: 5, url: 'https://youtu.be/bbbbbbbbbbb' },
+    ])
+  })
+})
+
+describe('enqueueUrls', () => {
+  it('reports accepted, duplicate and invalid lines', async () => {
+    downloadManager.getKnownDownloadKeys.mockReturnValue(new Set(['inLibrary01', 'https://example.com/known.mp4']))
+
+    const report = await enqueueUrls([
+      watchUrl('newVideo001'),
+      '',
+      watchUrl('inLibrary01'),
+      'not a url',
+      'https://example.com/clip.mp4',
+      'https://youtu.be/newVideo001',
+      'https://example.com/known.mp4',
+      'ftp://example.com/clip.mp4',
+    ])
+
+    expect(report.accepted).toEqual([
+      { line: 1, url: watchUrl('newVideo001'), downloadId: 'dl_1' },
+      { line: 5, url: 'https://example.com/clip.mp4', downloadId: 'dl_2' },
+    ])
+    expect(report.duplicates).toEqual([
+      { line: 3, url: watchUrl('inLibrary01'), videoId: 'inLibrary01' },
+      { line: 6, url: 'https://youtu.be/newVideo001', videoId: 'newVideo001' },
+      { line: 7, url: 'https://example.com/known.mp4', videoId: undefined },
+    ])
+    expect(report.invalid.map(entry => entry.line)).toEqual([4, 8])
+    expect(downloadManager.startDownload).not.toHaveBeenCalled()
+  })
+
+  it('reports sites the site policy blocks as invalid', async () => {
+    settings.sitePolicy = { mode: 'allowlist', patterns: ['*.youtube.com'] }
+
+    const report = await enqueueUrls([watchUrl('newVideo001'), 'https://example.com/clip.mp4'])
+
+    expect(report.accepted.map(entry => entry.line)).toEqual([1])
+    expect(report.invalid).toEqual([
+      {
+        line: 2,
+        url: 'https://example.com/clip.mp4',
+        error: 'Downloads from example.com are not allowed by the site policy',
+        messageKey: 'errorSiteBlocked',
+        params: { host: 'example.com' },
+      },
+    ])
+  })
+
+  it('queues with the download defaults filled in under the given options', async () => {
+    await enqueueUrls([watchUrl('newVideo001')], { format: 'mkv' })
+
+    expect(downloadManager.enqueueDownload).toHaveBeenCalledWith(watchUrl('newVideo001'), {
+      quality: '720p',
+      format: 'mkv',
+      downloadSubtitles: false,
+      downloadThumbnail: true,
+      saveMetadata: true,
+      createSubdirectories: false,
+    })
+  })
+
+  it('reports videos whose info lookup fails as invalid when prefetching', async () => {
+    downloadManager.startDownload.mockImplementation(async (url: string) => {
+      if (url.includes('private0001')) {
+        throw new Error('Video is private')
+      }
+      return { downloadId: 'dl_ok' }
+    })
+
+    const report = await enqueueUrls([watchUrl('private0001'), watchUrl('public00001')], {}, true)
+
+    expect(report.accepted).toEqual([{ line: 2, url: watchUrl('public00001'), downloadId: 'dl_ok' }])
+    expect(report.invalid).toEqual([{ line: 1, url: watchUrl('private0001'), error: 'Video is private' }])
+    expect(downloadManager.enqueueDownload).not.toHaveBeenCalled()
+  })
+})
+
+describe('enqueueUrlsFromFile', () => {
+  it('processes large files in chunks with a progress event per chunk', async () => {
+    const lineCount = BATCH_CHUNK_SIZE * 5 + 17
+    const lines = Array.from({ length: lineCount }, (_, i) => watchUrl(`vid${String(i).padStart(8, '0')}`))
+    const filePath = writeListFile(lines.join('\n'))
+
+    const events: BatchEnqueueProgress[] = []
+    const onProgress = (progress: BatchEnqueueProgress) => events.push(progress)
+    batchEnqueueEvents.on('progress', onProgress)
+    try {
+      const report = await enqueueUrlsFromFile(filePath)
+
+      expect(report.accepted).toHaveLength(lineCount)
+      expect(report.accepted[lineCount - 1].line).toBe(lineCount)
+      expect(events).toHaveLength(7) // Six chunks, then the final event
+      expect(events.map(event => event.processedLines).slice(0, 6)).toEqual([200, 400, 600, 800, 1000, lineCount])
+      expect(events[6]).toMatchObject({ batchId: report.batchId, accepted: lineCount, done: true })
+    } finally {
+      batchEnqueueEvents.off('progress', onProgress)
+    }
+  })
+})
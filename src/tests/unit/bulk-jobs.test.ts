@@ -0,0 +1,154 @@
+import { existsSync, mkdirSync, mkdtempSync, readdirSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+import type { BulkJobProgress, DownloadProgress } from '../../types/download'
+
+const stored = vi.hoisted(() => new Map<string, Partial<DownloadProgress>>())
+
+vi.mock('../../services/download-storage', () => ({
+  getStoredDownloadById: (id: string) => stored.get(id) ?? null,
+  getStoredDownloads: () => Array.from(stored.values()),
+  removeDownloadFromStorage: (id: string) => stored.delete(id),
+  addDownloadToStorage: vi.fn(),
+  updateDownloadInStorage: vi.fn(),
+}))
+vi.mock('../../services/downloader/yt-dlp-manager', () => ({
+  addEventListener: vi.fn(),
+  cancelDownload: vi.fn(),
+  getVideoInfo: vi.fn(),
+  // Never finishes, so no queue processor or event forwarding runs in these tests
+  initializeDownloadManager: vi.fn(() => new Promise(() => {})),
+  pauseDownload: vi.fn(),
+  startDownload: vi.fn(),
+}))
+vi.mock('../../utils/config', () => ({
+  ConfigManager: {
+    getInstance: () => ({
+      get: () => ({ permanentlyDeleteFiles: true }),
+      getNested: () => undefined,
+    }),
+  },
+}))
+vi.mock('../../services/video-cache', () => ({ VideoCache: { getInstance: () => ({}) } }))
+vi.mock('../../services/video-processor', () => ({ VideoProcessor: { getInstance: () => ({}) } }))
+vi.mock('../../services/library', () => ({ enrichLibraryEntry: vi.fn(), getVideoIdFromUrl: () => null }))
+vi.mock('../../services/lifecycle', () => ({ SHUTDOWN_TIMEOUT: 10000 }))
+vi.mock('../../services/watch-history', () => ({
+  getContinueWatching: vi.fn(),
+  removePlaybackPosition: vi.fn(),
+  withPlaybackPositions: vi.fn(),
+}))
+vi.mock('../../services/channels', () => ({ recordChannel: vi.fn() }))
+vi.mock('../../services/podcast-output', () => ({
+  hasPodcastFeed: vi.fn(),
+  isAudioFile: vi.fn(),
+  writeChapterFiles: vi.fn(),
+  writePodcastFeed: vi.fn(),
+}))
+
+import { bulkJobEvents, cancelBulkJob, getBulkJobStatus, startBulkJob } from '../../services/bulk-jobs'
+import { DownloadManager } from '../../services/download-manager'
+
+const ITEM_COUNT = 300
+
+let root: string
+
+function waitForJob(jobId: string, events: BulkJobProgress[] = []): Promise<BulkJobProgress> {
+  return new Promise(resolve => {
+    const onProgress = (progress: BulkJobProgress) => {
+      if (progress.jobId !== jobId) return
+      events.push(progress)
+      if (progress.status !== 'running') {
+        bulkJobEvents.off('progress', onProgress)
+        resolve(progress)
+      }
+    }
+    bulkJobEvents.on('progress', onProgress)
+  })
+}
+
+beforeEach(() => {
+  stored.clear()
+  root = mkdtempSync(join(tmpdir(), 'clipy-bulk-'))
+})
+
+describe('bulk delete', () => {
+  it('deletes a few hundred downloads and their files, collecting per-item errors', async () => {
+    const ids: string[] = []
+    for (let i = 0; i < ITEM_COUNT; i++) {
+      const id = `dl_${i}`
+      const filePath = join(root, `video ${i}.mp4`)
+      // A folder in place of the file can't be unlinked, so every 50th delete fails
+      if (i % 50 === 0) {
+        mkdirSync(filePath)
+      } else {
+        writeFileSync(filePath, 'video')
+      }
+      stored.set(id, { downloadId: id, title: `Video ${i}`, status: 'completed', filePath })
+      ids.push(id)
+    }
+    ids.push('dl_unknown')
+
+    const events: BulkJobProgress[] = []
+    const jobId = DownloadManager.getInstance().startBulkDelete(ids, { deleteFile: true })
+    expect(getBulkJobStatus(jobId)).toMatchObject({ jobId, kind: 'delete', status: 'running', total: ITEM_COUNT + 1 })
+
+    const final = await waitForJob(jobId, events)
+
+    expect(final).toMatchObject({ status: 'completed', done: ITEM_COUNT + 1, succeeded: ITEM_COUNT - 6 })
+    expect(final.currentTitle).toBeUndefined()
+    expect(final.errors.map(error => error.id)).toEqual([
+      'dl_0',
+      'dl_50',
+      'dl_100',
+      'dl_150',
+      'dl_200',
+      'dl_250',
+      'dl_unknown',
+    ])
+    expect(final.errors[0].code).toBe('DELETE_FAILED')
+    expect(final.errors[6]).toEqual({ id: 'dl_unknown', code: 'NOT_FOUND', error: 'Download not found' })
+    expect(events.some(event => event.currentTitle?.startsWith('Video '))).toBe(true)
+
+    expect(readdirSync(root)).toHaveLength(6)
+    expect(existsSync(join(root, 'video 1.mp4'))).toBe(false)
+    expect(stored.has('dl_0')).toBe(true)
+    expect(stored.has('dl_1')).toBe(false)
+    expect(getBulkJobStatus(jobId)).toEqual(final)
+  })
+})
+
+describe('startBulkJob', () => {
+  it('stops before the next item once cancelled', async () => {
+    const worker = vi.fn(async () => ({ ok: true }))
+    const items = Array.from({ length: 10 }, (_, i) => ({ id: `item_${i}` }))
+
+    const jobId = startBulkJob('delete', items, worker)
+    const finished = waitForJob(jobId)
+    expect(cancelBulkJob(jobId)).toBe(true)
+
+    expect(await finished).toMatchObject({ status: 'cancelled', done: 1, total: 10 })
+    expect(worker).toHaveBeenCalledTimes(1)
+    expect(cancelBulkJob(jobId)).toBe(false)
+  })
+
+  it('counts a throwing worker as a failed item', async () => {
+    const jobId = startBulkJob('delete', [{ id: 'a' }, { id: 'b' }], async id => {
+      if (id === 'a') throw new Error('Disk unavailable')
+      return { ok: true }
+    })
+
+    expect(await waitForJob(jobId)).toMatchObject({
+      status: 'completed',
+      succeeded: 1,
+      errors: [{ id: 'a', error: 'Disk unavailable' }],
+    })
+  })
+
+  it('does not know unknown jobs', () => {
+    expect(getBulkJobStatus('bulk_missing')).toBeNull()
+    expect(cancelBulkJob('bulk_missing')).toBe(false)
+  })
+})
@@ -0,0 +1,265 @@
+import { EventEmitter } from 'events'
+import { existsSync, mkdtempSync, readFileSync, statSync, writeFileSync } from 'fs'
+import { type IncomingMessage, type Server, type ServerResponse, createServer } from 'http'
+import type { AddressInfo } from 'net'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { afterAll, beforeAll, beforeEach, describe, expect, it, vi } from 'vitest'
+
+const output = vi.hoisted(() => ({ path: '' }))
+
+// electron's net module, over Node's http so requests reach the local test server
+vi.mock('electron', async () => {
+  const http = await import('http')
+  const { EventEmitter } = await import('events')
+  return {
+    app: { getPath: () => tmpdir(), getVersion: () => '1.0.0', isPackaged: false },
+    session: { fromPartition: () => ({}) },
+    net: {
+      request: ({ method, url }: { method: string; url: string }) => {
+        const request = new EventEmitter() as any
+        const headers: Record<string, string> = {}
+        let sent: import('http').ClientRequest | undefined
+        request.setHeader = (name: string, value: string) => {
+          headers[name] = value
+        }
+        request.end = () => {
+          sent = http.request(url, { method, headers }, response => request.emit('response', response))
+          sent.on('error', error => request.emit('error', error))
+          sent.end()
+        }
+        request.abort = () => sent?.destroy()
+        return request
+      },
+    },
+  }
+})
+vi.mock('../../services/connectivity', () => ({
+  applyConfiguredProxy: async () => ({ proxy: null, credentials: null }),
+}))
+vi.mock('../../services/downloader/yt-dlp-provider', () => ({ DOWNLOAD_PAUSE_REASON: 'pause' }))
+vi.mock('../../utils/config', () => ({
+  ConfigManager: { getInstance: () => ({ get: () => ({}) }) },
+}))
+vi.mock('../../utils/paths', () => ({
+  getDownloadPathSettings: () => ({}),
+  renderOutputTemplate: () => ({ path: output.path }),
+}))
+
+import { downloadDirect, getDirectVideoInfo, probeDirectMedia } from '../../services/downloader/direct-download'
+import { DownloadErrorCode, type DownloadProgress, type VideoInfo } from '../../types/download'
+
+const body = Buffer.from(Array.from({ length: 256 * 1024 }, (_, index) => index % 251))
+const half = body.length / 2
+
+let server: Server
+let baseUrl: string
+let ranges: (string | undefined)[]
+let root: string
+
+function sendFile(request: IncomingMessage, response: ServerResponse, honorRange = true): void {
+  const start = Number(request.headers.range?.match(/^bytes=(\d+)-$/)?.[1] ?? 0)
+  const partial = honorRange && start > 0
+  const content = partial ? body.subarray(start) : body
+  response.writeHead(partial ? 206 : 200, { 'Content-Type': 'video/mp4', 'Content-Length': content.length })
+  response.end(request.method === 'HEAD' ? undefined : content)
+}
+
+beforeAll(async () => {
+  server = createServer((request, response) => {
+    ranges.push(request.headers.range)
+    switch (request.url) {
+      case '/clip.mp4':
+      case '/clip':
+        sendFile(request, response)
+        break
+      case '/no-range.mp4':
+        sendFile(request, response, false)
+        break
+      case '/stalls.mp4':
+        // Sends half the file and waits, until it is asked for the rest
+        if (request.headers.range) {
+          sendFile(request, response)
+        } else {
+          response.writeHead(200, { 'Content-Type': 'video/mp4', 'Content-Length': body.length })
+          response.write(body.subarray(0, half))
+        }
+        break
+      case '/slow.mp4':
+        response.writeHead(200, { 'Content-Type': 'video/mp4', 'Content-Length': body.length })
+        response.write(body.subarray(0, half))
+        setTimeout(() => response.end(body.subarray(half)), 600)
+        break
+      case '/unknown-length':
+        response.writeHead(200, { 'Content-Type': 'audio/ogg' })
+        response.end(body)
+        break
+      default:
+        response.writeHead(200, { 'Content-Type': 'text/html' })
+        response.end('<html></html>')
+    }
+  })
+  await new Promise<void>(resolve => server.listen(0, '127.0.0.1', resolve))
+  baseUrl = `http://127.0.0.1:${(server.address() as AddressInfo).port}`
+})
+
+afterAll(() => {
+  server.closeAllConnections()
+  server.close()
+})
+
+beforeEach(() => {
+  ranges = []
+  root = mkdtempSync(join(tmpdir(), 'clipy-direct-'))
+  output.path = join(root, 'clip.mp4')
+})
+
+function newProgress(): DownloadProgress {
+  return {
+    downloadId: 'download-1',
+    url: '',
+    title: 'clip',
+    progress: 0,
+    speed: '',
+    eta: '',
+    size: '',
+    downloadedBytes: 0,
+    totalBytes: 0,
+    status: 'downloading',
+    startTime: Date.now(),
+    retryCount: 0,
+  }
+}
+
+const videoInfo = {
+  id: 'direct_abc',
+  channel: { name: '127.0.0.1' },
+  direct: { contentType: 'video/mp4', extension: 'mp4' },
+} as VideoInfo
+
+function download(path: string, progress = newProgress(), options = {}, controller = new AbortController()) {
+  const events = new EventEmitter()
+  const updates: DownloadProgress[] = []
+  events.on('progress', (update: DownloadProgress) => updates.push({ ...update }))
+  const result = downloadDirect(`${baseUrl}${path}`, options, progress, videoInfo, events, controller)
+  return { result, updates, progress, controller }
+}
+
+async function rejection(promise: Promise<unknown>): Promise<unknown> {
+  try {
+    await promise
+  } catch (error) {
+    return error
+  }
+  throw new Error('Expected the promise to reject')
+}
+
+describe('probeDirectMedia', () => {
+  it('reads the type and size of a media file', async () => {
+    expect(await probeDirectMedia(`${baseUrl}/clip.mp4`)).toEqual({
+      contentType: 'video/mp4',
+      extension: 'mp4',
+      size: body.length,
+      fileName: 'clip.mp4',
+    })
+  })
+
+  it('recognises media by its content type when the URL has no extension', async () => {
+    expect(await probeDirectMedia(`${baseUrl}/clip`)).toMatchObject({ extension: 'mp4', fileName: 'clip' })
+  })
+
+  it('rejects pages', async () => {
+    expect(await probeDirectMedia(`${baseUrl}/watch`)).toBeNull()
+    expect(await rejection(getDirectVideoInfo(`${baseUrl}/watch`))).toMatchObject({
+      code: DownloadErrorCode.UNSUPPORTED_SITE,
+    })
+  })
+})
+
+describe('downloadDirect', () => {
+  it('streams the file into place and reports it completed', async () => {
+    const { result, updates, progress } = download('/clip.mp4')
+    await result
+
+    expect(readFileSync(output.path).equals(body)).toBe(true)
+    expect(existsSync(`${output.path}.part`)).toBe(false)
+    expect(progress).toMatchObject({ status: 'completed', progress: 100, filePath: output.path, format: 'mp4' })
+    expect(updates.at(-1)).toMatchObject({ status: 'completed' })
+  })
+
+  it('reports progress against the Content-Length', async () => {
+    const { result, updates } = download('/slow.mp4')
+    await result
+
+    const downloading = updates.filter(update => update.status === 'downloading')
+    expect(downloading.length).toBeGreaterThan(0)
+    expect(downloading[0]).toMatchObject({ totalBytes: body.length, size: '256.00KiB' })
+    expect(downloading[0].downloadedBytes).toBeGreaterThanOrEqual(half)
+  })
+
+  it('reports the bytes received when the server sends no length', async () => {
+    output.path = join(root, 'clip.ogg')
+    const { result, progress } = download('/unknown-length')
+    await result
+
+    expect(readFileSync(output.path).equals(body)).toBe(true)
+    expect(progress.totalBytes).toBe(0)
+  })
+
+  it('keeps the part file on pause and resumes it with a Range request', async () => {
+    const first = download('/stalls.mp4')
+    const partPath = `${output.path}.part`
+    await vi.waitFor(() => expect(existsSync(partPath) && statSync(partPath).size).toBe(half))
+
+    first.controller.abort('pause')
+    expect(await rejection(first.result)).toMatchObject({ code: DownloadErrorCode.DOWNLOAD_PAUSED })
+    expect(first.progress.pausedState).toMatchObject({
+      outputTemplate: output.path,
+      partialFiles: [{ path: partPath, size: half }],
+      downloadedBytes: half,
+    })
+
+    const second = download('/stalls.mp4', first.progress, { resumeFrom: first.progress.pausedState })
+    await second.result
+
+    expect(ranges.at(-1)).toBe(`bytes=${half}-`)
+    expect(readFileSync(output.path).equals(body)).toBe(true)
+    expect(second.progress.status).toBe('completed')
+  })
+
+  it('starts over when the server ignores the range', async () => {
+    writeFileSync(`${output.path}.part`, body.subarray(0, half))
+    const resumeFrom = {
+      options: {},
+      outputTemplate: output.path,
+      partialFiles: [],
+      downloadedBytes: half,
+      pausedAt: 0,
+    }
+
+    await download('/no-range.mp4', newProgress(), { resumeFrom }).result
+
+    expect(ranges.at(-1)).toBe(`bytes=${half}-`)
+    expect(readFileSync(output.path).equals(body)).toBe(true)
+  })
+
+  it('removes the part file when cancelled', async () => {
+    const { result, controller } = download('/stalls.mp4')
+    const partPath = `${output.path}.part`
+    await vi.waitFor(() => expect(existsSync(partPath) && statSync(partPath).size).toBe(half))
+
+    controller.abort()
+
+    expect(await rejection(result)).toMatchObject({ code: DownloadErrorCode.DOWNLOAD_CANCELLED })
+    expect(existsSync(partPath)).toBe(false)
+  })
+
+  it('skips a file that is already downloaded', async () => {
+    writeFileSync(output.path, 'done')
+    const { result, progress } = download('/clip.mp4')
+    await result
+
+    expect(ranges).toEqual([])
+    expect(progress.status).toBe('completed')
+  })
+})
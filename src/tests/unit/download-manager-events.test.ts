@@ -0,0 +1,167 @@
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+import type { DownloadProgress } from '../../types/download'
+
+const storage = vi.hoisted(() => ({
+  removeDownloadFromStorage: vi.fn(),
+  getStoredDownloadById: vi.fn(),
+  getStoredDownloads: vi.fn((): DownloadProgress[] => []),
+  addDownloadToStorage: vi.fn(),
+  updateDownloadInStorage: vi.fn(),
+}))
+const ytdlp = vi.hoisted(() => ({
+  listeners: new Map<string, (progress: DownloadProgress) => void>(),
+  cancelDownload: vi.fn(() => true),
+  startDownload: vi.fn(async () => 'yt_1'),
+}))
+const settings = vi.hoisted(() => ({ download: { finalContainer: 'keep' } }))
+
+vi.mock('../../services/download-storage', () => storage)
+vi.mock('../../utils/file-removal', () => ({ removeFile: vi.fn() }))
+vi.mock('../../services/downloader/yt-dlp-manager', () => ({
+  addEventListener: (event: string, listener: (progress: DownloadProgress) => void) => {
+    ytdlp.listeners.set(event, listener)
+  },
+  cancelDownload: ytdlp.cancelDownload,
+  getVideoInfo: vi.fn(async (url: string) => ({
+    id: url.slice(-3),
+    title: 'Clip',
+    duration: 60,
+    channel: { name: 'Channel', id: '' },
+    thumbnails: [],
+    uploadDate: '',
+    views: 0,
+    formats: [],
+  })),
+  initializeDownloadManager: vi.fn(async () => {}),
+  pauseDownload: vi.fn(),
+  startDownload: ytdlp.startDownload,
+}))
+vi.mock('../../utils/config', () => ({
+  ConfigManager: {
+    getInstance: () => ({
+      get: () => settings.download,
+      getNested: (key: string) => (key === 'download.maxConcurrentDownloads' ? 1 : undefined),
+    }),
+  },
+}))
+vi.mock('../../services/video-cache', () => ({ VideoCache: { getInstance: () => ({}) } }))
+vi.mock('../../services/video-processor', () => ({
+  VideoProcessor: {
+    getInstance: () => ({
+      getVideoMetadata: vi.fn(async () => ({ width: 0, height: 0 })),
+      // Runs until it is aborted, like a remux FFmpeg is still working on
+      remuxVideo: vi.fn(
+        (_input: string, _output: string, options: { signal?: AbortSignal }) =>
+          new Promise((_resolve, reject) => {
+            options.signal?.addEventListener('abort', () => reject(new Error('Cancelled')))
+          }),
+      ),
+    }),
+  },
+}))
+vi.mock('../../services/library', () => ({ enrichLibraryEntry: vi.fn(async () => {}), getVideoIdFromUrl: () => null }))
+vi.mock('../../services/lifecycle', () => ({ SHUTDOWN_TIMEOUT: 10000 }))
+vi.mock('../../services/watch-history', () => ({
+  getContinueWatching: vi.fn(),
+  removePlaybackPosition: vi.fn(),
+  withPlaybackPositions: vi.fn(),
+}))
+vi.mock('../../services/channels', () => ({ recordChannel: vi.fn() }))
+vi.mock('../../services/podcast-output', () => ({
+  hasPodcastFeed: vi.fn(),
+  isAudioFile: vi.fn(() => false),
+  writeChapterFiles: vi.fn(),
+  writePodcastFeed: vi.fn(),
+}))
+vi.mock('../../services/downloader/partial-downloads', () => ({ removePartialFiles: vi.fn() }))
+
+import { removePartialFiles } from '../../services/downloader/partial-downloads'
+
+let downloadManager: import('../../services/download-manager').DownloadManager
+let events: { event: string; progress: DownloadProgress }[]
+let summaries: unknown[]
+
+function finishDownload(filePath: string): void {
+  ytdlp.listeners.get('completed')!({ downloadId: 'yt_1', status: 'completed', filePath } as DownloadProgress)
+}
+
+async function settle(): Promise<void> {
+  await new Promise(resolve => setTimeout(resolve, 0))
+}
+
+beforeEach(async () => {
+  vi.clearAllMocks()
+  ytdlp.listeners.clear()
+  settings.download = { finalContainer: 'keep' }
+  // A fresh manager per test: it is a singleton
+  vi.resetModules()
+  const { DownloadManager } = await import('../../services/download-manager')
+  downloadManager = DownloadManager.getInstance()
+  await vi.waitFor(() => expect(ytdlp.listeners.has('completed')).toBe(true))
+
+  events = []
+  summaries = []
+  for (const event of ['completed', 'failed', 'cancelled']) {
+    downloadManager.on(event, (progress: DownloadProgress) => events.push({ event, progress: { ...progress } }))
+  }
+  downloadManager.on('queue-completed', summary => summaries.push(summary))
+})
+
+describe('completing a download', () => {
+  it('still completes the download and frees the queue when saving it fails', async () => {
+    storage.addDownloadToStorage.mockImplementationOnce(() => {
+      throw new Error('Disk full')
+    })
+    const { downloadId } = await downloadManager.startDownload('https://youtu.be/abc')
+
+    finishDownload('/videos/clip.mp4')
+    await settle()
+
+    expect(events.map(({ event }) => event)).toEqual(['completed'])
+    expect(downloadManager.getStats().active).toBe(0)
+    expect(await downloadManager.getDownloadProgress(downloadId)).toMatchObject({ status: 'completed' })
+    expect(summaries).toEqual([{ completed: 1, failed: 0, cancelled: 0 }])
+  })
+})
+
+describe('cancelAllDownloads', () => {
+  it('stops a download that is being remuxed instead of completing it', async () => {
+    settings.download = { finalContainer: 'mkv' }
+    const { downloadId } = await downloadManager.startDownload('https://youtu.be/abc')
+    finishDownload('/videos/clip.mp4')
+
+    expect(downloadManager.cancelAllDownloads()).toEqual([downloadId])
+    await settle()
+
+    expect(events.map(({ event }) => event)).toEqual(['cancelled'])
+    expect(events[0].progress.status).toBe('cancelled')
+    expect(storage.addDownloadToStorage).not.toHaveBeenCalled()
+    expect(summaries).toEqual([{ completed: 0, failed: 0, cancelled: 1 }])
+  })
+
+  it('removes the partial files of a resumed download waiting in the queue', async () => {
+    const pausedState = {
+      options: {},
+      outputTemplate: '/videos/Paused.%(ext)s',
+      partialFiles: [],
+      downloadedBytes: 1,
+      pausedAt: 0,
+    }
+    storage.getStoredDownloads.mockReturnValue([
+      { downloadId: 'dl_paused', url: 'https://youtu.be/xyz', status: 'paused', startTime: 0, pausedState },
+    ] as unknown as DownloadProgress[])
+    ytdlp.listeners.clear()
+    vi.resetModules()
+    const { DownloadManager } = await import('../../services/download-manager')
+    downloadManager = DownloadManager.getInstance()
+    await vi.waitFor(() => expect(ytdlp.listeners.has('completed')).toBe(true))
+
+    await downloadManager.startDownload('https://youtu.be/abc') // Takes the only download slot
+    await downloadManager.resumeDownload('dl_paused')
+
+    expect(downloadManager.cancelAllDownloads()).toHaveLength(2)
+    expect(vi.mocked(removePartialFiles)).toHaveBeenCalledWith(pausedState)
+    expect(storage.removeDownloadFromStorage).toHaveBeenCalledWith('dl_paused')
+  })
+})
@@ -0,0 +1,117 @@
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+import type { DownloadProgress, FileRemovalResult } from '../../types/download'
+
+const storage = vi.hoisted(() => ({
+  removeDownloadFromStorage: vi.fn(),
+  getStoredDownloadById: vi.fn(),
+  getStoredDownloads: vi.fn(() => []),
+  addDownloadToStorage: vi.fn(),
+  updateDownloadInStorage: vi.fn(),
+}))
+const removeFile = vi.hoisted(() => vi.fn())
+
+vi.mock('../../services/download-storage', () => storage)
+vi.mock('../../utils/file-removal', () => ({ removeFile }))
+vi.mock('../../services/downloader/yt-dlp-manager', () => ({
+  addEventListener: vi.fn(),
+  cancelDownload: vi.fn(),
+  getVideoInfo: vi.fn(),
+  // Never finishes, so no queue processor or event forwarding runs in these tests
+  initializeDownloadManager: vi.fn(() => new Promise(() => {})),
+  pauseDownload: vi.fn(),
+  startDownload: vi.fn(),
+}))
+vi.mock('../../utils/config', () => ({
+  ConfigManager: {
+    getInstance: () => ({
+      get: () => ({ permanentlyDeleteFiles: false }),
+      getNested: () => undefined,
+    }),
+  },
+}))
+vi.mock('../../services/video-cache', () => ({ VideoCache: { getInstance: () => ({}) } }))
+vi.mock('../../services/video-processor', () => ({ VideoProcessor: { getInstance: () => ({}) } }))
+vi.mock('../../services/library', () => ({ enrichLibraryEntry: vi.fn(), getVideoIdFromUrl: () => null }))
+vi.mock('../../services/lifecycle', () => ({ SHUTDOWN_TIMEOUT: 10000 }))
+vi.mock('../../services/watch-history', () => ({
+  getContinueWatching: vi.fn(),
+  removePlaybackPosition: vi.fn(),
+  withPlaybackPositions: vi.fn(),
+}))
+vi.mock('../../services/channels', () => ({ recordChannel: vi.fn() }))
+vi.mock('../../services/podcast-output', () => ({
+  hasPodcastFeed: vi.fn(),
+  isAudioFile: vi.fn(),
+  writeChapterFiles: vi.fn(),
+  writePodcastFeed: vi.fn(),
+}))
+
+import { DownloadManager } from '../../services/download-manager'
+
+const downloadManager = DownloadManager.getInstance()
+
+const stored: Partial<DownloadProgress> = {
+  downloadId: 'dl_1',
+  status: 'completed',
+  filePath: '/videos/clip.mp4',
+  thumbnailPath: '/videos/clip.jpg',
+  sidecarFiles: ['/videos/clip.en.vtt'],
+}
+
+const removed = (path: string): FileRemovalResult => ({ path, method: 'trash' })
+const failed = (path: string): FileRemovalResult => ({
+  path,
+  method: 'trash',
+  code: 'DELETE_FAILED',
+  error: 'Permission denied',
+})
+
+beforeEach(() => {
+  vi.clearAllMocks()
+  storage.getStoredDownloadById.mockReturnValue(stored)
+  storage.removeDownloadFromStorage.mockReturnValue(true)
+  removeFile.mockImplementation(async (path: string) => removed(path))
+})
+
+describe('deleteDownload', () => {
+  it('removes the files and the download', async () => {
+    const result = await downloadManager.deleteDownload('dl_1', { deleteFile: true })
+
+    expect(result).toEqual({
+      downloadId: 'dl_1',
+      deleted: true,
+      files: [removed('/videos/clip.mp4'), removed('/videos/clip.jpg'), removed('/videos/clip.en.vtt')],
+    })
+    expect(storage.removeDownloadFromStorage).toHaveBeenCalledWith('dl_1')
+  })
+
+  it('keeps the download when the downloaded file cannot be removed', async () => {
+    removeFile.mockImplementation(async (path: string) => (path.endsWith('.mp4') ? failed(path) : removed(path)))
+
+    const result = await downloadManager.deleteDownload('dl_1', { deleteFile: true })
+
+    expect(result).toMatchObject({ deleted: false, code: 'DELETE_FAILED', error: 'Permission denied' })
+    expect(result.files).toEqual([failed('/videos/clip.mp4')])
+    expect(storage.removeDownloadFromStorage).not.toHaveBeenCalled()
+  })
+
+  it('removes the download and reports thumbnail or sidecar files that were left behind', async () => {
+    removeFile.mockImplementation(async (path: string) => (path.endsWith('.jpg') ? failed(path) : removed(path)))
+
+    const result = await downloadManager.deleteDownload('dl_1', { deleteFile: true })
+
+    expect(result.deleted).toBe(true)
+    expect(result.code).toBeUndefined()
+    expect(result.failedFiles).toEqual([failed('/videos/clip.jpg')])
+    expect(result.files).toHaveLength(3)
+    expect(storage.removeDownloadFromStorage).toHaveBeenCalledWith('dl_1')
+  })
+
+  it('leaves files alone without deleteFile', async () => {
+    const result = await downloadManager.deleteDownload('dl_1')
+
+    expect(result).toEqual({ downloadId: 'dl_1', deleted: true, files: [] })
+    expect(removeFile).not.toHaveBeenCalled()
+  })
+})
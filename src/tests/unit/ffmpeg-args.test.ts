@@ -0,0 +1,126 @@
+import { describe, expect, it } from 'vitest'
+
+import type { GifDither } from '../../types/project'
+import {
+  REMUX_CONTAINERS,
+  REMUX_CONTAINER_CODECS,
+  buildAtempoFilter,
+  buildGifFrameFilter,
+  buildPaletteUseFilter,
+  getRemuxAudioEncoder,
+  getRemuxIncompatibility,
+  getRemuxVideoEncoder,
+  normalizeGifOptions,
+} from '../../utils/ffmpeg-args'
+
+describe('buildAtempoFilter', () => {
+  it('uses a single atempo within its range', () => {
+    expect(buildAtempoFilter(1)).toBe('atempo=1')
+    expect(buildAtempoFilter(0.5)).toBe('atempo=0.5')
+    expect(buildAtempoFilter(1.5)).toBe('atempo=1.5')
+    expect(buildAtempoFilter(2)).toBe('atempo=2')
+  })
+
+  it('chains atempo steps for speeds below 0.5', () => {
+    expect(buildAtempoFilter(0.25)).toBe('atempo=0.5,atempo=0.5')
+    expect(buildAtempoFilter(0.3)).toBe('atempo=0.5,atempo=0.6')
+  })
+
+  it('chains atempo steps for speeds above 2', () => {
+    expect(buildAtempoFilter(4)).toBe('atempo=2,atempo=2')
+    expect(buildAtempoFilter(3)).toBe('atempo=2,atempo=1.5')
+  })
+
+  it('falls back to normal speed for invalid speeds', () => {
+    expect(buildAtempoFilter(0)).toBe('atempo=1')
+    expect(buildAtempoFilter(-2)).toBe('atempo=1')
+  })
+})
+
+describe('buildGifFrameFilter', () => {
+  it('uses the default fps and no scaling without options', () => {
+    expect(buildGifFrameFilter()).toBe('fps=15')
+  })
+
+  it('caps fps at the GIF maximum and the source frame rate', () => {
+    expect(buildGifFrameFilter({ fps: 60 })).toBe('fps=30')
+    expect(buildGifFrameFilter({ fps: 25 }, 24)).toBe('fps=24')
+  })
+
+  it('scales down to a rounded max width', () => {
+    expect(buildGifFrameFilter({ fps: 10, maxWidth: 479.6 })).toBe("fps=10,scale='min(480,iw)':-1:flags=lanczos")
+  })
+
+  it('never puts non-numeric values into the filter graph', () => {
+    const options = { fps: '10,drawtext=text=x' as unknown as number, maxWidth: '1;[0:v]' as unknown as number }
+
+    expect(buildGifFrameFilter(options)).toBe('fps=15')
+    expect(buildGifFrameFilter({ fps: -5, maxWidth: 0 })).toBe('fps=15')
+    expect(buildGifFrameFilter({ fps: Infinity, maxWidth: NaN })).toBe('fps=15')
+  })
+})
+
+describe('buildPaletteUseFilter', () => {
+  it('uses the given dither', () => {
+    expect(buildPaletteUseFilter('bayer')).toBe('paletteuse=dither=bayer:diff_mode=rectangle')
+    expect(buildPaletteUseFilter()).toBe('paletteuse=dither=sierra2_4a:diff_mode=rectangle')
+  })
+
+  it('falls back to the default for an unknown dither', () => {
+    expect(buildPaletteUseFilter('none,split' as GifDither)).toBe('paletteuse=dither=sierra2_4a:diff_mode=rectangle')
+  })
+})
+
+describe('normalizeGifOptions', () => {
+  it('clamps fps and max width to positive integers', () => {
+    expect(normalizeGifOptions({ fps: 12.4, maxWidth: 640.2, dither: 'none' })).toEqual({
+      fps: 12,
+      maxWidth: 640,
+      dither: 'none',
+    })
+    expect(normalizeGifOptions({ fps: 120, maxWidth: -1 })).toEqual({ fps: 30, maxWidth: undefined, dither: undefined })
+  })
+
+  it('rejects an unknown dither', () => {
+    expect(() => normalizeGifOptions({ dither: 'sierra3' as GifDither })).toThrow('Unsupported GIF dither')
+  })
+})
+
+describe('remux codecs', () => {
+  // ffprobe codec_name each fallback encoder produces
+  const ENCODER_CODECS: Record<string, string> = { aac: 'aac', libopus: 'opus', libx264: 'h264', 'libvpx-vp9': 'vp9' }
+
+  it('copies audio a container accepts and re-encodes the rest', () => {
+    expect(getRemuxAudioEncoder('mp4', 'aac')).toBeNull()
+    expect(getRemuxAudioEncoder('mp4', 'opus')).toBe('aac')
+    expect(getRemuxAudioEncoder('webm', 'aac')).toBe('libopus')
+    expect(getRemuxAudioEncoder('ts', 'opus')).toBeNull()
+    expect(getRemuxAudioEncoder('mov', 'flac')).toBe('aac')
+  })
+
+  it('copies anything into MKV and streams without audio', () => {
+    expect(getRemuxAudioEncoder('mkv', 'opus')).toBeNull()
+    expect(getRemuxAudioEncoder('mkv', 'pcm_s16le')).toBeNull()
+    expect(getRemuxAudioEncoder('mp4', null)).toBeNull()
+    expect(getRemuxVideoEncoder('mkv', 'prores')).toBeNull()
+  })
+
+  it('explains and re-encodes video a container cannot hold', () => {
+    expect(getRemuxIncompatibility('webm', 'h264')).toBe('H264 video cannot be stored in WEBM without re-encoding')
+    expect(getRemuxVideoEncoder('webm', 'h264')).toBe('libvpx-vp9')
+    expect(getRemuxIncompatibility('mp4', 'h264')).toBeNull()
+    expect(getRemuxVideoEncoder('mp4', null)).toBeNull()
+  })
+
+  it('re-encodes into codecs the container accepts', () => {
+    for (const container of REMUX_CONTAINERS) {
+      const codecs = REMUX_CONTAINER_CODECS[container]
+      if (!codecs) continue
+
+      const audioEncoder = getRemuxAudioEncoder(container, 'unknown_codec')!
+      const videoEncoder = getRemuxVideoEncoder(container, 'unknown_codec')!
+      expect(codecs.audio, container).toContain(ENCODER_CODECS[audioEncoder])
+      expect(codecs.video, container).toContain(ENCODER_CODECS[videoEncoder])
+    }
+  })
+})
@@ -0,0 +1,107 @@
+import { describe, expect, it } from 'vitest'
+
+import { en } from '../../localization/languages/en'
+import { es } from '../../localization/languages/es'
+import { fr } from '../../localization/languages/fr'
+import { DownloadErrorCode } from '../../types/download'
+import {
+  createLocalizedDownloadError,
+  createLocalizedErrorResponse,
+  formatMessage,
+  getAvailableLanguages,
+  interpolate,
+  isMessageKey,
+  localizeError,
+} from '../../utils/i18n'
+
+const placeholders = (template: string) => (template.match(/\{\{\s*\w+\s*\}\}/g) ?? []).sort()
+
+describe('interpolate', () => {
+  it('fills placeholders, with or without inner spaces', () => {
+    expect(interpolate('{{a}} and {{ b }}', { a: 'one', b: 2 })).toBe('one and 2')
+  })
+
+  it('fills a placeholder used more than once', () => {
+    expect(interpolate('{{n}} of {{n}}', { n: 3 })).toBe('3 of 3')
+  })
+
+  it('leaves unknown placeholders as they are', () => {
+    expect(interpolate('Failed: {{reason}}', {})).toBe('Failed: {{reason}}')
+    expect(interpolate('Failed: {{reason}}')).toBe('Failed: {{reason}}')
+  })
+
+  it('does not interpolate placeholders inside param values', () => {
+    expect(interpolate('{{a}}', { a: '{{b}}', b: 'x' })).toBe('{{b}}')
+  })
+})
+
+describe('formatMessage', () => {
+  it('formats in English by default', () => {
+    expect(formatMessage('errorSiteBlocked', { host: 'example.com' })).toBe(
+      'Downloads from example.com are not allowed by the site policy',
+    )
+  })
+
+  it('formats in a language from the language packs, ignoring the region', () => {
+    expect(formatMessage('errorSiteBlocked', { host: 'example.com' }, 'es-MX')).toBe(
+      'La política de sitios no permite descargas desde example.com',
+    )
+    expect(formatMessage('errorDownloadFailed', { reason: 'HTTP 500' }, 'fr_FR')).toBe(
+      'Échec du téléchargement : HTTP 500',
+    )
+  })
+
+  it('falls back to English for unknown languages', () => {
+    expect(formatMessage('errorUrlRequired', undefined, 'de')).toBe('URL is required')
+  })
+})
+
+describe('language packs', () => {
+  it('use the same placeholders in every translated error', () => {
+    for (const key of Object.keys(en.translation).filter(isMessageKey)) {
+      for (const pack of [es.translation, fr.translation] as Record<string, string>[]) {
+        if (pack[key] !== undefined) {
+          expect(placeholders(pack[key]), key).toEqual(placeholders(en.translation[key]))
+        }
+      }
+    }
+  })
+
+  it('lists the languages with a language pack', () => {
+    expect(getAvailableLanguages().map(language => language.key)).toEqual(['en', 'es', 'fr'])
+  })
+})
+
+describe('localized errors', () => {
+  it('carry the English text plus the key and params', () => {
+    const error = createLocalizedDownloadError(
+      'errorFormatUnavailable',
+      { requested: '4K', available: '1080p, 720p' },
+      DownloadErrorCode.NO_FORMAT_AVAILABLE,
+    )
+
+    expect(error).toMatchObject({
+      message: 'This video is not available in 4K. Available qualities: 1080p, 720p',
+      code: DownloadErrorCode.NO_FORMAT_AVAILABLE,
+      messageKey: 'errorFormatUnavailable',
+      params: { requested: '4K', available: '1080p, 720p' },
+    })
+    expect(localizeError(error, 'es', 'Failed')).toBe(
+      'Este video no está disponible en 4K. Calidades disponibles: 1080p, 720p',
+    )
+  })
+
+  it('localize error responses', () => {
+    const response = createLocalizedErrorResponse('errorInvalidUrl', undefined, 'INVALID_URL')
+
+    expect(response).toMatchObject({ success: false, error: 'Invalid URL format', messageKey: 'errorInvalidUrl' })
+    expect(localizeError(response, 'fr', 'Failed')).toBe("Format d'URL invalide")
+  })
+
+  it('show the plain text for unknown or missing keys', () => {
+    expect(isMessageKey('settingsTitle')).toBe(false)
+    expect(localizeError({ error: 'Boom', messageKey: 'settingsTitle' }, 'es', 'Failed')).toBe('Boom')
+    expect(localizeError({ message: 'Thrown' }, 'es', 'Failed')).toBe('Thrown')
+    expect(localizeError(null, 'es', 'Failed')).toBe('Failed')
+  })
+})
@@ -0,0 +1,73 @@
+import { mkdtempSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+const storage = vi.hoisted(() => ({
+  addDownloadsToStorage: vi.fn(),
+  getStoredDownloadById: vi.fn(),
+  getStoredDownloadByPath: vi.fn(),
+  getStoredDownloads: vi.fn(() => []),
+  updateDownloadInStorage: vi.fn(),
+}))
+
+vi.mock('../../services/download-storage', () => storage)
+vi.mock('../../services/downloader/yt-dlp-provider', () => ({
+  downloadThumbnail: vi.fn(),
+  downloadThumbnailOnly: vi.fn(),
+}))
+vi.mock('../../services/media-cache', () => ({
+  getMediaArtifactDir: vi.fn(),
+  getMediaFingerprint: vi.fn(),
+}))
+vi.mock('../../services/video-processor', () => ({
+  VideoProcessor: { getInstance: () => ({}) },
+}))
+
+import { importLibraryCsv, parseCsv } from '../../services/library'
+
+let dir: string
+
+beforeEach(() => {
+  vi.clearAllMocks()
+  dir = mkdtempSync(join(tmpdir(), 'clipy-csv-'))
+})
+
+function writeCsv(lines: string[]): string {
+  const csvPath = join(dir, 'library.csv')
+  writeFileSync(csvPath, lines.join('\r\n'), 'utf8')
+  return csvPath
+}
+
+describe('parseCsv', () => {
+  it('parses quoted fields, escaped quotes and embedded line breaks', () => {
+    expect(parseCsv('\uFEFFa,b\r\n"x, y","say ""hi"""\n"two\nlines",z')).toEqual([
+      ['a', 'b'],
+      ['x, y', 'say "hi"'],
+      ['two\nlines', 'z'],
+    ])
+  })
+})
+
+describe('importLibraryCsv', () => {
+  it('reports errors by 1-based row with the header as row 1', () => {
+    const video = join(dir, 'video.mp4')
+    const other = join(dir, 'other.mp4')
+    writeFileSync(video, 'data')
+    writeFileSync(other, 'data')
+    const csvPath = writeCsv([
+      'file_path,title,duration_seconds',
+      `${video},"Title with a\nline break",10`,
+      'relative/video.mp4,Bad path,',
+      `${other},Bad duration,-5`,
+    ])
+
+    const result = importLibraryCsv(csvPath, false)
+
+    expect(result.imported).toBe(1)
+    expect(result.errors).toEqual([
+      { row: 3, message: 'file_path must be an absolute path' },
+      { row: 4, message: 'Invalid duration_seconds: -5' },
+    ])
+  })
+})
@@ -0,0 +1,94 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
+
+import { isShuttingDown, requestShutdown, runShutdownSteps } from '../../services/lifecycle'
+
+const never = () => new Promise<void>(() => {})
+
+beforeEach(() => {
+  vi.useFakeTimers()
+})
+
+afterEach(() => {
+  vi.useRealTimers()
+})
+
+describe('runShutdownSteps', () => {
+  it('runs the steps in order', async () => {
+    const order: string[] = []
+    const steps = ['a', 'b', 'c'].map(name => ({
+      name,
+      run: async () => {
+        order.push(name)
+      },
+    }))
+
+    expect(await runShutdownSteps(steps)).toEqual({ timedOutSteps: [], failedSteps: [] })
+    expect(order).toEqual(['a', 'b', 'c'])
+  })
+
+  it('still runs later steps when one fails', async () => {
+    const save = vi.fn()
+    const result = await runShutdownSteps([
+      {
+        name: 'sync-failure',
+        run: () => {
+          throw new Error('Window already destroyed')
+        },
+      },
+      { name: 'async-failure', run: async () => Promise.reject(new Error('Disk full')) },
+      { name: 'save', run: save },
+    ])
+
+    expect(result).toEqual({ timedOutSteps: [], failedSteps: ['sync-failure', 'async-failure'] })
+    expect(save).toHaveBeenCalled()
+  })
+
+  it('gives up on a hanging step after its own timeout and saves afterwards', async () => {
+    const save = vi.fn()
+    const result = runShutdownSteps([
+      { name: 'pause-downloads', run: never, timeoutMs: 10000 },
+      { name: 'save-download-storage', run: save },
+    ])
+
+    await vi.advanceTimersByTimeAsync(9999)
+    expect(save).not.toHaveBeenCalled()
+
+    await vi.advanceTimersByTimeAsync(1)
+    expect(await result).toEqual({ timedOutSteps: ['pause-downloads'], failedSteps: [] })
+    expect(save).toHaveBeenCalled()
+  })
+
+  it('gives steps without a timeout of their own two seconds', async () => {
+    const result = runShutdownSteps([{ name: 'stop-watchers', run: never }])
+
+    await vi.advanceTimersByTimeAsync(2000)
+
+    expect(await result).toEqual({ timedOutSteps: ['stop-watchers'], failedSteps: [] })
+  })
+
+  it('does not time out a step that finished in time', async () => {
+    const result = runShutdownSteps([
+      { name: 'slow', run: () => new Promise<void>(resolve => setTimeout(resolve, 1500)) },
+    ])
+
+    await vi.advanceTimersByTimeAsync(5000)
+
+    expect(await result).toEqual({ timedOutSteps: [], failedSteps: [] })
+    expect(vi.getTimerCount()).toBe(0)
+  })
+})
+
+describe('requestShutdown', () => {
+  it('runs the shutdown once for every request', async () => {
+    const run = vi.fn()
+    expect(isShuttingDown()).toBe(false)
+
+    const first = requestShutdown([{ name: 'save', run }])
+    const second = requestShutdown([{ name: 'save', run }])
+
+    expect(second).toBe(first)
+    expect(isShuttingDown()).toBe(true)
+    await first
+    expect(run).toHaveBeenCalledTimes(1)
+  })
+})
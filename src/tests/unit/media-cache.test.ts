@@ -0,0 +1,115 @@
+import { existsSync, mkdtempSync, readFileSync, readdirSync, rmSync, utimesSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+const appData = vi.hoisted(() => ({ dir: '' }))
+
+vi.mock('../../utils/platform', () => ({
+  PlatformUtils: { getInstance: () => ({ getAppDataDir: () => appData.dir }) },
+}))
+
+import {
+  computeMediaFingerprint,
+  getMediaArtifactDir,
+  getMediaCacheDir,
+  getMediaFingerprint,
+  invalidateMediaCache,
+  pruneMediaCache,
+} from '../../services/media-cache'
+
+let root: string
+
+function writeMedia(name: string, content: string | Buffer, mtime = 1_700_000_000): string {
+  const filePath = join(root, name)
+  writeFileSync(filePath, content)
+  utimesSync(filePath, mtime, mtime)
+  return filePath
+}
+
+const readIndex = () => JSON.parse(readFileSync(join(getMediaCacheDir(), 'index.json'), 'utf8'))
+
+beforeEach(() => {
+  root = mkdtempSync(join(tmpdir(), 'clipy-media-'))
+  // The index is loaded once per process, so every test keeps using the first cache directory
+  appData.dir ||= mkdtempSync(join(tmpdir(), 'clipy-appdata-'))
+})
+
+describe('computeMediaFingerprint', () => {
+  it('is stable for the same content, size and mtime', () => {
+    const a = writeMedia('a.mp4', 'same bytes')
+    const b = writeMedia('b.mp4', 'same bytes')
+
+    expect(computeMediaFingerprint(a)).toMatch(/^[0-9a-f]{32}$/)
+    expect(computeMediaFingerprint(a)).toBe(computeMediaFingerprint(b))
+  })
+
+  it('changes with the mtime or the content at either end of the file', () => {
+    const middle = Buffer.alloc(200 * 1024, 1)
+    const original = computeMediaFingerprint(writeMedia('clip.mp4', middle))
+
+    expect(computeMediaFingerprint(writeMedia('clip.mp4', middle, 1_700_000_500))).not.toBe(original)
+
+    const tailEdited = Buffer.from(middle)
+    tailEdited[tailEdited.length - 1] = 2
+    expect(computeMediaFingerprint(writeMedia('clip.mp4', tailEdited))).not.toBe(original)
+
+    // Bytes between the sampled ends are not hashed
+    const middleEdited = Buffer.from(middle)
+    middleEdited[100 * 1024] = 2
+    expect(computeMediaFingerprint(writeMedia('clip.mp4', middleEdited))).toBe(original)
+  })
+})
+
+describe('getMediaFingerprint', () => {
+  it('remembers fingerprints in the index while the file is unchanged', () => {
+    const filePath = writeMedia('clip.mp4', 'original')
+
+    const fingerprint = getMediaFingerprint(filePath)
+
+    expect(getMediaFingerprint(filePath)).toBe(fingerprint)
+    expect(readIndex()).toMatchObject({ version: 1, entries: { [filePath]: { fingerprint } } })
+  })
+
+  it('drops the old artifacts when a file is replaced under the same name', () => {
+    const filePath = writeMedia('clip.mp4', 'original')
+    const oldDir = getMediaArtifactDir(filePath)
+    writeFileSync(join(oldDir, 'waveform.json'), '[]')
+
+    writeMedia('clip.mp4', 're-trimmed', 1_700_000_900)
+    const newDir = getMediaArtifactDir(filePath)
+
+    expect(newDir).not.toBe(oldDir)
+    expect(existsSync(oldDir)).toBe(false)
+    expect(readIndex().entries[filePath].fingerprint).toBe(getMediaFingerprint(filePath))
+  })
+
+  it('keeps artifacts another file with the same content still uses', () => {
+    const a = writeMedia('a.mp4', 'shared')
+    const b = writeMedia('b.mp4', 'shared')
+    const sharedDir = getMediaArtifactDir(a)
+    expect(getMediaArtifactDir(b)).toBe(sharedDir)
+
+    invalidateMediaCache(a)
+
+    expect(existsSync(sharedDir)).toBe(true)
+    expect(readIndex().entries[a]).toBeUndefined()
+  })
+})
+
+describe('pruneMediaCache', () => {
+  it('forgets missing files and removes unreferenced artifact folders', () => {
+    const kept = writeMedia('kept.mp4', 'kept')
+    const gone = writeMedia('gone.mp4', 'gone')
+    const keptDir = getMediaArtifactDir(kept)
+    const goneDir = getMediaArtifactDir(gone)
+    rmSync(gone)
+
+    expect(pruneMediaCache()).toBeGreaterThanOrEqual(1)
+
+    expect(existsSync(keptDir)).toBe(true)
+    expect(existsSync(goneDir)).toBe(false)
+    expect(readIndex().entries[gone]).toBeUndefined()
+    expect(readdirSync(getMediaCacheDir())).toContain('index.json')
+  })
+})
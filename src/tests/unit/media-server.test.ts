@@ -0,0 +1,45 @@
+import { describe, expect, it, vi } from 'vitest'
+
+vi.mock('../../services/media-cache', () => ({ getMediaFingerprint: vi.fn() }))
+vi.mock('../../services/transcode-cache', () => ({ findCachedTranscode: vi.fn() }))
+
+import { parseRangeHeader } from '../../services/media-server'
+
+describe('parseRangeHeader', () => {
+  const size = 1000
+
+  it('sends the whole file without a usable header', () => {
+    expect(parseRangeHeader(undefined, size)).toBeNull()
+    expect(parseRangeHeader('', size)).toBeNull()
+    expect(parseRangeHeader('bytes=-', size)).toBeNull()
+    expect(parseRangeHeader('items=0-10', size)).toBeNull()
+    expect(parseRangeHeader('bytes=abc-def', size)).toBeNull()
+  })
+
+  it('ignores multi-range requests', () => {
+    expect(parseRangeHeader('bytes=0-10,20-30', size)).toBeNull()
+  })
+
+  it('parses closed and open-ended ranges', () => {
+    expect(parseRangeHeader('bytes=0-499', size)).toEqual({ start: 0, end: 499 })
+    expect(parseRangeHeader('bytes=500-', size)).toEqual({ start: 500, end: 999 })
+    expect(parseRangeHeader(' bytes=10-10 ', size)).toEqual({ start: 10, end: 10 })
+  })
+
+  it('clamps the end to the last byte', () => {
+    expect(parseRangeHeader('bytes=900-5000', size)).toEqual({ start: 900, end: 999 })
+  })
+
+  it('parses suffix ranges as the last N bytes', () => {
+    expect(parseRangeHeader('bytes=-100', size)).toEqual({ start: 900, end: 999 })
+    expect(parseRangeHeader('bytes=-5000', size)).toEqual({ start: 0, end: 999 })
+  })
+
+  it('rejects ranges outside the file', () => {
+    expect(parseRangeHeader('bytes=1000-', size)).toBe('unsatisfiable')
+    expect(parseRangeHeader('bytes=1000-1100', size)).toBe('unsatisfiable')
+    expect(parseRangeHeader('bytes=500-100', size)).toBe('unsatisfiable')
+    expect(parseRangeHeader('bytes=-0', size)).toBe('unsatisfiable')
+    expect(parseRangeHeader('bytes=0-', 0)).toBe('unsatisfiable')
+  })
+})
@@ -0,0 +1,89 @@
+import { existsSync, mkdtempSync, readFileSync, readdirSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+const storage = vi.hoisted(() => ({ cache: '/data/clipy-cache', temp: '/data/clipy-temp' }))
+
+vi.mock('../../services/storage-manager', () => ({
+  StorageManager: {
+    getInstance: () => ({ getCacheDirectory: () => storage.cache, getTempDirectory: () => storage.temp }),
+  },
+}))
+
+import {
+  PARTIAL_OUTPUT_PREFIX,
+  getAvailableOutputPath,
+  isProtectedOutputPath,
+  renderToPartialOutput,
+} from '../../utils/output-file'
+
+let root: string
+
+beforeEach(() => {
+  root = mkdtempSync(join(tmpdir(), 'clipy-output-'))
+})
+
+describe('getAvailableOutputPath', () => {
+  it('keeps a free path and numbers taken ones', () => {
+    const taken = new Set(['/out/clip.mp4', '/out/clip (1).mp4'])
+    const exists = (candidate: string) => taken.has(candidate)
+
+    expect(getAvailableOutputPath('/out/other.mp4', exists)).toBe('/out/other.mp4')
+    expect(getAvailableOutputPath('/out/clip.mp4', exists)).toBe(join('/out', 'clip (2).mp4'))
+  })
+
+  it('gives up once every suffix is taken', () => {
+    expect(() => getAvailableOutputPath('/out/clip.mp4', () => true)).toThrow('No free file name for clip.mp4')
+  })
+})
+
+describe('renderToPartialOutput', () => {
+  it('renders to a partial file and renames it next to an existing output', async () => {
+    const outputPath = join(root, 'clip.mp4')
+    writeFileSync(outputPath, 'previous')
+
+    const { outputPath: written } = await renderToPartialOutput(outputPath, false, async partialPath => {
+      expect(partialPath.startsWith(join(root, PARTIAL_OUTPUT_PREFIX))).toBe(true)
+      writeFileSync(partialPath, 'rendered')
+    })
+
+    expect(written).toBe(join(root, 'clip (1).mp4'))
+    expect(readFileSync(outputPath, 'utf-8')).toBe('previous')
+    expect(readFileSync(written, 'utf-8')).toBe('rendered')
+    expect(readdirSync(root).sort()).toEqual(['clip (1).mp4', 'clip.mp4'])
+  })
+
+  it('replaces the output when overwriting', async () => {
+    const outputPath = join(root, 'clip.mp4')
+    writeFileSync(outputPath, 'previous')
+
+    await renderToPartialOutput(outputPath, true, async partialPath => writeFileSync(partialPath, 'rendered'))
+
+    expect(readFileSync(outputPath, 'utf-8')).toBe('rendered')
+    expect(readdirSync(root)).toEqual(['clip.mp4'])
+  })
+
+  it('removes the partial file when the render fails', async () => {
+    const outputPath = join(root, 'clip.mp4')
+
+    const render = async (partialPath: string) => {
+      writeFileSync(partialPath, 'half')
+      throw new Error('FFmpeg exited with code 1')
+    }
+
+    await expect(renderToPartialOutput(outputPath, false, render)).rejects.toThrow('FFmpeg exited with code 1')
+    expect(existsSync(outputPath)).toBe(false)
+    expect(readdirSync(root)).toEqual([])
+  })
+})
+
+describe('isProtectedOutputPath', () => {
+  it('protects the configured storage cache and temp folders', () => {
+    expect(isProtectedOutputPath(join(storage.cache, 'transcodes', 'a.mp4'))).toBe(true)
+    expect(isProtectedOutputPath(join(storage.cache, 'yt-dlp', 'a.json'))).toBe(true)
+    expect(isProtectedOutputPath(join(storage.temp, 'a.mp4'))).toBe(true)
+    expect(isProtectedOutputPath('/data/clipy-cache-exports/a.mp4')).toBe(false)
+    expect(isProtectedOutputPath(join(root, 'a.mp4'))).toBe(false)
+  })
+})
@@ -0,0 +1,109 @@
+import { existsSync, mkdirSync, mkdtempSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it } from 'vitest'
+
+import {
+  findPartialFileMismatch,
+  findPartialFiles,
+  removePartialFiles,
+} from '../../services/downloader/partial-downloads'
+import type { PausedDownloadState } from '../../types/download'
+
+let root: string
+
+function writeFile(name: string, size: number, dir = root): string {
+  const filePath = join(dir, name)
+  writeFileSync(filePath, Buffer.alloc(size))
+  return filePath
+}
+
+function pausedState(overrides: Partial<PausedDownloadState> = {}): PausedDownloadState {
+  return {
+    options: {},
+    outputTemplate: join(root, 'Video [abc].%(ext)s'),
+    partialFiles: [],
+    downloadedBytes: 0,
+    pausedAt: 0,
+    ...overrides,
+  }
+}
+
+beforeEach(() => {
+  root = mkdtempSync(join(tmpdir(), 'clipy-partial-'))
+})
+
+describe('findPartialFiles', () => {
+  it('finds the part files, fragments and resume state of a download', () => {
+    writeFile('Video [abc].f137.mp4.part', 100)
+    writeFile('Video [abc].f140.m4a.part-Frag3.part', 20)
+    writeFile('Video [abc].f140.m4a.part-Frag2', 10)
+    writeFile('Video [abc].f140.m4a.ytdl', 5)
+    writeFile('Video [abc].mp4', 500) // Finished files don't count
+    writeFile('Other [xyz].mp4.part', 50) // Nor another download's
+
+    const files = findPartialFiles(join(root, 'Video [abc].%(ext)s'))
+
+    expect(files.sort((a, b) => a.path.localeCompare(b.path))).toEqual([
+      { path: join(root, 'Video [abc].f137.mp4.part'), size: 100 },
+      { path: join(root, 'Video [abc].f140.m4a.part-Frag2'), size: 10 },
+      { path: join(root, 'Video [abc].f140.m4a.part-Frag3.part'), size: 20 },
+      { path: join(root, 'Video [abc].f140.m4a.ytdl'), size: 5 },
+    ])
+  })
+
+  it('unescapes %% in the template', () => {
+    writeFile('100% Real [abc].mp4.part', 1)
+
+    expect(findPartialFiles(join(root, '100%% Real [abc].%(ext)s'))).toHaveLength(1)
+  })
+
+  it('looks in the temp folder when the download used one', () => {
+    const tempDir = join(root, 'temp')
+    mkdirSync(tempDir)
+    writeFile('Video [abc].mp4.part', 1, tempDir)
+
+    expect(findPartialFiles(join(root, 'Video [abc].%(ext)s'), tempDir)).toEqual([
+      { path: join(tempDir, 'Video [abc].mp4.part'), size: 1 },
+    ])
+  })
+
+  it('finds nothing in a folder that does not exist', () => {
+    expect(findPartialFiles(join(root, 'missing', 'Video.%(ext)s'))).toEqual([])
+  })
+})
+
+describe('findPartialFileMismatch', () => {
+  it('accepts files that are unchanged since the pause', () => {
+    const path = writeFile('Video [abc].mp4.part', 100)
+
+    expect(findPartialFileMismatch(pausedState({ partialFiles: [{ path, size: 100 }] }))).toBeNull()
+  })
+
+  it('reports missing and resized files', () => {
+    const path = writeFile('Video [abc].mp4.part', 120)
+
+    expect(findPartialFileMismatch(pausedState({ partialFiles: [{ path, size: 100 }] }))).toBe(
+      'Video [abc].mp4.part is 120 bytes, expected 100',
+    )
+    expect(
+      findPartialFileMismatch(pausedState({ partialFiles: [{ path: join(root, 'gone.part'), size: 1 }] })),
+    ).toBe('gone.part is missing')
+  })
+})
+
+describe('removePartialFiles', () => {
+  it('removes the partial files and the temp folder', () => {
+    const part = writeFile('Video [abc].mp4.part', 1)
+    const tempDir = join(root, 'temp')
+    mkdirSync(tempDir)
+    writeFile('Video [abc].m4a.part', 1, tempDir)
+    const finished = writeFile('Video [abc].mp4', 1)
+
+    removePartialFiles(pausedState({ tempDir, partialFiles: [{ path: part, size: 1 }] }))
+
+    expect(existsSync(part)).toBe(false)
+    expect(existsSync(tempDir)).toBe(false)
+    expect(existsSync(finished)).toBe(true)
+  })
+})
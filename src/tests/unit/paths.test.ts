@@ -0,0 +1,106 @@
+import { join } from 'path'
+import { describe, expect, it } from 'vitest'
+
+import { DownloadErrorCode } from '../../types/download'
+import { fitOutputTemplateToPathLimit, getPlatformPathLength, getUniqueOutputTemplate } from '../../utils/paths'
+
+const dir = join('/downloads', 'Clipy')
+const existing = (...names: string[]) => {
+  const files = new Set(names.map(name => join(dir, name)))
+  return (path: string) => files.has(path)
+}
+
+describe('getUniqueOutputTemplate', () => {
+  const template = join(dir, 'My Video.%(ext)s')
+
+  it('keeps the template when no file is there', () => {
+    expect(getUniqueOutputTemplate(template, ['mp4', 'mkv'], existing('Other.mp4'))).toBe(template)
+  })
+
+  it('numbers the template when a file exists under any of the extensions', () => {
+    expect(getUniqueOutputTemplate(template, ['mp4', 'mkv'], existing('My Video.mkv'))).toBe(
+      join(dir, 'My Video (1).%(ext)s'),
+    )
+    expect(
+      getUniqueOutputTemplate(template, ['mp4'], existing('My Video.mp4', 'My Video (1).mp4', 'My Video (2).mp4')),
+    ).toBe(join(dir, 'My Video (3).%(ext)s'))
+  })
+
+  it('checks escaped percent signs as the literal file name', () => {
+    const escaped = join(dir, '100%% Done.%(ext)s')
+
+    expect(getUniqueOutputTemplate(escaped, ['mp4'], existing('100% Done.mp4'))).toBe(
+      join(dir, '100%% Done (1).%(ext)s'),
+    )
+  })
+
+  it('keeps dots in titles out of the extension', () => {
+    const dotted = join(dir, 'Version 1.2.%(ext)s')
+
+    expect(getUniqueOutputTemplate(dotted, ['mp4'], existing('Version 1.2.mp4'))).toBe(
+      join(dir, 'Version 1.2 (1).%(ext)s'),
+    )
+  })
+})
+
+describe('getPlatformPathLength', () => {
+  it('counts UTF-8 bytes outside Windows and UTF-16 code units on Windows', () => {
+    expect(getPlatformPathLength('abc', false)).toBe(3)
+    expect(getPlatformPathLength('é', false)).toBe(2)
+    expect(getPlatformPathLength('日本', false)).toBe(6)
+    expect(getPlatformPathLength('日本', true)).toBe(2)
+    expect(getPlatformPathLength('😀', false)).toBe(4)
+    expect(getPlatformPathLength('😀', true)).toBe(2)
+  })
+})
+
+describe('fitOutputTemplateToPathLimit', () => {
+  const nameOf = (template: string) => template.slice(dir.length + 1, -'.%(ext)s'.length)
+
+  it('keeps templates that fit', () => {
+    const template = join(dir, 'Short title.%(ext)s')
+
+    expect(fitOutputTemplateToPathLimit(template, false)).toEqual({ template, truncated: false })
+  })
+
+  it('truncates long multibyte titles by bytes and adds a hash suffix', () => {
+    const title = '日本語のタイトル'.repeat(20) // 480 bytes in UTF-8
+    const { template, truncated } = fitOutputTemplateToPathLimit(join(dir, `${title}.%(ext)s`), false)
+
+    const name = nameOf(template)
+    expect(truncated).toBe(true)
+    expect(name).toMatch(/_[0-9a-f]{8}$/)
+    expect(title.startsWith(name.slice(0, -9))).toBe(true)
+    expect(Buffer.byteLength(name, 'utf8')).toBeLessThanOrEqual(255 - 24)
+  })
+
+  it("doesn't split characters made of several code points", () => {
+    const title = '👨‍👩‍👧'.repeat(60)
+    const name = nameOf(fitOutputTemplateToPathLimit(join(dir, `${title}.%(ext)s`), false).template)
+
+    expect(name.slice(0, -9).length % '👨‍👩‍👧'.length).toBe(0)
+  })
+
+  it('gives distinct long titles distinct names', () => {
+    const prefix = 'a'.repeat(300)
+    const first = fitOutputTemplateToPathLimit(join(dir, `${prefix} part 1.%(ext)s`), false).template
+    const second = fitOutputTemplateToPathLimit(join(dir, `${prefix} part 2.%(ext)s`), false).template
+
+    expect(first).not.toBe(second)
+  })
+
+  it('counts the whole path against the Windows limit', () => {
+    const windowsDir = join(dir, 'x'.repeat(150))
+    const { template } = fitOutputTemplateToPathLimit(join(windowsDir, `${'t'.repeat(120)}.%(ext)s`), true)
+
+    expect(template.length - '.%(ext)s'.length).toBeLessThanOrEqual(259 - 24)
+  })
+
+  it('fails with FILENAME_TOO_LONG when the folder leaves no room for a name', () => {
+    const deepDir = join(dir, 'x'.repeat(230))
+
+    expect(() => fitOutputTemplateToPathLimit(join(deepDir, `${'t'.repeat(40)}.%(ext)s`), true)).toThrow(
+      expect.objectContaining({ code: DownloadErrorCode.FILENAME_TOO_LONG }),
+    )
+  })
+})
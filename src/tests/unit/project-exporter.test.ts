@@ -0,0 +1,157 @@
+import { describe, expect, it, vi } from 'vitest'
+
+import type { Clip, ClipProperties, Project, ProjectExportSettings, Track } from '../../types/project'
+
+vi.mock('../../services/export-reports', () => ({ buildExportReport: vi.fn(), recordExportReport: vi.fn() }))
+vi.mock('../../services/library', () => ({ enrichAndRegister: vi.fn() }))
+vi.mock('../../services/video-processor', () => ({ VideoProcessor: { getInstance: () => ({}) } }))
+
+import {
+  type StreamCopySource,
+  buildChapters,
+  buildClipVideoFilter,
+  getStreamCopyBlockers,
+} from '../../services/project-exporter'
+
+const settings: ProjectExportSettings = {
+  outputPath: '/exports/out.mp4',
+  format: 'mp4',
+  videoCodec: 'h264',
+  audioCodec: 'aac',
+  quality: 'high',
+}
+
+const source: StreamCopySource = { videoCodec: 'h264', audioCodec: 'aac', width: 1920, height: 1080, frameRate: 30 }
+
+function clip(overrides: Partial<Omit<Clip, 'properties'>> = {}, properties: Partial<ClipProperties> = {}): Clip {
+  return {
+    id: 'clip_1',
+    type: 'video',
+    name: 'Clip',
+    sourcePath: '/media/source.mp4',
+    startTime: 0,
+    sourceStart: 10,
+    sourceEnd: 20,
+    properties: { volume: 1, speed: 1, opacity: 1, muted: false, ...properties },
+    markers: [],
+    ...overrides,
+  }
+}
+
+function track(clips: Clip[], overrides: Partial<Track> = {}): Track {
+  return {
+    id: 'track_1',
+    name: 'Video 1',
+    kind: 'video',
+    clips,
+    markers: [],
+    muted: false,
+    locked: false,
+    ...overrides,
+  }
+}
+
+function project(tracks: Track[]): Project {
+  return {
+    id: 'proj_1',
+    name: 'Test',
+    tracks,
+    settings: { width: 1920, height: 1080, frameRate: 30 },
+    duration: 10,
+    createdAt: 0,
+    modifiedAt: 0,
+  }
+}
+
+describe('getStreamCopyBlockers', () => {
+  it('allows a single untouched clip matching the source and export codecs', () => {
+    expect(getStreamCopyBlockers(project([track([clip()])]), settings, source)).toEqual([])
+  })
+
+  it('always renders image exports', () => {
+    const gif = { ...settings, format: 'gif' as const }
+
+    expect(getStreamCopyBlockers(project([track([clip()])]), gif, source)).toEqual([
+      'Image exports are always rendered',
+    ])
+  })
+
+  it('renders projects with several clips', () => {
+    const tracks = [track([clip(), clip({ id: 'clip_2', startTime: 10 })])]
+
+    expect(getStreamCopyBlockers(project(tracks), settings, source)).toEqual(['Project has more than one clip'])
+  })
+
+  it('renders clips that are moved, sped up, faded or have effects', () => {
+    const edited = clip(
+      { startTime: 2 },
+      { speed: 2, opacity: 0.5, effectChain: [{ id: 'fx_1', effectType: 'vignette', params: {} }] },
+    )
+
+    expect(getStreamCopyBlockers(project([track([edited])]), settings, source)).toEqual([
+      'Clip does not start at the beginning of the timeline',
+      'Clip speed is changed',
+      'Clip opacity is changed',
+      'Clip has effects',
+    ])
+  })
+
+  it('renders image clips', () => {
+    const image = clip({ type: 'image' })
+
+    expect(getStreamCopyBlockers(project([track([image])]), settings, source)).toContain('Clip is not a video clip')
+  })
+
+  it('renders when the source differs from the project or export settings', () => {
+    const other: StreamCopySource = { videoCodec: 'hevc', audioCodec: 'opus', width: 1280, height: 720, frameRate: 60 }
+
+    expect(getStreamCopyBlockers(project([track([clip()])]), settings, other)).toEqual([
+      'Project resolution 1920x1080 differs from the source (1280x720)',
+      'Project frame rate 30 differs from the source (60)',
+      'Source video is hevc, not h264',
+      'Source audio is opus, not aac',
+    ])
+  })
+
+  it('renders clips with adjusted or muted audio, unless the source has none', () => {
+    const quiet = project([track([clip({}, { volume: 0.5 })])])
+    const muted = project([track([clip()], { muted: true })])
+
+    expect(getStreamCopyBlockers(quiet, settings, source)).toEqual(['Clip audio is adjusted or muted'])
+    expect(getStreamCopyBlockers(muted, settings, source)).toEqual(['Clip audio is adjusted or muted'])
+    expect(getStreamCopyBlockers(quiet, settings, { ...source, audioCodec: null })).toEqual([])
+  })
+
+  it('renders when the source could not be probed', () => {
+    expect(getStreamCopyBlockers(project([track([clip()])]), settings, null)).toEqual([
+      'Source streams could not be probed',
+    ])
+  })
+})
+
+describe('buildChapters', () => {
+  it('orders clip and track markers on the timeline, each running to the next', () => {
+    const marked = clip({ startTime: 0, markers: [{ id: 'm1', time: 4, label: 'Middle', color: '#fff' }] })
+    const tracks = [track([marked], { markers: [{ id: 'm2', time: 0, label: 'Start', color: '#fff' }] })]
+
+    expect(buildChapters(project(tracks), 10)).toEqual([
+      { start: 0, end: 4, title: 'Start' },
+      { start: 4, end: 10, title: 'Middle' },
+    ])
+  })
+})
+
+describe('buildClipVideoFilter', () => {
+  const frame = { width: 1920, height: 1080, frameRate: 30 }
+
+  it('speeds up the trimmed part of the clip', () => {
+    expect(buildClipVideoFilter(clip({}, { speed: 2 }), frame)).toMatch(
+      /^trim=start=10:end=20,setpts=\(PTS-STARTPTS\)\/2,/,
+    )
+  })
+
+  it('plays a clip without a usable speed at normal speed', () => {
+    expect(buildClipVideoFilter(clip({}, { speed: 0 }), frame)).toContain('setpts=(PTS-STARTPTS)/1,')
+    expect(buildClipVideoFilter(clip({}, { speed: undefined }), frame)).toContain('setpts=(PTS-STARTPTS)/1,')
+  })
+})
@@ -0,0 +1,147 @@
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+import type { Project } from '../../types/project'
+
+vi.mock('../../services/project-autosave', () => ({
+  scheduleAutosave: vi.fn(),
+  deleteAutosave: vi.fn(),
+}))
+
+vi.mock('../../services/project-exporter', () => ({
+  getProjectDuration: () => 0,
+}))
+
+import { ProjectManager } from '../../services/project-manager'
+
+const manager = ProjectManager.getInstance()
+let project: Project
+
+function addClip(trackIndex: number, name: string, startTime: number) {
+  return manager.insertSourceClip(
+    project.id,
+    project.tracks[trackIndex].id,
+    { type: 'video', name, sourcePath: `/media/${name}.mp4`, duration: 5 },
+    startTime,
+  ).clip
+}
+
+const clipNames = (trackIndex: number) => project.tracks[trackIndex].clips.map(clip => clip.name)
+
+beforeEach(() => {
+  project = manager.createProject('Test')
+})
+
+describe('move-clip', () => {
+  it('puts a clip moved to another track back at its original index on undo', () => {
+    addClip(0, 'a', 0)
+    const b = addClip(0, 'b', 5)
+    addClip(0, 'c', 10)
+
+    manager.executeCommand(project.id, {
+      type: 'move-clip',
+      clipId: b.id,
+      toTrackId: project.tracks[1].id,
+      startTime: 2,
+    })
+    expect(clipNames(0)).toEqual(['a', 'c'])
+    expect(clipNames(1)).toEqual(['b'])
+
+    manager.undo(project.id)
+    expect(clipNames(0)).toEqual(['a', 'b', 'c'])
+    expect(clipNames(1)).toEqual([])
+    expect(project.tracks[0].clips[1].startTime).toBe(5)
+
+    manager.redo(project.id)
+    expect(clipNames(0)).toEqual(['a', 'c'])
+    expect(clipNames(1)).toEqual(['b'])
+  })
+
+  it('keeps the clip order when moving within a track', () => {
+    const a = addClip(0, 'a', 0)
+    addClip(0, 'b', 5)
+
+    manager.executeCommand(project.id, {
+      type: 'move-clip',
+      clipId: a.id,
+      toTrackId: project.tracks[0].id,
+      startTime: 1,
+    })
+    manager.undo(project.id)
+
+    expect(clipNames(0)).toEqual(['a', 'b'])
+    expect(project.tracks[0].clips[0].startTime).toBe(0)
+  })
+})
+
+describe('markers', () => {
+  it('undoes and redoes adding, updating and removing a marker', () => {
+    const clip = addClip(0, 'a', 0)
+
+    const marker = { ...manager.addMarker(project.id, clip.id, 2, 'Intro', '#ff0000').markers[0] }
+    manager.updateMarker(project.id, clip.id, marker.id, 'Opening')
+    manager.removeMarker(project.id, clip.id, marker.id)
+    expect(manager.getClip(project.id, clip.id).markers).toEqual([])
+
+    manager.undo(project.id)
+    expect(manager.getClip(project.id, clip.id).markers).toEqual([{ ...marker, label: 'Opening' }])
+    manager.undo(project.id)
+    expect(manager.getClip(project.id, clip.id).markers).toEqual([marker])
+    manager.undo(project.id)
+    expect(manager.getClip(project.id, clip.id).markers).toEqual([])
+
+    manager.redo(project.id)
+    expect(manager.getClip(project.id, clip.id).markers).toEqual([marker])
+  })
+
+  it('clamps marker times to the clip length', () => {
+    const clip = addClip(0, 'a', 0)
+
+    expect(manager.addMarker(project.id, clip.id, 60, '').markers[0]).toMatchObject({ time: 5, label: 'Marker 1' })
+  })
+
+  it('undoes and redoes adding, updating and removing a track marker', () => {
+    const trackId = project.tracks[0].id
+
+    const marker = { ...manager.addTrackMarker(project.id, trackId, 12, 'Act 2').markers[0] }
+    manager.updateTrackMarker(project.id, trackId, marker.id, undefined, '#00ff00')
+    manager.removeTrackMarker(project.id, trackId, marker.id)
+    expect(manager.getTrack(project.id, trackId).markers).toEqual([])
+
+    manager.undo(project.id)
+    expect(manager.getTrack(project.id, trackId).markers).toEqual([{ ...marker, color: '#00ff00' }])
+    manager.undo(project.id)
+    manager.undo(project.id)
+    expect(manager.getTrack(project.id, trackId).markers).toEqual([])
+  })
+
+  it('lists clip and track markers by timeline position', () => {
+    const clip = addClip(0, 'a', 10)
+    const trackId = project.tracks[0].id
+    manager.addMarker(project.id, clip.id, 1, 'Clip')
+    manager.addTrackMarker(project.id, trackId, 5, 'Track')
+
+    expect(manager.getMarkers(project.id)).toMatchObject([
+      { label: 'Track', time: 5, trackId },
+      { label: 'Clip', time: 1, trackId, clipId: clip.id },
+    ])
+  })
+})
+
+describe('undo and redo stacks', () => {
+  it('caps the redo stack like the undo stack', () => {
+    for (let i = 0; i < 60; i++) {
+      addClip(0, `clip${i}`, i * 5)
+    }
+    expect(manager.getUndoStackSize(project.id)).toBe(50)
+
+    for (let i = 0; i < 50; i++) {
+      manager.undo(project.id)
+    }
+    expect(manager.getUndoRedoState(project.id)).toMatchObject({ undoStackSize: 0, redoStackSize: 50 })
+
+    for (let i = 0; i < 50; i++) {
+      manager.redo(project.id)
+    }
+    expect(manager.getUndoRedoState(project.id)).toMatchObject({ undoStackSize: 50, redoStackSize: 0 })
+  })
+})
@@ -0,0 +1,86 @@
+import { describe, expect, it } from 'vitest'
+
+import {
+  getAvailableHeights,
+  getExpectedResolution,
+  getRequestedHeight,
+  matchQuality,
+} from '../../services/downloader/quality-fallback'
+import type { VideoFormatInfo } from '../../types/download'
+
+function format(height: number | undefined, overrides: Partial<VideoFormatInfo> = {}): VideoFormatInfo {
+  return {
+    itag: height ?? 0,
+    quality: height ? `${height}p` : 'audio',
+    format: 'mp4',
+    container: 'mp4',
+    width: height ? Math.round((height * 16) / 9) : undefined,
+    height,
+    hasAudio: false,
+    hasVideo: height !== undefined,
+    protocol: 'https',
+    ...overrides,
+  }
+}
+
+const formats = [format(360), format(720), format(1080), format(720), format(undefined, { hasAudio: true })]
+
+describe('getRequestedHeight', () => {
+  it('reads the height limit of quality options', () => {
+    expect(getRequestedHeight('1080p')).toBe(1080)
+    expect(getRequestedHeight('hd_720P')).toBe(720)
+    expect(getRequestedHeight('4k')).toBe(2160)
+  })
+
+  it('has no limit for best and unknown qualities', () => {
+    expect(getRequestedHeight(undefined)).toBeNull()
+    expect(getRequestedHeight('best')).toBeNull()
+    expect(getRequestedHeight('720')).toBeNull()
+  })
+})
+
+describe('getAvailableHeights', () => {
+  it('lists distinct direct video heights, highest first', () => {
+    const withHls = [...formats, format(1440, { protocol: 'm3u8_native' }), format(2160, { protocol: 'm3u8' })]
+
+    expect(getAvailableHeights(withHls)).toEqual([1080, 720, 360])
+  })
+})
+
+describe('matchQuality', () => {
+  it('matches a height the video has', () => {
+    expect(matchQuality(formats, '720p')).toEqual({ kind: 'exact', height: 720 })
+  })
+
+  it('picks the highest height below the request', () => {
+    expect(matchQuality(formats, '4K')).toEqual({ kind: 'lower', height: 1080 })
+    expect(matchQuality(formats, '480p')).toEqual({ kind: 'lower', height: 360 })
+  })
+
+  it('reports the available heights when every format is above the request', () => {
+    expect(matchQuality(formats, '240p')).toEqual({ kind: 'none', available: [1080, 720, 360] })
+  })
+
+  it('accepts anything without a limit or formats to check', () => {
+    expect(matchQuality(formats, 'best')).toEqual({ kind: 'any' })
+    expect(matchQuality([], '1080p')).toEqual({ kind: 'any' })
+    expect(matchQuality([format(1080, { protocol: 'm3u8' })], '720p')).toEqual({ kind: 'any' })
+  })
+})
+
+describe('getExpectedResolution', () => {
+  it('gives the resolution the quality would pick', () => {
+    expect(getExpectedResolution(formats, '720p')).toBe('1280x720')
+    expect(getExpectedResolution(formats, '4K')).toBe('1920x1080')
+    expect(getExpectedResolution(formats, 'best')).toBe('1920x1080')
+  })
+
+  it('uses the closest quality when every format is above the request', () => {
+    expect(getExpectedResolution(formats, '144p')).toBe('640x360')
+  })
+
+  it('is undefined when the formats have no size', () => {
+    expect(getExpectedResolution([], '720p')).toBeUndefined()
+    expect(getExpectedResolution([format(720, { width: undefined })], '720p')).toBeUndefined()
+  })
+})
@@ -0,0 +1,56 @@
+import { describe, expect, it } from 'vitest'
+
+import { getUrlHost, selectStartableJobs } from '../../services/downloader/queue-scheduler'
+
+const job = (url: string) => ({ url })
+const youtube = (id: string) => job(`https://www.youtube.com/watch?v=${id}`)
+const vimeo = (id: string) => job(`https://vimeo.com/${id}`)
+
+describe('getUrlHost', () => {
+  it('normalizes hosts of the same site', () => {
+    expect(getUrlHost('https://www.youtube.com/watch?v=abc')).toBe('youtube.com')
+    expect(getUrlHost('https://m.youtube.com/watch?v=abc')).toBe('youtube.com')
+    expect(getUrlHost('https://music.youtube.com/watch?v=abc')).toBe('youtube.com')
+    expect(getUrlHost('https://youtu.be/abc')).toBe('youtube.com')
+    expect(getUrlHost('https://WWW.Vimeo.com/123')).toBe('vimeo.com')
+  })
+
+  it('returns an empty host for unparseable URLs', () => {
+    expect(getUrlHost('not a url')).toBe('')
+  })
+})
+
+describe('selectStartableJobs', () => {
+  it('fills free slots in queue order without a per-host limit', () => {
+    const pending = [youtube('a'), youtube('b'), youtube('c'), vimeo('1')]
+
+    expect(selectStartableJobs([], pending, { maxConcurrent: 3 })).toEqual([0, 1, 2])
+    expect(selectStartableJobs([youtube('x')], pending, { maxConcurrent: 3, maxPerHost: 0 })).toEqual([0, 1])
+  })
+
+  it('skips jobs of a host at its quota so other hosts can start', () => {
+    const pending = [youtube('a'), youtube('b'), youtube('c'), youtube('d'), youtube('e'), vimeo('1')]
+
+    expect(selectStartableJobs([], pending, { maxConcurrent: 3, maxPerHost: 2 })).toEqual([0, 1, 5])
+  })
+
+  it('counts active downloads against the host quota', () => {
+    const active = [youtube('x'), youtube('y')]
+    const pending = [job('https://youtu.be/a'), vimeo('1'), vimeo('2')]
+
+    expect(selectStartableJobs(active, pending, { maxConcurrent: 4, maxPerHost: 2 })).toEqual([1, 2])
+  })
+
+  it('starts nothing when every pending job is from a capped host, and leaves them queued', () => {
+    const active = [youtube('x'), youtube('y')]
+    const pending = [youtube('a'), job('https://m.youtube.com/watch?v=b')]
+
+    expect(selectStartableJobs(active, pending, { maxConcurrent: 3, maxPerHost: 2 })).toEqual([])
+    // Once a slot for the host frees up, the head of the queue goes first
+    expect(selectStartableJobs([youtube('x')], pending, { maxConcurrent: 3, maxPerHost: 2 })).toEqual([0])
+  })
+
+  it('starts nothing when all slots are taken', () => {
+    expect(selectStartableJobs([youtube('x'), vimeo('1')], [vimeo('2')], { maxConcurrent: 2 })).toEqual([])
+  })
+})
@@ -0,0 +1,24 @@
+/**
+ * Unit Test Setup
+ * Main process modules run under plain Node here: electron is stubbed with a throwaway
+ * userData folder and the logger is silenced so tests don't write log files.
+ */
+
+import { vi } from 'vitest'
+
+vi.mock('electron', async () => {
+  const { mkdtempSync } = await import('fs')
+  const { tmpdir } = await import('os')
+  const { join } = await import('path')
+  const userData = mkdtempSync(join(tmpdir(), 'clipy-test-'))
+  return {
+    app: { getPath: () => userData, getVersion: () => '1.0.0', isPackaged: false },
+    BrowserWindow: { getAllWindows: () => [] },
+    ipcMain: { handle: vi.fn(), on: vi.fn() },
+  }
+})
+
+vi.mock('../../utils/logger', () => {
+  const logger = { debug: vi.fn(), info: vi.fn(), warn: vi.fn(), error: vi.fn() }
+  return { Logger: { getInstance: () => logger } }
+})
@@ -0,0 +1,134 @@
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+import { DownloadErrorCode } from '../../types/download'
+import type { SitePolicyConfig } from '../../types/system'
+
+const settings = vi.hoisted(() => ({ sitePolicy: { mode: 'allow-all', patterns: [] as string[] } }))
+
+vi.mock('../../utils/config', () => ({
+  ConfigManager: {
+    getInstance: () => ({ get: (key: keyof typeof settings) => settings[key] }),
+  },
+}))
+
+import {
+  assertSiteAllowed,
+  isUrlAllowedByPolicy,
+  matchesSitePattern,
+  normalizeHost,
+  normalizeSitePattern,
+} from '../../utils/site-policy'
+
+const allowlist = (...patterns: string[]): SitePolicyConfig => ({ mode: 'allowlist', patterns })
+const denylist = (...patterns: string[]): SitePolicyConfig => ({ mode: 'denylist', patterns })
+
+beforeEach(() => {
+  settings.sitePolicy = { mode: 'allow-all', patterns: [] }
+})
+
+describe('normalizeHost', () => {
+  it('lowercases, trims and drops a trailing dot', () => {
+    expect(normalizeHost(' WWW.YouTube.com. ')).toBe('www.youtube.com')
+  })
+
+  it('converts IDN hosts to punycode', () => {
+    expect(normalizeHost('bücher.example')).toBe('xn--bcher-kva.example')
+    expect(normalizeHost('xn--bcher-kva.example')).toBe('xn--bcher-kva.example')
+  })
+
+  it('returns an empty string for invalid hosts', () => {
+    expect(normalizeHost('exa mple.com')).toBe('')
+  })
+})
+
+describe('normalizeSitePattern', () => {
+  it('accepts bare hosts and wildcards', () => {
+    expect(normalizeSitePattern('YouTube.com')).toBe('youtube.com')
+    expect(normalizeSitePattern(' *.Vimeo.com ')).toBe('*.vimeo.com')
+  })
+
+  it('keeps only the host of a pasted URL', () => {
+    expect(normalizeSitePattern('https://www.youtube.com/watch?v=abc')).toBe('www.youtube.com')
+    expect(normalizeSitePattern('http://example.com:8080/path')).toBe('example.com')
+    expect(normalizeSitePattern('*.example.com/videos')).toBe('*.example.com')
+  })
+
+  it('converts IDN patterns to punycode', () => {
+    expect(normalizeSitePattern('*.Bücher.example')).toBe('*.xn--bcher-kva.example')
+  })
+
+  it('rejects patterns without a usable host', () => {
+    expect(normalizeSitePattern('')).toBeNull()
+    expect(normalizeSitePattern('   ')).toBeNull()
+    expect(normalizeSitePattern('*.')).toBeNull()
+    expect(normalizeSitePattern('exa mple.com')).toBeNull()
+  })
+})
+
+describe('matchesSitePattern', () => {
+  it('matches exact hosts only without a wildcard', () => {
+    expect(matchesSitePattern('youtube.com', 'youtube.com')).toBe(true)
+    expect(matchesSitePattern('www.youtube.com', 'youtube.com')).toBe(false)
+  })
+
+  it('matches the domain and its subdomains with a wildcard', () => {
+    expect(matchesSitePattern('youtube.com', '*.youtube.com')).toBe(true)
+    expect(matchesSitePattern('m.www.youtube.com', '*.youtube.com')).toBe(true)
+    expect(matchesSitePattern('notyoutube.com', '*.youtube.com')).toBe(false)
+    expect(matchesSitePattern('youtube.com.evil.example', '*.youtube.com')).toBe(false)
+  })
+})
+
+describe('isUrlAllowedByPolicy', () => {
+  it('allows everything in allow-all mode', () => {
+    expect(isUrlAllowedByPolicy('not a url', { mode: 'allow-all', patterns: [] })).toBe(true)
+  })
+
+  it('allows only matching hosts with an allowlist', () => {
+    const policy = allowlist('*.youtube.com', 'youtu.be')
+
+    expect(isUrlAllowedByPolicy('https://www.youtube.com/watch?v=abc', policy)).toBe(true)
+    expect(isUrlAllowedByPolicy('https://YOUTU.BE/abc', policy)).toBe(true)
+    expect(isUrlAllowedByPolicy('https://vimeo.com/1', policy)).toBe(false)
+    expect(isUrlAllowedByPolicy('not a url', policy)).toBe(false)
+  })
+
+  it('blocks matching hosts with a denylist', () => {
+    const policy = denylist('example.com')
+
+    expect(isUrlAllowedByPolicy('https://example.com/a.mp4', policy)).toBe(false)
+    expect(isUrlAllowedByPolicy('https://cdn.example.com/a.mp4', policy)).toBe(true)
+    expect(isUrlAllowedByPolicy('not a url', policy)).toBe(true)
+  })
+
+  it('matches IDN hosts written in Unicode or punycode', () => {
+    expect(isUrlAllowedByPolicy('https://bücher.example/v', allowlist('xn--bcher-kva.example'))).toBe(true)
+    expect(isUrlAllowedByPolicy('https://xn--bcher-kva.example/v', allowlist('bücher.example'))).toBe(true)
+    expect(isUrlAllowedByPolicy('https://www.bücher.example/v', denylist('*.BÜCHER.example'))).toBe(false)
+  })
+
+  it('ignores unusable patterns', () => {
+    expect(isUrlAllowedByPolicy('https://example.com', allowlist('', '*.'))).toBe(false)
+    expect(isUrlAllowedByPolicy('https://example.com', denylist('', '*.'))).toBe(true)
+  })
+})
+
+describe('assertSiteAllowed', () => {
+  it('throws SITE_BLOCKED with the host for blocked sites', () => {
+    settings.sitePolicy = allowlist('*.youtube.com')
+
+    expect(() => assertSiteAllowed('https://www.youtube.com/watch?v=abc')).not.toThrow()
+
+    let thrown: unknown
+    try {
+      assertSiteAllowed('https://example.com/a.mp4')
+    } catch (error) {
+      thrown = error
+    }
+    expect(thrown).toMatchObject({
+      code: DownloadErrorCode.SITE_BLOCKED,
+      messageKey: 'errorSiteBlocked',
+      params: { host: 'example.com' },
+    })
+  })
+})
@@ -0,0 +1,70 @@
+import { existsSync, mkdirSync, mkdtempSync, utimesSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { describe, expect, it, vi } from 'vitest'
+
+const paths = vi.hoisted(() => ({ root: '' }))
+
+vi.mock('../../utils/config', () => ({
+  ConfigManager: {
+    getInstance: () => ({
+      get: (key: string) =>
+        key === 'storage'
+          ? { cachePath: join(paths.root, 'cache'), tempPath: join(paths.root, 'temp') }
+          : { downloadPath: join(paths.root, 'downloads') },
+    }),
+  },
+}))
+
+import { StorageManager } from '../../services/storage-manager'
+
+paths.root = mkdtempSync(join(tmpdir(), 'clipy-storage-'))
+const storage = StorageManager.getInstance()
+const DAY = 24 * 60 * 60 * 1000
+
+function writeFile(filePath: string, ageDays: number): string {
+  mkdirSync(join(filePath, '..'), { recursive: true })
+  writeFileSync(filePath, 'data')
+  const time = (Date.now() - ageDays * DAY) / 1000
+  utimesSync(filePath, time, time)
+  return filePath
+}
+
+describe('cleanCacheFiles', () => {
+  it("cleans old files in the cache and yt-dlp's cache, leaving other folders alone", async () => {
+    const cacheDir = storage.getCacheDirectory()
+    const oldFile = writeFile(join(cacheDir, 'old.json'), 10)
+    const newFile = writeFile(join(cacheDir, 'new.json'), 1)
+    const oldSigfunc = writeFile(join(cacheDir, 'yt-dlp', 'youtube-sigfuncs', 'js_abc.json'), 10)
+    const oldTranscode = writeFile(join(cacheDir, 'transcodes', 'clip.mp4'), 10)
+
+    expect(await storage.cleanCacheFiles(7 * DAY)).toBe(2)
+
+    expect(existsSync(oldFile)).toBe(false)
+    expect(existsSync(newFile)).toBe(true)
+    expect(existsSync(oldSigfunc)).toBe(false)
+    expect(existsSync(join(cacheDir, 'yt-dlp', 'youtube-sigfuncs'))).toBe(true)
+    expect(existsSync(oldTranscode)).toBe(true)
+  })
+})
+
+describe('cleanTempFiles', () => {
+  it('cleans old files without entering folders', async () => {
+    const tempDir = storage.getTempDirectory()
+    const oldFile = writeFile(join(tempDir, 'old.part'), 2)
+    const nested = writeFile(join(mkdtempSync(join(tempDir, 'job-')), 'old.part'), 2)
+
+    expect(await storage.cleanTempFiles(DAY)).toBe(1)
+
+    expect(existsSync(oldFile)).toBe(false)
+    expect(existsSync(nested)).toBe(true)
+  })
+})
+
+describe('directories', () => {
+  it('uses the configured cache and temp folders', () => {
+    expect(storage.getCacheDirectory()).toBe(join(paths.root, 'cache'))
+    expect(storage.getTempDirectory()).toBe(join(paths.root, 'temp'))
+    expect(existsSync(storage.getTempDirectory())).toBe(true)
+  })
+})
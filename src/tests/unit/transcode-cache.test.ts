@@ -0,0 +1,132 @@
+import { existsSync, mkdirSync, mkdtempSync, readFileSync, renameSync, rmSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
+
+const { paths, videoProcessor } = vi.hoisted(() => ({
+  paths: { root: '' },
+  videoProcessor: {
+    getVideoMetadata: vi.fn(async () => ({ duration: 10 })),
+    transcodeForEditing: vi.fn(),
+  },
+}))
+
+vi.mock('../../services/storage-manager', () => ({
+  StorageManager: { getInstance: () => ({ getCacheDirectory: () => join(paths.root, 'cache') }) },
+}))
+// Sources are told apart by their content, as the media cache's fingerprints do
+vi.mock('../../services/media-cache', () => ({
+  getMediaFingerprint: (filePath: string) => `fp-${readFileSync(filePath, 'utf-8')}`,
+}))
+vi.mock('../../services/analysis-pool', () => ({
+  runAnalysisJob: (_type: string, run: (signal: AbortSignal) => Promise<unknown>) => run(new AbortController().signal),
+}))
+vi.mock('../../services/video-processor', () => ({ VideoProcessor: { getInstance: () => videoProcessor } }))
+
+import type { EditingTranscodeProgress } from '../../types/project'
+
+let cache: typeof import('../../services/transcode-cache')
+
+function writeSource(name: string, content: string): string {
+  const filePath = join(paths.root, name)
+  writeFileSync(filePath, content)
+  return filePath
+}
+
+beforeEach(async () => {
+  paths.root = mkdtempSync(join(tmpdir(), 'clipy-transcodes-'))
+  videoProcessor.transcodeForEditing.mockReset()
+  videoProcessor.transcodeForEditing.mockImplementation(
+    async (_input: string, output: string, _profile: string, options: { onProgress: (seconds: number) => void }) => {
+      options.onProgress(5)
+      mkdirSync(join(output, '..'), { recursive: true })
+      writeFileSync(output, Buffer.alloc(100))
+    },
+  )
+  // The index is loaded once per module, so every test starts from a fresh one
+  vi.resetModules()
+  cache = await import('../../services/transcode-cache')
+})
+
+afterEach(() => {
+  vi.useRealTimers()
+})
+
+describe('getOrTranscodeForEditing', () => {
+  it('transcodes on a miss and reuses the copy on a hit', async () => {
+    const source = writeSource('clip.mp4', 'a')
+    const events: EditingTranscodeProgress[] = []
+    cache.transcodeEvents.on('progress', (event: EditingTranscodeProgress) => events.push(event))
+
+    const first = await cache.getOrTranscodeForEditing(source, 'edit')
+    const second = await cache.getOrTranscodeForEditing(source, 'edit')
+
+    expect(first).toEqual({ outputPath: join(cache.getTranscodeCacheDir(), 'fp-a-edit.mp4'), cached: false })
+    expect(second).toEqual({ outputPath: first.outputPath, cached: true })
+    expect(videoProcessor.transcodeForEditing).toHaveBeenCalledTimes(1)
+    expect(events.map(event => event.progress)).toEqual([0, 50, 100])
+    expect(JSON.parse(readFileSync(join(cache.getTranscodeCacheDir(), 'index.json'), 'utf-8')).entries).toHaveLength(1)
+  })
+
+  it('hits for a renamed source and misses for another profile', async () => {
+    const source = writeSource('clip.mp4', 'a')
+    await cache.getOrTranscodeForEditing(source, 'edit')
+    const renamed = join(paths.root, 'renamed.mp4')
+    renameSync(source, renamed)
+
+    expect(await cache.getOrTranscodeForEditing(renamed, 'edit')).toMatchObject({ cached: true })
+    expect(await cache.getOrTranscodeForEditing(renamed, 'proxy')).toMatchObject({ cached: false })
+    expect(videoProcessor.transcodeForEditing).toHaveBeenCalledTimes(2)
+  })
+
+  it('transcodes again when the cached file is gone', async () => {
+    const source = writeSource('clip.mp4', 'a')
+    const { outputPath } = await cache.getOrTranscodeForEditing(source, 'edit')
+    rmSync(outputPath)
+
+    expect(await cache.getOrTranscodeForEditing(source, 'edit')).toEqual({ outputPath, cached: false })
+    expect(videoProcessor.transcodeForEditing).toHaveBeenCalledTimes(2)
+  })
+
+  it('shares a transcode that is still running', async () => {
+    const source = writeSource('clip.mp4', 'a')
+
+    const [first, second] = await Promise.all([
+      cache.getOrTranscodeForEditing(source, 'edit'),
+      cache.getOrTranscodeForEditing(source, 'edit'),
+    ])
+
+    expect(second).toEqual(first)
+    expect(videoProcessor.transcodeForEditing).toHaveBeenCalledTimes(1)
+  })
+})
+
+describe('pruneTranscodeCache', () => {
+  it('forgets missing files and evicts the least recently used until the rest fit', async () => {
+    vi.useFakeTimers({ toFake: ['Date'] })
+    const sources = ['a', 'b', 'c'].map(content => writeSource(`${content}.mp4`, content))
+    const outputs: string[] = []
+    for (const [index, source] of sources.entries()) {
+      vi.setSystemTime(1000 * (index + 1))
+      outputs.push((await cache.getOrTranscodeForEditing(source, 'edit')).outputPath)
+    }
+    vi.setSystemTime(4000)
+    await cache.getOrTranscodeForEditing(sources[0], 'edit') // a is now the most recently used
+    rmSync(outputs[2])
+
+    expect(cache.pruneTranscodeCache(100)).toBe(2)
+
+    expect(existsSync(outputs[0])).toBe(true)
+    expect(existsSync(outputs[1])).toBe(false)
+    expect(cache.isCachedTranscode(outputs[0])).toBe(true)
+    expect(cache.isCachedTranscode(outputs[1])).toBe(false)
+    expect(cache.isCachedTranscode(outputs[2])).toBe(false)
+  })
+
+  it('removes nothing when everything fits', async () => {
+    const { outputPath } = await cache.getOrTranscodeForEditing(writeSource('clip.mp4', 'a'), 'edit')
+
+    expect(cache.pruneTranscodeCache(100)).toBe(0)
+    expect(existsSync(outputPath)).toBe(true)
+  })
+})
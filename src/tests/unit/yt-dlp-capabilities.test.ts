@@ -0,0 +1,174 @@
+import { mkdtempSync, utimesSync, writeFileSync } from 'fs'
+import { tmpdir } from 'os'
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+import { DownloadErrorCode } from '../../types/download'
+
+const probe = vi.hoisted(() => ({ version: '', help: '', fail: false }))
+const execFile = vi.hoisted(() =>
+  vi.fn((_path: string, args: string[], _options: unknown, callback: (...result: unknown[]) => void) => {
+    if (probe.fail) {
+      callback(new Error('spawn ENOENT'), '')
+    } else {
+      callback(null, args.includes('--version') ? probe.version : probe.help)
+    }
+  }),
+)
+
+vi.mock('child_process', () => ({ execFile }))
+vi.mock('../../services/downloader/yt-dlp-environment', () => ({
+  getYtdlpBaseArgs: () => [],
+  getYtdlpEnv: () => ({}),
+}))
+
+import {
+  assertYtdlpSupported,
+  compareYtdlpVersions,
+  getYtdlpCapabilities,
+  parseHelpFlags,
+  parseYtdlpVersion,
+  resetYtdlpCapabilities,
+} from '../../services/downloader/yt-dlp-capabilities'
+
+const YTDLP_HELP = `Usage: yt-dlp [OPTIONS] URL [URL...]
+
+Options:
+  General Options:
+    -h, --help                      Print this help text and exit
+    --version                       Print program version and exit
+    --compat-options OPTS           Options that can help keep compatibility
+                                    with youtube-dl, e.g. --no-playlist here
+  Download Options:
+    --download-sections REGEX       Download only chapters that match the
+                                    regular expression
+    -f, --format FORMAT             Video format code, see "FORMAT SELECTION"
+    --print-to-file [WHEN:]TEMPLATE FILE
+                                    Append given template to the file
+`
+
+const YOUTUBE_DL_HELP = `Usage: youtube-dl [OPTIONS] URL [URL...]
+
+Options:
+  General Options:
+    -h, --help                       Print this help text and exit
+    --version                        Print program version and exit
+    -f, --format FORMAT              Video format code
+`
+
+function thrownBy(fn: () => void): unknown {
+  try {
+    fn()
+  } catch (error) {
+    return error
+  }
+  return undefined
+}
+
+beforeEach(() => {
+  vi.clearAllMocks()
+  resetYtdlpCapabilities()
+  probe.version = '2024.08.06\n'
+  probe.help = YTDLP_HELP
+  probe.fail = false
+})
+
+describe('parseYtdlpVersion', () => {
+  it('reads release, nightly and patched versions', () => {
+    expect(parseYtdlpVersion('2024.08.06\n')).toBe('2024.08.06')
+    expect(parseYtdlpVersion('2024.08.06.232908 (nightly)')).toBe('2024.08.06.232908')
+    expect(parseYtdlpVersion('yt-dlp 2021.12.1')).toBe('2021.12.1')
+    expect(parseYtdlpVersion('unknown')).toBeNull()
+  })
+})
+
+describe('parseHelpFlags', () => {
+  it('collects long options and their aliases, ignoring descriptions', () => {
+    const flags = parseHelpFlags(YTDLP_HELP)
+
+    expect(Array.from(flags).sort()).toEqual([
+      '--compat-options',
+      '--download-sections',
+      '--format',
+      '--help',
+      '--print-to-file',
+      '--version',
+    ])
+    expect(flags.has('--no-playlist')).toBe(false)
+  })
+
+  it('handles Windows line endings', () => {
+    expect(parseHelpFlags(YTDLP_HELP.replace(/\n/g, '\r\n')).has('--download-sections')).toBe(true)
+  })
+})
+
+describe('compareYtdlpVersions', () => {
+  it('compares date parts numerically', () => {
+    expect(compareYtdlpVersions('2021.12.1', '2021.12.01')).toBe(0)
+    expect(compareYtdlpVersions('2021.9.30', '2021.12.01')).toBeLessThan(0)
+    expect(compareYtdlpVersions('2024.08.06.1', '2024.08.06')).toBeGreaterThan(0)
+  })
+})
+
+describe('getYtdlpCapabilities', () => {
+  it('probes the version and options of yt-dlp', async () => {
+    const capabilities = await getYtdlpCapabilities('/bin/yt-dlp-missing')
+
+    expect(capabilities).toMatchObject({ version: '2024.08.06', isYoutubeDl: false })
+    expect(capabilities.flags.has('--download-sections')).toBe(true)
+    expect(() => assertYtdlpSupported(capabilities)).not.toThrow()
+  })
+
+  it('probes once per binary version', async () => {
+    const ytdlpPath = join(mkdtempSync(join(tmpdir(), 'clipy-ytdlp-')), 'yt-dlp')
+    writeFileSync(ytdlpPath, '')
+    utimesSync(ytdlpPath, 1_700_000_000, 1_700_000_000)
+
+    await getYtdlpCapabilities(ytdlpPath)
+    await getYtdlpCapabilities(ytdlpPath)
+    expect(execFile).toHaveBeenCalledTimes(2) // --version and --help
+
+    // yt-dlp -U replaces the binary
+    utimesSync(ytdlpPath, 1_700_000_100, 1_700_000_100)
+    probe.version = '2024.10.22'
+    expect((await getYtdlpCapabilities(ytdlpPath)).version).toBe('2024.10.22')
+    expect(execFile).toHaveBeenCalledTimes(4)
+  })
+
+  it('does not cache a failed probe', async () => {
+    probe.fail = true
+    await expect(getYtdlpCapabilities('/bin/yt-dlp-missing')).rejects.toMatchObject({
+      code: DownloadErrorCode.UNKNOWN_ERROR,
+      message: expect.stringContaining('spawn ENOENT'),
+    })
+
+    probe.fail = false
+    expect((await getYtdlpCapabilities('/bin/yt-dlp-missing')).version).toBe('2024.08.06')
+  })
+})
+
+describe('assertYtdlpSupported', () => {
+  it('rejects youtube-dl installed under the yt-dlp name', async () => {
+    probe.version = '2021.12.17'
+    probe.help = YOUTUBE_DL_HELP
+
+    const capabilities = await getYtdlpCapabilities('/bin/yt-dlp-missing')
+
+    expect(capabilities.isYoutubeDl).toBe(true)
+    expect(thrownBy(() => assertYtdlpSupported(capabilities))).toMatchObject({
+      code: DownloadErrorCode.YTDLP_TOO_OLD,
+      messageKey: 'errorYtdlpIsYoutubeDl',
+    })
+  })
+
+  it('rejects yt-dlp older than the minimum version', async () => {
+    probe.version = '2021.10.22'
+
+    const capabilities = await getYtdlpCapabilities('/bin/yt-dlp-missing')
+
+    expect(thrownBy(() => assertYtdlpSupported(capabilities))).toMatchObject({
+      code: DownloadErrorCode.YTDLP_TOO_OLD,
+      message: expect.stringContaining('yt-dlp 2021.10.22 is too old (2021.12.01 or newer is required)'),
+    })
+  })
+})
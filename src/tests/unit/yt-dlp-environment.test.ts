@@ -0,0 +1,167 @@
+import { join } from 'path'
+import { beforeEach, describe, expect, it, vi } from 'vitest'
+
+const { advanced, download } = vi.hoisted(() => ({
+  advanced: { proxyUrl: '', respectUserYtdlpConfig: false },
+  download: { cookiesFromBrowser: '' },
+}))
+
+vi.mock('../../utils/config', () => ({
+  ConfigManager: { getInstance: () => ({ get: (key: string) => (key === 'download' ? download : advanced) }) },
+}))
+vi.mock('../../services/storage-manager', () => ({
+  YTDLP_CACHE_FOLDER: 'yt-dlp',
+  StorageManager: { getInstance: () => ({ getCacheDirectory: () => '/data/cache' }) },
+}))
+
+import {
+  buildCookieArgs,
+  getDownloadCookieChoice,
+  getYtdlpBaseArgs,
+  getYtdlpEnv,
+  getYtdlpEnvironmentInfo,
+  redactDownloadOptions,
+  redactYtdlpArgs,
+} from '../../services/downloader/yt-dlp-environment'
+
+const cacheDir = join('/data/cache', 'yt-dlp')
+
+beforeEach(() => {
+  advanced.proxyUrl = ''
+  advanced.respectUserYtdlpConfig = false
+  download.cookiesFromBrowser = ''
+})
+
+describe('getYtdlpBaseArgs', () => {
+  it('ignores the user config and keeps the cache in the app cache folder', () => {
+    expect(getYtdlpBaseArgs()).toEqual(['--ignore-config', '--cache-dir', cacheDir])
+  })
+
+  it('reads the user config when the setting allows it', () => {
+    advanced.respectUserYtdlpConfig = true
+
+    expect(getYtdlpBaseArgs()).toEqual(['--cache-dir', cacheDir])
+    expect(getYtdlpBaseArgs(false)).toEqual(['--ignore-config', '--cache-dir', cacheDir])
+  })
+
+  it('passes the configured proxy', () => {
+    advanced.proxyUrl = '  socks5://127.0.0.1:1080 '
+
+    expect(getYtdlpBaseArgs()).toEqual([
+      '--ignore-config',
+      '--cache-dir',
+      cacheDir,
+      '--proxy',
+      'socks5://127.0.0.1:1080',
+    ])
+  })
+})
+
+describe('getYtdlpEnv', () => {
+  it('removes proxy variables in any case and forces UTF-8 output', () => {
+    const env = getYtdlpEnv({
+      PATH: '/usr/bin',
+      HTTP_PROXY: 'http://proxy:8080',
+      https_proxy: 'http://proxy:8080',
+      All_Proxy: 'socks5://proxy',
+      NO_PROXY: 'localhost',
+      PROXY_HOST: 'kept',
+    })
+
+    expect(env).toEqual({ PATH: '/usr/bin', PROXY_HOST: 'kept', PYTHONIOENCODING: 'utf-8' })
+  })
+})
+
+describe('redactYtdlpArgs', () => {
+  it('hides cookie sources given as a separate or inline value', () => {
+    const args = ['--cookies', '/home/me/cookies.txt', '--cookies-from-browser=firefox:profile', '-f', 'best', 'URL']
+
+    expect(redactYtdlpArgs(args)).toEqual([
+      '--cookies',
+      '<redacted>',
+      '--cookies-from-browser=<redacted>',
+      '-f',
+      'best',
+      'URL',
+    ])
+  })
+
+  it('leaves other arguments alone', () => {
+    expect(redactYtdlpArgs(['--proxy', 'http://proxy', '--cookies'])).toEqual(['--proxy', 'http://proxy', '--cookies'])
+  })
+})
+
+describe('buildCookieArgs', () => {
+  it('uses the configured browser, else the app cookie file, for auto', () => {
+    expect(buildCookieArgs(undefined, '/data/cookies.txt')).toEqual(['--cookies', '/data/cookies.txt'])
+    expect(buildCookieArgs({ mode: 'auto' })).toEqual([])
+
+    download.cookiesFromBrowser = 'firefox'
+    expect(buildCookieArgs({ mode: 'auto' }, '/data/cookies.txt')).toEqual(['--cookies-from-browser', 'firefox'])
+  })
+
+  it('lets any other choice override both', () => {
+    download.cookiesFromBrowser = 'firefox'
+
+    expect(buildCookieArgs({ mode: 'never' }, '/data/cookies.txt')).toEqual([])
+    expect(buildCookieArgs({ mode: 'browser', browser: 'chrome' })).toEqual(['--cookies-from-browser', 'chrome'])
+    expect(buildCookieArgs({ mode: 'file', path: '/home/me/cookies.txt' })).toEqual([
+      '--cookies',
+      '/home/me/cookies.txt',
+    ])
+  })
+})
+
+describe('getDownloadCookieChoice', () => {
+  it('prefers useCookies over the older cookiesFromBrowser option', () => {
+    expect(getDownloadCookieChoice({ useCookies: { mode: 'never' }, cookiesFromBrowser: 'edge' })).toEqual({
+      mode: 'never',
+    })
+    expect(getDownloadCookieChoice({ cookiesFromBrowser: 'edge' })).toEqual({ mode: 'browser', browser: 'edge' })
+    expect(getDownloadCookieChoice({})).toBeUndefined()
+  })
+})
+
+describe('redactDownloadOptions', () => {
+  it('keeps the cookie mode but not the browser or file', () => {
+    expect(redactDownloadOptions({ quality: '720p', cookiesFromBrowser: 'firefox' })).toEqual({
+      quality: '720p',
+      cookiesFromBrowser: '<redacted>',
+    })
+    expect(redactDownloadOptions({ useCookies: { mode: 'browser', browser: 'chrome' } })).toEqual({
+      useCookies: { mode: 'browser', browser: '<redacted>' },
+    })
+    expect(redactDownloadOptions({ useCookies: { mode: 'file', path: '/home/me/cookies.txt' } })).toEqual({
+      useCookies: { mode: 'file', path: '<redacted>' },
+    })
+    expect(redactDownloadOptions({ useCookies: { mode: 'never' } })).toEqual({ useCookies: { mode: 'never' } })
+  })
+
+  it('redacts the options of a resumed download without changing the original', () => {
+    const options = {
+      resumeFrom: {
+        options: { useCookies: { mode: 'file' as const, path: '/home/me/cookies.txt' } },
+        outputTemplate: '/downloads/Video.%(ext)s',
+        partialFiles: [],
+        downloadedBytes: 0,
+        pausedAt: 0,
+      },
+    }
+
+    expect(redactDownloadOptions(options)?.resumeFrom?.options.useCookies).toEqual({
+      mode: 'file',
+      path: '<redacted>',
+    })
+    expect(options.resumeFrom.options.useCookies.path).toBe('/home/me/cookies.txt')
+  })
+
+  it('passes missing options through', () => {
+    expect(redactDownloadOptions(undefined)).toBeUndefined()
+  })
+})
+
+describe('getYtdlpEnvironmentInfo', () => {
+  it('reports the cache folder and whether the user config is ignored', () => {
+    expect(getYtdlpEnvironmentInfo()).toMatchObject({ userConfigIgnored: true, cacheDir })
+  })
+})
@@ -8,8 +8,82 @@
  */
 
 import { ApiResponse } from './types/api'
-import { DownloadOptions, DownloadProgress, DownloadFilter, DownloadListData, VideoInfo } from './types/download'
-import { ThemeMode, AppConfig, SystemInfo, StorageUsage, StoragePaths } from './types/system'
+import type { Language } from './localization/languages'
+import {
+  AudioExtractFormat,
+  BatchEnqueueReport,
+  BulkJobProgress,
+  CookieChoice,
+  DownloadDeleteOptions,
+  DownloadOptions,
+  DownloadProfile,
+  DownloadProgress,
+  DownloadStateSnapshot,
+  DownloadStatus,
+  DownloadFilter,
+  DownloadListData,
+  FileRemovalResult,
+  LibraryChannelSummary,
+  LibraryFormatSummary,
+  LibraryConversionProgress,
+  LibraryImportResult,
+  OutputTemplatePreview,
+  PlaybackPosition,
+  PlaylistFile,
+  QueueCompletionAction,
+  RelatedVideo,
+  RemuxStreamSelection,
+  StoryboardDownload,
+  VideoComment,
+  VideoFormatInfo,
+  VideoInfo,
+} from './types/download'
+import {
+  ThemeMode,
+  AppConfig,
+  SystemInfo,
+  StorageUsage,
+  StoragePaths,
+  ThumbnailCacheStats,
+  BackupResult,
+  BinaryInstallResult,
+  BinaryType,
+  BinaryVersion,
+  BrowserInfo,
+  ConnectivityReport,
+  DiagnosticsReport,
+  HealthReport,
+  ProcessMemory,
+  ProcessPriority,
+  RestoreResult,
+  SettingsExport,
+  SettingsImportPreview,
+} from './types/system'
+import {
+  AudioMergeOptions,
+  AutosaveInfo,
+  Clip,
+  EditingTranscodeProfile,
+  EditingTranscodeResult,
+  Effect,
+  EffectDefinition,
+  ExportFormatInfo,
+  ExportJob,
+  ExportJobResult,
+  ExportReport,
+  MarkerWithClipId,
+  MediaUrlInfo,
+  Project,
+  ProjectCommand,
+  ProjectDiff,
+  ProjectExportResult,
+  ProjectExportSettings,
+  SceneAnalysisProgress,
+  SlideshowOptions,
+  TextProperties,
+  Track,
+  UndoRedoState,
+} from './types/project'
 
 // Vite injects these at build time
 declare const MAIN_WINDOW_VITE_DEV_SERVER_URL: string
@@ -47,12 +121,36 @@ interface ThumbnailOptions {
   count?: number
   interval?: number
   width?: number
+  projectId?: string // Cancelled with the project's other analysis jobs when it closes
+  priority?: 'high' | 'normal' | 'low' // 'high' for what is in view
 }
 
 /** Options for extracting audio waveform data */
 interface WaveformOptions {
   inputPath: string
   samples?: number
+  projectId?: string
+  priority?: 'high' | 'normal' | 'low'
+}
+
+/** Scene cut detected by FFmpeg's scene score */
+interface SceneChange {
+  time: number // seconds into the source
+  score: number // 0-1
+}
+
+/** One stream of a media file, as reported by ffprobe */
+interface StreamInfo {
+  index: number
+  streamType: string // 'video', 'audio', 'subtitle', 'attachment' or 'data'
+  codec: string
+  language?: string
+  title?: string
+  bitrate?: number
+  width?: number
+  height?: number
+  channels?: number
+  sampleRate?: number
 }
 
 // ============================================================================
@@ -86,17 +184,42 @@ interface ElectronAPI {
     openPath: (filePath: string) => Promise<ApiResponse<void>>
     showItemInFolder: (filePath: string) => Promise<ApiResponse<void>>
     openExternal: (url: string) => Promise<ApiResponse<void>>
+    openAppDataFolder: () => Promise<ApiResponse<void>>
+    openCacheFolder: () => Promise<ApiResponse<void>>
+    openLogsFolder: () => Promise<ApiResponse<void>>
   }
 
   // Download operations
   downloadManager: {
     start: (url: string, options?: DownloadOptions) => Promise<ApiResponse<{ downloadId: string; message: string }>>
+    /** Download with a saved profile's options; the download settings are not changed */
+    startWithProfile: (url: string, profileId: string) => Promise<ApiResponse<{ downloadId: string; message: string }>>
+    getProfiles: () => Promise<ApiResponse<DownloadProfile[]>>
+    /** Create a profile, or update it when profileId is given; names must be unique */
+    saveProfile: (name: string, options: DownloadOptions, profileId?: string) => Promise<ApiResponse<DownloadProfile>>
+    deleteProfile: (profileId: string) => Promise<ApiResponse<{ deleted: boolean }>>
     cancel: (downloadId: string) => Promise<ApiResponse<{ downloadId: string; message: string }>>
-    delete: (downloadId: string) => Promise<ApiResponse<{ downloadId: string; message: string }>>
+    /** Cancel every queued and running download; paused ones stay */
+    cancelAll: () => Promise<ApiResponse<{ downloadIds: string[] }>>
+    delete: (
+      downloadId: string,
+      options?: DownloadDeleteOptions,
+    ) => Promise<
+      ApiResponse<{
+        downloadId: string
+        message: string
+        files: FileRemovalResult[]
+        failedFiles?: FileRemovalResult[]
+      }>
+    >
+    bulkDelete: (downloadIds: string[], options?: DownloadDeleteOptions) => Promise<ApiResponse<{ jobId: string }>>
     retry: (downloadId: string) => Promise<ApiResponse<{ downloadId: string; message: string }>>
+    pause: (downloadId: string) => Promise<ApiResponse<{ downloadId: string }>>
+    resume: (downloadId: string) => Promise<ApiResponse<{ downloadId: string }>>
     getProgress: (downloadId?: string) => Promise<ApiResponse<DownloadProgress | DownloadProgress[]>>
     list: (filter?: DownloadFilter) => Promise<ApiResponse<DownloadListData>>
-    getInfo: (url: string) => Promise<ApiResponse<VideoInfo>>
+    /** Look up a video; useCookies overrides the cookie settings for this lookup */
+    getInfo: (url: string, useCookies?: CookieChoice) => Promise<ApiResponse<VideoInfo>>
     getStreamingInfo: (url: string) => Promise<
       ApiResponse<{
         videoInfo: VideoInfo
@@ -105,6 +228,42 @@ interface ElectronAPI {
         fallbackUrl: string | null
       }>
     >
+    downloadStoryboard: (videoId: string, url: string) => Promise<ApiResponse<StoryboardDownload>>
+    enqueueFromFile: (
+      filePath: string,
+      options?: DownloadOptions,
+      prefetchInfo?: boolean,
+    ) => Promise<ApiResponse<BatchEnqueueReport>>
+    enqueueUrls: (
+      urls: string[],
+      options?: DownloadOptions,
+      prefetchInfo?: boolean,
+    ) => Promise<ApiResponse<BatchEnqueueReport>>
+    getLog: (downloadId: string, tailLines?: number) => Promise<ApiResponse<string[]>>
+    openLog: (downloadId: string) => Promise<ApiResponse<void>>
+    checkPathWritable: (dirPath: string) => Promise<ApiResponse<{ writable: boolean; error?: string }>>
+    isSiteSupported: (url: string) => Promise<ApiResponse<boolean>>
+    tag: (downloadId: string, tags: string[]) => Promise<ApiResponse<{ downloadId: string; tags: string[] }>>
+    getFormatDetails: (videoInfo: VideoInfo, formatId: string) => Promise<ApiResponse<VideoFormatInfo | null>>
+    getCompletionAction: () => Promise<ApiResponse<QueueCompletionAction>>
+    setCompletionAction: (action: QueueCompletionAction) => Promise<ApiResponse<void>>
+    cancelCompletionAction: () => Promise<ApiResponse<{ cancelled: boolean }>>
+    getById: (downloadId: string) => Promise<ApiResponse<DownloadProgress | null>>
+    getStatus: (downloadId: string) => Promise<ApiResponse<DownloadStatus | null>>
+    /** Where a filename template would save this video with these options, with its errors and warnings */
+    previewFilename: (
+      template: string,
+      videoInfo: VideoInfo,
+      options?: DownloadOptions,
+    ) => Promise<ApiResponse<OutputTemplatePreview>>
+    syncState: () => Promise<ApiResponse<DownloadStateSnapshot>>
+    /** Estimated seconds until the active and queued downloads are done */
+    getQueueEta: () => Promise<ApiResponse<number>>
+    /** Average speed of the running downloads in bytes/s, 0 while none reports one */
+    getAverageSpeed: () => Promise<ApiResponse<number>>
+    getComments: (url: string, maxComments?: number) => Promise<ApiResponse<VideoComment[]>>
+    downloadThumbnail: (url: string, outputPath?: string) => Promise<ApiResponse<{ path: string }>>
+    getRelatedVideos: (url: string) => Promise<ApiResponse<RelatedVideo[]>>
   }
 
   // File operations
@@ -127,6 +286,11 @@ interface ElectronAPI {
     get: () => Promise<ApiResponse<AppConfig>>
     update: (updates: Partial<AppConfig>) => Promise<ApiResponse<AppConfig>>
     reset: () => Promise<ApiResponse<AppConfig>>
+    /** Write the settings to a JSON file; redacted (secrets blanked, home paths as ~) unless redactSecrets is false */
+    exportSettings: (outputPath: string, redactSecrets?: boolean) => Promise<ApiResponse<SettingsExport>>
+    /** The fields importing a settings file would change, to confirm before importSettings */
+    previewImport: (filePath: string) => Promise<ApiResponse<SettingsImportPreview>>
+    importSettings: (filePath: string) => Promise<ApiResponse<SettingsImportPreview & { config: AppConfig }>>
   }
 
   // System operations
@@ -155,6 +319,25 @@ interface ElectronAPI {
     }) => Promise<ApiResponse<Electron.SaveDialogReturnValue>>
     getStorageUsage: () => Promise<ApiResponse<StorageUsage>>
     getStoragePaths: () => Promise<ApiResponse<StoragePaths>>
+    createBackup: (outputPath: string, includeThumbnails?: boolean) => Promise<ApiResponse<BackupResult>>
+    restoreBackup: (zipPath: string, includeThumbnails?: boolean) => Promise<ApiResponse<RestoreResult>>
+    getAvailableBrowsers: () => Promise<ApiResponse<BrowserInfo[]>>
+    runHealthCheck: () => Promise<ApiResponse<HealthReport>>
+    /** Reachability and latency of google.com, github.com and youtube.com, through the configured proxy */
+    testConnectivity: () => Promise<ApiResponse<ConnectivityReport>>
+    prefetchThumbnails: () => Promise<ApiResponse<number>>
+    getSupportedSites: () => Promise<ApiResponse<string[]>>
+    installFfmpeg: () => Promise<ApiResponse<BinaryInstallResult>>
+    getAvailableLanguages: () => Promise<ApiResponse<Language[]>>
+    runDiagnostics: () => Promise<ApiResponse<DiagnosticsReport>>
+    /** Main process memory; past advanced.maxMemoryMb it also arrives on 'high-memory-warning' */
+    getMemoryUsage: () => Promise<ApiResponse<ProcessMemory>>
+    exportDiagnostics: (outputPath: string) => Promise<ApiResponse<{ outputPath: string }>>
+    getThumbnailCacheStats: () => Promise<ApiResponse<ThumbnailCacheStats>>
+    getYtdlpVersion: () => Promise<ApiResponse<BinaryVersion | null>>
+    getFfmpegVersion: () => Promise<ApiResponse<BinaryVersion | null>>
+    invalidateVersions: () => Promise<ApiResponse<void>>
+    validateBinaryPath: (path: string, type: BinaryType) => Promise<ApiResponse<string>>
   }
 
   // Video processing operations
@@ -166,6 +349,7 @@ interface ElectronAPI {
       options: ThumbnailOptions,
     ) => Promise<ApiResponse<{ thumbnails: string[]; interval: number; duration: number }>>
     getWaveform: (options: WaveformOptions) => Promise<ApiResponse<{ waveform: number[]; samples: number }>>
+    detectSceneChanges: (filePath: string, threshold?: number) => Promise<ApiResponse<SceneChange[]>>
   }
 
   // Streaming proxy operations (for YouTube video preview)
@@ -174,6 +358,181 @@ interface ElectronAPI {
     getStatus: () => Promise<ApiResponse<{ running: boolean; port: number }>>
   }
 
+  // Editor project operations (undoable edits go through executeCommand)
+  editor: {
+    createProject: (name: string) => Promise<ApiResponse<Project>>
+    openProject: (filePath: string) => Promise<ApiResponse<Project>>
+    saveProject: (projectId: string, filePath?: string) => Promise<ApiResponse<Project>>
+    getProject: (projectId: string) => Promise<ApiResponse<Project>>
+    closeProject: (projectId: string) => Promise<ApiResponse<void>>
+    /** Autosaves of projects that were never saved or closed, newest first; open one with openProject */
+    getAutosaves: () => Promise<ApiResponse<AutosaveInfo[]>>
+    deleteAutosave: (projectId: string) => Promise<ApiResponse<void>>
+    executeCommand: (projectId: string, command: ProjectCommand) => Promise<ApiResponse<Project>>
+    undo: (projectId: string) => Promise<ApiResponse<Project>>
+    redo: (projectId: string) => Promise<ApiResponse<Project>>
+    getUndoStackSize: (projectId: string) => Promise<ApiResponse<UndoRedoState>>
+    addMarker: (
+      projectId: string,
+      clipId: string,
+      time: number,
+      label: string,
+      color?: string,
+    ) => Promise<ApiResponse<Clip>>
+    removeMarker: (projectId: string, clipId: string, markerId: string) => Promise<ApiResponse<Clip>>
+    updateMarker: (
+      projectId: string,
+      clipId: string,
+      markerId: string,
+      label?: string,
+      color?: string,
+    ) => Promise<ApiResponse<Clip>>
+    addTrackMarker: (
+      projectId: string,
+      trackId: string,
+      time: number,
+      label: string,
+      color?: string,
+    ) => Promise<ApiResponse<Track>>
+    removeTrackMarker: (projectId: string, trackId: string, markerId: string) => Promise<ApiResponse<Track>>
+    updateTrackMarker: (
+      projectId: string,
+      trackId: string,
+      markerId: string,
+      label?: string,
+      color?: string,
+    ) => Promise<ApiResponse<Track>>
+    getMarkers: (projectId: string) => Promise<ApiResponse<MarkerWithClipId[]>>
+    exportProject: (projectId: string, settings: ProjectExportSettings) => Promise<ApiResponse<ProjectExportResult>>
+    cancelExport: () => Promise<ApiResponse<{ cancelled: boolean }>>
+    /** Size, bitrate, encoder and speed of a project's last exports, newest first */
+    getExportReports: (projectId: string) => Promise<ApiResponse<ExportReport[]>>
+    /** Export saved projects one after the other; cancelExport stops the rest of the batch */
+    batchExport: (jobs: ExportJob[]) => Promise<ApiResponse<ExportJobResult[]>>
+    /** Render the frame the export would show at a timeline time to a .jpg; a newer call cancels an older one */
+    renderPreviewFrame: (projectId: string, time: number, outputPath: string) => Promise<ApiResponse<void>>
+    cancelPreviewRender: () => Promise<ApiResponse<{ cancelled: boolean }>>
+    setExportPriority: (priority: ProcessPriority) => Promise<ApiResponse<{ changed: boolean }>>
+    autoSplitClip: (projectId: string, clipId: string, threshold?: number) => Promise<ApiResponse<Project>>
+    /** Split at the given source times; times outside the clip's source range are ignored */
+    splitClipAtTimes: (projectId: string, clipId: string, times: number[]) => Promise<ApiResponse<Project>>
+    /** Add a library video as a full-length clip on a track; its timeline thumbnails are made in the background */
+    importLibraryClip: (
+      projectId: string,
+      libraryId: string,
+      trackId: string,
+      insertAtTime: number,
+    ) => Promise<ApiResponse<{ project: Project; clip: Clip }>>
+    /** Add an audio-only file (or part of it) as an audio clip; files with a video stream are refused */
+    addAudioClip: (
+      projectId: string,
+      trackId: string,
+      filePath: string,
+      startTime: number,
+      sourceStart?: number,
+      sourceEnd?: number,
+    ) => Promise<ApiResponse<{ project: Project; clip: Clip }>>
+    /** Find a video's scene cuts in the background; progress arrives on 'editor-scene-analysis-progress' */
+    analyzeScenes: (
+      videoPath: string,
+      threshold?: number,
+      projectId?: string,
+    ) => Promise<ApiResponse<{ jobId: string }>>
+    getSceneAnalysisStatus: (jobId: string) => Promise<ApiResponse<SceneAnalysisProgress>>
+    cancelSceneAnalysis: (jobId: string) => Promise<ApiResponse<{ cancelled: boolean }>>
+    /** Cancel a project's thumbnail, waveform and scene jobs; job status arrives on 'editor-analysis-job' */
+    cancelAnalysisJobs: (projectId: string) => Promise<ApiResponse<{ cancelled: number }>>
+    getExportFormats: () => Promise<ApiResponse<ExportFormatInfo[]>>
+    getMediaUrl: (filePath: string) => Promise<ApiResponse<MediaUrlInfo>>
+    renderTextPreview: (
+      backgroundVideoPath: string,
+      timeOffset: number,
+      text: TextProperties,
+      outputPath?: string,
+    ) => Promise<ApiResponse<{ outputPath: string }>>
+    extractFrames: (
+      videoPath: string,
+      outputDir: string,
+      start: number,
+      end: number,
+      fps: number,
+    ) => Promise<ApiResponse<string[]>>
+    extractSingleFrame: (videoPath: string, time: number, outputPath: string) => Promise<ApiResponse<void>>
+    /** Exact frame at a time as a JPEG data URL, at most maxWidth wide; nearby frames are prefetched */
+    getPreviewFrame: (videoPath: string, time: number, maxWidth: number) => Promise<ApiResponse<string>>
+    /** Keyframe times between start and end; a trim snapped to one exports without re-encoding */
+    detectKeyframes: (videoPath: string, start: number, end: number) => Promise<ApiResponse<number[]>>
+    /** Editing copy of a source, reused when transcoded before; progress arrives on 'editor-transcode-progress' */
+    transcodeForEditing: (
+      inputPath: string,
+      profile: EditingTranscodeProfile,
+      projectId?: string,
+    ) => Promise<ApiResponse<EditingTranscodeResult>>
+    getVideoStreams: (filePath: string) => Promise<ApiResponse<StreamInfo[]>>
+    remuxVideo: (inputPath: string, outputPath: string, streams?: RemuxStreamSelection) => Promise<ApiResponse<string>>
+    diffProjects: (before: Project, after: Project) => Promise<ApiResponse<ProjectDiff>>
+    mergeAudioToVideo: (
+      videoPath: string,
+      audioPath: string,
+      outputPath: string,
+      options?: AudioMergeOptions,
+    ) => Promise<ApiResponse<string>>
+    createSlideshow: (images: string[], outputPath: string, options?: SlideshowOptions) => Promise<ApiResponse<string>>
+    getAvailableEffects: () => Promise<ApiResponse<EffectDefinition[]>>
+    applyEffectPreview: (videoPath: string, effect: Effect, outputPath: string) => Promise<ApiResponse<void>>
+  }
+
+  // Library operations (completed downloads)
+  library: {
+    exportCsv: (videoIds?: string[]) => Promise<ApiResponse<string>>
+    exportCsvToFile: (
+      outputPath: string,
+      videoIds?: string[],
+    ) => Promise<ApiResponse<{ outputPath: string; bytesWritten: number }>>
+    /** Write an HTML report of the library (stats, storage by format, videos by channel) and open it */
+    generateReport: (outputPath: string) => Promise<ApiResponse<{ outputPath: string; bytesWritten: number }>>
+    /** Save the library videos matching a search as an .m3u; without a path it goes to the playlists folder */
+    saveSearchAsPlaylist: (
+      query: string,
+      name: string,
+      outputPath?: string,
+    ) => Promise<ApiResponse<{ outputPath: string; count: number }>>
+    listPlaylists: () => Promise<ApiResponse<PlaylistFile[]>>
+    importCsv: (csvPath: string, skipMissingFiles?: boolean) => Promise<ApiResponse<LibraryImportResult>>
+    downloadMissingThumbnails: () => Promise<ApiResponse<number>>
+    getFormats: () => Promise<ApiResponse<LibraryFormatSummary[]>>
+    getByFormat: (format: string) => Promise<ApiResponse<DownloadProgress[]>>
+    /** Library videos matching every word of the query, best match first; ignores case and accents */
+    search: (query: string, limit?: number) => Promise<ApiResponse<DownloadProgress[]>>
+    getChannels: () => Promise<ApiResponse<LibraryChannelSummary[]>>
+    getByChannel: (channelId: string) => Promise<ApiResponse<DownloadProgress[]>>
+    startWatcher: (folderPath: string) => Promise<ApiResponse<void>>
+    stopWatcher: () => Promise<ApiResponse<void>>
+    getBulkJobStatus: (jobId: string) => Promise<ApiResponse<BulkJobProgress>>
+    cancelBulkJob: (jobId: string) => Promise<ApiResponse<{ cancelled: boolean }>>
+    checkAlreadyDownloaded: (url: string) => Promise<ApiResponse<DownloadProgress | null>>
+    remuxVideo: (
+      downloadId: string,
+      container: string,
+      streams?: RemuxStreamSelection,
+    ) => Promise<ApiResponse<{ jobId: string }>>
+    extractAudio: (
+      downloadId: string,
+      format: AudioExtractFormat,
+      bitrate?: number,
+    ) => Promise<ApiResponse<{ jobId: string }>>
+    getConversionStatus: (jobId: string) => Promise<ApiResponse<LibraryConversionProgress>>
+    cancelConversion: (jobId: string) => Promise<ApiResponse<{ cancelled: boolean }>>
+    setPlaybackPosition: (
+      downloadId: string,
+      seconds: number,
+      duration?: number,
+    ) => Promise<ApiResponse<PlaybackPosition>>
+    getPlaybackPosition: (downloadId: string) => Promise<ApiResponse<PlaybackPosition | null>>
+    markWatched: (downloadId: string, watched: boolean) => Promise<ApiResponse<PlaybackPosition>>
+    getContinueWatching: (limit?: number) => Promise<ApiResponse<DownloadProgress[]>>
+  }
+
   // Event listeners - secure wrapper around ipcRenderer.on/off
   // Only whitelisted channels are allowed (see ALLOWED_CHANNELS in context-bridge.ts)
   on: (channel: string, listener: (...args: any[]) => void) => void
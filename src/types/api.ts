@@ -21,6 +21,9 @@ export interface ErrorResponse extends BaseResponse {
   error: string
   code: string
   retryable?: boolean
+  /** Message catalog key (utils/i18n) and params for showing error in the UI language */
+  messageKey?: string
+  params?: Record<string, string | number>
 }
 
 export type ApiResponse<T = unknown> = SuccessResponse<T> | ErrorResponse
@@ -23,13 +23,31 @@ export enum DownloadErrorCode {
   STREAM_ERROR = 'STREAM_ERROR',
   NO_STREAMS = 'NO_STREAMS',
   MUXING_ERROR = 'MUXING_ERROR',
+  FILENAME_TOO_LONG = 'FILENAME_TOO_LONG',
+  UNSUPPORTED_SITE = 'UNSUPPORTED_SITE',
+  YTDLP_TOO_OLD = 'YTDLP_TOO_OLD',
+  SITE_BLOCKED = 'SITE_BLOCKED',
+  DOWNLOAD_PAUSED = 'DOWNLOAD_PAUSED',
+  PARTIAL_FAILURE = 'PARTIAL_FAILURE',
+  INVALID_FILENAME_TEMPLATE = 'INVALID_FILENAME_TEMPLATE',
+  LOGIN_REQUIRED = 'LOGIN_REQUIRED',
 }
 
+/** Errors of videos only a signed-in user can see, which may work with cookies */
+export const RESTRICTED_VIDEO_ERROR_CODES: readonly DownloadErrorCode[] = [
+  DownloadErrorCode.VIDEO_PRIVATE,
+  DownloadErrorCode.AGE_RESTRICTED,
+  DownloadErrorCode.LOGIN_REQUIRED,
+]
+
 export interface DownloadError extends Error {
   readonly name: 'DownloadError'
   readonly code: DownloadErrorCode
   readonly originalError?: Error
   readonly retryable: boolean
+  /** Message catalog key (utils/i18n) and params for showing the message in the UI language */
+  readonly messageKey?: string
+  readonly params?: Record<string, string | number>
 }
 
 export function createDownloadError(
@@ -63,10 +81,47 @@ export type DownloadStatus =
   | 'downloading'
   | 'processing'
   | 'completed'
+  | 'completed-with-errors' // The file was saved, but part of the download failed (e.g. subtitles)
   | 'failed'
   | 'cancelled'
   | 'retrying'
   | 'queued'
+  | 'paused'
+
+/** Whether a download finished with its file saved, with or without errors along the way */
+export function isCompletedStatus(status: DownloadStatus | undefined): boolean {
+  return status === 'completed' || status === 'completed-with-errors'
+}
+
+/** What to do once every queued download has finished (reset on each launch) */
+export type QueueCompletionAction = 'none' | 'sleep' | 'shutdown' | 'quit'
+
+/** Counts for the downloads finished since the queue was last empty */
+export interface QueueCompletionSummary {
+  completed: number
+  failed: number
+  cancelled: number
+}
+
+export interface QueueCompletedEvent extends QueueCompletionSummary {
+  action: QueueCompletionAction
+  /** When the action runs unless cancelled; null when no action is set */
+  executeAt: number | null
+}
+
+/** The completion action and, while its countdown runs, when it fires */
+export interface QueueCompletionState {
+  action: QueueCompletionAction
+  executeAt: number | null
+}
+
+/** Everything the downloads UI needs on mount; later events with a higher sequence are newer */
+export interface DownloadStateSnapshot {
+  sequence: number
+  downloads: DownloadProgress[]
+  stats: { active: number; queued: number; completed: number; failed: number; total: number }
+  completion: QueueCompletionState
+}
 
 export interface DownloadProgress {
   downloadId: string
@@ -85,7 +140,163 @@ export interface DownloadProgress {
   startTime: number
   retryCount: number
   provider?: DownloadProvider
-  usedProvider?: 'ytdlp'
+  usedProvider?: 'ytdlp' | 'direct' // 'direct' for a media file fetched over HTTP without yt-dlp
+  // Library metadata, filled in once the download completes
+  videoId?: string
+  channel?: string
+  channelId?: string // Stable across channel renames, unlike the name
+  duration?: number // Seconds
+  format?: string // Container extension, e.g. 'mp4'
+  resolution?: string // e.g. '1920x1080'
+  completedAt?: number
+  thumbnailUrl?: string // Remote thumbnail, cached locally by the thumbnail prefetch
+  cachedThumbnailPath?: string
+  fingerprint?: string // Content fingerprint from the media cache, set when the file entered the library
+  tags?: string[]
+  parentId?: string // Library entry this file was converted from (remux or audio extraction)
+  qualityFallback?: { requested: string; actual: string } // Set when the requested quality wasn't available
+  sourceInfo?: VideoInfoSummary // The video as looked up when the download was started
+  warning?: string // Completed, but a step after the download failed (e.g. the final container remux)
+  partialErrors?: string[] // yt-dlp's errors when the download still produced its file (completed-with-errors)
+  sequence?: number // Increases with every download event, across all downloads
+  playback?: PlaybackPosition // Library entries that have been played in the preview
+  pausedState?: PausedDownloadState // Set while paused, so the download can continue after a restart
+  sidecarFiles?: string[] // Files written next to the download (chapter files), deleted along with it
+  resumeRestarted?: boolean // Resumed, but the partial files had changed on disk, so it started over
+  // Per-download yt-dlp log
+  logPath?: string
+  stderrTail?: string[] // Last stderr lines, set on failure
+}
+
+/** Options for deleting a download from the library */
+export interface DownloadDeleteOptions {
+  deleteFile?: boolean // Also remove the downloaded file and its thumbnail
+  permanentlyDelete?: boolean // Skip the recycle bin; defaults to the download.permanentlyDeleteFiles setting
+}
+
+export interface FileRemovalResult {
+  path: string
+  method: 'trash' | 'permanent' | 'missing'
+  code?: 'TRASH_UNAVAILABLE' | 'DELETE_FAILED'
+  error?: string
+}
+
+export interface DownloadDeleteResult {
+  downloadId: string
+  deleted: boolean
+  files: FileRemovalResult[]
+  code?: string // Set when the downloaded file couldn't be removed; the download is then kept
+  error?: string
+  failedFiles?: FileRemovalResult[] // Thumbnail or sidecar files left behind; the download is still deleted
+}
+
+export type BulkJobKind = 'delete'
+export type BulkJobStatus = 'running' | 'completed' | 'cancelled'
+
+export interface BulkJobItemError {
+  id: string
+  code?: string
+  error: string
+}
+
+/** State of a background bulk library operation, as sent with 'library-bulk-progress' */
+export interface BulkJobProgress {
+  jobId: string
+  kind: BulkJobKind
+  status: BulkJobStatus
+  done: number
+  total: number
+  succeeded: number
+  currentTitle?: string // Item being processed
+  errors: BulkJobItemError[]
+  startedAt: number
+  finishedAt?: number
+}
+
+/** Library videos grouped by container format */
+export interface LibraryFormatSummary {
+  format: string // File extension without the dot, e.g. 'webm'; 'unknown' if it can't be determined
+  count: number
+  totalSize: number // Bytes
+}
+
+/** An M3U playlist saved from a library search */
+export interface PlaylistFile {
+  name: string
+  path: string
+  entryCount: number
+  modifiedAt: number // ms since epoch
+}
+
+/** Channel of library videos, grouped by ID so a renamed channel stays one group */
+export interface LibraryChannel {
+  id: string
+  name: string // Latest name seen
+  previousNames: string[] // Earlier names, most recent first
+  avatarUrl?: string
+  avatarPath?: string // Cached copy in the thumbnail cache
+  lastSeen: number // Last download completion from this channel
+}
+
+export interface LibraryChannelSummary extends LibraryChannel {
+  videoCount: number
+}
+
+/** Where playback of a library video stopped, so it can resume there */
+export interface PlaybackPosition {
+  downloadId: string
+  position: number // Seconds
+  duration?: number // Seconds, as reported by the player
+  watched: boolean // Played past WATCHED_THRESHOLD of the duration, or marked by the user
+  updatedAt: number
+}
+
+/** A partial file yt-dlp left when the download was paused */
+export interface PartialFile {
+  path: string
+  size: number // Bytes
+}
+
+/** What a paused download needs to continue where it stopped, kept in its downloads.json entry */
+export interface PausedDownloadState {
+  options: DownloadOptions // Options the download was started with
+  outputTemplate: string // -o template yt-dlp ran with; the partial files are named after it
+  formatSelector?: string // -f selector the download was pinned to
+  tempDir?: string // Folder holding the partial files, when download.useTempDirForDownloads was on
+  partialFiles: PartialFile[]
+  downloadedBytes: number
+  pausedAt: number
+}
+
+/** Which stream of each type to keep when remuxing, by position among streams of that type (0:v:N) */
+export interface RemuxStreamSelection {
+  videoStream?: number
+  audioStream?: number
+  subtitleStream?: number
+}
+
+export type AudioExtractFormat = 'mp3' | 'm4a' | 'flac'
+
+export type LibraryConversionKind = 'remux' | 'extract-audio'
+
+/** Progress of converting a library video into a new library entry */
+export interface LibraryConversionProgress {
+  jobId: string
+  kind: LibraryConversionKind
+  sourceId: string
+  status: 'running' | 'completed' | 'failed' | 'cancelled'
+  progress: number // 0-100
+  entry?: DownloadProgress // The new library entry, once completed
+  error?: string
+  startedAt: number
+  finishedAt?: number
+}
+
+/** Outcome of a library CSV import */
+export interface LibraryImportResult {
+  imported: number
+  skipped: number
+  errors: { row: number; message: string }[] // row is the 1-based CSV record, header included
 }
 
 export type VideoQuality = 'highest' | 'lowest' | 'highestaudio' | 'lowestaudio' | string
@@ -93,11 +304,22 @@ export type VideoFormat = 'mp4' | 'webm' | 'mkv' | 'mp3' | 'm4a' | 'opus'
 
 export type DownloadProvider = 'ytdlp' | 'auto'
 
+/**
+ * Cookies a download or info lookup signs in with. 'auto' uses download.cookiesFromBrowser,
+ * else the app cookie file; 'never' sends none; 'browser' and 'file' name a source of their own.
+ */
+export type CookieChoice =
+  | { mode: 'auto' }
+  | { mode: 'never' }
+  | { mode: 'browser'; browser: string }
+  | { mode: 'file'; path: string } // A Netscape cookies.txt
+
 export interface DownloadOptions {
   quality?: VideoQuality
   format?: VideoFormat
   outputPath?: string
   filename?: string
+  filenameTemplate?: string // yt-dlp style template for this download only; overrides download.filenameTemplate
   downloadSubtitles?: boolean
   downloadThumbnail?: boolean
   saveMetadata?: boolean
@@ -105,9 +327,39 @@ export interface DownloadOptions {
   timeoutMs?: number
   overwrite?: boolean
   createSubdirectories?: boolean
+  cookiesFromBrowser?: string
+  useCookies?: CookieChoice // Overrides cookiesFromBrowser and download.cookiesFromBrowser for this download
   startTime?: number
   endTime?: number
   provider?: DownloadProvider
+  tags?: string[] // User labels for organizing downloads, e.g. 'favorites'
+  finalContainer?: FinalContainer // Overrides download.finalContainer for this download
+  sponsorBlockMode?: SponsorBlockMode // Overrides download.sponsorBlockMode for this download
+  sponsorBlockRemoveCategories?: string[]
+  sponsorBlockMarkCategories?: string[]
+  audioLanguage?: string // Preferred audio language code; other audio is used when the video lacks it
+  downloadAllAudioTracks?: boolean // Every audio language as its own track, in an MKV
+  writeChaptersFile?: boolean // Audio-only: .chapters.json (podcast JSON chapters) and .cue next to the file
+  writePodcastFeed?: boolean // Audio-only: keep a feed.xml of the audio in the destination folder
+  resumeFrom?: PausedDownloadState // Set internally when a paused download is resumed; not accepted over IPC
+}
+
+/** A filename template rendered for one video, before downloading it */
+export interface OutputTemplatePreview {
+  path: string // Where the download would be written
+  filename: string
+  errors: string[] // The download can't start with this template
+  warnings: string[]
+  exists: boolean // A file is already at the unnumbered path, so path has a ' (N)' suffix
+}
+
+/** Named set of download options, applied to single downloads without changing the settings */
+export interface DownloadProfile {
+  id: string
+  name: string // e.g. 'Archive'; unique, case-insensitive
+  options: DownloadOptions
+  createdAt: number
+  updatedAt: number
 }
 
 export interface VideoThumbnail {
@@ -117,6 +369,46 @@ export interface VideoThumbnail {
   subscriberCount?: number
 }
 
+/**
+ * Storyboard sprite sheet format (YouTube hover-scrub previews).
+ * Each sheet is a grid of columns x rows frames, one frame every `interval` seconds.
+ */
+export interface StoryboardInfo {
+  formatId: string
+  url: string // First sprite sheet URL
+  fragments: string[] // All sprite sheet URLs in playback order
+  columns: number
+  rows: number
+  frameWidth: number
+  frameHeight: number
+  interval: number // Seconds between frames
+}
+
+/** A comment on a video, with its replies nested */
+export interface VideoComment {
+  id: string
+  author: string
+  authorThumbnail?: string
+  text: string
+  timestamp: string // ISO date when the site gives one, else its relative text ('2 weeks ago')
+  likes: number
+  isPinned: boolean
+  isUploader: boolean // Written by the video's uploader
+  replies: VideoComment[]
+}
+
+/** Storyboard sprite sheets saved to the local thumbnail cache */
+export interface StoryboardDownload {
+  videoId: string
+  formatId: string
+  paths: string[]
+  columns: number
+  rows: number
+  frameWidth: number
+  frameHeight: number
+  interval: number
+}
+
 export interface VideoChannel {
   name: string
   id: string
@@ -132,6 +424,8 @@ export interface CaptionTrack {
 
 export interface VideoFormatInfo {
   itag: number
+  /** yt-dlp format ID; not always numeric (e.g. 'hls-1080p', '137-drc') */
+  formatId?: string
   quality: string
   qualityLabel?: string
   format: string
@@ -150,6 +444,20 @@ export interface VideoFormatInfo {
   contentLength?: number
   /** Download protocol: 'https' for direct URLs, 'm3u8'/'m3u8_native' for HLS streams */
   protocol?: string
+  /** 'SDR', 'HDR10', 'HLG' etc. for video formats */
+  dynamicRange?: string
+  /** 2 for stereo, 6 for 5.1 */
+  audioChannels?: number
+  /** Audio language code, when the site reports one */
+  language?: string
+  /** Site's description of the format, e.g. 'English (United States) original, medium' */
+  formatNote?: string
+}
+
+/** An audio language a video offers, for picking the dub to download */
+export interface AudioLanguage {
+  code: string // e.g. 'en' or 'es-US'
+  label: string // From the format note when there is one, else the code
 }
 
 export interface VideoInfo {
@@ -170,10 +478,65 @@ export interface VideoInfo {
   isPrivate: boolean
   ageRestricted: boolean
   formats: VideoFormatInfo[]
+  audioLanguages?: AudioLanguage[] // Only when the audio formats report more than one language
   captions?: CaptionTrack[]
   bestVideoFormat?: VideoFormatInfo
   bestAudioFormat?: VideoFormatInfo
   availableQualities: string[]
+  storyboards?: StoryboardInfo[]
+  relatedVideos?: RelatedVideo[] // Only when the site's metadata lists them
+  chapters?: VideoChapter[] // Only when the video has chapters
+  direct?: DirectMediaInfo // Set for a URL that points straight at a media file
+}
+
+/** What a download keeps of its VideoInfo: the metadata, without formats and the like */
+export interface VideoInfoSummary {
+  id: string
+  title: string
+  thumbnail?: string // URL of the largest thumbnail
+  duration: number // Seconds
+  channel: string
+  channelId?: string
+  uploadDate?: string // YYYYMMDD
+  viewCount?: number
+}
+
+export function summarizeVideoInfo(info: VideoInfo): VideoInfoSummary {
+  return {
+    id: info.id,
+    title: info.title,
+    thumbnail: info.thumbnails[info.thumbnails.length - 1]?.url,
+    duration: info.duration,
+    channel: info.channel.name,
+    channelId: info.channel.id || undefined,
+    uploadDate: info.uploadDate || undefined,
+    viewCount: info.views || undefined,
+  }
+}
+
+/** A media file at a plain http(s) URL, as its server described it */
+export interface DirectMediaInfo {
+  contentType: string
+  extension: string // Without the dot
+  size?: number // Bytes, when the server sent a Content-Length
+}
+
+/** A chapter of a video or audio file, in seconds */
+export interface VideoChapter {
+  start: number
+  end: number
+  title: string
+}
+
+/** A video the site recommends alongside another, for a "watch next" list */
+export interface RelatedVideo {
+  id: string
+  title: string
+  url: string
+  duration?: number // Seconds
+  viewCount?: number
+  thumbnail?: string
+  channel?: string
 }
 
 export interface DownloadConfig {
@@ -192,7 +555,7 @@ export interface DownloadManagerEvents {
   initialized: (success: boolean) => void
 }
 
-export type DownloadFilter = 'active' | 'completed' | 'failed' | 'all'
+export type DownloadFilter = 'active' | 'completed' | 'failed' | 'all' | 'continue-watching'
 
 export interface DownloadListData {
   downloads: DownloadProgress[]
@@ -200,6 +563,30 @@ export interface DownloadListData {
   filter: string
 }
 
+/** A URL from a batch enqueue, with its 1-based line in the file or pasted list */
+export interface BatchEnqueueLine {
+  line: number
+  url: string
+}
+
+export interface BatchEnqueueReport {
+  batchId: string
+  accepted: (BatchEnqueueLine & { downloadId: string })[]
+  duplicates: (BatchEnqueueLine & { videoId?: string })[] // Already queued, downloading, or downloaded
+  /** messageKey and params localize the error, as on ErrorResponse */
+  invalid: (BatchEnqueueLine & { error: string; messageKey?: string; params?: Record<string, string | number> })[]
+}
+
+export interface BatchEnqueueProgress {
+  batchId: string
+  processedLines: number
+  accepted: number
+  duplicates: number
+  invalid: number
+  done: boolean
+}
+
+import type { FinalContainer, SponsorBlockMode } from './system'
 import type {
   DownloadCancelResponse,
   DownloadListResponse,
@@ -0,0 +1,379 @@
+/**
+ * Project Types
+ * Type definitions for editor projects, tracks, clips, and undoable edit commands.
+ */
+
+export type ClipType = 'video' | 'audio' | 'image'
+
+export type TrackKind = 'video' | 'audio'
+
+/** View of a still image: zoom factor (1 = whole image) and the view center as fractions of the image size */
+export interface KenBurnsFrame {
+  zoom: number // 1-10
+  x: number // 0-1
+  y: number // 0-1
+}
+
+/** Slow pan/zoom across an image clip, from start to end over the clip's length */
+export interface KenBurnsEffect {
+  start: KenBurnsFrame
+  end: KenBurnsFrame
+}
+
+/** Look applied to a clip's finished frame, after trimming, scaling and pan/zoom */
+export type EffectType = 'vignette' | 'film-grain' | 'chromatic-aberration' | 'glow' | 'old-film'
+
+export interface Effect {
+  id: string
+  effectType: EffectType
+  params: Record<string, number> // Per EffectDefinition; missing params use their defaults
+}
+
+export interface EffectParamDefinition {
+  name: string
+  label: string
+  min: number
+  max: number
+  step: number
+  default: number
+}
+
+/** An effect the editor offers, with its adjustable parameters */
+export interface EffectDefinition {
+  effectType: EffectType
+  label: string
+  params: EffectParamDefinition[]
+}
+
+export interface ClipProperties {
+  volume: number // 0-2, 1 = original level
+  speed: number // Playback rate multiplier
+  opacity: number // 0-1
+  muted: boolean
+  kenBurns?: KenBurnsEffect // Image clips only
+  effectChain?: Effect[] // Applied in order, after the clip's own processing
+}
+
+/** Chapter/cue point annotation */
+export interface Marker {
+  id: string
+  time: number // Seconds; relative to the clip start for clip markers, timeline time for track markers
+  color: string
+  label: string
+}
+
+export interface MarkerWithClipId extends Marker {
+  clipId?: string // Unset for a track marker
+  trackId: string
+}
+
+export interface Clip {
+  id: string
+  type: ClipType
+  name: string
+  sourcePath: string
+  startTime: number // Position on the timeline (seconds)
+  sourceStart: number // In point within the source (seconds)
+  sourceEnd: number // Out point within the source (seconds); image clips show for sourceEnd - sourceStart
+  properties: ClipProperties
+  markers: Marker[]
+}
+
+export interface Track {
+  id: string
+  name: string
+  kind: TrackKind
+  clips: Clip[]
+  markers: Marker[]
+  muted: boolean
+  locked: boolean
+}
+
+export interface ProjectSettings {
+  width: number
+  height: number
+  frameRate: number
+}
+
+export interface Project {
+  id: string
+  name: string
+  tracks: Track[]
+  settings: ProjectSettings
+  duration: number
+  createdAt: number
+  modifiedAt: number
+  filePath?: string // Where the project was last saved, if ever
+}
+
+export type TextAlign = 'left' | 'center' | 'right'
+
+/** Styled text drawn over video (FFmpeg drawtext) */
+export interface TextProperties {
+  content: string
+  fontFile?: string // .ttf/.otf path; FFmpeg's default font when omitted
+  fontSize: number // Pixels
+  color: string // '#rrggbb', '#rrggbbaa' or an FFmpeg color name
+  align: TextAlign // Horizontal placement; left/right keep a small margin from the edge
+  y?: number // Vertical center of the text as a fraction of the frame height (default 0.5)
+}
+
+export type GifDither = 'none' | 'bayer' | 'floyd_steinberg' | 'sierra2_4a'
+
+export interface GifExportOptions {
+  fps?: number // Default 15, capped at 30
+  maxWidth?: number // Downscale wider output, keeping the aspect ratio
+  dither?: GifDither // Default 'sierra2_4a'
+}
+
+export type ExportFormat = 'mp4' | 'mkv' | 'mov' | 'webm' | 'gif' | 'png-sequence'
+export type ExportVideoCodec = 'h264' | 'h265' | 'vp9' | 'av1'
+export type ExportAudioCodec = 'aac' | 'mp3' | 'opus'
+export type ExportAudioFormat = 'mp3' | 'm4a' | 'flac' | 'opus'
+
+export interface ProjectExportSettings {
+  outputPath: string // For 'png-sequence', the directory the numbered frames are written to
+  format: ExportFormat
+  videoCodec: ExportVideoCodec
+  audioCodec: ExportAudioCodec
+  quality: 'low' | 'medium' | 'high'
+  rateControl?: 'crf' | 'bitrate' // Default 'crf' (constant quality)
+  videoBitrate?: number // kbps, for 'bitrate' rate control; defaults by quality
+  preset?: 'ultrafast' | 'fast' | 'medium' | 'slow'
+  includeChapters?: boolean // Write markers as chapters (default true)
+  allowKeyframeSnap?: boolean // Let a stream-copy export move the in point back to the previous keyframe
+  overwrite?: boolean // Replace an existing file at outputPath; otherwise a ' (N)' suffixed name is used
+  gif?: GifExportOptions
+  audioOnly?: boolean // Export just the mixed audio (podcast mode); format and the codecs are ignored
+  audioFormat?: ExportAudioFormat // For audioOnly; default 'mp3'
+  audioBitrate?: number // kbps, for audioOnly lossy formats; defaults by quality
+  addToLibrary?: boolean // Add the exported file to the library
+}
+
+export interface ProjectExportResult {
+  outputPath: string // Path actually written, which differs from the requested one after an auto-rename
+  method: 'stream-copy' | 're-encode'
+  reencodeReasons?: string[] // Why stream copy wasn't possible
+  snappedStart?: number // Source in point actually used, when moved to a keyframe
+  libraryEntryId?: string // Set when the file was added to the library
+  encoder?: string // FFmpeg encoder of the main stream; unset for stream copy
+  report?: ExportReport // Unset for PNG sequences, which are a folder of frames
+}
+
+/** Something about an exported file that looks wrong */
+export type ExportReportWarning = 'duration-mismatch' // Probed duration is more than 2% off the expected one
+
+export interface ExportReportStream {
+  type: string // 'video', 'audio' or 'subtitle'
+  codec: string
+  bitrate?: number // kbps, when the container records it
+}
+
+/** What an export produced and how long it took, from its progress and a probe of the file */
+export interface ExportReport {
+  projectId: string
+  outputPath: string
+  createdAt: number
+  method: ProjectExportResult['method']
+  encoder: string | null // null for stream copy
+  hardwareAccelerated: boolean
+  fileSize: number // Bytes
+  duration: number // Seconds, probed from the file
+  expectedDuration: number // Seconds the project should have come out as
+  averageBitrate: number // kbps over the whole file
+  encodeSeconds: number // Wall time
+  averageFps: number | null // Frames encoded per second of wall time; null without video
+  streams: ExportReportStream[]
+  warnings: ExportReportWarning[]
+}
+
+/** A video codec a format can hold, and the FFmpeg encoder that would be used (null if not installed) */
+export interface ExportCodecSupport {
+  codec: ExportVideoCodec
+  encoder: string | null
+}
+
+/** Codecs valid for an export format, so the UI can disable impossible combinations */
+export interface ExportFormatInfo {
+  format: ExportFormat
+  label: string
+  videoCodecs: ExportCodecSupport[] // Empty for image formats
+  audioCodecs: ExportAudioCodec[] // Empty when the format has no audio
+}
+
+export interface ProjectExportProgress {
+  projectId: string
+  progress: number // 0-100, across all passes
+  currentTime: number
+  duration: number
+  pass?: number // 1-based, for multi-pass exports (GIF)
+  totalPasses?: number
+}
+
+/** One project of a batch export */
+export interface ExportJob {
+  id: string
+  projectPath: string // Project file, exported as last saved
+  settings: ProjectExportSettings
+}
+
+export interface ExportJobResult {
+  jobId: string
+  success: boolean
+  outputPath?: string
+  error?: string
+  elapsedSeconds: number
+}
+
+export interface BatchExportProgress {
+  completed: number
+  total: number
+  currentJobId: string | null // null once the batch is done
+}
+
+/** Scene cut detected by FFmpeg's scene score */
+export interface SceneChange {
+  time: number // Seconds into the source
+  score: number // Scene difference score, 0-1
+}
+
+/** Background FFmpeg analysis the editor runs through the analysis pool */
+export type AnalysisJobType = 'thumbnails' | 'waveform' | 'scenes' | 'transcode'
+
+/** 'high' for clips in view, which should not wait behind the rest of the project */
+export type AnalysisJobPriority = 'high' | 'normal' | 'low'
+
+export interface AnalysisJobEvent {
+  jobId: string
+  type: AnalysisJobType
+  projectId?: string
+  status: 'queued' | 'running' | 'completed' | 'failed' | 'cancelled'
+  error?: string
+}
+
+/**
+ * Editing-friendly copy of a source the editor struggles to seek in (e.g. HEVC): 'edit' keeps
+ * the resolution, 'proxy' is a smaller, faster 720p copy
+ */
+export type EditingTranscodeProfile = 'edit' | 'proxy'
+
+export interface EditingTranscodeResult {
+  outputPath: string
+  cached: boolean // An earlier transcode of the same file and profile was reused
+}
+
+export interface EditingTranscodeProgress {
+  inputPath: string
+  profile: EditingTranscodeProfile
+  progress: number // 0-100
+}
+
+export interface SceneAnalysisProgress {
+  jobId: string
+  videoPath: string
+  threshold: number
+  status: 'running' | 'completed' | 'failed' | 'cancelled'
+  progress: number // 0-100
+  scenes?: SceneChange[] // Once completed
+  cached?: boolean // Result came from an earlier analysis of the same file
+  error?: string
+  startedAt: number
+  finishedAt?: number
+}
+
+/** How the preview media server delivers a source: as-is, remuxed, or transcoded to H.264 */
+export type MediaDeliveryMode = 'direct' | 'remux' | 'transcode'
+
+export interface MediaUrlInfo {
+  url: string
+  mode: MediaDeliveryMode // Remuxed/transcoded streams can't be range-seeked; add a t=<seconds> query parameter instead
+}
+
+/** How an external audio file is added to a video */
+export interface AudioMergeOptions {
+  replace?: boolean // Drop the video's own audio instead of mixing the new audio in
+  offset?: number // Seconds; positive delays the new audio, negative skips its start
+  originalVolume?: number // Mix weights (0-2); only their ratio matters
+  newVolume?: number
+}
+
+/** Slideshow built from still images; unset values use the defaults noted */
+export interface SlideshowOptions {
+  durationPerImage?: number // Seconds each image is shown on its own (4)
+  transitionDuration?: number // Seconds of crossfade between images, 0 for hard cuts (1)
+  fps?: number // (30)
+  width?: number // Output frame size; images are fitted and letterboxed (1920x1080)
+  height?: number
+  kenBurns?: boolean // Slow zoom into each image (true)
+  audioPath?: string // Soundtrack; the video stops when the shorter of the two ends
+}
+
+/** Clip properties that can be changed through an update-clip-property command */
+export type ClipPropertyKey = keyof ClipProperties | 'name'
+
+/**
+ * Undoable project mutation.
+ * Every command applied to a project produces its inverse, which is what the undo stack stores.
+ */
+export type ProjectCommand =
+  | { type: 'add-clip'; trackId: string; clip: Clip; index?: number }
+  | { type: 'remove-clip'; trackId: string; clipId: string }
+  | { type: 'move-clip'; clipId: string; toTrackId: string; startTime: number; index?: number }
+  | { type: 'resize-clip'; clipId: string; sourceStart: number; sourceEnd: number; startTime?: number }
+  | {
+      type: 'update-clip-property'
+      clipId: string
+      property: ClipPropertyKey
+      value: ClipProperties[keyof ClipProperties] | string
+    }
+  | { type: 'add-track'; track: Track; index?: number }
+  | { type: 'remove-track'; trackId: string }
+  | { type: 'add-marker'; clipId: string; marker: Marker }
+  | { type: 'remove-marker'; clipId: string; markerId: string }
+  | { type: 'update-marker'; clipId: string; markerId: string; label?: string; color?: string }
+  | { type: 'add-track-marker'; trackId: string; marker: Marker }
+  | { type: 'remove-track-marker'; trackId: string; markerId: string }
+  | { type: 'update-track-marker'; trackId: string; markerId: string; label?: string; color?: string }
+  | { type: 'batch'; commands: ProjectCommand[] } // Applied and undone as one step
+
+export type ProjectCommandType = ProjectCommand['type']
+
+/** One changed value; path is dot-separated from the changed object, e.g. 'properties.speed' */
+export interface ProjectPropertyChange {
+  path: string
+  from: unknown
+  to: unknown
+}
+
+/**
+ * Structural change between two versions of a project.
+ * Moving a clip to another track is a removal plus an addition.
+ */
+export type ProjectDiffChange =
+  | { type: 'project-changed'; changes: ProjectPropertyChange[] }
+  | { type: 'track-added'; track: Track; index: number }
+  | { type: 'track-removed'; trackId: string }
+  | { type: 'track-changed'; trackId: string; changes: ProjectPropertyChange[] }
+  | { type: 'clip-added'; trackId: string; clip: Clip; index: number }
+  | { type: 'clip-removed'; trackId: string; clipId: string }
+  | { type: 'clip-changed'; trackId: string; clipId: string; changes: ProjectPropertyChange[] }
+
+/** Compact difference between two project snapshots, small enough to keep in undo history */
+export interface ProjectDiff {
+  identical: boolean // No changes besides modifiedAt and the derived duration
+  changes: ProjectDiffChange[]
+}
+
+/** A project autosave in the temp folder, offered for recovery */
+export interface AutosaveInfo {
+  projectId: string
+  projectName: string
+  savedAt: number
+  path: string
+}
+
+export interface UndoRedoState {
+  projectId: string
+  undoStackSize: number
+  redoStackSize: number
+}
@@ -1,15 +1,18 @@
 /**
  * Configuration Manager
- * Centralized configuration management with persistence
+ * Centralized configuration management with persistence.
+ * Optionally watches config.json so manual edits (or sync tools like Dropbox) are picked up.
  */
 
 import type { AppConfig, ThemeMode } from '../types/system'
-import { existsSync, readFileSync, writeFileSync } from 'fs'
+import { FSWatcher, existsSync, readFileSync, watch, writeFileSync } from 'fs'
 
 import { FileSystemUtils } from './file-system'
 import { Logger } from './logger'
 import { PlatformUtils } from './platform'
-import { join } from 'path'
+import { basename, dirname, join } from 'path'
+
+const CONFIG_WATCH_DEBOUNCE = 500 // ms; editors and sync tools often write a file in several steps
 
 export class ConfigManager {
   private static instance: ConfigManager
@@ -18,6 +21,10 @@ export class ConfigManager {
   private logger = Logger.getInstance()
   private fileSystem = FileSystemUtils.getInstance()
   private platform = PlatformUtils.getInstance()
+  private configWatcher: FSWatcher | null = null
+  private watchDebounceTimer: NodeJS.Timeout | null = null
+  // Content of our own last write, so the watcher can ignore the change events it causes
+  private lastWrittenContent: string | null = null
 
   // Default configuration
   private readonly DEFAULT_CONFIG: AppConfig = {
@@ -29,16 +36,29 @@ export class ConfigManager {
       downloadThumbnails: true,
       saveMetadata: true,
       createSubdirectories: true,
+      includeDateInFilename: false,
+      filenameTemplate: '%(title)s.%(ext)s',
+      cookiesFromBrowser: '',
       maxConcurrentDownloads: 3,
+      maxConcurrentPerHost: 0,
       autoRetryFailed: true,
+      permanentlyDeleteFiles: false,
+      watchDownloadFolder: false,
+      useTempDirForDownloads: true,
       downloadPath: join(this.platform.getDownloadsDir(), 'Clipy'),
       maxRetries: 3,
       timeoutMs: 300000,
+      qualityFallback: 'closest',
+      finalContainer: 'keep',
+      sponsorBlockMode: 'off',
+      sponsorBlockRemoveCategories: ['sponsor'],
+      sponsorBlockMarkCategories: ['sponsor', 'intro', 'outro', 'selfpromo', 'interaction'],
     },
     cache: {
       maxSize: 10 * 1024 * 1024 * 1024, // 10GB
       maxAge: 7 * 24 * 60 * 60 * 1000, // 7 days
       cleanupInterval: 60 * 60 * 1000, // 1 hour
+      downloadLogRetentionDays: 14,
     },
     storage: {
       tempPath: join(this.platform.getAppDataDir('clipy'), 'temp'),
@@ -49,6 +69,10 @@ export class ConfigManager {
       defaultQuality: 'high',
       preferFastTrim: true,
       defaultAudioFormat: 'mp3',
+      processPriority: 'normal',
+      threadsLimit: 0,
+      previewCacheMb: 64,
+      maxAnalysisJobs: 0,
     },
     notifications: {
       downloadComplete: true,
@@ -63,6 +87,16 @@ export class ConfigManager {
       debugLogging: false,
       ffmpegPath: '',
       ytDlpPath: '',
+      maxLogFileSizeMb: 10,
+      maxLogFiles: 10,
+      thumbnailCacheDays: 30,
+      respectUserYtdlpConfig: false,
+      proxyUrl: '',
+      maxMemoryMb: 0,
+    },
+    sitePolicy: {
+      mode: 'allow-all',
+      patterns: [],
     },
     shortcuts: [
       { action: 'playPause', key: 'Space', modifiers: [] },
@@ -102,6 +136,7 @@ export class ConfigManager {
       if (existsSync(this.configFile)) {
         const data = readFileSync(this.configFile, 'utf-8')
         const storedConfig = JSON.parse(data)
+        this.lastWrittenContent = data
 
         // Deep merge with defaults
         this.config = this.deepMerge(this.DEFAULT_CONFIG, storedConfig)
@@ -116,14 +151,110 @@ export class ConfigManager {
       this.logger.error('Failed to load configuration, using defaults', error as Error)
       this.config = { ...this.DEFAULT_CONFIG }
     }
+    this.applyLoggingSettings()
+  }
+
+  /**
+   * Push log rotation settings to the logger (it can't read config itself)
+   */
+  private applyLoggingSettings(): void {
+    const { maxLogFileSizeMb, maxLogFiles } = this.config.advanced
+    this.logger.configureRotation(maxLogFileSizeMb, maxLogFiles)
+  }
+
+  /**
+   * Re-read configuration from disk (e.g. after the file was replaced by a restore)
+   */
+  reload(): void {
+    this.loadConfig()
+  }
+
+  /**
+   * Watch config.json for external changes. Valid changes replace the in-memory config
+   * and are passed to onChange, which applies them to services that keep their own copy
+   * (e.g. DownloadManager.updateConfig); invalid or unparseable files are ignored (and logged).
+   */
+  watchConfigFile(onChange: (config: AppConfig) => void): void {
+    if (this.configWatcher) {
+      return
+    }
+
+    // Watch the directory rather than the file: many editors and sync tools replace the
+    // file (write temp + rename), which would leave a file watcher on a stale inode
+    const configName = basename(this.configFile)
+    try {
+      this.configWatcher = watch(dirname(this.configFile), (_eventType, filename) => {
+        if (filename && filename.toString() !== configName) {
+          return
+        }
+        if (this.watchDebounceTimer) clearTimeout(this.watchDebounceTimer)
+        this.watchDebounceTimer = setTimeout(() => {
+          this.watchDebounceTimer = null
+          this.reloadExternalChange(onChange)
+        }, CONFIG_WATCH_DEBOUNCE)
+      })
+      this.configWatcher.on('error', error => {
+        this.logger.warn('Config file watcher failed', { error: error.message })
+        this.stopWatchingConfigFile()
+      })
+      this.logger.info('Watching configuration file for external changes', { path: this.configFile })
+    } catch (error) {
+      this.logger.warn('Failed to watch configuration file', { error: (error as Error).message })
+    }
+  }
+
+  stopWatchingConfigFile(): void {
+    if (this.watchDebounceTimer) {
+      clearTimeout(this.watchDebounceTimer)
+      this.watchDebounceTimer = null
+    }
+    this.configWatcher?.close()
+    this.configWatcher = null
+  }
+
+  private reloadExternalChange(onChange: (config: AppConfig) => void): void {
+    if (!existsSync(this.configFile)) {
+      return
+    }
+
+    let content: string
+    let merged: AppConfig
+    try {
+      content = readFileSync(this.configFile, 'utf-8')
+      // Our own write (or a touch that didn't change anything)
+      if (content === this.lastWrittenContent) {
+        return
+      }
+      merged = this.deepMerge(this.DEFAULT_CONFIG, JSON.parse(content))
+    } catch (error) {
+      this.logger.warn('Ignoring external config change that could not be parsed', {
+        error: (error as Error).message,
+      })
+      return
+    }
+
+    const { isValid, errors } = this.validate(merged)
+    if (!isValid) {
+      this.logger.warn('Ignoring invalid external config change', { errors })
+      return
+    }
+
+    this.config = merged
+    this.lastWrittenContent = content
+    this.applyLoggingSettings()
+    this.logger.info('Configuration reloaded after external change')
+    onChange(this.getAll())
   }
 
   /**
    * Save configuration to disk
    */
   private saveConfig(): void {
+    // Every change goes through here, so settings that live outside config are applied too
+    this.applyLoggingSettings()
     try {
       const data = JSON.stringify(this.config, null, 2)
+      this.lastWrittenContent = data
       writeFileSync(this.configFile, data, 'utf-8')
       this.logger.debug('Configuration saved to disk')
     } catch (error) {
@@ -179,6 +310,13 @@ export class ConfigManager {
     this.logger.info('Configuration batch updated', { updates })
   }
 
+  /**
+   * The configuration these updates would give, without applying them
+   */
+  preview(updates: Partial<AppConfig>): AppConfig {
+    return this.deepMerge(this.config, updates)
+  }
+
   /**
    * Reset configuration to defaults
    */
@@ -189,27 +327,27 @@ export class ConfigManager {
   }
 
   /**
-   * Validate configuration
+   * Validate configuration (the current one unless another is given)
    */
-  validate(): { isValid: boolean; errors: string[] } {
+  validate(config: AppConfig = this.config): { isValid: boolean; errors: string[] } {
     const errors: string[] = []
 
     // Validate download settings
-    if (this.config.download.maxConcurrentDownloads < 1) {
+    if (config.download.maxConcurrentDownloads < 1) {
       errors.push('maxConcurrentDownloads must be at least 1')
     }
 
-    if (this.config.download.timeoutMs < 1000) {
+    if (config.download.timeoutMs < 1000) {
       errors.push('timeoutMs must be at least 1000ms')
     }
 
     // Validate theme
-    if (!['light', 'dark', 'system'].includes(this.config.theme)) {
+    if (!['light', 'dark', 'system'].includes(config.theme)) {
       errors.push('theme must be one of: light, dark, system')
     }
 
     // Validate cache settings
-    if (this.config.cache.maxSize < 1024 * 1024) {
+    if (config.cache.maxSize < 1024 * 1024) {
       // 1MB minimum
       errors.push('cache.maxSize must be at least 1MB')
     }
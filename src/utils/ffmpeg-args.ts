@@ -0,0 +1,570 @@
+/**
+ * FFmpeg Argument Helpers
+ * Encoder selection, container/codec compatibility, filter chains and progress math
+ * shared by exports (H.264/H.265/VP9/AV1 video, GIF palette passes, image sequences).
+ */
+
+import type {
+  Effect,
+  EffectDefinition,
+  EffectType,
+  ExportAudioCodec,
+  ExportAudioFormat,
+  ExportFormat,
+  EditingTranscodeProfile,
+  ExportVideoCodec,
+  GifDither,
+  GifExportOptions,
+  KenBurnsEffect,
+  KenBurnsFrame,
+  ProjectExportSettings,
+  SlideshowOptions,
+  TextProperties,
+} from '../types/project'
+import type { AudioExtractFormat } from '../types/download'
+
+type ExportSettingsQuality = ProjectExportSettings['quality']
+
+export const DEFAULT_GIF_FPS = 15
+export const GIF_MAX_FPS = 30
+const DEFAULT_GIF_DITHER: GifDither = 'sierra2_4a'
+export const GIF_DITHERS: GifDither[] = ['none', 'bayer', 'floyd_steinberg', 'sierra2_4a']
+
+/** Numbered frame pattern for image sequence exports (frame_000001.png, ...) */
+export const PNG_SEQUENCE_PATTERN = 'frame_%06d.png'
+
+const TEXT_EDGE_MARGIN = 0.05 // Fraction of the frame width kept clear beside left/right aligned text
+const KEN_BURNS_MAX_ZOOM = 10
+const SLIDESHOW_SUPERSAMPLE = 2 // Zoom over an upscaled image so the motion doesn't step in whole pixels
+const SLIDESHOW_ZOOM_PER_FRAME = 0.001
+const SLIDESHOW_MAX_ZOOM = 1.5
+const FRAME_SEEK_PRE_ROLL = 2 // Seconds decoded before a single-frame grab, see getFrameSeek
+
+/**
+ * Quote a file path for use as a filter option value. Forward slashes avoid backslash
+ * escaping; ':' separates options, so it is escaped even inside quotes.
+ */
+export function escapeFilterPath(filePath: string): string {
+  const escaped = filePath.replace(/\\/g, '/').replace(/:/g, '\\:')
+  return `'${escaped.replace(/'/g, `'\\''`)}'`
+}
+
+/**
+ * '#rrggbb[aa]' to FFmpeg's '0xrrggbb[aa]'; color names pass through. Null if invalid.
+ */
+export function toFfmpegColor(color: string): string | null {
+  const hex = color.trim().match(/^#([0-9a-f]{6}(?:[0-9a-f]{2})?)$/i)
+  if (hex) return `0x${hex[1]}`
+  return /^[a-z]+$/i.test(color.trim()) ? color.trim() : null
+}
+
+/**
+ * drawtext filter for a text overlay. The text is read from textFilePath (with expansion
+ * off) so it needs no escaping, whatever characters it contains.
+ */
+export function buildDrawtextFilter(text: TextProperties, textFilePath: string): string {
+  const x = {
+    left: `w*${TEXT_EDGE_MARGIN}`,
+    center: '(w-text_w)/2',
+    right: `w-text_w-w*${TEXT_EDGE_MARGIN}`,
+  }[text.align]
+  const y = `h*${Math.min(Math.max(text.y ?? 0.5, 0), 1)}-text_h/2`
+
+  const options = [
+    `textfile=${escapeFilterPath(textFilePath)}`,
+    'expansion=none',
+    `fontsize=${Math.round(text.fontSize)}`,
+    `fontcolor=${toFfmpegColor(text.color) ?? 'white'}`,
+    `x=${x}`,
+    `y=${y}`,
+  ]
+  if (text.fontFile) {
+    options.push(`fontfile=${escapeFilterPath(text.fontFile)}`)
+  }
+  return `drawtext=${options.join(':')}`
+}
+
+function clampKenBurnsFrame(frame: KenBurnsFrame): KenBurnsFrame {
+  const clamp = (value: number, min: number, max: number) => Math.min(Math.max(Number(value) || min, min), max)
+  return { zoom: clamp(frame.zoom, 1, KEN_BURNS_MAX_ZOOM), x: clamp(frame.x, 0, 1), y: clamp(frame.y, 0, 1) }
+}
+
+/**
+ * zoompan filter that moves the view from effect.start to effect.end over the clip, one
+ * output frame per input frame of a looped still. zoompan keeps the view inside the image.
+ */
+export function buildKenBurnsFilter(
+  effect: KenBurnsEffect,
+  width: number,
+  height: number,
+  frameRate: number,
+  duration: number,
+): string {
+  const start = clampKenBurnsFrame(effect.start)
+  const end = clampKenBurnsFrame(effect.end)
+  const lastFrame = Math.max(1, Math.round(duration * frameRate) - 1)
+  const lerp = (from: number, to: number) => `(${from}+${(to - from).toFixed(6)}*min(on/${lastFrame},1))`
+
+  return [
+    `zoompan=z='${lerp(start.zoom, end.zoom)}'`,
+    `x='${lerp(start.x, end.x)}*iw-iw/zoom/2'`,
+    `y='${lerp(start.y, end.y)}*ih-ih/zoom/2'`,
+    'd=1',
+    `s=${width}x${height}`,
+    `fps=${frameRate}`,
+  ].join(':')
+}
+
+/**
+ * filter_complex for a slideshow of `imageCount` looped stills (inputs 0..n-1, each
+ * durationPerImage + transitionDuration long, so it still fills the frame while fading
+ * out). Slides are fitted to the frame, optionally slowly zoomed into, and crossfaded
+ * into each other (or concatenated when there is no transition); the result is [vout].
+ */
+export function buildSlideshowFilter(
+  imageCount: number,
+  options: Required<Omit<SlideshowOptions, 'audioPath'>>,
+): string {
+  const { durationPerImage, transitionDuration, fps, width, height, kenBurns } = options
+  const slideDuration = durationPerImage + transitionDuration
+  const fit = (w: number, h: number) =>
+    `scale=${w}:${h}:force_original_aspect_ratio=decrease,pad=${w}:${h}:(ow-iw)/2:(oh-ih)/2`
+
+  let slideFilter = `${fit(width, height)},fps=${fps}`
+  if (kenBurns) {
+    const zoom = Math.min(1 + SLIDESHOW_ZOOM_PER_FRAME * Math.round(slideDuration * fps), SLIDESHOW_MAX_ZOOM)
+    const center = { x: 0.5, y: 0.5 }
+    const zoompan = buildKenBurnsFilter(
+      { start: { zoom: 1, ...center }, end: { zoom, ...center } },
+      width,
+      height,
+      fps,
+      slideDuration,
+    )
+    slideFilter = `${fit(width * SLIDESHOW_SUPERSAMPLE, height * SLIDESHOW_SUPERSAMPLE)},${zoompan}`
+  }
+
+  const filters: string[] = []
+  for (let i = 0; i < imageCount; i++) {
+    filters.push(`[${i}:v]${slideFilter},setsar=1,format=yuv420p,setpts=PTS-STARTPTS[s${i}]`)
+  }
+
+  if (imageCount === 1 || transitionDuration <= 0) {
+    const inputs = Array.from({ length: imageCount }, (_, i) => `[s${i}]`).join('')
+    filters.push(`${inputs}concat=n=${imageCount}:v=1:a=0[vout]`)
+    return filters.join(';')
+  }
+
+  // Slide k starts fading in at k * durationPerImage, while slide k-1 is in its extra tail
+  let previous = 's0'
+  for (let k = 1; k < imageCount; k++) {
+    const next = k === imageCount - 1 ? 'vout' : `x${k}`
+    const offset = (k * durationPerImage).toFixed(3)
+    filters.push(`[${previous}][s${k}]xfade=transition=fade:duration=${transitionDuration}:offset=${offset}[${next}]`)
+    previous = next
+  }
+  return filters.join(';')
+}
+
+/** Effects a clip's effect chain can use, in the order the editor lists them */
+export const EFFECT_DEFINITIONS: EffectDefinition[] = [
+  {
+    type: 'vignette',
+    label: 'Vignette',
+    params: [{ name: 'strength', label: 'Strength', min: 0, max: 1, step: 0.05, default: 0.5 }],
+  },
+  {
+    type: 'film-grain',
+    label: 'Film Grain',
+    params: [{ name: 'amount', label: 'Amount', min: 0, max: 100, step: 1, default: 20 }],
+  },
+  {
+    type: 'chromatic-aberration',
+    label: 'Chromatic Aberration',
+    params: [{ name: 'shift', label: 'Shift (px)', min: 0, max: 20, step: 1, default: 4 }],
+  },
+  {
+    type: 'glow',
+    label: 'Glow',
+    params: [
+      { name: 'radius', label: 'Radius', min: 1, max: 50, step: 1, default: 10 },
+      { name: 'intensity', label: 'Intensity', min: 0, max: 1, step: 0.05, default: 0.5 },
+    ],
+  },
+  {
+    type: 'old-film',
+    label: 'Old Film',
+    params: [
+      { name: 'grain', label: 'Grain', min: 0, max: 100, step: 1, default: 25 },
+      { name: 'flicker', label: 'Flicker', min: 0, max: 1, step: 0.05, default: 0.5 },
+    ],
+  },
+]
+
+export function isEffectType(type: unknown): type is EffectType {
+  return EFFECT_DEFINITIONS.some(definition => definition.type === type)
+}
+
+/**
+ * Effect params clamped to their ranges, with defaults for missing ones
+ */
+export function resolveEffectParams(effect: Pick<Effect, 'effectType' | 'params'>): Record<string, number> {
+  const definition = EFFECT_DEFINITIONS.find(candidate => candidate.type === effect.effectType)
+  const params: Record<string, number> = {}
+  for (const param of definition?.params ?? []) {
+    const value = effect.params?.[param.name]
+    params[param.name] = Number.isFinite(value) ? Math.min(Math.max(value, param.min), param.max) : param.default
+  }
+  return params
+}
+
+/**
+ * Filter chain for one effect. `label` must be unique in the graph: effects that blend
+ * the frame with a processed copy of itself (glow) need named pads.
+ */
+export function buildEffectFilter(effect: Pick<Effect, 'effectType' | 'params'>, label: string): string {
+  const params = resolveEffectParams(effect)
+  switch (effect.effectType) {
+    case 'vignette':
+      return `vignette=angle=${(params.strength * (Math.PI / 2)).toFixed(4)}`
+    case 'film-grain':
+      return `noise=alls=${params.amount}:allf=t+u`
+    case 'chromatic-aberration':
+      return `rgbashift=rh=${-params.shift}:bh=${params.shift}`
+    case 'glow':
+      // Screen-blend a blurred copy over the frame, in RGB so the chroma planes aren't brightened
+      return [
+        `format=gbrp,split[${label}a][${label}b]`,
+        `[${label}b]gblur=sigma=${params.radius}[${label}c]`,
+        `[${label}a][${label}c]blend=all_mode=screen:all_opacity=${params.intensity}`,
+      ].join(';')
+    case 'old-film': {
+      const sepia = 'colorchannelmixer=.393:.769:.189:0:.349:.686:.168:0:.272:.534:.131'
+      const flicker = `eq=brightness=${(params.flicker * 0.04).toFixed(4)}*sin(2*PI*t*6):eval=frame`
+      return `${sepia},noise=alls=${params.grain}:allf=t+u,${flicker},vignette=angle=PI/4`
+    }
+    default:
+      return 'null'
+  }
+}
+
+/**
+ * A clip's whole effect chain, or null when it has none
+ */
+export function buildEffectChainFilter(effects: Effect[] | undefined, labelPrefix: string): string | null {
+  if (!effects || effects.length === 0) {
+    return null
+  }
+  return effects.map((effect, index) => buildEffectFilter(effect, `${labelPrefix}fx${index}`)).join(',')
+}
+
+/**
+ * atempo chain for a playback speed. One atempo only takes 0.5-2 (0.5-100 in newer
+ * builds), so speeds outside that are split into steps whose product is the speed.
+ */
+export function buildAtempoFilter(speed: number): string {
+  let remaining = speed > 0 ? speed : 1
+  const steps: number[] = []
+  while (remaining < 0.5) {
+    steps.push(0.5)
+    remaining /= 0.5
+  }
+  while (remaining > 2) {
+    steps.push(2)
+    remaining /= 2
+  }
+  steps.push(Number(remaining.toFixed(6)))
+  return steps.map(step => `atempo=${step}`).join(',')
+}
+
+export function isGifDither(value: unknown): value is GifDither {
+  return GIF_DITHERS.includes(value as GifDither)
+}
+
+/**
+ * GIF options as they may go into a filter graph: fps and maxWidth as positive integers (fps
+ * capped at GIF_MAX_FPS), anything else left unset so the defaults apply. Throws on an
+ * unknown dither.
+ */
+export function normalizeGifOptions(options: GifExportOptions): GifExportOptions {
+  if (options.dither !== undefined && !isGifDither(options.dither)) {
+    throw new Error(`Unsupported GIF dither: ${options.dither}`)
+  }
+  const toPositiveInteger = (value: unknown) => {
+    const number = Math.round(Number(value))
+    return Number.isFinite(number) && number >= 1 ? number : undefined
+  }
+  const fps = toPositiveInteger(options.fps)
+  return {
+    fps: fps === undefined ? undefined : Math.min(fps, GIF_MAX_FPS),
+    maxWidth: toPositiveInteger(options.maxWidth),
+    dither: options.dither,
+  }
+}
+
+/**
+ * fps/scale chain applied before both palette passes, so the palette matches the output frames
+ */
+export function buildGifFrameFilter(options: GifExportOptions = {}, sourceFps?: number): string {
+  const { fps: requestedFps, maxWidth } = normalizeGifOptions({ fps: options.fps, maxWidth: options.maxWidth })
+  let fps = requestedFps ?? DEFAULT_GIF_FPS
+  if (sourceFps && sourceFps > 0) {
+    fps = Math.min(fps, sourceFps)
+  }
+
+  const filters = [`fps=${fps}`]
+  if (maxWidth) {
+    filters.push(`scale='min(${maxWidth},iw)':-1:flags=lanczos`)
+  }
+  return filters.join(',')
+}
+
+/** Pass one: build an optimized 256-color palette (weighted toward moving areas) */
+export function buildPaletteGenFilter(): string {
+  return 'palettegen=stats_mode=diff'
+}
+
+/** Pass two: map frames onto the palette */
+export function buildPaletteUseFilter(dither: GifDither = DEFAULT_GIF_DITHER): string {
+  return `paletteuse=dither=${isGifDither(dither) ? dither : DEFAULT_GIF_DITHER}:diff_mode=rectangle`
+}
+
+/**
+ * Split a seek to one frame into a fast input-side seek and a short output-side one. The
+ * input seek jumps to a point FRAME_SEEK_PRE_ROLL before the frame; decoding the rest and
+ * dropping it with the output seek makes the frame exact even in files whose input seeking
+ * lands off target (e.g. WebM/MKV without a cue index).
+ */
+export function getFrameSeek(time: number): { inputSeek: number; outputSeek: number } {
+  const target = Math.max(0, time)
+  const inputSeek = Math.max(0, target - FRAME_SEEK_PRE_ROLL)
+  return { inputSeek: Number(inputSeek.toFixed(3)), outputSeek: Number((target - inputSeek).toFixed(3)) }
+}
+
+/**
+ * Overall progress (0-100) of a multi-pass job, given the fraction (0-1) of the current pass.
+ * Passes are weighted equally, e.g. two passes report 0-50% then 50-100%.
+ */
+export function getPassProgress(fraction: number, passIndex: number, passCount: number): number {
+  const clamped = Math.min(1, Math.max(0, fraction))
+  return ((passIndex + clamped) / Math.max(1, passCount)) * 100
+}
+
+/** Video/audio codecs each container can hold */
+export const EXPORT_FORMAT_CODECS: Record<
+  ExportFormat,
+  { label: string; videoCodecs: ExportVideoCodec[]; audioCodecs: ExportAudioCodec[] }
+> = {
+  mp4: { label: 'MP4', videoCodecs: ['h264', 'h265', 'av1'], audioCodecs: ['aac', 'mp3', 'opus'] },
+  mkv: { label: 'MKV', videoCodecs: ['h264', 'h265', 'vp9', 'av1'], audioCodecs: ['aac', 'mp3', 'opus'] },
+  mov: { label: 'MOV', videoCodecs: ['h264', 'h265'], audioCodecs: ['aac', 'mp3'] },
+  webm: { label: 'WebM', videoCodecs: ['vp9', 'av1'], audioCodecs: ['opus'] },
+  gif: { label: 'GIF', videoCodecs: [], audioCodecs: [] },
+  'png-sequence': { label: 'PNG sequence', videoCodecs: [], audioCodecs: [] },
+}
+
+/** FFmpeg encoders per codec, in order of preference (SVT-AV1 is much faster than libaom) */
+export const VIDEO_ENCODERS: Record<ExportVideoCodec, string[]> = {
+  h264: ['libx264'],
+  h265: ['libx265'],
+  vp9: ['libvpx-vp9'],
+  av1: ['libsvtav1', 'libaom-av1'],
+}
+
+export const AUDIO_ENCODERS: Record<ExportAudioCodec, string> = {
+  aac: 'aac',
+  mp3: 'libmp3lame',
+  opus: 'libopus',
+}
+
+/** CRF values per quality; the scales differ between encoder families */
+const CRF_BY_ENCODER: Record<string, Record<ExportSettingsQuality, number>> = {
+  libx264: { low: 28, medium: 23, high: 18 },
+  libx265: { low: 30, medium: 26, high: 22 },
+  'libvpx-vp9': { low: 40, medium: 33, high: 24 },
+  libsvtav1: { low: 40, medium: 32, high: 24 },
+  'libaom-av1': { low: 40, medium: 32, high: 24 },
+}
+const VIDEO_BITRATE_BY_QUALITY: Record<ExportSettingsQuality, number> = { low: 2500, medium: 5000, high: 10000 }
+
+/** Formats for audio-only project exports (the file extension), with their encoder */
+export const AUDIO_EXPORT_FORMATS: Record<ExportAudioFormat, { label: string; encoder: string; lossless: boolean }> = {
+  mp3: { label: 'MP3', encoder: 'libmp3lame', lossless: false },
+  m4a: { label: 'M4A (AAC)', encoder: 'aac', lossless: false },
+  flac: { label: 'FLAC', encoder: 'flac', lossless: true },
+  opus: { label: 'Opus', encoder: 'libopus', lossless: false },
+}
+
+export function isAudioExportFormat(format: unknown): format is ExportAudioFormat {
+  return typeof format === 'string' && format in AUDIO_EXPORT_FORMATS
+}
+
+/**
+ * Describe why the format/codec combination can't be exported, or null if it can
+ */
+export function getExportIncompatibility(
+  settings: Pick<ProjectExportSettings, 'format' | 'videoCodec' | 'audioCodec'>,
+): string | null {
+  const format = EXPORT_FORMAT_CODECS[settings.format]
+  if (!format) {
+    return `Unsupported export format: ${settings.format}`
+  }
+  if (format.videoCodecs.length === 0) {
+    return null // Image formats ignore the codec settings
+  }
+  if (!format.videoCodecs.includes(settings.videoCodec)) {
+    return `${settings.videoCodec.toUpperCase()} video cannot be stored in ${format.label}`
+  }
+  if (!format.audioCodecs.includes(settings.audioCodec)) {
+    return `${settings.audioCodec.toUpperCase()} audio cannot be stored in ${format.label}`
+  }
+  return null
+}
+
+/** Containers a file can be remuxed into, by file extension */
+export const REMUX_CONTAINERS = ['mp4', 'mkv', 'mov', 'm4v', 'webm', 'ts'] as const
+export type RemuxContainer = (typeof REMUX_CONTAINERS)[number]
+
+/**
+ * Codecs (ffprobe codec_name) each remux container takes as-is; null accepts anything.
+ * Opus in MP4/MOV is left out: FFmpeg can write it, but many players can't play it.
+ */
+export const REMUX_CONTAINER_CODECS: Record<RemuxContainer, { video: string[]; audio: string[] } | null> = {
+  mp4: { video: ['h264', 'hevc', 'av1', 'vp9', 'mpeg4'], audio: ['aac', 'mp3', 'ac3', 'eac3', 'alac', 'flac'] },
+  m4v: { video: ['h264', 'hevc', 'av1', 'mpeg4'], audio: ['aac', 'mp3', 'ac3', 'eac3', 'alac'] },
+  mov: { video: ['h264', 'hevc', 'mpeg4', 'prores'], audio: ['aac', 'mp3', 'ac3', 'alac', 'pcm_s16le', 'pcm_s24le'] },
+  webm: { video: ['vp8', 'vp9', 'av1'], audio: ['opus', 'vorbis'] },
+  ts: { video: ['h264', 'hevc', 'mpeg2video'], audio: ['aac', 'mp3', 'ac3', 'eac3', 'opus'] },
+  mkv: null,
+}
+
+/** Audio encoder used when a container can't take the source audio as-is */
+const REMUX_AUDIO_FALLBACK: Record<RemuxContainer, string> = {
+  mp4: 'aac',
+  m4v: 'aac',
+  mov: 'aac',
+  webm: 'libopus',
+  ts: 'aac',
+  mkv: 'aac',
+}
+
+/** Video encoder used when a container can't take the source video as-is */
+const REMUX_VIDEO_FALLBACK: Record<RemuxContainer, string> = {
+  mp4: 'libx264',
+  m4v: 'libx264',
+  mov: 'libx264',
+  webm: 'libvpx-vp9',
+  ts: 'libx264',
+  mkv: 'libx264',
+}
+
+export function isRemuxContainer(container: string): container is RemuxContainer {
+  return (REMUX_CONTAINERS as readonly string[]).includes(container)
+}
+
+/**
+ * Video encoder to re-encode with for a container, or null when the video can be copied
+ */
+export function getRemuxVideoEncoder(container: RemuxContainer, videoCodec: string | null): string | null {
+  return getRemuxIncompatibility(container, videoCodec) ? REMUX_VIDEO_FALLBACK[container] : null
+}
+
+/**
+ * Describe why a video codec can't be copied into the container, or null if it can
+ */
+export function getRemuxIncompatibility(container: RemuxContainer, videoCodec: string | null): string | null {
+  const codecs = REMUX_CONTAINER_CODECS[container]
+  if (!codecs || videoCodec === null || codecs.video.includes(videoCodec)) {
+    return null
+  }
+  return `${videoCodec.toUpperCase()} video cannot be stored in ${container.toUpperCase()} without re-encoding`
+}
+
+/**
+ * Encoder to re-encode the audio with, or null when it can be copied. Audio is cheap to
+ * re-encode and barely loses quality, so it never blocks a remux.
+ */
+export function getRemuxAudioEncoder(container: RemuxContainer, audioCodec: string | null): string | null {
+  const codecs = REMUX_CONTAINER_CODECS[container]
+  if (!codecs || audioCodec === null || codecs.audio.includes(audioCodec)) {
+    return null
+  }
+  return REMUX_AUDIO_FALLBACK[container]
+}
+
+/** Audio-only formats for extraction, with their encoder; FLAC is lossless and ignores the bitrate */
+export const AUDIO_EXTRACT_FORMATS: Record<AudioExtractFormat, { encoder: string; lossless: boolean }> = {
+  mp3: { encoder: 'libmp3lame', lossless: false },
+  m4a: { encoder: 'aac', lossless: false },
+  flac: { encoder: 'flac', lossless: true },
+}
+
+/**
+ * Encoding of each editing transcode profile. A keyframe every second keeps seeking and
+ * frame-accurate trims fast; H.264 in MP4 plays everywhere the editor previews.
+ */
+export const EDITING_TRANSCODE_PROFILES: Record<EditingTranscodeProfile, { crf: number; maxHeight: number | null }> = {
+  edit: { crf: 18, maxHeight: null },
+  proxy: { crf: 23, maxHeight: 720 },
+}
+
+export function isEditingTranscodeProfile(value: unknown): value is EditingTranscodeProfile {
+  return typeof value === 'string' && value in EDITING_TRANSCODE_PROFILES
+}
+
+/**
+ * Output arguments for an editing transcode
+ */
+export function getEditingTranscodeArgs(profile: EditingTranscodeProfile): string[] {
+  const { crf, maxHeight } = EDITING_TRANSCODE_PROFILES[profile]
+  const args = ['-map', '0:v:0', '-map', '0:a:0?', '-c:v', 'libx264', '-preset', 'veryfast', '-crf', String(crf)]
+  if (maxHeight) {
+    args.push('-vf', `scale=-2:'min(${maxHeight},ih)'`)
+  }
+  args.push('-pix_fmt', 'yuv420p', '-force_key_frames', 'expr:gte(t,n_forced*1)')
+  args.push('-c:a', 'aac', '-b:a', '192k', '-movflags', '+faststart')
+  return args
+}
+
+/**
+ * Whether an FFmpeg encoder runs on the GPU or a media engine (NVENC, Quick Sync, AMF,
+ * VA-API, VideoToolbox, Media Foundation, V4L2) rather than the CPU
+ */
+export function isHardwareEncoder(encoder: string): boolean {
+  return /_(nvenc|qsv|amf|vaapi|videotoolbox|mf|v4l2m2m)$/.test(encoder)
+}
+
+/**
+ * First installed encoder for a codec, or null
+ */
+export function selectVideoEncoder(codec: ExportVideoCodec, availableEncoders: Set<string>): string | null {
+  return VIDEO_ENCODERS[codec]?.find(encoder => availableEncoders.has(encoder)) ?? null
+}
+
+/**
+ * Rate control and speed arguments for a video encoder
+ */
+export function buildVideoEncoderArgs(encoder: string, settings: ProjectExportSettings): string[] {
+  const args = ['-c:v', encoder]
+  const useBitrate = settings.rateControl === 'bitrate'
+  const bitrate = `${Math.round(settings.videoBitrate || VIDEO_BITRATE_BY_QUALITY[settings.quality] || 5000)}k`
+  const crf = String(CRF_BY_ENCODER[encoder]?.[settings.quality] ?? 23)
+
+  switch (encoder) {
+    case 'libvpx-vp9':
+      // -b:v 0 makes -crf constant quality instead of a quality cap on a bitrate target
+      args.push('-b:v', useBitrate ? bitrate : '0', ...(useBitrate ? [] : ['-crf', crf]))
+      args.push('-row-mt', '1', '-deadline', 'good', '-cpu-used', '2')
+      break
+    case 'libaom-av1':
+      args.push('-b:v', useBitrate ? bitrate : '0', ...(useBitrate ? [] : ['-crf', crf]))
+      args.push('-row-mt', '1', '-cpu-used', '6')
+      break
+    case 'libsvtav1':
+      args.push(...(useBitrate ? ['-b:v', bitrate] : ['-crf', crf]), '-preset', '8')
+      break
+    default:
+      args.push(...(useBitrate ? ['-b:v', bitrate] : ['-crf', crf]), '-preset', settings.preset || 'medium')
+  }
+  return args
+}
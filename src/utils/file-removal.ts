@@ -0,0 +1,58 @@
+/**
+ * File Removal Utilities
+ * Deletes user files (downloaded videos) via the OS recycle bin by default.
+ *
+ * If the trash is unavailable (common on network shares) the file is left alone and
+ * TRASH_UNAVAILABLE is reported, so the caller can ask before deleting permanently.
+ * Regenerable files (cache, temp) don't go through here and are always deleted outright.
+ */
+
+import { existsSync, unlinkSync } from 'fs'
+import { basename } from 'path'
+import { shell } from 'electron'
+
+import type { FileRemovalResult } from '../types/download'
+
+/** Moves a file to the recycle bin; injectable so callers can substitute the platform call */
+export type TrashFunction = (filePath: string) => Promise<void>
+
+const trashWithShell: TrashFunction = filePath => shell.trashItem(filePath)
+
+/**
+ * Remove a file, to the recycle bin unless permanent is set
+ */
+export async function removeFile(
+  filePath: string,
+  permanent: boolean,
+  trash: TrashFunction = trashWithShell,
+): Promise<FileRemovalResult> {
+  if (!existsSync(filePath)) {
+    return { path: filePath, method: 'missing' }
+  }
+
+  if (permanent) {
+    try {
+      unlinkSync(filePath)
+      return { path: filePath, method: 'permanent' }
+    } catch (error) {
+      return {
+        path: filePath,
+        method: 'permanent',
+        code: 'DELETE_FAILED',
+        error: `Failed to delete ${basename(filePath)}: ${(error as Error).message}`,
+      }
+    }
+  }
+
+  try {
+    await trash(filePath)
+    return { path: filePath, method: 'trash' }
+  } catch (error) {
+    return {
+      path: filePath,
+      method: 'trash',
+      code: 'TRASH_UNAVAILABLE',
+      error: `Could not move ${basename(filePath)} to the recycle bin: ${(error as Error).message}`,
+    }
+  }
+}
@@ -0,0 +1,100 @@
+/**
+ * Message Catalog
+ * Formats user-facing errors raised in the main process from the renderer's language packs
+ * (the error* keys in localization/languages). Errors carry the English text plus a message
+ * key and params, so the renderer can show them in the UI language.
+ *
+ * Templates use i18next-style {{param}} placeholders. Keys missing from a language fall
+ * back to English.
+ */
+
+import type { ErrorResponse } from '../types/api'
+import { type DownloadError, DownloadErrorCode, createDownloadError } from '../types/download'
+import languages, { type Language } from '../localization/languages'
+import { en } from '../localization/languages/en'
+import { es } from '../localization/languages/es'
+import { fr } from '../localization/languages/fr'
+
+export type MessageParams = Record<string, string | number>
+
+/** Error messages in the language packs */
+export type MessageKey = Extract<keyof typeof en.translation, `error${string}`>
+
+const catalogs: Record<string, Partial<Record<MessageKey, string>>> = {
+  en: en.translation,
+  es: es.translation,
+  fr: fr.translation,
+}
+
+export function getAvailableLanguages(): Language[] {
+  return languages.filter(language => language.key in catalogs)
+}
+
+export function isMessageKey(key: unknown): key is MessageKey {
+  return typeof key === 'string' && key.startsWith('error') && key in en.translation
+}
+
+/**
+ * Fill a template's {{param}} placeholders; unknown params are left as-is
+ */
+export function interpolate(template: string, params: MessageParams = {}): string {
+  return template.replace(/\{\{\s*(\w+)\s*\}\}/g, (placeholder, name: string) =>
+    name in params ? String(params[name]) : placeholder,
+  )
+}
+
+/**
+ * Message text in a language (English by default), falling back to English
+ */
+export function formatMessage(key: MessageKey, params?: MessageParams, language: string = 'en'): string {
+  const base = language.toLowerCase().split(/[-_]/)[0]
+  return interpolate(catalogs[base]?.[key] ?? en.translation[key], params)
+}
+
+/**
+ * Error response with the English text plus the key and params to localize it
+ */
+export function createLocalizedErrorResponse(
+  key: MessageKey,
+  params: MessageParams | undefined,
+  code: string,
+  retryable?: boolean,
+): ErrorResponse {
+  return {
+    success: false,
+    error: formatMessage(key, params),
+    code,
+    retryable,
+    messageKey: key,
+    params,
+    timestamp: Date.now(),
+  }
+}
+
+/**
+ * DownloadError with the English text plus the key and params to localize it
+ */
+export function createLocalizedDownloadError(
+  key: MessageKey,
+  params: MessageParams | undefined,
+  code: DownloadErrorCode,
+  originalError?: Error,
+  retryable: boolean = false,
+): DownloadError {
+  const error = createDownloadError(formatMessage(key, params), code, originalError, retryable)
+  return Object.assign(error, { messageKey: key, params })
+}
+
+/**
+ * Text to show for an error from the main process: localized when it carries a known key
+ */
+export function localizeError(
+  error: { error?: string; message?: string; messageKey?: string; params?: MessageParams } | null | undefined,
+  language: string,
+  fallback: string,
+): string {
+  if (error && isMessageKey(error.messageKey)) {
+    return formatMessage(error.messageKey, error.params, language)
+  }
+  return error?.error || error?.message || fallback
+}
@@ -1,9 +1,10 @@
 /**
  * Logger Utility
- * Structured logging with file output and console output
+ * Structured logging with file output and console output.
+ * Log files rotate by size; only the newest maxLogFiles files are kept.
  */
 
-import { WriteStream, createWriteStream } from 'fs'
+import { WriteStream, createWriteStream, existsSync, readdirSync, statSync, unlinkSync } from 'fs'
 
 import { PlatformUtils } from './platform'
 import { join } from 'path'
@@ -31,6 +32,9 @@ export class Logger {
   private writeStream: WriteStream | null = null
   private logBuffer: LogEntry[] = []
   private maxBufferSize = 100
+  private currentFileSize = 0
+  private maxFileSize = 10 * 1024 * 1024 // 10MB
+  private maxFiles = 10
   private isInitialized = false
   private platform = PlatformUtils.getInstance()
 
@@ -54,6 +58,7 @@ export class Logger {
     try {
       // Ensure logs directory exists
       this.platform.ensureDirectory(this.logsDir)
+      this.currentFileSize = existsSync(this.currentLogFile) ? statSync(this.currentLogFile).size : 0
 
       // Create write stream
       this.writeStream = createWriteStream(this.currentLogFile, {
@@ -83,6 +88,14 @@ export class Logger {
     }
   }
 
+  /**
+   * Set the size at which the log file rotates and how many log files to keep
+   */
+  configureRotation(maxFileSizeMb: number, maxFiles: number): void {
+    this.maxFileSize = Math.max(1, maxFileSizeMb) * 1024 * 1024
+    this.maxFiles = Math.max(1, Math.floor(maxFiles))
+  }
+
   /**
    * Set log level
    */
@@ -169,7 +182,7 @@ export class Logger {
   private writeToFile(entry: LogEntry): void {
     if (this.writeStream && this.isInitialized) {
       try {
-        this.writeStream.write(`${JSON.stringify(entry)}\n`)
+        this.writeLine(`${JSON.stringify(entry)}\n`)
       } catch (error) {
         // If file writing fails, buffer the log
         this.bufferLog(entry)
@@ -186,13 +199,25 @@ export class Logger {
   private writeToStream(entry: LogEntry): void {
     if (this.writeStream) {
       try {
-        this.writeStream.write(`${JSON.stringify(entry)}\n`)
+        this.writeLine(`${JSON.stringify(entry)}\n`)
       } catch (error) {
         console.error('Failed to write to log stream:', error)
       }
     }
   }
 
+  /**
+   * Write a line, rotating first if it would push the file past the size limit
+   */
+  private writeLine(line: string): void {
+    const size = Buffer.byteLength(line)
+    if (this.currentFileSize > 0 && this.currentFileSize + size > this.maxFileSize) {
+      this.rotateLogFile()
+    }
+    this.writeStream?.write(line)
+    this.currentFileSize += size
+  }
+
   /**
    * Buffer log entry
    */
@@ -291,11 +316,16 @@ export class Logger {
   private generateLogFileName(): string {
     const now = new Date()
     const timestamp = now.toISOString().replace(/[:.]/g, '-').slice(0, 19)
-    return join(this.logsDir, `clipy-${timestamp}.log`)
+    // Several rotations can happen within a second when logging heavily
+    let fileName = join(this.logsDir, `clipy-${timestamp}.log`)
+    for (let i = 1; existsSync(fileName) || fileName === this.currentLogFile; i++) {
+      fileName = join(this.logsDir, `clipy-${timestamp}-${i}.log`)
+    }
+    return fileName
   }
 
   /**
-   * Rotate log file (create new file)
+   * Rotate log file (create new file), then drop the oldest files beyond the limit
    */
   rotateLogFile(): void {
     if (this.writeStream) {
@@ -304,6 +334,7 @@ export class Logger {
 
     this.currentLogFile = this.generateLogFileName()
     this.initializeLogger()
+    this.cleanupOldLogs()
   }
 
   /**
@@ -321,11 +352,23 @@ export class Logger {
   }
 
   /**
-   * Cleanup old log files
+   * Delete the oldest app log files so at most maxFiles remain (including the current one)
    */
-  cleanupOldLogs(maxAge: number = 30 * 24 * 60 * 60 * 1000): void {
-    // This would clean up old log files - implementation can be added later
-    this.info('Log cleanup requested', { maxAge })
+  cleanupOldLogs(maxFiles: number = this.maxFiles): void {
+    try {
+      const logFiles = readdirSync(this.logsDir)
+        .filter(file => /^clipy-.*\.log$/.test(file))
+        .map(file => join(this.logsDir, file))
+        .filter(file => file !== this.currentLogFile)
+        .map(file => ({ file, modified: statSync(file).mtimeMs }))
+        .sort((a, b) => b.modified - a.modified)
+
+      for (const { file } of logFiles.slice(Math.max(0, maxFiles - 1))) {
+        unlinkSync(file)
+      }
+    } catch (error) {
+      console.error('Failed to clean up old log files:', error)
+    }
   }
 
   /**
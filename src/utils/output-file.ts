@@ -0,0 +1,113 @@
+/**
+ * Output File Utilities
+ * Renders write to a hidden partial file next to the destination and are moved into
+ * place only on success, so a failed or cancelled render never leaves a half-written
+ * file (or clobbers a previous good one) where the user expects their video.
+ *
+ * The partial file lives in the destination directory, keeping the final rename on
+ * the same volume and therefore atomic.
+ */
+
+import { existsSync, readdirSync, renameSync, rmSync, statSync } from 'fs'
+import { randomUUID } from 'crypto'
+import { basename, dirname, extname, isAbsolute, join, relative } from 'path'
+
+import { StorageManager } from '../services/storage-manager'
+import { PlatformUtils } from './platform'
+
+export const PARTIAL_OUTPUT_PREFIX = '.clipy-partial-'
+
+const MAX_SUFFIX = 9999
+
+/**
+ * Hidden sibling path to render into, keeping the extension so FFmpeg picks the right muxer
+ */
+export function getPartialOutputPath(finalPath: string): string {
+  return join(dirname(finalPath), `${PARTIAL_OUTPUT_PREFIX}${randomUUID()}${extname(finalPath)}`)
+}
+
+/**
+ * The path itself if free, otherwise the first free 'name (N).ext'
+ */
+export function getAvailableOutputPath(filePath: string, exists: (candidate: string) => boolean = existsSync): string {
+  if (!exists(filePath)) {
+    return filePath
+  }
+
+  const extension = extname(filePath)
+  const stem = basename(filePath, extension)
+  for (let suffix = 1; suffix <= MAX_SUFFIX; suffix++) {
+    const candidate = join(dirname(filePath), `${stem} (${suffix})${extension}`)
+    if (!exists(candidate)) {
+      return candidate
+    }
+  }
+  throw new Error(`No free file name for ${basename(filePath)}`)
+}
+
+/**
+ * Whether filePath is directory itself or somewhere below it
+ */
+export function isPathInside(filePath: string, directory: string): boolean {
+  const caseInsensitive = PlatformUtils.getInstance().getPlatformInfo().isWindows
+  const from = caseInsensitive ? directory.toLowerCase() : directory
+  const to = caseInsensitive ? filePath.toLowerCase() : filePath
+  const relativePath = relative(from, to)
+  return relativePath === '' || (!relativePath.startsWith('..') && !isAbsolute(relativePath))
+}
+
+/**
+ * App temp and cache directories; their contents get cleaned up, so nothing is exported there.
+ * Includes the configurable storage cache (editing transcodes, yt-dlp's cache) and temp folders.
+ */
+export function isProtectedOutputPath(filePath: string): boolean {
+  const platform = PlatformUtils.getInstance()
+  const storage = StorageManager.getInstance()
+  const appDataDir = platform.getAppDataDir('clipy')
+  const protectedDirs = [
+    platform.getTempDir('clipy'),
+    storage.getCacheDirectory(),
+    storage.getTempDirectory(),
+    join(appDataDir, 'video-cache'),
+    join(appDataDir, 'thumbnail-cache'),
+    join(appDataDir, 'media-cache'),
+  ]
+  return protectedDirs.some(directory => isPathInside(filePath, directory))
+}
+
+/**
+ * Move a finished partial output to its final path. A directory output (e.g. a PNG
+ * sequence) merged into an existing directory replaces same-named files only.
+ */
+function commitPartialOutput(partialPath: string, finalPath: string): void {
+  if (statSync(partialPath).isDirectory() && existsSync(finalPath)) {
+    for (const name of readdirSync(partialPath)) {
+      renameSync(join(partialPath, name), join(finalPath, name))
+    }
+    rmSync(partialPath, { recursive: true, force: true })
+    return
+  }
+  renameSync(partialPath, finalPath)
+}
+
+/**
+ * Resolve the destination (auto-renamed unless overwrite is set), run render against a
+ * partial path, then move the result into place. The partial output is removed if render
+ * throws. Returns the render result and the path actually written.
+ */
+export async function renderToPartialOutput<T>(
+  outputPath: string,
+  overwrite: boolean,
+  render: (partialPath: string) => Promise<T>,
+): Promise<{ result: T; outputPath: string }> {
+  const finalPath = overwrite ? outputPath : getAvailableOutputPath(outputPath)
+  const partialPath = getPartialOutputPath(finalPath)
+
+  try {
+    const result = await render(partialPath)
+    commitPartialOutput(partialPath, finalPath)
+    return { result, outputPath: finalPath }
+  } finally {
+    rmSync(partialPath, { recursive: true, force: true })
+  }
+}
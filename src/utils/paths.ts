@@ -0,0 +1,344 @@
+/**
+ * Path Utilities
+ * Builds download output paths from the user's download settings and video metadata,
+ * and the temp paths editor projects are autosaved to
+ */
+
+import { DownloadErrorCode, createDownloadError, isDownloadError } from '../types/download'
+import type { DownloadConfig, FinalContainer } from '../types/system'
+import type { DownloadOptions, OutputTemplatePreview, VideoInfo } from '../types/download'
+import { getAvailableOutputPath } from './output-file'
+import { PlatformUtils, truncateGraphemes } from './platform'
+import { basename, dirname, join } from 'path'
+import { createHash } from 'crypto'
+import { existsSync } from 'fs'
+import { homedir } from 'os'
+
+export type DownloadPathSettings = Pick<
+  DownloadConfig,
+  'downloadPath' | 'createSubdirectories' | 'includeDateInFilename' | 'filenameTemplate'
+>
+
+/** yt-dlp fills in the extension once the final container is known */
+export const EXTENSION_PLACEHOLDER = '.%(ext)s'
+export const DEFAULT_FILENAME_TEMPLATE = '%(title)s.%(ext)s'
+
+/** Extensions a finished yt-dlp download is looked for under */
+export const DOWNLOADED_FILE_EXTENSIONS = ['mp4', 'm4a', 'webm', 'mkv', 'mov', 'avi']
+
+const TEMPLATE_FIELD_PATTERN = /%\((\w+)\)s/g
+
+/** Template fields Clipy fills in from the video info; any other field is left for yt-dlp */
+const CLIPY_TEMPLATE_FIELDS = [
+  'id',
+  'title',
+  'uploader',
+  'channel',
+  'channel_id',
+  'upload_date',
+  'duration',
+  'view_count',
+  'resolution',
+  'ext',
+]
+
+/** Not allowed in a filename on Windows, the strictest platform, plus path separators */
+const ILLEGAL_FILENAME_CHARACTERS = /[<>:"|?*/\\\x00-\x1f]/g
+
+const AUDIO_FORMATS = ['mp3', 'm4a', 'opus']
+
+export interface OutputTemplateContext {
+  resolution?: string // e.g. '1920x1080'; left for yt-dlp when not given
+  extension?: string // Without the dot; %(ext)s is left for yt-dlp when not given
+}
+
+/** Autosaves are kept apart from saved projects by this extension */
+export const AUTOSAVE_EXTENSION = '.autosave.clipy'
+
+/**
+ * Path limits per platform. Windows counts UTF-16 code units against MAX_PATH (260 incl. NUL);
+ * Linux/macOS count UTF-8 bytes, 255 per component.
+ */
+const WINDOWS_MAX_PATH = 259
+const MAX_COMPONENT_LENGTH = 255
+const UNIX_MAX_PATH = 4095
+
+/**
+ * Longest suffix yt-dlp may put where %(ext)s is, e.g. '.f399.webm.part-Frag1234'
+ * for fragment downloads or '.temp.mp4' while merging
+ */
+const WORST_CASE_EXTENSION_LENGTH = 24
+
+/** Room kept for the hash suffix and a readable part of the title when truncating */
+const HASH_SUFFIX_LENGTH = 9 // '_' + 8 hex chars
+const MIN_NAME_LENGTH = 16
+
+/**
+ * Sanitize a single path component, replacing separators so it can't create subdirectories
+ */
+export function sanitizePathComponent(name: string, fallback: string): string {
+  const sanitized = PlatformUtils.getInstance().sanitizeFilename(name.replace(/[/\\]/g, '_'))
+  return sanitized || fallback
+}
+
+/**
+ * Folder project autosaves are written to
+ */
+export function getAutosaveDir(): string {
+  return PlatformUtils.getInstance().getTempDir('clipy')
+}
+
+/**
+ * Where a project is autosaved: <temp>/clipy/autosave_<projectId>.autosave.clipy
+ */
+export function getProjectAutosavePath(projectId: string): string {
+  if (!/^[\w-]+$/.test(projectId)) {
+    throw new Error('Invalid project ID')
+  }
+  return join(getAutosaveDir(), `autosave_${projectId}${AUTOSAVE_EXTENSION}`)
+}
+
+/**
+ * Escape literal text for a yt-dlp output template ('%' starts a field). The fields in
+ * keepFields stay fields, for yt-dlp to fill in.
+ */
+export function escapeOutputTemplate(value: string, keepFields: string[] = []): string {
+  return value.replace(/%(\((\w+)\)s)?/g, (match, _field, name?: string) =>
+    name && keepFields.includes(name) ? match : `%${match}`,
+  )
+}
+
+/**
+ * Fields in a template that Clipy doesn't fill in, so yt-dlp does
+ */
+export function getYtdlpTemplateFields(template: string): string[] {
+  const fields = Array.from(template.matchAll(TEMPLATE_FIELD_PATTERN), match => match[1])
+  return Array.from(new Set(fields.filter(field => !CLIPY_TEMPLATE_FIELDS.includes(field))))
+}
+
+/**
+ * Expand yt-dlp style %(field)s variables from video info. %(ext)s and %(resolution)s are
+ * filled in when the context has them and otherwise kept for yt-dlp, as are fields Clipy
+ * doesn't know. Known fields without a value become 'NA' like yt-dlp does.
+ */
+export function expandFilenameTemplate(
+  template: string,
+  videoInfo: VideoInfo,
+  context: OutputTemplateContext = {},
+): string {
+  const fields: Record<string, string | number | undefined> = {
+    id: videoInfo.id,
+    title: videoInfo.title,
+    uploader: videoInfo.channel.name,
+    channel: videoInfo.channel.name,
+    channel_id: videoInfo.channel.id,
+    upload_date: videoInfo.uploadDate,
+    duration: videoInfo.duration,
+    view_count: videoInfo.views,
+    ...(context.resolution ? { resolution: context.resolution } : {}),
+    ...(context.extension ? { ext: context.extension } : {}),
+  }
+
+  return template.replace(TEMPLATE_FIELD_PATTERN, (match, field: string) => {
+    if (!(field in fields)) return match
+    const value = fields[field]
+    return value === undefined || value === '' ? 'NA' : String(value)
+  })
+}
+
+export function isAudioOnlyFormat(format: string | undefined): boolean {
+  return !!format && AUDIO_FORMATS.includes(format)
+}
+
+/**
+ * Extension a download will end up with: the audio format for audio-only downloads, else
+ * the final container when one is set, else what yt-dlp merges into
+ */
+export function getExpectedExtension(options: DownloadOptions, finalContainer: FinalContainer = 'keep'): string {
+  if (isAudioOnlyFormat(options.format)) {
+    return options.format!
+  }
+  const container = options.finalContainer ?? finalContainer
+  if (container !== 'keep') {
+    return container
+  }
+  return options.format ?? 'mp4'
+}
+
+/**
+ * Path settings for a download: its own options over the download settings
+ */
+export function getDownloadPathSettings(options: DownloadOptions, settings: DownloadConfig): DownloadPathSettings {
+  return {
+    downloadPath: options.outputPath || settings.downloadPath || join(homedir(), 'Downloads', 'Clipy'),
+    createSubdirectories: options.createSubdirectories ?? settings.createSubdirectories,
+    includeDateInFilename: settings.includeDateInFilename,
+    filenameTemplate: options.filenameTemplate || settings.filenameTemplate,
+  }
+}
+
+/**
+ * Format a date as YYYYMMDD
+ */
+function formatDateStamp(date: Date): string {
+  const month = (date.getMonth() + 1).toString().padStart(2, '0')
+  const day = date.getDate().toString().padStart(2, '0')
+  return `${date.getFullYear()}${month}${day}`
+}
+
+/**
+ * Build the full yt-dlp output template for a video:
+ * downloadPath[/channel]/[YYYYMMDD_]<expanded filename template>.%(ext)s
+ *
+ * The date prefix is the upload date when known, otherwise today.
+ * Each component is sanitized and '%' is escaped, so only %(ext)s is left for yt-dlp.
+ */
+export function getDownloadPathForVideo(
+  settings: DownloadPathSettings,
+  videoInfo: VideoInfo,
+  context: Pick<OutputTemplateContext, 'resolution'> = {},
+): string {
+  let directory = escapeOutputTemplate(settings.downloadPath)
+  if (settings.createSubdirectories) {
+    directory = join(directory, escapeOutputTemplate(sanitizePathComponent(videoInfo.channel.name, 'Unknown')))
+  }
+
+  let template = settings.filenameTemplate?.trim() || DEFAULT_FILENAME_TEMPLATE
+  if (template.endsWith(EXTENSION_PLACEHOLDER)) {
+    template = template.slice(0, -EXTENSION_PLACEHOLDER.length)
+  }
+
+  let filename = expandFilenameTemplate(template, videoInfo, context).replace(/%\(ext\)s/g, '')
+  if (settings.includeDateInFilename) {
+    const dateStamp = /^\d{8}$/.test(videoInfo.uploadDate) ? videoInfo.uploadDate : formatDateStamp(new Date())
+    filename = `${dateStamp}_${filename}`
+  }
+
+  const safeName = sanitizePathComponent(filename, videoInfo.id)
+  return join(directory, escapeOutputTemplate(safeName, getYtdlpTemplateFields(template)) + EXTENSION_PLACEHOLDER)
+}
+
+/**
+ * Number an output template 'name (N).%(ext)s' when a file is already there under any of
+ * the extensions, so downloading a video again keeps both instead of yt-dlp skipping it
+ */
+export function getUniqueOutputTemplate(
+  template: string,
+  extensions: string[],
+  exists: (path: string) => boolean = existsSync,
+): string {
+  const taken = (candidate: string) => {
+    const base = candidate.slice(0, -EXTENSION_PLACEHOLDER.length).replace(/%%/g, '%')
+    return extensions.some(extension => exists(`${base}.${extension}`))
+  }
+  return getAvailableOutputPath(template, taken)
+}
+
+/**
+ * Length of a path as the current platform counts it against its limits
+ */
+export function getPlatformPathLength(
+  path: string,
+  isWindows = PlatformUtils.getInstance().getPlatformInfo().isWindows,
+): number {
+  return isWindows ? path.length : Buffer.byteLength(path, 'utf8')
+}
+
+/**
+ * Truncate a string to at most maxLength (as counted by getPlatformPathLength)
+ * without splitting a character, even one made of several code points
+ */
+function truncateToLength(value: string, maxLength: number, isWindows: boolean): string {
+  return truncateGraphemes(value, maxLength, text => getPlatformPathLength(text, isWindows))
+}
+
+/**
+ * Make sure a yt-dlp output template (ending in .%(ext)s) stays within the platform's
+ * path limits once yt-dlp fills in the extension and temporary suffixes.
+ * Over-long filenames are truncated and get a short hash of the original name so
+ * distinct long titles don't collide. Throws FILENAME_TOO_LONG if the directory alone
+ * leaves no room for a usable filename.
+ */
+export function fitOutputTemplateToPathLimit(
+  template: string,
+  isWindows = PlatformUtils.getInstance().getPlatformInfo().isWindows,
+): { template: string; truncated: boolean } {
+  const unescape = (value: string) => value.replace(/%%/g, '%')
+  const directory = unescape(dirname(template))
+  const name = unescape(basename(template).slice(0, -EXTENSION_PLACEHOLDER.length))
+
+  const maxPath = isWindows ? WINDOWS_MAX_PATH : UNIX_MAX_PATH
+  const directoryLength = getPlatformPathLength(join(directory, 'x'), isWindows) - 1
+  const available = Math.min(
+    MAX_COMPONENT_LENGTH - WORST_CASE_EXTENSION_LENGTH,
+    maxPath - directoryLength - WORST_CASE_EXTENSION_LENGTH,
+  )
+
+  if (getPlatformPathLength(name, isWindows) <= available) {
+    return { template, truncated: false }
+  }
+
+  if (available < MIN_NAME_LENGTH + HASH_SUFFIX_LENGTH) {
+    const suggested = truncateToLength(name, Math.max(available, MIN_NAME_LENGTH), isWindows).trim()
+    throw createDownloadError(
+      `Output path is too long for this system. Choose a shorter download folder (e.g. name the file "${suggested}")`,
+      DownloadErrorCode.FILENAME_TOO_LONG,
+    )
+  }
+
+  const hash = createHash('sha1').update(name).digest('hex').slice(0, 8)
+  const shortened = `${truncateToLength(name, available - HASH_SUFFIX_LENGTH, isWindows).trim()}_${hash}`
+  const keepFields = getYtdlpTemplateFields(basename(template))
+  const fittedName = escapeOutputTemplate(shortened, keepFields) + EXTENSION_PLACEHOLDER
+  return { template: join(escapeOutputTemplate(directory), fittedName), truncated: true }
+}
+
+/**
+ * Render a filename template for one video the way a download would, for a live preview
+ * before it starts: the path after sanitizing and the path length check, with the
+ * problems found on the way. Errors stop the download from starting; warnings don't:
+ * - errors: characters no filename can have in the template text, a path too long
+ * - warnings: fields only yt-dlp fills in (kept as-is in the preview), a name shortened to
+ *   fit the path limit, an existing file at that path (the download is numbered instead)
+ */
+export function renderOutputTemplate(
+  settings: DownloadPathSettings,
+  videoInfo: VideoInfo,
+  context: OutputTemplateContext & { extension: string },
+  isWindows = PlatformUtils.getInstance().getPlatformInfo().isWindows,
+): OutputTemplatePreview {
+  const template = settings.filenameTemplate?.trim() || DEFAULT_FILENAME_TEMPLATE
+  const errors: string[] = []
+  const warnings: string[] = []
+
+  const literalText = template.replace(TEMPLATE_FIELD_PATTERN, '')
+  const illegal = Array.from(new Set(literalText.match(ILLEGAL_FILENAME_CHARACTERS) ?? []))
+  if (illegal.length > 0) {
+    const shown = illegal.map(char => (char < ' ' ? 'control characters' : char)).join(' ')
+    errors.push(`The template contains characters a filename can't have: ${shown}`)
+  }
+  for (const field of getYtdlpTemplateFields(template)) {
+    warnings.push(`%(${field})s is filled in by yt-dlp and can't be previewed`)
+  }
+
+  let outputTemplate = getDownloadPathForVideo(settings, videoInfo, context)
+  try {
+    const fitted = fitOutputTemplateToPathLimit(outputTemplate, isWindows)
+    if (fitted.truncated) {
+      warnings.push('The filename is shortened to fit the path length limit')
+      outputTemplate = fitted.template
+    }
+  } catch (error) {
+    if (!isDownloadError(error)) throw error
+    errors.push(error.message)
+  }
+
+  const unique = getUniqueOutputTemplate(outputTemplate, [context.extension, ...DOWNLOADED_FILE_EXTENSIONS])
+  const exists = unique !== outputTemplate
+  if (exists) {
+    warnings.push('A file with this name already exists; the download gets a number added to its name')
+  }
+  const path = `${unique.slice(0, -EXTENSION_PLACEHOLDER.length).replace(/%%/g, '%')}.${context.extension}`
+
+  return { path, filename: basename(path), errors, warnings, exists }
+}
@@ -5,10 +5,11 @@
 
 import { arch, cpus, freemem, homedir, hostname, platform, release, tmpdir, totalmem, uptime } from 'os'
 import { basename, delimiter, extname, join, relative, sep } from 'path'
-import { existsSync, mkdirSync, statSync } from 'fs'
+import { existsSync, mkdirSync, readFileSync, statSync } from 'fs'
 
+import type { BrowserInfo, ProcessMemory } from '../types/system'
 import { Logger } from './logger'
-import { spawnSync } from 'child_process'
+import { execFile, spawnSync } from 'child_process'
 
 // Import electron app conditionally
 let electronApp: any = null
@@ -19,6 +20,41 @@ try {
   // Not available in renderer process
 }
 
+/** Longest filename sanitizeFilename returns, in UTF-8 bytes (fits the 255 limits of every platform) */
+const MAX_FILENAME_BYTES = 200
+
+const OS_NAMES: Record<string, string> = {
+  win32: 'Windows',
+  darwin: 'macOS',
+  linux: 'Linux',
+  freebsd: 'FreeBSD',
+  sunos: 'SunOS',
+}
+
+const WINDOWS_VERSION_KEY = 'HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion'
+const WINDOWS_11_FIRST_BUILD = 22000 // Windows 11 still reports itself as Windows 10 in ProductName
+const OS_VERSION_TIMEOUT = 5000
+
+const graphemeSegmenter = new Intl.Segmenter(undefined, { granularity: 'grapheme' })
+
+/**
+ * Truncate a string to at most maxLength, as measured by `measure` (UTF-8 bytes by default),
+ * cutting only between user-perceived characters: multi-byte characters, surrogate pairs,
+ * combining marks and emoji ZWJ sequences are never split
+ */
+export function truncateGraphemes(
+  value: string,
+  maxLength: number,
+  measure: (text: string) => number = text => Buffer.byteLength(text, 'utf8'),
+): string {
+  let result = ''
+  for (const { segment } of graphemeSegmenter.segment(value)) {
+    if (measure(result + segment) > maxLength) break
+    result += segment
+  }
+  return result
+}
+
 export interface PlatformInfo {
   name: 'windows' | 'macos' | 'linux' | 'unknown'
   version: string
@@ -35,10 +71,18 @@ export interface PlatformInfo {
   executableExtension: string
 }
 
+interface BrowserCandidate {
+  name: string
+  displayName: string
+  binaries: string[]
+  profiles: string[]
+}
+
 export class PlatformUtils {
   private static instance: PlatformUtils
   private platformInfo: PlatformInfo
   private logger?: Logger
+  private osVersionString: string | null = null
 
   private constructor() {
     this.platformInfo = this.detectPlatform()
@@ -168,13 +212,18 @@ export class PlatformUtils {
 
     // Application resources directory
     const resourcesDir = join(process.cwd(), 'resources')
+    const installedDir = this.getInstalledBinariesDir()
 
     if (this.platformInfo.isWindows) {
-      paths.push(join(resourcesDir, `${baseName}.exe`), join(resourcesDir, 'windows', `${baseName}.exe`))
+      paths.push(
+        join(resourcesDir, `${baseName}.exe`),
+        join(resourcesDir, 'windows', `${baseName}.exe`),
+        join(installedDir, `${baseName}.exe`),
+      )
     } else if (this.platformInfo.isMacOS) {
-      paths.push(join(resourcesDir, baseName), join(resourcesDir, 'macos', baseName))
+      paths.push(join(resourcesDir, baseName), join(resourcesDir, 'macos', baseName), join(installedDir, baseName))
     } else if (this.platformInfo.isLinux) {
-      paths.push(join(resourcesDir, baseName), join(resourcesDir, 'linux', baseName))
+      paths.push(join(resourcesDir, baseName), join(resourcesDir, 'linux', baseName), join(installedDir, baseName))
     }
 
     return paths
@@ -183,7 +232,7 @@ export class PlatformUtils {
   /**
    * Check if file is executable
    */
-  private isExecutable(filePath: string): boolean {
+  isExecutable(filePath: string): boolean {
     try {
       if (!this.platformInfo.isWindows) {
         // On Unix systems, check if file has execute permission
@@ -236,6 +285,13 @@ export class PlatformUtils {
     }
   }
 
+  /**
+   * Directory for binaries Clipy installs itself (e.g. FFmpeg), searched after resources/
+   */
+  getInstalledBinariesDir(): string {
+    return join(this.getAppDataDir('clipy'), 'bin')
+  }
+
   /**
    * Get platform-specific temporary directory
    */
@@ -265,7 +321,8 @@ export class PlatformUtils {
    * Sanitize filename for current platform
    */
   sanitizeFilename(filename: string): string {
-    let sanitized = filename
+    // Composed form, so the same title always gives the same name (macOS would otherwise mix NFD names in)
+    let sanitized = filename.normalize('NFC')
 
     // Remove or replace platform-specific invalid characters
     if (this.platformInfo.isWindows) {
@@ -297,11 +354,12 @@ export class PlatformUtils {
       sanitized = sanitized.replace(/^[.]+|[.]+$/g, '')
     }
 
-    // Limit length (Windows has 255 char limit for filenames)
-    if (sanitized.length > 200) {
+    // Limit length in bytes: Linux/macOS count UTF-8 bytes against their 255 limit, so emoji and CJK
+    // titles well under 200 characters could still be too long
+    if (Buffer.byteLength(sanitized, 'utf8') > MAX_FILENAME_BYTES) {
       const ext = extname(sanitized)
       const nameWithoutExt = basename(sanitized, ext)
-      sanitized = nameWithoutExt.substring(0, 200 - ext.length) + ext
+      sanitized = truncateGraphemes(nameWithoutExt, MAX_FILENAME_BYTES - Buffer.byteLength(ext, 'utf8')).trim() + ext
     }
 
     return sanitized
@@ -416,6 +474,289 @@ export class PlatformUtils {
     process.env[name] = value
   }
 
+  /**
+   * Command that puts the computer to sleep or shuts it down
+   */
+  getPowerCommand(action: 'sleep' | 'shutdown'): { command: string; args: string[] } {
+    if (this.platformInfo.isWindows) {
+      return action === 'sleep'
+        ? { command: 'rundll32.exe', args: ['powrprof.dll,SetSuspendState', '0,1,0'] }
+        : { command: 'shutdown', args: ['/s', '/t', '0'] }
+    }
+    if (this.platformInfo.isMacOS) {
+      return action === 'sleep'
+        ? { command: 'pmset', args: ['sleepnow'] }
+        : { command: 'osascript', args: ['-e', 'tell application "System Events" to shut down'] }
+    }
+    return { command: 'systemctl', args: [action === 'sleep' ? 'suspend' : 'poweroff'] }
+  }
+
+  /**
+   * Sleep or shut down the computer. Resolves once the command was accepted.
+   */
+  runPowerAction(action: 'sleep' | 'shutdown'): Promise<void> {
+    const { command, args } = this.getPowerCommand(action)
+    return new Promise((resolve, reject) => {
+      execFile(command, args, { timeout: 30000, windowsHide: true }, (error, _stdout, stderr) => {
+        if (error) {
+          reject(new Error(`${command} failed: ${stderr.trim() || error.message}`))
+          return
+        }
+        resolve()
+      })
+    })
+  }
+
+  /**
+   * Detect installed browsers that yt-dlp can read cookies from.
+   * A browser is reported only if one of its well-known binaries exists.
+   */
+  getInstalledBrowsers(): BrowserInfo[] {
+    return this.getBrowserCandidates()
+      .filter(browser => browser.binaries.some(binary => existsSync(binary)))
+      .map(browser => ({
+        name: browser.name,
+        displayName: browser.displayName,
+        profilePaths: browser.profiles.filter(profile => existsSync(profile)),
+      }))
+  }
+
+  /**
+   * Well-known binary and profile locations per browser for the current platform
+   */
+  private getBrowserCandidates(): BrowserCandidate[] {
+    const home = this.platformInfo.homeDir
+
+    if (this.platformInfo.isWindows) {
+      const programFiles = [process.env['ProgramFiles'], process.env['ProgramFiles(x86)']].filter(Boolean) as string[]
+      const localAppData = process.env.LOCALAPPDATA || join(home, 'AppData', 'Local')
+      const roamingAppData = process.env.APPDATA || join(home, 'AppData', 'Roaming')
+      const installRoots = [...programFiles, localAppData]
+      const inRoots = (...segments: string[]) => installRoots.map(root => join(root, ...segments))
+
+      return [
+        {
+          name: 'chrome',
+          displayName: 'Google Chrome',
+          binaries: inRoots('Google', 'Chrome', 'Application', 'chrome.exe'),
+          profiles: [join(localAppData, 'Google', 'Chrome', 'User Data')],
+        },
+        {
+          name: 'firefox',
+          displayName: 'Mozilla Firefox',
+          binaries: inRoots('Mozilla Firefox', 'firefox.exe'),
+          profiles: [join(roamingAppData, 'Mozilla', 'Firefox', 'Profiles')],
+        },
+        {
+          name: 'edge',
+          displayName: 'Microsoft Edge',
+          binaries: inRoots('Microsoft', 'Edge', 'Application', 'msedge.exe'),
+          profiles: [join(localAppData, 'Microsoft', 'Edge', 'User Data')],
+        },
+        {
+          name: 'brave',
+          displayName: 'Brave',
+          binaries: inRoots('BraveSoftware', 'Brave-Browser', 'Application', 'brave.exe'),
+          profiles: [join(localAppData, 'BraveSoftware', 'Brave-Browser', 'User Data')],
+        },
+        {
+          name: 'opera',
+          displayName: 'Opera',
+          binaries: [...inRoots('Opera', 'opera.exe'), join(localAppData, 'Programs', 'Opera', 'opera.exe')],
+          profiles: [join(roamingAppData, 'Opera Software', 'Opera Stable')],
+        },
+        {
+          name: 'vivaldi',
+          displayName: 'Vivaldi',
+          binaries: inRoots('Vivaldi', 'Application', 'vivaldi.exe'),
+          profiles: [join(localAppData, 'Vivaldi', 'User Data')],
+        },
+      ]
+    }
+
+    if (this.platformInfo.isMacOS) {
+      const appBundle = (name: string) => [join('/Applications', name), join(home, 'Applications', name)]
+      const support = join(home, 'Library', 'Application Support')
+
+      return [
+        {
+          name: 'chrome',
+          displayName: 'Google Chrome',
+          binaries: appBundle('Google Chrome.app'),
+          profiles: [join(support, 'Google', 'Chrome')],
+        },
+        {
+          name: 'firefox',
+          displayName: 'Mozilla Firefox',
+          binaries: appBundle('Firefox.app'),
+          profiles: [join(support, 'Firefox', 'Profiles')],
+        },
+        {
+          name: 'edge',
+          displayName: 'Microsoft Edge',
+          binaries: appBundle('Microsoft Edge.app'),
+          profiles: [join(support, 'Microsoft Edge')],
+        },
+        {
+          name: 'brave',
+          displayName: 'Brave',
+          binaries: appBundle('Brave Browser.app'),
+          profiles: [join(support, 'BraveSoftware', 'Brave-Browser')],
+        },
+        {
+          name: 'opera',
+          displayName: 'Opera',
+          binaries: appBundle('Opera.app'),
+          profiles: [join(support, 'com.operasoftware.Opera')],
+        },
+        {
+          name: 'vivaldi',
+          displayName: 'Vivaldi',
+          binaries: appBundle('Vivaldi.app'),
+          profiles: [join(support, 'Vivaldi')],
+        },
+        {
+          name: 'safari',
+          displayName: 'Safari',
+          binaries: ['/Applications/Safari.app'],
+          profiles: [join(home, 'Library', 'Containers', 'com.apple.Safari', 'Data', 'Library', 'Cookies')],
+        },
+      ]
+    }
+
+    // Linux: distro packages, Snap and Flatpak exports
+    const bin = (...names: string[]) =>
+      names.flatMap(name => [join('/usr/bin', name), join('/usr/local/bin', name), join('/snap/bin', name)])
+    const flatpak = (appId: string) => [
+      join('/var/lib/flatpak/exports/bin', appId),
+      join(home, '.local/share/flatpak/exports/bin', appId),
+    ]
+    const config = join(home, '.config')
+
+    return [
+      {
+        name: 'chrome',
+        displayName: 'Google Chrome',
+        binaries: [...bin('google-chrome', 'google-chrome-stable'), ...flatpak('com.google.Chrome')],
+        profiles: [join(config, 'google-chrome'), join(home, '.var/app/com.google.Chrome/config/google-chrome')],
+      },
+      {
+        name: 'firefox',
+        displayName: 'Mozilla Firefox',
+        binaries: [...bin('firefox'), ...flatpak('org.mozilla.firefox')],
+        profiles: [
+          join(home, '.mozilla', 'firefox'),
+          join(home, 'snap/firefox/common/.mozilla/firefox'),
+          join(home, '.var/app/org.mozilla.firefox/.mozilla/firefox'),
+        ],
+      },
+      {
+        name: 'edge',
+        displayName: 'Microsoft Edge',
+        binaries: [...bin('microsoft-edge', 'microsoft-edge-stable'), ...flatpak('com.microsoft.Edge')],
+        profiles: [join(config, 'microsoft-edge')],
+      },
+      {
+        name: 'brave',
+        displayName: 'Brave',
+        binaries: [...bin('brave-browser', 'brave'), ...flatpak('com.brave.Browser')],
+        profiles: [join(config, 'BraveSoftware', 'Brave-Browser')],
+      },
+      {
+        name: 'opera',
+        displayName: 'Opera',
+        binaries: [...bin('opera'), ...flatpak('com.opera.Opera')],
+        profiles: [join(config, 'opera')],
+      },
+      {
+        name: 'vivaldi',
+        displayName: 'Vivaldi',
+        binaries: [...bin('vivaldi', 'vivaldi-stable'), ...flatpak('com.vivaldi.Vivaldi')],
+        profiles: [join(config, 'vivaldi')],
+      },
+    ]
+  }
+
+  /**
+   * Human-readable OS name and version, e.g. 'Windows 11 Pro (Build 22631)', 'macOS 14.5'
+   * or 'Ubuntu 22.04.3 LTS'. Read from the OS once; falls back to the OS name and kernel release.
+   */
+  getOsVersionString(): string {
+    if (this.osVersionString) {
+      return this.osVersionString
+    }
+
+    let version: string | null = null
+    try {
+      if (this.platformInfo.isWindows) {
+        version = this.readWindowsVersion()
+      } else if (this.platformInfo.isMacOS) {
+        const result = spawnSync('sw_vers', ['-productVersion'], { encoding: 'utf-8', timeout: OS_VERSION_TIMEOUT })
+        version = result.status === 0 && result.stdout.trim() ? `macOS ${result.stdout.trim()}` : null
+      } else if (this.platformInfo.isLinux) {
+        const osRelease = readFileSync('/etc/os-release', 'utf-8')
+        version = osRelease.match(/^PRETTY_NAME=(["']?)(.+)\1\s*$/m)?.[2] ?? null
+      }
+    } catch (error) {
+      this.getLogger().debug('Failed to read the OS version', { error: (error as Error).message })
+    }
+
+    this.osVersionString = version || `${OS_NAMES[platform()] || platform()} ${release()}`
+    return this.osVersionString
+  }
+
+  /**
+   * Windows edition and build from the registry
+   */
+  private readWindowsVersion(): string | null {
+    const result = spawnSync('reg', ['query', WINDOWS_VERSION_KEY], {
+      encoding: 'utf-8',
+      timeout: OS_VERSION_TIMEOUT,
+      windowsHide: true,
+    })
+    if (result.status !== 0) {
+      return null
+    }
+
+    // Values are listed as '    Name    REG_SZ    Data'
+    const readValue = (name: string) =>
+      result.stdout.match(new RegExp(`^\\s+${name}\\s+REG_SZ\\s+(.+?)\\s*$`, 'm'))?.[1]
+    let productName = readValue('ProductName')
+    const build = readValue('CurrentBuildNumber')
+    if (!productName) {
+      return null
+    }
+    if (build && parseInt(build, 10) >= WINDOWS_11_FIRST_BUILD) {
+      productName = productName.replace('Windows 10', 'Windows 11')
+    }
+    return build ? `${productName} (Build ${build})` : productName
+  }
+
+  /**
+   * Memory of this process. Linux reports it in /proc/self/status; elsewhere Node only
+   * knows the resident and peak sizes, not the virtual one.
+   */
+  getProcessMemory(): ProcessMemory {
+    const peakRssBytes = process.resourceUsage().maxRSS * 1024
+    if (this.platformInfo.isLinux) {
+      try {
+        const status = readFileSync('/proc/self/status', 'utf-8')
+        const readKb = (field: string) => {
+          const match = status.match(new RegExp(`^${field}:\\s+(\\d+) kB`, 'm'))
+          return match ? parseInt(match[1], 10) * 1024 : null
+        }
+        return {
+          rssBytes: readKb('VmRSS') ?? process.memoryUsage.rss(),
+          virtualBytes: readKb('VmSize'),
+          peakRssBytes: readKb('VmHWM') ?? peakRssBytes,
+        }
+      } catch {
+        // Fall through to what Node reports
+      }
+    }
+    return { rssBytes: process.memoryUsage.rss(), virtualBytes: null, peakRssBytes }
+  }
+
   /**
    * Get system information
    */
@@ -0,0 +1,77 @@
+/**
+ * Process Priority
+ * Keeps FFmpeg jobs from making the rest of the machine unusable: each job runs at the
+ * configured OS priority (editor.processPriority) and with at most editor.threadsLimit
+ * threads. os.setPriority maps the levels to priority classes on Windows and nice values
+ * elsewhere.
+ *
+ * Raising a process back to normal may need privileges the app doesn't have (Unix only
+ * lets unprivileged users lower priority). Failures are logged and otherwise ignored; the
+ * job keeps running at whatever priority it has.
+ */
+
+import { constants, setPriority } from 'os'
+
+import type { ProcessPriority } from '../types/system'
+import { ConfigManager } from './config'
+import { Logger } from './logger'
+
+export const PROCESS_PRIORITIES: ProcessPriority[] = ['normal', 'below-normal', 'idle']
+
+const PRIORITY_VALUES: Record<ProcessPriority, number> = {
+  normal: constants.priority.PRIORITY_NORMAL,
+  'below-normal': constants.priority.PRIORITY_BELOW_NORMAL,
+  idle: constants.priority.PRIORITY_LOW, // IDLE_PRIORITY_CLASS on Windows, nice 19 elsewhere
+}
+
+export function isProcessPriority(value: unknown): value is ProcessPriority {
+  return PROCESS_PRIORITIES.includes(value as ProcessPriority)
+}
+
+/**
+ * Set a running process's priority. Returns false if the process is gone or the OS refused.
+ */
+export function setProcessPriority(pid: number | undefined, priority: ProcessPriority): boolean {
+  if (!pid) return false
+  try {
+    setPriority(pid, PRIORITY_VALUES[priority])
+    return true
+  } catch (error) {
+    Logger.getInstance().debug('Could not change process priority', {
+      pid,
+      priority,
+      error: (error as Error).message,
+    })
+    return false
+  }
+}
+
+/** Priority FFmpeg jobs run at, from settings */
+export function getFFmpegPriority(): ProcessPriority {
+  const priority = ConfigManager.getInstance().get('editor')?.processPriority
+  return isProcessPriority(priority) ? priority : 'normal'
+}
+
+/** Apply the configured priority to a just-spawned FFmpeg process */
+export function applyFFmpegPriority(pid: number | undefined): void {
+  const priority = getFFmpegPriority()
+  if (priority !== 'normal') {
+    setProcessPriority(pid, priority)
+  }
+}
+
+/**
+ * FFmpeg arguments with the configured thread limit applied. `-threads` limits the
+ * encoder when placed before the output (the last argument); the filter graph has its own
+ * global limit.
+ */
+export function withFFmpegThreadLimit(args: string[]): string[] {
+  const limit = ConfigManager.getInstance().get('editor')?.threadsLimit
+  if (!limit || limit < 1 || args.length === 0) {
+    return args
+  }
+  const threads = String(Math.floor(limit))
+  const output = args.length - 1
+  const filterThreads = args.includes('-filter_complex') ? ['-filter_complex_threads', threads] : []
+  return [...filterThreads, ...args.slice(0, output), '-threads', threads, args[output]]
+}
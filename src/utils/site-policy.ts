@@ -0,0 +1,94 @@
+/**
+ * Site Policy
+ * Restricts which sites videos may be fetched from (settings: sitePolicy), e.g. an
+ * allowlist of approved domains on managed machines.
+ *
+ * Patterns are host names, optionally with a leading '*.' to include subdomains:
+ * 'youtube.com' matches only that host, '*.youtube.com' matches youtube.com and any
+ * subdomain. Hosts and patterns are compared in lowercase punycode, so IDN domains
+ * match whether written in Unicode or as xn-- labels.
+ */
+
+import { domainToASCII } from 'url'
+
+import { DownloadErrorCode } from '../types/download'
+import type { SitePolicyConfig } from '../types/system'
+import { ConfigManager } from './config'
+import { createLocalizedDownloadError } from './i18n'
+
+const WILDCARD_PREFIX = '*.'
+
+/**
+ * Lowercase punycode form of a host name, without a trailing dot. '' if it isn't a valid host.
+ */
+export function normalizeHost(host: string): string {
+  return domainToASCII(host.trim().replace(/\.$/, '')).toLowerCase()
+}
+
+/**
+ * Normalize a user-entered pattern: accepts a bare host, '*.host', or a pasted URL.
+ * Returns null when nothing usable is left.
+ */
+export function normalizeSitePattern(pattern: string): string | null {
+  let value = pattern.trim().toLowerCase()
+  const wildcard = value.startsWith(WILDCARD_PREFIX)
+  if (wildcard) {
+    value = value.slice(WILDCARD_PREFIX.length)
+  }
+
+  // Keep only the host of a pasted URL
+  value = value.replace(/^[a-z][a-z\d+.-]*:\/\//, '').split(/[/?#]/)[0]
+  const host = normalizeHost(value.replace(/:\d+$/, ''))
+  if (!host) {
+    return null
+  }
+  return wildcard ? `${WILDCARD_PREFIX}${host}` : host
+}
+
+/**
+ * Whether a normalized host matches a normalized pattern
+ */
+export function matchesSitePattern(host: string, pattern: string): boolean {
+  if (pattern.startsWith(WILDCARD_PREFIX)) {
+    const domain = pattern.slice(WILDCARD_PREFIX.length)
+    return host === domain || host.endsWith(`.${domain}`)
+  }
+  return host === pattern
+}
+
+/**
+ * Whether a URL may be fetched under a policy. URLs without a host are never allowed
+ * by an allowlist.
+ */
+export function isUrlAllowedByPolicy(url: string, policy: SitePolicyConfig): boolean {
+  if (policy.mode === 'allow-all') {
+    return true
+  }
+
+  let host = ''
+  try {
+    host = normalizeHost(new URL(url).hostname)
+  } catch {
+    // Not a URL: only an allowlist has anything to say about it
+  }
+
+  const patterns = policy.patterns.map(normalizeSitePattern).filter((pattern): pattern is string => pattern !== null)
+  const matched = host !== '' && patterns.some(pattern => matchesSitePattern(host, pattern))
+  return policy.mode === 'allowlist' ? matched : !matched
+}
+
+/**
+ * Throw SITE_BLOCKED if the configured site policy doesn't allow this URL
+ */
+export function assertSiteAllowed(url: string): void {
+  const policy = ConfigManager.getInstance().get('sitePolicy')
+  if (policy && !isUrlAllowedByPolicy(url, policy)) {
+    let host = url
+    try {
+      host = new URL(url).hostname
+    } catch {
+      // Report the raw value
+    }
+    throw createLocalizedDownloadError('errorSiteBlocked', { host }, DownloadErrorCode.SITE_BLOCKED)
+  }
+}
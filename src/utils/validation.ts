@@ -4,35 +4,99 @@
  */
 
 import path from 'path'
+import { randomUUID } from 'crypto'
+import { existsSync, mkdirSync, unlinkSync, writeFileSync } from 'fs'
 
 import { DownloadError, DownloadErrorCode, createDownloadError } from '../types/download'
-import type { DownloadFilter, DownloadOptions } from '../types/download'
-
+import type { CookieChoice, DownloadFilter, DownloadOptions, RemuxStreamSelection } from '../types/download'
+import type { AudioMergeOptions, Effect, SlideshowOptions, TextProperties } from '../types/project'
+import type { ProxyInfo } from '../types/system'
+
+import { type ErrorResponse, createErrorResponse } from '../types/api'
+import {
+  type MessageKey,
+  type MessageParams,
+  createLocalizedDownloadError,
+  createLocalizedErrorResponse,
+  formatMessage,
+} from './i18n'
+import { isEffectType, resolveEffectParams } from './ffmpeg-args'
 import { Logger } from './logger'
 import { PlatformUtils } from './platform'
+import { normalizeSitePattern } from './site-policy'
+
+/** Browser names accepted by yt-dlp --cookies-from-browser that Clipy can detect */
+const COOKIE_BROWSERS = ['chrome', 'firefox', 'edge', 'brave', 'opera', 'vivaldi', 'safari']
+
+const PROXY_SCHEMES = ['http', 'https', 'socks4', 'socks4a', 'socks5', 'socks5h']
+// URL drops a port that is the scheme's default (http://host:80), so the port is read from the text
+const PROXY_PORT_PATTERN = /^[a-z0-9]+:\/\/(?:[^@/?#]*@)?(?:\[[^\]]*\]|[^:/?#]*):(\d+)(?:[/?#]|$)/i
+
+const SITE_POLICY_MODES = ['allow-all', 'allowlist', 'denylist']
+const MAX_SITE_PATTERNS = 500
+
+const SPONSORBLOCK_MODES = ['off', 'remove', 'mark', 'mark-and-remove']
+/** Categories yt-dlp can cut out; highlights and chapter labels can only be marked */
+const SPONSORBLOCK_REMOVE_CATEGORIES = [
+  'sponsor',
+  'intro',
+  'outro',
+  'selfpromo',
+  'preview',
+  'filler',
+  'interaction',
+  'music_offtopic',
+]
+const SPONSORBLOCK_MARK_CATEGORIES = [...SPONSORBLOCK_REMOVE_CATEGORIES, 'poi_highlight', 'chapter']
+
+/** Language codes as yt-dlp reports them (en, pt-BR, zh-Hans); used inside format selectors */
+const AUDIO_LANGUAGE_PATTERN = /^[a-z]{2,3}(-[a-z0-9]{2,8})*$/i
+
+const MAX_TAGS = 20
+const MAX_TAG_LENGTH = 50
+const MAX_TEXT_LENGTH = 500
+const MAX_FONT_SIZE = 1000
+const TEXT_ALIGNS = ['left', 'center', 'right']
+const FONT_EXTENSIONS = ['.ttf', '.otf', '.ttc']
 
 export interface ValidationResult<T = any> {
   isValid: boolean
   value?: T
   error?: string
+  /** Message catalog key (utils/i18n) and params matching error */
+  messageKey?: MessageKey
+  params?: MessageParams
 }
 
 export class ValidationUtils {
   private static logger = Logger.getInstance()
   private static platform = PlatformUtils.getInstance()
 
+  /**
+   * A URL a download can start from: a YouTube URL, or any other http(s) URL, which is
+   * downloaded directly if it turns out to point at a media file
+   */
+  static validateDownloadUrl(url: string): ValidationResult<string> {
+    const youtube = this.validateUrl(url)
+    if (youtube.isValid || youtube.messageKey !== 'errorInvalidYoutubeUrl') {
+      return youtube
+    }
+    const { protocol } = new URL(url.trim())
+    return protocol === 'http:' || protocol === 'https:' ? { isValid: true, value: url.trim() } : youtube
+  }
+
   /**
    * Validate YouTube URL
    */
   static validateUrl(url: string): ValidationResult<string> {
     try {
       if (!url || typeof url !== 'string') {
-        return { isValid: false, error: 'URL is required' }
+        return { isValid: false, error: 'URL is required', messageKey: 'errorUrlRequired' }
       }
 
       const trimmedUrl = url.trim()
       if (!trimmedUrl) {
-        return { isValid: false, error: 'URL cannot be empty' }
+        return { isValid: false, error: 'URL cannot be empty', messageKey: 'errorUrlRequired' }
       }
 
       // Parse URL
@@ -40,14 +104,14 @@ export class ValidationUtils {
       try {
         urlObj = new URL(trimmedUrl)
       } catch {
-        return { isValid: false, error: 'Invalid URL format' }
+        return { isValid: false, error: 'Invalid URL format', messageKey: 'errorInvalidUrl' }
       }
 
       // Check for YouTube domains
       const youtubeDomains = ['youtube.com', 'www.youtube.com', 'youtu.be', 'music.youtube.com', 'm.youtube.com']
 
       if (!youtubeDomains.includes(urlObj.hostname.toLowerCase())) {
-        return { isValid: false, error: 'URL must be a valid YouTube URL' }
+        return { isValid: false, error: 'URL must be a valid YouTube URL', messageKey: 'errorInvalidYoutubeUrl' }
       }
 
       // Validate YouTube URL patterns
@@ -61,7 +125,7 @@ export class ValidationUtils {
 
       const isValidPattern = youtubePatterns.some(pattern => pattern.test(trimmedUrl))
       if (!isValidPattern) {
-        return { isValid: false, error: 'Invalid YouTube URL format' }
+        return { isValid: false, error: 'Invalid YouTube URL format', messageKey: 'errorInvalidYoutubeUrl' }
       }
 
       return { isValid: true, value: trimmedUrl }
@@ -71,6 +135,39 @@ export class ValidationUtils {
     }
   }
 
+  /**
+   * Validate a proxy URL for yt-dlp: scheme://[user:pass@]host:port with an HTTP or SOCKS scheme
+   */
+  static validateProxyUrl(url: string): ValidationResult<ProxyInfo> {
+    if (!url || typeof url !== 'string' || !url.trim()) {
+      return { isValid: false, error: 'Proxy URL is required' }
+    }
+
+    const trimmedUrl = url.trim()
+    let urlObj: URL
+    try {
+      urlObj = new URL(trimmedUrl)
+    } catch {
+      return { isValid: false, error: 'Invalid URL format' }
+    }
+
+    const scheme = urlObj.protocol.replace(/:$/, '').toLowerCase()
+    if (!PROXY_SCHEMES.includes(scheme)) {
+      return { isValid: false, error: `Unsupported scheme "${scheme}", use one of: ${PROXY_SCHEMES.join(', ')}` }
+    }
+    const host = urlObj.hostname.replace(/^\[|\]$/g, '')
+    if (!host) {
+      return { isValid: false, error: 'Host is missing' }
+    }
+    const portMatch = PROXY_PORT_PATTERN.exec(trimmedUrl)
+    const port = portMatch ? Number(portMatch[1]) : NaN
+    if (!Number.isInteger(port) || port < 1 || port > 65535) {
+      return { isValid: false, error: 'Port is missing or out of range' }
+    }
+
+    return { isValid: true, value: { scheme, host, port, hasAuth: urlObj.username !== '' } }
+  }
+
   /**
    * Validate download ID
    */
@@ -128,6 +225,49 @@ export class ValidationUtils {
         }
       }
 
+      // Validate final container
+      if (options.finalContainer !== undefined) {
+        const validContainers = ['keep', 'mp4', 'mkv', 'webm', 'mov']
+        if (validContainers.includes(options.finalContainer)) {
+          validatedOptions.finalContainer = options.finalContainer
+        }
+      }
+
+      // Validate SponsorBlock mode and categories
+      if (options.sponsorBlockMode !== undefined) {
+        if (SPONSORBLOCK_MODES.includes(options.sponsorBlockMode)) {
+          validatedOptions.sponsorBlockMode = options.sponsorBlockMode
+        }
+      }
+      if (options.sponsorBlockRemoveCategories !== undefined) {
+        const categories = this.validateSponsorBlockCategories(
+          options.sponsorBlockRemoveCategories,
+          SPONSORBLOCK_REMOVE_CATEGORIES,
+        )
+        if (!categories.isValid) {
+          return { isValid: false, error: categories.error }
+        }
+        validatedOptions.sponsorBlockRemoveCategories = categories.value
+      }
+      if (options.sponsorBlockMarkCategories !== undefined) {
+        const categories = this.validateSponsorBlockCategories(
+          options.sponsorBlockMarkCategories,
+          SPONSORBLOCK_MARK_CATEGORIES,
+        )
+        if (!categories.isValid) {
+          return { isValid: false, error: categories.error }
+        }
+        validatedOptions.sponsorBlockMarkCategories = categories.value
+      }
+
+      // Validate audio language, e.g. 'en' or 'pt-BR'
+      if (options.audioLanguage !== undefined) {
+        if (typeof options.audioLanguage !== 'string' || !AUDIO_LANGUAGE_PATTERN.test(options.audioLanguage)) {
+          return { isValid: false, error: 'Invalid audio language' }
+        }
+        validatedOptions.audioLanguage = options.audioLanguage
+      }
+
       // Validate output path
       if (options.outputPath !== undefined) {
         if (typeof options.outputPath === 'string' && options.outputPath.trim()) {
@@ -148,11 +288,49 @@ export class ValidationUtils {
         }
       }
 
+      // Validate per-download filename template
+      if (typeof options.filenameTemplate === 'string' && options.filenameTemplate.trim()) {
+        // Same rules as the settings template: no path separators
+        validatedOptions.filenameTemplate = options.filenameTemplate.replace(/[/\\]/g, '').trim().substring(0, 200)
+      }
+
+      // Validate cookie source browser
+      if (options.cookiesFromBrowser !== undefined) {
+        if (typeof options.cookiesFromBrowser === 'string' && COOKIE_BROWSERS.includes(options.cookiesFromBrowser)) {
+          validatedOptions.cookiesFromBrowser = options.cookiesFromBrowser
+        }
+      }
+
+      // Validate per-download cookie choice
+      if (options.useCookies !== undefined) {
+        const cookieValidation = this.validateCookieChoice(options.useCookies)
+        if (!cookieValidation.isValid) {
+          return { isValid: false, error: cookieValidation.error }
+        }
+        validatedOptions.useCookies = cookieValidation.value
+      }
+
       // Validate boolean options
       const booleanOptions: (keyof Pick<
         DownloadOptions,
-        'downloadSubtitles' | 'downloadThumbnail' | 'saveMetadata' | 'createSubdirectories' | 'overwrite'
-      >)[] = ['downloadSubtitles', 'downloadThumbnail', 'saveMetadata', 'createSubdirectories', 'overwrite']
+        | 'downloadSubtitles'
+        | 'downloadThumbnail'
+        | 'saveMetadata'
+        | 'createSubdirectories'
+        | 'overwrite'
+        | 'downloadAllAudioTracks'
+        | 'writeChaptersFile'
+        | 'writePodcastFeed'
+      >)[] = [
+        'downloadSubtitles',
+        'downloadThumbnail',
+        'saveMetadata',
+        'createSubdirectories',
+        'overwrite',
+        'downloadAllAudioTracks',
+        'writeChaptersFile',
+        'writePodcastFeed',
+      ]
 
       for (const option of booleanOptions) {
         if (options[option] !== undefined) {
@@ -178,6 +356,14 @@ export class ValidationUtils {
         validatedOptions.endTime = Math.floor(options.endTime)
       }
 
+      if (options.tags !== undefined) {
+        const tags = this.validateTags(options.tags)
+        if (!tags.isValid) {
+          return { isValid: false, error: tags.error }
+        }
+        validatedOptions.tags = tags.value
+      }
+
       // Validate time range logic
       if (validatedOptions.startTime !== undefined && validatedOptions.endTime !== undefined) {
         if (validatedOptions.startTime >= validatedOptions.endTime) {
@@ -192,12 +378,130 @@ export class ValidationUtils {
     }
   }
 
+  /**
+   * Validate a cookie choice: a known browser, or an absolute path to an existing cookies file
+   */
+  static validateCookieChoice(choice: any): ValidationResult<CookieChoice> {
+    switch (choice?.mode) {
+      case 'auto':
+      case 'never':
+        return { isValid: true, value: { mode: choice.mode } }
+      case 'browser':
+        if (typeof choice.browser !== 'string' || !COOKIE_BROWSERS.includes(choice.browser)) {
+          return { isValid: false, error: 'Unknown browser for cookies' }
+        }
+        return { isValid: true, value: { mode: 'browser', browser: choice.browser } }
+      case 'file': {
+        if (typeof choice.path !== 'string' || choice.path.includes('\0')) {
+          return { isValid: false, error: 'Cookies file path is required' }
+        }
+        const filePath = path.normalize(choice.path)
+        if (!path.isAbsolute(filePath) || !existsSync(filePath)) {
+          return { isValid: false, error: 'Cookies file not found' }
+        }
+        return { isValid: true, value: { mode: 'file', path: filePath } }
+      }
+      default:
+        return { isValid: false, error: 'Invalid cookie choice' }
+    }
+  }
+
+  /**
+   * Validate a list of SponsorBlock categories against the ones allowed, dropping duplicates
+   */
+  static validateSponsorBlockCategories(categories: any, allowed: string[]): ValidationResult<string[]> {
+    if (!Array.isArray(categories)) {
+      return { isValid: false, error: 'SponsorBlock categories must be a list' }
+    }
+    const invalid = categories.find(category => typeof category !== 'string' || !allowed.includes(category))
+    if (invalid !== undefined) {
+      return { isValid: false, error: `Unknown SponsorBlock category: ${String(invalid)}` }
+    }
+    return { isValid: true, value: Array.from(new Set<string>(categories)) }
+  }
+
+  /**
+   * Validate download tags: trimmed, non-empty, case-insensitively unique
+   */
+  static validateTags(tags: any): ValidationResult<string[]> {
+    if (!Array.isArray(tags)) {
+      return { isValid: false, error: 'Tags must be a list' }
+    }
+    if (tags.length > MAX_TAGS) {
+      return { isValid: false, error: `At most ${MAX_TAGS} tags are allowed` }
+    }
+
+    const seen = new Set<string>()
+    const result: string[] = []
+    for (const tag of tags) {
+      if (typeof tag !== 'string') {
+        return { isValid: false, error: 'Tags must be strings' }
+      }
+      const trimmed = tag.trim()
+      if (trimmed.length > MAX_TAG_LENGTH) {
+        return { isValid: false, error: `Tags must be at most ${MAX_TAG_LENGTH} characters` }
+      }
+      if (trimmed && !seen.has(trimmed.toLowerCase())) {
+        seen.add(trimmed.toLowerCase())
+        result.push(trimmed)
+      }
+    }
+    return { isValid: true, value: result }
+  }
+
+  /**
+   * Validate text overlay properties
+   */
+  static validateTextProperties(text: any): ValidationResult<TextProperties> {
+    if (!text || typeof text !== 'object') {
+      return { isValid: false, error: 'Text properties are required' }
+    }
+    if (typeof text.content !== 'string' || !text.content.trim()) {
+      return { isValid: false, error: 'Text content is required' }
+    }
+    if (text.content.length > MAX_TEXT_LENGTH) {
+      return { isValid: false, error: `Text must be at most ${MAX_TEXT_LENGTH} characters` }
+    }
+    if (typeof text.fontSize !== 'number' || !(text.fontSize >= 1 && text.fontSize <= MAX_FONT_SIZE)) {
+      return { isValid: false, error: `Font size must be between 1 and ${MAX_FONT_SIZE}` }
+    }
+    if (typeof text.color !== 'string' || !/^(#[0-9a-f]{6}([0-9a-f]{2})?|[a-z]+)$/i.test(text.color.trim())) {
+      return { isValid: false, error: 'Color must be #rrggbb, #rrggbbaa or a color name' }
+    }
+    if (!TEXT_ALIGNS.includes(text.align)) {
+      return { isValid: false, error: `Text alignment must be one of: ${TEXT_ALIGNS.join(', ')}` }
+    }
+    if (text.y !== undefined && (typeof text.y !== 'number' || !(text.y >= 0 && text.y <= 1))) {
+      return { isValid: false, error: 'Vertical position must be between 0 and 1' }
+    }
+    if (text.fontFile !== undefined) {
+      if (typeof text.fontFile !== 'string' || text.fontFile.includes('\0') || !path.isAbsolute(text.fontFile)) {
+        return { isValid: false, error: 'Font file must be an absolute path' }
+      }
+      if (!FONT_EXTENSIONS.includes(path.extname(text.fontFile).toLowerCase()) || !existsSync(text.fontFile)) {
+        return { isValid: false, error: 'Font file must be an existing .ttf, .otf or .ttc file' }
+      }
+    }
+
+    return {
+      isValid: true,
+      value: {
+        content: text.content,
+        fontFile: text.fontFile,
+        fontSize: text.fontSize,
+        color: text.color.trim(),
+        align: text.align,
+        y: text.y,
+      },
+    }
+  }
+
   /**
    * Validate download filter
    */
   static validateDownloadFilter(filter: any): ValidationResult<DownloadFilter> {
     try {
-      const validFilters: DownloadFilter[] = ['active', 'completed', 'failed', 'all']
+      const validFilters: DownloadFilter[] = ['active', 'completed', 'failed', 'all', 'continue-watching']
 
       if (typeof filter === 'string' && validFilters.includes(filter as DownloadFilter)) {
         return { isValid: true, value: filter as DownloadFilter }
@@ -248,6 +552,124 @@ export class ValidationUtils {
     }
   }
 
+  /**
+   * Validate a remux stream selection: each given index must be a non-negative integer
+   */
+  static validateRemuxStreams(streams: any): ValidationResult<RemuxStreamSelection> {
+    if (streams === undefined || streams === null) {
+      return { isValid: true, value: {} }
+    }
+    if (typeof streams !== 'object') {
+      return { isValid: false, error: 'Stream selection must be an object' }
+    }
+
+    const selection: RemuxStreamSelection = {}
+    for (const key of ['videoStream', 'audioStream', 'subtitleStream'] as const) {
+      const index = streams[key]
+      if (index === undefined || index === null) continue
+      if (!Number.isInteger(index) || index < 0 || index > 99) {
+        return { isValid: false, error: `${key} must be a stream index between 0 and 99` }
+      }
+      selection[key] = index
+    }
+    return { isValid: true, value: selection }
+  }
+
+  /**
+   * Validate options for adding external audio to a video
+   */
+  static validateAudioMergeOptions(options: any): ValidationResult<AudioMergeOptions> {
+    if (options === undefined || options === null) {
+      return { isValid: true, value: {} }
+    }
+    if (typeof options !== 'object') {
+      return { isValid: false, error: 'Audio merge options must be an object' }
+    }
+
+    const value: AudioMergeOptions = { replace: options.replace === true }
+    if (options.offset !== undefined) {
+      if (typeof options.offset !== 'number' || !Number.isFinite(options.offset) || Math.abs(options.offset) > 3600) {
+        return { isValid: false, error: 'Audio offset must be between -3600 and 3600 seconds' }
+      }
+      value.offset = options.offset
+    }
+    for (const key of ['originalVolume', 'newVolume'] as const) {
+      const volume = options[key]
+      if (volume === undefined) continue
+      if (typeof volume !== 'number' || !Number.isFinite(volume) || volume < 0 || volume > 2) {
+        return { isValid: false, error: `${key} must be between 0 and 2` }
+      }
+      value[key] = volume
+    }
+    if (value.originalVolume === 0 && value.newVolume === 0) {
+      return { isValid: false, error: 'At least one audio track must be audible' }
+    }
+    return { isValid: true, value }
+  }
+
+  /**
+   * Validate slideshow options; the audio path is checked by the caller
+   */
+  static validateSlideshowOptions(options: any): ValidationResult<SlideshowOptions> {
+    if (options === undefined || options === null) {
+      return { isValid: true, value: {} }
+    }
+    if (typeof options !== 'object') {
+      return { isValid: false, error: 'Slideshow options must be an object' }
+    }
+
+    const ranges = {
+      durationPerImage: [0.1, 600],
+      transitionDuration: [0, 10],
+      fps: [1, 120],
+      width: [16, 7680],
+      height: [16, 4320],
+    } as const
+    const value: SlideshowOptions = {}
+    for (const [key, [min, max]] of Object.entries(ranges) as [keyof typeof ranges, readonly [number, number]][]) {
+      const number = options[key]
+      if (number === undefined) continue
+      if (typeof number !== 'number' || !Number.isFinite(number) || number < min || number > max) {
+        return { isValid: false, error: `${key} must be between ${min} and ${max}` }
+      }
+      value[key] = key === 'width' || key === 'height' ? Math.round(number / 2) * 2 : number // yuv420p needs even sizes
+    }
+    if ((value.transitionDuration ?? 1) > (value.durationPerImage ?? 4)) {
+      return { isValid: false, error: 'Transitions cannot be longer than the time each image is shown' }
+    }
+    if (options.kenBurns !== undefined) {
+      value.kenBurns = options.kenBurns === true
+    }
+    if (options.audioPath !== undefined && typeof options.audioPath !== 'string') {
+      return { isValid: false, error: 'Audio path must be a string' }
+    }
+    value.audioPath = options.audioPath || undefined
+    return { isValid: true, value }
+  }
+
+  /**
+   * Validate a clip effect; params are clamped to their ranges and missing ones get defaults
+   */
+  static validateEffect(effect: any): ValidationResult<Effect> {
+    if (!effect || typeof effect !== 'object') {
+      return { isValid: false, error: 'Effect must be an object' }
+    }
+    if (!isEffectType(effect.effectType)) {
+      return { isValid: false, error: `Unknown effect type: ${effect.effectType}` }
+    }
+    if (effect.params !== undefined && (typeof effect.params !== 'object' || effect.params === null)) {
+      return { isValid: false, error: 'Effect params must be an object' }
+    }
+    return {
+      isValid: true,
+      value: {
+        id: typeof effect.id === 'string' && effect.id ? effect.id : randomUUID(),
+        effectType: effect.effectType,
+        params: resolveEffectParams({ effectType: effect.effectType, params: effect.params ?? {} }),
+      },
+    }
+  }
+
   /**
    * Validate file path
    */
@@ -291,6 +713,51 @@ export class ValidationUtils {
     }
   }
 
+  /**
+   * Check that a download folder can be written to, creating it if it doesn't exist yet.
+   * Writes and removes a small temp file, since permission bits don't tell the whole story
+   * (read-only mounts, ACLs, sync folders).
+   */
+  static validateOutputPathWritable(dirPath: string): ValidationResult<string> {
+    if (!dirPath || typeof dirPath !== 'string' || dirPath.includes('\0') || !path.isAbsolute(dirPath)) {
+      return {
+        isValid: false,
+        error: 'Download folder must be an absolute path',
+        messageKey: 'errorDownloadFolderNotAbsolute',
+      }
+    }
+
+    const resolvedPath = path.resolve(dirPath)
+    try {
+      if (!existsSync(resolvedPath)) {
+        mkdirSync(resolvedPath, { recursive: true })
+      }
+      const probePath = path.join(resolvedPath, `clipy_write_test_${randomUUID()}.tmp`)
+      writeFileSync(probePath, '')
+      unlinkSync(probePath)
+      return { isValid: true, value: resolvedPath }
+    } catch (error) {
+      this.logger.warn('Download folder is not writable', { dirPath, error: (error as Error).message })
+      const params = { path: resolvedPath, reason: (error as Error).message }
+      return {
+        isValid: false,
+        error: formatMessage('errorDownloadFolderNotWritable', params, 'en'),
+        messageKey: 'errorDownloadFolderNotWritable',
+        params,
+      }
+    }
+  }
+
+  /**
+   * Error response for a failed validation, localizable when the result carries a message key
+   */
+  static toErrorResponse(result: ValidationResult, fallback: string, code: string): ErrorResponse {
+    if (result.messageKey) {
+      return createLocalizedErrorResponse(result.messageKey, result.params, code)
+    }
+    return createErrorResponse(result.error || fallback, code)
+  }
+
   /**
    * Handle download errors with proper error codes
    */
@@ -313,7 +780,12 @@ export class ValidationUtils {
       }
 
       if (message.includes('disk') || message.includes('space')) {
-        return createDownloadError(error.message, DownloadErrorCode.DISK_SPACE, error, false)
+        return createLocalizedDownloadError(
+          'errorDiskSpace',
+          { reason: error.message },
+          DownloadErrorCode.DISK_SPACE,
+          error,
+        )
       }
 
       if (message.includes('permission') || message.includes('access')) {
@@ -495,18 +967,64 @@ export class ValidationUtils {
           validatedUpdates.download.maxConcurrentDownloads = value
         }
 
+        if (typeof updates.download.maxConcurrentPerHost === 'number') {
+          const value = Math.max(0, Math.min(10, Math.floor(updates.download.maxConcurrentPerHost)))
+          validatedUpdates.download.maxConcurrentPerHost = value
+        }
+
+        if (updates.download.qualityFallback === 'closest' || updates.download.qualityFallback === 'fail') {
+          validatedUpdates.download.qualityFallback = updates.download.qualityFallback
+        }
+
         if (typeof updates.download.timeoutMs === 'number') {
           const value = Math.max(1000, Math.min(3600000, updates.download.timeoutMs))
           validatedUpdates.download.timeoutMs = value
         }
 
+        if (typeof updates.download.filenameTemplate === 'string') {
+          // Path separators would let the template escape the download directory
+          validatedUpdates.download.filenameTemplate = updates.download.filenameTemplate
+            .replace(/[/\\]/g, '')
+            .trim()
+            .substring(0, 200)
+        }
+
+        if (SPONSORBLOCK_MODES.includes(updates.download.sponsorBlockMode)) {
+          validatedUpdates.download.sponsorBlockMode = updates.download.sponsorBlockMode
+        }
+        const removeCategories = this.validateSponsorBlockCategories(
+          updates.download.sponsorBlockRemoveCategories,
+          SPONSORBLOCK_REMOVE_CATEGORIES,
+        )
+        if (removeCategories.isValid) {
+          validatedUpdates.download.sponsorBlockRemoveCategories = removeCategories.value
+        }
+        const markCategories = this.validateSponsorBlockCategories(
+          updates.download.sponsorBlockMarkCategories,
+          SPONSORBLOCK_MARK_CATEGORIES,
+        )
+        if (markCategories.isValid) {
+          validatedUpdates.download.sponsorBlockMarkCategories = markCategories.value
+        }
+
+        if (typeof updates.download.cookiesFromBrowser === 'string') {
+          const browser = updates.download.cookiesFromBrowser
+          if (browser === '' || COOKIE_BROWSERS.includes(browser)) {
+            validatedUpdates.download.cookiesFromBrowser = browser
+          }
+        }
+
         // Validate boolean settings
         const booleanSettings = [
           'downloadSubtitles',
           'downloadThumbnails',
           'saveMetadata',
           'createSubdirectories',
+          'includeDateInFilename',
           'autoRetryFailed',
+          'permanentlyDeleteFiles',
+          'watchDownloadFolder',
+          'useTempDirForDownloads',
         ]
 
         for (const setting of booleanSettings) {
@@ -516,6 +1034,68 @@ export class ValidationUtils {
         }
       }
 
+      if (updates.advanced) {
+        validatedUpdates.advanced = {}
+
+        if (typeof updates.advanced.maxLogFileSizeMb === 'number') {
+          const value = Math.max(1, Math.min(1024, Math.floor(updates.advanced.maxLogFileSizeMb)))
+          validatedUpdates.advanced.maxLogFileSizeMb = value
+        }
+
+        if (typeof updates.advanced.maxLogFiles === 'number') {
+          validatedUpdates.advanced.maxLogFiles = Math.max(1, Math.min(100, Math.floor(updates.advanced.maxLogFiles)))
+        }
+
+        if (typeof updates.advanced.thumbnailCacheDays === 'number') {
+          const value = Math.max(1, Math.min(365, Math.floor(updates.advanced.thumbnailCacheDays)))
+          validatedUpdates.advanced.thumbnailCacheDays = value
+        }
+
+        if (typeof updates.advanced.maxMemoryMb === 'number') {
+          const value = Math.max(0, Math.min(65536, Math.floor(updates.advanced.maxMemoryMb)))
+          validatedUpdates.advanced.maxMemoryMb = value
+        }
+
+        if (typeof updates.advanced.debugLogging === 'boolean') {
+          validatedUpdates.advanced.debugLogging = updates.advanced.debugLogging
+        }
+
+        if (typeof updates.advanced.proxyUrl === 'string') {
+          const proxyUrl = updates.advanced.proxyUrl.trim()
+          if (proxyUrl === '' || this.validateProxyUrl(proxyUrl).isValid) {
+            validatedUpdates.advanced.proxyUrl = proxyUrl
+          }
+        }
+
+        if (typeof updates.advanced.respectUserYtdlpConfig === 'boolean') {
+          validatedUpdates.advanced.respectUserYtdlpConfig = updates.advanced.respectUserYtdlpConfig
+        }
+
+        // Custom binaries: empty means the bundled one
+        for (const setting of ['ffmpegPath', 'ytDlpPath']) {
+          const value = updates.advanced[setting]
+          if (typeof value === 'string' && !value.includes('\0') && (value === '' || path.isAbsolute(value.trim()))) {
+            validatedUpdates.advanced[setting] = value.trim()
+          }
+        }
+      }
+
+      if (updates.sitePolicy) {
+        validatedUpdates.sitePolicy = {}
+
+        if (SITE_POLICY_MODES.includes(updates.sitePolicy.mode)) {
+          validatedUpdates.sitePolicy.mode = updates.sitePolicy.mode
+        }
+
+        if (Array.isArray(updates.sitePolicy.patterns)) {
+          const patterns = updates.sitePolicy.patterns
+            .filter((pattern: unknown): pattern is string => typeof pattern === 'string')
+            .map(normalizeSitePattern)
+            .filter((pattern: string | null): pattern is string => pattern !== null)
+          validatedUpdates.sitePolicy.patterns = Array.from(new Set(patterns)).slice(0, MAX_SITE_PATTERNS)
+        }
+      }
+
       return { isValid: true, value: validatedUpdates }
     } catch (error) {
       this.logger.error('Config update validation failed', error as Error, { updates })
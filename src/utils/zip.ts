@@ -0,0 +1,173 @@
+/**
+ * Zip Utilities
+ * Minimal ZIP archive reader/writer (deflate, no ZIP64) built on zlib.
+ * Used for app data backups, which are small enough to hold in memory.
+ */
+
+import { deflateRawSync, inflateRawSync } from 'zlib'
+
+export interface ZipEntry {
+  name: string // Forward-slash separated path inside the archive
+  data: Buffer
+}
+
+const LOCAL_HEADER_SIGNATURE = 0x04034b50
+const CENTRAL_HEADER_SIGNATURE = 0x02014b50
+const END_OF_CENTRAL_DIR_SIGNATURE = 0x06054b50
+const METHOD_STORE = 0
+const METHOD_DEFLATE = 8
+const UTF8_FLAG = 0x0800
+
+const CRC_TABLE = (() => {
+  const table = new Uint32Array(256)
+  for (let n = 0; n < 256; n++) {
+    let c = n
+    for (let k = 0; k < 8; k++) {
+      c = c & 1 ? 0xedb88320 ^ (c >>> 1) : c >>> 1
+    }
+    table[n] = c >>> 0
+  }
+  return table
+})()
+
+function crc32(data: Buffer): number {
+  let crc = 0xffffffff
+  for (let i = 0; i < data.length; i++) {
+    crc = CRC_TABLE[(crc ^ data[i]) & 0xff] ^ (crc >>> 8)
+  }
+  return (crc ^ 0xffffffff) >>> 0
+}
+
+/**
+ * Convert a Date to MS-DOS time/date fields
+ */
+function toDosDateTime(date: Date): { time: number; date: number } {
+  return {
+    time: (date.getHours() << 11) | (date.getMinutes() << 5) | Math.floor(date.getSeconds() / 2),
+    date: ((date.getFullYear() - 1980) << 9) | ((date.getMonth() + 1) << 5) | date.getDate(),
+  }
+}
+
+/**
+ * Build a ZIP archive from in-memory entries
+ */
+export function createZip(entries: ZipEntry[]): Buffer {
+  const localParts: Buffer[] = []
+  const centralParts: Buffer[] = []
+  const { time, date } = toDosDateTime(new Date())
+  let offset = 0
+
+  for (const entry of entries) {
+    const name = Buffer.from(entry.name.replace(/\\/g, '/'), 'utf8')
+    const compressed = deflateRawSync(entry.data)
+    // Keep already-compressed data (e.g. JPEGs) stored if deflate doesn't help
+    const useDeflate = compressed.length < entry.data.length
+    const payload = useDeflate ? compressed : entry.data
+    const checksum = crc32(entry.data)
+
+    const local = Buffer.alloc(30)
+    local.writeUInt32LE(LOCAL_HEADER_SIGNATURE, 0)
+    local.writeUInt16LE(20, 4) // Version needed
+    local.writeUInt16LE(UTF8_FLAG, 6)
+    local.writeUInt16LE(useDeflate ? METHOD_DEFLATE : METHOD_STORE, 8)
+    local.writeUInt16LE(time, 10)
+    local.writeUInt16LE(date, 12)
+    local.writeUInt32LE(checksum, 14)
+    local.writeUInt32LE(payload.length, 18)
+    local.writeUInt32LE(entry.data.length, 22)
+    local.writeUInt16LE(name.length, 26)
+    local.writeUInt16LE(0, 28)
+
+    const central = Buffer.alloc(46)
+    central.writeUInt32LE(CENTRAL_HEADER_SIGNATURE, 0)
+    central.writeUInt16LE(20, 4) // Version made by
+    central.writeUInt16LE(20, 6) // Version needed
+    central.writeUInt16LE(UTF8_FLAG, 8)
+    central.writeUInt16LE(useDeflate ? METHOD_DEFLATE : METHOD_STORE, 10)
+    central.writeUInt16LE(time, 12)
+    central.writeUInt16LE(date, 14)
+    central.writeUInt32LE(checksum, 16)
+    central.writeUInt32LE(payload.length, 20)
+    central.writeUInt32LE(entry.data.length, 24)
+    central.writeUInt16LE(name.length, 28)
+    central.writeUInt32LE(offset, 42)
+
+    localParts.push(local, name, payload)
+    centralParts.push(central, name)
+    offset += local.length + name.length + payload.length
+  }
+
+  const centralDirectory = Buffer.concat(centralParts)
+  const end = Buffer.alloc(22)
+  end.writeUInt32LE(END_OF_CENTRAL_DIR_SIGNATURE, 0)
+  end.writeUInt16LE(entries.length, 8)
+  end.writeUInt16LE(entries.length, 10)
+  end.writeUInt32LE(centralDirectory.length, 12)
+  end.writeUInt32LE(offset, 16)
+
+  return Buffer.concat([...localParts, centralDirectory, end])
+}
+
+/**
+ * Read the entries of a ZIP archive, optionally only those whose name passes include.
+ * Throws on unsupported or corrupt archives.
+ */
+export function readZip(archive: Buffer, include?: (name: string) => boolean): ZipEntry[] {
+  // The end-of-central-directory record sits in the last 22 bytes plus an optional comment
+  let endOffset = -1
+  for (let i = archive.length - 22; i >= Math.max(0, archive.length - 22 - 0xffff); i--) {
+    if (archive.readUInt32LE(i) === END_OF_CENTRAL_DIR_SIGNATURE) {
+      endOffset = i
+      break
+    }
+  }
+  if (endOffset === -1) {
+    throw new Error('Not a valid zip archive')
+  }
+
+  const entryCount = archive.readUInt16LE(endOffset + 10)
+  let offset = archive.readUInt32LE(endOffset + 16)
+  const entries: ZipEntry[] = []
+
+  for (let i = 0; i < entryCount; i++) {
+    if (archive.readUInt32LE(offset) !== CENTRAL_HEADER_SIGNATURE) {
+      throw new Error('Corrupt zip central directory')
+    }
+
+    const method = archive.readUInt16LE(offset + 10)
+    const checksum = archive.readUInt32LE(offset + 16)
+    const compressedSize = archive.readUInt32LE(offset + 20)
+    const nameLength = archive.readUInt16LE(offset + 28)
+    const extraLength = archive.readUInt16LE(offset + 30)
+    const commentLength = archive.readUInt16LE(offset + 32)
+    const localOffset = archive.readUInt32LE(offset + 42)
+    const name = archive.toString('utf8', offset + 46, offset + 46 + nameLength)
+    if (name.endsWith('/') || (include && !include(name))) {
+      offset += 46 + nameLength + extraLength + commentLength
+      continue
+    }
+
+    const localNameLength = archive.readUInt16LE(localOffset + 26)
+    const localExtraLength = archive.readUInt16LE(localOffset + 28)
+    const dataStart = localOffset + 30 + localNameLength + localExtraLength
+    const payload = archive.subarray(dataStart, dataStart + compressedSize)
+
+    let data: Buffer
+    if (method === METHOD_STORE) {
+      data = Buffer.from(payload)
+    } else if (method === METHOD_DEFLATE) {
+      data = inflateRawSync(payload)
+    } else {
+      throw new Error(`Unsupported zip compression method: ${method}`)
+    }
+
+    if (crc32(data) !== checksum) {
+      throw new Error(`Checksum mismatch for ${name}`)
+    }
+
+    entries.push({ name, data })
+    offset += 46 + nameLength + extraLength + commentLength
+  }
+
+  return entries
+}
@@ -0,0 +1,15 @@
+import path from 'path'
+import { defineConfig } from 'vitest/config'
+
+export default defineConfig({
+  resolve: {
+    alias: {
+      '@': path.resolve(__dirname, './src'),
+    },
+  },
+  test: {
+    environment: 'node',
+    include: ['src/tests/unit/**/*.test.ts'],
+    setupFiles: ['src/tests/unit/setup.ts'],
+  },
+})